@@ -44,6 +44,23 @@ fn main() {
                     value.source(),
                     value.destination()
                 ),
+                Some(Ieee8023(value)) => println!(
+                    "  IEEE 802.3 {:?} => {:?} {:?}",
+                    value.source(),
+                    value.destination(),
+                    value.llc()
+                ),
+                Some(LinuxSll(value)) => println!(
+                    "  Linux SLL {:?} (protocol {:?})",
+                    value.packet_type(),
+                    value.protocol_type()
+                ),
+                Some(LinuxSll2(value)) => println!(
+                    "  Linux SLL2 {:?} on interface {} (protocol {:?})",
+                    value.packet_type(),
+                    value.interface_index(),
+                    value.protocol_type()
+                ),
                 Some(EtherPayload(payload)) => {
                     println!("  EtherPayload (ether type {:?})", payload.ether_type)
                 }
@@ -60,6 +77,10 @@ fn main() {
                 None => {}
             }
 
+            if let Some(pppoe) = value.pppoe {
+                println!("  PPPoE session {:?}", pppoe.session_id());
+            }
+
             match value.net {
                 Some(Ipv4(ipv4)) => {
                     println!(
@@ -81,6 +102,12 @@ fn main() {
                         println!("    {:?}", ipv6.extensions());
                     }
                 }
+                Some(Arp(arp)) => println!(
+                    "  Arp {:?} {:?} => {:?}",
+                    arp.operation(),
+                    arp.sender_protocol_addr(),
+                    arp.target_protocol_addr()
+                ),
                 None => {}
             }
 
@@ -102,6 +129,13 @@ fn main() {
                         value.options_iterator().collect();
                     println!("    {:?}", options);
                 }
+                Some(Igmp(value)) => println!("  IGMP {:?}", value.message()),
+                Some(Esp(value)) => println!("  ESP spi: {}", value.spi()),
+                Some(Dccp(value)) => println!(
+                    "  DCCP {:?} -> {:?}",
+                    value.source_port(),
+                    value.destination_port()
+                ),
                 None => {}
             }
         }
@@ -268,6 +268,152 @@ mod sum16_bit_words_tests {
     }
 }
 
+/// Streaming calculator for the 16 bit ones complement "Internet checksum"
+/// (RFC 1071) that works without allocating.
+///
+/// Data can be pushed in arbitrarily split chunks. In case a chunk has an odd
+/// length the last byte is kept back and combined with the first byte of the
+/// next chunk, so the result is the same as if all data had been pushed
+/// at once. Only when [`Checksum16::finish`] is called a still outstanding
+/// trailing byte gets padded with zero.
+///
+/// ```
+/// use etherparse::checksum::Checksum16;
+///
+/// let mut checksum = Checksum16::new();
+/// checksum.push_slice(&[0x12, 0x34, 0x56]);
+/// checksum.push_slice(&[0x78]);
+/// checksum.push_u16(0x1111);
+/// assert_eq!(!(0x1234u16 + 0x5678 + 0x1111), checksum.finish());
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Checksum16 {
+    /// Sum of all complete 16 bit words pushed so far.
+    sum: Sum16BitWords,
+
+    /// Last byte of the data pushed so far in case an odd
+    /// number of bytes was pushed.
+    odd_byte: Option<u8>,
+}
+
+impl Checksum16 {
+    /// Creates a new checksum calculator without any data pushed.
+    #[inline]
+    pub fn new() -> Checksum16 {
+        Default::default()
+    }
+
+    /// Adds the given bytes to the checksum.
+    pub fn push_slice(&mut self, slice: &[u8]) {
+        let mut slice = slice;
+
+        // complete the word started by a previous odd length slice
+        if let Some(first) = self.odd_byte {
+            match slice.split_first() {
+                Some((second, rest)) => {
+                    self.sum = core::mem::take(&mut self.sum).add_2bytes([first, *second]);
+                    self.odd_byte = None;
+                    slice = rest;
+                }
+                None => return,
+            }
+        }
+
+        let even_len = slice.len() & !1;
+        self.sum = core::mem::take(&mut self.sum).add_slice(&slice[..even_len]);
+        if even_len != slice.len() {
+            self.odd_byte = Some(slice[even_len]);
+        }
+    }
+
+    /// Adds the given value in big endian byte order to the checksum.
+    #[inline]
+    pub fn push_u16(&mut self, value: u16) {
+        self.push_slice(&value.to_be_bytes());
+    }
+
+    /// Returns the ones complement checksum of all data pushed so far
+    /// (a trailing odd byte is padded with zero).
+    pub fn finish(&self) -> u16 {
+        match self.odd_byte {
+            Some(last) => self.sum.clone().add_2bytes([last, 0]).ones_complement(),
+            None => self.sum.ones_complement(),
+        }
+        .to_be()
+    }
+}
+
+#[cfg(test)]
+mod checksum16_tests {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn new() {
+        assert_eq!(0xffff, Checksum16::new().finish());
+    }
+
+    #[test]
+    fn push_u16() {
+        let mut checksum = Checksum16::new();
+        checksum.push_u16(0x1234);
+        assert_eq!(!0x1234, checksum.finish());
+
+        // odd byte still outstanding
+        let mut checksum = Checksum16::new();
+        checksum.push_slice(&[0x12]);
+        checksum.push_u16(0x3456);
+        assert_eq!(!(0x1234u16 + 0x5600), checksum.finish());
+    }
+
+    #[test]
+    fn finish_odd() {
+        let mut checksum = Checksum16::new();
+        checksum.push_slice(&[0x12, 0x34, 0x56]);
+        assert_eq!(!(0x1234u16 + 0x5600), checksum.finish());
+
+        // empty slices don't change the outstanding byte
+        checksum.push_slice(&[]);
+        assert_eq!(!(0x1234u16 + 0x5600), checksum.finish());
+        checksum.push_slice(&[0x78]);
+        assert_eq!(!(0x1234u16 + 0x5678), checksum.finish());
+    }
+
+    #[test]
+    fn debug_clone_eq_default() {
+        let value = Checksum16::new();
+        assert_eq!(value, Checksum16::default());
+        assert_eq!(value.clone(), value);
+        assert_eq!(
+            format!("{:?}", value),
+            format!(
+                "Checksum16 {{ sum: {:?}, odd_byte: None }}",
+                Sum16BitWords::new()
+            )
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn push_slice(
+            data in proptest::collection::vec(any::<u8>(), 0..64),
+            split_a in 0usize..64,
+            split_b in 0usize..64,
+        ) {
+            let expected = u16::from_be(Sum16BitWords::new().add_slice(&data).ones_complement());
+            let split_a = split_a.min(data.len());
+            let split_b = split_b.min(data.len()).max(split_a);
+
+            let mut checksum = Checksum16::new();
+            checksum.push_slice(&data[..split_a]);
+            checksum.push_slice(&data[split_a..split_b]);
+            checksum.push_slice(&data[split_b..]);
+            prop_assert_eq!(expected, checksum.finish());
+        }
+    }
+}
+
 /// Helper functions for calculating a 16 bit checksum using
 /// a u32 to sum up all values.
 pub mod u32_16bit_word {
@@ -922,3 +1068,447 @@ pub mod u64_16bit_word {
         }
     }
 }
+
+/// Lookup table for the CRC32c (Castagnoli, reflected polynomial 0x82F63B78).
+const CRC32C_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if 0 != crc & 1 {
+                (crc >> 1) ^ 0x82F6_3B78
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Feeds the given data into a not yet finalized (non inverted)
+/// CRC32c (Castagnoli) value.
+pub(crate) fn crc32c_update(crc: u32, data: &[u8]) -> u32 {
+    data.iter().fold(crc, |crc, value| {
+        CRC32C_TABLE[usize::from((crc as u8) ^ value)] ^ (crc >> 8)
+    })
+}
+
+/// Calculates the CRC32c (Castagnoli) checksum over the given data.
+///
+/// The CRC32c is used as checksum in SCTP packets
+/// ([RFC 9260](https://datatracker.ietf.org/doc/html/rfc9260)) & in
+/// iSCSI ([RFC 3720](https://datatracker.ietf.org/doc/html/rfc3720)).
+///
+/// Note that SCTP calculates the checksum over the complete packet with
+/// the checksum field set to zero & transmits it in little endian byte
+/// order:
+///
+/// ```
+/// use etherparse::checksum::crc32c;
+///
+/// assert_eq!(0xE306_9283, crc32c(b"123456789"));
+///
+/// # let sctp_packet = {
+/// #     let mut data = vec![0u8; 16];
+/// #     let crc = crc32c(&data);
+/// #     data[8..12].copy_from_slice(&crc.to_le_bytes());
+/// #     data
+/// # };
+/// // verify the checksum of a received SCTP packet
+/// let mut data = sctp_packet.clone();
+/// let received = u32::from_le_bytes([data[8], data[9], data[10], data[11]]);
+/// data[8..12].copy_from_slice(&[0; 4]);
+/// assert_eq!(received, crc32c(&data));
+/// ```
+#[inline]
+pub fn crc32c(data: &[u8]) -> u32 {
+    !crc32c_update(!0, data)
+}
+
+#[cfg(test)]
+mod crc32c_tests {
+    #[test]
+    fn crc32c() {
+        assert_eq!(0, super::crc32c(&[]));
+        assert_eq!(0xE306_9283, super::crc32c(b"123456789"));
+
+        // test vectors from RFC 3720 (appendix B.4)
+        assert_eq!(0x8A91_36AA, super::crc32c(&[0; 32]));
+        assert_eq!(0x62A8_AB43, super::crc32c(&[0xff; 32]));
+        {
+            let mut data = [0u8; 32];
+            for (i, value) in data.iter_mut().enumerate() {
+                *value = i as u8;
+            }
+            assert_eq!(0x46DD_794E, super::crc32c(&data));
+            data.reverse();
+            assert_eq!(0x113F_DB5C, super::crc32c(&data));
+        }
+    }
+
+    #[test]
+    fn crc32c_update() {
+        assert_eq!(0, !super::crc32c_update(!0, &[]));
+        let data = b"123456789";
+        for split in 0..data.len() {
+            assert_eq!(
+                0xE306_9283,
+                !super::crc32c_update(super::crc32c_update(!0, &data[..split]), &data[split..])
+            );
+        }
+    }
+}
+
+/// Lookup table for the CRC32 used by Ethernet (IEEE 802.3, reflected
+/// polynomial 0xEDB88320).
+const CRC32_ETHERNET_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if 0 != crc & 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Calculates the CRC32 used in the Ethernet FCS (frame check sequence)
+/// over the given data.
+///
+/// The FCS is transmitted in little endian byte order after the frame,
+/// so a frame (headers & payload without the FCS) can be verified
+/// by comparing the result with the last 4 bytes of the captured data:
+///
+/// ```
+/// use etherparse::checksum::crc32_ethernet;
+///
+/// assert_eq!(0xCBF4_3926, crc32_ethernet(b"123456789"));
+///
+/// # let frame_with_fcs = {
+/// #     let mut data = vec![1, 2, 3, 4];
+/// #     let fcs = crc32_ethernet(&data);
+/// #     data.extend_from_slice(&fcs.to_le_bytes());
+/// #     data
+/// # };
+/// let (frame, fcs) = frame_with_fcs.split_at(frame_with_fcs.len() - 4);
+/// assert_eq!(
+///     u32::from_le_bytes([fcs[0], fcs[1], fcs[2], fcs[3]]),
+///     crc32_ethernet(frame)
+/// );
+/// ```
+pub fn crc32_ethernet(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, value| {
+        CRC32_ETHERNET_TABLE[usize::from((crc as u8) ^ value)] ^ (crc >> 8)
+    })
+}
+
+#[cfg(test)]
+mod crc32_ethernet_tests {
+    #[test]
+    fn crc32_ethernet() {
+        assert_eq!(0, super::crc32_ethernet(&[]));
+        assert_eq!(0xCBF4_3926, super::crc32_ethernet(b"123456789"));
+        assert_eq!(0x190A_55AD, super::crc32_ethernet(&[0; 32]));
+    }
+}
+
+/// Recalculates the IPv4 header checksum & the checksum of the transport
+/// layer (UDP, TCP, ICMPv4, ICMPv6, IGMP & DCCP) of a packet starting with an
+/// Ethernet II header & writes them into the given buffer.
+///
+/// This is useful after modifying fields of an already serialized packet
+/// (e.g. changing an address or a TCP flag). The packet is decoded via
+/// [`crate::SlicedPacket::from_ethernet`], layers that are not decoded
+/// (e.g. unknown ether types or IP protocols) are left unchanged, the same
+/// goes for ESP as it has no transport layer checksum. A zero UDP checksum
+/// in an IPv4 packet is kept as it indicates that the checksum is not used.
+///
+/// # Errors
+///
+/// The packet is decoded completely before any checksum is written. If an
+/// error is encountered while decoding any of the layers, the error is
+/// returned & no checksum is updated (not even the checksums of the layers
+/// that were decoded successfully before the error). The buffer is left
+/// unchanged in this case.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{checksum::recompute_checksums, PacketBuilder, SlicedPacket, TransportSlice};
+///
+/// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .tcp(21, 1234, 1, 1024);
+/// let mut packet = Vec::<u8>::with_capacity(builder.size(4));
+/// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+///
+/// // change the destination address & set the FIN flag
+/// packet[30..34].copy_from_slice(&[10, 0, 0, 1]);
+/// packet[47] |= 0b0000_0001;
+///
+/// recompute_checksums(&mut packet).unwrap();
+///
+/// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+/// let Some(TransportSlice::Tcp(tcp)) = sliced.transport else { panic!() };
+/// assert!(tcp.fin());
+/// assert_eq!(
+///     tcp.checksum(),
+///     tcp.to_header().calc_checksum_ipv4_raw([192, 168, 1, 1], [10, 0, 0, 1], tcp.payload()).unwrap()
+/// );
+/// ```
+pub fn recompute_checksums(buf: &mut [u8]) -> Result<(), crate::err::packet::SliceError> {
+    let updates = checksum_updates(buf, crate::SlicedPacket::from_ethernet(buf)?);
+    apply_checksum_updates(buf, updates);
+    Ok(())
+}
+
+/// Recalculates the IPv4 header checksum & the checksum of the transport
+/// layer (UDP, TCP, ICMPv4, ICMPv6, IGMP & DCCP) of a packet starting with an
+/// IPv4 or IPv6 header & writes them into the given buffer.
+///
+/// Behaves like [`recompute_checksums`] but decodes the packet via
+/// [`crate::SlicedPacket::from_ip`].
+pub fn recompute_checksums_ip(buf: &mut [u8]) -> Result<(), crate::err::packet::SliceError> {
+    let updates = checksum_updates(buf, crate::SlicedPacket::from_ip(buf)?);
+    apply_checksum_updates(buf, updates);
+    Ok(())
+}
+
+/// Calculates the checksums of the given sliced packet together with the
+/// offsets of the checksum fields relative to the start of `base` (the
+/// data the packet was sliced from).
+fn checksum_updates(base: &[u8], sliced: crate::SlicedPacket<'_>) -> [Option<(usize, u16)>; 2] {
+    use crate::{NetSlice, TransportSlice::*};
+
+    let offset_of = |slice: &[u8]| slice.as_ptr() as usize - base.as_ptr() as usize;
+
+    let ipv4 = match &sliced.net {
+        Some(NetSlice::Ipv4(ipv4)) => Some((
+            offset_of(ipv4.header().slice()) + 10,
+            ipv4.header().to_header().calc_header_checksum(),
+        )),
+        _ => None,
+    };
+    let transport = sliced
+        .calc_transport_checksum()
+        .and_then(|(offset, checksum)| {
+            let start = offset_of(match sliced.transport.as_ref()? {
+                Icmpv4(s) => s.slice(),
+                Icmpv6(s) => s.slice(),
+                Udp(s) => s.slice(),
+                Tcp(s) => s.slice(),
+                Igmp(s) => s.slice(),
+                Esp(s) => s.slice(),
+                Dccp(s) => s.slice(),
+            });
+            Some((start + offset, checksum))
+        });
+    [ipv4, transport]
+}
+
+/// Writes the checksums calculated by [`checksum_updates`] into the buffer.
+fn apply_checksum_updates(buf: &mut [u8], updates: [Option<(usize, u16)>; 2]) {
+    for (offset, checksum) in updates.into_iter().flatten() {
+        buf[offset..offset + 2].copy_from_slice(&checksum.to_be_bytes());
+    }
+}
+
+#[cfg(test)]
+mod recompute_checksums_tests {
+    use super::*;
+    use crate::*;
+    use alloc::vec::Vec;
+
+    fn build(
+        write: impl FnOnce(&mut Vec<u8>) -> Result<(), err::packet::BuildWriteError>,
+    ) -> Vec<u8> {
+        let mut result = Vec::new();
+        write(&mut result).unwrap();
+        result
+    }
+
+    #[test]
+    fn recompute_checksums() {
+        let payload = [1, 2, 3, 4, 5];
+        let tests = [
+            build(|v| {
+                PacketBuilder::ethernet2([1; 6], [2; 6])
+                    .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                    .udp(21, 1234)
+                    .write(v, &payload)
+            }),
+            build(|v| {
+                PacketBuilder::ethernet2([1; 6], [2; 6])
+                    .single_vlan(VlanId::try_new(12).unwrap())
+                    .ipv6([1; 16], [2; 16], 20)
+                    .tcp(21, 1234, 1, 1024)
+                    .write(v, &payload)
+            }),
+            build(|v| {
+                PacketBuilder::ethernet2([1; 6], [2; 6])
+                    .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                    .icmpv4_echo_request(1, 2)
+                    .write(v, &payload)
+            }),
+            build(|v| {
+                PacketBuilder::ethernet2([1; 6], [2; 6])
+                    .ipv6([1; 16], [2; 16], 20)
+                    .icmpv6_echo_request(1, 2)
+                    .write(v, &payload)
+            }),
+        ];
+        for expected in tests {
+            // overwrite all checksums & check they get restored
+            let mut actual = expected.clone();
+            let [ipv4, transport] =
+                checksum_updates(&expected, SlicedPacket::from_ethernet(&expected).unwrap());
+            assert!(transport.is_some());
+            for (offset, _) in [ipv4, transport].into_iter().flatten() {
+                actual[offset..offset + 2].copy_from_slice(&[0xab, 0xcd]);
+            }
+            assert_ne!(actual, expected);
+            super::recompute_checksums(&mut actual).unwrap();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn recompute_checksums_modified() {
+        let mut packet = build(|v| {
+            PacketBuilder::ethernet2([1; 6], [2; 6])
+                .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                .udp(21, 1234)
+                .write(v, &[1, 2, 3, 4])
+        });
+
+        // change the destination address
+        packet[30..34].copy_from_slice(&[10, 0, 0, 1]);
+        super::recompute_checksums(&mut packet).unwrap();
+
+        assert_eq!(
+            packet,
+            build(|v| PacketBuilder::ethernet2([1; 6], [2; 6])
+                .ipv4([192, 168, 1, 1], [10, 0, 0, 1], 20)
+                .udp(21, 1234)
+                .write(v, &[1, 2, 3, 4]))
+        );
+    }
+
+    #[test]
+    fn recompute_checksums_skipped() {
+        // zero udp checksum over ipv4 is kept
+        {
+            let mut packet = build(|v| {
+                PacketBuilder::ethernet2([1; 6], [2; 6])
+                    .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                    .udp(21, 1234)
+                    .write(v, &[1, 2, 3, 4])
+            });
+            packet[40..42].copy_from_slice(&[0, 0]);
+            let expected = packet.clone();
+            super::recompute_checksums(&mut packet).unwrap();
+            assert_eq!(packet, expected);
+        }
+        // unknown ether type
+        {
+            let mut packet = Vec::new();
+            packet.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type: EtherType(0x1234),
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            packet.extend_from_slice(&[1, 2, 3, 4]);
+            let expected = packet.clone();
+            super::recompute_checksums(&mut packet).unwrap();
+            assert_eq!(packet, expected);
+        }
+    }
+
+    #[test]
+    fn recompute_checksums_error() {
+        let mut packet = build(|v| {
+            PacketBuilder::ethernet2([1; 6], [2; 6])
+                .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                .udp(21, 1234)
+                .write(v, &[])
+        });
+        // invalidate the ipv4 header checksum (the header itself can
+        // still be decoded, only the udp header is cut off)
+        packet[14 + 10] = !packet[14 + 10];
+        let len = packet.len();
+        let expected_err = SlicedPacket::from_ethernet(&packet[..len - 1]).unwrap_err();
+        let expected_packet = packet.clone();
+        assert_eq!(
+            super::recompute_checksums(&mut packet[..len - 1]),
+            Err(expected_err)
+        );
+        // no checksum is written if an error occurs
+        assert_eq!(expected_packet, packet);
+    }
+
+    #[test]
+    fn recompute_checksums_ip() {
+        let expected = build(|v| {
+            PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                .tcp(21, 1234, 1, 1024)
+                .write(v, &[1, 2, 3, 4])
+        });
+        let mut actual = expected.clone();
+        // ipv4 header checksum & tcp checksum
+        actual[10..12].copy_from_slice(&[0, 0]);
+        actual[36..38].copy_from_slice(&[0, 0]);
+        super::recompute_checksums_ip(&mut actual).unwrap();
+        assert_eq!(actual, expected);
+
+        // error
+        assert!(super::recompute_checksums_ip(&mut [0x10]).is_err());
+    }
+
+    #[test]
+    fn recompute_checksums_dccp() {
+        let payload = [1, 2, 3, 4, 5];
+        let dccp = DccpHeader {
+            source_port: 21,
+            destination_port: 1234,
+            ..Default::default()
+        };
+        let ip = Ipv6Header {
+            payload_length: (dccp.header_len() + payload.len()) as u16,
+            next_header: ip_number::DCCP,
+            hop_limit: 20,
+            source: [1; 16],
+            destination: [2; 16],
+            ..Default::default()
+        };
+        let mut packet = Vec::new();
+        packet.extend_from_slice(&ip.to_bytes());
+        packet.extend_from_slice(&dccp.to_bytes());
+        packet.extend_from_slice(&payload);
+        packet[46..48].copy_from_slice(&[0xab, 0xcd]);
+
+        super::recompute_checksums_ip(&mut packet).unwrap();
+
+        let expected = DccpSlice::from_slice(&packet[40..])
+            .unwrap()
+            .calc_checksum_ipv6([1; 16], [2; 16])
+            .unwrap();
+        assert_ne!(0xabcd, expected);
+        assert_eq!(&packet[46..48], &expected.to_be_bytes());
+    }
+}
@@ -0,0 +1,70 @@
+/// Determines how the [`crate::PacketBuilder`] fills the checksum field
+/// of UDP & TCP headers.
+///
+/// Network cards that support checksum offloading calculate the transport
+/// checksum while sending the packet. In that case the checksum does not have
+/// to be calculated by the [`crate::PacketBuilder`] and the checksum field can
+/// be set to a placeholder value instead.
+///
+/// The IPv4 header checksum & ICMP checksums are always calculated.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ChecksumMode, PacketBuilder, SlicedPacket, TransportSlice};
+///
+/// let builder = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .udp(21, 1234)
+///     .checksum(ChecksumMode::Zero);
+///
+/// let mut packet = Vec::<u8>::with_capacity(builder.size(4));
+/// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+///
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// if let Some(TransportSlice::Udp(udp)) = sliced.transport {
+///     assert_eq!(0, udp.checksum());
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ChecksumMode {
+    /// Calculate the checksum (default).
+    #[default]
+    Compute,
+
+    /// Set the checksum field to zero.
+    ///
+    /// For UDP over IPv4 a checksum of zero signals that no checksum was
+    /// calculated. For TCP a zero checksum is only valid if the checksum
+    /// gets filled in by the network card. UDP over IPv6 requires a
+    /// checksum, writing a packet with this combination results in the
+    /// error [`crate::err::packet::BuildWriteError::ZeroUdpChecksumInIpv6`].
+    Zero,
+
+    /// Set the checksum field to the (not complemented) one's complement
+    /// sum of the pseudo header.
+    ///
+    /// This is the placeholder value expected by network cards that
+    /// calculate the checksum over the transport header & payload starting
+    /// with the value already present in the checksum field (e.g. Linux
+    /// `CHECKSUM_PARTIAL` offloading).
+    Placeholder,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn default() {
+        assert_eq!(ChecksumMode::Compute, ChecksumMode::default());
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let mode = ChecksumMode::Placeholder;
+        assert_eq!(mode, mode.clone());
+        assert_eq!("Placeholder", format!("{:?}", mode));
+    }
+}
@@ -291,6 +291,9 @@ impl ComponentTest {
             match result.link.as_ref() {
                 Some(l) => match l {
                     LinkSlice::Ethernet2(e) => Some(e.to_header()),
+                    LinkSlice::Ieee8023(_) => None,
+                    LinkSlice::LinuxSll(_) => None,
+                    LinkSlice::LinuxSll2(_) => None,
                     LinkSlice::EtherPayload(_) => None,
                 },
                 None => None,
@@ -317,6 +320,7 @@ impl ComponentTest {
                     .unwrap()
                     .0,
                 )),
+                Some(Arp(_)) => unreachable!(),
                 None => None,
             }
         });
@@ -331,6 +335,9 @@ impl ComponentTest {
                     Some(TransportHeader::Icmpv6(actual.header())),
                 Some(TransportSlice::Udp(actual)) => Some(TransportHeader::Udp(actual.to_header())),
                 Some(TransportSlice::Tcp(actual)) => Some(TransportHeader::Tcp(actual.to_header())),
+                Some(TransportSlice::Igmp(_)) => unreachable!(),
+                Some(TransportSlice::Esp(_)) => unreachable!(),
+                Some(TransportSlice::Dccp(_)) => unreachable!(),
                 None => None,
             }
         );
@@ -356,6 +363,9 @@ impl ComponentTest {
             Some(TransportSlice::Tcp(tcp)) => {
                 assert_eq!(&self.payload[..], tcp.payload());
             }
+            Some(TransportSlice::Igmp(_)) => unreachable!(),
+            Some(TransportSlice::Esp(_)) => unreachable!(),
+            Some(TransportSlice::Dccp(_)) => unreachable!(),
             // check ip next
             None => {
                 if let Some(ip) = result.net.as_ref() {
@@ -364,6 +374,7 @@ impl ComponentTest {
                         match ip {
                             NetSlice::Ipv4(s) => s.payload.payload,
                             NetSlice::Ipv6(s) => s.payload.payload,
+                            NetSlice::Arp(_) => unreachable!(),
                         }
                     );
                 } else {
@@ -635,8 +646,12 @@ fn test_packet_slicing_panics() {
     let s = SlicedPacket {
         link: None,
         vlan: None,
+        pppoe: None,
         net: None,
         transport: None,
+        vxlan: None,
+        gtpu: None,
+        geneve: None,
     };
     ComponentTest {
         link: Some(Ethernet2Header {
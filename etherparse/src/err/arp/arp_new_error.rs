@@ -0,0 +1,108 @@
+/// Error when creating an [`crate::ArpPacket`] with addresses
+/// that can not be represented in an ARP packet.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ArpNewError {
+    /// Error if the sender & target hardware addresses have
+    /// different lengths (sender length, target length).
+    HwAddrLenInconsistent(usize, usize),
+
+    /// Error if the hardware addresses are longer then 255 bytes.
+    HwAddrLenTooBig(usize),
+
+    /// Error if the sender & target protocol addresses have
+    /// different lengths (sender length, target length).
+    ProtocolAddrLenInconsistent(usize, usize),
+
+    /// Error if the protocol addresses are longer then 255 bytes.
+    ProtocolAddrLenTooBig(usize),
+}
+
+impl core::fmt::Display for ArpNewError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use ArpNewError::*;
+        match self {
+            HwAddrLenInconsistent(sender, target) =>
+                write!(f, "ARP Error: The sender hardware address length ({} bytes) differs from the target hardware address length ({} bytes). Both addresses must have the same length.", sender, target),
+            HwAddrLenTooBig(len) =>
+                write!(f, "ARP Error: The hardware address length ({} bytes) is bigger then the maximum of 255 bytes that can be represented in an ARP packet.", len),
+            ProtocolAddrLenInconsistent(sender, target) =>
+                write!(f, "ARP Error: The sender protocol address length ({} bytes) differs from the target protocol address length ({} bytes). Both addresses must have the same length.", sender, target),
+            ProtocolAddrLenTooBig(len) =>
+                write!(f, "ARP Error: The protocol address length ({} bytes) is bigger then the maximum of 255 bytes that can be represented in an ARP packet.", len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ArpNewError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArpNewError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "HwAddrLenTooBig(300)",
+            format!("{:?}", HwAddrLenTooBig(300))
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = HwAddrLenInconsistent(6, 4);
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "ARP Error: The sender hardware address length (6 bytes) differs from the target hardware address length (4 bytes). Both addresses must have the same length.",
+            format!("{}", HwAddrLenInconsistent(6, 4))
+        );
+        assert_eq!(
+            "ARP Error: The hardware address length (300 bytes) is bigger then the maximum of 255 bytes that can be represented in an ARP packet.",
+            format!("{}", HwAddrLenTooBig(300))
+        );
+        assert_eq!(
+            "ARP Error: The sender protocol address length (4 bytes) differs from the target protocol address length (16 bytes). Both addresses must have the same length.",
+            format!("{}", ProtocolAddrLenInconsistent(4, 16))
+        );
+        assert_eq!(
+            "ARP Error: The protocol address length (256 bytes) is bigger then the maximum of 255 bytes that can be represented in an ARP packet.",
+            format!("{}", ProtocolAddrLenTooBig(256))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(HwAddrLenInconsistent(6, 4).source().is_none());
+        assert!(HwAddrLenTooBig(300).source().is_none());
+        assert!(ProtocolAddrLenInconsistent(4, 16).source().is_none());
+        assert!(ProtocolAddrLenTooBig(256).source().is_none());
+    }
+}
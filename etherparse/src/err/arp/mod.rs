@@ -0,0 +1,2 @@
+mod arp_new_error;
+pub use arp_new_error::*;
@@ -0,0 +1,101 @@
+/// Errors that can be encountered while decoding a DCCP header.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum HeaderError {
+    /// Error when the data_offset is so small that the data would
+    /// start within the DCCP generic header itself.
+    DataOffsetTooSmall {
+        data_offset: u8,
+        extended_sequence_number: bool,
+    },
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            DataOffsetTooSmall{ data_offset, extended_sequence_number } => write!(
+                f,
+                "DCCP Header Error: 'data offset' too small ({}). The 'data offset' must be at least {} so the data is not overlapping with the DCCP generic header itself.",
+                data_offset,
+                if *extended_sequence_number { 4 } else { 3 }
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "DataOffsetTooSmall { data_offset: 1, extended_sequence_number: true }",
+            format!(
+                "{:?}",
+                DataOffsetTooSmall {
+                    data_offset: 1,
+                    extended_sequence_number: true
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = DataOffsetTooSmall {
+            data_offset: 1,
+            extended_sequence_number: false,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "DCCP Header Error: 'data offset' too small (2). The 'data offset' must be at least 3 so the data is not overlapping with the DCCP generic header itself.",
+            format!("{}", DataOffsetTooSmall{ data_offset: 2, extended_sequence_number: false })
+        );
+        assert_eq!(
+            "DCCP Header Error: 'data offset' too small (3). The 'data offset' must be at least 4 so the data is not overlapping with the DCCP generic header itself.",
+            format!("{}", DataOffsetTooSmall{ data_offset: 3, extended_sequence_number: true })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(DataOffsetTooSmall {
+            data_offset: 0,
+            extended_sequence_number: false
+        }
+        .source()
+        .is_none());
+    }
+}
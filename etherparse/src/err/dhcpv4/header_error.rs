@@ -0,0 +1,89 @@
+/// Error when decoding a DHCPv4 message.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum HeaderError {
+    /// Error when the magic cookie in front of the options does
+    /// not match the DHCP magic cookie (99, 130, 83, 99).
+    InvalidMagicCookie {
+        /// The unexpected magic cookie.
+        magic_cookie: [u8; 4],
+    },
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            InvalidMagicCookie { magic_cookie } => write!(f, "DHCPv4 Message Error: Encountered {:?} as magic cookie (must be [99, 130, 83, 99] in a DHCP message).", magic_cookie),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "InvalidMagicCookie { magic_cookie: [1, 2, 3, 4] }",
+            format!(
+                "{:?}",
+                InvalidMagicCookie {
+                    magic_cookie: [1, 2, 3, 4]
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = InvalidMagicCookie {
+            magic_cookie: [1, 2, 3, 4],
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "DHCPv4 Message Error: Encountered [1, 2, 3, 4] as magic cookie (must be [99, 130, 83, 99] in a DHCP message).",
+            format!("{}", InvalidMagicCookie{ magic_cookie: [1, 2, 3, 4] })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(InvalidMagicCookie {
+            magic_cookie: [0; 4]
+        }
+        .source()
+        .is_none());
+    }
+}
@@ -2,6 +2,7 @@ use crate::EtherType;
 
 /// Errors in an double vlan header encountered while decoding it.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HeaderError {
     /// Error when two vlan header were expected but the ether_type
     /// value of the first vlan header is not an vlan header type.
@@ -3,6 +3,7 @@ use crate::err::LenError;
 
 /// Error when decoding a double VLAN header from a slice.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HeaderSliceError {
     /// Error when an length error is encountered (e.g. unexpected
     /// end of slice).
@@ -0,0 +1,99 @@
+/// Error when the Ethernet FCS (frame check sequence) of a frame does
+/// not match the CRC32 calculated over the frame.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FcsError {
+    /// FCS value present at the end of the frame.
+    pub received: u32,
+
+    /// CRC32 calculated over the frame (see [`crate::checksum::crc32_ethernet`]).
+    pub calculated: u32,
+}
+
+impl core::fmt::Display for FcsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Ethernet FCS Error: Frame check sequence {:#010X} does not match the calculated CRC32 {:#010X}.",
+            self.received, self.calculated
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for FcsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "FcsError { received: 1, calculated: 2 }",
+            format!(
+                "{:?}",
+                FcsError {
+                    received: 1,
+                    calculated: 2
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = FcsError {
+            received: 1,
+            calculated: 2,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "Ethernet FCS Error: Frame check sequence 0x12345678 does not match the calculated CRC32 0xCBF43926.",
+            format!(
+                "{}",
+                FcsError {
+                    received: 0x1234_5678,
+                    calculated: 0xCBF4_3926
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(FcsError {
+            received: 1,
+            calculated: 2
+        }
+        .source()
+        .is_none());
+    }
+}
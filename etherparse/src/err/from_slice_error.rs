@@ -4,6 +4,7 @@ use super::*;
 /// as a "catch all" type for errors caused by `from_slice` functions
 /// as all errors from these functions can be converted into this type.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum FromSliceError {
     /// Error when parsing had to be aborted because of a length error (usually
     /// not enough data being available).
@@ -29,6 +30,12 @@ pub enum FromSliceError {
 
     /// Error while parsing a TCP extension header.
     Tcp(tcp::HeaderError),
+
+    /// Error while parsing a DCCP header.
+    Dccp(dccp::HeaderError),
+
+    /// Error when the Ethernet FCS does not match the frame.
+    Fcs(FcsError),
 }
 
 impl FromSliceError {
@@ -80,6 +87,18 @@ impl FromSliceError {
             _ => None,
         }
     }
+    pub fn dccp(&self) -> Option<&dccp::HeaderError> {
+        match self {
+            FromSliceError::Dccp(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn fcs(&self) -> Option<&FcsError> {
+        match self {
+            FromSliceError::Fcs(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 impl core::fmt::Display for FromSliceError {
@@ -94,6 +113,8 @@ impl core::fmt::Display for FromSliceError {
             Ipv6(err) => err.fmt(f),
             Ipv6Exts(err) => err.fmt(f),
             Tcp(err) => err.fmt(f),
+            Dccp(err) => err.fmt(f),
+            Fcs(err) => err.fmt(f),
         }
     }
 }
@@ -111,6 +132,8 @@ impl std::error::Error for FromSliceError {
             FromSliceError::Ipv6(err) => Some(err),
             FromSliceError::Ipv6Exts(err) => Some(err),
             FromSliceError::Tcp(err) => Some(err),
+            FromSliceError::Dccp(err) => Some(err),
+            FromSliceError::Fcs(err) => Some(err),
         }
     }
 }
@@ -286,6 +309,8 @@ impl From<packet::SliceError> for FromSliceError {
             Ipv4Exts(err) => FromSliceError::IpAuth(err),
             Ipv6Exts(err) => FromSliceError::Ipv6Exts(err),
             Tcp(err) => FromSliceError::Tcp(err),
+            Dccp(err) => FromSliceError::Dccp(err),
+            Fcs(err) => FromSliceError::Fcs(err),
         }
     }
 }
@@ -308,6 +333,23 @@ impl From<tcp::HeaderSliceError> for FromSliceError {
     }
 }
 
+// dccp error conversions
+impl From<dccp::HeaderError> for FromSliceError {
+    fn from(value: dccp::HeaderError) -> Self {
+        FromSliceError::Dccp(value)
+    }
+}
+
+impl From<dccp::HeaderSliceError> for FromSliceError {
+    fn from(value: dccp::HeaderSliceError) -> Self {
+        use dccp::HeaderSliceError::*;
+        match value {
+            Len(err) => FromSliceError::Len(err),
+            Content(err) => FromSliceError::Dccp(err),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{EtherType, LenSource};
@@ -343,7 +385,7 @@ mod tests {
 
     #[test]
     fn debug_source() {
-        let test_values: [(&str, FromSliceError); 8] = [
+        let test_values: [(&str, FromSliceError); 10] = [
             (
                 "Len",
                 Len(LenError {
@@ -383,6 +425,20 @@ mod tests {
                 "Tcp",
                 Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
             ),
+            (
+                "Dccp",
+                Dccp(dccp::HeaderError::DataOffsetTooSmall {
+                    data_offset: 1,
+                    extended_sequence_number: false,
+                }),
+            ),
+            (
+                "Fcs",
+                Fcs(FcsError {
+                    received: 1,
+                    calculated: 2,
+                }),
+            ),
         ];
         for (prefix, value) in &test_values {
             // display
@@ -395,7 +451,7 @@ mod tests {
 
     #[test]
     fn display_source() {
-        let test_values: [FromSliceError; 8] = [
+        let test_values: [FromSliceError; 10] = [
             Len(LenError {
                 required_len: 0,
                 len: 0,
@@ -414,6 +470,14 @@ mod tests {
             Ipv6(ipv6::HeaderError::UnexpectedVersion { version_number: 1 }),
             Ipv6Exts(ipv6_exts::HeaderError::HopByHopNotAtStart),
             Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
+            Dccp(dccp::HeaderError::DataOffsetTooSmall {
+                data_offset: 1,
+                extended_sequence_number: false,
+            }),
+            Fcs(FcsError {
+                received: 1,
+                calculated: 2,
+            }),
         ];
         for value in &test_values {
             // display
@@ -440,6 +504,10 @@ mod tests {
         let ip_auth_error = || ip_auth::HeaderError::ZeroPayloadLen;
         let ipv6_exts_error = || ipv6_exts::HeaderError::HopByHopNotAtStart;
         let tcp_error = || tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
+        let dccp_error = || dccp::HeaderError::DataOffsetTooSmall {
+            data_offset: 1,
+            extended_sequence_number: false,
+        };
 
         // len
         assert_eq!(Len(len_error()).len(), Some(&len_error()));
@@ -478,6 +546,18 @@ mod tests {
         // tcp
         assert_eq!(Tcp(tcp_error()).tcp(), Some(&tcp_error()));
         assert_eq!(IpAuth(ip_auth_error()).tcp(), None);
+
+        // dccp
+        assert_eq!(Dccp(dccp_error()).dccp(), Some(&dccp_error()));
+        assert_eq!(IpAuth(ip_auth_error()).dccp(), None);
+
+        // fcs
+        let fcs_error = || FcsError {
+            received: 1,
+            calculated: 2,
+        };
+        assert_eq!(Fcs(fcs_error()).fcs(), Some(&fcs_error()));
+        assert_eq!(IpAuth(ip_auth_error()).fcs(), None);
     }
 
     #[test]
@@ -724,6 +804,10 @@ mod tests {
             let ip_auth_error = || ip_auth::HeaderError::ZeroPayloadLen;
             let ipv6_exts_error = || ipv6_exts::HeaderError::HopByHopNotAtStart;
             let tcp_error = || tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
+            let dccp_error = || dccp::HeaderError::DataOffsetTooSmall {
+                data_offset: 1,
+                extended_sequence_number: false,
+            };
 
             // SliceError
             assert_eq!(
@@ -768,6 +852,22 @@ mod tests {
                     .tcp()
                     .unwrap()
             );
+            assert_eq!(
+                &dccp_error(),
+                FromSliceError::from(packet::SliceError::Dccp(dccp_error()))
+                    .dccp()
+                    .unwrap()
+            );
+            let fcs_error = || FcsError {
+                received: 1,
+                calculated: 2,
+            };
+            assert_eq!(
+                &fcs_error(),
+                FromSliceError::from(packet::SliceError::Fcs(fcs_error()))
+                    .fcs()
+                    .unwrap()
+            );
         }
 
         // tcp errors
@@ -796,5 +896,29 @@ mod tests {
                     .unwrap()
             );
         }
+
+        // dccp errors
+        {
+            let header_error = || dccp::HeaderError::DataOffsetTooSmall {
+                data_offset: 1,
+                extended_sequence_number: false,
+            };
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(header_error()).dccp().unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                FromSliceError::from(dccp::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                FromSliceError::from(dccp::HeaderSliceError::Content(header_error()))
+                    .dccp()
+                    .unwrap()
+            );
+        }
     }
 } // mod tests
@@ -0,0 +1,126 @@
+/// Error that can occur when finishing a [`crate::io::BackfillWriter`].
+#[derive(Debug)]
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub enum BackfillError {
+    /// IO error was encountered while writing or seeking.
+    Io(std::io::Error),
+
+    /// Length that should be backfilled is too big for the
+    /// 16 bit length field.
+    LenTooBig {
+        /// Offset of the length field (relative to the start of the writer).
+        offset: u64,
+        /// Length that should have been written.
+        len: u64,
+    },
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl BackfillError {
+    /// Returns the `std::io::Error` value if the `BackfillError` is `Io`.
+    /// Otherwise `None` is returned.
+    #[inline]
+    pub fn io(self) -> Option<std::io::Error> {
+        use BackfillError::*;
+        match self {
+            Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl core::fmt::Display for BackfillError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use BackfillError::*;
+        match self {
+            Io(err) => err.fmt(f),
+            LenTooBig { offset, len } => write!(
+                f,
+                "Backfill Error: Length of {} byte(s) is too big for the 16 bit length field at offset {} (maximum is {}).",
+                len,
+                offset,
+                u16::MAX
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for BackfillError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use BackfillError::*;
+        match self {
+            Io(err) => Some(err),
+            LenTooBig { .. } => None,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::BackfillError::*;
+    use alloc::format;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "LenTooBig { offset: 2, len: 70000 }",
+            format!(
+                "{:?}",
+                LenTooBig {
+                    offset: 2,
+                    len: 70000
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn fmt() {
+        {
+            let err = std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            );
+            assert_eq!(format!("{}", err), format!("{}", Io(err)));
+        }
+        assert_eq!(
+            "Backfill Error: Length of 70000 byte(s) is too big for the 16 bit length field at offset 2 (maximum is 65535).",
+            format!(
+                "{}",
+                LenTooBig {
+                    offset: 2,
+                    len: 70000
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn source() {
+        use std::error::Error;
+        assert!(Io(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        ))
+        .source()
+        .is_some());
+        assert!(LenTooBig { offset: 0, len: 0 }.source().is_none());
+    }
+
+    #[test]
+    fn io() {
+        assert!(Io(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        ))
+        .io()
+        .is_some());
+        assert!(LenTooBig { offset: 0, len: 0 }.io().is_none());
+    }
+}
@@ -1,2 +1,5 @@
+mod backfill_error;
+pub use backfill_error::*;
+
 mod limited_read_error;
 pub use limited_read_error::*;
@@ -3,6 +3,7 @@ use crate::*;
 
 /// Error when decoding the IP header part of a message.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HeaderError {
     /// Error when the IP header version field is not equal to 4 or 6.
     UnsupportedIpVersion {
@@ -2,6 +2,7 @@ use crate::*;
 
 /// Error when decoding the IP header part of a message.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HeadersError {
     /// Error in the IPv4 or IPv6 header.
     Ip(err::ip::HeaderError),
@@ -3,6 +3,7 @@ use crate::err::LenError;
 
 /// Error when decoding an IP header from a slice.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HeadersSliceError {
     /// Error when an length error is encountered (e.g. unexpected
     /// end of slice).
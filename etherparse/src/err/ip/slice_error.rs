@@ -2,6 +2,7 @@ use crate::err::{ip, LenError};
 
 /// Errors that can occur when slicing the IP part of a packet.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum SliceError {
     /// Length related errors (e.g. not enough data in slice).
     Len(LenError),
@@ -1,6 +1,7 @@
 /// Errors that can be encountered while decoding an IP
 /// authentication header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HeaderError {
     /// Error when the payload length is zero and therefor
     /// too small to contain the minimum fields of the IP
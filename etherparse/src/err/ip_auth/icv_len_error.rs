@@ -2,6 +2,7 @@
 /// length of the raw ICV is non representable in an IP authentication
 /// header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum IcvLenError {
     /// Error when the payload length is bigger then
     /// [`crate::IpAuthHeader::MAX_ICV_LEN`] (1016).
@@ -3,6 +3,7 @@ use crate::err::{ipv4_exts, ipv6_exts};
 /// Errors while serializing or determining the next_header of
 /// an [`crate::IpHeaders`].
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ExtsWalkError {
     /// Error within the IPv4 extensions headers.
     Ipv4Exts(ipv4_exts::ExtsWalkError),
@@ -2,6 +2,7 @@ use crate::*;
 
 /// Error when decoding the IP extension header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HeaderError {
     /// Error in the IPv4 extension headers (only authentication header).
     Ipv4Ext(err::ip_auth::HeaderError),
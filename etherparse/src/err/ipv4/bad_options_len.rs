@@ -3,6 +3,7 @@
 ///
 /// The length for options in an IPv4 header
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct BadOptionsLen {
     /// Invalid length.
     pub bad_len: usize,
@@ -1,5 +1,6 @@
 /// Error when decoding the IPv4 part of a message.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HeaderError {
     /// Error when the IPv4 header version field is not equal to 4.
     UnexpectedVersion {
@@ -3,6 +3,7 @@ use crate::IpNumber;
 /// Errors in content of IPv4 header extensions that prevent serialization
 /// or determining the next header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ExtsWalkError {
     /// Error when a header in [`crate::Ipv4Extensions`] is never referenced even
     /// though it is present in the [`crate::Ipv4Extensions`].
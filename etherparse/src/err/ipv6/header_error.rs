@@ -1,5 +1,6 @@
 /// Error when decoding the IPv6 header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HeaderError {
     /// Error when the IPv6 header version field is not equal to 6.
     UnexpectedVersion {
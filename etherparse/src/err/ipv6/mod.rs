@@ -9,5 +9,8 @@ pub use header_read_error::*;
 mod header_slice_error;
 pub use header_slice_error::*;
 
+mod payload_len_error;
+pub use payload_len_error::*;
+
 mod slice_error;
 pub use slice_error::*;
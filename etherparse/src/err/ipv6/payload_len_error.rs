@@ -0,0 +1,84 @@
+/// Error when setting the payload length of an [`crate::Ipv6Header`] via
+/// [`crate::Ipv6Header::set_payload_len`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PayloadLenError {
+    /// Error when the payload length is bigger than what can be represented
+    /// in the 16 bit "payload length" field. Payloads of this size have to
+    /// be sent as jumbograms ("payload length" set to zero & the length stored
+    /// in a "Jumbo Payload" option in a hop-by-hop header, see
+    /// [RFC 2675](https://datatracker.ietf.org/doc/html/rfc2675)).
+    PayloadTooLargeForField {
+        /// Payload length that was passed.
+        payload_len: usize,
+    },
+}
+
+impl core::fmt::Display for PayloadLenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use PayloadLenError::*;
+        match self {
+            PayloadTooLargeForField { payload_len } => write!(f, "IPv6 Header Error: Payload length of {} bytes is too large for the 16 bit 'payload length' field (maximum is 65535 bytes). Larger payloads require a jumbogram (zero 'payload length' field & a 'Jumbo Payload' option in a hop-by-hop header, see RFC 2675).", payload_len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for PayloadLenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PayloadLenError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "PayloadTooLargeForField { payload_len: 65536 }",
+            format!("{:?}", PayloadTooLargeForField { payload_len: 65536 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = PayloadTooLargeForField { payload_len: 65536 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv6 Header Error: Payload length of 65536 bytes is too large for the 16 bit 'payload length' field (maximum is 65535 bytes). Larger payloads require a jumbogram (zero 'payload length' field & a 'Jumbo Payload' option in a hop-by-hop header, see RFC 2675).",
+            format!("{}", PayloadTooLargeForField { payload_len: 65536 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(PayloadTooLargeForField { payload_len: 65536 }
+            .source()
+            .is_none());
+    }
+}
@@ -1,6 +1,7 @@
 /// Error when creating an [`crate::Ipv6RawExtHeader`] and the
 /// payload len is non representable.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ExtPayloadLenError {
     /// Error when the payload length is smaller then
     /// [`crate::Ipv6RawExtHeader::MIN_PAYLOAD_LEN`] (6).
@@ -2,6 +2,7 @@ use crate::IpNumber;
 
 /// Errors in content of IPv6 header extensions that prevent serialization.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ExtsWalkError {
     /// Error when a hop-by-hop header is not referenced as the
     /// first header after the ipv6 header but as a later extension
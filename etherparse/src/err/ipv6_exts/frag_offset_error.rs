@@ -0,0 +1,76 @@
+/// Error when creating an [`crate::Ipv6FragmentHeader`] via
+/// [`crate::Ipv6FragmentHeader::for_fragment`] and the offset in
+/// bytes is non representable.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FragOffsetError {
+    /// Error when the offset in bytes is not a multiple of 8
+    /// (the fragment offset field can only express offsets in
+    /// multiples of 8 bytes).
+    Unaligned(u16),
+}
+
+impl core::fmt::Display for FragOffsetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use FragOffsetError::*;
+        match self {
+            Unaligned(offset_bytes) =>
+                write!(f, "IPv6 fragment offset ({} bytes) is not a multiple of 8. This is required as the fragment offset field can only express offsets in multiples of 8 bytes.", offset_bytes),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for FragOffsetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FragOffsetError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!("Unaligned(3)", format!("{:?}", Unaligned(3)));
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = Unaligned(3);
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv6 fragment offset (12 bytes) is not a multiple of 8. This is required as the fragment offset field can only express offsets in multiples of 8 bytes.",
+            format!("{}", Unaligned(12))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(Unaligned(12).source().is_none());
+    }
+}
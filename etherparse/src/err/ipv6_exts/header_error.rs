@@ -2,10 +2,18 @@ use crate::*;
 
 /// Error when decoding IPv6 extension headers.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HeaderError {
     /// Error if the ipv6 hop by hop header does not occur directly after the ipv6 header (see rfc8200 chapter 4.1.)
     HopByHopNotAtStart,
 
+    /// Error if more extension headers were encountered then the
+    /// configured maximum (protects against maliciously long chains).
+    TooManyHeaders {
+        /// Maximum number of extension headers that were allowed.
+        max: usize,
+    },
+
     /// Error in the ip authentication header.
     IpAuth(err::ip_auth::HeaderError),
 }
@@ -15,6 +23,7 @@ impl core::fmt::Display for HeaderError {
         use HeaderError::*;
         match self {
             HopByHopNotAtStart => write!(f, "IPv6 Extension Header Error: Encountered an IPv6 hop-by-hop header not directly after the IPv6 header. This is not allowed according to RFC 8200."),
+            TooManyHeaders { max } => write!(f, "IPv6 Extension Header Error: Encountered more then the maximum allowed number of {} IPv6 extension headers.", max),
             IpAuth(err) => err.fmt(f),
         }
     }
@@ -27,6 +36,7 @@ impl std::error::Error for HeaderError {
         use HeaderError::*;
         match self {
             HopByHopNotAtStart => None,
+            TooManyHeaders { max: _ } => None,
             IpAuth(err) => Some(err),
         }
     }
@@ -71,6 +81,10 @@ mod tests {
             "IPv6 Extension Header Error: Encountered an IPv6 hop-by-hop header not directly after the IPv6 header. This is not allowed according to RFC 8200.",
             format!("{}", HopByHopNotAtStart)
         );
+        assert_eq!(
+            "IPv6 Extension Header Error: Encountered more then the maximum allowed number of 16 IPv6 extension headers.",
+            format!("{}", TooManyHeaders { max: 16 })
+        );
         {
             let err = err::ip_auth::HeaderError::ZeroPayloadLen;
             assert_eq!(format!("{}", err), format!("{}", IpAuth(err)));
@@ -83,6 +97,7 @@ mod tests {
         use err::ip_auth::HeaderError::ZeroPayloadLen;
 
         assert!(HopByHopNotAtStart.source().is_none());
+        assert!(TooManyHeaders { max: 16 }.source().is_none());
         assert!(IpAuth(ZeroPayloadLen).source().is_some());
     }
 }
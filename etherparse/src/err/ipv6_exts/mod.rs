@@ -1,6 +1,9 @@
 mod ext_payload_len_error;
 pub use ext_payload_len_error::*;
 
+mod frag_offset_error;
+pub use frag_offset_error::*;
+
 mod header_error;
 pub use header_error::*;
 
@@ -20,6 +23,12 @@ pub use exts_walk_error::*;
 mod header_slice_error;
 pub use header_slice_error::*;
 
+mod routing_data_len_error;
+pub use routing_data_len_error::*;
+
+mod segments_left_error;
+pub use segments_left_error::*;
+
 #[cfg(feature = "std")]
 mod header_write_error;
 #[cfg(feature = "std")]
@@ -0,0 +1,97 @@
+/// Error when creating an [`crate::Ipv6RoutingHeader`] and the
+/// length of the type specific data is non representable.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RoutingDataLenError {
+    /// Error when the data length is smaller then
+    /// [`crate::Ipv6RoutingHeader::MIN_DATA_LEN`] (4).
+    TooSmall(usize),
+
+    /// Error when the data length is bigger then
+    /// [`crate::Ipv6RoutingHeader::MAX_DATA_LEN`] (2044).
+    TooBig(usize),
+
+    /// Error when the data length can not be represented
+    /// as a multiple of 8-bytes in the routing header
+    /// (`0 == (data.len() + 4) % 8` is not fulfilled).
+    Unaligned(usize),
+}
+
+impl core::fmt::Display for RoutingDataLenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use RoutingDataLenError::*;
+        match self {
+            TooSmall(size) =>
+                write!(f, "IPv6 routing header data length is too small. The data size ({} bytes) is less then 4 octets which is the minimum IPv6 routing header data size.", size),
+            TooBig(size) =>
+                write!(f, "IPv6 routing header data length is too large. The data size ({} bytes) is larger then what can be be represented by the 'extended header size' field in an IPv6 routing header.", size),
+            Unaligned(size) =>
+                write!(f, "IPv6 routing header 'data length ({} bytes) + 4' is not multiple of 8 (+ 4 for the `next_header`, `header_length`, `routing_type` and `segments_left` fields). This is required as the header length field can only express lengths in multiple of 8 bytes.", size),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for RoutingDataLenError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoutingDataLenError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!("TooBig(3000)", format!("{:?}", TooBig(3000)));
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = TooBig(5000);
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv6 routing header data length is too small. The data size (2 bytes) is less then 4 octets which is the minimum IPv6 routing header data size.",
+            format!("{}", TooSmall(2))
+        );
+        assert_eq!(
+            "IPv6 routing header data length is too large. The data size (4000 bytes) is larger then what can be be represented by the 'extended header size' field in an IPv6 routing header.",
+            format!("{}", TooBig(4000))
+        );
+        assert_eq!(
+            "IPv6 routing header 'data length (6 bytes) + 4' is not multiple of 8 (+ 4 for the `next_header`, `header_length`, `routing_type` and `segments_left` fields). This is required as the header length field can only express lengths in multiple of 8 bytes.",
+            format!("{}", Unaligned(6))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(TooSmall(1).source().is_none());
+        assert!(TooBig(4000).source().is_none());
+        assert!(Unaligned(6).source().is_none());
+    }
+}
@@ -0,0 +1,115 @@
+/// Error when decrementing the `segments_left` field of an
+/// [`crate::Ipv6RoutingHeader`] via
+/// [`crate::Ipv6RoutingHeader::decrement_segments_left`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SegmentsLeftError {
+    /// Error when `segments_left` is already zero (no segments
+    /// left to be visited).
+    NoSegmentsLeft,
+
+    /// Error when `segments_left` of a "Segment Routing Header" (routing
+    /// type 4) is bigger then the number of segments in the segment list.
+    ExceedsSegmentList {
+        /// Value of the `segments_left` field.
+        segments_left: u8,
+        /// Number of segments in the segment list.
+        segment_count: usize,
+    },
+}
+
+impl core::fmt::Display for SegmentsLeftError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use SegmentsLeftError::*;
+        match self {
+            NoSegmentsLeft =>
+                write!(f, "IPv6 routing header 'segments left' can not be decremented as it is already zero."),
+            ExceedsSegmentList { segments_left, segment_count } =>
+                write!(f, "IPv6 segment routing header 'segments left' ({}) is bigger then the number of segments in the segment list ({}).", segments_left, segment_count),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for SegmentsLeftError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SegmentsLeftError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!("NoSegmentsLeft", format!("{:?}", NoSegmentsLeft));
+        assert_eq!(
+            "ExceedsSegmentList { segments_left: 3, segment_count: 2 }",
+            format!(
+                "{:?}",
+                ExceedsSegmentList {
+                    segments_left: 3,
+                    segment_count: 2
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = ExceedsSegmentList {
+            segments_left: 3,
+            segment_count: 2,
+        };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "IPv6 routing header 'segments left' can not be decremented as it is already zero.",
+            format!("{}", NoSegmentsLeft)
+        );
+        assert_eq!(
+            "IPv6 segment routing header 'segments left' (3) is bigger then the number of segments in the segment list (2).",
+            format!(
+                "{}",
+                ExceedsSegmentList {
+                    segments_left: 3,
+                    segment_count: 2
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(NoSegmentsLeft.source().is_none());
+        assert!(ExceedsSegmentList {
+            segments_left: 3,
+            segment_count: 2
+        }
+        .source()
+        .is_none());
+    }
+}
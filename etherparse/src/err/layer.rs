@@ -1,12 +1,31 @@
 /// Layers on which an error can occur.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Layer {
     /// Error occurred in the ethernet 2 header.
     Ethernet2Header,
     /// Payload identified by an ether type number (e.g. after an ethernet 2 header).
     EtherPayload,
+    /// Error occurred in the IEEE 802.3 header (destination, source & length).
+    Ieee8023Header,
+    /// Error occurred verifying the length of the IEEE 802.3 payload.
+    Ieee8023Payload,
+    /// Error occurred in the Linux cooked capture v1 (SLL) header.
+    LinuxSllHeader,
+    /// Error occurred in the Linux cooked capture v2 (SLL2) header.
+    LinuxSll2Header,
+    /// Error occurred while decoding an IEEE 802.2 LLC header.
+    LlcHeader,
+    /// Error occurred while decoding a SNAP header.
+    SnapHeader,
     /// Error occurred in the vlan header.
     VlanHeader,
+    /// Error occurred while decoding a MPLS label stack.
+    MplsLabelStack,
+    /// Error occurred while decoding a PPPoE header.
+    PppoeHeader,
+    /// Error occurred while decoding an ARP packet.
+    Arp,
     /// Error occurred when decoding an IP header (v4 or v6).
     IpHeader,
     /// Error occurred in the IPv4 layer.
@@ -15,6 +34,8 @@ pub enum Layer {
     Ipv4Packet,
     /// Error occurred in the IP Authentication header.
     IpAuthHeader,
+    /// Error occurred in the IPsec ESP header.
+    EspHeader,
     /// Error occurred in the IPv6 layer.
     Ipv6Header,
     /// Error occurred verifying the payload length of an IPv6 packet.
@@ -29,12 +50,30 @@ pub enum Layer {
     Ipv6RouteHeader,
     /// Error occurred while decoding an IPv6 fragment header.
     Ipv6FragHeader,
+    /// Error occurred while decoding an IPv6 mobility header.
+    Ipv6MobilityHeader,
+    /// Error occurred while decoding a GRE header.
+    GreHeader,
     /// Error occurred while decoding an UDP header.
     UdpHeader,
     /// Error occurred verifying the length of the UDP payload.
     UdpPayload,
     /// Error occurred while decoding a TCP header.
     TcpHeader,
+    /// Error occurred while decoding an SCTP common header.
+    SctpHeader,
+    /// Error occurred while decoding a DCCP header.
+    DccpHeader,
+    /// Error occurred while decoding a VXLAN header.
+    VxlanHeader,
+    /// Error occurred while decoding a GTP-U header or GTP-U extension header.
+    GtpuHeader,
+    /// Error occurred verifying the length of the GTP-U payload.
+    GtpuPayload,
+    /// Error occurred while decoding a Geneve header or Geneve option.
+    GeneveHeader,
+    /// Error occurred while decoding a DHCPv4 (BOOTP) message.
+    Dhcpv4Message,
     /// Error occurred while parsing an ICMP packet.
     Icmpv4,
     /// Error occurred while parsing an ICMP timestamp packet.
@@ -43,6 +82,15 @@ pub enum Layer {
     Icmpv4TimestampReply,
     /// Error occurred while parsing an ICMPv6 packet.
     Icmpv6,
+    /// Error occurred while parsing an IGMP packet.
+    Igmp,
+    /// Error occurred while decoding the global header of a pcap file.
+    PcapHeader,
+    /// Error occurred while decoding a record (packet) in a pcap file.
+    PcapRecord,
+    /// Error occurred while decoding a length prefixed frame (see
+    /// [`crate::LengthPrefixedPackets`]).
+    LengthPrefixedFrame,
 }
 
 impl Layer {
@@ -52,11 +100,21 @@ impl Layer {
         match self {
             Ethernet2Header => "Ethernet 2 Header Error",
             EtherPayload => "Payload with Ether Type Error",
+            Ieee8023Header => "IEEE 802.3 Header Error",
+            Ieee8023Payload => "IEEE 802.3 Payload Error",
+            LinuxSllHeader => "Linux SLL Header Error",
+            LinuxSll2Header => "Linux SLL2 Header Error",
+            LlcHeader => "LLC Header Error",
+            SnapHeader => "SNAP Header Error",
             VlanHeader => "VLAN Header Error",
+            MplsLabelStack => "MPLS Label Stack Error",
+            PppoeHeader => "PPPoE Header Error",
+            Arp => "ARP Packet Error",
             IpHeader => "IP Header Error",
             Ipv4Header => "IPv4 Header Error",
             Ipv4Packet => "IPv4 Packet Error",
             IpAuthHeader => "IP Authentication Header Error",
+            EspHeader => "ESP Header Error",
             Ipv6Header => "IPv6 Header Error",
             Ipv6Packet => "IPv6 Packet Error",
             Ipv6ExtHeader => "IPv6 Extension Header Error",
@@ -64,13 +122,26 @@ impl Layer {
             Ipv6DestOptionsHeader => "IPv6 Destination Options Header Error",
             Ipv6RouteHeader => "IPv6 Routing Header Error",
             Ipv6FragHeader => "IPv6 Fragment Header Error",
+            Ipv6MobilityHeader => "IPv6 Mobility Header Error",
+            GreHeader => "GRE Header Error",
             UdpHeader => "UDP Header Error",
             UdpPayload => "UDP Payload Error",
             TcpHeader => "TCP Header Error",
+            SctpHeader => "SCTP Header Error",
+            DccpHeader => "DCCP Header Error",
+            VxlanHeader => "VXLAN Header Error",
+            GtpuHeader => "GTP-U Header Error",
+            GtpuPayload => "GTP-U Payload Error",
+            GeneveHeader => "Geneve Header Error",
+            Dhcpv4Message => "DHCPv4 Message Error",
             Icmpv4 => "ICMP Packet Error",
             Icmpv4Timestamp => "ICMP Timestamp Error",
             Icmpv4TimestampReply => "ICMP Timestamp Reply Error",
             Icmpv6 => "ICMPv6 Packet Error",
+            Igmp => "IGMP Packet Error",
+            PcapHeader => "Pcap Header Error",
+            PcapRecord => "Pcap Record Error",
+            LengthPrefixedFrame => "Length Prefixed Frame Error",
         }
     }
 }
@@ -81,11 +152,21 @@ impl core::fmt::Display for Layer {
         match self {
             Ethernet2Header => write!(f, "Ethernet 2 header"),
             EtherPayload => write!(f, "Ether type payload"),
+            Ieee8023Header => write!(f, "IEEE 802.3 header"),
+            Ieee8023Payload => write!(f, "IEEE 802.3 payload"),
+            LinuxSllHeader => write!(f, "Linux SLL header"),
+            LinuxSll2Header => write!(f, "Linux SLL2 header"),
+            LlcHeader => write!(f, "LLC header"),
+            SnapHeader => write!(f, "SNAP header"),
             VlanHeader => write!(f, "VLAN header"),
+            MplsLabelStack => write!(f, "MPLS label stack"),
+            PppoeHeader => write!(f, "PPPoE header"),
+            Arp => write!(f, "ARP packet"),
             IpHeader => write!(f, "IP header"),
             Ipv4Header => write!(f, "IPv4 header"),
             Ipv4Packet => write!(f, "IPv4 packet"),
             IpAuthHeader => write!(f, "IP Authentication header"),
+            EspHeader => write!(f, "ESP header"),
             Ipv6Header => write!(f, "IPv6 header"),
             Ipv6Packet => write!(f, "IPv6 packet"),
             Ipv6ExtHeader => write!(f, "IPv6 extension header"),
@@ -93,13 +174,26 @@ impl core::fmt::Display for Layer {
             Ipv6DestOptionsHeader => write!(f, "IPv6 destination options header"),
             Ipv6RouteHeader => write!(f, "IPv6 routing header"),
             Ipv6FragHeader => write!(f, "IPv6 fragment header"),
+            Ipv6MobilityHeader => write!(f, "IPv6 mobility header"),
+            GreHeader => write!(f, "GRE header"),
             UdpHeader => write!(f, "UDP header"),
             UdpPayload => write!(f, "UDP payload"),
             TcpHeader => write!(f, "TCP header"),
+            SctpHeader => write!(f, "SCTP header"),
+            DccpHeader => write!(f, "DCCP header"),
+            VxlanHeader => write!(f, "VXLAN header"),
+            GtpuHeader => write!(f, "GTP-U header"),
+            GtpuPayload => write!(f, "GTP-U payload"),
+            GeneveHeader => write!(f, "Geneve header"),
+            Dhcpv4Message => write!(f, "DHCPv4 message"),
             Icmpv4 => write!(f, "ICMP packet"),
             Icmpv4Timestamp => write!(f, "ICMP timestamp message"),
             Icmpv4TimestampReply => write!(f, "ICMP timestamp reply message"),
             Icmpv6 => write!(f, "ICMPv6 packet"),
+            Igmp => write!(f, "IGMP packet"),
+            PcapHeader => write!(f, "pcap header"),
+            PcapRecord => write!(f, "pcap record"),
+            LengthPrefixedFrame => write!(f, "length prefixed frame"),
         }
     }
 }
@@ -142,11 +236,21 @@ mod test {
     fn error_title() {
         let tests = [
             (Ethernet2Header, "Ethernet 2 Header Error"),
+            (Ieee8023Header, "IEEE 802.3 Header Error"),
+            (Ieee8023Payload, "IEEE 802.3 Payload Error"),
+            (LinuxSllHeader, "Linux SLL Header Error"),
+            (LinuxSll2Header, "Linux SLL2 Header Error"),
+            (LlcHeader, "LLC Header Error"),
+            (SnapHeader, "SNAP Header Error"),
             (VlanHeader, "VLAN Header Error"),
+            (MplsLabelStack, "MPLS Label Stack Error"),
+            (PppoeHeader, "PPPoE Header Error"),
+            (Arp, "ARP Packet Error"),
             (IpHeader, "IP Header Error"),
             (Ipv4Header, "IPv4 Header Error"),
             (Ipv4Packet, "IPv4 Packet Error"),
             (IpAuthHeader, "IP Authentication Header Error"),
+            (EspHeader, "ESP Header Error"),
             (Ipv6Header, "IPv6 Header Error"),
             (Ipv6Packet, "IPv6 Packet Error"),
             (Ipv6ExtHeader, "IPv6 Extension Header Error"),
@@ -157,13 +261,26 @@ mod test {
             ),
             (Ipv6RouteHeader, "IPv6 Routing Header Error"),
             (Ipv6FragHeader, "IPv6 Fragment Header Error"),
+            (Ipv6MobilityHeader, "IPv6 Mobility Header Error"),
+            (GreHeader, "GRE Header Error"),
             (UdpHeader, "UDP Header Error"),
             (UdpPayload, "UDP Payload Error"),
             (TcpHeader, "TCP Header Error"),
+            (SctpHeader, "SCTP Header Error"),
+            (DccpHeader, "DCCP Header Error"),
+            (VxlanHeader, "VXLAN Header Error"),
+            (GtpuHeader, "GTP-U Header Error"),
+            (GtpuPayload, "GTP-U Payload Error"),
+            (GeneveHeader, "Geneve Header Error"),
+            (Dhcpv4Message, "DHCPv4 Message Error"),
             (Icmpv4, "ICMP Packet Error"),
             (Icmpv4Timestamp, "ICMP Timestamp Error"),
             (Icmpv4TimestampReply, "ICMP Timestamp Reply Error"),
             (Icmpv6, "ICMPv6 Packet Error"),
+            (Igmp, "IGMP Packet Error"),
+            (PcapHeader, "Pcap Header Error"),
+            (PcapRecord, "Pcap Record Error"),
+            (LengthPrefixedFrame, "Length Prefixed Frame Error"),
         ];
         for test in tests {
             assert_eq!(test.0.error_title(), test.1);
@@ -174,11 +291,21 @@ mod test {
     fn fmt() {
         let tests = [
             (Ethernet2Header, "Ethernet 2 header"),
+            (Ieee8023Header, "IEEE 802.3 header"),
+            (Ieee8023Payload, "IEEE 802.3 payload"),
+            (LinuxSllHeader, "Linux SLL header"),
+            (LinuxSll2Header, "Linux SLL2 header"),
+            (LlcHeader, "LLC header"),
+            (SnapHeader, "SNAP header"),
             (VlanHeader, "VLAN header"),
+            (MplsLabelStack, "MPLS label stack"),
+            (PppoeHeader, "PPPoE header"),
+            (Arp, "ARP packet"),
             (IpHeader, "IP header"),
             (Ipv4Header, "IPv4 header"),
             (Ipv4Packet, "IPv4 packet"),
             (IpAuthHeader, "IP Authentication header"),
+            (EspHeader, "ESP header"),
             (Ipv6Header, "IPv6 header"),
             (Ipv6Packet, "IPv6 packet"),
             (Ipv6ExtHeader, "IPv6 extension header"),
@@ -186,13 +313,26 @@ mod test {
             (Ipv6DestOptionsHeader, "IPv6 destination options header"),
             (Ipv6RouteHeader, "IPv6 routing header"),
             (Ipv6FragHeader, "IPv6 fragment header"),
+            (Ipv6MobilityHeader, "IPv6 mobility header"),
+            (GreHeader, "GRE header"),
             (UdpHeader, "UDP header"),
             (UdpPayload, "UDP payload"),
             (TcpHeader, "TCP header"),
+            (SctpHeader, "SCTP header"),
+            (DccpHeader, "DCCP header"),
+            (VxlanHeader, "VXLAN header"),
+            (GtpuHeader, "GTP-U header"),
+            (GtpuPayload, "GTP-U payload"),
+            (GeneveHeader, "Geneve header"),
+            (Dhcpv4Message, "DHCPv4 message"),
             (Icmpv4, "ICMP packet"),
             (Icmpv4Timestamp, "ICMP timestamp message"),
             (Icmpv4TimestampReply, "ICMP timestamp reply message"),
             (Icmpv6, "ICMPv6 packet"),
+            (Igmp, "IGMP packet"),
+            (PcapHeader, "pcap header"),
+            (PcapRecord, "pcap record"),
+            (LengthPrefixedFrame, "length prefixed frame"),
         ];
         for test in tests {
             assert_eq!(format!("{}", test.0), test.1);
@@ -38,6 +38,7 @@ use crate::{err::Layer, LenSource};
 /// };
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct LenError {
     /// Expected minimum or maximum length conflicting with the
     /// `len` value.
@@ -82,8 +83,15 @@ impl core::fmt::Display for LenError {
                 Ipv6HeaderPayloadLen => {
                     "length calculated from the IPv6 header 'payload length' field"
                 }
+                Ipv6JumboPayloadLen => {
+                    "length calculated from the IPv6 'jumbo payload' hop by hop option"
+                }
+                Ipv6PayloadLenOverride => "IPv6 payload length passed by the caller",
                 UdpHeaderLen => "length calculated from the UDP header 'length' field",
                 TcpHeaderLen => "length calculated from the TCP header 'length' field",
+                GtpuHeaderLen => "length calculated from the GTP-U 'length' field",
+                GeneveHeaderOptLen => "length calculated from the Geneve 'Opt Len' field",
+                Ieee8023Len => "length calculated from the IEEE 802.3 'length' field",
             }
         };
 
@@ -229,8 +237,13 @@ mod test {
                 (Slice, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the slice length."),
                 (Ipv4HeaderTotalLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the IPv4 header 'total length' field."),
                 (Ipv6HeaderPayloadLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the IPv6 header 'payload length' field."),
+                (Ipv6JumboPayloadLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the IPv6 'jumbo payload' hop by hop option."),
+                (Ipv6PayloadLenOverride, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the IPv6 payload length passed by the caller."),
                 (UdpHeaderLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the UDP header 'length' field."),
                 (TcpHeaderLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the TCP header 'length' field."),
+                (GtpuHeaderLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the GTP-U 'length' field."),
+                (GeneveHeaderOptLen, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the Geneve 'Opt Len' field."),
+                (Ieee8023Len, "IPv4 Header Error: Not enough data to decode 'IPv4 header'. 2 byte(s) would be required, but only 1 byte(s) are available based on the length calculated from the IEEE 802.3 'length' field."),
             ];
 
             for test in len_source_tests {
@@ -272,8 +285,13 @@ mod test {
                 (Slice, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The slice length was used to determine the length."),
                 (Ipv4HeaderTotalLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the IPv4 header 'total length' field was used to determine the length."),
                 (Ipv6HeaderPayloadLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the IPv6 header 'payload length' field was used to determine the length."),
+                (Ipv6JumboPayloadLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the IPv6 'jumbo payload' hop by hop option was used to determine the length."),
+                (Ipv6PayloadLenOverride, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The IPv6 payload length passed by the caller was used to determine the length."),
                 (UdpHeaderLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the UDP header 'length' field was used to determine the length."),
                 (TcpHeaderLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the TCP header 'length' field was used to determine the length."),
+                (GtpuHeaderLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the GTP-U 'length' field was used to determine the length."),
+                (GeneveHeaderOptLen, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the Geneve 'Opt Len' field was used to determine the length."),
+                (Ieee8023Len, "IPv4 Header Error: Length of 2 byte(s) is too big for an 'IPv4 header' (maximum is 1 bytes). The length calculated from the IEEE 802.3 'length' field was used to determine the length."),
             ];
 
             for test in len_source_tests {
@@ -1,3 +1,6 @@
+pub mod arp;
+pub mod dccp;
+pub mod dhcpv4;
 pub mod double_vlan;
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -10,11 +13,16 @@ pub mod ipv4_exts;
 pub mod ipv6;
 pub mod ipv6_exts;
 pub mod packet;
+pub mod pcap;
+pub mod reassembly;
 pub mod tcp;
 
 mod value_type;
 pub use value_type::*;
 
+mod fcs_error;
+pub use fcs_error::*;
+
 mod from_slice_error;
 pub use from_slice_error::*;
 
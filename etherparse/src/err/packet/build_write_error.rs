@@ -1,5 +1,5 @@
 #[cfg(feature = "std")]
-use crate::err::{ipv4_exts, ipv6_exts, ValueTooBigError};
+use crate::err::{ipv4_exts, ipv6_exts, SliceWriteSpaceError, ValueTooBigError};
 
 /// Error while writing packet
 #[cfg(feature = "std")]
@@ -25,6 +25,46 @@ pub enum BuildWriteError {
     /// Error if ICMPv6 is packaged in an IPv4 packet (it is undefined
     /// how to calculate the checksum).
     Icmpv6InIpv4,
+
+    /// Error if IPv6 extension headers were added to a packet
+    /// with an IPv4 header.
+    Ipv6ExtsInIpv4,
+
+    /// Error if IPv4 options were added to a packet with an IPv6 header.
+    Ipv4OptionsInIpv6,
+
+    /// Error if [`crate::ChecksumMode::Zero`] was selected for an UDP
+    /// header in an IPv6 packet (IPv6 requires an UDP checksum).
+    ZeroUdpChecksumInIpv6,
+
+    /// Error if the slice passed to `write_to_slice` is too small
+    /// to contain the complete packet.
+    Space(SliceWriteSpaceError),
+
+    /// Error if the MTU passed to `write_fragmented` or `to_fragments`
+    /// is too small to contain the headers of a fragment & at least
+    /// 8 bytes of payload.
+    MtuTooSmall {
+        /// MTU that was passed to `write_fragmented` or `to_fragments`.
+        mtu: usize,
+        /// Minimum MTU required to fragment the packet.
+        min_mtu: usize,
+    },
+
+    /// Error if an IPv4 packet does not fit into the MTU passed to
+    /// `write_fragmented` or `to_fragments` but the "don't fragment"
+    /// flag is set in the IPv4 header.
+    DontFragment {
+        /// MTU that was passed to `write_fragmented` or `to_fragments`.
+        mtu: usize,
+        /// Length of the unfragmented IP packet.
+        packet_len: usize,
+    },
+
+    /// Error if `write_fragmented` or `to_fragments` is called on an IPv6
+    /// packet that already contains a fragment header (added via
+    /// [`crate::PacketBuilderStep<crate::IpHeaders>::fragment`]).
+    FragmentHeaderPresent,
 }
 
 #[cfg(feature = "std")]
@@ -70,6 +110,45 @@ impl BuildWriteError {
     pub fn is_icmpv6_in_ipv4(&self) -> bool {
         matches!(self, BuildWriteError::Icmpv6InIpv4)
     }
+
+    /// Returns true if the `BuildWriteError` is a `Ipv6ExtsInIpv4`.
+    pub fn is_ipv6_exts_in_ipv4(&self) -> bool {
+        matches!(self, BuildWriteError::Ipv6ExtsInIpv4)
+    }
+
+    /// Returns true if the `BuildWriteError` is a `Ipv4OptionsInIpv6`.
+    pub fn is_ipv4_options_in_ipv6(&self) -> bool {
+        matches!(self, BuildWriteError::Ipv4OptionsInIpv6)
+    }
+
+    /// Returns true if the `BuildWriteError` is a `ZeroUdpChecksumInIpv6`.
+    pub fn is_zero_udp_checksum_in_ipv6(&self) -> bool {
+        matches!(self, BuildWriteError::ZeroUdpChecksumInIpv6)
+    }
+
+    /// Returns the [`crate::err::SliceWriteSpaceError`] value if the
+    /// `BuildWriteError` is a `Space`. Otherwise `None` is returned.
+    pub fn space(&self) -> Option<&SliceWriteSpaceError> {
+        match self {
+            BuildWriteError::Space(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the `BuildWriteError` is a `MtuTooSmall`.
+    pub fn is_mtu_too_small(&self) -> bool {
+        matches!(self, BuildWriteError::MtuTooSmall { .. })
+    }
+
+    /// Returns true if the `BuildWriteError` is a `DontFragment`.
+    pub fn is_dont_fragment(&self) -> bool {
+        matches!(self, BuildWriteError::DontFragment { .. })
+    }
+
+    /// Returns true if the `BuildWriteError` is a `FragmentHeaderPresent`.
+    pub fn is_fragment_header_present(&self) -> bool {
+        matches!(self, BuildWriteError::FragmentHeaderPresent)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -83,6 +162,13 @@ impl core::fmt::Display for BuildWriteError {
             Ipv4Exts(err) => err.fmt(f),
             Ipv6Exts(err) => err.fmt(f),
             Icmpv6InIpv4 => write!(f, "Error: ICMPv6 can not be combined with an IPv4 headers (checksum can not be calculated)."),
+            Ipv6ExtsInIpv4 => write!(f, "Error: IPv6 extension headers can not be combined with an IPv4 header."),
+            Ipv4OptionsInIpv6 => write!(f, "Error: IPv4 options can not be combined with an IPv6 header."),
+            ZeroUdpChecksumInIpv6 => write!(f, "Error: UDP checksum can not be set to zero in an IPv6 packet (IPv6 requires an UDP checksum)."),
+            Space(err) => err.fmt(f),
+            MtuTooSmall { mtu, min_mtu } => write!(f, "Error: MTU of {} bytes is too small to fragment the packet (at least {} bytes are required).", mtu, min_mtu),
+            DontFragment { mtu, packet_len } => write!(f, "Error: IPv4 packet of {} bytes does not fit into the MTU of {} bytes and can not be fragmented as the 'don't fragment' flag is set.", packet_len, mtu),
+            FragmentHeaderPresent => write!(f, "Error: IPv6 packet can not be fragmented as it already contains a fragment header."),
         }
     }
 }
@@ -98,6 +184,13 @@ impl std::error::Error for BuildWriteError {
             Ipv4Exts(err) => Some(err),
             Ipv6Exts(err) => Some(err),
             Icmpv6InIpv4 => None,
+            Ipv6ExtsInIpv4 => None,
+            Ipv4OptionsInIpv6 => None,
+            ZeroUdpChecksumInIpv6 => None,
+            Space(err) => Some(err),
+            MtuTooSmall { .. } => None,
+            DontFragment { .. } => None,
+            FragmentHeaderPresent => None,
         }
     }
 }
@@ -105,7 +198,10 @@ impl std::error::Error for BuildWriteError {
 #[cfg(test)]
 mod tests {
     use super::{BuildWriteError::*, *};
-    use crate::{err::ValueType, *};
+    use crate::{
+        err::{Layer, ValueType},
+        *,
+    };
     use alloc::format;
     use std::error::Error;
 
@@ -187,6 +283,64 @@ mod tests {
         assert!(Icmpv6InIpv4.is_icmpv6_in_ipv4());
     }
 
+    #[test]
+    fn is_ipv6_exts_in_ipv4() {
+        assert_eq!(false, Icmpv6InIpv4.is_ipv6_exts_in_ipv4());
+        assert!(Ipv6ExtsInIpv4.is_ipv6_exts_in_ipv4());
+    }
+
+    #[test]
+    fn is_ipv4_options_in_ipv6() {
+        assert_eq!(false, Ipv6ExtsInIpv4.is_ipv4_options_in_ipv6());
+        assert!(Ipv4OptionsInIpv6.is_ipv4_options_in_ipv6());
+    }
+
+    #[test]
+    fn is_zero_udp_checksum_in_ipv6() {
+        assert_eq!(false, Ipv4OptionsInIpv6.is_zero_udp_checksum_in_ipv6());
+        assert!(ZeroUdpChecksumInIpv6.is_zero_udp_checksum_in_ipv6());
+    }
+
+    #[test]
+    fn is_mtu_too_small() {
+        assert_eq!(false, Icmpv6InIpv4.is_mtu_too_small());
+        assert!(MtuTooSmall {
+            mtu: 10,
+            min_mtu: 56
+        }
+        .is_mtu_too_small());
+    }
+
+    #[test]
+    fn is_dont_fragment() {
+        assert_eq!(false, Icmpv6InIpv4.is_dont_fragment());
+        assert!(DontFragment {
+            mtu: 1500,
+            packet_len: 3000
+        }
+        .is_dont_fragment());
+    }
+
+    #[test]
+    fn is_fragment_header_present() {
+        assert_eq!(false, Icmpv6InIpv4.is_fragment_header_present());
+        assert!(FragmentHeaderPresent.is_fragment_header_present());
+    }
+
+    #[test]
+    fn space() {
+        assert!(Icmpv6InIpv4.space().is_none());
+        {
+            let err = SliceWriteSpaceError {
+                required_len: 2,
+                len: 1,
+                layer: Layer::Ethernet2Header,
+                layer_start_offset: 0,
+            };
+            assert_eq!(Some(&err), Space(err.clone()).space());
+        }
+    }
+
     #[test]
     fn debug() {
         let err = ipv4_exts::ExtsWalkError::ExtNotReferenced {
@@ -231,6 +385,51 @@ mod tests {
             "Error: ICMPv6 can not be combined with an IPv4 headers (checksum can not be calculated).",
             format!("{}", Icmpv6InIpv4)
         );
+        assert_eq!(
+            "Error: IPv6 extension headers can not be combined with an IPv4 header.",
+            format!("{}", Ipv6ExtsInIpv4)
+        );
+        assert_eq!(
+            "Error: IPv4 options can not be combined with an IPv6 header.",
+            format!("{}", Ipv4OptionsInIpv6)
+        );
+        assert_eq!(
+            "Error: UDP checksum can not be set to zero in an IPv6 packet (IPv6 requires an UDP checksum).",
+            format!("{}", ZeroUdpChecksumInIpv6)
+        );
+        {
+            let err = SliceWriteSpaceError {
+                required_len: 2,
+                len: 1,
+                layer: Layer::Ethernet2Header,
+                layer_start_offset: 0,
+            };
+            assert_eq!(format!("{}", err), format!("{}", Space(err.clone())));
+        }
+        assert_eq!(
+            "Error: MTU of 10 bytes is too small to fragment the packet (at least 56 bytes are required).",
+            format!(
+                "{}",
+                MtuTooSmall {
+                    mtu: 10,
+                    min_mtu: 56
+                }
+            )
+        );
+        assert_eq!(
+            "Error: IPv4 packet of 3000 bytes does not fit into the MTU of 1500 bytes and can not be fragmented as the 'don't fragment' flag is set.",
+            format!(
+                "{}",
+                DontFragment {
+                    mtu: 1500,
+                    packet_len: 3000
+                }
+            )
+        );
+        assert_eq!(
+            "Error: IPv6 packet can not be fragmented as it already contains a fragment header.",
+            format!("{}", FragmentHeaderPresent)
+        );
     }
 
     #[cfg(feature = "std")]
@@ -260,5 +459,29 @@ mod tests {
         .source()
         .is_some());
         assert!(Icmpv6InIpv4.source().is_none());
+        assert!(Ipv6ExtsInIpv4.source().is_none());
+        assert!(Ipv4OptionsInIpv6.source().is_none());
+        assert!(ZeroUdpChecksumInIpv6.source().is_none());
+        assert!(MtuTooSmall {
+            mtu: 10,
+            min_mtu: 56
+        }
+        .source()
+        .is_none());
+        assert!(DontFragment {
+            mtu: 1500,
+            packet_len: 3000
+        }
+        .source()
+        .is_none());
+        assert!(FragmentHeaderPresent.source().is_none());
+        assert!(Space(SliceWriteSpaceError {
+            required_len: 2,
+            len: 1,
+            layer: Layer::Ethernet2Header,
+            layer_start_offset: 0,
+        })
+        .source()
+        .is_some());
     }
 }
@@ -3,6 +3,7 @@ use crate::*;
 /// Error when slicing an packet from downwards (both
 /// starting from ethernet or ip layer downwards).
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum SliceError {
     /// Length related errors (e.g. not enough data in slice).
     Len(err::LenError),
@@ -18,6 +19,11 @@ pub enum SliceError {
     Ipv6Exts(err::ipv6_exts::HeaderError),
     /// Error when decoding a TCP header.
     Tcp(err::tcp::HeaderError),
+    /// Error when decoding a DCCP header.
+    Dccp(err::dccp::HeaderError),
+    /// Error when the Ethernet FCS does not match the frame (only
+    /// returned if enabled via [`crate::ParseOptions::validate_fcs`]).
+    Fcs(err::FcsError),
 }
 
 impl core::fmt::Display for SliceError {
@@ -32,6 +38,8 @@ impl core::fmt::Display for SliceError {
             Ipv4Exts(err) => err.fmt(f),
             Ipv6Exts(err) => err.fmt(f),
             Tcp(err) => err.fmt(f),
+            Dccp(err) => err.fmt(f),
+            Fcs(err) => err.fmt(f),
         }
     }
 }
@@ -49,6 +57,8 @@ impl std::error::Error for SliceError {
             Ipv4Exts(err) => Some(err),
             Ipv6Exts(err) => Some(err),
             Tcp(err) => Some(err),
+            Dccp(err) => Some(err),
+            Fcs(err) => Some(err),
         }
     }
 }
@@ -142,6 +152,24 @@ mod tests {
             let err = err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
             assert_eq!(format!("{}", err), format!("{}", Tcp(err)));
         }
+
+        // DccpHeader
+        {
+            let err = err::dccp::HeaderError::DataOffsetTooSmall {
+                data_offset: 1,
+                extended_sequence_number: false,
+            };
+            assert_eq!(format!("{}", err), format!("{}", Dccp(err)));
+        }
+
+        // Fcs
+        {
+            let err = err::FcsError {
+                received: 1,
+                calculated: 2,
+            };
+            assert_eq!(format!("{}", err), format!("{}", Fcs(err)));
+        }
     }
 
     #[cfg(feature = "std")]
@@ -194,5 +222,23 @@ mod tests {
             let err = err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
             assert!(Tcp(err).source().is_some());
         }
+
+        // DccpHeader
+        {
+            let err = err::dccp::HeaderError::DataOffsetTooSmall {
+                data_offset: 1,
+                extended_sequence_number: false,
+            };
+            assert!(Dccp(err).source().is_some());
+        }
+
+        // Fcs
+        {
+            let err = err::FcsError {
+                received: 1,
+                calculated: 2,
+            };
+            assert!(Fcs(err).source().is_some());
+        }
     }
 }
@@ -2,6 +2,7 @@ use crate::err::ValueTooBigError;
 
 /// Error while calculating the checksum in a transport header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum TransportChecksumError {
     /// Error if the length of the payload is too
     /// big to be representable by the length fields.
@@ -0,0 +1,78 @@
+/// Error when decoding the global header of a pcap file.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum HeaderError {
+    /// Error when the magic number at the start of the file is not one of
+    /// the known classic pcap magic numbers (e.g. pcapng files).
+    UnknownMagicNumber {
+        /// Magic number (as read in big endian) at the start of the file.
+        magic_number: u32,
+    },
+}
+
+impl core::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use HeaderError::*;
+        match self {
+            UnknownMagicNumber { magic_number } => write!(f, "Pcap Header Error: Encountered unknown magic number '{:#010x}' at the start of the pcap file (only classic pcap files with the magic numbers '0xa1b2c3d4' & '0xa1b23c4d' are supported).", magic_number),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "UnknownMagicNumber { magic_number: 1 }",
+            format!("{:?}", UnknownMagicNumber { magic_number: 1 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = UnknownMagicNumber { magic_number: 1 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "Pcap Header Error: Encountered unknown magic number '0x0a0d0d0a' at the start of the pcap file (only classic pcap files with the magic numbers '0xa1b2c3d4' & '0xa1b23c4d' are supported).",
+            format!("{}", UnknownMagicNumber { magic_number: 0x0a0d0d0a })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(UnknownMagicNumber { magic_number: 1 }.source().is_none());
+    }
+}
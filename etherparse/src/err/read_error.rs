@@ -33,6 +33,12 @@ pub enum ReadError {
 
     /// Error while parsing a TCP extension header.
     Tcp(tcp::HeaderError),
+
+    /// Error while parsing a DCCP header.
+    Dccp(dccp::HeaderError),
+
+    /// Error when the Ethernet FCS does not match the frame.
+    Fcs(FcsError),
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -91,6 +97,18 @@ impl ReadError {
             _ => None,
         }
     }
+    pub fn dccp(&self) -> Option<&dccp::HeaderError> {
+        match self {
+            ReadError::Dccp(err) => Some(err),
+            _ => None,
+        }
+    }
+    pub fn fcs(&self) -> Option<&FcsError> {
+        match self {
+            ReadError::Fcs(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 impl core::fmt::Display for ReadError {
@@ -106,6 +124,8 @@ impl core::fmt::Display for ReadError {
             Ipv6(err) => err.fmt(f),
             Ipv6Exts(err) => err.fmt(f),
             Tcp(err) => err.fmt(f),
+            Dccp(err) => err.fmt(f),
+            Fcs(err) => err.fmt(f),
         }
     }
 }
@@ -124,6 +144,8 @@ impl std::error::Error for ReadError {
             ReadError::Ipv6(err) => Some(err),
             ReadError::Ipv6Exts(err) => Some(err),
             ReadError::Tcp(err) => Some(err),
+            ReadError::Dccp(err) => Some(err),
+            ReadError::Fcs(err) => Some(err),
         }
     }
 }
@@ -383,6 +405,8 @@ impl From<packet::SliceError> for ReadError {
             Ipv4Exts(err) => ReadError::IpAuth(err),
             Ipv6Exts(err) => ReadError::Ipv6Exts(err),
             Tcp(err) => ReadError::Tcp(err),
+            Dccp(err) => ReadError::Dccp(err),
+            Fcs(err) => ReadError::Fcs(err),
         }
     }
 }
@@ -417,6 +441,122 @@ impl From<tcp::HeaderSliceError> for ReadError {
     }
 }
 
+// dccp error conversions
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<dccp::HeaderError> for ReadError {
+    fn from(value: dccp::HeaderError) -> Self {
+        ReadError::Dccp(value)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<dccp::HeaderSliceError> for ReadError {
+    fn from(value: dccp::HeaderSliceError) -> Self {
+        use dccp::HeaderSliceError::*;
+        match value {
+            Len(err) => ReadError::Len(err),
+            Content(err) => ReadError::Dccp(err),
+        }
+    }
+}
+
+// fcs & "from slice" error conversions
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<FcsError> for ReadError {
+    fn from(value: FcsError) -> Self {
+        ReadError::Fcs(value)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<FromSliceError> for ReadError {
+    fn from(value: FromSliceError) -> Self {
+        use FromSliceError::*;
+        match value {
+            Len(err) => ReadError::Len(err),
+            DoubleVlan(err) => ReadError::DoubleVlan(err),
+            Ip(err) => ReadError::Ip(err),
+            IpAuth(err) => ReadError::IpAuth(err),
+            Ipv4(err) => ReadError::Ipv4(err),
+            Ipv6(err) => ReadError::Ipv6(err),
+            Ipv6Exts(err) => ReadError::Ipv6Exts(err),
+            Tcp(err) => ReadError::Tcp(err),
+            Dccp(err) => ReadError::Dccp(err),
+            Fcs(err) => ReadError::Fcs(err),
+        }
+    }
+}
+
+// std::io::Error conversions
+
+/// Converts the error into an [`std::io::Error`].
+///
+/// [`ReadError::Io`] is returned as is. Length errors are mapped to
+/// [`std::io::ErrorKind::UnexpectedEof`] and all other errors to
+/// [`std::io::ErrorKind::InvalidData`]. The [`ReadError`] is kept as the
+/// inner error (accessible via [`std::io::Error::get_ref`]) so the message
+/// of the original error is preserved.
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl From<ReadError> for std::io::Error {
+    fn from(value: ReadError) -> Self {
+        use std::io::ErrorKind;
+        match value {
+            ReadError::Io(err) => err,
+            ReadError::Len(_) => std::io::Error::new(ErrorKind::UnexpectedEof, value),
+            _ => std::io::Error::new(ErrorKind::InvalidData, value),
+        }
+    }
+}
+
+/// Implements the conversion into [`std::io::Error`] for error types
+/// that can be converted into [`ReadError`].
+macro_rules! impl_from_for_io_error {
+    ($($t:ty),* $(,)?) => {
+        $(
+            #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+            impl From<$t> for std::io::Error {
+                fn from(value: $t) -> Self {
+                    ReadError::from(value).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_io_error!(
+    LenError,
+    FcsError,
+    FromSliceError,
+    double_vlan::HeaderError,
+    double_vlan::HeaderReadError,
+    double_vlan::HeaderSliceError,
+    ip::HeaderError,
+    ip::HeadersError,
+    ip::HeaderReadError,
+    ip::HeadersSliceError,
+    ip::SliceError,
+    ip_auth::HeaderError,
+    ip_auth::HeaderReadError,
+    ip_auth::HeaderSliceError,
+    ipv4::HeaderError,
+    ipv4::HeaderReadError,
+    ipv4::HeaderSliceError,
+    ipv4::SliceError,
+    ipv6::HeaderError,
+    ipv6::HeaderReadError,
+    ipv6::HeaderSliceError,
+    ipv6::SliceError,
+    ipv6_exts::HeaderError,
+    ipv6_exts::HeaderReadError,
+    ipv6_exts::HeaderSliceError,
+    packet::SliceError,
+    tcp::HeaderError,
+    tcp::HeaderReadError,
+    tcp::HeaderSliceError,
+    dccp::HeaderError,
+    dccp::HeaderSliceError,
+);
+
 #[cfg(test)]
 mod tests {
     use crate::EtherType;
@@ -429,7 +569,7 @@ mod tests {
 
     #[test]
     fn debug_source() {
-        let test_values: [(&str, ReadError); 8] = [
+        let test_values: [(&str, ReadError); 10] = [
             (
                 "Len",
                 Len(LenError {
@@ -469,6 +609,20 @@ mod tests {
                 "Tcp",
                 Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
             ),
+            (
+                "Dccp",
+                Dccp(dccp::HeaderError::DataOffsetTooSmall {
+                    data_offset: 1,
+                    extended_sequence_number: false,
+                }),
+            ),
+            (
+                "Fcs",
+                Fcs(FcsError {
+                    received: 1,
+                    calculated: 2,
+                }),
+            ),
         ];
         for (prefix, value) in &test_values {
             // display
@@ -489,7 +643,7 @@ mod tests {
 
     #[test]
     fn display_source() {
-        let test_values: [ReadError; 8] = [
+        let test_values: [ReadError; 10] = [
             Len(LenError {
                 required_len: 0,
                 len: 0,
@@ -508,6 +662,14 @@ mod tests {
             Ipv6(ipv6::HeaderError::UnexpectedVersion { version_number: 1 }),
             Ipv6Exts(ipv6_exts::HeaderError::HopByHopNotAtStart),
             Tcp(tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 }),
+            Dccp(dccp::HeaderError::DataOffsetTooSmall {
+                data_offset: 1,
+                extended_sequence_number: false,
+            }),
+            Fcs(FcsError {
+                received: 1,
+                calculated: 2,
+            }),
         ];
         for value in &test_values {
             // display
@@ -541,6 +703,10 @@ mod tests {
         let ip_auth_error = || ip_auth::HeaderError::ZeroPayloadLen;
         let ipv6_exts_error = || ipv6_exts::HeaderError::HopByHopNotAtStart;
         let tcp_error = || tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
+        let dccp_error = || dccp::HeaderError::DataOffsetTooSmall {
+            data_offset: 1,
+            extended_sequence_number: false,
+        };
 
         // io
         assert!(Io(io_error()).io().is_some());
@@ -583,6 +749,18 @@ mod tests {
         // tcp
         assert_eq!(Tcp(tcp_error()).tcp(), Some(&tcp_error()));
         assert_eq!(IpAuth(ip_auth_error()).tcp(), None);
+
+        // dccp
+        assert_eq!(Dccp(dccp_error()).dccp(), Some(&dccp_error()));
+        assert_eq!(IpAuth(ip_auth_error()).dccp(), None);
+
+        // fcs
+        let fcs_error = || FcsError {
+            received: 1,
+            calculated: 2,
+        };
+        assert_eq!(Fcs(fcs_error()).fcs(), Some(&fcs_error()));
+        assert_eq!(IpAuth(ip_auth_error()).fcs(), None);
     }
 
     #[test]
@@ -901,6 +1079,10 @@ mod tests {
             let ip_auth_error = || ip_auth::HeaderError::ZeroPayloadLen;
             let ipv6_exts_error = || ipv6_exts::HeaderError::HopByHopNotAtStart;
             let tcp_error = || tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
+            let dccp_error = || dccp::HeaderError::DataOffsetTooSmall {
+                data_offset: 1,
+                extended_sequence_number: false,
+            };
 
             // IpSliceError
             assert_eq!(
@@ -945,6 +1127,22 @@ mod tests {
                     .tcp()
                     .unwrap()
             );
+            assert_eq!(
+                &dccp_error(),
+                ReadError::from(packet::SliceError::Dccp(dccp_error()))
+                    .dccp()
+                    .unwrap()
+            );
+            let fcs_error = || FcsError {
+                received: 1,
+                calculated: 2,
+            };
+            assert_eq!(
+                &fcs_error(),
+                ReadError::from(packet::SliceError::Fcs(fcs_error()))
+                    .fcs()
+                    .unwrap()
+            );
         }
 
         // tcp errors
@@ -982,5 +1180,119 @@ mod tests {
                     .unwrap()
             );
         }
+
+        // dccp errors
+        {
+            let header_error = || dccp::HeaderError::DataOffsetTooSmall {
+                data_offset: 1,
+                extended_sequence_number: false,
+            };
+            assert_eq!(
+                &header_error(),
+                ReadError::from(header_error()).dccp().unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                ReadError::from(dccp::HeaderSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+            assert_eq!(
+                &header_error(),
+                ReadError::from(dccp::HeaderSliceError::Content(header_error()))
+                    .dccp()
+                    .unwrap()
+            );
+        }
+
+        // fcs & from slice errors
+        {
+            let fcs_error = || FcsError {
+                received: 1,
+                calculated: 2,
+            };
+            assert_eq!(&fcs_error(), ReadError::from(fcs_error()).fcs().unwrap());
+            assert_eq!(
+                &fcs_error(),
+                ReadError::from(FromSliceError::Fcs(fcs_error()))
+                    .fcs()
+                    .unwrap()
+            );
+            assert_eq!(
+                &len_error(),
+                ReadError::from(FromSliceError::Len(len_error()))
+                    .len()
+                    .unwrap()
+            );
+            let tcp_error = || tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
+            assert_eq!(
+                &tcp_error(),
+                ReadError::from(FromSliceError::Tcp(tcp_error()))
+                    .tcp()
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn into_io_error() {
+        use std::io::ErrorKind;
+
+        let len_error = || -> LenError {
+            LenError {
+                required_len: 2,
+                len: 1,
+                len_source: LenSource::Slice,
+                layer: Layer::Icmpv4,
+                layer_start_offset: 0,
+            }
+        };
+        let tcp_error = || tcp::HeaderError::DataOffsetTooSmall { data_offset: 1 };
+
+        // io errors are passed through
+        {
+            let err: std::io::Error =
+                ReadError::Io(std::io::Error::new(ErrorKind::Other, "some error")).into();
+            assert_eq!(ErrorKind::Other, err.kind());
+            assert_eq!("some error", format!("{}", err));
+        }
+
+        // len errors
+        {
+            let err: std::io::Error = len_error().into();
+            assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+            assert_eq!(format!("{}", len_error()), format!("{}", err));
+            assert!(err
+                .get_ref()
+                .unwrap()
+                .downcast_ref::<ReadError>()
+                .unwrap()
+                .len()
+                .is_some());
+
+            let err: std::io::Error = tcp::HeaderSliceError::Len(len_error()).into();
+            assert_eq!(ErrorKind::UnexpectedEof, err.kind());
+        }
+
+        // content errors
+        {
+            let err: std::io::Error = tcp_error().into();
+            assert_eq!(ErrorKind::InvalidData, err.kind());
+            assert_eq!(format!("{}", tcp_error()), format!("{}", err));
+
+            let err: std::io::Error = packet::SliceError::Tcp(tcp_error()).into();
+            assert_eq!(ErrorKind::InvalidData, err.kind());
+
+            let err: std::io::Error = FromSliceError::Tcp(tcp_error()).into();
+            assert_eq!(ErrorKind::InvalidData, err.kind());
+        }
+
+        // usable with the ? operator
+        {
+            fn parse(data: &[u8]) -> std::io::Result<crate::TcpHeader> {
+                Ok(crate::TcpHeader::from_slice(data)?.0)
+            }
+            assert_eq!(ErrorKind::UnexpectedEof, parse(&[]).unwrap_err().kind());
+        }
     }
 } // mod tests
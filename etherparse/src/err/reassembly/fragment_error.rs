@@ -0,0 +1,200 @@
+/// Error when a fragment can not be added to a reassembly buffer
+/// (e.g. [`crate::reassembly::Ipv4FragmentBuffer`] or
+/// [`crate::reassembly::Ipv6FragmentBuffer`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FragmentError {
+    /// Error when the payload of a fragment overlaps with the payload
+    /// of an already received fragment of the same packet.
+    ///
+    /// [RFC5722](https://datatracker.ietf.org/doc/html/rfc5722) requires
+    /// that packets containing overlapping fragments are silently discarded.
+    Overlapping {
+        /// Offset in bytes of the fragment payload that caused the error.
+        offset: usize,
+        /// Length in bytes of the fragment payload that caused the error.
+        len: usize,
+    },
+
+    /// Error when a fragment that is not the last fragment has a
+    /// payload length that is not a multiple of 8 octets.
+    UnalignedLen {
+        /// Offset in bytes of the fragment payload that caused the error.
+        offset: usize,
+        /// Length in bytes of the fragment payload that caused the error.
+        len: usize,
+    },
+
+    /// Error when the reassembled payload would exceed the configured
+    /// maximum payload length.
+    MaxLenExceeded {
+        /// Maximum allowed reassembled payload length in bytes.
+        max_len: usize,
+        /// End of the fragment payload that exceeded the maximum length.
+        required_len: usize,
+    },
+
+    /// Error when a fragment conflicts with the end of the packet
+    /// determined by an already received last fragment (or multiple
+    /// last fragments with different ends were received).
+    ConflictingEnd {
+        /// End in bytes of the packet as previously determined.
+        previous_end: usize,
+        /// End in bytes of the conflicting fragment payload.
+        end: usize,
+    },
+
+    /// Error when adding a fragment would exceed the configured maximum
+    /// number of bytes buffered over all incomplete packets.
+    MaxBufferedLenExceeded {
+        /// Maximum allowed number of buffered bytes.
+        max_len: usize,
+        /// Number of bytes that would have been buffered after adding
+        /// the fragment.
+        required_len: usize,
+    },
+}
+
+impl core::fmt::Display for FragmentError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use FragmentError::*;
+        match self {
+            Overlapping { offset, len } => write!(
+                f,
+                "Fragment Error: Fragment payload (offset {} bytes, length {} bytes) overlaps with the payload of a previously received fragment.",
+                offset, len
+            ),
+            UnalignedLen { offset, len } => write!(
+                f,
+                "Fragment Error: Fragment payload (offset {} bytes, length {} bytes) is not the last fragment but has a length that is not a multiple of 8.",
+                offset, len
+            ),
+            MaxLenExceeded { max_len, required_len } => write!(
+                f,
+                "Fragment Error: Reassembled payload would require a length of {} bytes, which exceeds the maximum allowed length of {} bytes.",
+                required_len, max_len
+            ),
+            ConflictingEnd { previous_end, end } => write!(
+                f,
+                "Fragment Error: Fragment payload end ({} bytes) conflicts with the previously determined end of the packet ({} bytes).",
+                end, previous_end
+            ),
+            MaxBufferedLenExceeded { max_len, required_len } => write!(
+                f,
+                "Fragment Error: Buffering the fragment would require {} bytes, which exceeds the maximum allowed buffered length of {} bytes.",
+                required_len, max_len
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for FragmentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FragmentError::*;
+    use alloc::format;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        error::Error,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            "Overlapping { offset: 8, len: 16 }",
+            format!("{:?}", Overlapping { offset: 8, len: 16 })
+        );
+    }
+
+    #[test]
+    fn clone_eq_hash() {
+        let err = Overlapping { offset: 8, len: 16 };
+        assert_eq!(err, err.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            err.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            err.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "Fragment Error: Fragment payload (offset 8 bytes, length 16 bytes) overlaps with the payload of a previously received fragment.",
+            format!("{}", Overlapping { offset: 8, len: 16 })
+        );
+        assert_eq!(
+            "Fragment Error: Fragment payload (offset 8 bytes, length 3 bytes) is not the last fragment but has a length that is not a multiple of 8.",
+            format!("{}", UnalignedLen { offset: 8, len: 3 })
+        );
+        assert_eq!(
+            "Fragment Error: Reassembled payload would require a length of 2000 bytes, which exceeds the maximum allowed length of 1500 bytes.",
+            format!(
+                "{}",
+                MaxLenExceeded {
+                    max_len: 1500,
+                    required_len: 2000
+                }
+            )
+        );
+        assert_eq!(
+            "Fragment Error: Fragment payload end (32 bytes) conflicts with the previously determined end of the packet (16 bytes).",
+            format!(
+                "{}",
+                ConflictingEnd {
+                    previous_end: 16,
+                    end: 32
+                }
+            )
+        );
+        assert_eq!(
+            "Fragment Error: Buffering the fragment would require 2000 bytes, which exceeds the maximum allowed buffered length of 1500 bytes.",
+            format!(
+                "{}",
+                MaxBufferedLenExceeded {
+                    max_len: 1500,
+                    required_len: 2000
+                }
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source() {
+        assert!(Overlapping { offset: 0, len: 0 }.source().is_none());
+        assert!(UnalignedLen { offset: 0, len: 0 }.source().is_none());
+        assert!(MaxLenExceeded {
+            max_len: 0,
+            required_len: 0
+        }
+        .source()
+        .is_none());
+        assert!(ConflictingEnd {
+            previous_end: 0,
+            end: 0
+        }
+        .source()
+        .is_none());
+        assert!(MaxBufferedLenExceeded {
+            max_len: 0,
+            required_len: 0
+        }
+        .source()
+        .is_none());
+    }
+}
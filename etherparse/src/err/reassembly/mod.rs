@@ -0,0 +1,2 @@
+mod fragment_error;
+pub use fragment_error::*;
@@ -3,6 +3,7 @@ use crate::err::Layer;
 /// Error when not enough space is available in a slice
 /// to write a packet or header to it.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct SliceWriteSpaceError {
     /// Expected minimum length conflicting with the
     /// `actual_len` value.
@@ -1,5 +1,6 @@
 /// Errors that can be encountered while decoding a TCP header.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum HeaderError {
     /// Error when the data_offset is so small that the data would
     /// start within the TCP header itself.
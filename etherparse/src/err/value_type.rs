@@ -1,6 +1,7 @@
 /// Types of values that have a limited allowed value range
 /// and can cause an [`crate::err::ValueTooBigError`].
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ValueType {
     /// VLAN identifier field present in a [`crate::SingleVlanHeader`].
     VlanId,
@@ -37,6 +38,20 @@ pub enum ValueType {
     TcpPayloadLengthIpv6,
     /// Variable length data of an ICMPv6 packet.
     Icmpv6PayloadLength,
+    /// VXLAN Network Identifier field present in a [`crate::VxlanHeader`].
+    VxlanVni,
+    /// Payload length in an IPv4 pseudo header (see
+    /// [`crate::Ipv4Header::pseudo_header_bytes`]).
+    PseudoHeaderPayloadLengthIpv4,
+    /// Payload length in an IPv6 pseudo header (see
+    /// [`crate::Ipv6Header::pseudo_header_bytes`]).
+    PseudoHeaderPayloadLengthIpv6,
+    /// Length of a DCCP packet (header & payload) used when calculating
+    /// the checksum of a [`crate::DccpSlice`] for IPv4.
+    DccpLengthIpv4,
+    /// Length of a DCCP packet (header & payload) used when calculating
+    /// the checksum of a [`crate::DccpSlice`] for IPv6.
+    DccpLengthIpv6,
 }
 
 impl core::fmt::Display for ValueType {
@@ -56,6 +71,11 @@ impl core::fmt::Display for ValueType {
             TcpPayloadLengthIpv4 => write!(f, "TCP Payload Length (in IPv4 checksum calculation)"),
             TcpPayloadLengthIpv6 => write!(f, "TCP Payload Length (in IPv6 checksum calculation)"),
             Icmpv6PayloadLength => write!(f, "ICMPv6 Payload Length"),
+            VxlanVni => write!(f, "VXLAN VNI (VXLAN Network Identifier)"),
+            PseudoHeaderPayloadLengthIpv4 => write!(f, "IPv4 Pseudo Header Payload Length"),
+            PseudoHeaderPayloadLengthIpv6 => write!(f, "IPv6 Pseudo Header Payload Length"),
+            DccpLengthIpv4 => write!(f, "DCCP Length (in IPv4 checksum calculation)"),
+            DccpLengthIpv6 => write!(f, "DCCP Length (in IPv6 checksum calculation)"),
         }
     }
 }
@@ -123,5 +143,25 @@ mod test {
             &format!("{}", TcpPayloadLengthIpv6)
         );
         assert_eq!("ICMPv6 Payload Length", &format!("{}", Icmpv6PayloadLength));
+        assert_eq!(
+            "VXLAN VNI (VXLAN Network Identifier)",
+            &format!("{}", VxlanVni)
+        );
+        assert_eq!(
+            "IPv4 Pseudo Header Payload Length",
+            &format!("{}", PseudoHeaderPayloadLengthIpv4)
+        );
+        assert_eq!(
+            "IPv6 Pseudo Header Payload Length",
+            &format!("{}", PseudoHeaderPayloadLengthIpv6)
+        );
+        assert_eq!(
+            "DCCP Length (in IPv4 checksum calculation)",
+            &format!("{}", DccpLengthIpv4)
+        );
+        assert_eq!(
+            "DCCP Length (in IPv6 checksum calculation)",
+            &format!("{}", DccpLengthIpv6)
+        );
     }
 }
@@ -0,0 +1,294 @@
+use crate::*;
+
+/// Addresses, ports & protocol identifying a TCP or UDP flow (the
+/// "5-tuple").
+///
+/// Can be extracted from a sliced packet via [`SlicedPacket::flow_identifier`].
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{FlowTuple, PacketBuilder, SlicedPacket, ip_number};
+///
+/// let request = {
+///     let builder = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///         .udp(1234, 53);
+///     let mut packet = Vec::<u8>::with_capacity(builder.size(0));
+///     builder.write(&mut packet, &[]).unwrap();
+///     packet
+/// };
+/// let response = {
+///     let builder = PacketBuilder::ipv4([192, 168, 1, 2], [192, 168, 1, 1], 20)
+///         .udp(53, 1234);
+///     let mut packet = Vec::<u8>::with_capacity(builder.size(0));
+///     builder.write(&mut packet, &[]).unwrap();
+///     packet
+/// };
+///
+/// let request_flow = SlicedPacket::from_ip(&request)
+///     .unwrap()
+///     .flow_identifier()
+///     .unwrap();
+/// assert_eq!(
+///     request_flow,
+///     FlowTuple::Ipv4 {
+///         source: [192, 168, 1, 1],
+///         destination: [192, 168, 1, 2],
+///         source_port: 1234,
+///         destination_port: 53,
+///         protocol: ip_number::UDP,
+///     }
+/// );
+///
+/// // both directions of a flow are identical after normalization
+/// let response_flow = SlicedPacket::from_ip(&response)
+///     .unwrap()
+///     .flow_identifier()
+///     .unwrap();
+/// assert_ne!(request_flow, response_flow);
+/// assert_eq!(request_flow.normalized(), response_flow.normalized());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum FlowTuple {
+    /// Flow transported via IPv4.
+    Ipv4 {
+        /// IPv4 source address.
+        source: [u8; 4],
+        /// IPv4 destination address.
+        destination: [u8; 4],
+        /// Source port of the transport layer.
+        source_port: u16,
+        /// Destination port of the transport layer.
+        destination_port: u16,
+        /// IP number of the transport layer protocol (e.g. [`ip_number::UDP`]).
+        protocol: IpNumber,
+    },
+    /// Flow transported via IPv6.
+    Ipv6 {
+        /// IPv6 source address.
+        source: [u8; 16],
+        /// IPv6 destination address.
+        destination: [u8; 16],
+        /// Source port of the transport layer.
+        source_port: u16,
+        /// Destination port of the transport layer.
+        destination_port: u16,
+        /// IP number of the transport layer protocol (e.g. [`ip_number::UDP`]).
+        protocol: IpNumber,
+    },
+}
+
+impl FlowTuple {
+    /// Source port of the transport layer.
+    #[inline]
+    pub fn source_port(&self) -> u16 {
+        match self {
+            FlowTuple::Ipv4 { source_port, .. } => *source_port,
+            FlowTuple::Ipv6 { source_port, .. } => *source_port,
+        }
+    }
+
+    /// Destination port of the transport layer.
+    #[inline]
+    pub fn destination_port(&self) -> u16 {
+        match self {
+            FlowTuple::Ipv4 {
+                destination_port, ..
+            } => *destination_port,
+            FlowTuple::Ipv6 {
+                destination_port, ..
+            } => *destination_port,
+        }
+    }
+
+    /// IP number of the transport layer protocol.
+    #[inline]
+    pub fn protocol(&self) -> IpNumber {
+        match self {
+            FlowTuple::Ipv4 { protocol, .. } => *protocol,
+            FlowTuple::Ipv6 { protocol, .. } => *protocol,
+        }
+    }
+
+    /// Source address as [`core::net::IpAddr`].
+    pub fn source_addr(&self) -> core::net::IpAddr {
+        match self {
+            FlowTuple::Ipv4 { source, .. } => core::net::Ipv4Addr::from(*source).into(),
+            FlowTuple::Ipv6 { source, .. } => core::net::Ipv6Addr::from(*source).into(),
+        }
+    }
+
+    /// Destination address as [`core::net::IpAddr`].
+    pub fn destination_addr(&self) -> core::net::IpAddr {
+        match self {
+            FlowTuple::Ipv4 { destination, .. } => core::net::Ipv4Addr::from(*destination).into(),
+            FlowTuple::Ipv6 { destination, .. } => core::net::Ipv6Addr::from(*destination).into(),
+        }
+    }
+
+    /// Returns the flow tuple with source & destination (address & port)
+    /// swapped.
+    pub fn reversed(&self) -> FlowTuple {
+        match *self {
+            FlowTuple::Ipv4 {
+                source,
+                destination,
+                source_port,
+                destination_port,
+                protocol,
+            } => FlowTuple::Ipv4 {
+                source: destination,
+                destination: source,
+                source_port: destination_port,
+                destination_port: source_port,
+                protocol,
+            },
+            FlowTuple::Ipv6 {
+                source,
+                destination,
+                source_port,
+                destination_port,
+                protocol,
+            } => FlowTuple::Ipv6 {
+                source: destination,
+                destination: source,
+                source_port: destination_port,
+                destination_port: source_port,
+                protocol,
+            },
+        }
+    }
+
+    /// Returns the flow tuple with the endpoints ordered so that the
+    /// source endpoint (address & port) is the smaller one.
+    ///
+    /// Packets of both directions of a flow result in the same normalized
+    /// tuple, which makes it usable as a key for bidirectional flow tracking.
+    pub fn normalized(&self) -> FlowTuple {
+        let swap = match self {
+            FlowTuple::Ipv4 {
+                source,
+                destination,
+                source_port,
+                destination_port,
+                ..
+            } => (destination, destination_port) < (source, source_port),
+            FlowTuple::Ipv6 {
+                source,
+                destination,
+                source_port,
+                destination_port,
+                ..
+            } => (destination, destination_port) < (source, source_port),
+        };
+        if swap {
+            self.reversed()
+        } else {
+            *self
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    const V4: FlowTuple = FlowTuple::Ipv4 {
+        source: [1, 2, 3, 4],
+        destination: [5, 6, 7, 8],
+        source_port: 1234,
+        destination_port: 80,
+        protocol: ip_number::TCP,
+    };
+
+    const V6: FlowTuple = FlowTuple::Ipv6 {
+        source: [1; 16],
+        destination: [2; 16],
+        source_port: 53,
+        destination_port: 1234,
+        protocol: ip_number::UDP,
+    };
+
+    #[test]
+    fn debug_clone_eq() {
+        assert_eq!(V4.clone(), V4);
+        assert_ne!(V4, V6);
+        assert_eq!(
+            format!("{:?}", V4),
+            format!(
+                "Ipv4 {{ source: {:?}, destination: {:?}, source_port: 1234, destination_port: 80, protocol: {:?} }}",
+                [1, 2, 3, 4],
+                [5, 6, 7, 8],
+                ip_number::TCP
+            )
+        );
+    }
+
+    #[test]
+    fn accessors() {
+        assert_eq!(V4.source_port(), 1234);
+        assert_eq!(V4.destination_port(), 80);
+        assert_eq!(V4.protocol(), ip_number::TCP);
+        assert_eq!(V6.source_port(), 53);
+        assert_eq!(V6.destination_port(), 1234);
+        assert_eq!(V6.protocol(), ip_number::UDP);
+    }
+
+    #[test]
+    fn addrs() {
+        use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+        assert_eq!(V4.source_addr(), IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)));
+        assert_eq!(V4.destination_addr(), IpAddr::V4(Ipv4Addr::new(5, 6, 7, 8)));
+        assert_eq!(V6.source_addr(), IpAddr::V6(Ipv6Addr::from([1; 16])));
+        assert_eq!(V6.destination_addr(), IpAddr::V6(Ipv6Addr::from([2; 16])));
+    }
+
+    #[test]
+    fn reversed() {
+        assert_eq!(
+            V4.reversed(),
+            FlowTuple::Ipv4 {
+                source: [5, 6, 7, 8],
+                destination: [1, 2, 3, 4],
+                source_port: 80,
+                destination_port: 1234,
+                protocol: ip_number::TCP,
+            }
+        );
+        assert_eq!(
+            V6.reversed(),
+            FlowTuple::Ipv6 {
+                source: [2; 16],
+                destination: [1; 16],
+                source_port: 1234,
+                destination_port: 53,
+                protocol: ip_number::UDP,
+            }
+        );
+        assert_eq!(V4.reversed().reversed(), V4);
+        assert_eq!(V6.reversed().reversed(), V6);
+    }
+
+    #[test]
+    fn normalized() {
+        // already ordered
+        assert_eq!(V4.normalized(), V4);
+        assert_eq!(V6.normalized(), V6);
+
+        // swapped
+        assert_eq!(V4.reversed().normalized(), V4);
+        assert_eq!(V6.reversed().normalized(), V6);
+
+        // same address, ports decide the order
+        let same_addr = FlowTuple::Ipv4 {
+            source: [1, 2, 3, 4],
+            destination: [1, 2, 3, 4],
+            source_port: 2,
+            destination_port: 1,
+            protocol: ip_number::UDP,
+        };
+        assert_eq!(same_addr.normalized(), same_addr.reversed());
+        assert_eq!(same_addr.reversed().normalized(), same_addr.reversed());
+    }
+}
@@ -0,0 +1,50 @@
+/// Interpretation chosen by [`crate::SlicedPacket::from_guess`] for the
+/// start of the data.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GuessedLinkType {
+    /// Data was decoded as starting with an Ethernet II header.
+    Ethernet2,
+    /// Data was decoded as a raw IPv4 packet (no link layer).
+    Ipv4,
+    /// Data was decoded as a raw IPv6 packet (no link layer).
+    Ipv6,
+}
+
+#[cfg(test)]
+mod test {
+    use super::GuessedLinkType::*;
+    use alloc::format;
+    use std::{
+        cmp::{Ord, Ordering},
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    #[test]
+    fn debug() {
+        let tests = [(Ethernet2, "Ethernet2"), (Ipv4, "Ipv4"), (Ipv6, "Ipv6")];
+        for (value, expected) in tests {
+            assert_eq!(expected, format!("{:?}", value));
+        }
+    }
+
+    #[test]
+    fn clone_eq_hash_ord() {
+        let value = Ipv4;
+        assert_eq!(value, value.clone());
+        assert_eq!(Ordering::Equal, value.cmp(&value));
+        assert_eq!(Ordering::Less, Ethernet2.cmp(&Ipv6));
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            value.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+    }
+}
@@ -81,3 +81,59 @@ pub(crate) unsafe fn get_unchecked_16_byte_array(ptr: *const u8) -> [u8; 16] {
         *ptr.add(15),
     ]
 }
+
+/// Helper for deserializing option bytes (IPv4 & TCP options) into a
+/// fixed size buffer without requiring an allocation.
+#[cfg(feature = "serde")]
+pub(crate) struct OptionsBufVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for OptionsBufVisitor {
+    type Value = ([u8; 40], usize);
+
+    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "a byte array with a length of at most 40 bytes")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        if v.len() > 40 {
+            return Err(E::invalid_length(v.len(), &self));
+        }
+        let mut buf = [0u8; 40];
+        buf[..v.len()].copy_from_slice(v);
+        Ok((buf, v.len()))
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut buf = [0u8; 40];
+        let mut len = 0;
+        while let Some(value) = seq.next_element::<u8>()? {
+            if len >= buf.len() {
+                return Err(serde::de::Error::invalid_length(len + 1, &self));
+            }
+            buf[len] = value;
+            len += 1;
+        }
+        Ok((buf, len))
+    }
+}
+
+/// Helper function for reading a port of a transport header at the start
+/// of the given slice (`offset` 0 for the source & 2 for the destination port).
+///
+/// Returns `None` if the slice is too short or `ip_number` is not a protocol
+/// with 16 bit ports at the start of its header (TCP, UDP, UDP-Lite & SCTP).
+#[inline]
+pub(crate) fn get_transport_port(
+    ip_number: crate::IpNumber,
+    transport: &[u8],
+    offset: usize,
+) -> Option<u16> {
+    use crate::ip_number::*;
+    match ip_number {
+        TCP | UDP | UDP_LITE | SCTP => transport
+            .get(offset..offset + 2)
+            .map(|port| u16::from_be_bytes([port[0], port[1]])),
+        _ => None,
+    }
+}
@@ -0,0 +1,481 @@
+use crate::{
+    checksum::{Checksum16, Sum16BitWords},
+    err::io::BackfillError,
+};
+use std::{
+    io::{Seek, SeekFrom, Write},
+    vec::Vec,
+};
+
+/// Identifier of a checksum region started via [`BackfillWriter::start_checksum`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct BackfillChecksumId(usize);
+
+/// Kind of a field that gets backfilled by a [`BackfillWriter`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BackfillKind {
+    /// 16 bit length field.
+    Len,
+    /// 16 bit ones complement checksum field.
+    Checksum,
+}
+
+/// Field that was backfilled by [`BackfillWriter::finish`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct BackfillField {
+    /// Kind of the field.
+    pub kind: BackfillKind,
+    /// Offset of the field (relative to the start of the writer).
+    pub offset: u64,
+    /// Value that was written (in big endian byte order) to the field.
+    pub value: u16,
+}
+
+/// Data range over which a checksum gets calculated.
+#[cfg(feature = "std")]
+struct ChecksumRegion {
+    /// Offset of the first byte of the region.
+    start: u64,
+    /// Offset after the last byte of the region (`None` if the
+    /// region is still open).
+    end: Option<u64>,
+    /// Sum of the pseudo header.
+    pseudo_header_sum: Sum16BitWords,
+    /// True if the length of the region should be added to the
+    /// pseudo header sum (as 32 bit value).
+    add_len: bool,
+    /// Checksum of the data written in the region.
+    checksum: Checksum16,
+}
+
+/// Placeholder written to the writer that will be backfilled.
+#[cfg(feature = "std")]
+enum Placeholder {
+    /// Length field containing the length from `start` till the end.
+    Len { offset: u64, start: u64 },
+    /// Checksum field of the region with the given index.
+    Checksum { offset: u64, region: usize },
+}
+
+/// Writer that writes placeholders for length & checksum fields and
+/// backfills them once all data has been written.
+///
+/// This allows writing packets layer by layer to a stream (e.g. with
+/// large payloads streamed from disk) without knowing the payload length
+/// or checksum in advance. All offsets are relative to the position of
+/// the underlying writer when the [`BackfillWriter`] was created.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{checksum::Sum16BitWords, io::BackfillWriter, SlicedPacket, TransportSlice};
+/// use std::io::{Cursor, Write};
+///
+/// let source = [192, 168, 1, 1];
+/// let destination = [192, 168, 1, 2];
+///
+/// let mut writer = BackfillWriter::new(Cursor::new(Vec::new())).unwrap();
+///
+/// // IPv4 header (total length & header checksum get backfilled)
+/// let ip_start = writer.position();
+/// let ip_checksum = writer.start_checksum(Sum16BitWords::new(), false);
+/// writer.write_all(&[0x45, 0]).unwrap();
+/// writer.write_len_placeholder(ip_start).unwrap();
+/// writer.write_all(&[0, 0, 0x40, 0, 64, 17]).unwrap();
+/// writer.write_checksum_placeholder(ip_checksum).unwrap();
+/// writer.write_all(&source).unwrap();
+/// writer.write_all(&destination).unwrap();
+/// writer.end_checksum(ip_checksum);
+///
+/// // UDP header (length & checksum get backfilled)
+/// let udp_start = writer.position();
+/// let udp_checksum = writer.start_checksum(
+///     Sum16BitWords::new()
+///         .add_4bytes(source)
+///         .add_4bytes(destination)
+///         .add_2bytes([0, 17]),
+///     true,
+/// );
+/// writer.write_all(&1234u16.to_be_bytes()).unwrap();
+/// writer.write_all(&5678u16.to_be_bytes()).unwrap();
+/// writer.write_len_placeholder(udp_start).unwrap();
+/// writer.write_checksum_placeholder(udp_checksum).unwrap();
+///
+/// // payload (could also be streamed via std::io::copy)
+/// writer.write_all(&[1, 2, 3, 4, 5]).unwrap();
+///
+/// let (cursor, fields) = writer.finish().unwrap();
+/// assert_eq!(4, fields.len());
+///
+/// let packet = cursor.into_inner();
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// if let Some(TransportSlice::Udp(udp)) = sliced.transport {
+///     assert_eq!(13, udp.length());
+///     assert_eq!(&[1, 2, 3, 4, 5], udp.payload());
+/// } else {
+///     panic!("expected UDP");
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct BackfillWriter<T> {
+    /// Writer to which the data gets written.
+    writer: T,
+    /// Position of the writer when the [`BackfillWriter`] was created.
+    start: u64,
+    /// Number of bytes written so far.
+    len: u64,
+    /// Regions over which checksums get calculated.
+    checksums: Vec<ChecksumRegion>,
+    /// Placeholders that get backfilled on finish.
+    placeholders: Vec<Placeholder>,
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T: Write + Seek> BackfillWriter<T> {
+    /// Creates a new backfill writer starting at the current
+    /// position of the given writer.
+    pub fn new(mut writer: T) -> Result<BackfillWriter<T>, std::io::Error> {
+        let start = writer.stream_position()?;
+        Ok(BackfillWriter {
+            writer,
+            start,
+            len: 0,
+            checksums: Vec::new(),
+            placeholders: Vec::new(),
+        })
+    }
+
+    /// Offset of the next byte that gets written (relative to the
+    /// start of the writer).
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.len
+    }
+
+    /// Writes a 16 bit placeholder that gets backfilled with the number of
+    /// bytes from the offset `start` till the end of the written data.
+    pub fn write_len_placeholder(&mut self, start: u64) -> Result<(), std::io::Error> {
+        self.placeholders.push(Placeholder::Len {
+            offset: self.len,
+            start,
+        });
+        self.write_all(&[0, 0])
+    }
+
+    /// Starts a checksum region at the current position.
+    ///
+    /// All data written till [`BackfillWriter::end_checksum`] is called
+    /// (or till the end if it is never called) is included in the
+    /// checksum. `pseudo_header_sum` is added to the checksum and if
+    /// `add_len` is set the length of the region is added as 32 bit
+    /// value (as required by the UDP & TCP pseudo headers).
+    pub fn start_checksum(
+        &mut self,
+        pseudo_header_sum: Sum16BitWords,
+        add_len: bool,
+    ) -> BackfillChecksumId {
+        self.checksums.push(ChecksumRegion {
+            start: self.len,
+            end: None,
+            pseudo_header_sum,
+            add_len,
+            checksum: Checksum16::new(),
+        });
+        BackfillChecksumId(self.checksums.len() - 1)
+    }
+
+    /// Ends the checksum region at the current position.
+    pub fn end_checksum(&mut self, id: BackfillChecksumId) {
+        let region = &mut self.checksums[id.0];
+        if region.end.is_none() {
+            region.end = Some(self.len);
+        }
+    }
+
+    /// Writes a 16 bit placeholder that gets backfilled with the
+    /// checksum of the given region.
+    ///
+    /// Placeholders (length & checksum) located in a checksum region are
+    /// included with their backfilled values. For checksum fields this
+    /// only works if the covered checksum field is located after the
+    /// checksum field of the region (e.g. a checksum field in a payload).
+    pub fn write_checksum_placeholder(
+        &mut self,
+        id: BackfillChecksumId,
+    ) -> Result<(), std::io::Error> {
+        self.placeholders.push(Placeholder::Checksum {
+            offset: self.len,
+            region: id.0,
+        });
+        self.write_all(&[0, 0])
+    }
+
+    /// Backfills all placeholders and returns the underlying writer
+    /// together with the backfilled fields (sorted by offset).
+    ///
+    /// The underlying writer is positioned after the last written byte.
+    pub fn finish(mut self) -> Result<(T, Vec<BackfillField>), BackfillError> {
+        let end = self.len;
+        let mut fields = Vec::with_capacity(self.placeholders.len());
+
+        // lengths
+        for p in &self.placeholders {
+            if let Placeholder::Len { offset, start } = *p {
+                let len = end - start;
+                let value =
+                    u16::try_from(len).map_err(|_| BackfillError::LenTooBig { offset, len })?;
+                fields.push(BackfillField {
+                    kind: BackfillKind::Len,
+                    offset,
+                    value,
+                });
+            }
+        }
+
+        // checksums (calculated from the last to the first, so that
+        // checksums covering later checksum fields include their values)
+        let mut checksums: Vec<(u64, usize)> = self
+            .placeholders
+            .iter()
+            .filter_map(|p| match *p {
+                Placeholder::Checksum { offset, region } => Some((offset, region)),
+                Placeholder::Len { .. } => None,
+            })
+            .collect();
+        checksums.sort_by_key(|c| core::cmp::Reverse(c.0));
+
+        for (offset, region_index) in checksums {
+            let region = &self.checksums[region_index];
+            let region_end = region.end.unwrap_or(end);
+            let mut sum = region
+                .pseudo_header_sum
+                .clone()
+                .add_2bytes((!region.checksum.finish()).to_be_bytes());
+            if region.add_len {
+                sum = sum.add_4bytes(((region_end - region.start) as u32).to_be_bytes());
+            }
+            for field in &fields {
+                if field.offset >= region.start && field.offset + 2 <= region_end {
+                    let bytes = field.value.to_be_bytes();
+                    sum = if 0 == (field.offset - region.start) % 2 {
+                        sum.add_2bytes(bytes)
+                    } else {
+                        sum.add_2bytes([bytes[1], bytes[0]])
+                    };
+                }
+            }
+            let value = sum.to_ones_complement_with_no_zero().to_be();
+            fields.push(BackfillField {
+                kind: BackfillKind::Checksum,
+                offset,
+                // fields at an odd offset in the region contribute
+                // with swapped bytes to the sum
+                value: if offset >= region.start && 0 != (offset - region.start) % 2 {
+                    value.swap_bytes()
+                } else {
+                    value
+                },
+            });
+        }
+
+        // write the values
+        fields.sort_by_key(|f| f.offset);
+        for field in &fields {
+            self.writer
+                .seek(SeekFrom::Start(self.start + field.offset))
+                .map_err(BackfillError::Io)?;
+            self.writer
+                .write_all(&field.value.to_be_bytes())
+                .map_err(BackfillError::Io)?;
+        }
+        self.writer
+            .seek(SeekFrom::Start(self.start + end))
+            .map_err(BackfillError::Io)?;
+
+        Ok((self.writer, fields))
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T: Write + Seek> Write for BackfillWriter<T> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, std::io::Error> {
+        let written = self.writer.write(buf)?;
+        for region in self.checksums.iter_mut().filter(|r| r.end.is_none()) {
+            region.checksum.push_slice(&buf[..written]);
+        }
+        self.len += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> Result<(), std::io::Error> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+    use crate::*;
+    use alloc::{format, vec, vec::Vec};
+    use std::io::Cursor;
+
+    #[test]
+    fn id_kind_field_debug_clone_eq() {
+        let field = BackfillField {
+            kind: BackfillKind::Len,
+            offset: 2,
+            value: 3,
+        };
+        assert_eq!(field.clone(), field);
+        assert_eq!(
+            format!("{:?}", field),
+            "BackfillField { kind: Len, offset: 2, value: 3 }"
+        );
+        let id = BackfillChecksumId(1);
+        assert_eq!(id.clone(), id);
+        assert_eq!(format!("{:?}", id), "BackfillChecksumId(1)");
+    }
+
+    #[test]
+    fn ipv4_udp() {
+        let payload = [1, 2, 3, 4, 5];
+
+        // expected packet
+        let expected = {
+            let builder =
+                PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 64).udp(1234, 5678);
+            let mut result = Vec::with_capacity(builder.size(payload.len()));
+            builder.write(&mut result, &payload).unwrap();
+            result
+        };
+        let ip = Ipv4HeaderSlice::from_slice(&expected).unwrap();
+
+        // write with placeholders (offset by a prefix to check that
+        // offsets are relative to the start of the writer)
+        let mut cursor = Cursor::new(vec![0xff; 3]);
+        cursor.set_position(3);
+        let mut writer = BackfillWriter::new(cursor).unwrap();
+        let ip_start = writer.position();
+        let ip_checksum = writer.start_checksum(Sum16BitWords::new(), false);
+        writer.write_all(&expected[..2]).unwrap();
+        writer.write_len_placeholder(ip_start).unwrap();
+        writer.write_all(&expected[4..10]).unwrap();
+        writer.write_checksum_placeholder(ip_checksum).unwrap();
+        writer.write_all(&expected[12..20]).unwrap();
+        writer.end_checksum(ip_checksum);
+
+        let udp_start = writer.position();
+        let udp_checksum = writer.start_checksum(
+            Sum16BitWords::new()
+                .add_4bytes(ip.source())
+                .add_4bytes(ip.destination())
+                .add_2bytes([0, ip_number::UDP.0]),
+            true,
+        );
+        writer.write_all(&expected[20..24]).unwrap();
+        writer.write_len_placeholder(udp_start).unwrap();
+        writer.write_checksum_placeholder(udp_checksum).unwrap();
+        writer.write_all(&payload).unwrap();
+        writer.flush().unwrap();
+
+        let (cursor, fields) = writer.finish().unwrap();
+        assert_eq!(cursor.position(), 3 + expected.len() as u64);
+        assert_eq!(&cursor.get_ref()[..3], &[0xff, 0xff, 0xff]);
+        assert_eq!(&cursor.get_ref()[3..], &expected[..]);
+        assert_eq!(
+            fields,
+            vec![
+                BackfillField {
+                    kind: BackfillKind::Len,
+                    offset: 2,
+                    value: expected.len() as u16,
+                },
+                BackfillField {
+                    kind: BackfillKind::Checksum,
+                    offset: 10,
+                    value: ip.header_checksum(),
+                },
+                BackfillField {
+                    kind: BackfillKind::Len,
+                    offset: 24,
+                    value: (8 + payload.len()) as u16,
+                },
+                BackfillField {
+                    kind: BackfillKind::Checksum,
+                    offset: 26,
+                    value: u16::from_be_bytes([expected[26], expected[27]]),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn odd_aligned_fields() {
+        // checksum covering a length field at an odd offset & a
+        // checksum field located after its own checksum field
+        let mut writer = BackfillWriter::new(Cursor::new(Vec::new())).unwrap();
+        let outer = writer.start_checksum(Sum16BitWords::new(), false);
+        writer.write_checksum_placeholder(outer).unwrap();
+        writer.write_all(&[0x12]).unwrap();
+        writer.write_len_placeholder(0).unwrap();
+        let inner = writer.start_checksum(Sum16BitWords::new(), false);
+        writer.write_all(&[0x34]).unwrap();
+        writer.write_checksum_placeholder(inner).unwrap();
+        writer.write_all(&[0x56, 0x78, 0x9a]).unwrap();
+
+        let (cursor, _) = writer.finish().unwrap();
+        let data = cursor.into_inner();
+
+        // the inner checksum is calculated over its region & the outer
+        // checksum over all data
+        let verify = |slice: &[u8]| {
+            let mut checksum = checksum::Checksum16::new();
+            checksum.push_slice(slice);
+            assert_eq!(0, checksum.finish());
+        };
+        verify(&data[5..]);
+        verify(&data);
+        assert_eq!(&data[3..5], &(data.len() as u16).to_be_bytes());
+    }
+
+    #[test]
+    fn len_too_big() {
+        let mut writer = BackfillWriter::new(Cursor::new(Vec::new())).unwrap();
+        writer.write_len_placeholder(0).unwrap();
+        writer
+            .write_all(&vec![0; usize::from(u16::MAX) - 1])
+            .unwrap();
+
+        // ok at max len
+        assert!(BackfillWriter::new(Cursor::new(Vec::new()))
+            .and_then(|mut w| {
+                w.write_len_placeholder(0)?;
+                w.write_all(&vec![0; usize::from(u16::MAX) - 2])?;
+                Ok(w)
+            })
+            .unwrap()
+            .finish()
+            .is_ok());
+
+        // error one above
+        assert_eq!(
+            format!("{:?}", writer.finish().unwrap_err()),
+            format!(
+                "{:?}",
+                err::io::BackfillError::LenTooBig {
+                    offset: 0,
+                    len: u64::from(u16::MAX) + 1
+                }
+            )
+        );
+    }
+}
@@ -1,3 +1,8 @@
+#[cfg(feature = "std")]
+mod backfill_writer;
+#[cfg(feature = "std")]
+pub use backfill_writer::*;
+
 #[cfg(feature = "std")]
 mod limited_reader;
 #[cfg(feature = "std")]
@@ -13,7 +13,7 @@ use crate::{
 ///
 /// depending on your starting header to parse the headers in a slice and get this
 /// struct as a result.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct LaxPacketHeaders<'a> {
     /// Ethernet II header if present.
     pub link: Option<Ethernet2Header>,
@@ -25,6 +25,10 @@ impl<'a> LaxSlicedPacket<'a> {
     /// headers from the ethernet header downwards with lax length checks and
     /// non-terminating errors.
     ///
+    /// If the ether type field contains a value smaller or equal to 1500 the frame
+    /// is decoded as an IEEE 802.3 frame with an LLC header & optional SNAP header
+    /// instead (see [`LinkSlice::Ieee8023`]).
+    ///
     /// # Example
     ///
     /// Basic usage:
@@ -235,6 +239,9 @@ impl<'a> LaxSlicedPacket<'a> {
         } else if let Some(eth) = self.link.as_ref() {
             match eth {
                 LinkSlice::Ethernet2(e) => Some(e.payload()),
+                LinkSlice::Ieee8023(_) => Some(eth.payload()),
+                LinkSlice::LinuxSll(s) => Some(s.payload()),
+                LinkSlice::LinuxSll2(s) => Some(s.payload()),
                 LinkSlice::EtherPayload(e) => Some(e.clone()),
             }
         } else {
@@ -255,6 +262,68 @@ impl<'a> LaxSlicedPacket<'a> {
             None
         }
     }
+
+    /// Returns the expected length of the TCP payload (segment length without
+    /// the TCP header & options) if a TCP header was decoded.
+    ///
+    /// The length is determined via the IPv4 total length or IPv6 payload
+    /// length field minus the IP options, IP extension headers & the TCP
+    /// header length (data offset * 4). In case the packet was cut off
+    /// (e.g. by a capture snap length) the returned length can be bigger
+    /// than the TCP payload present in the slice. If the IP length field
+    /// could not be used (e.g. it is smaller than the headers), the length
+    /// of the TCP payload present in the slice is returned instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{LaxSlicedPacket, PacketBuilder, TransportSlice};
+    ///
+    /// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .tcp(21, 1234, 1, 2);
+    /// let mut packet = Vec::<u8>::with_capacity(builder.size(4));
+    /// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+    ///
+    /// // cut off the last two bytes of the tcp payload
+    /// let sliced = LaxSlicedPacket::from_ethernet(&packet[..packet.len() - 2]).unwrap();
+    /// match &sliced.transport {
+    ///     Some(TransportSlice::Tcp(tcp)) => assert_eq!(2, tcp.payload_len()),
+    ///     _ => unreachable!(),
+    /// }
+    /// assert_eq!(Some(4), sliced.tcp_payload_len());
+    /// ```
+    pub fn tcp_payload_len(&self) -> Option<usize> {
+        let tcp = match self.transport.as_ref() {
+            Some(TransportSlice::Tcp(tcp)) => tcp,
+            _ => return None,
+        };
+        let expected_ip_payload_len = match self.net.as_ref() {
+            Some(LaxNetSlice::Ipv4(ipv4)) if ipv4.payload().incomplete => {
+                let header = ipv4.header();
+                usize::from(header.total_len())
+                    .checked_sub(header.slice().len())
+                    .and_then(|len| {
+                        len.checked_sub(
+                            ipv4.extensions()
+                                .auth
+                                .map(|auth| auth.slice().len())
+                                .unwrap_or(0),
+                        )
+                    })
+            }
+            Some(LaxNetSlice::Ipv6(ipv6)) if ipv6.payload().incomplete => {
+                usize::from(ipv6.header().payload_length())
+                    .checked_sub(ipv6.extensions().slice().len())
+            }
+            _ => None,
+        };
+        Some(
+            expected_ip_payload_len
+                .and_then(|len| len.checked_sub(tcp.header_len()))
+                .unwrap_or_else(|| tcp.payload_len()),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -498,6 +567,81 @@ mod test {
         }
     }
 
+    #[test]
+    fn tcp_payload_len() {
+        use alloc::vec::*;
+
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+        let ip_variants = [
+            IpHeaders::Ipv4(
+                Ipv4Header {
+                    time_to_live: 20,
+                    source: [192, 168, 1, 1],
+                    destination: [192, 168, 1, 2],
+                    options: [1, 2, 3, 4].into(),
+                    ..Default::default()
+                },
+                Default::default(),
+            ),
+            IpHeaders::Ipv6(
+                Ipv6Header {
+                    hop_limit: 20,
+                    ..Default::default()
+                },
+                Ipv6Extensions {
+                    hop_by_hop_options: Some(
+                        Ipv6RawExtHeader::new_raw(ip_number::TCP, &[0; 6]).unwrap(),
+                    ),
+                    ..Default::default()
+                },
+            ),
+        ];
+        for ip in ip_variants {
+            // tcp
+            let mut buf = Vec::new();
+            PacketBuilder::ip(ip.clone())
+                .tcp(21, 1234, 1, 2)
+                .write(&mut buf, &payload)
+                .unwrap();
+
+            // complete packet & packet cut off in the tcp payload
+            for cut in 0..=payload.len() {
+                let sliced = LaxSlicedPacket::from_ip(&buf[..buf.len() - cut]).unwrap();
+                assert_eq!(Some(payload.len()), sliced.tcp_payload_len());
+            }
+
+            // ip length field unusable (fallback to the slice)
+            {
+                let mut buf = buf.clone();
+                match &ip {
+                    IpHeaders::Ipv4(_, _) => {
+                        // total_len = 20 (smaller then the ipv4 header with options)
+                        buf[2] = 0;
+                        buf[3] = 20;
+                        buf.truncate(Ipv4Header::MIN_LEN + 4 + TcpHeader::MIN_LEN + 2);
+                    }
+                    IpHeaders::Ipv6(_, _) => {
+                        // payload_len = 0 (no length present)
+                        buf[4] = 0;
+                        buf[5] = 0;
+                        buf.truncate(Ipv6Header::LEN + 8 + TcpHeader::MIN_LEN + 2);
+                    }
+                }
+                let sliced = LaxSlicedPacket::from_ip(&buf).unwrap();
+                assert_eq!(Some(2), sliced.tcp_payload_len());
+            }
+
+            // udp
+            let mut buf = Vec::new();
+            PacketBuilder::ip(ip)
+                .udp(21, 1234)
+                .write(&mut buf, &payload)
+                .unwrap();
+            let sliced = LaxSlicedPacket::from_ip(&buf).unwrap();
+            assert_eq!(None, sliced.tcp_payload_len());
+        }
+    }
+
     #[test]
     fn from_x_slice() {
         // no eth
@@ -513,7 +657,7 @@ mod test {
             let eth = Ethernet2Header {
                 source: [1, 2, 3, 4, 5, 6],
                 destination: [1, 2, 3, 4, 5, 6],
-                ether_type: 0.into(),
+                ether_type: ether_type::WAKE_ON_LAN,
             };
             let test = TestPacket {
                 link: Some(eth.clone()),
@@ -1366,6 +1510,9 @@ mod test {
                     Some(S::Tcp(s)) => {
                         assert_eq!(&test.transport, &Some(H::Tcp(s.to_header())));
                     }
+                    Some(S::Igmp(_)) => unreachable!(),
+                    Some(S::Esp(_)) => unreachable!(),
+                    Some(S::Dccp(_)) => unreachable!(),
                     None => {
                         assert_eq!(&test.transport, &None);
                     }
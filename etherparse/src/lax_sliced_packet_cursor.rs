@@ -27,12 +27,23 @@ impl<'a> LaxSlicedPacketCursor<'a> {
 
         let result = Ethernet2Slice::from_slice_without_fcs(slice)?;
 
-        // cache the ether_type for later
-        let payload = result.payload();
+        // ether types up to 1500 are the length field of an IEEE 802.3 frame
+        let payload = if result.ether_type().0 <= Ieee8023Slice::MAX_PAYLOAD_LEN {
+            let result = Ieee8023Slice::from_slice(slice)?;
+            let link = Ieee8023(result.clone());
+            let payload = link.payload();
+            cursor.offset += result.header_len();
+            cursor.result.link = Some(link);
+            payload
+        } else {
+            // cache the ether_type for later
+            let payload = result.payload();
 
-        // set the new data
-        cursor.offset += result.header_len();
-        cursor.result.link = Some(Ethernet2(result));
+            // set the new data
+            cursor.offset += result.header_len();
+            cursor.result.link = Some(Ethernet2(result));
+            payload
+        };
 
         // continue parsing (if required)
         match payload.ether_type {
@@ -96,6 +107,7 @@ impl<'a> LaxSlicedPacketCursor<'a> {
                             I::Len(l) => O::Len(l),
                             I::Content(c) => match c {
                                 E::HopByHopNotAtStart => O::Ipv6Exts(E::HopByHopNotAtStart),
+                                E::TooManyHeaders { max } => O::Ipv6Exts(E::TooManyHeaders { max }),
                                 E::IpAuth(auth) => {
                                     if is_ip_v4 {
                                         O::Ipv4Exts(auth)
@@ -197,6 +209,7 @@ impl<'a> LaxSlicedPacketCursor<'a> {
                     I::Len(l) => O::Len(l.add_offset(self.offset)),
                     I::Content(c) => match c {
                         E::HopByHopNotAtStart => O::Ipv6Exts(E::HopByHopNotAtStart),
+                        E::TooManyHeaders { max } => O::Ipv6Exts(E::TooManyHeaders { max }),
                         E::IpAuth(auth) => match &ip.0 {
                             LaxIpSlice::Ipv4(_) => O::Ipv4Exts(auth),
                             LaxIpSlice::Ipv6(_) => O::Ipv6Exts(E::IpAuth(auth)),
@@ -0,0 +1,204 @@
+use crate::err::Layer;
+
+/// Set of [`Layer`]s stored as a bitmask.
+///
+/// Can be used to get a cheap summary of the headers present in a packet
+/// (see [`SlicedPacket::layers`](crate::SlicedPacket::layers)).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{err::Layer, PacketBuilder, SlicedPacket};
+///
+/// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///     .ipv6([0; 16], [1; 16], 20)
+///     .tcp(21, 1234, 1, 2);
+/// let mut packet = Vec::<u8>::with_capacity(builder.size(0));
+/// builder.write(&mut packet, &[]).unwrap();
+///
+/// let layers = SlicedPacket::from_ethernet(&packet).unwrap().layers();
+/// assert!(layers.contains(Layer::Ethernet2Header));
+/// assert!(layers.contains(Layer::Ipv6Header));
+/// assert!(layers.contains(Layer::TcpHeader));
+/// assert!(false == layers.contains(Layer::UdpHeader));
+/// assert_eq!(3, layers.len());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LayerSet(u64);
+
+impl LayerSet {
+    /// Set containing no layers.
+    pub const EMPTY: LayerSet = LayerSet(0);
+
+    /// Returns a set containing no layers.
+    #[inline]
+    pub const fn new() -> LayerSet {
+        LayerSet::EMPTY
+    }
+
+    /// Returns the bit representing the given layer.
+    #[inline]
+    const fn bit(layer: Layer) -> u64 {
+        1u64 << (layer as u8)
+    }
+
+    /// Returns the raw bitmask (bit `n` is set if the layer with the
+    /// discriminant `n` is part of the set).
+    #[inline]
+    pub const fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns true if the given layer is part of the set.
+    #[inline]
+    pub const fn contains(&self, layer: Layer) -> bool {
+        0 != self.0 & LayerSet::bit(layer)
+    }
+
+    /// Returns true if all layers in `other` are also part of this set.
+    #[inline]
+    pub const fn contains_all(&self, other: LayerSet) -> bool {
+        other.0 == self.0 & other.0
+    }
+
+    /// Adds the given layer to the set.
+    #[inline]
+    pub fn insert(&mut self, layer: Layer) {
+        self.0 |= LayerSet::bit(layer);
+    }
+
+    /// Removes the given layer from the set.
+    #[inline]
+    pub fn remove(&mut self, layer: Layer) {
+        self.0 &= !LayerSet::bit(layer);
+    }
+
+    /// Returns a copy of the set with the given layer added.
+    #[inline]
+    pub const fn with(self, layer: Layer) -> LayerSet {
+        LayerSet(self.0 | LayerSet::bit(layer))
+    }
+
+    /// Returns true if the set contains no layers.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        0 == self.0
+    }
+
+    /// Returns the number of layers in the set.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+}
+
+impl core::ops::BitOr for LayerSet {
+    type Output = LayerSet;
+
+    #[inline]
+    fn bitor(self, rhs: LayerSet) -> LayerSet {
+        LayerSet(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for LayerSet {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: LayerSet) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl core::ops::BitAnd for LayerSet {
+    type Output = LayerSet;
+
+    #[inline]
+    fn bitand(self, rhs: LayerSet) -> LayerSet {
+        LayerSet(self.0 & rhs.0)
+    }
+}
+
+impl From<Layer> for LayerSet {
+    #[inline]
+    fn from(layer: Layer) -> LayerSet {
+        LayerSet::EMPTY.with(layer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn all_layers_fit() {
+        // the bitmask has to be able to represent every layer
+        assert!((Layer::LengthPrefixedFrame as u8) < 64);
+    }
+
+    #[test]
+    fn debug_clone_eq_default() {
+        let set = LayerSet::from(Layer::TcpHeader);
+        assert_eq!(set, set.clone());
+        assert_eq!(
+            format!("LayerSet({})", 1u64 << (Layer::TcpHeader as u8)),
+            format!("{:?}", set)
+        );
+        assert_eq!(LayerSet::EMPTY, LayerSet::default());
+        assert_eq!(LayerSet::EMPTY, LayerSet::new());
+    }
+
+    #[test]
+    fn insert_remove_contains() {
+        let mut set = LayerSet::new();
+        assert!(set.is_empty());
+        assert_eq!(0, set.len());
+        assert!(!set.contains(Layer::Ethernet2Header));
+
+        set.insert(Layer::Ethernet2Header);
+        set.insert(Layer::LengthPrefixedFrame);
+        set.insert(Layer::Ethernet2Header);
+        assert!(!set.is_empty());
+        assert_eq!(2, set.len());
+        assert!(set.contains(Layer::Ethernet2Header));
+        assert!(set.contains(Layer::LengthPrefixedFrame));
+        assert!(!set.contains(Layer::TcpHeader));
+        assert_eq!(
+            (1u64 << (Layer::Ethernet2Header as u8)) | (1u64 << (Layer::LengthPrefixedFrame as u8)),
+            set.bits()
+        );
+
+        set.remove(Layer::Ethernet2Header);
+        set.remove(Layer::TcpHeader);
+        assert_eq!(1, set.len());
+        assert!(!set.contains(Layer::Ethernet2Header));
+        assert!(set.contains(Layer::LengthPrefixedFrame));
+    }
+
+    #[test]
+    fn with_contains_all() {
+        let set = LayerSet::new()
+            .with(Layer::Ipv4Header)
+            .with(Layer::UdpHeader);
+        assert!(set.contains_all(LayerSet::EMPTY));
+        assert!(set.contains_all(Layer::UdpHeader.into()));
+        assert!(set.contains_all(set));
+        assert!(!set.contains_all(set.with(Layer::VxlanHeader)));
+    }
+
+    #[test]
+    fn bit_ops() {
+        let a = LayerSet::from(Layer::Ipv4Header).with(Layer::TcpHeader);
+        let b = LayerSet::from(Layer::Ipv6Header).with(Layer::TcpHeader);
+        assert_eq!(
+            LayerSet::from(Layer::Ipv4Header)
+                .with(Layer::Ipv6Header)
+                .with(Layer::TcpHeader),
+            a | b
+        );
+        assert_eq!(LayerSet::from(Layer::TcpHeader), a & b);
+        let mut c = a;
+        c |= b;
+        assert_eq!(a | b, c);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::*;
+
+/// Slice of a single layer returned by [`PacketSlicer::next_layer`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LayerSlice<'a> {
+    /// Ethernet II header & payload.
+    Ethernet2(Ethernet2Slice<'a>),
+    /// A single VLAN header (double VLAN tagged packets return
+    /// one layer per VLAN header).
+    Vlan(SingleVlanSlice<'a>),
+    /// PPPoE header.
+    Pppoe(PppoeHeaderSlice<'a>),
+    /// ARP packet.
+    Arp(ArpPacketSlice<'a>),
+    /// IPv4 header, extension headers & payload.
+    Ipv4(Ipv4Slice<'a>),
+    /// IPv6 header, extension headers & payload.
+    Ipv6(Ipv6Slice<'a>),
+    /// ICMPv4 header & payload.
+    Icmpv4(Icmpv4Slice<'a>),
+    /// ICMPv6 header & payload.
+    Icmpv6(Icmpv6Slice<'a>),
+    /// UDP header & payload.
+    Udp(UdpSlice<'a>),
+    /// TCP header & payload.
+    Tcp(TcpSlice<'a>),
+    /// IGMP packet.
+    Igmp(IgmpSlice<'a>),
+    /// IPsec ESP header & encrypted payload.
+    Esp(EspSlice<'a>),
+    /// DCCP header & payload.
+    Dccp(DccpSlice<'a>),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let header: UdpHeader = Default::default();
+        let raw = header.to_bytes();
+        let udp = UdpSlice::from_slice(&raw).unwrap();
+        let layer = LayerSlice::Udp(udp.clone());
+        assert_eq!(layer.clone(), layer);
+        assert_eq!(format!("{:?}", layer), format!("Udp({:?})", udp));
+    }
+}
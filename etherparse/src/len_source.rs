@@ -1,5 +1,6 @@
 /// Sources of length limiting values (e.g. "ipv6 payload length field").
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum LenSource {
     /// Limiting length was the slice length (we don't know what determined
     /// that one originally).
@@ -8,10 +9,23 @@ pub enum LenSource {
     Ipv4HeaderTotalLen,
     /// Error occurred in the IPv6 layer.
     Ipv6HeaderPayloadLen,
+    /// Length from the "Jumbo Payload" option in the IPv6 hop by hop header
+    /// (used instead of the zero "payload length" field of jumbograms).
+    Ipv6JumboPayloadLen,
+    /// Payload length passed by the caller to
+    /// [`crate::Ipv6Slice::from_slice_with_payload_len`] (used instead of
+    /// the "payload length" field of the IPv6 header).
+    Ipv6PayloadLenOverride,
     /// Error occurred while decoding an UDP header.
     UdpHeaderLen,
     /// Error occurred while decoding a TCP header.
     TcpHeaderLen,
+    /// Length field of a GTP-U header or GTP-U extension header.
+    GtpuHeaderLen,
+    /// "Opt Len" field of a Geneve header.
+    GeneveHeaderOptLen,
+    /// Length field of an IEEE 802.3 header.
+    Ieee8023Len,
 }
 
 #[cfg(test)]
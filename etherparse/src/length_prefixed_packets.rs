@@ -0,0 +1,240 @@
+use crate::{
+    err::{packet::SliceError, Layer, LenError},
+    LenSource, SlicedPacket,
+};
+
+/// Iterator over a buffer of Ethernet II frames that are each preceded
+/// by a 2 byte big endian length (a common framing when forwarding
+/// packets over a TCP stream).
+///
+/// Each frame is sliced via [`SlicedPacket::from_ethernet`]. Length errors
+/// of the returned [`SliceError`]s contain offsets relative to the start of
+/// the buffer. A slicing error in one frame does not stop the iteration,
+/// as the following frames can still be located via their length prefix.
+///
+/// The iteration ends when the end of the buffer is reached. If the
+/// buffer ends in the middle of a length prefix or a frame is longer than
+/// the rest of the buffer, an error with the layer
+/// [`Layer::LengthPrefixedFrame`] is returned & the iteration stops.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{LengthPrefixedPackets, PacketBuilder};
+///
+/// let builder = PacketBuilder::ethernet2([1; 6], [2; 6])
+///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///     .udp(21, 1234);
+/// let mut packet = Vec::new();
+/// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+///
+/// // two length prefixed frames
+/// let mut buffer = Vec::new();
+/// for _ in 0..2 {
+///     buffer.extend_from_slice(&(packet.len() as u16).to_be_bytes());
+///     buffer.extend_from_slice(&packet);
+/// }
+///
+/// let mut count = 0;
+/// for sliced in LengthPrefixedPackets::new(&buffer) {
+///     let sliced = sliced.unwrap();
+///     assert!(sliced.transport.is_some());
+///     count += 1;
+/// }
+/// assert_eq!(count, 2);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LengthPrefixedPackets<'a> {
+    rest: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> LengthPrefixedPackets<'a> {
+    /// Length of the length prefix in front of each frame in bytes/octets.
+    pub const PREFIX_LEN: usize = 2;
+
+    /// Creates an iterator over the length prefixed frames in the given buffer.
+    #[inline]
+    pub fn new(data: &'a [u8]) -> LengthPrefixedPackets<'a> {
+        LengthPrefixedPackets {
+            rest: data,
+            offset: 0,
+        }
+    }
+
+    /// Offset of the next length prefix in the buffer.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Remaining (not yet decoded) part of the buffer.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for LengthPrefixedPackets<'a> {
+    type Item = Result<SlicedPacket<'a>, SliceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        if self.rest.len() < LengthPrefixedPackets::PREFIX_LEN {
+            let err = LenError {
+                required_len: LengthPrefixedPackets::PREFIX_LEN,
+                len: self.rest.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::LengthPrefixedFrame,
+                layer_start_offset: self.offset,
+            };
+            self.rest = &[];
+            return Some(Err(SliceError::Len(err)));
+        }
+
+        let frame_len = LengthPrefixedPackets::PREFIX_LEN
+            + usize::from(u16::from_be_bytes([self.rest[0], self.rest[1]]));
+        if self.rest.len() < frame_len {
+            let err = LenError {
+                required_len: frame_len,
+                len: self.rest.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::LengthPrefixedFrame,
+                layer_start_offset: self.offset,
+            };
+            self.rest = &[];
+            return Some(Err(SliceError::Len(err)));
+        }
+
+        let frame = &self.rest[LengthPrefixedPackets::PREFIX_LEN..frame_len];
+        let frame_offset = self.offset + LengthPrefixedPackets::PREFIX_LEN;
+        self.rest = &self.rest[frame_len..];
+        self.offset += frame_len;
+
+        Some(SlicedPacket::from_ethernet(frame).map_err(|err| match err {
+            SliceError::Len(err) => SliceError::Len(err.add_offset(frame_offset)),
+            err => err,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::*;
+    use alloc::{format, vec::Vec};
+
+    fn frame(packet: &[u8]) -> Vec<u8> {
+        let mut result = Vec::with_capacity(2 + packet.len());
+        result.extend_from_slice(&(packet.len() as u16).to_be_bytes());
+        result.extend_from_slice(packet);
+        result
+    }
+
+    fn udp_packet(payload: &[u8]) -> Vec<u8> {
+        let builder = PacketBuilder::ethernet2([1; 6], [2; 6])
+            .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+            .udp(21, 1234);
+        let mut result = Vec::new();
+        builder.write(&mut result, payload).unwrap();
+        result
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = LengthPrefixedPackets::new(&[]);
+        assert_eq!(iter, iter.clone());
+        assert_eq!(
+            "LengthPrefixedPackets { rest: [], offset: 0 }",
+            format!("{:?}", iter)
+        );
+    }
+
+    #[test]
+    fn new() {
+        let data = [1, 2, 3];
+        let iter = LengthPrefixedPackets::new(&data);
+        assert_eq!(iter.offset(), 0);
+        assert_eq!(iter.rest(), &data);
+    }
+
+    #[test]
+    fn iter() {
+        let a = udp_packet(&[1, 2, 3]);
+        let b = udp_packet(&[4, 5]);
+        let mut data = Vec::new();
+        data.extend_from_slice(&frame(&a));
+        data.extend_from_slice(&frame(&b));
+
+        let mut iter = LengthPrefixedPackets::new(&data);
+        assert_eq!(iter.next(), Some(SlicedPacket::from_ethernet(&a)));
+        assert_eq!(iter.offset(), 2 + a.len());
+        assert_eq!(iter.rest(), &frame(&b)[..]);
+        assert_eq!(iter.next(), Some(SlicedPacket::from_ethernet(&b)));
+        assert_eq!(iter.offset(), 4 + a.len() + b.len());
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_slice_error() {
+        // a slicing error in one frame does not stop the iteration
+        let a = udp_packet(&[1, 2, 3]);
+        let b = udp_packet(&[4, 5]);
+        let mut data = Vec::new();
+        data.extend_from_slice(&frame(&a[..a.len() - 4]));
+        data.extend_from_slice(&frame(&b));
+
+        let expected = match SlicedPacket::from_ethernet(&a[..a.len() - 4]).unwrap_err() {
+            SliceError::Len(err) => SliceError::Len(err.add_offset(2)),
+            _ => panic!("expected a length error"),
+        };
+        let mut iter = LengthPrefixedPackets::new(&data);
+        assert_eq!(iter.next(), Some(Err(expected)));
+        assert_eq!(iter.next(), Some(SlicedPacket::from_ethernet(&b)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_len_errors() {
+        let a = udp_packet(&[1, 2, 3]);
+        let mut data = frame(&a);
+        data.extend_from_slice(&frame(&a));
+
+        // length prefix too short
+        {
+            let mut iter = LengthPrefixedPackets::new(&data[..2 + a.len() + 1]);
+            assert!(iter.next().unwrap().is_ok());
+            assert_eq!(
+                iter.next(),
+                Some(Err(SliceError::Len(LenError {
+                    required_len: 2,
+                    len: 1,
+                    len_source: LenSource::Slice,
+                    layer: Layer::LengthPrefixedFrame,
+                    layer_start_offset: 2 + a.len(),
+                })))
+            );
+            assert_eq!(iter.next(), None);
+        }
+
+        // frame overruns the buffer
+        for len in 2..2 + a.len() {
+            let mut iter = LengthPrefixedPackets::new(&data[..len]);
+            assert_eq!(
+                iter.next(),
+                Some(Err(SliceError::Len(LenError {
+                    required_len: 2 + a.len(),
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: Layer::LengthPrefixedFrame,
+                    layer_start_offset: 0,
+                })))
+            );
+            assert_eq!(iter.next(), None);
+        }
+    }
+}
@@ -2,12 +2,33 @@
 //!
 //! Currently supported are:
 //! * Ethernet II
+//! * IEEE 802.3 with IEEE 802.2 LLC & SNAP header (only decoded by [`SlicedPacket`] & [`LaxSlicedPacket`])
 //! * IEEE 802.1Q VLAN Tagging Header
+//! * PPPoE (only decoded by [`SlicedPacket`])
+//! * MPLS label stack (not decoded by [`SlicedPacket`], use [`MplsLabelStackSlice`])
+//! * ARP (only decoded by [`SlicedPacket`])
 //! * IPv4
 //! * IPv6 (supporting the most common extension headers, but not all)
+//! * GRE (only decoded by [`PacketHeaders`])
 //! * UDP
 //! * TCP
+//! * SCTP (common header & chunks, not part of the automatic packet parsing)
+//! * DCCP (only decoded by [`SlicedPacket`])
+//! * VXLAN (only decoded by [`SlicedPacket`] if enabled via [`ParseOptions::vxlan_port`])
+//! * GTP-U (only decoded by [`SlicedPacket`] if enabled via [`ParseOptions::gtpu_port`])
+//! * Geneve (only decoded by [`SlicedPacket`] if enabled via [`ParseOptions::geneve_port`])
+//! * DHCPv4 (not part of the automatic packet parsing, use [`Dhcpv4Message`])
 //! * ICMP & ICMPv6 (not all message types are supported)
+//! * IGMP (IGMPv1 & IGMPv2 messages & IGMPv3 membership reports, only decoded by [`SlicedPacket`])
+//! * IPsec ESP (only the unencrypted header, only decoded by [`SlicedPacket`])
+//!
+//! The optional `serde` feature adds `Serialize` & `Deserialize` implementations
+//! to the IPv4, IPv6, IPv6 fragment, UDP & TCP headers (newtypes like
+//! [`IpNumber`] & [`IpFragOffset`] are serialized as their inner value).
+//!
+//! The optional `defmt` feature adds [`defmt::Format`](https://docs.rs/defmt) implementations
+//! to the owned header types & the error types (excluding the `std` only errors)
+//! that output the same fields as the `Debug` implementations.
 //!
 //! # Usage
 //!
@@ -68,6 +89,9 @@
 //! * [`SlicedPacket::from_ether_type`] for parsing a slice starting after an Ethernet II header
 //! * [`SlicedPacket::from_ip`] for parsing from an IPv4 or IPv6 downwards
 //!
+//! If you only need some of the layers you can also use a [`PacketSlicer`], which decodes
+//! the layers one at a time (see [`PacketSlicer::next_layer`]).
+//!
 //! In case you want to parse cut off packets (e.g. packets returned in in ICMP message) you can use the "lax" parsing methods:
 //!
 //! * [`LaxSlicedPacket::from_ethernet`] for parsing from an Ethernet II header downwards
@@ -128,8 +152,11 @@
 //! * [`Ipv6Slice::from_slice`] & [`LaxIpv6Slice::from_slice`]
 //! * [`UdpSlice::from_slice`] & [`UdpSlice::from_slice_lax`]
 //! * [`TcpSlice::from_slice`]
+//! * [`DccpSlice::from_slice`]
 //! * [`Icmpv4Slice::from_slice`]
 //! * [`Icmpv6Slice::from_slice`]
+//! * [`IgmpSlice::from_slice`]
+//! * [`EspSlice::from_slice`]
 //!
 //! The resulting data types allow access to both the header(s) and the payload of the layer
 //! and will automatically limit the length of payload if the layer has a length field limiting the
@@ -144,21 +171,32 @@
 //! * [`Ethernet2HeaderSlice::from_slice`]
 //! * [`SingleVlanHeaderSlice::from_slice`]
 //! * [`DoubleVlanHeaderSlice::from_slice`]
+//! * [`ArpPacketSlice::from_slice`]
 //! * [`Ipv4HeaderSlice::from_slice`]
 //! * [`Ipv4ExtensionsSlice::from_slice`]
 //! * [`Ipv6HeaderSlice::from_slice`]
 //! * [`Ipv6ExtensionsSlice::from_slice`]
 //! * [`Ipv6RawExtHeaderSlice::from_slice`]
 //! * [`IpAuthHeaderSlice::from_slice`]
+//! * [`EspHeaderSlice::from_slice`]
 //! * [`Ipv6FragmentHeaderSlice::from_slice`]
+//! * [`PppoeHeaderSlice::from_slice`]
+//! * [`MplsLabelStackSlice::from_slice`]
+//! * [`GreHeaderSlice::from_slice`]
 //! * [`UdpHeaderSlice::from_slice`]
 //! * [`TcpHeaderSlice::from_slice`]
+//! * [`SctpHeaderSlice::from_slice`]
+//! * [`DccpHeaderSlice::from_slice`]
+//! * [`VxlanHeaderSlice::from_slice`]
+//! * [`GtpuHeaderSlice::from_slice`]
+//! * [`GeneveHeaderSlice::from_slice`]
 //!
 //! And for deserialization into the corresponding header structs have a look at:
 //!
 //! * [`Ethernet2Header::read`] & [`Ethernet2Header::from_slice`]
 //! * [`SingleVlanHeader::read`] & [`SingleVlanHeader::from_slice`]
 //! * [`DoubleVlanHeader::read`] & [`DoubleVlanHeader::from_slice`]
+//! * [`ArpPacket::read`] & [`ArpPacket::from_slice`]
 //! * [`IpHeaders::read`] & [`IpHeaders::from_slice`]
 //! * [`Ipv4Header::read`] & [`Ipv4Header::from_slice`]
 //! * [`Ipv4Extensions::read`] & [`Ipv4Extensions::from_slice`]
@@ -166,9 +204,18 @@
 //! * [`Ipv6Extensions::read`] & [`Ipv6Extensions::from_slice`]
 //! * [`Ipv6RawExtHeader::read`] & [`Ipv6RawExtHeader::from_slice`]
 //! * [`IpAuthHeader::read`] & [`IpAuthHeader::from_slice`]
+//! * [`EspHeader::read`] & [`EspHeader::from_slice`]
 //! * [`Ipv6FragmentHeader::read`] & [`Ipv6FragmentHeader::from_slice`]
+//! * [`PppoeHeader::read`] & [`PppoeHeader::from_slice`]
+//! * [`MplsHeader::read`] & [`MplsHeader::from_slice`]
+//! * [`GreHeader::read`] & [`GreHeader::from_slice`]
 //! * [`UdpHeader::read`] & [`UdpHeader::from_slice`]
 //! * [`TcpHeader::read`] & [`TcpHeader::from_slice`]
+//! * [`SctpHeader::read`] & [`SctpHeader::from_slice`]
+//! * [`DccpHeader::read`] & [`DccpHeader::from_slice`]
+//! * [`VxlanHeader::read`] & [`VxlanHeader::from_slice`]
+//! * [`GtpuHeader::read`] & [`GtpuHeader::from_slice`]
+//! * [`GeneveHeader::read`] & [`GeneveHeader::from_slice`]
 //! * [`Icmpv4Header::read`] & [`Icmpv4Header::from_slice`]
 //! * [`Icmpv6Header::read`] & [`Icmpv6Header::from_slice`]
 //!
@@ -222,15 +269,25 @@
 //! * [`Ethernet2Header::to_bytes`] & [`Ethernet2Header::write`]
 //! * [`SingleVlanHeader::to_bytes`] & [`SingleVlanHeader::write`]
 //! * [`DoubleVlanHeader::to_bytes`] & [`DoubleVlanHeader::write`]
+//! * [`ArpPacket::to_bytes`] & [`ArpPacket::write`]
 //! * [`Ipv4Header::to_bytes`] & [`Ipv4Header::write`] & [`Ipv4Header::write_raw`]
 //! * [`Ipv4Extensions::write`]
 //! * [`Ipv6Header::to_bytes`] & [`Ipv6Header::write`]
 //! * [`Ipv6Extensions::write`]
 //! * [`Ipv6RawExtHeader::to_bytes`] & [`Ipv6RawExtHeader::write`]
 //! * [`IpAuthHeader::to_bytes`] & [`IpAuthHeader::write`]
+//! * [`EspHeader::to_bytes`] & [`EspHeader::write`]
 //! * [`Ipv6FragmentHeader::to_bytes`] & [`Ipv6FragmentHeader::write`]
+//! * [`PppoeHeader::to_bytes`] & [`PppoeHeader::write`]
+//! * [`MplsHeader::to_bytes`] & [`MplsHeader::write`]
+//! * [`GreHeader::to_bytes`] & [`GreHeader::write`]
 //! * [`UdpHeader::to_bytes`] & [`UdpHeader::write`]
 //! * [`TcpHeader::to_bytes`] & [`TcpHeader::write`]
+//! * [`SctpHeader::to_bytes`] & [`SctpHeader::write`]
+//! * [`DccpHeader::to_bytes`] & [`DccpHeader::write`]
+//! * [`VxlanHeader::to_bytes`] & [`VxlanHeader::write`]
+//! * [`GtpuHeader::to_bytes`] & [`GtpuHeader::write`]
+//! * [`GeneveHeader::to_bytes`] & [`GeneveHeader::write`]
 //! * [`Icmpv4Header::to_bytes`] & [`Icmpv4Header::write`]
 //! * [`Icmpv6Header::to_bytes`] & [`Icmpv6Header::write`]
 //!
@@ -240,13 +297,27 @@
 //! * [IANA 802 EtherTypes](https://www.iana.org/assignments/ieee-802-numbers/ieee-802-numbers.xhtml)
 //! * [IANA Protocol Numbers](https://www.iana.org/assignments/protocol-numbers/protocol-numbers.xhtml)
 //! * [Internet Protocol Version 6 (IPv6) Parameters](https://www.iana.org/assignments/ipv6-parameters/ipv6-parameters.xhtml)
+//! * An Ethernet Address Resolution Protocol [RFC 826](https://datatracker.ietf.org/doc/html/rfc826)
+//! * MPLS Label Stack Encoding [RFC 3032](https://datatracker.ietf.org/doc/html/rfc3032)
+//! * A Method for Transmitting PPP Over Ethernet (PPPoE) [RFC 2516](https://datatracker.ietf.org/doc/html/rfc2516)
+//! * A Standard for the Transmission of IP Datagrams over IEEE 802 Networks [RFC 1042](https://datatracker.ietf.org/doc/html/rfc1042)
 //! * [Wikipedia IEEE_802.1Q](https://en.wikipedia.org/w/index.php?title=IEEE_802.1Q&oldid=820983900)
 //! * User Datagram Protocol (UDP) [RFC 768](https://tools.ietf.org/html/rfc768)
 //! * Transmission Control Protocol [RFC 793](https://tools.ietf.org/html/rfc793)
 //! * TCP Extensions for High Performance [RFC 7323](https://tools.ietf.org/html/rfc7323)
+//! * Generic Routing Encapsulation (GRE) [RFC 2784](https://datatracker.ietf.org/doc/html/rfc2784)
+//! * Key and Sequence Number Extensions to GRE [RFC 2890](https://datatracker.ietf.org/doc/html/rfc2890)
+//! * Stream Control Transmission Protocol [RFC 9260](https://datatracker.ietf.org/doc/html/rfc9260)
+//! * Datagram Congestion Control Protocol (DCCP) [RFC 4340](https://datatracker.ietf.org/doc/html/rfc4340)
+//! * Virtual eXtensible Local Area Network (VXLAN) [RFC 7348](https://datatracker.ietf.org/doc/html/rfc7348)
+//! * GPRS Tunnelling Protocol User Plane (GTP-U) [3GPP TS 29.281](https://www.3gpp.org/DynaReport/29281.htm)
+//! * Geneve: Generic Network Virtualization Encapsulation [RFC 8926](https://datatracker.ietf.org/doc/html/rfc8926)
+//! * Dynamic Host Configuration Protocol [RFC 2131](https://datatracker.ietf.org/doc/html/rfc2131)
+//! * DHCP Options and BOOTP Vendor Extensions [RFC 2132](https://datatracker.ietf.org/doc/html/rfc2132)
 //! * The Addition of Explicit Congestion Notification (ECN) to IP [RFC 3168](https://tools.ietf.org/html/rfc3168)
 //! * Robust Explicit Congestion Notification (ECN) Signaling with Nonces [RFC 3540](https://tools.ietf.org/html/rfc3540)
 //! * IP Authentication Header [RFC 4302](https://tools.ietf.org/html/rfc4302)
+//! * IP Encapsulating Security Payload (ESP) [RFC 4303](https://tools.ietf.org/html/rfc4303)
 //! * Mobility Support in IPv6 [RFC 6275](https://tools.ietf.org/html/rfc6275)
 //! * Host Identity Protocol Version 2 (HIPv2) [RFC 7401](https://tools.ietf.org/html/rfc7401)
 //! * Shim6: Level 3 Multihoming Shim Protocol for IPv6 [RFC 5533](https://tools.ietf.org/html/rfc5533)
@@ -260,6 +331,8 @@
 //! * [Internet Control Message Protocol version 6 (ICMPv6) Parameters](https://www.iana.org/assignments/icmpv6-parameters/icmpv6-parameters.xhtml)
 //! * Multicast Listener Discovery (MLD) for IPv6 [RFC 2710](https://datatracker.ietf.org/doc/html/rfc2710)
 //! * Neighbor Discovery for IP version 6 (IPv6) [RFC 4861](https://datatracker.ietf.org/doc/html/rfc4861)
+//! * Internet Group Management Protocol, Version 2 [RFC 2236](https://datatracker.ietf.org/doc/html/rfc2236)
+//! * Internet Group Management Protocol, Version 3 [RFC 3376](https://datatracker.ietf.org/doc/html/rfc3376)
 
 // # Reason for 'bool_comparison' disable:
 //
@@ -301,10 +374,23 @@ pub use crate::link::ether_type_impl::*;
 pub use crate::link::ethernet2_header::*;
 pub use crate::link::ethernet2_header_slice::*;
 pub use crate::link::ethernet2_slice::*;
+pub use crate::link::ieee8023_slice::*;
 pub use crate::link::link_slice::*;
+pub use crate::link::linux_sll2_header::*;
+pub use crate::link::linux_sll2_slice::*;
+pub use crate::link::linux_sll_header::*;
+pub use crate::link::linux_sll_packet_type::*;
+pub use crate::link::linux_sll_slice::*;
+pub use crate::link::llc_header::*;
+pub use crate::link::mpls_header::*;
+pub use crate::link::mpls_label_stack_iter::*;
+pub use crate::link::mpls_label_stack_slice::*;
+pub use crate::link::pppoe_header::*;
+pub use crate::link::pppoe_header_slice::*;
 pub use crate::link::single_vlan_header::*;
 pub use crate::link::single_vlan_header_slice::*;
 pub use crate::link::single_vlan_slice::*;
+pub use crate::link::snap_header::*;
 pub use crate::link::vlan_header::*;
 pub use crate::link::vlan_id::*;
 pub use crate::link::vlan_pcp::*;
@@ -321,15 +407,45 @@ pub use net::*;
 pub mod io;
 
 mod transport;
+pub use crate::transport::dccp_header::*;
+pub use crate::transport::dccp_header_slice::*;
+pub use crate::transport::dccp_slice::*;
+pub use crate::transport::dhcpv4_message::*;
+pub use crate::transport::dhcpv4_options_iter::*;
+pub use crate::transport::geneve_header::*;
+pub use crate::transport::geneve_header_slice::*;
+pub use crate::transport::geneve_options_iterator::*;
+pub use crate::transport::geneve_slice::*;
+pub use crate::transport::gtpu_extension_header_iterator::*;
+pub use crate::transport::gtpu_extension_header_slice::*;
+pub use crate::transport::gtpu_header::*;
+pub use crate::transport::gtpu_header_slice::*;
+pub use crate::transport::gtpu_slice::*;
 pub use crate::transport::icmp_echo_header::*;
 pub use crate::transport::icmpv4;
+pub use crate::transport::icmpv4_error_payload::*;
 pub use crate::transport::icmpv4_header::*;
 pub use crate::transport::icmpv4_slice::*;
 pub use crate::transport::icmpv4_type::*;
 pub use crate::transport::icmpv6;
+pub use crate::transport::icmpv6_error_payload::*;
 pub use crate::transport::icmpv6_header::*;
 pub use crate::transport::icmpv6_slice::*;
 pub use crate::transport::icmpv6_type::*;
+pub use crate::transport::igmp;
+pub use crate::transport::igmp_message::*;
+pub use crate::transport::igmp_slice::*;
+pub use crate::transport::igmpv2_message::*;
+pub use crate::transport::igmpv3_group_record::*;
+pub use crate::transport::igmpv3_group_record_iter::*;
+pub use crate::transport::igmpv3_membership_report::*;
+pub use crate::transport::igmpv3_source_address_iter::*;
+pub use crate::transport::sctp_chunk_iter::*;
+pub use crate::transport::sctp_header::*;
+pub use crate::transport::sctp_header_slice::*;
+pub use crate::transport::tcp_authentication_mac::*;
+pub use crate::transport::tcp_fast_open_cookie::*;
+pub use crate::transport::tcp_flag_anomaly::*;
 pub use crate::transport::tcp_header::*;
 pub use crate::transport::tcp_header_slice::*;
 pub use crate::transport::tcp_option_element::*;
@@ -344,13 +460,29 @@ pub use crate::transport::transport_slice::*;
 pub use crate::transport::udp_header::*;
 pub use crate::transport::udp_header_slice::*;
 pub use crate::transport::udp_slice::*;
+pub use crate::transport::vxlan_header::*;
+pub use crate::transport::vxlan_header_slice::*;
+pub use crate::transport::vxlan_slice::*;
+pub use crate::transport::vxlan_vni::*;
 
 /// Helpers for calculating checksums.
 pub mod checksum;
 
+#[cfg(feature = "std")]
+mod checksum_mode;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::checksum_mode::*;
+
 #[cfg(test)]
 mod compositions_tests;
 
+mod flow_tuple;
+pub use crate::flow_tuple::*;
+
+mod guessed_link_type;
+pub use crate::guessed_link_type::*;
+
 mod helpers;
 pub(crate) use helpers::*;
 
@@ -366,9 +498,23 @@ pub use lax_sliced_packet::*;
 mod lax_sliced_packet_cursor;
 pub(crate) use lax_sliced_packet_cursor::*;
 
+mod layer_set;
+pub use layer_set::*;
+
+mod layer_slice;
+pub use layer_slice::*;
+
 mod len_source;
 pub use len_source::*;
 
+mod length_prefixed_packets;
+pub use length_prefixed_packets::*;
+
+#[cfg(feature = "std")]
+mod owned_packet_headers;
+#[cfg(feature = "std")]
+pub use crate::owned_packet_headers::*;
+
 #[cfg(feature = "std")]
 mod packet_builder;
 #[cfg(feature = "std")]
@@ -377,9 +523,23 @@ pub use crate::packet_builder::*;
 mod packet_headers;
 pub use crate::packet_headers::*;
 
+mod packet_slicer;
+pub use crate::packet_slicer::*;
+
+mod parse_options;
+pub use crate::parse_options::*;
+
 mod payload_slice;
 pub use crate::payload_slice::*;
 
+/// Minimal reader for classic pcap files stored in memory.
+pub mod pcap;
+
+/// Helpers for reassembling fragmented IP packets.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod reassembly;
+
 mod sliced_packet;
 pub use crate::sliced_packet::*;
 
@@ -44,6 +44,17 @@ impl<'a> DoubleVlanSlice<'a> {
         self.slice
     }
 
+    /// Slice containing the outer & inner VLAN header.
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        unsafe {
+            // SAFETY:
+            // Safe as the contructor checks that the slice has
+            // at least the length of DoubleVlanHeader::LEN (8).
+            core::slice::from_raw_parts(self.slice.as_ptr(), DoubleVlanHeader::LEN)
+        }
+    }
+
     /// Outer VLAN header & payload (includes header of inner vlan header).
     #[inline]
     pub fn outer(&self) -> SingleVlanSlice {
@@ -209,6 +220,7 @@ mod test {
             {
                 let slice = DoubleVlanSlice::from_slice(&data).unwrap();
                 assert_eq!(slice.to_header(), vlan);
+                assert_eq!(slice.header_slice(), &data[..DoubleVlanHeader::LEN]);
                 assert_eq!(slice.payload_slice(), &payload);
             }
 
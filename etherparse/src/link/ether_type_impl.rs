@@ -35,9 +35,45 @@ impl EtherType {
     pub const IPV6: EtherType = Self(0x86dd);
     pub const ARP: EtherType = Self(0x0806);
     pub const WAKE_ON_LAN: EtherType = Self(0x0842);
+    pub const TRANSPARENT_ETHERNET_BRIDGING: EtherType = Self(0x6558);
     pub const VLAN_TAGGED_FRAME: EtherType = Self(0x8100);
+    pub const MPLS_UNICAST: EtherType = Self(0x8847);
+    pub const MPLS_MULTICAST: EtherType = Self(0x8848);
+    pub const PPPOE_DISCOVERY: EtherType = Self(0x8863);
+    pub const PPPOE_SESSION: EtherType = Self(0x8864);
     pub const PROVIDER_BRIDGING: EtherType = Self(0x88A8);
     pub const VLAN_DOUBLE_TAGGED_FRAME: EtherType = Self(0x9100);
+
+    /// Returns a short human readable name of the ether type
+    /// (`None` is returned if the ether type has no constant defined
+    /// in [`crate::ether_type`]).
+    ///
+    /// ```
+    /// use etherparse::EtherType;
+    ///
+    /// assert_eq!(EtherType::IPV4.name(), Some("IPv4"));
+    /// assert_eq!(EtherType::PROVIDER_BRIDGING.name(), Some("QinQ"));
+    ///
+    /// // unknown values return None
+    /// assert_eq!(EtherType(0x1234).name(), None);
+    /// ```
+    pub fn name(self) -> Option<&'static str> {
+        match self {
+            Self::IPV4 => Some("IPv4"),
+            Self::IPV6 => Some("IPv6"),
+            Self::ARP => Some("ARP"),
+            Self::WAKE_ON_LAN => Some("Wake-on-LAN"),
+            Self::TRANSPARENT_ETHERNET_BRIDGING => Some("TEB"),
+            Self::VLAN_TAGGED_FRAME => Some("VLAN"),
+            Self::MPLS_UNICAST => Some("MPLS"),
+            Self::MPLS_MULTICAST => Some("MPLS multicast"),
+            Self::PPPOE_DISCOVERY => Some("PPPoE Discovery"),
+            Self::PPPOE_SESSION => Some("PPPoE Session"),
+            Self::PROVIDER_BRIDGING => Some("QinQ"),
+            Self::VLAN_DOUBLE_TAGGED_FRAME => Some("VLAN double tagged"),
+            _ => None,
+        }
+    }
 }
 
 impl From<u16> for EtherType {
@@ -61,11 +97,18 @@ impl core::fmt::Debug for EtherType {
             Self::IPV6 => write!(f, "{:#06X} (Internet Protocol Version 6 (IPV6))", self.0),
             Self::ARP => write!(f, "{:#06X} (Address Resolution Protocol (ARP))", self.0),
             Self::WAKE_ON_LAN => write!(f, "{:#06X} (Wake on LAN)", self.0),
+            Self::TRANSPARENT_ETHERNET_BRIDGING => {
+                write!(f, "{:#06X} (Transparent Ethernet Bridging)", self.0)
+            }
             Self::VLAN_TAGGED_FRAME => write!(
                 f,
                 "{:#06X} (Customer VLAN Tag (C-TAG) as defined in IEEE Std 802.1Q)",
                 self.0
             ),
+            Self::MPLS_UNICAST => write!(f, "{:#06X} (MPLS Unicast)", self.0),
+            Self::MPLS_MULTICAST => write!(f, "{:#06X} (MPLS Multicast)", self.0),
+            Self::PPPOE_DISCOVERY => write!(f, "{:#06X} (PPPoE Discovery Stage)", self.0),
+            Self::PPPOE_SESSION => write!(f, "{:#06X} (PPPoE Session Stage)", self.0),
             Self::PROVIDER_BRIDGING => write!(
                 f,
                 "{:#06X} (IEEE Std 802.1Q - Service VLAN tag identifier (S-Tag))",
@@ -79,6 +122,70 @@ impl core::fmt::Debug for EtherType {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for EtherType {
+    fn format(&self, f: defmt::Formatter) {
+        match *self {
+            Self::IPV4 => defmt::write!(
+                f,
+                "{=u16:#06X} (Internet Protocol version 4 (IPv4))",
+                self.0
+            ),
+            Self::IPV6 => defmt::write!(
+                f,
+                "{=u16:#06X} (Internet Protocol Version 6 (IPV6))",
+                self.0
+            ),
+            Self::ARP => {
+                defmt::write!(f, "{=u16:#06X} (Address Resolution Protocol (ARP))", self.0)
+            }
+            Self::WAKE_ON_LAN => defmt::write!(f, "{=u16:#06X} (Wake on LAN)", self.0),
+            Self::TRANSPARENT_ETHERNET_BRIDGING => {
+                defmt::write!(f, "{=u16:#06X} (Transparent Ethernet Bridging)", self.0)
+            }
+            Self::VLAN_TAGGED_FRAME => defmt::write!(
+                f,
+                "{=u16:#06X} (Customer VLAN Tag (C-TAG) as defined in IEEE Std 802.1Q)",
+                self.0
+            ),
+            Self::MPLS_UNICAST => defmt::write!(f, "{=u16:#06X} (MPLS Unicast)", self.0),
+            Self::MPLS_MULTICAST => defmt::write!(f, "{=u16:#06X} (MPLS Multicast)", self.0),
+            Self::PPPOE_DISCOVERY => {
+                defmt::write!(f, "{=u16:#06X} (PPPoE Discovery Stage)", self.0)
+            }
+            Self::PPPOE_SESSION => defmt::write!(f, "{=u16:#06X} (PPPoE Session Stage)", self.0),
+            Self::PROVIDER_BRIDGING => defmt::write!(
+                f,
+                "{=u16:#06X} (IEEE Std 802.1Q - Service VLAN tag identifier (S-Tag))",
+                self.0
+            ),
+            Self::VLAN_DOUBLE_TAGGED_FRAME => {
+                defmt::write!(f, "{=u16:#06X} (VLAN Double Tagged Frame)", self.0)
+            }
+            _ => defmt::write!(f, "{=u16:#06X}", self.0),
+        }
+    }
+}
+
+/// Writes the name of the ether type (see [`EtherType::name`]) followed
+/// by the hex value or only the hex value if the ether type is unknown.
+///
+/// ```
+/// use etherparse::EtherType;
+///
+/// assert_eq!("IPv6 (0x86DD)", format!("{}", EtherType::IPV6));
+/// assert_eq!("0x1234", format!("{}", EtherType(0x1234)));
+/// ```
+impl core::fmt::Display for EtherType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(name) = self.name() {
+            write!(f, "{} ({:#06X})", name, self.0)
+        } else {
+            write!(f, "{:#06X}", self.0)
+        }
+    }
+}
+
 /// Constants for the ethertype values for easy importing (e.g. `use ether_type::*;`).
 ///
 /// The constants only exist for convenience so you can import them
@@ -103,7 +210,12 @@ pub mod ether_type {
     pub const IPV6: EtherType = EtherType::IPV6;
     pub const ARP: EtherType = EtherType::ARP;
     pub const WAKE_ON_LAN: EtherType = EtherType::WAKE_ON_LAN;
+    pub const TRANSPARENT_ETHERNET_BRIDGING: EtherType = EtherType::TRANSPARENT_ETHERNET_BRIDGING;
     pub const VLAN_TAGGED_FRAME: EtherType = EtherType::VLAN_TAGGED_FRAME;
+    pub const MPLS_UNICAST: EtherType = EtherType::MPLS_UNICAST;
+    pub const MPLS_MULTICAST: EtherType = EtherType::MPLS_MULTICAST;
+    pub const PPPOE_DISCOVERY: EtherType = EtherType::PPPOE_DISCOVERY;
+    pub const PPPOE_SESSION: EtherType = EtherType::PPPOE_SESSION;
     pub const PROVIDER_BRIDGING: EtherType = EtherType::PROVIDER_BRIDGING;
     pub const VLAN_DOUBLE_TAGGED_FRAME: EtherType = EtherType::VLAN_DOUBLE_TAGGED_FRAME;
 }
@@ -119,7 +231,12 @@ mod test {
         assert_eq!(0x86dd, u16::from(EtherType::IPV6));
         assert_eq!(0x0806, u16::from(EtherType::ARP));
         assert_eq!(0x0842, u16::from(EtherType::WAKE_ON_LAN));
+        assert_eq!(0x6558, u16::from(EtherType::TRANSPARENT_ETHERNET_BRIDGING));
         assert_eq!(0x8100, u16::from(EtherType::VLAN_TAGGED_FRAME));
+        assert_eq!(0x8847, u16::from(EtherType::MPLS_UNICAST));
+        assert_eq!(0x8848, u16::from(EtherType::MPLS_MULTICAST));
+        assert_eq!(0x8863, u16::from(EtherType::PPPOE_DISCOVERY));
+        assert_eq!(0x8864, u16::from(EtherType::PPPOE_SESSION));
         assert_eq!(0x88A8, u16::from(EtherType::PROVIDER_BRIDGING));
         assert_eq!(0x9100, u16::from(EtherType::VLAN_DOUBLE_TAGGED_FRAME));
     }
@@ -130,7 +247,15 @@ mod test {
         assert_eq!(EtherType::from(0x86dd), EtherType::IPV6);
         assert_eq!(EtherType::from(0x0806), EtherType::ARP);
         assert_eq!(EtherType::from(0x0842), EtherType::WAKE_ON_LAN);
+        assert_eq!(
+            EtherType::from(0x6558),
+            EtherType::TRANSPARENT_ETHERNET_BRIDGING
+        );
         assert_eq!(EtherType::from(0x8100), EtherType::VLAN_TAGGED_FRAME);
+        assert_eq!(EtherType::from(0x8847), EtherType::MPLS_UNICAST);
+        assert_eq!(EtherType::from(0x8848), EtherType::MPLS_MULTICAST);
+        assert_eq!(EtherType::from(0x8863), EtherType::PPPOE_DISCOVERY);
+        assert_eq!(EtherType::from(0x8864), EtherType::PPPOE_SESSION);
         assert_eq!(EtherType::from(0x88A8), EtherType::PROVIDER_BRIDGING);
         assert_eq!(EtherType::from(0x9100), EtherType::VLAN_DOUBLE_TAGGED_FRAME);
         assert_eq!(EtherType::from(0x1234), EtherType(0x1234));
@@ -144,7 +269,15 @@ mod test {
             (EtherType::IPV6, IPV6),
             (EtherType::ARP, ARP),
             (EtherType::WAKE_ON_LAN, WAKE_ON_LAN),
+            (
+                EtherType::TRANSPARENT_ETHERNET_BRIDGING,
+                TRANSPARENT_ETHERNET_BRIDGING,
+            ),
             (EtherType::VLAN_TAGGED_FRAME, VLAN_TAGGED_FRAME),
+            (EtherType::MPLS_UNICAST, MPLS_UNICAST),
+            (EtherType::MPLS_MULTICAST, MPLS_MULTICAST),
+            (EtherType::PPPOE_DISCOVERY, PPPOE_DISCOVERY),
+            (EtherType::PPPOE_SESSION, PPPOE_SESSION),
             (EtherType::PROVIDER_BRIDGING, PROVIDER_BRIDGING),
             (
                 EtherType::VLAN_DOUBLE_TAGGED_FRAME,
@@ -157,6 +290,54 @@ mod test {
         }
     }
 
+    #[test]
+    fn name_display() {
+        let pairs = &[
+            (EtherType::IPV4, Some("IPv4"), "IPv4 (0x0800)"),
+            (EtherType::IPV6, Some("IPv6"), "IPv6 (0x86DD)"),
+            (EtherType::ARP, Some("ARP"), "ARP (0x0806)"),
+            (
+                EtherType::WAKE_ON_LAN,
+                Some("Wake-on-LAN"),
+                "Wake-on-LAN (0x0842)",
+            ),
+            (
+                EtherType::TRANSPARENT_ETHERNET_BRIDGING,
+                Some("TEB"),
+                "TEB (0x6558)",
+            ),
+            (EtherType::VLAN_TAGGED_FRAME, Some("VLAN"), "VLAN (0x8100)"),
+            (EtherType::MPLS_UNICAST, Some("MPLS"), "MPLS (0x8847)"),
+            (
+                EtherType::MPLS_MULTICAST,
+                Some("MPLS multicast"),
+                "MPLS multicast (0x8848)",
+            ),
+            (
+                EtherType::PPPOE_DISCOVERY,
+                Some("PPPoE Discovery"),
+                "PPPoE Discovery (0x8863)",
+            ),
+            (
+                EtherType::PPPOE_SESSION,
+                Some("PPPoE Session"),
+                "PPPoE Session (0x8864)",
+            ),
+            (EtherType::PROVIDER_BRIDGING, Some("QinQ"), "QinQ (0x88A8)"),
+            (
+                EtherType::VLAN_DOUBLE_TAGGED_FRAME,
+                Some("VLAN double tagged"),
+                "VLAN double tagged (0x9100)",
+            ),
+            (EtherType(1), None, "0x0001"),
+        ];
+
+        for (ether_type, name, display) in pairs {
+            assert_eq!(*name, ether_type.name());
+            assert_eq!(*display, &format!("{}", ether_type));
+        }
+    }
+
     #[test]
     fn dbg() {
         let pairs = &[
@@ -170,10 +351,18 @@ mod test {
             ),
             (EtherType::ARP, "0x0806 (Address Resolution Protocol (ARP))"),
             (EtherType::WAKE_ON_LAN, "0x0842 (Wake on LAN)"),
+            (
+                EtherType::TRANSPARENT_ETHERNET_BRIDGING,
+                "0x6558 (Transparent Ethernet Bridging)",
+            ),
             (
                 EtherType::VLAN_TAGGED_FRAME,
                 "0x8100 (Customer VLAN Tag (C-TAG) as defined in IEEE Std 802.1Q)",
             ),
+            (EtherType::MPLS_UNICAST, "0x8847 (MPLS Unicast)"),
+            (EtherType::MPLS_MULTICAST, "0x8848 (MPLS Multicast)"),
+            (EtherType::PPPOE_DISCOVERY, "0x8863 (PPPoE Discovery Stage)"),
+            (EtherType::PPPOE_SESSION, "0x8864 (PPPoE Session Stage)"),
             (
                 EtherType::PROVIDER_BRIDGING,
                 "0x88A8 (IEEE Std 802.1Q - Service VLAN tag identifier (S-Tag))",
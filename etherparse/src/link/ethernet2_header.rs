@@ -1,7 +1,8 @@
 use crate::{err::Layer, err::SliceWriteSpaceError, *};
 
 /// Ethernet II header.
-#[derive(Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Ethernet2Header {
     /// Source MAC Address
     pub source: [u8; 6],
@@ -120,11 +121,44 @@ impl Ethernet2Header {
     }
 }
 
+/// Writes a compact single line summary of the header (MAC addresses
+/// in colon separated hex notation).
+///
+/// ```
+/// use etherparse::{EtherType, Ethernet2Header};
+///
+/// let header = Ethernet2Header {
+///     source: [0x00, 0x1b, 0x21, 0x0a, 0x0b, 0x0c],
+///     destination: [0xff; 6],
+///     ether_type: EtherType::IPV4,
+/// };
+/// assert_eq!(
+///     "Ethernet2 00:1b:21:0a:0b:0c -> ff:ff:ff:ff:ff:ff type=IPv4",
+///     format!("{}", header)
+/// );
+/// ```
+impl core::fmt::Display for Ethernet2Header {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let s = &self.source;
+        let d = &self.destination;
+        write!(
+            f,
+            "Ethernet2 {:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x} -> {:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+            s[0], s[1], s[2], s[3], s[4], s[5], d[0], d[1], d[2], d[3], d[4], d[5]
+        )?;
+        if let Some(name) = self.ether_type.name() {
+            write!(f, " type={}", name)
+        } else {
+            write!(f, " type={:#06X}", self.ether_type.0)
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::test_gens::*;
-    use alloc::{format, vec::Vec};
+    use alloc::{format, string::ToString, vec::Vec};
     use proptest::prelude::*;
     use std::io::{Cursor, ErrorKind};
 
@@ -302,6 +336,25 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn hash(input in ethernet_2_any()) {
+            use std::collections::hash_map::DefaultHasher;
+            use core::hash::{Hash, Hasher};
+            let a = {
+                let mut hasher = DefaultHasher::new();
+                input.hash(&mut hasher);
+                hasher.finish()
+            };
+            let b = {
+                let mut hasher = DefaultHasher::new();
+                input.clone().hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(a, b);
+        }
+    }
+
     proptest! {
         #[test]
         fn dbg(input in ethernet_2_any()) {
@@ -316,4 +369,39 @@ mod test {
             );
         }
     }
+
+    proptest! {
+        #[test]
+        fn display(input in ethernet_2_any()) {
+            let s = input.source;
+            let d = input.destination;
+            let ether_type = if let Some(name) = input.ether_type.name() {
+                name.to_string()
+            } else {
+                format!("{:#06X}", input.ether_type.0)
+            };
+            assert_eq!(
+                format!(
+                    "Ethernet2 {:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x} -> {:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x} type={}",
+                    s[0], s[1], s[2], s[3], s[4], s[5],
+                    d[0], d[1], d[2], d[3], d[4], d[5],
+                    ether_type
+                ),
+                format!("{}", input)
+            );
+        }
+    }
+
+    #[test]
+    fn display_unknown_ether_type() {
+        let header = Ethernet2Header {
+            source: [1, 2, 3, 4, 5, 6],
+            destination: [0xa, 0xb, 0xc, 0xd, 0xe, 0xf],
+            ether_type: EtherType(0x1234),
+        };
+        assert_eq!(
+            "Ethernet2 01:02:03:04:05:06 -> 0a:0b:0c:0d:0e:0f type=0x1234",
+            format!("{}", header)
+        );
+    }
 }
@@ -114,7 +114,7 @@ impl<'a> Ethernet2Slice<'a> {
     }
 
     /// Slice containing the Ethernet 2 header.
-    pub fn header_slice(&self) -> &[u8] {
+    pub fn header_slice(&self) -> &'a [u8] {
         unsafe {
             // SAFETY:
             // Safe as the contructor checks that the slice has
@@ -0,0 +1,302 @@
+use crate::{err::*, *};
+
+/// Slice containing an IEEE 802.3 frame (destination, source & length
+/// field followed by an IEEE 802.2 LLC header, an optional SNAP header
+/// & the payload).
+///
+/// IEEE 802.3 frames are distinguished from Ethernet II frames by the
+/// value of the ether type field. Values smaller or equal to
+/// [`Ieee8023Slice::MAX_PAYLOAD_LEN`] are the length of the data
+/// following the field (LLC header, SNAP header & payload).
+///
+/// The slice is cut to the length indicated by the length field
+/// (ethernet padding after the payload is not part of the slice).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ieee8023Slice<'a> {
+    /// Length of the 802.3, LLC & SNAP header.
+    header_len: usize,
+    /// Slice containing the headers & payload.
+    slice: &'a [u8],
+}
+
+impl<'a> Ieee8023Slice<'a> {
+    /// Length of the destination, source & length fields in bytes/octets.
+    pub const MAC_HEADER_LEN: usize = 14;
+
+    /// Maximum value of the length field (values above are ether types).
+    pub const MAX_PAYLOAD_LEN: u16 = 1500;
+
+    /// Try creating a [`Ieee8023Slice`] from a slice containing an
+    /// IEEE 802.3 frame WITHOUT an FCS (frame check sequence) at the end.
+    ///
+    /// Note that the length field is not checked to be smaller or equal
+    /// to [`Ieee8023Slice::MAX_PAYLOAD_LEN`].
+    pub fn from_slice(slice: &'a [u8]) -> Result<Ieee8023Slice<'a>, LenError> {
+        if slice.len() < Ieee8023Slice::MAC_HEADER_LEN {
+            return Err(LenError {
+                required_len: Ieee8023Slice::MAC_HEADER_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::Ieee8023Header,
+                layer_start_offset: 0,
+            });
+        }
+
+        // cut the slice to the length indicated by the length field
+        let total_len =
+            Ieee8023Slice::MAC_HEADER_LEN + usize::from(u16::from_be_bytes([slice[12], slice[13]]));
+        if slice.len() < total_len {
+            return Err(LenError {
+                required_len: total_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::Ieee8023Payload,
+                layer_start_offset: 0,
+            });
+        }
+        let slice = &slice[..total_len];
+
+        // llc & snap headers (limited by the length field)
+        let to_len_field_err = |err: LenError, offset: usize| {
+            let mut err = err.add_offset(offset);
+            err.len_source = LenSource::Ieee8023Len;
+            err
+        };
+        let (llc, rest) = LlcHeader::from_slice(&slice[Ieee8023Slice::MAC_HEADER_LEN..])
+            .map_err(|err| to_len_field_err(err, Ieee8023Slice::MAC_HEADER_LEN))?;
+        let mut header_len = Ieee8023Slice::MAC_HEADER_LEN + llc.header_len();
+        if llc.has_snap_header() {
+            SnapHeader::from_slice(rest).map_err(|err| to_len_field_err(err, header_len))?;
+            header_len += SnapHeader::LEN;
+        }
+
+        Ok(Ieee8023Slice { header_len, slice })
+    }
+
+    /// Returns the slice containing the IEEE 802.3 headers & payload.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the destination MAC address
+    #[inline]
+    pub fn destination(&self) -> [u8; 6] {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of Ieee8023Slice::MAC_HEADER_LEN (14).
+        unsafe { get_unchecked_6_byte_array(self.slice.as_ptr()) }
+    }
+
+    /// Read the source MAC address
+    #[inline]
+    pub fn source(&self) -> [u8; 6] {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of Ieee8023Slice::MAC_HEADER_LEN (14).
+        unsafe { get_unchecked_6_byte_array(self.slice.as_ptr().add(6)) }
+    }
+
+    /// Read the length field (length of the LLC header, SNAP header
+    /// & payload).
+    #[inline]
+    pub fn length(&self) -> u16 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of Ieee8023Slice::MAC_HEADER_LEN (14).
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(12)) }
+    }
+
+    /// Decode the LLC header.
+    pub fn llc(&self) -> LlcHeader {
+        let control = self.slice[16];
+        LlcHeader {
+            dsap: self.slice[14],
+            ssap: self.slice[15],
+            control,
+            control_ext: if LlcHeader::MAX_LEN == LlcHeader::header_len_from_control(control) {
+                Some(self.slice[17])
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Decode the SNAP header (if present).
+    pub fn snap(&self) -> Option<SnapHeader> {
+        if self.header_len - Ieee8023Slice::MAC_HEADER_LEN > LlcHeader::MAX_LEN {
+            let start = self.header_len - SnapHeader::LEN;
+            Some(SnapHeader::from_bytes([
+                self.slice[start],
+                self.slice[start + 1],
+                self.slice[start + 2],
+                self.slice[start + 3],
+                self.slice[start + 4],
+            ]))
+        } else {
+            None
+        }
+    }
+
+    /// Length of the IEEE 802.3, LLC & SNAP headers in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.header_len
+    }
+
+    /// Slice containing the IEEE 802.3, LLC & SNAP headers.
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        &self.slice[..self.header_len]
+    }
+
+    /// Ether type of the payload if a SNAP header with an ether type
+    /// based OUI is present.
+    #[inline]
+    pub fn payload_ether_type(&self) -> Option<EtherType> {
+        self.snap().and_then(|snap| snap.ether_type())
+    }
+
+    /// Returns the slice containing the payload after the LLC & SNAP headers.
+    #[inline]
+    pub fn payload_slice(&self) -> &'a [u8] {
+        &self.slice[self.header_len..]
+    }
+
+    /// Value that was used to determine the length of the payload
+    /// (always the length field of the IEEE 802.3 header).
+    #[inline]
+    pub fn payload_len_source(&self) -> LenSource {
+        LenSource::Ieee8023Len
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    fn build(
+        destination: [u8; 6],
+        source: [u8; 6],
+        llc: &LlcHeader,
+        snap: Option<&SnapHeader>,
+        payload: &[u8],
+        padding: &[u8],
+    ) -> Vec<u8> {
+        let len = llc.header_len() + snap.map(|s| s.header_len()).unwrap_or(0) + payload.len();
+        let mut result = Vec::with_capacity(14 + len + padding.len());
+        result.extend_from_slice(&destination);
+        result.extend_from_slice(&source);
+        result.extend_from_slice(&(len as u16).to_be_bytes());
+        result.extend_from_slice(&llc.to_bytes());
+        if let Some(snap) = snap {
+            result.extend_from_slice(&snap.to_bytes());
+        }
+        result.extend_from_slice(payload);
+        result.extend_from_slice(padding);
+        result
+    }
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(snap in snap_any()) {
+            let data = build([1;6], [2;6], &Default::default(), Some(&snap), &[1, 2], &[]);
+            let slice = Ieee8023Slice::from_slice(&data).unwrap();
+            assert_eq!(slice.clone(), slice);
+            assert_eq!(
+                format!("{:?}", slice),
+                format!("Ieee8023Slice {{ header_len: 22, slice: {:?} }}", &data[..])
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_getters(
+            destination in prop::array::uniform6(any::<u8>()),
+            source in prop::array::uniform6(any::<u8>()),
+            llc in llc_any(),
+            snap in snap_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20),
+            padding in proptest::collection::vec(any::<u8>(), 0..4),
+        ) {
+            // random llc header (usually without a SNAP header) & an llc header with SNAP header
+            for llc in [llc.clone(), LlcHeader::default()] {
+                let snap = if llc.has_snap_header() { Some(&snap) } else { None };
+                let data = build(destination, source, &llc, snap, &payload, &padding);
+                let header_len = 14 + llc.header_len() + snap.map(|s| s.header_len()).unwrap_or(0);
+
+                // ok
+                {
+                    let slice = Ieee8023Slice::from_slice(&data).unwrap();
+                    assert_eq!(slice.slice(), &data[..data.len() - padding.len()]);
+                    assert_eq!(slice.destination(), destination);
+                    assert_eq!(slice.source(), source);
+                    assert_eq!(usize::from(slice.length()), header_len - 14 + payload.len());
+                    assert_eq!(slice.llc(), llc);
+                    assert_eq!(slice.snap(), snap.cloned());
+                    assert_eq!(slice.header_len(), header_len);
+                    assert_eq!(slice.header_slice(), &data[..header_len]);
+                    assert_eq!(slice.payload_ether_type(), snap.and_then(|s| s.ether_type()));
+                    assert_eq!(slice.payload_slice(), &payload[..]);
+                    assert_eq!(slice.payload_len_source(), LenSource::Ieee8023Len);
+                }
+
+                // header length error
+                for len in 0..14 {
+                    assert_eq!(
+                        Ieee8023Slice::from_slice(&data[..len]).unwrap_err(),
+                        LenError {
+                            required_len: 14,
+                            len,
+                            len_source: LenSource::Slice,
+                            layer: Layer::Ieee8023Header,
+                            layer_start_offset: 0,
+                        }
+                    );
+                }
+
+                // payload length error
+                let total_len = data.len() - padding.len();
+                for len in 14..total_len {
+                    assert_eq!(
+                        Ieee8023Slice::from_slice(&data[..len]).unwrap_err(),
+                        LenError {
+                            required_len: total_len,
+                            len,
+                            len_source: LenSource::Slice,
+                            layer: Layer::Ieee8023Payload,
+                            layer_start_offset: 0,
+                        }
+                    );
+                }
+
+                // llc & snap length errors (based on the length field)
+                for len in 0..header_len - 14 {
+                    let mut data = data.clone();
+                    data[12..14].copy_from_slice(&(len as u16).to_be_bytes());
+                    let (required_len, layer, layer_start_offset) = if len < 3 {
+                        (3, Layer::LlcHeader, 14)
+                    } else if len < llc.header_len() {
+                        (4, Layer::LlcHeader, 14)
+                    } else {
+                        (SnapHeader::LEN, Layer::SnapHeader, 14 + llc.header_len())
+                    };
+                    assert_eq!(
+                        Ieee8023Slice::from_slice(&data).unwrap_err(),
+                        LenError {
+                            required_len,
+                            len: len + 14 - layer_start_offset,
+                            len_source: LenSource::Ieee8023Len,
+                            layer,
+                            layer_start_offset,
+                        }
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,34 +1,93 @@
 use crate::*;
 
-/// A slice containing the link layer header (currently only Ethernet II is supported).
+/// A slice containing the link layer header (Ethernet II, IEEE 802.3 with LLC & SNAP
+/// or a Linux cooked capture header).
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum LinkSlice<'a> {
     /// A slice containing an Ethernet II header.
     Ethernet2(Ethernet2Slice<'a>),
 
+    /// A slice containing an IEEE 802.3 frame with an LLC header
+    /// (and optionally a SNAP header).
+    Ieee8023(Ieee8023Slice<'a>),
+
+    /// A slice containing a Linux cooked capture v1 (SLL) header.
+    LinuxSll(LinuxSllSlice<'a>),
+
+    /// A slice containing a Linux cooked capture v2 (SLL2) header.
+    LinuxSll2(LinuxSll2Slice<'a>),
+
     /// Ether payload without header.
     EtherPayload(EtherPayloadSlice<'a>),
 }
 
 impl<'a> LinkSlice<'a> {
-    /// Convert the link slice to a header (currently just the
-    /// ethernet2 header as this is the only value it can take).
+    /// Convert the link slice to an Ethernet II header.
+    ///
+    /// Returns `None` for all other link layers (IEEE 802.3, Linux SLL,
+    /// Linux SLL2 & ether payloads without a header). Use the slice of
+    /// the corresponding variant to decode those headers.
     pub fn to_header(&self) -> Option<Ethernet2Header> {
         use LinkSlice::*;
         match self {
             Ethernet2(slice) => Some(slice.to_header()),
+            Ieee8023(_) => None,
+            LinuxSll(_) => None,
+            LinuxSll2(_) => None,
             EtherPayload(_) => None,
         }
     }
 
+    /// Returns the slice containing the link layer header (including
+    /// the LLC & SNAP header in case of an IEEE 802.3 frame).
+    ///
+    /// An empty slice is returned for [`LinkSlice::EtherPayload`] as no
+    /// header is present.
+    pub fn header_slice(&self) -> &'a [u8] {
+        use LinkSlice::*;
+        match self {
+            Ethernet2(s) => s.header_slice(),
+            Ieee8023(s) => s.header_slice(),
+            LinuxSll(s) => s.header_slice(),
+            LinuxSll2(s) => s.header_slice(),
+            EtherPayload(_) => &[],
+        }
+    }
+
     /// Returns the link layer payload (slice + ether type number).
+    ///
+    /// IEEE 802.3 frames without an ether type in a SNAP header use the
+    /// value of the 802.3 length field as ether type (as it was the case
+    /// before IEEE 802.3 frames were decoded). This value is always
+    /// smaller or equal to [`Ieee8023Slice::MAX_PAYLOAD_LEN`] and as such
+    /// never a valid ether type.
     pub fn payload(&self) -> EtherPayloadSlice<'a> {
         use LinkSlice::*;
         match self {
             Ethernet2(s) => s.payload().clone(),
+            Ieee8023(s) => EtherPayloadSlice {
+                ether_type: s.payload_ether_type().unwrap_or(EtherType(s.length())),
+                payload: s.payload_slice(),
+            },
+            LinuxSll(s) => s.payload(),
+            LinuxSll2(s) => s.payload(),
             EtherPayload(p) => p.clone(),
         }
     }
+
+    /// Returns the ether type of the link layer payload (see
+    /// [`LinkSlice::payload`] for IEEE 802.3 frames).
+    #[inline]
+    pub fn payload_ether_type(&self) -> EtherType {
+        use LinkSlice::*;
+        match self {
+            Ethernet2(s) => s.ether_type(),
+            Ieee8023(s) => s.payload_ether_type().unwrap_or(EtherType(s.length())),
+            LinuxSll(s) => s.protocol_type(),
+            LinuxSll2(s) => s.protocol_type(),
+            EtherPayload(p) => p.ether_type,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -99,6 +158,8 @@ mod test {
                     slice.payload(),
                     EtherPayloadSlice{ ether_type: eth.ether_type, payload: &p }
                 );
+                assert_eq!(slice.payload_ether_type(), eth.ether_type);
+                assert_eq!(slice.header_slice(), &bytes[..Ethernet2Header::LEN]);
             }
             {
                 let p = [1,2,3,4];
@@ -110,6 +171,92 @@ mod test {
                     slice.payload(),
                     EtherPayloadSlice{ ether_type: eth.ether_type, payload: &p }
                 );
+                assert_eq!(slice.payload_ether_type(), eth.ether_type);
+                assert_eq!(slice.header_slice(), &[]);
+            }
+        }
+    }
+
+    #[test]
+    fn ieee8023() {
+        let p = [1, 2, 3, 4];
+
+        // with snap header containing an ether type
+        {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&[0; 12]);
+            bytes.extend_from_slice(&(3u16 + 5 + 4).to_be_bytes());
+            bytes.extend_from_slice(&LlcHeader::default().to_bytes());
+            bytes.extend_from_slice(
+                &SnapHeader {
+                    oui: SnapHeader::OUI_ETHER_TYPE,
+                    protocol_id: EtherType::IPV4.0,
+                }
+                .to_bytes(),
+            );
+            bytes.extend_from_slice(&p);
+            let slice = LinkSlice::Ieee8023(Ieee8023Slice::from_slice(&bytes).unwrap());
+            assert_eq!(slice.to_header(), None);
+            assert_eq!(
+                slice.payload(),
+                EtherPayloadSlice {
+                    ether_type: EtherType::IPV4,
+                    payload: &p
+                }
+            );
+            assert_eq!(slice.payload_ether_type(), EtherType::IPV4);
+            assert_eq!(slice.header_slice(), &bytes[..bytes.len() - p.len()]);
+        }
+
+        // without snap header (length field is used as ether type)
+        {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&[0; 12]);
+            bytes.extend_from_slice(&(3u16 + 4).to_be_bytes());
+            bytes.extend_from_slice(&[0x42, 0x42, 0x03]);
+            bytes.extend_from_slice(&p);
+            let slice = LinkSlice::Ieee8023(Ieee8023Slice::from_slice(&bytes).unwrap());
+            assert_eq!(
+                slice.payload(),
+                EtherPayloadSlice {
+                    ether_type: EtherType(7),
+                    payload: &p
+                }
+            );
+            assert_eq!(slice.payload_ether_type(), EtherType(7));
+            assert_eq!(slice.header_slice(), &bytes[..bytes.len() - p.len()]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn linux_sll(ref sll in linux_sll_any(), ref sll2 in linux_sll2_any()) {
+            let p = [1,2,3,4];
+            {
+                let mut bytes = Vec::with_capacity(LinuxSllHeader::LEN + p.len());
+                bytes.extend_from_slice(&sll.to_bytes());
+                bytes.extend_from_slice(&p);
+                let slice = LinkSlice::LinuxSll(LinuxSllSlice::from_slice(&bytes).unwrap());
+                assert_eq!(slice.to_header(), None);
+                assert_eq!(
+                    slice.payload(),
+                    EtherPayloadSlice{ ether_type: sll.protocol_type, payload: &p }
+                );
+                assert_eq!(slice.payload_ether_type(), sll.protocol_type);
+                assert_eq!(slice.header_slice(), &bytes[..LinuxSllHeader::LEN]);
+            }
+            {
+                let mut bytes = Vec::with_capacity(LinuxSll2Header::LEN + p.len());
+                bytes.extend_from_slice(&sll2.to_bytes());
+                bytes.extend_from_slice(&p);
+                let slice = LinkSlice::LinuxSll2(LinuxSll2Slice::from_slice(&bytes).unwrap());
+                assert_eq!(slice.to_header(), None);
+                assert_eq!(
+                    slice.payload(),
+                    EtherPayloadSlice{ ether_type: sll2.protocol_type, payload: &p }
+                );
+                assert_eq!(slice.payload_ether_type(), sll2.protocol_type);
+                assert_eq!(slice.header_slice(), &bytes[..LinuxSll2Header::LEN]);
             }
         }
     }
@@ -0,0 +1,378 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+
+/// Linux cooked capture v2 ("SLL2") header.
+///
+/// Successor of the [`LinuxSllHeader`] used by libpcap when capturing on
+/// the Linux "any" device (`LINKTYPE_LINUX_SLL2`, link type 276). In
+/// contrast to the SLL header it additionally contains the index of the
+/// interface the packet was captured on.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LinuxSll2Header {
+    /// Protocol present after the header.
+    ///
+    /// For most link layer types this is an ether type. Some
+    /// ARPHRD_ types use different values (e.g. netlink protocol numbers
+    /// for `ARPHRD_NETLINK`).
+    pub protocol_type: EtherType,
+    /// Reserved (should be zero).
+    pub reserved: u16,
+    /// Index of the interface the packet was captured on.
+    pub interface_index: u32,
+    /// Linux ARPHRD_ value of the link layer device the packet was
+    /// captured on (e.g. 1 for Ethernet or 772 for loopback).
+    pub arp_hrd_type: ArpHardwareId,
+    /// How the packet was received or sent by the capturing host.
+    ///
+    /// Note that the SLL2 header only has one byte for the packet type.
+    /// When serializing only the lower 8 bits of the value are written.
+    pub packet_type: LinuxSllPacketType,
+    /// Number of bytes in `sender_address` containing the link layer
+    /// address of the sender (can be bigger than 8 if the address got
+    /// truncated).
+    pub sender_address_valid_length: u8,
+    /// Link layer address of the sender (padded with zeros or truncated
+    /// to 8 bytes).
+    pub sender_address: [u8; 8],
+}
+
+impl LinuxSll2Header {
+    /// Serialized size of a Linux SLL2 header in bytes/octets.
+    pub const LEN: usize = 20;
+
+    /// Read a [`LinuxSll2Header`] from a slice and return the header & unused
+    /// parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(LinuxSll2Header, &[u8]), err::LenError> {
+        if slice.len() < LinuxSll2Header::LEN {
+            return Err(err::LenError {
+                required_len: LinuxSll2Header::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::LinuxSll2Header,
+                layer_start_offset: 0,
+            });
+        }
+        let (header, rest) = slice.split_at(LinuxSll2Header::LEN);
+        let mut bytes = [0u8; LinuxSll2Header::LEN];
+        bytes.copy_from_slice(header);
+        Ok((LinuxSll2Header::from_bytes(bytes), rest))
+    }
+
+    /// Read a [`LinuxSll2Header`] from a static sized byte array.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 20]) -> LinuxSll2Header {
+        LinuxSll2Header {
+            protocol_type: EtherType(u16::from_be_bytes([bytes[0], bytes[1]])),
+            reserved: u16::from_be_bytes([bytes[2], bytes[3]]),
+            interface_index: u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+            arp_hrd_type: ArpHardwareId(u16::from_be_bytes([bytes[8], bytes[9]])),
+            packet_type: LinuxSllPacketType(u16::from(bytes[10])),
+            sender_address_valid_length: bytes[11],
+            sender_address: [
+                bytes[12], bytes[13], bytes[14], bytes[15], bytes[16], bytes[17], bytes[18],
+                bytes[19],
+            ],
+        }
+    }
+
+    /// Reads a Linux SLL2 header from the current position of the read argument.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(
+        reader: &mut T,
+    ) -> Result<LinuxSll2Header, std::io::Error> {
+        let mut buffer = [0; LinuxSll2Header::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(LinuxSll2Header::from_bytes(buffer))
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        if slice.len() < LinuxSll2Header::LEN {
+            Err(SliceWriteSpaceError {
+                required_len: LinuxSll2Header::LEN,
+                len: slice.len(),
+                layer: Layer::LinuxSll2Header,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..LinuxSll2Header::LEN].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[LinuxSll2Header::LEN..])
+        }
+    }
+
+    /// Writes the header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Returns the part of `sender_address` that contains the valid
+    /// address bytes (based on `sender_address_valid_length`).
+    #[inline]
+    pub fn sender_address_slice(&self) -> &[u8] {
+        let len = usize::from(self.sender_address_valid_length).min(self.sender_address.len());
+        &self.sender_address[..len]
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        LinuxSll2Header::LEN
+    }
+
+    /// Returns the serialized form of the header as a statically
+    /// sized byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 20] {
+        let protocol_type_be = self.protocol_type.0.to_be_bytes();
+        let reserved_be = self.reserved.to_be_bytes();
+        let interface_index_be = self.interface_index.to_be_bytes();
+        let arp_hrd_type_be = self.arp_hrd_type.0.to_be_bytes();
+        [
+            protocol_type_be[0],
+            protocol_type_be[1],
+            reserved_be[0],
+            reserved_be[1],
+            interface_index_be[0],
+            interface_index_be[1],
+            interface_index_be[2],
+            interface_index_be[3],
+            arp_hrd_type_be[0],
+            arp_hrd_type_be[1],
+            (self.packet_type.0 & 0xff) as u8,
+            self.sender_address_valid_length,
+            self.sender_address[0],
+            self.sender_address[1],
+            self.sender_address[2],
+            self.sender_address[3],
+            self.sender_address[4],
+            self.sender_address[5],
+            self.sender_address[6],
+            self.sender_address[7],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn default() {
+        let h: LinuxSll2Header = Default::default();
+        assert_eq!(EtherType(0), h.protocol_type);
+        assert_eq!(0, h.reserved);
+        assert_eq!(0, h.interface_index);
+        assert_eq!(ArpHardwareId(0), h.arp_hrd_type);
+        assert_eq!(LinuxSllPacketType(0), h.packet_type);
+        assert_eq!(0, h.sender_address_valid_length);
+        assert_eq!([0u8; 8], h.sender_address);
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in linux_sll2_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(20 + dummy_data.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer.extend(&dummy_data[..]);
+
+            // valid
+            {
+                let (result, rest) = LinuxSll2Header::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[20..], rest);
+            }
+
+            // not enough data
+            for len in 0..20 {
+                assert_eq!(
+                    LinuxSll2Header::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: LinuxSll2Header::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::LinuxSll2Header,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(input in linux_sll2_any()) {
+            assert_eq!(input, LinuxSll2Header::from_bytes(input.to_bytes()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read(
+            input in linux_sll2_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(20 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // valid
+            {
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(input, LinuxSll2Header::read(&mut cursor).unwrap());
+                assert_eq!(cursor.position(), 20);
+            }
+
+            // unexpected eof
+            for len in 0..20 {
+                let mut cursor = Cursor::new(&buffer[0..len]);
+                assert_eq!(
+                    LinuxSll2Header::read(&mut cursor).unwrap_err().kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in linux_sll2_any()) {
+            // normal write
+            {
+                let mut buffer = [0u8; 20];
+                input.write_to_slice(&mut buffer).unwrap();
+                assert_eq!(buffer, input.to_bytes());
+            }
+            // len too small
+            for len in 0..20 {
+                let mut buffer = [0u8; 20];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: LinuxSll2Header::LEN,
+                        len,
+                        layer: Layer::LinuxSll2Header,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write(input in linux_sll2_any()) {
+            // successful write
+            {
+                let mut buffer: Vec<u8> = Vec::with_capacity(20);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+            }
+
+            // not enough memory for write
+            for len in 0..20 {
+                let mut buffer = [0u8; 20];
+                let mut writer = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut writer).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn sender_address_slice() {
+        let mut h = LinuxSll2Header {
+            sender_address: [1, 2, 3, 4, 5, 6, 7, 8],
+            sender_address_valid_length: 6,
+            ..Default::default()
+        };
+        assert_eq!(&[1, 2, 3, 4, 5, 6], h.sender_address_slice());
+
+        h.sender_address_valid_length = 0;
+        assert_eq!(&[] as &[u8], h.sender_address_slice());
+
+        // truncated addresses
+        h.sender_address_valid_length = 20;
+        assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8], h.sender_address_slice());
+    }
+
+    proptest! {
+        #[test]
+        fn header_len(input in linux_sll2_any()) {
+            assert_eq!(input.header_len(), 20);
+        }
+    }
+
+    #[test]
+    fn to_bytes() {
+        let h = LinuxSll2Header {
+            protocol_type: EtherType::IPV6,
+            reserved: 0,
+            interface_index: 0x0102_0304,
+            arp_hrd_type: ArpHardwareId::ETHERNET,
+            packet_type: LinuxSllPacketType::OUTGOING,
+            sender_address_valid_length: 6,
+            sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+        };
+        assert_eq!(
+            h.to_bytes(),
+            [0x86, 0xdd, 0, 0, 1, 2, 3, 4, 0, 1, 4, 6, 1, 2, 3, 4, 5, 6, 0, 0]
+        );
+
+        // only the lower 8 bits of the packet type are written
+        assert_eq!(
+            LinuxSll2Header {
+                packet_type: LinuxSllPacketType(0x1234),
+                ..Default::default()
+            }
+            .to_bytes()[10],
+            0x34
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_hash_dbg(input in linux_sll2_any()) {
+            use std::collections::hash_map::DefaultHasher;
+            use core::hash::{Hash, Hasher};
+
+            assert_eq!(input, input.clone());
+            let a = {
+                let mut hasher = DefaultHasher::new();
+                input.hash(&mut hasher);
+                hasher.finish()
+            };
+            let b = {
+                let mut hasher = DefaultHasher::new();
+                input.clone().hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(a, b);
+            assert_eq!(
+                format!(
+                    "LinuxSll2Header {{ protocol_type: {:?}, reserved: {:?}, interface_index: {:?}, arp_hrd_type: {:?}, packet_type: {:?}, sender_address_valid_length: {:?}, sender_address: {:?} }}",
+                    input.protocol_type,
+                    input.reserved,
+                    input.interface_index,
+                    input.arp_hrd_type,
+                    input.packet_type,
+                    input.sender_address_valid_length,
+                    input.sender_address,
+                ),
+                format!("{:?}", input)
+            );
+        }
+    }
+}
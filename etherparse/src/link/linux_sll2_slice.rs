@@ -0,0 +1,224 @@
+use crate::{err::*, *};
+
+/// Slice containing a Linux cooked capture v2 (SLL2) header & payload.
+#[derive(Clone, Eq, PartialEq)]
+pub struct LinuxSll2Slice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> LinuxSll2Slice<'a> {
+    /// Try creating a [`LinuxSll2Slice`] from a slice containing the
+    /// Linux SLL2 header & payload.
+    pub fn from_slice(slice: &'a [u8]) -> Result<LinuxSll2Slice<'a>, LenError> {
+        // check length
+        if slice.len() < LinuxSll2Header::LEN {
+            return Err(LenError {
+                required_len: LinuxSll2Header::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::LinuxSll2Header,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(LinuxSll2Slice { slice })
+    }
+
+    /// Returns the slice containing the Linux SLL2 header & payload.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the protocol type field of the header indicating the protocol
+    /// after the header.
+    #[inline]
+    pub fn protocol_type(&self) -> EtherType {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSll2Header::LEN (20).
+        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr()) })
+    }
+
+    /// Read the reserved field of the header.
+    #[inline]
+    pub fn reserved(&self) -> u16 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSll2Header::LEN (20).
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+    }
+
+    /// Read the index of the interface the packet was captured on.
+    #[inline]
+    pub fn interface_index(&self) -> u32 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSll2Header::LEN (20).
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Read the "ARPHRD_" type of the capturing device.
+    #[inline]
+    pub fn arp_hrd_type(&self) -> ArpHardwareId {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSll2Header::LEN (20).
+        ArpHardwareId(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(8)) })
+    }
+
+    /// Read the "packet type" field of the header.
+    #[inline]
+    pub fn packet_type(&self) -> LinuxSllPacketType {
+        LinuxSllPacketType(u16::from(self.slice[10]))
+    }
+
+    /// Read the number of valid bytes in the sender address.
+    #[inline]
+    pub fn sender_address_valid_length(&self) -> u8 {
+        self.slice[11]
+    }
+
+    /// Read the (zero padded or truncated) sender address.
+    #[inline]
+    pub fn sender_address(&self) -> [u8; 8] {
+        let mut result = [0u8; 8];
+        result.copy_from_slice(&self.slice[12..20]);
+        result
+    }
+
+    /// Decode all the fields and copy the results to a [`LinuxSll2Header`] struct
+    pub fn to_header(&self) -> LinuxSll2Header {
+        LinuxSll2Header {
+            protocol_type: self.protocol_type(),
+            reserved: self.reserved(),
+            interface_index: self.interface_index(),
+            arp_hrd_type: self.arp_hrd_type(),
+            packet_type: self.packet_type(),
+            sender_address_valid_length: self.sender_address_valid_length(),
+            sender_address: self.sender_address(),
+        }
+    }
+
+    /// Slice containing the Linux SLL2 header.
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        &self.slice[..LinuxSll2Header::LEN]
+    }
+
+    /// Returns the slice containing the payload & the protocol type
+    /// identifying it's content type.
+    #[inline]
+    pub fn payload(&self) -> EtherPayloadSlice<'a> {
+        EtherPayloadSlice {
+            ether_type: self.protocol_type(),
+            payload: self.payload_slice(),
+        }
+    }
+
+    /// Returns the slice containing the payload after the Linux SLL2 header.
+    #[inline]
+    pub fn payload_slice(&self) -> &'a [u8] {
+        &self.slice[LinuxSll2Header::LEN..]
+    }
+
+    /// Length of the Linux SLL2 header in bytes (equal to
+    /// [`crate::LinuxSll2Header::LEN`]).
+    #[inline]
+    pub const fn header_len(&self) -> usize {
+        LinuxSll2Header::LEN
+    }
+}
+
+impl core::fmt::Debug for LinuxSll2Slice<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LinuxSll2Slice")
+            .field("header", &self.to_header())
+            .field("payload", &self.payload())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(sll in linux_sll2_any()) {
+            let payload: [u8;8] = [1,2,3,4,5,6,7,8];
+            let mut data = Vec::with_capacity(sll.header_len() + payload.len());
+            data.extend_from_slice(&sll.to_bytes());
+            data.extend_from_slice(&payload);
+
+            let slice = LinuxSll2Slice::from_slice(&data).unwrap();
+            prop_assert_eq!(
+                format!("{:?}", slice),
+                format!(
+                    "LinuxSll2Slice {{ header: {:?}, payload: {:?} }}",
+                    slice.to_header(),
+                    slice.payload(),
+                )
+            );
+            prop_assert_eq!(slice.clone(), slice);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(sll in linux_sll2_any()) {
+            let payload: [u8;8] = [1,2,3,4,5,6,7,8];
+            let mut data = Vec::with_capacity(sll.header_len() + payload.len());
+            data.extend_from_slice(&sll.to_bytes());
+            data.extend_from_slice(&payload);
+
+            let slice = LinuxSll2Slice::from_slice(&data).unwrap();
+            assert_eq!(sll.protocol_type, slice.protocol_type());
+            assert_eq!(sll.reserved, slice.reserved());
+            assert_eq!(sll.interface_index, slice.interface_index());
+            assert_eq!(sll.arp_hrd_type, slice.arp_hrd_type());
+            assert_eq!(sll.packet_type, slice.packet_type());
+            assert_eq!(sll.sender_address_valid_length, slice.sender_address_valid_length());
+            assert_eq!(sll.sender_address, slice.sender_address());
+            assert_eq!(&payload, slice.payload_slice());
+            assert_eq!(
+                EtherPayloadSlice{
+                    payload: &payload,
+                    ether_type: sll.protocol_type,
+                },
+                slice.payload()
+            );
+            assert_eq!(sll, slice.to_header());
+            assert_eq!(&data, slice.slice());
+            assert_eq!(&data[..LinuxSll2Header::LEN], slice.header_slice());
+            assert_eq!(LinuxSll2Header::LEN, slice.header_len());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(sll in linux_sll2_any()) {
+            let data = sll.to_bytes();
+
+            // ok
+            assert!(LinuxSll2Slice::from_slice(&data).is_ok());
+
+            // length error
+            for len in 0..data.len() {
+                assert_eq!(
+                    LinuxSll2Slice::from_slice(&data[..len]).unwrap_err(),
+                    LenError{
+                        required_len: LinuxSll2Header::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::LinuxSll2Header,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+}
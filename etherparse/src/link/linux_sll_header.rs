@@ -0,0 +1,347 @@
+use crate::{err::Layer, err::SliceWriteSpaceError, *};
+
+/// Linux cooked capture v1 ("SLL") header.
+///
+/// Pseudo link layer header used by libpcap when capturing on the Linux
+/// "any" device or on devices without a link layer header that libpcap
+/// supports (`LINKTYPE_LINUX_SLL`, link type 113).
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LinuxSllHeader {
+    /// How the packet was received or sent by the capturing host.
+    pub packet_type: LinuxSllPacketType,
+    /// Linux ARPHRD_ value of the link layer device the packet was
+    /// captured on (e.g. 1 for Ethernet or 772 for loopback).
+    pub arp_hrd_type: ArpHardwareId,
+    /// Number of bytes in `sender_address` containing the link layer
+    /// address of the sender (can be bigger than 8 if the address got
+    /// truncated).
+    pub sender_address_valid_length: u16,
+    /// Link layer address of the sender (padded with zeros or truncated
+    /// to 8 bytes).
+    pub sender_address: [u8; 8],
+    /// Protocol present after the header.
+    ///
+    /// For most link layer types this is an ether type. Some
+    /// ARPHRD_ types use different values (e.g. netlink protocol numbers
+    /// for `ARPHRD_NETLINK`).
+    pub protocol_type: EtherType,
+}
+
+impl LinuxSllHeader {
+    /// Serialized size of a Linux SLL header in bytes/octets.
+    pub const LEN: usize = 16;
+
+    /// Read a [`LinuxSllHeader`] from a slice and return the header & unused
+    /// parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(LinuxSllHeader, &[u8]), err::LenError> {
+        if slice.len() < LinuxSllHeader::LEN {
+            return Err(err::LenError {
+                required_len: LinuxSllHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::LinuxSllHeader,
+                layer_start_offset: 0,
+            });
+        }
+        let (header, rest) = slice.split_at(LinuxSllHeader::LEN);
+        let mut bytes = [0u8; LinuxSllHeader::LEN];
+        bytes.copy_from_slice(header);
+        Ok((LinuxSllHeader::from_bytes(bytes), rest))
+    }
+
+    /// Read a [`LinuxSllHeader`] from a static sized byte array.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 16]) -> LinuxSllHeader {
+        LinuxSllHeader {
+            packet_type: LinuxSllPacketType(u16::from_be_bytes([bytes[0], bytes[1]])),
+            arp_hrd_type: ArpHardwareId(u16::from_be_bytes([bytes[2], bytes[3]])),
+            sender_address_valid_length: u16::from_be_bytes([bytes[4], bytes[5]]),
+            sender_address: [
+                bytes[6], bytes[7], bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13],
+            ],
+            protocol_type: EtherType(u16::from_be_bytes([bytes[14], bytes[15]])),
+        }
+    }
+
+    /// Reads a Linux SLL header from the current position of the read argument.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(
+        reader: &mut T,
+    ) -> Result<LinuxSllHeader, std::io::Error> {
+        let mut buffer = [0; LinuxSllHeader::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(LinuxSllHeader::from_bytes(buffer))
+    }
+
+    /// Serialize the header to a given slice. Returns the unused part of the slice.
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], SliceWriteSpaceError> {
+        if slice.len() < LinuxSllHeader::LEN {
+            Err(SliceWriteSpaceError {
+                required_len: LinuxSllHeader::LEN,
+                len: slice.len(),
+                layer: Layer::LinuxSllHeader,
+                layer_start_offset: 0,
+            })
+        } else {
+            slice[..LinuxSllHeader::LEN].copy_from_slice(&self.to_bytes());
+            Ok(&mut slice[LinuxSllHeader::LEN..])
+        }
+    }
+
+    /// Writes the header to the current position of the write argument.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Returns the part of `sender_address` that contains the valid
+    /// address bytes (based on `sender_address_valid_length`).
+    #[inline]
+    pub fn sender_address_slice(&self) -> &[u8] {
+        let len = usize::from(self.sender_address_valid_length).min(self.sender_address.len());
+        &self.sender_address[..len]
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        LinuxSllHeader::LEN
+    }
+
+    /// Returns the serialized form of the header as a statically
+    /// sized byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let packet_type_be = self.packet_type.0.to_be_bytes();
+        let arp_hrd_type_be = self.arp_hrd_type.0.to_be_bytes();
+        let addr_len_be = self.sender_address_valid_length.to_be_bytes();
+        let protocol_type_be = self.protocol_type.0.to_be_bytes();
+        [
+            packet_type_be[0],
+            packet_type_be[1],
+            arp_hrd_type_be[0],
+            arp_hrd_type_be[1],
+            addr_len_be[0],
+            addr_len_be[1],
+            self.sender_address[0],
+            self.sender_address[1],
+            self.sender_address[2],
+            self.sender_address[3],
+            self.sender_address[4],
+            self.sender_address[5],
+            self.sender_address[6],
+            self.sender_address[7],
+            protocol_type_be[0],
+            protocol_type_be[1],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn default() {
+        let h: LinuxSllHeader = Default::default();
+        assert_eq!(LinuxSllPacketType(0), h.packet_type);
+        assert_eq!(ArpHardwareId(0), h.arp_hrd_type);
+        assert_eq!(0, h.sender_address_valid_length);
+        assert_eq!([0u8; 8], h.sender_address);
+        assert_eq!(EtherType(0), h.protocol_type);
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in linux_sll_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(16 + dummy_data.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer.extend(&dummy_data[..]);
+
+            // valid
+            {
+                let (result, rest) = LinuxSllHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(input, result);
+                assert_eq!(&buffer[16..], rest);
+            }
+
+            // not enough data
+            for len in 0..16 {
+                assert_eq!(
+                    LinuxSllHeader::from_slice(&buffer[..len]),
+                    Err(err::LenError{
+                        required_len: LinuxSllHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::LinuxSllHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(input in linux_sll_any()) {
+            assert_eq!(input, LinuxSllHeader::from_bytes(input.to_bytes()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read(
+            input in linux_sll_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(16 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // valid
+            {
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(input, LinuxSllHeader::read(&mut cursor).unwrap());
+                assert_eq!(cursor.position(), 16);
+            }
+
+            // unexpected eof
+            for len in 0..16 {
+                let mut cursor = Cursor::new(&buffer[0..len]);
+                assert_eq!(
+                    LinuxSllHeader::read(&mut cursor).unwrap_err().kind(),
+                    ErrorKind::UnexpectedEof
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(input in linux_sll_any()) {
+            // normal write
+            {
+                let mut buffer = [0u8; 16];
+                input.write_to_slice(&mut buffer).unwrap();
+                assert_eq!(buffer, input.to_bytes());
+            }
+            // len too small
+            for len in 0..16 {
+                let mut buffer = [0u8; 16];
+                assert_eq!(
+                    SliceWriteSpaceError {
+                        required_len: LinuxSllHeader::LEN,
+                        len,
+                        layer: Layer::LinuxSllHeader,
+                        layer_start_offset: 0,
+                    },
+                    input.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write(input in linux_sll_any()) {
+            // successful write
+            {
+                let mut buffer: Vec<u8> = Vec::with_capacity(16);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+            }
+
+            // not enough memory for write
+            for len in 0..16 {
+                let mut buffer = [0u8; 16];
+                let mut writer = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut writer).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn sender_address_slice() {
+        let mut h = LinuxSllHeader {
+            sender_address: [1, 2, 3, 4, 5, 6, 7, 8],
+            sender_address_valid_length: 6,
+            ..Default::default()
+        };
+        assert_eq!(&[1, 2, 3, 4, 5, 6], h.sender_address_slice());
+
+        h.sender_address_valid_length = 0;
+        assert_eq!(&[] as &[u8], h.sender_address_slice());
+
+        // truncated addresses
+        h.sender_address_valid_length = 20;
+        assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8], h.sender_address_slice());
+    }
+
+    proptest! {
+        #[test]
+        fn header_len(input in linux_sll_any()) {
+            assert_eq!(input.header_len(), 16);
+        }
+    }
+
+    #[test]
+    fn to_bytes() {
+        let h = LinuxSllHeader {
+            packet_type: LinuxSllPacketType::OUTGOING,
+            arp_hrd_type: ArpHardwareId::ETHERNET,
+            sender_address_valid_length: 6,
+            sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+            protocol_type: EtherType::IPV4,
+        };
+        assert_eq!(
+            h.to_bytes(),
+            [0, 4, 0, 1, 0, 6, 1, 2, 3, 4, 5, 6, 0, 0, 0x08, 0x00]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_hash_dbg(input in linux_sll_any()) {
+            use std::collections::hash_map::DefaultHasher;
+            use core::hash::{Hash, Hasher};
+
+            assert_eq!(input, input.clone());
+            let a = {
+                let mut hasher = DefaultHasher::new();
+                input.hash(&mut hasher);
+                hasher.finish()
+            };
+            let b = {
+                let mut hasher = DefaultHasher::new();
+                input.clone().hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(a, b);
+            assert_eq!(
+                format!(
+                    "LinuxSllHeader {{ packet_type: {:?}, arp_hrd_type: {:?}, sender_address_valid_length: {:?}, sender_address: {:?}, protocol_type: {:?} }}",
+                    input.packet_type,
+                    input.arp_hrd_type,
+                    input.sender_address_valid_length,
+                    input.sender_address,
+                    input.protocol_type,
+                ),
+                format!("{:?}", input)
+            );
+        }
+    }
+}
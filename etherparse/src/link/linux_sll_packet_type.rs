@@ -0,0 +1,150 @@
+/// Represents the "packet type" field present in a Linux cooked capture
+/// (SLL & SLL2) header, indicating how the packet was received or sent
+/// by the capturing host.
+///
+/// You can access the underlying `u16` value by using `.0` and any `u16`
+/// can be converted to a `LinuxSllPacketType`:
+///
+/// ```
+/// use etherparse::LinuxSllPacketType;
+///
+/// assert_eq!(LinuxSllPacketType::OUTGOING.0, 4);
+/// assert_eq!(LinuxSllPacketType::OUTGOING, LinuxSllPacketType(4));
+///
+/// // convert to LinuxSllPacketType using the from & into trait
+/// let packet_type: LinuxSllPacketType = 4.into();
+/// assert_eq!(LinuxSllPacketType::OUTGOING, packet_type);
+///
+/// // convert to u16 using the from & into trait
+/// let num: u16 = LinuxSllPacketType::OUTGOING.into();
+/// assert_eq!(4, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct LinuxSllPacketType(pub u16);
+
+impl LinuxSllPacketType {
+    /// Packet was sent to the capturing host.
+    pub const HOST: LinuxSllPacketType = Self(0);
+    /// Packet was broadcast by another host.
+    pub const BROADCAST: LinuxSllPacketType = Self(1);
+    /// Packet was multicast by another host (but not broadcast).
+    pub const MULTICAST: LinuxSllPacketType = Self(2);
+    /// Packet was sent by another host to another host.
+    pub const OTHERHOST: LinuxSllPacketType = Self(3);
+    /// Packet was sent by the capturing host.
+    pub const OUTGOING: LinuxSllPacketType = Self(4);
+    /// Packet was looped back to the capturing host (kernel internal).
+    pub const LOOPBACK: LinuxSllPacketType = Self(5);
+    /// Packet was sent via the "fast route" (kernel internal).
+    pub const FASTROUTE: LinuxSllPacketType = Self(6);
+}
+
+impl From<u16> for LinuxSllPacketType {
+    #[inline]
+    fn from(val: u16) -> Self {
+        LinuxSllPacketType(val)
+    }
+}
+
+impl From<LinuxSllPacketType> for u16 {
+    #[inline]
+    fn from(val: LinuxSllPacketType) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for LinuxSllPacketType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::HOST => write!(f, "{} (Host)", self.0),
+            Self::BROADCAST => write!(f, "{} (Broadcast)", self.0),
+            Self::MULTICAST => write!(f, "{} (Multicast)", self.0),
+            Self::OTHERHOST => write!(f, "{} (Other Host)", self.0),
+            Self::OUTGOING => write!(f, "{} (Outgoing)", self.0),
+            Self::LOOPBACK => write!(f, "{} (Loopback)", self.0),
+            Self::FASTROUTE => write!(f, "{} (Fast Route)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for LinuxSllPacketType {
+    fn format(&self, f: defmt::Formatter) {
+        match *self {
+            Self::HOST => defmt::write!(f, "{=u16} (Host)", self.0),
+            Self::BROADCAST => defmt::write!(f, "{=u16} (Broadcast)", self.0),
+            Self::MULTICAST => defmt::write!(f, "{=u16} (Multicast)", self.0),
+            Self::OTHERHOST => defmt::write!(f, "{=u16} (Other Host)", self.0),
+            Self::OUTGOING => defmt::write!(f, "{=u16} (Outgoing)", self.0),
+            Self::LOOPBACK => defmt::write!(f, "{=u16} (Loopback)", self.0),
+            Self::FASTROUTE => defmt::write!(f, "{=u16} (Fast Route)", self.0),
+            _ => defmt::write!(f, "{=u16}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::LinuxSllPacketType;
+    use alloc::format;
+
+    #[test]
+    fn to_u16() {
+        assert_eq!(0, u16::from(LinuxSllPacketType::HOST));
+        assert_eq!(6, u16::from(LinuxSllPacketType::FASTROUTE));
+    }
+
+    #[test]
+    fn from_u16() {
+        assert_eq!(LinuxSllPacketType::from(4), LinuxSllPacketType::OUTGOING);
+        assert_eq!(LinuxSllPacketType::from(1234), LinuxSllPacketType(1234));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (LinuxSllPacketType::HOST, "0 (Host)"),
+            (LinuxSllPacketType::BROADCAST, "1 (Broadcast)"),
+            (LinuxSllPacketType::MULTICAST, "2 (Multicast)"),
+            (LinuxSllPacketType::OTHERHOST, "3 (Other Host)"),
+            (LinuxSllPacketType::OUTGOING, "4 (Outgoing)"),
+            (LinuxSllPacketType::LOOPBACK, "5 (Loopback)"),
+            (LinuxSllPacketType::FASTROUTE, "6 (Fast Route)"),
+            (LinuxSllPacketType(7), "7"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn default() {
+        let value: LinuxSllPacketType = Default::default();
+        assert_eq!(LinuxSllPacketType(0), value);
+    }
+
+    #[test]
+    fn clone_eq_hash_ord() {
+        use core::cmp::Ordering;
+        use core::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+
+        let a = LinuxSllPacketType::OUTGOING;
+        assert_eq!(a, a.clone());
+        let hash_a = {
+            let mut hasher = DefaultHasher::new();
+            a.hash(&mut hasher);
+            hasher.finish()
+        };
+        let hash_b = {
+            let mut hasher = DefaultHasher::new();
+            a.clone().hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash_a, hash_b);
+        assert_eq!(Ordering::Equal, a.cmp(&a));
+        assert_eq!(Some(Ordering::Less), a.partial_cmp(&LinuxSllPacketType(5)));
+    }
+}
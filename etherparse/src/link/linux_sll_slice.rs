@@ -0,0 +1,208 @@
+use crate::{err::*, *};
+
+/// Slice containing a Linux cooked capture v1 (SLL) header & payload.
+#[derive(Clone, Eq, PartialEq)]
+pub struct LinuxSllSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> LinuxSllSlice<'a> {
+    /// Try creating a [`LinuxSllSlice`] from a slice containing the
+    /// Linux SLL header & payload.
+    pub fn from_slice(slice: &'a [u8]) -> Result<LinuxSllSlice<'a>, LenError> {
+        // check length
+        if slice.len() < LinuxSllHeader::LEN {
+            return Err(LenError {
+                required_len: LinuxSllHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::LinuxSllHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(LinuxSllSlice { slice })
+    }
+
+    /// Returns the slice containing the Linux SLL header & payload.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Read the "packet type" field of the header.
+    #[inline]
+    pub fn packet_type(&self) -> LinuxSllPacketType {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSllHeader::LEN (16).
+        LinuxSllPacketType(unsafe { get_unchecked_be_u16(self.slice.as_ptr()) })
+    }
+
+    /// Read the "ARPHRD_" type of the capturing device.
+    #[inline]
+    pub fn arp_hrd_type(&self) -> ArpHardwareId {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSllHeader::LEN (16).
+        ArpHardwareId(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) })
+    }
+
+    /// Read the number of valid bytes in the sender address.
+    #[inline]
+    pub fn sender_address_valid_length(&self) -> u16 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSllHeader::LEN (16).
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Read the (zero padded or truncated) sender address.
+    #[inline]
+    pub fn sender_address(&self) -> [u8; 8] {
+        let mut result = [0u8; 8];
+        result.copy_from_slice(&self.slice[6..14]);
+        result
+    }
+
+    /// Read the protocol type field of the header indicating the protocol
+    /// after the header.
+    #[inline]
+    pub fn protocol_type(&self) -> EtherType {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of LinuxSllHeader::LEN (16).
+        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(14)) })
+    }
+
+    /// Decode all the fields and copy the results to a [`LinuxSllHeader`] struct
+    pub fn to_header(&self) -> LinuxSllHeader {
+        LinuxSllHeader {
+            packet_type: self.packet_type(),
+            arp_hrd_type: self.arp_hrd_type(),
+            sender_address_valid_length: self.sender_address_valid_length(),
+            sender_address: self.sender_address(),
+            protocol_type: self.protocol_type(),
+        }
+    }
+
+    /// Slice containing the Linux SLL header.
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        &self.slice[..LinuxSllHeader::LEN]
+    }
+
+    /// Returns the slice containing the payload & the protocol type
+    /// identifying it's content type.
+    #[inline]
+    pub fn payload(&self) -> EtherPayloadSlice<'a> {
+        EtherPayloadSlice {
+            ether_type: self.protocol_type(),
+            payload: self.payload_slice(),
+        }
+    }
+
+    /// Returns the slice containing the payload after the Linux SLL header.
+    #[inline]
+    pub fn payload_slice(&self) -> &'a [u8] {
+        &self.slice[LinuxSllHeader::LEN..]
+    }
+
+    /// Length of the Linux SLL header in bytes (equal to
+    /// [`crate::LinuxSllHeader::LEN`]).
+    #[inline]
+    pub const fn header_len(&self) -> usize {
+        LinuxSllHeader::LEN
+    }
+}
+
+impl core::fmt::Debug for LinuxSllSlice<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LinuxSllSlice")
+            .field("header", &self.to_header())
+            .field("payload", &self.payload())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_gens::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(sll in linux_sll_any()) {
+            let payload: [u8;8] = [1,2,3,4,5,6,7,8];
+            let mut data = Vec::with_capacity(sll.header_len() + payload.len());
+            data.extend_from_slice(&sll.to_bytes());
+            data.extend_from_slice(&payload);
+
+            let slice = LinuxSllSlice::from_slice(&data).unwrap();
+            prop_assert_eq!(
+                format!("{:?}", slice),
+                format!(
+                    "LinuxSllSlice {{ header: {:?}, payload: {:?} }}",
+                    slice.to_header(),
+                    slice.payload(),
+                )
+            );
+            prop_assert_eq!(slice.clone(), slice);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(sll in linux_sll_any()) {
+            let payload: [u8;8] = [1,2,3,4,5,6,7,8];
+            let mut data = Vec::with_capacity(sll.header_len() + payload.len());
+            data.extend_from_slice(&sll.to_bytes());
+            data.extend_from_slice(&payload);
+
+            let slice = LinuxSllSlice::from_slice(&data).unwrap();
+            assert_eq!(sll.packet_type, slice.packet_type());
+            assert_eq!(sll.arp_hrd_type, slice.arp_hrd_type());
+            assert_eq!(sll.sender_address_valid_length, slice.sender_address_valid_length());
+            assert_eq!(sll.sender_address, slice.sender_address());
+            assert_eq!(sll.protocol_type, slice.protocol_type());
+            assert_eq!(&payload, slice.payload_slice());
+            assert_eq!(
+                EtherPayloadSlice{
+                    payload: &payload,
+                    ether_type: sll.protocol_type,
+                },
+                slice.payload()
+            );
+            assert_eq!(sll, slice.to_header());
+            assert_eq!(&data, slice.slice());
+            assert_eq!(&data[..LinuxSllHeader::LEN], slice.header_slice());
+            assert_eq!(LinuxSllHeader::LEN, slice.header_len());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(sll in linux_sll_any()) {
+            let data = sll.to_bytes();
+
+            // ok
+            assert!(LinuxSllSlice::from_slice(&data).is_ok());
+
+            // length error
+            for len in 0..data.len() {
+                assert_eq!(
+                    LinuxSllSlice::from_slice(&data[..len]).unwrap_err(),
+                    LenError{
+                        required_len: LinuxSllHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::LinuxSllHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+}
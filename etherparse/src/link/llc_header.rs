@@ -0,0 +1,297 @@
+use crate::*;
+use arrayvec::ArrayVec;
+
+/// IEEE 802.2 LLC (Logical Link Control) header.
+///
+/// The LLC header follows the length field of IEEE 802.3 frames. If
+/// the DSAP & SSAP are set to [`LlcHeader::SAP_SNAP`] and the control
+/// field is set to [`LlcHeader::CONTROL_UI`] a [`crate::SnapHeader`]
+/// follows the LLC header (see [`LlcHeader::has_snap_header`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LlcHeader {
+    /// Destination service access point.
+    pub dsap: u8,
+    /// Source service access point (the lowest bit is the
+    /// command/response bit).
+    pub ssap: u8,
+    /// First byte of the control field.
+    ///
+    /// If the two lowest bits are set the frame is an unnumbered
+    /// ("U" format) frame with a one byte control field. Otherwise
+    /// the control field is two bytes long.
+    pub control: u8,
+    /// Second byte of the control field (only present in information
+    /// ("I" format) & supervisory ("S" format) frames).
+    pub control_ext: Option<u8>,
+}
+
+impl LlcHeader {
+    /// Minimum length of a LLC header in bytes/octets.
+    pub const MIN_LEN: usize = 3;
+
+    /// Maximum length of a LLC header in bytes/octets.
+    pub const MAX_LEN: usize = 4;
+
+    /// Service access point indicating that a SNAP header follows.
+    pub const SAP_SNAP: u8 = 0xAA;
+
+    /// Control field value of an "unnumbered information" frame.
+    pub const CONTROL_UI: u8 = 0x03;
+
+    /// Bits in the first control byte that are set for unnumbered
+    /// ("U" format) frames with a one byte control field.
+    pub const CONTROL_U_FORMAT_MASK: u8 = 0b11;
+
+    /// Read an LlcHeader from a slice and return the header & unused parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(LlcHeader, &[u8]), err::LenError> {
+        if slice.len() < LlcHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: LlcHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::LlcHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        let len = LlcHeader::header_len_from_control(slice[2]);
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::LlcHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok((
+            LlcHeader {
+                dsap: slice[0],
+                ssap: slice[1],
+                control: slice[2],
+                control_ext: if len == LlcHeader::MAX_LEN {
+                    Some(slice[3])
+                } else {
+                    None
+                },
+            },
+            &slice[len..],
+        ))
+    }
+
+    /// Tries to read a LLC header from the current position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<LlcHeader, std::io::Error> {
+        let mut bytes = [0u8; 3];
+        reader.read_exact(&mut bytes)?;
+        let control_ext = if LlcHeader::MAX_LEN == LlcHeader::header_len_from_control(bytes[2]) {
+            let mut ext = [0u8; 1];
+            reader.read_exact(&mut ext)?;
+            Some(ext[0])
+        } else {
+            None
+        };
+        Ok(LlcHeader {
+            dsap: bytes[0],
+            ssap: bytes[1],
+            control: bytes[2],
+            control_ext,
+        })
+    }
+
+    /// Write the LLC header to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Calculates the header length based on the first byte of the control field.
+    #[inline]
+    pub(crate) fn header_len_from_control(control: u8) -> usize {
+        if control & LlcHeader::CONTROL_U_FORMAT_MASK == LlcHeader::CONTROL_U_FORMAT_MASK {
+            LlcHeader::MIN_LEN
+        } else {
+            LlcHeader::MAX_LEN
+        }
+    }
+
+    /// Returns true if DSAP, SSAP & control field indicate that a
+    /// SNAP header follows the LLC header.
+    #[inline]
+    pub fn has_snap_header(&self) -> bool {
+        LlcHeader::SAP_SNAP == self.dsap
+            && LlcHeader::SAP_SNAP == (self.ssap & 0b1111_1110)
+            && LlcHeader::CONTROL_UI == self.control
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        if self.control_ext.is_some() {
+            LlcHeader::MAX_LEN
+        } else {
+            LlcHeader::MIN_LEN
+        }
+    }
+
+    /// Returns the serialized form of the header.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { LlcHeader::MAX_LEN }> {
+        let mut result = ArrayVec::new();
+        result.push(self.dsap);
+        result.push(self.ssap);
+        result.push(self.control);
+        if let Some(ext) = self.control_ext {
+            result.push(ext);
+        }
+        result
+    }
+}
+
+impl Default for LlcHeader {
+    fn default() -> Self {
+        LlcHeader {
+            dsap: LlcHeader::SAP_SNAP,
+            ssap: LlcHeader::SAP_SNAP,
+            control: LlcHeader::CONTROL_UI,
+            control_ext: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn default() {
+        let header = LlcHeader::default();
+        assert_eq!(0xAA, header.dsap);
+        assert_eq!(0xAA, header.ssap);
+        assert_eq!(0x03, header.control);
+        assert_eq!(None, header.control_ext);
+        assert!(header.has_snap_header());
+    }
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(header in llc_any()) {
+            assert_eq!(header.clone(), header);
+            assert_eq!(
+                format!("{:?}", header),
+                format!(
+                    "LlcHeader {{ dsap: {}, ssap: {}, control: {}, control_ext: {:?} }}",
+                    header.dsap, header.ssap, header.control, header.control_ext
+                )
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            header in llc_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(header.header_len() + dummy_data.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+
+            // ok case
+            {
+                let (result, rest) = LlcHeader::from_slice(&buffer).unwrap();
+                assert_eq!(result, header);
+                assert_eq!(rest, &dummy_data[..]);
+            }
+
+            // length error
+            for len in 0..header.header_len() {
+                assert_eq!(
+                    LlcHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: if len < LlcHeader::MIN_LEN {
+                            LlcHeader::MIN_LEN
+                        } else {
+                            header.header_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::LlcHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(header in llc_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(header.header_len());
+                header.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &header.to_bytes()[..]);
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(header, LlcHeader::read(&mut cursor).unwrap());
+                assert_eq!(header.header_len(), cursor.position() as usize);
+            }
+            // io errors
+            for len in 0..header.header_len() {
+                let bytes = header.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(LlcHeader::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; LlcHeader::MAX_LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(header.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn has_snap_header() {
+        let snap = LlcHeader::default();
+        assert!(snap.has_snap_header());
+        // response bit set
+        assert!(LlcHeader {
+            ssap: 0xAB,
+            ..snap.clone()
+        }
+        .has_snap_header());
+        assert!(!LlcHeader {
+            dsap: 0x42,
+            ..snap.clone()
+        }
+        .has_snap_header());
+        assert!(!LlcHeader {
+            ssap: 0x42,
+            ..snap.clone()
+        }
+        .has_snap_header());
+        assert!(!LlcHeader {
+            control: 0x00,
+            control_ext: Some(0),
+            ..snap.clone()
+        }
+        .has_snap_header());
+    }
+
+    proptest! {
+        #[test]
+        fn header_len_to_bytes(header in llc_any()) {
+            let bytes = header.to_bytes();
+            assert_eq!(bytes.len(), header.header_len());
+            assert_eq!(bytes[0], header.dsap);
+            assert_eq!(bytes[1], header.ssap);
+            assert_eq!(bytes[2], header.control);
+            assert_eq!(bytes.get(3).copied(), header.control_ext);
+        }
+    }
+}
@@ -6,10 +6,23 @@ pub mod ether_type_impl;
 pub mod ethernet2_header;
 pub mod ethernet2_header_slice;
 pub mod ethernet2_slice;
+pub mod ieee8023_slice;
 pub mod link_slice;
+pub mod linux_sll2_header;
+pub mod linux_sll2_slice;
+pub mod linux_sll_header;
+pub mod linux_sll_packet_type;
+pub mod linux_sll_slice;
+pub mod llc_header;
+pub mod mpls_header;
+pub mod mpls_label_stack_iter;
+pub mod mpls_label_stack_slice;
+pub mod pppoe_header;
+pub mod pppoe_header_slice;
 pub mod single_vlan_header;
 pub mod single_vlan_header_slice;
 pub mod single_vlan_slice;
+pub mod snap_header;
 pub mod vlan_header;
 pub mod vlan_id;
 pub mod vlan_pcp;
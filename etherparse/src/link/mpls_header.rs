@@ -0,0 +1,215 @@
+use crate::*;
+
+/// A single MPLS label stack entry according to
+/// [RFC 3032](https://datatracker.ietf.org/doc/html/rfc3032)
+/// (ether types 0x8847 & 0x8848).
+///
+/// Use [`MplsLabelStackSlice`] to decode a complete label stack.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MplsHeader {
+    /// 20 bit label value.
+    pub label: u32,
+    /// 3 bit traffic class (formerly called "experimental use" bits).
+    pub traffic_class: u8,
+    /// Set if this is the last entry of the label stack.
+    pub bottom_of_stack: bool,
+    /// Time to live.
+    pub ttl: u8,
+}
+
+impl MplsHeader {
+    /// Serialized size of a MPLS label stack entry in bytes/octets.
+    pub const LEN: usize = 4;
+
+    /// Maximum value of the 20 bit label.
+    pub const MAX_LABEL: u32 = 0xf_ffff;
+
+    /// Maximum value of the 3 bit traffic class.
+    pub const MAX_TRAFFIC_CLASS: u8 = 0b111;
+
+    /// Read a MPLS label stack entry from a slice and return the entry
+    /// & unused parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(MplsHeader, &[u8]), err::LenError> {
+        if slice.len() < MplsHeader::LEN {
+            return Err(err::LenError {
+                required_len: MplsHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::MplsLabelStack,
+                layer_start_offset: 0,
+            });
+        }
+        Ok((
+            MplsHeader::from_bytes([slice[0], slice[1], slice[2], slice[3]]),
+            &slice[MplsHeader::LEN..],
+        ))
+    }
+
+    /// Read a MPLS label stack entry from a static sized byte array.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 4]) -> MplsHeader {
+        MplsHeader {
+            label: u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]) >> 4,
+            traffic_class: (bytes[2] >> 1) & MplsHeader::MAX_TRAFFIC_CLASS,
+            bottom_of_stack: 0 != (bytes[2] & 1),
+            ttl: bytes[3],
+        }
+    }
+
+    /// Reads a MPLS label stack entry from the current position of the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<MplsHeader, std::io::Error> {
+        let mut buffer = [0u8; MplsHeader::LEN];
+        reader.read_exact(&mut buffer)?;
+        Ok(MplsHeader::from_bytes(buffer))
+    }
+
+    /// Writes the MPLS label stack entry to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        MplsHeader::LEN
+    }
+
+    /// Returns the serialized entry (bits of the label & traffic class
+    /// exceeding their field sizes are ignored).
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 4] {
+        let label_be = ((self.label & MplsHeader::MAX_LABEL) << 4).to_be_bytes();
+        [
+            label_be[1],
+            label_be[2],
+            label_be[3]
+                | ((self.traffic_class & MplsHeader::MAX_TRAFFIC_CLASS) << 1)
+                | u8::from(self.bottom_of_stack),
+            self.ttl,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    proptest! {
+        #[test]
+        fn from_slice(header in mpls_any(), rest in proptest::collection::vec(any::<u8>(), 0..4)) {
+            let mut buffer = Vec::with_capacity(MplsHeader::LEN + rest.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&rest);
+
+            // ok
+            {
+                let (actual, actual_rest) = MplsHeader::from_slice(&buffer).unwrap();
+                assert_eq!(actual, header);
+                assert_eq!(actual_rest, &rest[..]);
+            }
+
+            // length error
+            for len in 0..MplsHeader::LEN {
+                assert_eq!(
+                    MplsHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: MplsHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::MplsLabelStack,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(header in mpls_any()) {
+            assert_eq!(header, MplsHeader::from_bytes(header.to_bytes()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(header in mpls_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(header.header_len());
+                header.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &header.to_bytes()[..]);
+
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(header, MplsHeader::read(&mut cursor).unwrap());
+                assert_eq!(MplsHeader::LEN, cursor.position() as usize);
+            }
+
+            // io errors
+            for len in 0..MplsHeader::LEN {
+                let bytes = header.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(MplsHeader::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; MplsHeader::LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(header.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn header_len() {
+        assert_eq!(4, MplsHeader::default().header_len());
+    }
+
+    #[test]
+    fn to_bytes() {
+        assert_eq!(
+            MplsHeader {
+                label: 0x12345,
+                traffic_class: 0b101,
+                bottom_of_stack: true,
+                ttl: 0x40,
+            }
+            .to_bytes(),
+            [0x12, 0x34, 0x5b, 0x40]
+        );
+        // bits exceeding the field sizes are ignored
+        assert_eq!(
+            MplsHeader {
+                label: 0xfff0_0001,
+                traffic_class: 0xf8,
+                bottom_of_stack: false,
+                ttl: 1,
+            }
+            .to_bytes(),
+            [0, 0, 0x10, 1]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_debug(header in mpls_any()) {
+            assert_eq!(header, header.clone());
+            assert_eq!(
+                format!(
+                    "MplsHeader {{ label: {:?}, traffic_class: {:?}, bottom_of_stack: {:?}, ttl: {:?} }}",
+                    header.label,
+                    header.traffic_class,
+                    header.bottom_of_stack,
+                    header.ttl
+                ),
+                format!("{:?}", header)
+            );
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::*;
+
+/// Iterator over the entries of a MPLS label stack (see [`MplsLabelStackSlice::iter`]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MplsLabelStackIter<'a> {
+    /// Not yet returned label stack entries.
+    rest: &'a [u8],
+}
+
+impl<'a> MplsLabelStackIter<'a> {
+    /// Creates an iterator over the label stack entries contained in the
+    /// given slice (4 bytes per entry, trailing bytes are ignored).
+    ///
+    /// Note that the iterator does not stop at an entry with the bottom
+    /// of stack bit set (use [`MplsLabelStackSlice`] to determine the end
+    /// of a label stack).
+    #[inline]
+    pub fn from_slice(slice: &'a [u8]) -> MplsLabelStackIter<'a> {
+        MplsLabelStackIter { rest: slice }
+    }
+}
+
+impl<'a> Iterator for MplsLabelStackIter<'a> {
+    type Item = MplsHeader;
+
+    fn next(&mut self) -> Option<MplsHeader> {
+        match self.rest {
+            [a, b, c, d, rest @ ..] => {
+                self.rest = rest;
+                Some(MplsHeader::from_bytes([*a, *b, *c, *d]))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = MplsLabelStackIter::from_slice(&[1]);
+        assert_eq!(iter.clone(), iter);
+        assert_eq!(format!("{:?}", iter), "MplsLabelStackIter { rest: [1] }");
+    }
+
+    #[test]
+    fn next() {
+        assert_eq!(
+            MplsLabelStackIter::from_slice(&[]).collect::<Vec<_>>(),
+            Vec::<MplsHeader>::new()
+        );
+        assert_eq!(
+            MplsLabelStackIter::from_slice(&[0, 0, 0x10, 1, 0, 0, 0x21, 2, 9]).collect::<Vec<_>>(),
+            [
+                MplsHeader {
+                    label: 1,
+                    traffic_class: 0,
+                    bottom_of_stack: false,
+                    ttl: 1,
+                },
+                MplsHeader {
+                    label: 2,
+                    traffic_class: 0,
+                    bottom_of_stack: true,
+                    ttl: 2,
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::*;
+
+/// Slice containing a MPLS label stack (all entries up to and including
+/// the entry with the bottom of stack bit set) & the payload after it.
+///
+/// As MPLS does not carry a type identifier for the payload after the
+/// label stack it is up to the user to decide how the payload is decoded
+/// (e.g. based on the labels or with the help of
+/// [`MplsLabelStackSlice::payload_ether_type_guess`]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MplsLabelStackSlice<'a> {
+    /// Slice containing the label stack entries.
+    stack: &'a [u8],
+    /// Slice containing the data after the label stack.
+    payload: &'a [u8],
+}
+
+impl<'a> MplsLabelStackSlice<'a> {
+    /// Decodes the label stack at the start of the slice.
+    ///
+    /// The stack ends with the first entry that has the bottom of stack
+    /// bit set. A [`err::LenError`] is returned if the slice ends before
+    /// such an entry is found.
+    pub fn from_slice(slice: &'a [u8]) -> Result<MplsLabelStackSlice<'a>, err::LenError> {
+        let mut offset = 0;
+        loop {
+            if slice.len() < offset + MplsHeader::LEN {
+                return Err(err::LenError {
+                    required_len: offset + MplsHeader::LEN,
+                    len: slice.len(),
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::MplsLabelStack,
+                    layer_start_offset: 0,
+                });
+            }
+            // check the bottom of stack bit
+            let bottom_of_stack = 0 != (slice[offset + 2] & 1);
+            offset += MplsHeader::LEN;
+            if bottom_of_stack {
+                let (stack, payload) = slice.split_at(offset);
+                return Ok(MplsLabelStackSlice { stack, payload });
+            }
+        }
+    }
+
+    /// Returns the slice containing the label stack entries.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.stack
+    }
+
+    /// Returns the slice containing the data after the label stack.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        self.payload
+    }
+
+    /// Returns the number of entries in the label stack (at least 1).
+    #[inline]
+    pub fn num_entries(&self) -> usize {
+        self.stack.len() / MplsHeader::LEN
+    }
+
+    /// Returns an iterator over the entries of the label stack.
+    #[inline]
+    pub fn iter(&self) -> MplsLabelStackIter<'a> {
+        MplsLabelStackIter::from_slice(self.stack)
+    }
+
+    /// Returns the first (outermost) entry of the label stack.
+    #[inline]
+    pub fn top(&self) -> MplsHeader {
+        // SAFETY:
+        // Safe as the constructor checks that at least one
+        // complete entry is present.
+        MplsHeader::from_bytes(unsafe { get_unchecked_4_byte_array(self.stack.as_ptr()) })
+    }
+
+    /// Returns the last entry of the label stack (the entry with the
+    /// bottom of stack bit set).
+    #[inline]
+    pub fn bottom(&self) -> MplsHeader {
+        // SAFETY:
+        // Safe as the constructor checks that at least one complete
+        // entry is present & the stack length is a multiple of 4.
+        MplsHeader::from_bytes(unsafe {
+            get_unchecked_4_byte_array(self.stack.as_ptr().add(self.stack.len() - MplsHeader::LEN))
+        })
+    }
+
+    /// Guesses the ether type of the payload based on the first nibble
+    /// (IP version) of the payload.
+    ///
+    /// Returns [`EtherType::IPV4`] if the first nibble is 4,
+    /// [`EtherType::IPV6`] if it is 6 and `None` otherwise. Note that this
+    /// is only a heuristic, as MPLS does not identify the payload type.
+    pub fn payload_ether_type_guess(&self) -> Option<EtherType> {
+        match self.payload.first().map(|v| v >> 4) {
+            Some(4) => Some(EtherType::IPV4),
+            Some(6) => Some(EtherType::IPV6),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    prop_compose! {
+        fn mpls_stack_any()(
+            entries in proptest::collection::vec(mpls_any(), 1..4)
+        ) -> Vec<MplsHeader> {
+            let last = entries.len() - 1;
+            entries
+                .into_iter()
+                .enumerate()
+                .map(|(i, mut e)| {
+                    e.bottom_of_stack = i == last;
+                    e
+                })
+                .collect()
+        }
+    }
+
+    fn serialize(entries: &[MplsHeader], payload: &[u8]) -> Vec<u8> {
+        let mut result = Vec::with_capacity(entries.len() * MplsHeader::LEN + payload.len());
+        for e in entries {
+            result.extend_from_slice(&e.to_bytes());
+        }
+        result.extend_from_slice(payload);
+        result
+    }
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(entries in mpls_stack_any()) {
+            let bytes = serialize(&entries, &[1, 2]);
+            let slice = MplsLabelStackSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.clone(), slice);
+            assert_eq!(
+                format!("{:?}", slice),
+                format!(
+                    "MplsLabelStackSlice {{ stack: {:?}, payload: {:?} }}",
+                    slice.slice(),
+                    slice.payload()
+                )
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            entries in mpls_stack_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..10)
+        ) {
+            let stack_len = entries.len() * MplsHeader::LEN;
+            let bytes = serialize(&entries, &payload);
+
+            // ok
+            {
+                let slice = MplsLabelStackSlice::from_slice(&bytes).unwrap();
+                assert_eq!(slice.slice(), &bytes[..stack_len]);
+                assert_eq!(slice.payload(), &payload[..]);
+                assert_eq!(slice.num_entries(), entries.len());
+                assert_eq!(slice.iter().collect::<Vec<_>>(), entries);
+                assert_eq!(slice.top(), entries[0]);
+                assert_eq!(&slice.bottom(), entries.last().unwrap());
+            }
+
+            // length error
+            for len in 0..stack_len {
+                assert_eq!(
+                    MplsLabelStackSlice::from_slice(&bytes[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: (len / MplsHeader::LEN + 1) * MplsHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::MplsLabelStack,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+
+            // missing bottom of stack bit
+            {
+                let mut entries = entries.clone();
+                entries.last_mut().unwrap().bottom_of_stack = false;
+                let bytes = serialize(&entries, &[]);
+                assert_eq!(
+                    MplsLabelStackSlice::from_slice(&bytes).unwrap_err(),
+                    err::LenError {
+                        required_len: stack_len + MplsHeader::LEN,
+                        len: stack_len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::MplsLabelStack,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn payload_ether_type_guess(
+            entries in mpls_stack_any(),
+            first in any::<u8>()
+        ) {
+            // empty payload
+            {
+                let bytes = serialize(&entries, &[]);
+                let slice = MplsLabelStackSlice::from_slice(&bytes).unwrap();
+                assert_eq!(None, slice.payload_ether_type_guess());
+            }
+            // payload with content
+            {
+                let bytes = serialize(&entries, &[first]);
+                let slice = MplsLabelStackSlice::from_slice(&bytes).unwrap();
+                assert_eq!(
+                    match first >> 4 {
+                        4 => Some(EtherType::IPV4),
+                        6 => Some(EtherType::IPV6),
+                        _ => None,
+                    },
+                    slice.payload_ether_type_guess()
+                );
+            }
+        }
+    }
+}
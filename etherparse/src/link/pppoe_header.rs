@@ -0,0 +1,250 @@
+use crate::*;
+use arrayvec::ArrayVec;
+
+/// PPP over Ethernet (PPPoE) header according to
+/// [RFC 2516](https://datatracker.ietf.org/doc/html/rfc2516)
+/// (ether types 0x8863 & 0x8864).
+///
+/// In the session stage (code [`PppoeHeader::CODE_SESSION_DATA`]) the
+/// header is followed by the 2 byte PPP protocol field, which is stored
+/// in `ppp_protocol`. The PPP protocol field is counted as part of the
+/// payload by the `payload_length` field.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PppoeHeader {
+    /// PPPoE version (4 bits, must be 1 according to RFC 2516).
+    pub version: u8,
+    /// PPPoE type (4 bits, must be 1 according to RFC 2516).
+    pub pppoe_type: u8,
+    /// Code identifying the discovery packet type or
+    /// [`PppoeHeader::CODE_SESSION_DATA`] for session data.
+    pub code: u8,
+    /// Identifier of the PPP session.
+    pub session_id: u16,
+    /// Length of the PPPoE payload in bytes (including the PPP
+    /// protocol field if present, excluding the PPPoE header).
+    pub payload_length: u16,
+    /// PPP protocol field (present in session data packets).
+    pub ppp_protocol: Option<u16>,
+}
+
+impl PppoeHeader {
+    /// Length of a PPPoE header without the PPP protocol field.
+    pub const MIN_LEN: usize = 6;
+
+    /// Length of a PPPoE header including the PPP protocol field.
+    pub const MAX_LEN: usize = 8;
+
+    /// Code of a session data packet.
+    pub const CODE_SESSION_DATA: u8 = 0x00;
+
+    /// Code of a PPPoE Active Discovery Offer (PADO) packet.
+    pub const CODE_PADO: u8 = 0x07;
+
+    /// Code of a PPPoE Active Discovery Initiation (PADI) packet.
+    pub const CODE_PADI: u8 = 0x09;
+
+    /// Code of a PPPoE Active Discovery Request (PADR) packet.
+    pub const CODE_PADR: u8 = 0x19;
+
+    /// Code of a PPPoE Active Discovery Session-confirmation (PADS) packet.
+    pub const CODE_PADS: u8 = 0x65;
+
+    /// Code of a PPPoE Active Discovery Terminate (PADT) packet.
+    pub const CODE_PADT: u8 = 0xa7;
+
+    /// PPP protocol number indicating an IPv4 payload.
+    pub const PPP_PROTOCOL_IPV4: u16 = 0x0021;
+
+    /// PPP protocol number indicating an IPv6 payload.
+    pub const PPP_PROTOCOL_IPV6: u16 = 0x0057;
+
+    /// Read a PPPoE header from a slice and return the header & unused
+    /// parts of the slice (see [`PppoeHeaderSlice::from_slice`] for the
+    /// length checks).
+    pub fn from_slice(slice: &[u8]) -> Result<(PppoeHeader, &[u8]), err::LenError> {
+        let s = PppoeHeaderSlice::from_slice(slice)?;
+        Ok((s.to_header(), &slice[s.slice().len()..]))
+    }
+
+    /// Reads a PPPoE header from the current position of the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<PppoeHeader, std::io::Error> {
+        let mut buffer = [0u8; PppoeHeader::MAX_LEN];
+        reader.read_exact(&mut buffer[..PppoeHeader::MIN_LEN])?;
+        let len = PppoeHeaderSlice::header_len_from_code(buffer[1]);
+        reader.read_exact(&mut buffer[PppoeHeader::MIN_LEN..len])?;
+        // SAFETY:
+        // Safe as the buffer contains at least the required
+        // number of bytes given by the code.
+        Ok(unsafe { PppoeHeaderSlice::from_slice_unchecked(&buffer[..len]) }.to_header())
+    }
+
+    /// Writes the PPPoE header to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        PppoeHeader::MIN_LEN + if self.ppp_protocol.is_some() { 2 } else { 0 }
+    }
+
+    /// Returns the serialized header.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { PppoeHeader::MAX_LEN }> {
+        let session_id_be = self.session_id.to_be_bytes();
+        let payload_length_be = self.payload_length.to_be_bytes();
+
+        let mut result = ArrayVec::new();
+        result.extend([
+            ((self.version & 0xf) << 4) | (self.pppoe_type & 0xf),
+            self.code,
+            session_id_be[0],
+            session_id_be[1],
+            payload_length_be[0],
+            payload_length_be[1],
+        ]);
+        if let Some(ppp_protocol) = self.ppp_protocol {
+            result.extend(ppp_protocol.to_be_bytes());
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    proptest! {
+        #[test]
+        fn from_slice(header in pppoe_any()) {
+            let mut buffer = Vec::with_capacity(
+                PppoeHeader::MIN_LEN + usize::from(header.payload_length)
+            );
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.resize(
+                core::cmp::max(
+                    header.header_len(),
+                    PppoeHeader::MIN_LEN + usize::from(header.payload_length)
+                ),
+                0
+            );
+
+            // ok
+            {
+                let (actual, rest) = PppoeHeader::from_slice(&buffer).unwrap();
+                assert_eq!(actual, header);
+                assert_eq!(rest, &buffer[header.header_len()..]);
+            }
+
+            // length error
+            for len in 0..header.header_len() {
+                assert_eq!(
+                    PppoeHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: if len < PppoeHeader::MIN_LEN {
+                            PppoeHeader::MIN_LEN
+                        } else {
+                            header.header_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::PppoeHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(header in pppoe_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(header.header_len());
+                header.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &header.to_bytes()[..]);
+
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(header, PppoeHeader::read(&mut cursor).unwrap());
+                assert_eq!(header.header_len(), cursor.position() as usize);
+            }
+
+            // io errors
+            for len in 0..header.header_len() {
+                let bytes = header.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(PppoeHeader::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; PppoeHeader::MAX_LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(header.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn header_len() {
+        let mut header: PppoeHeader = Default::default();
+        assert_eq!(6, header.header_len());
+        header.ppp_protocol = Some(PppoeHeader::PPP_PROTOCOL_IPV4);
+        assert_eq!(8, header.header_len());
+    }
+
+    #[test]
+    fn to_bytes() {
+        // discovery
+        assert_eq!(
+            &PppoeHeader {
+                version: 1,
+                pppoe_type: 1,
+                code: PppoeHeader::CODE_PADI,
+                session_id: 0,
+                payload_length: 4,
+                ppp_protocol: None,
+            }
+            .to_bytes()[..],
+            &[0x11, 0x09, 0, 0, 0, 4]
+        );
+        // session data & bits of version & type masked
+        assert_eq!(
+            &PppoeHeader {
+                version: 0xf1,
+                pppoe_type: 0xf2,
+                code: PppoeHeader::CODE_SESSION_DATA,
+                session_id: 0x1234,
+                payload_length: 0x5678,
+                ppp_protocol: Some(PppoeHeader::PPP_PROTOCOL_IPV6),
+            }
+            .to_bytes()[..],
+            &[0x12, 0, 0x12, 0x34, 0x56, 0x78, 0x00, 0x57]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_debug(header in pppoe_any()) {
+            assert_eq!(header, header.clone());
+            assert_eq!(
+                format!(
+                    "PppoeHeader {{ version: {:?}, pppoe_type: {:?}, code: {:?}, session_id: {:?}, payload_length: {:?}, ppp_protocol: {:?} }}",
+                    header.version,
+                    header.pppoe_type,
+                    header.code,
+                    header.session_id,
+                    header.payload_length,
+                    header.ppp_protocol
+                ),
+                format!("{:?}", header)
+            );
+        }
+    }
+}
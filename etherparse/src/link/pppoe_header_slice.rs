@@ -0,0 +1,271 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing a PPPoE header (including the PPP protocol field
+/// in case of session data).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PppoeHeaderSlice<'a> {
+    /// Slice containing the header data.
+    slice: &'a [u8],
+}
+
+impl<'a> PppoeHeaderSlice<'a> {
+    /// Creates a PPPoE header slice from a slice.
+    ///
+    /// The PPP protocol field is considered part of the header if the
+    /// code is [`PppoeHeader::CODE_SESSION_DATA`]. Additionally it is
+    /// checked that the slice contains the complete payload indicated
+    /// by the "payload length" field.
+    pub fn from_slice(slice: &'a [u8]) -> Result<PppoeHeaderSlice<'a>, err::LenError> {
+        // check length
+        if slice.len() < PppoeHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: PppoeHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::PppoeHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check the length again now that it is known if the ppp protocol is present
+        let len = PppoeHeaderSlice::header_len_from_code(slice[1]);
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::PppoeHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check that the payload is present
+        let total_len =
+            PppoeHeader::MIN_LEN + usize::from(u16::from_be_bytes([slice[4], slice[5]]));
+        if slice.len() < total_len {
+            return Err(err::LenError {
+                required_len: total_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::PppoeHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(PppoeHeaderSlice {
+            // SAFETY:
+            // Safe as the slice has been checked in the previous ifs
+            // to have at least the the length of the variable len.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Creates a PPPoE header slice from a slice (assumes slice
+    /// size & content was validated before).
+    ///
+    /// # Safety
+    ///
+    /// This method assumes that the slice was previously validated to contain
+    /// a valid PPPoE header. This means the slice length must at least be
+    /// at least 6 and 8 if the code indicates session data.
+    ///
+    /// If these preconditions are not fulfilled the behavior of this function
+    /// and the methods of the return [`PppoeHeaderSlice`] will be undefined.
+    pub unsafe fn from_slice_unchecked(slice: &'a [u8]) -> PppoeHeaderSlice<'a> {
+        PppoeHeaderSlice {
+            slice: from_raw_parts(
+                slice.as_ptr(),
+                PppoeHeaderSlice::header_len_from_code(*slice.get_unchecked(1)),
+            ),
+        }
+    }
+
+    /// Calculates the header length based on the code of a PPPoE header.
+    #[inline]
+    pub(crate) fn header_len_from_code(code: u8) -> usize {
+        if PppoeHeader::CODE_SESSION_DATA == code {
+            PppoeHeader::MAX_LEN
+        } else {
+            PppoeHeader::MIN_LEN
+        }
+    }
+
+    /// Returns the slice containing the PPPoE header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the PPPoE version (4 bits).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 6 bytes in constructor.
+        (unsafe { *self.slice.get_unchecked(0) }) >> 4
+    }
+
+    /// Returns the PPPoE type (4 bits).
+    #[inline]
+    pub fn pppoe_type(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 6 bytes in constructor.
+        (unsafe { *self.slice.get_unchecked(0) }) & 0xf
+    }
+
+    /// Returns the code field.
+    #[inline]
+    pub fn code(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 6 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(1) }
+    }
+
+    /// Returns the session id.
+    #[inline]
+    pub fn session_id(&self) -> u16 {
+        // SAFETY:
+        // Slice size checked to be at least 6 bytes in constructor.
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+    }
+
+    /// Returns the "payload length" field (length of the payload
+    /// including the PPP protocol field).
+    #[inline]
+    pub fn payload_length(&self) -> u16 {
+        // SAFETY:
+        // Slice size checked to be at least 6 bytes in constructor.
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Returns the PPP protocol field if the code indicates session data.
+    #[inline]
+    pub fn ppp_protocol(&self) -> Option<u16> {
+        if self.slice.len() == PppoeHeader::MAX_LEN {
+            // SAFETY:
+            // Slice size checked to be 8 bytes.
+            Some(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(6)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the length of the payload after the header (and PPP
+    /// protocol field) based on the "payload length" field.
+    #[inline]
+    pub fn payload_len(&self) -> usize {
+        (PppoeHeader::MIN_LEN + usize::from(self.payload_length())).saturating_sub(self.slice.len())
+    }
+
+    /// Decode all the fields and copy the results to a [`PppoeHeader`] struct.
+    pub fn to_header(&self) -> PppoeHeader {
+        PppoeHeader {
+            version: self.version(),
+            pppoe_type: self.pppoe_type(),
+            code: self.code(),
+            session_id: self.session_id(),
+            payload_length: self.payload_length(),
+            ppp_protocol: self.ppp_protocol(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(header in pppoe_any()) {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&header.to_bytes());
+            bytes.resize(PppoeHeader::MAX_LEN + usize::from(header.payload_length), 0);
+            let slice = PppoeHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.clone(), slice);
+            assert_eq!(
+                format!("{:?}", slice),
+                format!("PppoeHeaderSlice {{ slice: {:?} }}", slice.slice())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(header in pppoe_any()) {
+            let total_len = core::cmp::max(
+                header.header_len(),
+                PppoeHeader::MIN_LEN + usize::from(header.payload_length),
+            );
+            let mut bytes = Vec::with_capacity(total_len + 1);
+            bytes.extend_from_slice(&header.to_bytes());
+            bytes.resize(total_len + 1, 0);
+
+            // ok
+            {
+                let slice = PppoeHeaderSlice::from_slice(&bytes).unwrap();
+                assert_eq!(slice.slice(), &bytes[..header.header_len()]);
+                assert_eq!(slice.payload_len(), total_len - header.header_len());
+            }
+
+            // length error
+            for len in 0..total_len {
+                assert_eq!(
+                    PppoeHeaderSlice::from_slice(&bytes[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: if len < PppoeHeader::MIN_LEN {
+                            PppoeHeader::MIN_LEN
+                        } else if len < header.header_len() {
+                            header.header_len()
+                        } else {
+                            total_len
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::PppoeHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_unchecked(header in pppoe_any()) {
+            let bytes = header.to_bytes();
+            let slice = unsafe {
+                PppoeHeaderSlice::from_slice_unchecked(&bytes)
+            };
+            assert_eq!(&bytes[..], slice.slice());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(header in pppoe_any()) {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&header.to_bytes());
+            bytes.resize(PppoeHeader::MAX_LEN + usize::from(header.payload_length), 0);
+            let slice = PppoeHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.version(), header.version);
+            assert_eq!(slice.pppoe_type(), header.pppoe_type);
+            assert_eq!(slice.code(), header.code);
+            assert_eq!(slice.session_id(), header.session_id);
+            assert_eq!(slice.payload_length(), header.payload_length);
+            assert_eq!(slice.ppp_protocol(), header.ppp_protocol);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(header in pppoe_any()) {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&header.to_bytes());
+            bytes.resize(PppoeHeader::MAX_LEN + usize::from(header.payload_length), 0);
+            let slice = PppoeHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(header, slice.to_header());
+        }
+    }
+}
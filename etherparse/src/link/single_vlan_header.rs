@@ -1,7 +1,8 @@
 use crate::*;
 
 /// IEEE 802.1Q VLAN Tagging Header
-#[derive(Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct SingleVlanHeader {
     /// A 3 bit number which refers to the IEEE 802.1p class of service and maps to the frame priority level.
     pub pcp: VlanPcp,
@@ -106,6 +107,33 @@ impl SingleVlanHeader {
     }
 }
 
+/// Writes a compact single line summary of the header.
+///
+/// ```
+/// use etherparse::{EtherType, SingleVlanHeader, VlanId, VlanPcp};
+///
+/// let header = SingleVlanHeader {
+///     pcp: VlanPcp::try_new(3).unwrap(),
+///     drop_eligible_indicator: false,
+///     vlan_id: VlanId::try_new(100).unwrap(),
+///     ether_type: EtherType::IPV6,
+/// };
+/// assert_eq!("VLAN id=100 pcp=3 type=IPv6", format!("{}", header));
+/// ```
+impl core::fmt::Display for SingleVlanHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "VLAN id={} pcp={}", self.vlan_id, self.pcp)?;
+        if self.drop_eligible_indicator {
+            write!(f, " dei")?;
+        }
+        if let Some(name) = self.ether_type.name() {
+            write!(f, " type={}", name)
+        } else {
+            write!(f, " type={:#06X}", self.ether_type.0)
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{test_gens::*, *};
@@ -262,6 +290,25 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn hash(input in vlan_single_any()) {
+            use std::collections::hash_map::DefaultHasher;
+            use core::hash::{Hash, Hasher};
+            let a = {
+                let mut hasher = DefaultHasher::new();
+                input.hash(&mut hasher);
+                hasher.finish()
+            };
+            let b = {
+                let mut hasher = DefaultHasher::new();
+                input.clone().hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(a, b);
+        }
+    }
+
     proptest! {
         #[test]
         fn dbg(input in vlan_single_any()) {
@@ -277,4 +324,22 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn display() {
+        let header = SingleVlanHeader {
+            pcp: VlanPcp::try_new(7).unwrap(),
+            drop_eligible_indicator: true,
+            vlan_id: VlanId::try_new(4095).unwrap(),
+            ether_type: EtherType(0x1234),
+        };
+        assert_eq!("VLAN id=4095 pcp=7 dei type=0x1234", format!("{}", header));
+
+        let header = SingleVlanHeader {
+            drop_eligible_indicator: false,
+            ether_type: EtherType::IPV4,
+            ..header
+        };
+        assert_eq!("VLAN id=4095 pcp=7 type=IPv4", format!("{}", header));
+    }
 }
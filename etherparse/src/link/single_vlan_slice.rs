@@ -91,8 +91,8 @@ impl<'a> SingleVlanSlice<'a> {
         }
     }
 
-    /// Slice containing the Ethernet 2 header.
-    pub fn header_slice(&self) -> &[u8] {
+    /// Slice containing the VLAN header.
+    pub fn header_slice(&self) -> &'a [u8] {
         unsafe {
             // SAFETY:
             // Safe as the contructor checks that the slice has
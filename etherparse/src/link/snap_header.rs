@@ -0,0 +1,203 @@
+use crate::*;
+
+/// SNAP (Subnetwork Access Protocol) header following an IEEE 802.2
+/// LLC header (see [`crate::LlcHeader::has_snap_header`]).
+///
+/// If the OUI is [`SnapHeader::OUI_ETHER_TYPE`] (RFC 1042) or
+/// [`SnapHeader::OUI_BRIDGE_TUNNEL`] (IEEE 802.1H) the protocol id
+/// contains the ether type of the payload.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SnapHeader {
+    /// Organizationally unique identifier.
+    pub oui: [u8; 3],
+    /// Protocol identifier (ether type in case of the OUIs
+    /// [`SnapHeader::OUI_ETHER_TYPE`] & [`SnapHeader::OUI_BRIDGE_TUNNEL`]).
+    pub protocol_id: u16,
+}
+
+impl SnapHeader {
+    /// Serialized size of a SNAP header in bytes/octets.
+    pub const LEN: usize = 5;
+
+    /// OUI indicating that the protocol id is an ether type (RFC 1042).
+    pub const OUI_ETHER_TYPE: [u8; 3] = [0, 0, 0];
+
+    /// OUI indicating that the protocol id is an ether type and the
+    /// frame is bridged (IEEE 802.1H bridge tunnel encapsulation).
+    pub const OUI_BRIDGE_TUNNEL: [u8; 3] = [0, 0, 0xF8];
+
+    /// Read a SnapHeader from a slice and return the header & unused parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(SnapHeader, &[u8]), err::LenError> {
+        if slice.len() < SnapHeader::LEN {
+            return Err(err::LenError {
+                required_len: SnapHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::SnapHeader,
+                layer_start_offset: 0,
+            });
+        }
+        Ok((
+            SnapHeader::from_bytes([slice[0], slice[1], slice[2], slice[3], slice[4]]),
+            &slice[SnapHeader::LEN..],
+        ))
+    }
+
+    /// Read a SnapHeader from a static sized byte array.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 5]) -> SnapHeader {
+        SnapHeader {
+            oui: [bytes[0], bytes[1], bytes[2]],
+            protocol_id: u16::from_be_bytes([bytes[3], bytes[4]]),
+        }
+    }
+
+    /// Tries to read a SNAP header from the current position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<SnapHeader, std::io::Error> {
+        let mut bytes = [0u8; SnapHeader::LEN];
+        reader.read_exact(&mut bytes)?;
+        Ok(SnapHeader::from_bytes(bytes))
+    }
+
+    /// Write the SNAP header to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Returns the ether type of the payload if the OUI indicates
+    /// that the protocol id is an ether type.
+    #[inline]
+    pub fn ether_type(&self) -> Option<EtherType> {
+        if self.oui == SnapHeader::OUI_ETHER_TYPE || self.oui == SnapHeader::OUI_BRIDGE_TUNNEL {
+            Some(EtherType(self.protocol_id))
+        } else {
+            None
+        }
+    }
+
+    /// Length of the serialized header in bytes.
+    ///
+    /// The function always returns the constant [`crate::SnapHeader::LEN`]
+    /// and exists to keep the methods consistent with other headers.
+    #[inline]
+    pub const fn header_len(&self) -> usize {
+        SnapHeader::LEN
+    }
+
+    /// Returns the serialized form of the header as a statically
+    /// sized byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 5] {
+        let id = self.protocol_id.to_be_bytes();
+        [self.oui[0], self.oui[1], self.oui[2], id[0], id[1]]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(header in snap_any()) {
+            assert_eq!(header.clone(), header);
+            assert_eq!(
+                format!("{:?}", header),
+                format!(
+                    "SnapHeader {{ oui: {:?}, protocol_id: {} }}",
+                    header.oui, header.protocol_id
+                )
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            header in snap_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(SnapHeader::LEN + dummy_data.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+
+            // ok case
+            {
+                let (result, rest) = SnapHeader::from_slice(&buffer).unwrap();
+                assert_eq!(result, header);
+                assert_eq!(rest, &dummy_data[..]);
+            }
+
+            // length error
+            for len in 0..SnapHeader::LEN {
+                assert_eq!(
+                    SnapHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: SnapHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::SnapHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(header in snap_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(SnapHeader::LEN);
+                header.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &header.to_bytes());
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(header, SnapHeader::read(&mut cursor).unwrap());
+                assert_eq!(SnapHeader::LEN, cursor.position() as usize);
+            }
+            // io errors
+            for len in 0..SnapHeader::LEN {
+                let bytes = header.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(SnapHeader::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; SnapHeader::LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(header.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn ether_type() {
+        let tests = [
+            ([0, 0, 0], Some(EtherType::IPV4)),
+            ([0, 0, 0xF8], Some(EtherType::IPV4)),
+            ([0, 0, 0x0C], None),
+        ];
+        for (oui, expected) in tests {
+            let header = SnapHeader {
+                oui,
+                protocol_id: EtherType::IPV4.0,
+            };
+            assert_eq!(expected, header.ether_type());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len_to_bytes(header in snap_any()) {
+            assert_eq!(SnapHeader::LEN, header.header_len());
+            assert_eq!(header, SnapHeader::from_bytes(header.to_bytes()));
+        }
+    }
+}
@@ -19,6 +19,15 @@ impl<'a> VlanSlice<'a> {
         }
     }
 
+    /// Returns the slice containing the VLAN header(s).
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        match self {
+            VlanSlice::SingleVlan(s) => s.header_slice(),
+            VlanSlice::DoubleVlan(d) => d.header_slice(),
+        }
+    }
+
     #[inline]
     pub fn payload(&self) -> EtherPayloadSlice<'a> {
         match self {
@@ -26,6 +35,16 @@ impl<'a> VlanSlice<'a> {
             VlanSlice::DoubleVlan(d) => d.payload(),
         }
     }
+
+    /// Returns the ether type of the payload after the most inner
+    /// VLAN header.
+    #[inline]
+    pub fn payload_ether_type(&self) -> EtherType {
+        match self {
+            VlanSlice::SingleVlan(s) => s.ether_type(),
+            VlanSlice::DoubleVlan(d) => d.inner().ether_type(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -48,8 +67,9 @@ mod test {
                 );
                 assert_eq!(
                     slice.to_header(),
-                    VlanHeader::Single(single)
+                    VlanHeader::Single(single.clone())
                 );
+                assert_eq!(slice.payload_ether_type(), single.ether_type);
             }
 
             // double
@@ -60,8 +80,39 @@ mod test {
                 );
                 assert_eq!(
                     slice.to_header(),
-                    VlanHeader::Double(double)
+                    VlanHeader::Double(double.clone())
+                );
+                assert_eq!(slice.payload_ether_type(), double.inner.ether_type);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_slice(
+            single in vlan_single_any(),
+            double in vlan_double_any(),
+        ) {
+            let payload = [1, 2, 3, 4];
+
+            // single
+            {
+                let mut raw = single.to_bytes().to_vec();
+                raw.extend_from_slice(&payload);
+                let slice = VlanSlice::SingleVlan(
+                    SingleVlanSlice::from_slice(&raw).unwrap()
+                );
+                assert_eq!(slice.header_slice(), &raw[..SingleVlanHeader::LEN]);
+            }
+
+            // double
+            {
+                let mut raw = double.to_bytes().to_vec();
+                raw.extend_from_slice(&payload);
+                let slice = VlanSlice::DoubleVlan(
+                    DoubleVlanSlice::from_slice(&raw).unwrap()
                 );
+                assert_eq!(slice.header_slice(), &raw[..DoubleVlanHeader::LEN]);
             }
         }
     }
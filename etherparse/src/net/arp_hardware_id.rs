@@ -0,0 +1,172 @@
+/// Represents the "hardware type" field present in an ARP packet.
+///
+/// You can access the underlying `u16` value by using `.0` and any `u16`
+/// can be converted to an `ArpHardwareId`:
+///
+/// ```
+/// use etherparse::ArpHardwareId;
+///
+/// assert_eq!(ArpHardwareId::ETHERNET.0, 1);
+/// assert_eq!(ArpHardwareId::ETHERNET, ArpHardwareId(1));
+///
+/// // convert to ArpHardwareId using the from & into trait
+/// let hw_id: ArpHardwareId = 1.into();
+/// assert_eq!(ArpHardwareId::ETHERNET, hw_id);
+///
+/// // convert to u16 using the from & into trait
+/// let num: u16 = ArpHardwareId::ETHERNET.into();
+/// assert_eq!(1, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct ArpHardwareId(pub u16);
+
+impl ArpHardwareId {
+    /// Ethernet (10Mb)
+    pub const ETHERNET: ArpHardwareId = Self(1);
+    /// Experimental Ethernet (3Mb)
+    pub const EXPERIMENTAL_ETHERNET: ArpHardwareId = Self(2);
+    /// Amateur Radio AX.25
+    pub const AX25: ArpHardwareId = Self(3);
+    /// IEEE 802 Networks
+    pub const IEEE802: ArpHardwareId = Self(6);
+    /// ARCNET
+    pub const ARCNET: ArpHardwareId = Self(7);
+    /// Frame Relay
+    pub const FRAME_RELAY: ArpHardwareId = Self(15);
+    /// Asynchronous Transmission Mode (ATM)
+    pub const ATM: ArpHardwareId = Self(16);
+    /// HDLC
+    pub const HDLC: ArpHardwareId = Self(17);
+    /// Fibre Channel
+    pub const FIBRE_CHANNEL: ArpHardwareId = Self(18);
+    /// InfiniBand
+    pub const INFINIBAND: ArpHardwareId = Self(32);
+}
+
+impl From<u16> for ArpHardwareId {
+    #[inline]
+    fn from(val: u16) -> Self {
+        ArpHardwareId(val)
+    }
+}
+
+impl From<ArpHardwareId> for u16 {
+    #[inline]
+    fn from(val: ArpHardwareId) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for ArpHardwareId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::ETHERNET => write!(f, "{} (Ethernet)", self.0),
+            Self::EXPERIMENTAL_ETHERNET => write!(f, "{} (Experimental Ethernet)", self.0),
+            Self::AX25 => write!(f, "{} (Amateur Radio AX.25)", self.0),
+            Self::IEEE802 => write!(f, "{} (IEEE 802 Networks)", self.0),
+            Self::ARCNET => write!(f, "{} (ARCNET)", self.0),
+            Self::FRAME_RELAY => write!(f, "{} (Frame Relay)", self.0),
+            Self::ATM => write!(f, "{} (Asynchronous Transmission Mode (ATM))", self.0),
+            Self::HDLC => write!(f, "{} (HDLC)", self.0),
+            Self::FIBRE_CHANNEL => write!(f, "{} (Fibre Channel)", self.0),
+            Self::INFINIBAND => write!(f, "{} (InfiniBand)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ArpHardwareId {
+    fn format(&self, f: defmt::Formatter) {
+        match *self {
+            Self::ETHERNET => defmt::write!(f, "{=u16} (Ethernet)", self.0),
+            Self::EXPERIMENTAL_ETHERNET => {
+                defmt::write!(f, "{=u16} (Experimental Ethernet)", self.0)
+            }
+            Self::AX25 => defmt::write!(f, "{=u16} (Amateur Radio AX.25)", self.0),
+            Self::IEEE802 => defmt::write!(f, "{=u16} (IEEE 802 Networks)", self.0),
+            Self::ARCNET => defmt::write!(f, "{=u16} (ARCNET)", self.0),
+            Self::FRAME_RELAY => defmt::write!(f, "{=u16} (Frame Relay)", self.0),
+            Self::ATM => defmt::write!(f, "{=u16} (Asynchronous Transmission Mode (ATM))", self.0),
+            Self::HDLC => defmt::write!(f, "{=u16} (HDLC)", self.0),
+            Self::FIBRE_CHANNEL => defmt::write!(f, "{=u16} (Fibre Channel)", self.0),
+            Self::INFINIBAND => defmt::write!(f, "{=u16} (InfiniBand)", self.0),
+            _ => defmt::write!(f, "{=u16}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ArpHardwareId;
+    use alloc::format;
+
+    #[test]
+    fn to_u16() {
+        assert_eq!(1, u16::from(ArpHardwareId::ETHERNET));
+        assert_eq!(32, u16::from(ArpHardwareId::INFINIBAND));
+    }
+
+    #[test]
+    fn from_u16() {
+        assert_eq!(ArpHardwareId::from(1), ArpHardwareId::ETHERNET);
+        assert_eq!(ArpHardwareId::from(1234), ArpHardwareId(1234));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (ArpHardwareId::ETHERNET, "1 (Ethernet)"),
+            (
+                ArpHardwareId::EXPERIMENTAL_ETHERNET,
+                "2 (Experimental Ethernet)",
+            ),
+            (ArpHardwareId::AX25, "3 (Amateur Radio AX.25)"),
+            (ArpHardwareId::IEEE802, "6 (IEEE 802 Networks)"),
+            (ArpHardwareId::ARCNET, "7 (ARCNET)"),
+            (ArpHardwareId::FRAME_RELAY, "15 (Frame Relay)"),
+            (
+                ArpHardwareId::ATM,
+                "16 (Asynchronous Transmission Mode (ATM))",
+            ),
+            (ArpHardwareId::HDLC, "17 (HDLC)"),
+            (ArpHardwareId::FIBRE_CHANNEL, "18 (Fibre Channel)"),
+            (ArpHardwareId::INFINIBAND, "32 (InfiniBand)"),
+            (ArpHardwareId(1234), "1234"),
+        ];
+
+        for (id, str_value) in pairs {
+            assert_eq!(str_value, &format!("{:?}", id));
+        }
+    }
+
+    #[test]
+    fn default() {
+        let value: ArpHardwareId = Default::default();
+        assert_eq!(ArpHardwareId(0), value);
+    }
+
+    #[test]
+    fn clone_eq_hash_ord() {
+        use core::cmp::Ordering;
+        use core::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+
+        let a = ArpHardwareId::ETHERNET;
+        assert_eq!(a, a.clone());
+        assert_ne!(a, ArpHardwareId::IEEE802);
+
+        let a_hash = {
+            let mut s = DefaultHasher::new();
+            a.hash(&mut s);
+            s.finish()
+        };
+        let b_hash = {
+            let mut s = DefaultHasher::new();
+            a.clone().hash(&mut s);
+            s.finish()
+        };
+        assert_eq!(a_hash, b_hash);
+        assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+    }
+}
@@ -0,0 +1,119 @@
+/// Represents the "operation" field present in an ARP packet.
+///
+/// You can access the underlying `u16` value by using `.0` and any `u16`
+/// can be converted to an `ArpOperation`:
+///
+/// ```
+/// use etherparse::ArpOperation;
+///
+/// assert_eq!(ArpOperation::REQUEST.0, 1);
+/// assert_eq!(ArpOperation::REQUEST, ArpOperation(1));
+///
+/// // convert to ArpOperation using the from & into trait
+/// let op: ArpOperation = 2.into();
+/// assert_eq!(ArpOperation::REPLY, op);
+///
+/// // convert to u16 using the from & into trait
+/// let num: u16 = ArpOperation::REPLY.into();
+/// assert_eq!(2, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct ArpOperation(pub u16);
+
+impl ArpOperation {
+    /// ARP request.
+    pub const REQUEST: ArpOperation = Self(1);
+    /// ARP reply.
+    pub const REPLY: ArpOperation = Self(2);
+}
+
+impl From<u16> for ArpOperation {
+    #[inline]
+    fn from(val: u16) -> Self {
+        ArpOperation(val)
+    }
+}
+
+impl From<ArpOperation> for u16 {
+    #[inline]
+    fn from(val: ArpOperation) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for ArpOperation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::REQUEST => write!(f, "{} (ARP Request)", self.0),
+            Self::REPLY => write!(f, "{} (ARP Reply)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ArpOperation {
+    fn format(&self, f: defmt::Formatter) {
+        match *self {
+            Self::REQUEST => defmt::write!(f, "{=u16} (ARP Request)", self.0),
+            Self::REPLY => defmt::write!(f, "{=u16} (ARP Reply)", self.0),
+            _ => defmt::write!(f, "{=u16}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ArpOperation;
+    use alloc::format;
+
+    #[test]
+    fn to_u16() {
+        assert_eq!(1, u16::from(ArpOperation::REQUEST));
+        assert_eq!(2, u16::from(ArpOperation::REPLY));
+    }
+
+    #[test]
+    fn from_u16() {
+        assert_eq!(ArpOperation::from(1), ArpOperation::REQUEST);
+        assert_eq!(ArpOperation::from(2), ArpOperation::REPLY);
+        assert_eq!(ArpOperation::from(3), ArpOperation(3));
+    }
+
+    #[test]
+    fn dbg() {
+        assert_eq!("1 (ARP Request)", format!("{:?}", ArpOperation::REQUEST));
+        assert_eq!("2 (ARP Reply)", format!("{:?}", ArpOperation::REPLY));
+        assert_eq!("3", format!("{:?}", ArpOperation(3)));
+    }
+
+    #[test]
+    fn default() {
+        let value: ArpOperation = Default::default();
+        assert_eq!(ArpOperation(0), value);
+    }
+
+    #[test]
+    fn clone_eq_hash_ord() {
+        use core::cmp::Ordering;
+        use core::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+
+        let a = ArpOperation::REQUEST;
+        assert_eq!(a, a.clone());
+        assert_ne!(a, ArpOperation::REPLY);
+
+        let a_hash = {
+            let mut s = DefaultHasher::new();
+            a.hash(&mut s);
+            s.finish()
+        };
+        let b_hash = {
+            let mut s = DefaultHasher::new();
+            a.clone().hash(&mut s);
+            s.finish()
+        };
+        assert_eq!(a_hash, b_hash);
+        assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+    }
+}
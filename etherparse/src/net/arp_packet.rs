@@ -0,0 +1,615 @@
+use crate::*;
+use arrayvec::ArrayVec;
+
+/// "Address Resolution Protocol" packet according to
+/// [RFC 826](https://datatracker.ietf.org/doc/html/rfc826).
+///
+/// The hardware & protocol addresses are variable in length (up to 255
+/// bytes). For the common case of Ethernet & IPv4 addresses the methods
+/// [`ArpPacket::new_eth_ipv4`], [`ArpPacket::sender_mac`],
+/// [`ArpPacket::sender_ipv4`], [`ArpPacket::target_mac`] &
+/// [`ArpPacket::target_ipv4`] can be used.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ArpOperation, ArpPacket};
+///
+/// let request = ArpPacket::new_eth_ipv4(
+///     ArpOperation::REQUEST,
+///     [1, 2, 3, 4, 5, 6],  // sender mac
+///     [192, 168, 1, 1],    // sender ipv4
+///     [0; 6],              // target mac (unknown)
+///     [192, 168, 1, 2],    // target ipv4
+/// );
+///
+/// let bytes = request.to_bytes();
+/// let (decoded, _) = ArpPacket::from_slice(&bytes).unwrap();
+/// assert_eq!(decoded.target_ipv4(), Some([192, 168, 1, 2]));
+/// ```
+#[derive(Clone)]
+pub struct ArpPacket {
+    /// Network link protocol type (e.g. [`ArpHardwareId::ETHERNET`]).
+    pub hw_addr_type: ArpHardwareId,
+
+    /// Protocol for which the ARP request is intended (e.g. [`EtherType::IPV4`]).
+    pub proto_addr_type: EtherType,
+
+    /// Length (in octets) of a hardware address.
+    hw_addr_size: u8,
+
+    /// Length (in octets) of a protocol address.
+    proto_addr_size: u8,
+
+    /// Specifies the operation that the sender is performing.
+    pub operation: ArpOperation,
+
+    /// Buffer containing the sender hardware address.
+    sender_hw_addr_buf: [u8; 255],
+
+    /// Buffer containing the sender protocol address.
+    sender_protocol_addr_buf: [u8; 255],
+
+    /// Buffer containing the target hardware address.
+    target_hw_addr_buf: [u8; 255],
+
+    /// Buffer containing the target protocol address.
+    target_protocol_addr_buf: [u8; 255],
+}
+
+impl ArpPacket {
+    /// Length of an ARP packet without the addresses in bytes/octets.
+    pub const MIN_LEN: usize = 8;
+
+    /// Maximum length of an ARP packet in bytes/octets (all addresses 255 bytes long).
+    pub const MAX_LEN: usize = 8 + 4 * 255;
+
+    /// Creates an ARP packet with the given addresses.
+    ///
+    /// The sender & target addresses of the same type must have the same length
+    /// and can not be longer then 255 bytes.
+    pub fn new(
+        hw_addr_type: ArpHardwareId,
+        proto_addr_type: EtherType,
+        operation: ArpOperation,
+        sender_hw_addr: &[u8],
+        sender_protocol_addr: &[u8],
+        target_hw_addr: &[u8],
+        target_protocol_addr: &[u8],
+    ) -> Result<ArpPacket, err::arp::ArpNewError> {
+        use err::arp::ArpNewError::*;
+        if sender_hw_addr.len() != target_hw_addr.len() {
+            return Err(HwAddrLenInconsistent(
+                sender_hw_addr.len(),
+                target_hw_addr.len(),
+            ));
+        }
+        if sender_protocol_addr.len() != target_protocol_addr.len() {
+            return Err(ProtocolAddrLenInconsistent(
+                sender_protocol_addr.len(),
+                target_protocol_addr.len(),
+            ));
+        }
+        if sender_hw_addr.len() > 255 {
+            return Err(HwAddrLenTooBig(sender_hw_addr.len()));
+        }
+        if sender_protocol_addr.len() > 255 {
+            return Err(ProtocolAddrLenTooBig(sender_protocol_addr.len()));
+        }
+        Ok(ArpPacket {
+            hw_addr_type,
+            proto_addr_type,
+            hw_addr_size: sender_hw_addr.len() as u8,
+            proto_addr_size: sender_protocol_addr.len() as u8,
+            operation,
+            sender_hw_addr_buf: {
+                let mut buf = [0u8; 255];
+                buf[..sender_hw_addr.len()].copy_from_slice(sender_hw_addr);
+                buf
+            },
+            sender_protocol_addr_buf: {
+                let mut buf = [0u8; 255];
+                buf[..sender_protocol_addr.len()].copy_from_slice(sender_protocol_addr);
+                buf
+            },
+            target_hw_addr_buf: {
+                let mut buf = [0u8; 255];
+                buf[..target_hw_addr.len()].copy_from_slice(target_hw_addr);
+                buf
+            },
+            target_protocol_addr_buf: {
+                let mut buf = [0u8; 255];
+                buf[..target_protocol_addr.len()].copy_from_slice(target_protocol_addr);
+                buf
+            },
+        })
+    }
+
+    /// Creates an ARP packet for resolving IPv4 addresses to Ethernet
+    /// MAC addresses (hardware type [`ArpHardwareId::ETHERNET`] & protocol
+    /// type [`EtherType::IPV4`]).
+    pub fn new_eth_ipv4(
+        operation: ArpOperation,
+        sender_mac: [u8; 6],
+        sender_ipv4: [u8; 4],
+        target_mac: [u8; 6],
+        target_ipv4: [u8; 4],
+    ) -> ArpPacket {
+        // SAFETY: Unwrap is safe as the address lengths are fixed & consistent.
+        ArpPacket::new(
+            ArpHardwareId::ETHERNET,
+            EtherType::IPV4,
+            operation,
+            &sender_mac,
+            &sender_ipv4,
+            &target_mac,
+            &target_ipv4,
+        )
+        .unwrap()
+    }
+
+    /// Reads an ARP packet from a slice and returns the packet & unused parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(ArpPacket, &[u8]), err::LenError> {
+        let s = ArpPacketSlice::from_slice(slice)?;
+        Ok((s.to_packet(), &slice[s.slice().len()..]))
+    }
+
+    /// Reads an ARP packet from the current position of the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<ArpPacket, std::io::Error> {
+        let mut buffer = [0u8; ArpPacket::MAX_LEN];
+        reader.read_exact(&mut buffer[..ArpPacket::MIN_LEN])?;
+        let len = ArpPacket::MIN_LEN + 2 * usize::from(buffer[4]) + 2 * usize::from(buffer[5]);
+        reader.read_exact(&mut buffer[ArpPacket::MIN_LEN..len])?;
+        // SAFETY: Unwrap is safe as the buffer contains the complete packet.
+        Ok(ArpPacketSlice::from_slice(&buffer[..len])
+            .unwrap()
+            .to_packet())
+    }
+
+    /// Writes the ARP packet to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length (in octets) of a hardware address.
+    #[inline]
+    pub fn hw_addr_size(&self) -> u8 {
+        self.hw_addr_size
+    }
+
+    /// Length (in octets) of a protocol address.
+    #[inline]
+    pub fn proto_addr_size(&self) -> u8 {
+        self.proto_addr_size
+    }
+
+    /// Sender hardware address.
+    #[inline]
+    pub fn sender_hw_addr(&self) -> &[u8] {
+        &self.sender_hw_addr_buf[..usize::from(self.hw_addr_size)]
+    }
+
+    /// Sender protocol address.
+    #[inline]
+    pub fn sender_protocol_addr(&self) -> &[u8] {
+        &self.sender_protocol_addr_buf[..usize::from(self.proto_addr_size)]
+    }
+
+    /// Target hardware address.
+    #[inline]
+    pub fn target_hw_addr(&self) -> &[u8] {
+        &self.target_hw_addr_buf[..usize::from(self.hw_addr_size)]
+    }
+
+    /// Target protocol address.
+    #[inline]
+    pub fn target_protocol_addr(&self) -> &[u8] {
+        &self.target_protocol_addr_buf[..usize::from(self.proto_addr_size)]
+    }
+
+    /// Returns the sender hardware address as an Ethernet MAC address if the
+    /// hardware type is [`ArpHardwareId::ETHERNET`] and the address has a length of 6.
+    #[inline]
+    pub fn sender_mac(&self) -> Option<[u8; 6]> {
+        self.mac_from(self.sender_hw_addr())
+    }
+
+    /// Returns the sender protocol address as an IPv4 address if the
+    /// protocol type is [`EtherType::IPV4`] and the address has a length of 4.
+    #[inline]
+    pub fn sender_ipv4(&self) -> Option<[u8; 4]> {
+        self.ipv4_from(self.sender_protocol_addr())
+    }
+
+    /// Returns the target hardware address as an Ethernet MAC address if the
+    /// hardware type is [`ArpHardwareId::ETHERNET`] and the address has a length of 6.
+    #[inline]
+    pub fn target_mac(&self) -> Option<[u8; 6]> {
+        self.mac_from(self.target_hw_addr())
+    }
+
+    /// Returns the target protocol address as an IPv4 address if the
+    /// protocol type is [`EtherType::IPV4`] and the address has a length of 4.
+    #[inline]
+    pub fn target_ipv4(&self) -> Option<[u8; 4]> {
+        self.ipv4_from(self.target_protocol_addr())
+    }
+
+    fn mac_from(&self, addr: &[u8]) -> Option<[u8; 6]> {
+        if ArpHardwareId::ETHERNET == self.hw_addr_type {
+            addr.try_into().ok()
+        } else {
+            None
+        }
+    }
+
+    fn ipv4_from(&self, addr: &[u8]) -> Option<[u8; 4]> {
+        if EtherType::IPV4 == self.proto_addr_type {
+            addr.try_into().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Length of the serialized ARP packet in bytes.
+    #[inline]
+    pub fn packet_len(&self) -> usize {
+        ArpPacket::MIN_LEN
+            + 2 * usize::from(self.hw_addr_size)
+            + 2 * usize::from(self.proto_addr_size)
+    }
+
+    /// Returns the serialized packet.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { ArpPacket::MAX_LEN }> {
+        let hw_addr_type_be = self.hw_addr_type.0.to_be_bytes();
+        let proto_addr_type_be = self.proto_addr_type.0.to_be_bytes();
+        let operation_be = self.operation.0.to_be_bytes();
+
+        let mut result = ArrayVec::new();
+        result.extend([
+            hw_addr_type_be[0],
+            hw_addr_type_be[1],
+            proto_addr_type_be[0],
+            proto_addr_type_be[1],
+            self.hw_addr_size,
+            self.proto_addr_size,
+            operation_be[0],
+            operation_be[1],
+        ]);
+        // SAFETY: Unwraps are safe as the buffer has enough space for all addresses.
+        result.try_extend_from_slice(self.sender_hw_addr()).unwrap();
+        result
+            .try_extend_from_slice(self.sender_protocol_addr())
+            .unwrap();
+        result.try_extend_from_slice(self.target_hw_addr()).unwrap();
+        result
+            .try_extend_from_slice(self.target_protocol_addr())
+            .unwrap();
+        result
+    }
+}
+
+impl core::fmt::Debug for ArpPacket {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ArpPacket")
+            .field("hw_addr_type", &self.hw_addr_type)
+            .field("proto_addr_type", &self.proto_addr_type)
+            .field("hw_addr_size", &self.hw_addr_size)
+            .field("proto_addr_size", &self.proto_addr_size)
+            .field("operation", &self.operation)
+            .field("sender_hw_addr", &self.sender_hw_addr())
+            .field("sender_protocol_addr", &self.sender_protocol_addr())
+            .field("target_hw_addr", &self.target_hw_addr())
+            .field("target_protocol_addr", &self.target_protocol_addr())
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ArpPacket {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ArpPacket {{ hw_addr_type: {}, proto_addr_type: {}, hw_addr_size: {=u8}, proto_addr_size: {=u8}, operation: {}, sender_hw_addr: {=[u8]}, sender_protocol_addr: {=[u8]}, target_hw_addr: {=[u8]}, target_protocol_addr: {=[u8]} }}",
+            self.hw_addr_type,
+            self.proto_addr_type,
+            self.hw_addr_size,
+            self.proto_addr_size,
+            self.operation,
+            self.sender_hw_addr(),
+            self.sender_protocol_addr(),
+            self.target_hw_addr(),
+            self.target_protocol_addr()
+        )
+    }
+}
+
+impl core::cmp::PartialEq for ArpPacket {
+    fn eq(&self, other: &Self) -> bool {
+        self.hw_addr_type == other.hw_addr_type
+            && self.proto_addr_type == other.proto_addr_type
+            && self.hw_addr_size == other.hw_addr_size
+            && self.proto_addr_size == other.proto_addr_size
+            && self.operation == other.operation
+            && self.sender_hw_addr() == other.sender_hw_addr()
+            && self.sender_protocol_addr() == other.sender_protocol_addr()
+            && self.target_hw_addr() == other.target_hw_addr()
+            && self.target_protocol_addr() == other.target_protocol_addr()
+    }
+}
+
+impl core::cmp::Eq for ArpPacket {}
+
+impl core::hash::Hash for ArpPacket {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.hw_addr_type.hash(state);
+        self.proto_addr_type.hash(state);
+        self.hw_addr_size.hash(state);
+        self.proto_addr_size.hash(state);
+        self.operation.hash(state);
+        self.sender_hw_addr().hash(state);
+        self.sender_protocol_addr().hash(state);
+        self.target_hw_addr().hash(state);
+        self.target_protocol_addr().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{err::arp::ArpNewError, test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn new() {
+        // ok
+        {
+            let packet = ArpPacket::new(
+                ArpHardwareId::IEEE802,
+                EtherType::IPV6,
+                ArpOperation::REPLY,
+                &[1, 2],
+                &[3, 4, 5],
+                &[6, 7],
+                &[8, 9, 10],
+            )
+            .unwrap();
+            assert_eq!(ArpHardwareId::IEEE802, packet.hw_addr_type);
+            assert_eq!(EtherType::IPV6, packet.proto_addr_type);
+            assert_eq!(ArpOperation::REPLY, packet.operation);
+            assert_eq!(2, packet.hw_addr_size());
+            assert_eq!(3, packet.proto_addr_size());
+            assert_eq!(&[1, 2], packet.sender_hw_addr());
+            assert_eq!(&[3, 4, 5], packet.sender_protocol_addr());
+            assert_eq!(&[6, 7], packet.target_hw_addr());
+            assert_eq!(&[8, 9, 10], packet.target_protocol_addr());
+        }
+
+        // errors
+        let new = |sender_hw: &[u8], sender_proto: &[u8], target_hw: &[u8], target_proto: &[u8]| {
+            ArpPacket::new(
+                ArpHardwareId::ETHERNET,
+                EtherType::IPV4,
+                ArpOperation::REQUEST,
+                sender_hw,
+                sender_proto,
+                target_hw,
+                target_proto,
+            )
+            .unwrap_err()
+        };
+        assert_eq!(
+            ArpNewError::HwAddrLenInconsistent(1, 2),
+            new(&[0], &[], &[0, 0], &[])
+        );
+        assert_eq!(
+            ArpNewError::ProtocolAddrLenInconsistent(2, 1),
+            new(&[], &[0, 0], &[], &[0])
+        );
+        assert_eq!(
+            ArpNewError::HwAddrLenTooBig(256),
+            new(&[0; 256], &[], &[0; 256], &[])
+        );
+        assert_eq!(
+            ArpNewError::ProtocolAddrLenTooBig(256),
+            new(&[], &[0; 256], &[], &[0; 256])
+        );
+    }
+
+    #[test]
+    fn new_eth_ipv4() {
+        let packet = ArpPacket::new_eth_ipv4(
+            ArpOperation::REQUEST,
+            [1, 2, 3, 4, 5, 6],
+            [7, 8, 9, 10],
+            [11, 12, 13, 14, 15, 16],
+            [17, 18, 19, 20],
+        );
+        assert_eq!(ArpHardwareId::ETHERNET, packet.hw_addr_type);
+        assert_eq!(EtherType::IPV4, packet.proto_addr_type);
+        assert_eq!(ArpOperation::REQUEST, packet.operation);
+        assert_eq!(Some([1, 2, 3, 4, 5, 6]), packet.sender_mac());
+        assert_eq!(Some([7, 8, 9, 10]), packet.sender_ipv4());
+        assert_eq!(Some([11, 12, 13, 14, 15, 16]), packet.target_mac());
+        assert_eq!(Some([17, 18, 19, 20]), packet.target_ipv4());
+    }
+
+    #[test]
+    fn typed_addrs_none() {
+        // wrong hardware & protocol type
+        {
+            let mut packet = ArpPacket::new_eth_ipv4(
+                ArpOperation::REQUEST,
+                [1, 2, 3, 4, 5, 6],
+                [7, 8, 9, 10],
+                [11, 12, 13, 14, 15, 16],
+                [17, 18, 19, 20],
+            );
+            packet.hw_addr_type = ArpHardwareId::IEEE802;
+            packet.proto_addr_type = EtherType::IPV6;
+            assert_eq!(None, packet.sender_mac());
+            assert_eq!(None, packet.sender_ipv4());
+            assert_eq!(None, packet.target_mac());
+            assert_eq!(None, packet.target_ipv4());
+        }
+        // wrong address lengths
+        {
+            let packet = ArpPacket::new(
+                ArpHardwareId::ETHERNET,
+                EtherType::IPV4,
+                ArpOperation::REQUEST,
+                &[1, 2, 3],
+                &[4, 5],
+                &[6, 7, 8],
+                &[9, 10],
+            )
+            .unwrap();
+            assert_eq!(None, packet.sender_mac());
+            assert_eq!(None, packet.sender_ipv4());
+            assert_eq!(None, packet.target_mac());
+            assert_eq!(None, packet.target_ipv4());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            packet in arp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(packet.packet_len() + dummy_data.len());
+            buffer.extend_from_slice(&packet.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+
+            // ok
+            {
+                let (actual, rest) = ArpPacket::from_slice(&buffer).unwrap();
+                assert_eq!(actual, packet);
+                assert_eq!(rest, &dummy_data[..]);
+            }
+
+            // length error
+            for len in 0..packet.packet_len() {
+                assert_eq!(
+                    ArpPacket::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: if len < ArpPacket::MIN_LEN {
+                            ArpPacket::MIN_LEN
+                        } else {
+                            packet.packet_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::Arp,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(packet in arp_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(packet.packet_len());
+                packet.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &packet.to_bytes()[..]);
+
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(packet, ArpPacket::read(&mut cursor).unwrap());
+                assert_eq!(packet.packet_len(), cursor.position() as usize);
+            }
+
+            // io errors
+            for len in 0..packet.packet_len() {
+                let bytes = packet.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(ArpPacket::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; ArpPacket::MAX_LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(packet.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(packet in arp_any()) {
+            let hw_be = packet.hw_addr_type.0.to_be_bytes();
+            let proto_be = packet.proto_addr_type.0.to_be_bytes();
+            let op_be = packet.operation.0.to_be_bytes();
+            let mut expected = Vec::with_capacity(packet.packet_len());
+            expected.extend_from_slice(&[
+                hw_be[0], hw_be[1],
+                proto_be[0], proto_be[1],
+                packet.hw_addr_size(), packet.proto_addr_size(),
+                op_be[0], op_be[1],
+            ]);
+            expected.extend_from_slice(packet.sender_hw_addr());
+            expected.extend_from_slice(packet.sender_protocol_addr());
+            expected.extend_from_slice(packet.target_hw_addr());
+            expected.extend_from_slice(packet.target_protocol_addr());
+            assert_eq!(&expected[..], &packet.to_bytes()[..]);
+            assert_eq!(expected.len(), packet.packet_len());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_debug(packet in arp_any()) {
+            assert_eq!(packet, packet.clone());
+            assert_eq!(
+                format!(
+                    "ArpPacket {{ hw_addr_type: {:?}, proto_addr_type: {:?}, hw_addr_size: {:?}, proto_addr_size: {:?}, operation: {:?}, sender_hw_addr: {:?}, sender_protocol_addr: {:?}, target_hw_addr: {:?}, target_protocol_addr: {:?} }}",
+                    packet.hw_addr_type,
+                    packet.proto_addr_type,
+                    packet.hw_addr_size(),
+                    packet.proto_addr_size(),
+                    packet.operation,
+                    packet.sender_hw_addr(),
+                    packet.sender_protocol_addr(),
+                    packet.target_hw_addr(),
+                    packet.target_protocol_addr()
+                ),
+                format!("{:?}", packet)
+            );
+
+            // not equal if a field differs
+            let mut other = packet.clone();
+            other.operation = ArpOperation(packet.operation.0.wrapping_add(1));
+            assert!(packet != other);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn hash(packet in arp_any()) {
+            use core::hash::{Hash, Hasher};
+            use std::collections::hash_map::DefaultHasher;
+
+            // bytes outside of the used area of the buffer
+            // should not influence the hash
+            let mut other = packet.clone();
+            other.sender_hw_addr_buf[usize::from(packet.hw_addr_size)..].fill(0xff);
+            other.sender_protocol_addr_buf[usize::from(packet.proto_addr_size)..].fill(0xff);
+            other.target_hw_addr_buf[usize::from(packet.hw_addr_size)..].fill(0xff);
+            other.target_protocol_addr_buf[usize::from(packet.proto_addr_size)..].fill(0xff);
+            assert_eq!(packet, other);
+
+            let hash = |value: &ArpPacket| {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(hash(&packet), hash(&other));
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing an "Address Resolution Protocol" packet.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArpPacketSlice<'a> {
+    /// Slice containing the packet data.
+    slice: &'a [u8],
+}
+
+impl<'a> ArpPacketSlice<'a> {
+    /// Creates an ARP packet slice from a slice.
+    ///
+    /// The length of the packet is determined by the hardware & protocol
+    /// address length fields. Data after the packet (e.g. ethernet padding)
+    /// is not part of the resulting slice.
+    pub fn from_slice(slice: &'a [u8]) -> Result<ArpPacketSlice<'a>, err::LenError> {
+        // check length
+        if slice.len() < ArpPacket::MIN_LEN {
+            return Err(err::LenError {
+                required_len: ArpPacket::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Arp,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check the length again now that the address lengths are known
+        let len = ArpPacket::MIN_LEN + 2 * usize::from(slice[4]) + 2 * usize::from(slice[5]);
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Arp,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(ArpPacketSlice {
+            // SAFETY:
+            // Safe as the slice has been checked in the previous if
+            // to have at least the the length of the variable len.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Returns the slice containing the ARP packet.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Network link protocol type (e.g. [`ArpHardwareId::ETHERNET`]).
+    #[inline]
+    pub fn hw_addr_type(&self) -> ArpHardwareId {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        ArpHardwareId(unsafe { get_unchecked_be_u16(self.slice.as_ptr()) })
+    }
+
+    /// Protocol for which the ARP request is intended (e.g. [`EtherType::IPV4`]).
+    #[inline]
+    pub fn proto_addr_type(&self) -> EtherType {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) })
+    }
+
+    /// Length (in octets) of a hardware address.
+    #[inline]
+    pub fn hw_addr_size(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(4) }
+    }
+
+    /// Length (in octets) of a protocol address.
+    #[inline]
+    pub fn proto_addr_size(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(5) }
+    }
+
+    /// Specifies the operation that the sender is performing.
+    #[inline]
+    pub fn operation(&self) -> ArpOperation {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        ArpOperation(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(6)) })
+    }
+
+    /// Sender hardware address.
+    #[inline]
+    pub fn sender_hw_addr(&self) -> &'a [u8] {
+        self.addr(0, usize::from(self.hw_addr_size()))
+    }
+
+    /// Sender protocol address.
+    #[inline]
+    pub fn sender_protocol_addr(&self) -> &'a [u8] {
+        self.addr(
+            usize::from(self.hw_addr_size()),
+            usize::from(self.proto_addr_size()),
+        )
+    }
+
+    /// Target hardware address.
+    #[inline]
+    pub fn target_hw_addr(&self) -> &'a [u8] {
+        self.addr(
+            usize::from(self.hw_addr_size()) + usize::from(self.proto_addr_size()),
+            usize::from(self.hw_addr_size()),
+        )
+    }
+
+    /// Target protocol address.
+    #[inline]
+    pub fn target_protocol_addr(&self) -> &'a [u8] {
+        self.addr(
+            2 * usize::from(self.hw_addr_size()) + usize::from(self.proto_addr_size()),
+            usize::from(self.proto_addr_size()),
+        )
+    }
+
+    /// Returns the address at the given offset (relative to the end of the fixed fields).
+    #[inline]
+    fn addr(&self, offset: usize, len: usize) -> &'a [u8] {
+        // SAFETY:
+        // Slice size checked in the constructor to contain all
+        // addresses indicated by the address length fields.
+        unsafe { from_raw_parts(self.slice.as_ptr().add(ArpPacket::MIN_LEN + offset), len) }
+    }
+
+    /// Returns the sender hardware address as an Ethernet MAC address if the
+    /// hardware type is [`ArpHardwareId::ETHERNET`] and the address has a length of 6.
+    #[inline]
+    pub fn sender_mac(&self) -> Option<[u8; 6]> {
+        self.mac_from(self.sender_hw_addr())
+    }
+
+    /// Returns the sender protocol address as an IPv4 address if the
+    /// protocol type is [`EtherType::IPV4`] and the address has a length of 4.
+    #[inline]
+    pub fn sender_ipv4(&self) -> Option<[u8; 4]> {
+        self.ipv4_from(self.sender_protocol_addr())
+    }
+
+    /// Returns the target hardware address as an Ethernet MAC address if the
+    /// hardware type is [`ArpHardwareId::ETHERNET`] and the address has a length of 6.
+    #[inline]
+    pub fn target_mac(&self) -> Option<[u8; 6]> {
+        self.mac_from(self.target_hw_addr())
+    }
+
+    /// Returns the target protocol address as an IPv4 address if the
+    /// protocol type is [`EtherType::IPV4`] and the address has a length of 4.
+    #[inline]
+    pub fn target_ipv4(&self) -> Option<[u8; 4]> {
+        self.ipv4_from(self.target_protocol_addr())
+    }
+
+    fn mac_from(&self, addr: &[u8]) -> Option<[u8; 6]> {
+        if ArpHardwareId::ETHERNET == self.hw_addr_type() {
+            addr.try_into().ok()
+        } else {
+            None
+        }
+    }
+
+    fn ipv4_from(&self, addr: &[u8]) -> Option<[u8; 4]> {
+        if EtherType::IPV4 == self.proto_addr_type() {
+            addr.try_into().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Decode all the fields and copy the results to an [`ArpPacket`] struct.
+    pub fn to_packet(&self) -> ArpPacket {
+        // SAFETY: Unwrap is safe as the address lengths are read from
+        // u8 fields & the sender & target addresses have the same lengths.
+        ArpPacket::new(
+            self.hw_addr_type(),
+            self.proto_addr_type(),
+            self.operation(),
+            self.sender_hw_addr(),
+            self.sender_protocol_addr(),
+            self.target_hw_addr(),
+            self.target_protocol_addr(),
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(packet in arp_any()) {
+            let bytes = packet.to_bytes();
+            let slice = ArpPacketSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.clone(), slice);
+            assert_eq!(
+                format!("{:?}", slice),
+                format!("ArpPacketSlice {{ slice: {:?} }}", slice.slice())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            packet in arp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(packet.packet_len() + dummy_data.len());
+            buffer.extend_from_slice(&packet.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+
+            // ok
+            {
+                let slice = ArpPacketSlice::from_slice(&buffer).unwrap();
+                assert_eq!(slice.slice(), &buffer[..packet.packet_len()]);
+            }
+
+            // length error
+            for len in 0..packet.packet_len() {
+                assert_eq!(
+                    ArpPacketSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: if len < ArpPacket::MIN_LEN {
+                            ArpPacket::MIN_LEN
+                        } else {
+                            packet.packet_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::Arp,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(packet in arp_any()) {
+            let bytes = packet.to_bytes();
+            let slice = ArpPacketSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.hw_addr_type(), packet.hw_addr_type);
+            assert_eq!(slice.proto_addr_type(), packet.proto_addr_type);
+            assert_eq!(slice.hw_addr_size(), packet.hw_addr_size());
+            assert_eq!(slice.proto_addr_size(), packet.proto_addr_size());
+            assert_eq!(slice.operation(), packet.operation);
+            assert_eq!(slice.sender_hw_addr(), packet.sender_hw_addr());
+            assert_eq!(slice.sender_protocol_addr(), packet.sender_protocol_addr());
+            assert_eq!(slice.target_hw_addr(), packet.target_hw_addr());
+            assert_eq!(slice.target_protocol_addr(), packet.target_protocol_addr());
+            assert_eq!(slice.sender_mac(), packet.sender_mac());
+            assert_eq!(slice.sender_ipv4(), packet.sender_ipv4());
+            assert_eq!(slice.target_mac(), packet.target_mac());
+            assert_eq!(slice.target_ipv4(), packet.target_ipv4());
+        }
+    }
+
+    #[test]
+    fn eth_ipv4_getters() {
+        let packet = ArpPacket::new_eth_ipv4(
+            ArpOperation::REPLY,
+            [1, 2, 3, 4, 5, 6],
+            [7, 8, 9, 10],
+            [11, 12, 13, 14, 15, 16],
+            [17, 18, 19, 20],
+        );
+        let bytes = packet.to_bytes();
+        let slice = ArpPacketSlice::from_slice(&bytes).unwrap();
+        assert_eq!(Some([1, 2, 3, 4, 5, 6]), slice.sender_mac());
+        assert_eq!(Some([7, 8, 9, 10]), slice.sender_ipv4());
+        assert_eq!(Some([11, 12, 13, 14, 15, 16]), slice.target_mac());
+        assert_eq!(Some([17, 18, 19, 20]), slice.target_ipv4());
+    }
+
+    proptest! {
+        #[test]
+        fn to_packet(packet in arp_any()) {
+            let bytes = packet.to_bytes();
+            let slice = ArpPacketSlice::from_slice(&bytes).unwrap();
+            assert_eq!(packet, slice.to_packet());
+        }
+    }
+}
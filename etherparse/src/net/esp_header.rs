@@ -0,0 +1,198 @@
+use crate::*;
+
+/// IPsec Encapsulating Security Payload (ESP) header according to
+/// [RFC 4303](https://datatracker.ietf.org/doc/html/rfc4303#section-2).
+///
+/// Only the unencrypted start of an ESP packet (security parameters index &
+/// sequence number) is represented by the header. Everything following it
+/// (payload data, padding, next header & integrity check value) can only
+/// be decoded with the security association keys and is treated as
+/// encrypted payload.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EspHeader {
+    /// Security parameters index identifying the security association.
+    pub spi: u32,
+    /// Counter value that increases by one for each packet sent.
+    pub sequence_number: u32,
+}
+
+impl EspHeader {
+    /// Serialized size of an ESP header in bytes/octets.
+    pub const LEN: usize = 8;
+
+    /// Read an EspHeader from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(EspHeader, &[u8]), err::LenError> {
+        Ok((
+            EspHeaderSlice::from_slice(slice)?.to_header(),
+            &slice[EspHeader::LEN..],
+        ))
+    }
+
+    /// Read an EspHeader from a static sized byte array.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 8]) -> EspHeader {
+        EspHeader {
+            spi: u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            sequence_number: u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+        }
+    }
+
+    /// Tries to read an ESP header from the current position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<EspHeader, std::io::Error> {
+        let bytes = {
+            let mut bytes: [u8; 8] = [0; 8];
+            reader.read_exact(&mut bytes)?;
+            bytes
+        };
+        Ok(EspHeader::from_bytes(bytes))
+    }
+
+    /// Write the ESP header to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())?;
+        Ok(())
+    }
+
+    /// Length of the serialized header in bytes.
+    ///
+    /// The function always returns the constant [`crate::EspHeader::LEN`]
+    /// and exists to keep the methods consistent with other headers.
+    #[inline]
+    pub const fn header_len(&self) -> usize {
+        EspHeader::LEN
+    }
+
+    /// Returns the serialized form of the header as a statically
+    /// sized byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 8] {
+        let spi_be = self.spi.to_be_bytes();
+        let seq_be = self.sequence_number.to_be_bytes();
+        [
+            spi_be[0], spi_be[1], spi_be[2], spi_be[3], seq_be[0], seq_be[1], seq_be[2], seq_be[3],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in esp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(EspHeader::LEN + dummy_data.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer.extend(&dummy_data[..]);
+
+            // ok case
+            {
+                let (result, rest) = EspHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(result, input);
+                assert_eq!(rest, &dummy_data[..]);
+            }
+
+            // length error
+            for len in 0..EspHeader::LEN {
+                assert_eq!(
+                    EspHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: EspHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::EspHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(input in esp_any()) {
+            assert_eq!(input, EspHeader::from_bytes(input.to_bytes()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(input in esp_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(EspHeader::LEN);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(input, EspHeader::read(&mut cursor).unwrap());
+                assert_eq!(EspHeader::LEN, cursor.position() as usize);
+            }
+            // io errors
+            for len in 0..EspHeader::LEN {
+                let bytes = input.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(EspHeader::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; EspHeader::LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len(input in esp_any()) {
+            assert_eq!(EspHeader::LEN, input.header_len());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in esp_any()) {
+            let spi_be = input.spi.to_be_bytes();
+            let seq_be = input.sequence_number.to_be_bytes();
+            assert_eq!(
+                input.to_bytes(),
+                [
+                    spi_be[0], spi_be[1], spi_be[2], spi_be[3],
+                    seq_be[0], seq_be[1], seq_be[2], seq_be[3],
+                ]
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_debug(input in esp_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                format!(
+                    "EspHeader {{ spi: {:?}, sequence_number: {:?} }}",
+                    input.spi, input.sequence_number
+                ),
+                format!("{:?}", input)
+            );
+        }
+    }
+
+    #[test]
+    fn default() {
+        let header: EspHeader = Default::default();
+        assert_eq!(0, header.spi);
+        assert_eq!(0, header.sequence_number);
+    }
+}
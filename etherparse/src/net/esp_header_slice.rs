@@ -0,0 +1,162 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing an IPsec ESP header of a network package.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EspHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> EspHeaderSlice<'a> {
+    /// Creates a slice containing an ESP header.
+    #[inline]
+    pub fn from_slice(slice: &'a [u8]) -> Result<EspHeaderSlice<'a>, err::LenError> {
+        //check length
+        if slice.len() < EspHeader::LEN {
+            return Err(err::LenError {
+                required_len: EspHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::EspHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        //done
+        Ok(EspHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // EspHeader::LEN (8) before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), EspHeader::LEN) },
+        })
+    }
+
+    /// Creates a ESP header slice from a slice (assumes slice size & content was validated before).
+    ///
+    /// # Safety
+    ///
+    /// This method assumes that the slice was previously validated to contain
+    /// at least [`EspHeader::LEN`] (8) bytes. If this precondition is not
+    /// fulfilled the behavior of this function and the methods of the
+    /// returned EspHeaderSlice will be undefined.
+    #[inline]
+    pub(crate) unsafe fn from_slice_unchecked(slice: &'a [u8]) -> EspHeaderSlice<'a> {
+        EspHeaderSlice {
+            slice: from_raw_parts(slice.as_ptr(), EspHeader::LEN),
+        }
+    }
+
+    /// Returns the slice containing the ESP header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Reads the "security parameters index" from the slice.
+    #[inline]
+    pub fn spi(&self) -> u32 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of EspHeader::LEN (8).
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr()) }
+    }
+
+    /// Reads the "sequence number" from the slice.
+    #[inline]
+    pub fn sequence_number(&self) -> u32 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of EspHeader::LEN (8).
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Decode all the fields and copy the results to a EspHeader struct.
+    #[inline]
+    pub fn to_header(&self) -> EspHeader {
+        EspHeader {
+            spi: self.spi(),
+            sequence_number: self.sequence_number(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in esp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(EspHeader::LEN + dummy_data.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer.extend(&dummy_data[..]);
+
+            // ok case
+            {
+                let slice = EspHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(slice.slice(), &buffer[..EspHeader::LEN]);
+            }
+
+            // length error
+            for len in 0..EspHeader::LEN {
+                assert_eq!(
+                    EspHeaderSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: EspHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::EspHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_unchecked(input in esp_any()) {
+            let bytes = input.to_bytes();
+            let slice = unsafe { EspHeaderSlice::from_slice_unchecked(&bytes) };
+            assert_eq!(slice.slice(), &bytes[..]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in esp_any()) {
+            let bytes = input.to_bytes();
+            let slice = EspHeaderSlice::from_slice(&bytes).unwrap();
+
+            assert_eq!(slice.spi(), input.spi);
+            assert_eq!(slice.sequence_number(), input.sequence_number);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in esp_any()) {
+            let bytes = input.to_bytes();
+            let slice = EspHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in esp_any()) {
+            let bytes = input.to_bytes();
+            let slice = EspHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                &format!("EspHeaderSlice {{ slice: {:?} }}", slice.slice()),
+                &format!("{:?}", slice)
+            );
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::*;
+
+/// Slice containing an IPsec ESP header & the encrypted remainder of
+/// the packet.
+///
+/// As the content following the ESP header can not be decoded without the
+/// keys of the security association, all data after the header is returned
+/// as payload (including the padding, next header field & integrity
+/// check value).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EspSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> EspSlice<'a> {
+    /// Checks that the slice contains at least an ESP header and uses the
+    /// rest of the slice as encrypted payload.
+    pub fn from_slice(slice: &'a [u8]) -> Result<EspSlice<'a>, err::LenError> {
+        // only done to check the length
+        EspHeaderSlice::from_slice(slice)?;
+        Ok(EspSlice { slice })
+    }
+
+    /// Returns the slice containing the ESP header & encrypted payload.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns a slice containing only the ESP header.
+    #[inline]
+    pub fn header(&self) -> EspHeaderSlice<'a> {
+        // SAFETY: Safe as the slice length is checked to be at least
+        // EspHeader::LEN (8) in the constructor.
+        unsafe { EspHeaderSlice::from_slice_unchecked(self.slice) }
+    }
+
+    /// Returns the encrypted data following the ESP header.
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        // SAFETY: Safe as the slice length is checked to be at least
+        // EspHeader::LEN (8) in the constructor.
+        unsafe {
+            core::slice::from_raw_parts(
+                self.slice.as_ptr().add(EspHeader::LEN),
+                self.slice.len() - EspHeader::LEN,
+            )
+        }
+    }
+
+    /// Reads the "security parameters index" from the ESP header.
+    #[inline]
+    pub fn spi(&self) -> u32 {
+        // SAFETY: Safe as the slice length is checked to be at least
+        // EspHeader::LEN (8) in the constructor.
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr()) }
+    }
+
+    /// Reads the "sequence number" from the ESP header.
+    #[inline]
+    pub fn sequence_number(&self) -> u32 {
+        // SAFETY: Safe as the slice length is checked to be at least
+        // EspHeader::LEN (8) in the constructor.
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Decode all the fields of the ESP header and copy the results
+    /// to a EspHeader struct.
+    #[inline]
+    pub fn to_header(&self) -> EspHeader {
+        self.header().to_header()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in esp_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(EspHeader::LEN + payload.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer.extend(&payload[..]);
+
+            // ok case
+            {
+                let slice = EspSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(slice.slice(), &buffer[..]);
+                assert_eq!(slice.header().slice(), &buffer[..EspHeader::LEN]);
+                assert_eq!(slice.payload(), &payload[..]);
+                assert_eq!(slice.spi(), input.spi);
+                assert_eq!(slice.sequence_number(), input.sequence_number);
+                assert_eq!(slice.to_header(), input);
+            }
+
+            // length error
+            for len in 0..EspHeader::LEN {
+                assert_eq!(
+                    EspSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: EspHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::EspHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn clone_eq_dbg() {
+        let bytes = EspHeader::default().to_bytes();
+        let slice = EspSlice::from_slice(&bytes).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!(
+            &format!("EspSlice {{ slice: {:?} }}", slice.slice()),
+            &format!("{:?}", slice)
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use crate::*;
+use arrayvec::ArrayVec;
+
+/// Generic Routing Encapsulation (GRE) header according to
+/// [RFC 2784](https://datatracker.ietf.org/doc/html/rfc2784) &
+/// [RFC 2890](https://datatracker.ietf.org/doc/html/rfc2890) (IP number 47).
+///
+/// The optional fields are only present in the serialized header if
+/// they are set to `Some` (the corresponding "present" flags are set
+/// automatically during serialization).
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GreHeader {
+    /// GRE version number (3 bits, only the lower 3 bits are serialized).
+    ///
+    /// RFC 2784 & RFC 2890 define version 0, version 1 is used by PPTP.
+    pub version: u8,
+    /// Ether type of the encapsulated payload.
+    pub protocol_type: EtherType,
+    /// Checksum over the GRE header & payload (present if the
+    /// "checksum present" flag is set).
+    pub checksum: Option<u16>,
+    /// Key used to identify an individual traffic flow within a
+    /// tunnel (present if the "key present" flag is set).
+    pub key: Option<u32>,
+    /// Sequence number of the packet (present if the "sequence
+    /// number present" flag is set).
+    pub sequence_number: Option<u32>,
+}
+
+impl GreHeader {
+    /// Minimum length of a GRE header in bytes/octets (no optional fields).
+    pub const MIN_LEN: usize = 4;
+
+    /// Maximum length of a GRE header in bytes/octets (all optional fields present).
+    pub const MAX_LEN: usize = 16;
+
+    /// Bit in the first byte indicating that the checksum is present.
+    pub const CHECKSUM_PRESENT_FLAG: u8 = 0b1000_0000;
+
+    /// Bit in the first byte indicating that the key is present.
+    pub const KEY_PRESENT_FLAG: u8 = 0b0010_0000;
+
+    /// Bit in the first byte indicating that the sequence number is present.
+    pub const SEQUENCE_PRESENT_FLAG: u8 = 0b0001_0000;
+
+    /// Read a GRE header from a slice and return the header & unused parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(GreHeader, &[u8]), err::LenError> {
+        let s = GreHeaderSlice::from_slice(slice)?;
+        Ok((s.to_header(), &slice[s.slice().len()..]))
+    }
+
+    /// Reads a GRE header from the current position of the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<GreHeader, std::io::Error> {
+        let mut buffer = [0u8; GreHeader::MAX_LEN];
+        reader.read_exact(&mut buffer[..GreHeader::MIN_LEN])?;
+        let len = GreHeaderSlice::header_len_from_flags(buffer[0]);
+        reader.read_exact(&mut buffer[GreHeader::MIN_LEN..len])?;
+        // SAFETY:
+        // Safe as the buffer contains at least the required
+        // number of bytes given by the flags.
+        Ok(unsafe { GreHeaderSlice::from_slice_unchecked(&buffer[..len]) }.to_header())
+    }
+
+    /// Writes the GRE header to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        GreHeader::MIN_LEN
+            + if self.checksum.is_some() { 4 } else { 0 }
+            + if self.key.is_some() { 4 } else { 0 }
+            + if self.sequence_number.is_some() { 4 } else { 0 }
+    }
+
+    /// Calculates the checksum over the GRE header & the given payload
+    /// (the checksum field itself is treated as zero).
+    pub fn calc_checksum(&self, payload: &[u8]) -> u16 {
+        let mut header = self.clone();
+        header.checksum = Some(0);
+        checksum::Sum16BitWords::new()
+            .add_slice(&header.to_bytes())
+            .add_slice(payload)
+            .ones_complement()
+            .to_be()
+    }
+
+    /// Returns the serialized header.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { GreHeader::MAX_LEN }> {
+        let mut flags = 0;
+        if self.checksum.is_some() {
+            flags |= GreHeader::CHECKSUM_PRESENT_FLAG;
+        }
+        if self.key.is_some() {
+            flags |= GreHeader::KEY_PRESENT_FLAG;
+        }
+        if self.sequence_number.is_some() {
+            flags |= GreHeader::SEQUENCE_PRESENT_FLAG;
+        }
+        let protocol_type_be = self.protocol_type.0.to_be_bytes();
+
+        let mut result = ArrayVec::new();
+        result.extend([
+            flags,
+            self.version & 0b111,
+            protocol_type_be[0],
+            protocol_type_be[1],
+        ]);
+        if let Some(checksum) = self.checksum {
+            let checksum_be = checksum.to_be_bytes();
+            // the checksum is followed by 2 reserved bytes
+            result.extend([checksum_be[0], checksum_be[1], 0, 0]);
+        }
+        if let Some(key) = self.key {
+            result.extend(key.to_be_bytes());
+        }
+        if let Some(sequence_number) = self.sequence_number {
+            result.extend(sequence_number.to_be_bytes());
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            header in gre_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(header.header_len() + dummy_data.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+
+            // ok
+            {
+                let (actual, rest) = GreHeader::from_slice(&buffer).unwrap();
+                assert_eq!(actual, header);
+                assert_eq!(rest, &dummy_data[..]);
+            }
+
+            // length error
+            for len in 0..header.header_len() {
+                assert_eq!(
+                    GreHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: if len < GreHeader::MIN_LEN {
+                            GreHeader::MIN_LEN
+                        } else {
+                            header.header_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::GreHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(header in gre_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(header.header_len());
+                header.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &header.to_bytes()[..]);
+
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(header, GreHeader::read(&mut cursor).unwrap());
+                assert_eq!(header.header_len(), cursor.position() as usize);
+            }
+
+            // io errors
+            for len in 0..header.header_len() {
+                let bytes = header.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(GreHeader::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; GreHeader::MAX_LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(header.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn header_len() {
+        let mut header: GreHeader = Default::default();
+        assert_eq!(4, header.header_len());
+        header.checksum = Some(1);
+        assert_eq!(8, header.header_len());
+        header.key = Some(2);
+        assert_eq!(12, header.header_len());
+        header.sequence_number = Some(3);
+        assert_eq!(16, header.header_len());
+        header.checksum = None;
+        assert_eq!(12, header.header_len());
+    }
+
+    #[test]
+    fn to_bytes() {
+        // no optional fields
+        assert_eq!(
+            &GreHeader {
+                version: 0,
+                protocol_type: EtherType::IPV4,
+                checksum: None,
+                key: None,
+                sequence_number: None,
+            }
+            .to_bytes()[..],
+            &[0, 0, 0x08, 0x00]
+        );
+        // all optional fields & version bits masked
+        assert_eq!(
+            &GreHeader {
+                version: 0b1111_1001,
+                protocol_type: EtherType::IPV6,
+                checksum: Some(0x1234),
+                key: Some(0x5678_9abc),
+                sequence_number: Some(0xdef0_1234),
+            }
+            .to_bytes()[..],
+            &[
+                0b1011_0000,
+                0b001,
+                0x86,
+                0xdd,
+                0x12,
+                0x34,
+                0,
+                0,
+                0x56,
+                0x78,
+                0x9a,
+                0xbc,
+                0xde,
+                0xf0,
+                0x12,
+                0x34
+            ]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn calc_checksum(
+            header in gre_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut header = header.clone();
+            header.checksum = Some(header.calc_checksum(&payload));
+
+            // the checksum over the complete data must result in 0
+            let sum = checksum::Sum16BitWords::new()
+                .add_slice(&header.to_bytes())
+                .add_slice(&payload)
+                .ones_complement();
+            assert_eq!(0, sum);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_debug(header in gre_any()) {
+            assert_eq!(header, header.clone());
+            assert_eq!(
+                format!(
+                    "GreHeader {{ version: {:?}, protocol_type: {:?}, checksum: {:?}, key: {:?}, sequence_number: {:?} }}",
+                    header.version,
+                    header.protocol_type,
+                    header.checksum,
+                    header.key,
+                    header.sequence_number
+                ),
+                format!("{:?}", header)
+            );
+        }
+    }
+}
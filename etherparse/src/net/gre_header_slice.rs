@@ -0,0 +1,273 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing a GRE header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GreHeaderSlice<'a> {
+    /// Slice containing the header data.
+    slice: &'a [u8],
+}
+
+impl<'a> GreHeaderSlice<'a> {
+    /// Creates a GRE header slice from a slice.
+    ///
+    /// The length of the header is determined by the "present"
+    /// flags of the optional fields.
+    pub fn from_slice(slice: &'a [u8]) -> Result<GreHeaderSlice<'a>, err::LenError> {
+        // check length
+        if slice.len() < GreHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: GreHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::GreHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check the length again now that the optional fields are known
+        let len = GreHeaderSlice::header_len_from_flags(slice[0]);
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::GreHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(GreHeaderSlice {
+            // SAFETY:
+            // Safe as the slice has been checked in the previous if
+            // to have at least the the length of the variable len.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Creates a GRE header slice from a slice (assumes slice
+    /// size & content was validated before).
+    ///
+    /// # Safety
+    ///
+    /// This method assumes that the slice was previously validated to contain
+    /// a valid GRE header. This means the slice length must at least be
+    /// at least 4 and the length indicated by the flags in the first byte.
+    ///
+    /// If these preconditions are not fulfilled the behavior of this function
+    /// and the methods of the return [`GreHeaderSlice`] will be undefined.
+    pub unsafe fn from_slice_unchecked(slice: &'a [u8]) -> GreHeaderSlice<'a> {
+        GreHeaderSlice {
+            slice: from_raw_parts(
+                slice.as_ptr(),
+                GreHeaderSlice::header_len_from_flags(*slice.get_unchecked(0)),
+            ),
+        }
+    }
+
+    /// Calculates the header length based on the first byte of a GRE header.
+    #[inline]
+    pub(crate) fn header_len_from_flags(flags: u8) -> usize {
+        GreHeader::MIN_LEN
+            + if 0 != flags & GreHeader::CHECKSUM_PRESENT_FLAG {
+                4
+            } else {
+                0
+            }
+            + if 0 != flags & GreHeader::KEY_PRESENT_FLAG {
+                4
+            } else {
+                0
+            }
+            + if 0 != flags & GreHeader::SEQUENCE_PRESENT_FLAG {
+                4
+            } else {
+                0
+            }
+    }
+
+    /// Returns the slice containing the GRE header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns true if the "checksum present" flag is set.
+    #[inline]
+    pub fn checksum_present(&self) -> bool {
+        // SAFETY:
+        // Slice size checked to be at least 4 bytes in constructor.
+        0 != unsafe { *self.slice.get_unchecked(0) } & GreHeader::CHECKSUM_PRESENT_FLAG
+    }
+
+    /// Returns true if the "key present" flag is set.
+    #[inline]
+    pub fn key_present(&self) -> bool {
+        // SAFETY:
+        // Slice size checked to be at least 4 bytes in constructor.
+        0 != unsafe { *self.slice.get_unchecked(0) } & GreHeader::KEY_PRESENT_FLAG
+    }
+
+    /// Returns true if the "sequence number present" flag is set.
+    #[inline]
+    pub fn sequence_number_present(&self) -> bool {
+        // SAFETY:
+        // Slice size checked to be at least 4 bytes in constructor.
+        0 != unsafe { *self.slice.get_unchecked(0) } & GreHeader::SEQUENCE_PRESENT_FLAG
+    }
+
+    /// Returns the GRE version number (3 bits).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 4 bytes in constructor.
+        (unsafe { *self.slice.get_unchecked(1) }) & 0b111
+    }
+
+    /// Returns the ether type of the encapsulated payload.
+    #[inline]
+    pub fn protocol_type(&self) -> EtherType {
+        // SAFETY:
+        // Slice size checked to be at least 4 bytes in constructor.
+        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) })
+    }
+
+    /// Returns the checksum if the "checksum present" flag is set.
+    #[inline]
+    pub fn checksum(&self) -> Option<u16> {
+        if self.checksum_present() {
+            // SAFETY:
+            // Slice size checked in the constructor to contain
+            // the checksum if the flag is set.
+            Some(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the key if the "key present" flag is set.
+    #[inline]
+    pub fn key(&self) -> Option<u32> {
+        if self.key_present() {
+            let offset = if self.checksum_present() { 8 } else { 4 };
+            // SAFETY:
+            // Slice size checked in the constructor to contain
+            // the key if the flag is set.
+            Some(unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(offset)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the sequence number if the "sequence number present" flag is set.
+    #[inline]
+    pub fn sequence_number(&self) -> Option<u32> {
+        if self.sequence_number_present() {
+            // the sequence number is always the last field
+            // SAFETY:
+            // Slice size checked in the constructor to contain
+            // the sequence number if the flag is set.
+            Some(unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(self.slice.len() - 4)) })
+        } else {
+            None
+        }
+    }
+
+    /// Decode all the fields and copy the results to a [`GreHeader`] struct.
+    pub fn to_header(&self) -> GreHeader {
+        GreHeader {
+            version: self.version(),
+            protocol_type: self.protocol_type(),
+            checksum: self.checksum(),
+            key: self.key(),
+            sequence_number: self.sequence_number(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::format;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(header in gre_any()) {
+            let bytes = header.to_bytes();
+            let slice = GreHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.clone(), slice);
+            assert_eq!(
+                format!("{:?}", slice),
+                format!("GreHeaderSlice {{ slice: {:?} }}", slice.slice())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(header in gre_any()) {
+            let bytes = header.to_bytes();
+
+            // ok
+            {
+                let slice = GreHeaderSlice::from_slice(&bytes).unwrap();
+                assert_eq!(slice.slice(), &bytes[..]);
+            }
+
+            // length error
+            for len in 0..bytes.len() {
+                assert_eq!(
+                    GreHeaderSlice::from_slice(&bytes[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: if len < GreHeader::MIN_LEN {
+                            GreHeader::MIN_LEN
+                        } else {
+                            header.header_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::GreHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_unchecked(header in gre_any()) {
+            let bytes = header.to_bytes();
+            let slice = unsafe {
+                GreHeaderSlice::from_slice_unchecked(&bytes)
+            };
+            assert_eq!(&bytes[..], slice.slice());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(header in gre_any()) {
+            let bytes = header.to_bytes();
+            let slice = GreHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.checksum_present(), header.checksum.is_some());
+            assert_eq!(slice.key_present(), header.key.is_some());
+            assert_eq!(slice.sequence_number_present(), header.sequence_number.is_some());
+            assert_eq!(slice.version(), header.version);
+            assert_eq!(slice.protocol_type(), header.protocol_type);
+            assert_eq!(slice.checksum(), header.checksum);
+            assert_eq!(slice.key(), header.key);
+            assert_eq!(slice.sequence_number(), header.sequence_number);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(header in gre_any()) {
+            let bytes = header.to_bytes();
+            let slice = GreHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(header, slice.to_header());
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::*;
+
+/// GRE header and the decoded network headers of the encapsulated packet.
+///
+/// Used by [`PacketHeaders`] to store the tunnel in case a GRE packet
+/// was encountered in the payload of an IP packet.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GreTunnelHeaders {
+    /// GRE header following the outer IP header.
+    pub gre: GreHeader,
+    /// IPv4 or IPv6 headers of the encapsulated packet (only set if
+    /// the GRE protocol type is IPv4 or IPv6).
+    pub net: Option<NetHeaders>,
+}
+
+impl GreTunnelHeaders {
+    /// Returns the serialized length of the GRE header and the
+    /// encapsulated network headers.
+    pub fn header_len(&self) -> usize {
+        self.gre.header_len() + self.net.as_ref().map(|v| v.header_len()).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = GreTunnelHeaders {
+            gre: Default::default(),
+            net: None,
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!("{:?}", value),
+            format!("GreTunnelHeaders {{ gre: {:?}, net: None }}", value.gre)
+        );
+    }
+
+    #[test]
+    fn header_len() {
+        let gre = GreHeader {
+            key: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            8,
+            GreTunnelHeaders {
+                gre: gre.clone(),
+                net: None
+            }
+            .header_len()
+        );
+        assert_eq!(
+            8 + Ipv6Header::LEN,
+            GreTunnelHeaders {
+                gre,
+                net: Some(NetHeaders::Ipv6(Default::default(), Default::default()))
+            }
+            .header_len()
+        );
+    }
+}
@@ -42,6 +42,20 @@ impl Debug for IpAuthHeader {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for IpAuthHeader {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IpAuthHeader {{ next_header: {}, spi: {=u32}, sequence_number: {=u32}, raw_icv: {=[u8]} }}",
+            self.next_header,
+            self.spi,
+            self.sequence_number,
+            self.raw_icv()
+        )
+    }
+}
+
 impl PartialEq for IpAuthHeader {
     fn eq(&self, other: &Self) -> bool {
         self.next_header == other.next_header
@@ -53,6 +67,15 @@ impl PartialEq for IpAuthHeader {
 
 impl Eq for IpAuthHeader {}
 
+impl core::hash::Hash for IpAuthHeader {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.next_header.hash(state);
+        self.spi.hash(state);
+        self.sequence_number.hash(state);
+        self.raw_icv().hash(state);
+    }
+}
+
 impl Default for IpAuthHeader {
     fn default() -> Self {
         IpAuthHeader {
@@ -271,6 +294,14 @@ impl<'a> IpAuthHeader {
         12 + usize::from(self.raw_icv_len) * 4
     }
 
+    /// Returns the value of the "payload length" field (length of the
+    /// header in 4 octet units minus 2).
+    #[inline]
+    pub fn payload_len(&self) -> u8 {
+        // the icv length is limited to 0xfe, so no overflow can occur
+        self.raw_icv_len + 1
+    }
+
     /// Returns the serialized header.
     pub fn to_bytes(&self) -> ArrayVec<u8, { IpAuthHeader::MAX_LEN }> {
         let spi_be = self.spi.to_be_bytes();
@@ -659,6 +690,14 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn payload_len(header in ip_auth_any()) {
+            assert_eq!((usize::from(header.payload_len()) + 2) * 4, header.header_len());
+            assert_eq!(header.payload_len(), header.to_bytes()[1]);
+        }
+    }
+
     proptest! {
         #[test]
         fn to_bytes(header in ip_auth_any()) {
@@ -679,4 +718,25 @@ mod test {
             assert_eq!(&bytes[12..], header.raw_icv());
         }
     }
+
+    proptest! {
+        #[test]
+        fn hash(header in ip_auth_any()) {
+            use core::hash::{Hash, Hasher};
+            use std::collections::hash_map::DefaultHasher;
+
+            // bytes outside of the used area of the buffer
+            // should not influence the hash
+            let mut other = header.clone();
+            other.raw_icv_buffer[usize::from(header.raw_icv_len) * 4..].fill(0xff);
+            assert_eq!(header, other);
+
+            let hash = |value: &IpAuthHeader| {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(hash(&header), hash(&other));
+        }
+    }
 }
@@ -98,6 +98,16 @@ impl<'a> IpAuthHeaderSlice<'a> {
         IpNumber(unsafe { *self.slice.get_unchecked(0) })
     }
 
+    /// Returns the "payload length" field (length of the authentication
+    /// header in 4 octet units minus 2).
+    #[inline]
+    pub fn payload_len(&self) -> u8 {
+        // SAFETY:
+        // Safe as slice length is checked in the constructor
+        // to be at least 12.
+        unsafe { *self.slice.get_unchecked(1) }
+    }
+
     /// Read the security parameters index from the slice
     #[inline]
     pub fn spi(&self) -> u32 {
@@ -236,6 +246,7 @@ mod test {
             let slice = IpAuthHeaderSlice::from_slice(&bytes).unwrap();
             assert_eq!(slice.slice(), &bytes[..]);
             assert_eq!(slice.next_header(), header.next_header);
+            assert_eq!(slice.payload_len(), header.payload_len());
             assert_eq!(slice.spi(), header.spi);
             assert_eq!(slice.sequence_number(), header.sequence_number);
             assert_eq!(slice.raw_icv(), header.raw_icv());
@@ -0,0 +1,84 @@
+use crate::*;
+
+/// Protocol in which the chain of IP header & IP extension headers ends
+/// (see [`SlicedPacket::ip_chain_end`]).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum IpChainEnd {
+    /// The chain ends in [`IpNumber::IPV6_NO_NEXT_HEADER`] (59), meaning
+    /// that there is no payload by design ([RFC 8200 Section
+    /// 4.7](https://datatracker.ietf.org/doc/html/rfc8200#section-4.7)).
+    NoNextHeader,
+    /// The chain ends in a transport protocol that can be decoded by
+    /// etherparse (ICMPv4, ICMPv6, UDP, TCP, IGMP, ESP or DCCP).
+    Transport(IpNumber),
+    /// The chain ends in a protocol not decoded by etherparse (or an
+    /// IP number not assigned to a protocol).
+    Unknown(IpNumber),
+}
+
+impl IpChainEnd {
+    /// Classifies the IP number following the last IP (extension) header.
+    pub fn from_ip_number(ip_number: IpNumber) -> IpChainEnd {
+        use ip_number::*;
+        match ip_number {
+            IPV6_NO_NEXT_HEADER => IpChainEnd::NoNextHeader,
+            ICMP | IPV6_ICMP | UDP | TCP | IGMP | ENCAP_SEC | DCCP => {
+                IpChainEnd::Transport(ip_number)
+            }
+            other => IpChainEnd::Unknown(other),
+        }
+    }
+
+    /// Returns the IP number following the last IP (extension) header.
+    pub fn ip_number(&self) -> IpNumber {
+        match self {
+            IpChainEnd::NoNextHeader => IpNumber::IPV6_NO_NEXT_HEADER,
+            IpChainEnd::Transport(v) => *v,
+            IpChainEnd::Unknown(v) => *v,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = IpChainEnd::Transport(ip_number::UDP);
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!("Transport({:?})", ip_number::UDP),
+            format!("{:?}", value)
+        );
+        assert_eq!("NoNextHeader", format!("{:?}", IpChainEnd::NoNextHeader));
+    }
+
+    #[test]
+    fn from_ip_number() {
+        use ip_number::*;
+
+        assert_eq!(
+            IpChainEnd::NoNextHeader,
+            IpChainEnd::from_ip_number(IPV6_NO_NEXT_HEADER)
+        );
+        for n in [ICMP, IPV6_ICMP, UDP, TCP, IGMP, ENCAP_SEC, DCCP] {
+            assert_eq!(IpChainEnd::Transport(n), IpChainEnd::from_ip_number(n));
+        }
+        for n in [IPV6_HOP_BY_HOP, IPV6_FRAG, AUTH, SCTP, IpNumber(253)] {
+            assert_eq!(IpChainEnd::Unknown(n), IpChainEnd::from_ip_number(n));
+        }
+    }
+
+    #[test]
+    fn ip_number() {
+        for n in 0..=u8::MAX {
+            assert_eq!(
+                IpNumber(n),
+                IpChainEnd::from_ip_number(IpNumber(n)).ip_number()
+            );
+        }
+    }
+}
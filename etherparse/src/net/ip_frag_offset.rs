@@ -52,7 +52,29 @@ use crate::err::ValueTooBigError;
 ///     assert_eq!(frag_offset.value(), 123);
 /// }
 /// ```
+///
+/// Fragment offsets are ordered by their value, so fragments can be
+/// sorted by their offset without converting it to an `u16`:
+///
+/// ```
+/// use etherparse::{IpFragOffset, Ipv6FragmentHeader, ip_number};
+///
+/// let mut fragments = [
+///     Ipv6FragmentHeader::new(ip_number::UDP, IpFragOffset::try_new(2).unwrap(), false, 1),
+///     Ipv6FragmentHeader::new(ip_number::UDP, IpFragOffset::ZERO, true, 1),
+///     Ipv6FragmentHeader::new(ip_number::UDP, IpFragOffset::try_new(1).unwrap(), true, 1),
+/// ];
+/// fragments.sort_by_key(|f| f.fragment_offset);
+/// assert!(fragments[0].fragment_offset < fragments[1].fragment_offset);
+/// assert!(fragments[1].fragment_offset < fragments[2].fragment_offset);
+/// ```
 #[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u16", into = "u16")
+)]
 pub struct IpFragOffset(u16);
 
 impl IpFragOffset {
@@ -299,4 +321,22 @@ mod test {
             assert_eq!(actual, valid_value);
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        // serialized as inner value
+        assert_tokens(&IpFragOffset::try_new(123).unwrap(), &[Token::U16(123)]);
+
+        // values exceeding the maximum are rejected
+        assert_de_tokens_error::<IpFragOffset>(
+            &[Token::U16(IpFragOffset::MAX_U16 + 1)],
+            &format!(
+                "{}",
+                IpFragOffset::try_new(IpFragOffset::MAX_U16 + 1).unwrap_err()
+            ),
+        );
+    }
 }
@@ -4,7 +4,8 @@ use crate::err::{Layer, LenError, ValueTooBigError};
 use crate::*;
 
 /// Internet protocol headers version 4 & 6.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[allow(clippy::large_enum_variant)]
 pub enum IpHeaders {
     /// IPv4 header & extension headers.
@@ -194,45 +195,61 @@ impl IpHeaders {
                     };
 
                     // restrict slice by the length specified in the header
-                    let (header_payload, len_source) =
-                        if 0 == header.payload_length && slice.len() > Ipv6Header::LEN {
-                            // In case the payload_length is 0 assume that the entire
-                            // rest of the slice is part of the packet until the jumbogram
-                            // parameters can be parsed.
-
-                            // TODO: Add payload length parsing from the jumbogram
-                            unsafe {
-                                (
-                                    core::slice::from_raw_parts(
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        slice.len() - Ipv6Header::LEN,
-                                    ),
-                                    LenSource::Slice,
-                                )
-                            }
-                        } else {
-                            let payload_len: usize = header.payload_length.into();
-                            let expected_len = Ipv6Header::LEN + payload_len;
-                            if slice.len() < expected_len {
+                    let (header_payload, len_source) = if 0 == header.payload_length
+                        && slice.len() > Ipv6Header::LEN
+                    {
+                        // SAFETY: Safe as the slice is longer then Ipv6Header::LEN.
+                        let rest = unsafe {
+                            core::slice::from_raw_parts(
+                                slice.as_ptr().add(Ipv6Header::LEN),
+                                slice.len() - Ipv6Header::LEN,
+                            )
+                        };
+                        if let Some(jumbo_len) =
+                            Ipv6Option::jumbo_payload_after_ipv6_header(header.next_header, rest)
+                        {
+                            // jumbogram, the payload length is determined by the
+                            // "jumbo payload" option in the hop by hop header
+                            let payload_len = usize::try_from(jumbo_len).unwrap_or(usize::MAX);
+                            if rest.len() < payload_len {
                                 return Err(Len(LenError {
-                                    required_len: expected_len,
+                                    required_len: Ipv6Header::LEN.saturating_add(payload_len),
                                     len: slice.len(),
                                     len_source: LenSource::Slice,
                                     layer: Layer::Ipv6Packet,
                                     layer_start_offset: 0,
                                 }));
-                            } else {
-                                unsafe {
-                                    (
-                                        core::slice::from_raw_parts(
-                                            slice.as_ptr().add(Ipv6Header::LEN),
-                                            payload_len,
-                                        ),
-                                        LenSource::Ipv6HeaderPayloadLen,
-                                    )
-                                }
                             }
-                        };
+                            (&rest[..payload_len], LenSource::Ipv6JumboPayloadLen)
+                        } else {
+                            // In case the payload_length is 0 & no jumbo payload
+                            // option is present assume that the entire rest of the
+                            // slice is part of the packet.
+                            (rest, LenSource::Slice)
+                        }
+                    } else {
+                        let payload_len: usize = header.payload_length.into();
+                        let expected_len = Ipv6Header::LEN + payload_len;
+                        if slice.len() < expected_len {
+                            return Err(Len(LenError {
+                                required_len: expected_len,
+                                len: slice.len(),
+                                len_source: LenSource::Slice,
+                                layer: Layer::Ipv6Packet,
+                                layer_start_offset: 0,
+                            }));
+                        } else {
+                            unsafe {
+                                (
+                                    core::slice::from_raw_parts(
+                                        slice.as_ptr().add(Ipv6Header::LEN),
+                                        payload_len,
+                                    ),
+                                    LenSource::Ipv6HeaderPayloadLen,
+                                )
+                            }
+                        }
+                    };
 
                     let (exts, next_header, rest) =
                         Ipv6Extensions::from_slice(header.next_header, header_payload).map_err(
@@ -314,7 +331,8 @@ impl IpHeaders {
     /// if the `payload_length` is
     ///
     /// * Bigger then the given slice (payload cannot fully be seperated).
-    /// * The value `0`.
+    /// * The value `0` and no "Jumbo Payload" option is present in a
+    ///   hop by hop header (or the slice is smaller then the jumbo payload length).
     pub fn from_slice_lax(
         slice: &[u8],
     ) -> Result<
@@ -483,48 +501,56 @@ impl IpHeaders {
 
                     // restrict slice by the length specified in the header
                     let payload_len = usize::from(header.payload_length);
-                    let (header_payload, len_source, incomplete) =
-                        if (header.payload_length == 0) && (Ipv6Header::LEN < slice.len()) {
-                            // TODO: Add payload length parsing from the jumbogram
-                            unsafe {
-                                (
-                                    core::slice::from_raw_parts(
-                                        // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
-                                        slice.len() - Ipv6Header::LEN,
-                                    ),
-                                    LenSource::Slice,
-                                    false,
-                                )
-                            }
-                        } else if (slice.len() - Ipv6Header::LEN) < payload_len {
-                            unsafe {
-                                (
-                                    core::slice::from_raw_parts(
-                                        // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
-                                        slice.len() - Ipv6Header::LEN,
-                                    ),
-                                    LenSource::Slice,
-                                    true,
-                                )
-                            }
-                        } else {
-                            unsafe {
-                                (
-                                    core::slice::from_raw_parts(
-                                        // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        // SAFTEY: Safe as we verify that `(slice.len() - Ipv6Header::LEN) >= payload_len` above.
-                                        payload_len,
-                                    ),
-                                    LenSource::Ipv6HeaderPayloadLen,
-                                    false,
-                                )
-                            }
+                    let (header_payload, len_source, incomplete) = if (header.payload_length == 0)
+                        && (Ipv6Header::LEN < slice.len())
+                    {
+                        let rest = unsafe {
+                            core::slice::from_raw_parts(
+                                // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
+                                slice.as_ptr().add(Ipv6Header::LEN),
+                                // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
+                                slice.len() - Ipv6Header::LEN,
+                            )
                         };
+                        match Ipv6Option::jumbo_payload_after_ipv6_header(header.next_header, rest)
+                            .map(|v| usize::try_from(v).unwrap_or(usize::MAX))
+                        {
+                            // jumbogram, the payload length is determined by the
+                            // "jumbo payload" option in the hop by hop header
+                            Some(jumbo_len) if jumbo_len <= rest.len() => {
+                                (&rest[..jumbo_len], LenSource::Ipv6JumboPayloadLen, false)
+                            }
+                            // slice is smaller then the jumbo payload length
+                            Some(_) => (rest, LenSource::Slice, true),
+                            None => (rest, LenSource::Slice, false),
+                        }
+                    } else if (slice.len() - Ipv6Header::LEN) < payload_len {
+                        unsafe {
+                            (
+                                core::slice::from_raw_parts(
+                                    // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
+                                    slice.as_ptr().add(Ipv6Header::LEN),
+                                    // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
+                                    slice.len() - Ipv6Header::LEN,
+                                ),
+                                LenSource::Slice,
+                                true,
+                            )
+                        }
+                    } else {
+                        unsafe {
+                            (
+                                core::slice::from_raw_parts(
+                                    // SAFTEY: Safe as we verify what `slice.len() >= Ipv6Header::LEN` above.
+                                    slice.as_ptr().add(Ipv6Header::LEN),
+                                    // SAFTEY: Safe as we verify that `(slice.len() - Ipv6Header::LEN) >= payload_len` above.
+                                    payload_len,
+                                ),
+                                LenSource::Ipv6HeaderPayloadLen,
+                                false,
+                            )
+                        }
+                    };
 
                     let (exts, next_header, rest, stop_err) =
                         Ipv6Extensions::from_slice_lax(header.next_header, header_payload);
@@ -767,9 +793,10 @@ impl IpHeaders {
     /// containing the payload (e.g. TCP, UDP etc.) length limited by payload_length
     /// field in the IPv6 header.
     ///
-    /// Note that slice length is used as a fallback value in case the
-    /// payload_length in the IPv6 is set to zero. This is a temporary workaround
-    /// to partially support jumbograms.
+    /// If the payload_length in the IPv6 header is set to zero the length
+    /// from the "Jumbo Payload" option in the hop by hop header is used
+    /// (jumbograms). If no such option is present the slice length is used
+    /// as a fallback value.
     pub fn from_ipv6_slice(
         slice: &[u8],
     ) -> Result<(IpHeaders, IpPayloadSlice<'_>), err::ipv6::SliceError> {
@@ -787,12 +814,28 @@ impl IpHeaders {
         // restrict slice by the length specified in the header
         let (header_payload, len_source) =
             if 0 == header.payload_length && slice.len() > Ipv6Header::LEN {
-                // In case the payload_length is 0 assume that the entire
-                // rest of the slice is part of the packet until the jumbogram
-                // parameters can be parsed.
-
-                // TODO: Add payload length parsing from the jumbogram
-                (header_rest, LenSource::Slice)
+                if let Some(jumbo_len) =
+                    Ipv6Option::jumbo_payload_after_ipv6_header(header.next_header, header_rest)
+                {
+                    // jumbogram, the payload length is determined by the
+                    // "jumbo payload" option in the hop by hop header
+                    let payload_len = usize::try_from(jumbo_len).unwrap_or(usize::MAX);
+                    if header_rest.len() < payload_len {
+                        return Err(Len(LenError {
+                            required_len: Ipv6Header::LEN.saturating_add(payload_len),
+                            len: slice.len(),
+                            len_source: LenSource::Slice,
+                            layer: Layer::Ipv6Packet,
+                            layer_start_offset: 0,
+                        }));
+                    }
+                    (&header_rest[..payload_len], LenSource::Ipv6JumboPayloadLen)
+                } else {
+                    // In case the payload_length is 0 & no jumbo payload
+                    // option is present assume that the entire rest of the
+                    // slice is part of the packet.
+                    (header_rest, LenSource::Slice)
+                }
             } else {
                 let payload_len: usize = header.payload_length.into();
                 if header_rest.len() < payload_len {
@@ -879,7 +922,8 @@ impl IpHeaders {
     /// field in the IPv6 header is
     ///
     /// * Bigger then the given slice (payload cannot fully be seperated).
-    /// * The value `0`.
+    /// * The value `0` and no "Jumbo Payload" option is present in a
+    ///   hop by hop header (or the slice is smaller then the jumbo payload length).
     pub fn from_ipv6_slice_lax(
         slice: &[u8],
     ) -> Result<
@@ -897,7 +941,20 @@ impl IpHeaders {
         let payload_len: usize = header.payload_length.into();
         let (header_payload, len_source, incomplete) =
             if payload_len == 0 && (false == header_rest.is_empty()) {
-                (header_rest, LenSource::Slice, false)
+                match Ipv6Option::jumbo_payload_after_ipv6_header(header.next_header, header_rest)
+                    .map(|v| usize::try_from(v).unwrap_or(usize::MAX))
+                {
+                    // jumbogram, the payload length is determined by the
+                    // "jumbo payload" option in the hop by hop header
+                    Some(jumbo_len) if jumbo_len <= header_rest.len() => (
+                        &header_rest[..jumbo_len],
+                        LenSource::Ipv6JumboPayloadLen,
+                        false,
+                    ),
+                    // slice is smaller then the jumbo payload length
+                    Some(_) => (header_rest, LenSource::Slice, true),
+                    None => (header_rest, LenSource::Slice, false),
+                }
             } else if payload_len > header_rest.len() {
                 (header_rest, LenSource::Slice, true)
             } else {
@@ -2639,4 +2696,86 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn jumbogram() {
+        // jumbo payload lengths have to be bigger then 65535 (RFC 2675)
+        let payload: Vec<u8> = (0..0x1_0000u32).map(|v| v as u8).collect();
+        let jumbo_len = 8 + payload.len();
+        let mut data = Vec::new();
+        data.extend_from_slice(
+            &Ipv6Header {
+                payload_length: 0,
+                next_header: IPV6_HOP_BY_HOP,
+                ..Default::default()
+            }
+            .to_bytes(),
+        );
+        data.extend_from_slice(&[UDP.0, 0, Ipv6Option::TYPE_JUMBO_PAYLOAD, 4]);
+        data.extend_from_slice(&(jumbo_len as u32).to_be_bytes());
+        data.extend_from_slice(&payload);
+        // data after the packet
+        data.extend_from_slice(&[5, 6]);
+
+        // ok
+        {
+            let expected = IpPayloadSlice {
+                ip_number: UDP,
+                fragmented: false,
+                len_source: LenSource::Ipv6JumboPayloadLen,
+                payload: &payload,
+            };
+            assert_eq!(IpHeaders::from_slice(&data).unwrap().1, expected);
+            assert_eq!(IpHeaders::from_ipv6_slice(&data).unwrap().1, expected);
+
+            let expected_lax = LaxIpPayloadSlice {
+                incomplete: false,
+                ip_number: UDP,
+                fragmented: false,
+                len_source: LenSource::Ipv6JumboPayloadLen,
+                payload: &payload,
+            };
+            assert_eq!(IpHeaders::from_slice_lax(&data).unwrap().1, expected_lax);
+            assert_eq!(
+                IpHeaders::from_ipv6_slice_lax(&data).unwrap().1,
+                expected_lax
+            );
+        }
+
+        // slice smaller then the jumbo payload length
+        {
+            let len = Ipv6Header::LEN + jumbo_len - 1;
+            let expected = LenError {
+                required_len: Ipv6Header::LEN + jumbo_len,
+                len,
+                len_source: LenSource::Slice,
+                layer: Layer::Ipv6Packet,
+                layer_start_offset: 0,
+            };
+            assert_eq!(
+                IpHeaders::from_slice(&data[..len]).unwrap_err(),
+                HeadersSliceError::Len(expected.clone())
+            );
+            assert_eq!(
+                IpHeaders::from_ipv6_slice(&data[..len]).unwrap_err(),
+                err::ipv6::SliceError::Len(expected)
+            );
+
+            let expected_lax = LaxIpPayloadSlice {
+                incomplete: true,
+                ip_number: UDP,
+                fragmented: false,
+                len_source: LenSource::Slice,
+                payload: &payload[..payload.len() - 1],
+            };
+            assert_eq!(
+                IpHeaders::from_slice_lax(&data[..len]).unwrap().1,
+                expected_lax
+            );
+            assert_eq!(
+                IpHeaders::from_ipv6_slice_lax(&data[..len]).unwrap().1,
+                expected_lax
+            );
+        }
+    }
 }
@@ -0,0 +1,112 @@
+/// Set of IP header fields that are skipped when comparing headers via
+/// [`crate::Ipv4Header::equals_ignoring`] or
+/// [`crate::Ipv6Header::equals_ignoring`].
+///
+/// Flags can be combined via `|`.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{IpIgnoreFields, Ipv4Header, ip_number};
+///
+/// let before = Ipv4Header::new(8, 64, ip_number::UDP, [10, 0, 0, 1], [10, 0, 0, 2]).unwrap();
+/// let mut after = before.clone();
+/// after.time_to_live -= 1;
+/// after.header_checksum = after.calc_header_checksum();
+///
+/// assert!(before != after);
+/// assert!(before.equals_ignoring(&after, IpIgnoreFields::TTL | IpIgnoreFields::CHECKSUM));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IpIgnoreFields(u8);
+
+impl IpIgnoreFields {
+    /// No field is ignored (same result as `==`).
+    pub const NONE: IpIgnoreFields = IpIgnoreFields(0);
+
+    /// Ignore the IPv4 "time to live" & IPv6 "hop limit" fields.
+    pub const TTL: IpIgnoreFields = IpIgnoreFields(0b001);
+
+    /// Ignore the IPv4 header checksum (no effect on IPv6 headers).
+    pub const CHECKSUM: IpIgnoreFields = IpIgnoreFields(0b010);
+
+    /// Ignore the "type of service" (IPv4 DSCP & ECN fields, IPv6
+    /// traffic class).
+    pub const TOS: IpIgnoreFields = IpIgnoreFields(0b100);
+
+    /// Ignore all fields covered by the flags above.
+    pub const ALL: IpIgnoreFields = IpIgnoreFields(0b111);
+
+    /// Returns the raw bits of the set.
+    #[inline]
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns true if all flags in `other` are set.
+    #[inline]
+    pub const fn contains(&self, other: IpIgnoreFields) -> bool {
+        other.0 == self.0 & other.0
+    }
+
+    /// Returns true if no flag is set.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        0 == self.0
+    }
+}
+
+impl core::ops::BitOr for IpIgnoreFields {
+    type Output = IpIgnoreFields;
+
+    #[inline]
+    fn bitor(self, rhs: IpIgnoreFields) -> IpIgnoreFields {
+        IpIgnoreFields(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for IpIgnoreFields {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: IpIgnoreFields) {
+        self.0 |= rhs.0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq_default() {
+        let value = IpIgnoreFields::TTL;
+        assert_eq!(value, value.clone());
+        assert_eq!("IpIgnoreFields(1)", format!("{:?}", value));
+        assert_eq!(IpIgnoreFields::NONE, IpIgnoreFields::default());
+    }
+
+    #[test]
+    fn contains_bits() {
+        assert!(IpIgnoreFields::NONE.is_empty());
+        assert!(!IpIgnoreFields::TTL.is_empty());
+        assert!(IpIgnoreFields::ALL.contains(IpIgnoreFields::TTL));
+        assert!(IpIgnoreFields::ALL.contains(IpIgnoreFields::CHECKSUM));
+        assert!(IpIgnoreFields::ALL.contains(IpIgnoreFields::TOS));
+        assert!(IpIgnoreFields::TTL.contains(IpIgnoreFields::NONE));
+        assert!(!IpIgnoreFields::TTL.contains(IpIgnoreFields::TOS));
+        assert!(!IpIgnoreFields::TTL.contains(IpIgnoreFields::ALL));
+        assert_eq!(0b111, IpIgnoreFields::ALL.bits());
+    }
+
+    #[test]
+    fn bit_or() {
+        assert_eq!(
+            IpIgnoreFields::ALL,
+            IpIgnoreFields::TTL | IpIgnoreFields::CHECKSUM | IpIgnoreFields::TOS
+        );
+        let mut value = IpIgnoreFields::NONE;
+        value |= IpIgnoreFields::CHECKSUM;
+        assert_eq!(IpIgnoreFields::CHECKSUM, value);
+    }
+}
@@ -44,6 +44,11 @@ pub type IpTrafficClass = IpNumber;
 /// The list original values were copied from
 /// <https://www.iana.org/assignments/protocol-numbers/protocol-numbers.xhtml>
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct IpNumber(pub u8);
 
 impl IpNumber {
@@ -745,6 +750,43 @@ impl core::fmt::Debug for IpNumber {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for IpNumber {
+    fn format(&self, f: defmt::Formatter) {
+        if let Some(keyword) = self.keyword_str() {
+            if let Some(protocol) = self.protocol_str() {
+                defmt::write!(f, "{} ({=str} - {=str})", self.0, keyword, protocol)
+            } else {
+                defmt::write!(f, "{} ({=str})", self.0, keyword)
+            }
+        } else if let Some(protocol) = self.protocol_str() {
+            defmt::write!(f, "{} ({=str})", self.0, protocol)
+        } else {
+            defmt::write!(f, "{}", self.0)
+        }
+    }
+}
+
+/// Writes the keyword of the ip number (see [`IpNumber::keyword_str`])
+/// followed by the numeric value or only the numeric value if no
+/// keyword is known.
+///
+/// ```
+/// use etherparse::{IpNumber, ip_number};
+///
+/// assert_eq!("IPv6-Frag (44)", format!("{}", ip_number::IPV6_FRAG));
+/// assert_eq!("145", format!("{}", IpNumber(145)));
+/// ```
+impl core::fmt::Display for IpNumber {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(keyword) = self.keyword_str() {
+            write!(f, "{} ({})", keyword, self.0)
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
 /// Constants for the ip protocol numbers for easy importing (e.g. `use ip_number::*;`).
 ///
 /// The constants only exist for convenience so you can import them
@@ -1870,6 +1912,19 @@ mod tests {
         assert_eq!(format!("{:?}", IpNumber(145)), format!("145"));
     }
 
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", IpNumber::UDP), "UDP (17)");
+        assert_eq!(
+            format!("{}", IpNumber::IPV6_FRAGMENTATION_HEADER),
+            "IPv6-Frag (44)"
+        );
+        // only protocol string exist
+        assert_eq!(format!("{}", IpNumber(253)), "253");
+        // no keyword & no protocol string
+        assert_eq!(format!("{}", IpNumber(145)), "145");
+    }
+
     #[test]
     fn clone_eq_hash_ord() {
         // clone eq
@@ -1891,4 +1946,11 @@ mod tests {
         assert_eq!(value.cmp(&value.clone()), Ordering::Equal);
         assert!(value.ge(&value.clone()));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_tokens, Token};
+        assert_tokens(&IpNumber::TCP, &[Token::U8(6)]);
+    }
 }
@@ -40,22 +40,16 @@ impl<'a> IpSlice<'a> {
         }
     }
 
-    /// Return the source address as an std::net::Ipvddr (requires
-    /// crate feature `std`).
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-    pub fn source_addr(&self) -> std::net::IpAddr {
+    /// Return the source address as an core::net::IpAddr.
+    pub fn source_addr(&self) -> core::net::IpAddr {
         match self {
             IpSlice::Ipv4(s) => s.header().source_addr().into(),
             IpSlice::Ipv6(s) => s.header().source_addr().into(),
         }
     }
 
-    /// Return the destination address as an std::net::IpAddr (requires
-    /// crate feature `std`).
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-    pub fn destination_addr(&self) -> std::net::IpAddr {
+    /// Return the destination address as an core::net::IpAddr.
+    pub fn destination_addr(&self) -> core::net::IpAddr {
         match self {
             IpSlice::Ipv4(s) => s.header().destination_addr().into(),
             IpSlice::Ipv6(s) => s.header().destination_addr().into(),
@@ -90,6 +84,16 @@ impl<'a> IpSlice<'a> {
     /// in the given slice and determine the sub-slice containing the payload
     /// of the IP packet.
     pub fn from_slice(slice: &[u8]) -> Result<IpSlice, err::ip::SliceError> {
+        Self::from_slice_with_max_ipv6_exts(slice, None)
+    }
+
+    /// Separates and validates IP headers like [`IpSlice::from_slice`] but
+    /// limits the number of decoded IPv6 extension headers to `max_ipv6_exts`
+    /// (if set).
+    pub(crate) fn from_slice_with_max_ipv6_exts(
+        slice: &[u8],
+        max_ipv6_exts: Option<usize>,
+    ) -> Result<IpSlice<'_>, err::ip::SliceError> {
         use crate::ip_number::AUTH;
         use err::ip::{HeaderError::*, HeadersError::*, SliceError::*};
         use IpSlice::*;
@@ -243,61 +247,86 @@ impl<'a> IpSlice<'a> {
                     };
 
                     // restrict slice by the length specified in the header
-                    let (header_payload, len_source) =
-                        if 0 == header.payload_length() && slice.len() > Ipv6Header::LEN {
-                            // In case the payload_length is 0 assume that the entire
-                            // rest of the slice is part of the packet until the jumbogram
-                            // parameters can be parsed.
-
-                            // TODO: Add payload length parsing from the jumbogram
-                            (
-                                unsafe {
-                                    core::slice::from_raw_parts(
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        slice.len() - Ipv6Header::LEN,
-                                    )
-                                },
-                                LenSource::Slice,
+                    let (header_payload, len_source) = if 0 == header.payload_length()
+                        && slice.len() > Ipv6Header::LEN
+                    {
+                        // SAFETY: Safe as the slice is longer then Ipv6Header::LEN.
+                        let rest = unsafe {
+                            core::slice::from_raw_parts(
+                                slice.as_ptr().add(Ipv6Header::LEN),
+                                slice.len() - Ipv6Header::LEN,
                             )
-                        } else {
-                            let payload_len = usize::from(header.payload_length());
-                            let expected_len = Ipv6Header::LEN + payload_len;
-                            if slice.len() < expected_len {
+                        };
+                        if let Some(jumbo_len) =
+                            Ipv6Option::jumbo_payload_after_ipv6_header(header.next_header(), rest)
+                        {
+                            // jumbogram, the payload length is determined by the
+                            // "jumbo payload" option in the hop by hop header
+                            let payload_len = usize::try_from(jumbo_len).unwrap_or(usize::MAX);
+                            if rest.len() < payload_len {
                                 return Err(Len(LenError {
-                                    required_len: expected_len,
+                                    required_len: Ipv6Header::LEN.saturating_add(payload_len),
                                     len: slice.len(),
                                     len_source: LenSource::Slice,
                                     layer: Layer::Ipv6Packet,
                                     layer_start_offset: 0,
                                 }));
-                            } else {
-                                (
-                                    unsafe {
-                                        core::slice::from_raw_parts(
-                                            slice.as_ptr().add(Ipv6Header::LEN),
-                                            payload_len,
-                                        )
-                                    },
-                                    LenSource::Ipv6HeaderPayloadLen,
-                                )
                             }
-                        };
+                            (&rest[..payload_len], LenSource::Ipv6JumboPayloadLen)
+                        } else {
+                            // In case the payload_length is 0 & no jumbo payload
+                            // option is present assume that the entire rest of the
+                            // slice is part of the packet.
+                            (rest, LenSource::Slice)
+                        }
+                    } else {
+                        let payload_len = usize::from(header.payload_length());
+                        let expected_len = Ipv6Header::LEN + payload_len;
+                        if slice.len() < expected_len {
+                            return Err(Len(LenError {
+                                required_len: expected_len,
+                                len: slice.len(),
+                                len_source: LenSource::Slice,
+                                layer: Layer::Ipv6Packet,
+                                layer_start_offset: 0,
+                            }));
+                        } else {
+                            (
+                                unsafe {
+                                    core::slice::from_raw_parts(
+                                        slice.as_ptr().add(Ipv6Header::LEN),
+                                        payload_len,
+                                    )
+                                },
+                                LenSource::Ipv6HeaderPayloadLen,
+                            )
+                        }
+                    };
 
                     // parse extension headers
                     let (exts, payload_ip_number, payload) =
-                        Ipv6ExtensionsSlice::from_slice(header.next_header(), header_payload)
-                            .map_err(|err| {
-                                // modify length errors
-                                use crate::err::ipv6_exts::HeaderSliceError as I;
-                                match err {
-                                    I::Len(mut err) => {
-                                        err.len_source = LenSource::Ipv6HeaderPayloadLen;
-                                        err.layer_start_offset += Ipv6Header::LEN;
-                                        Len(err)
-                                    }
-                                    I::Content(err) => IpHeaders(ip::HeadersError::Ipv6Ext(err)),
+                        Ipv6ExtensionsSlice::from_slice_with_max_headers(
+                            header.next_header(),
+                            header_payload,
+                            max_ipv6_exts,
+                        )
+                        .map_err(|err| {
+                            // modify length errors
+                            use crate::err::ipv6_exts::HeaderSliceError as I;
+                            match err {
+                                I::Len(mut err) => {
+                                    err.len_source = if LenSource::Ipv6JumboPayloadLen == len_source
+                                    {
+                                        len_source
+                                    } else {
+                                        LenSource::Ipv6HeaderPayloadLen
+                                    };
+                                    err.layer_start_offset += Ipv6Header::LEN;
+                                    Len(err)
                                 }
-                            })?;
+                                I::Content(err) => IpHeaders(ip::HeadersError::Ipv6Ext(err)),
+                            }
+                        })?;
 
                     let fragmented = exts.is_fragmenting_payload();
                     Ok(Ipv6(Ipv6Slice {
@@ -334,8 +363,8 @@ mod test {
     use super::*;
     use crate::test_gens::*;
     use alloc::{format, vec::Vec};
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
     use proptest::prelude::*;
-    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
     #[test]
     fn debug_clone_eq() {
@@ -419,7 +448,6 @@ mod test {
         }
     }
 
-    #[cfg(feature = "std")]
     #[test]
     fn source_addr() {
         // ipv4
@@ -453,7 +481,6 @@ mod test {
         }
     }
 
-    #[cfg(feature = "std")]
     #[test]
     fn destination_addr() {
         use crate::ip_number::UDP;
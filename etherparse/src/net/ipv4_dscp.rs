@@ -1,8 +1,15 @@
 use crate::err::ValueTooBigError;
 
 /// 6 bit unsigned integer containing the "Differentiated Services
-/// Code Point" (present in the [`crate::Ipv4Header`]).
+/// Code Point" (present in the [`crate::Ipv4Header`] & in the traffic
+/// class of the [`crate::Ipv6Header`]).
 #[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u8", into = "u8")
+)]
 pub struct Ipv4Dscp(u8);
 
 impl Ipv4Dscp {
@@ -1,8 +1,15 @@
 use crate::err::ValueTooBigError;
 
 /// 2 bit unsigned integer containing the "Explicit Congestion
-/// Notification" (present in the [`crate::Ipv4Header`]).
+/// Notification" (present in the [`crate::Ipv4Header`] & in the traffic
+/// class of the [`crate::Ipv6Header`]).
 #[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u8", into = "u8")
+)]
 pub struct Ipv4Ecn(u8);
 
 impl Ipv4Ecn {
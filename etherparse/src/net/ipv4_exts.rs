@@ -7,7 +7,8 @@ use crate::{err::ipv4_exts::ExtsWalkError, *};
 ///
 /// Currently not supported:
 /// - Encapsulating Security Payload Header (ESP)
-#[derive(Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Ipv4Extensions {
     pub auth: Option<IpAuthHeader>,
 }
@@ -29,6 +30,18 @@ impl Ipv4Extensions {
         Ipv4ExtensionsSlice::from_slice(start_ip_number, slice).map(|v| (v.0.to_header(), v.1, v.2))
     }
 
+    /// Read all known ipv4 extensions like [`Ipv4Extensions::from_slice`]
+    /// but return the number of bytes consumed from the slice instead of
+    /// the non parsed data.
+    #[inline]
+    pub fn from_slice_with_len(
+        start_ip_number: IpNumber,
+        slice: &[u8],
+    ) -> Result<(Ipv4Extensions, IpNumber, usize), err::ip_auth::HeaderSliceError> {
+        let (exts, next_header, rest) = Ipv4Extensions::from_slice(start_ip_number, slice)?;
+        Ok((exts, next_header, slice.len() - rest.len()))
+    }
+
     /// Collects all known ipv4 extension headers in a slice until an error
     /// is encountered or a "non IP extension header" is found and
     /// returns the successfully parsed parts (+ the unparsed slice
@@ -275,6 +288,24 @@ mod test {
             assert_eq!(rest, &buffer[auth_header.header_len()..]);
         }
 
+        // with len
+        {
+            assert_eq!(
+                Ipv4Extensions::from_slice_with_len(TCP, &buffer).unwrap(),
+                (Ipv4Extensions { auth: None }, TCP, 0)
+            );
+            assert_eq!(
+                Ipv4Extensions::from_slice_with_len(AUTH, &buffer).unwrap(),
+                (
+                    Ipv4Extensions {
+                        auth: Some(auth_header.clone())
+                    },
+                    UDP,
+                    auth_header.header_len()
+                )
+            );
+        }
+
         // too small
         {
             use err::ip_auth::HeaderSliceError::Len;
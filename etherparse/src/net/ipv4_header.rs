@@ -33,6 +33,8 @@ use arrayvec::ArrayVec;
 /// assert_eq!(slice_rest, &[]);
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ipv4Header {
     /// Differentiated Services Code Point
     pub dscp: Ipv4Dscp,
@@ -312,6 +314,9 @@ impl Ipv4Header {
     /// header.set_payload_len(100).unwrap();
     /// assert_eq!(100 + header.header_len() as u16, header.total_len);
     ///
+    /// // payload_len calculates the payload length back from total_len
+    /// assert_eq!(Ok(100), header.payload_len());
+    ///
     /// // in case the payload is len is bigger then can represented in the
     /// // total_len field an error is returned
     /// use etherparse::err::{ValueTooBigError, ValueType};
@@ -339,12 +344,53 @@ impl Ipv4Header {
         }
     }
 
+    /// Returns an iterator that decodes the options of the header.
+    #[inline]
+    pub fn options_iterator(&self) -> Ipv4OptionsIterator<'_> {
+        Ipv4OptionsIterator::from_slice(&self.options[..])
+    }
+
     /// Returns the maximum payload size based on the current options size.
     #[inline]
     pub fn max_payload_len(&self) -> u16 {
         core::u16::MAX - u16::from(self.options.len_u8()) - (Ipv4Header::MIN_LEN as u16)
     }
 
+    /// Return the ipv4 source address as an core::net::Ipv4Addr
+    #[inline]
+    pub fn source_addr(&self) -> core::net::Ipv4Addr {
+        core::net::Ipv4Addr::from(self.source)
+    }
+
+    /// Return the ipv4 destination address as an core::net::Ipv4Addr
+    #[inline]
+    pub fn destination_addr(&self) -> core::net::Ipv4Addr {
+        core::net::Ipv4Addr::from(self.destination)
+    }
+
+    /// Sets the source address from an core::net::Ipv4Addr.
+    ///
+    /// ```
+    /// use core::net::Ipv4Addr;
+    /// use etherparse::Ipv4Header;
+    ///
+    /// let mut header = Ipv4Header::default();
+    /// header.set_source_addr(Ipv4Addr::new(192, 168, 0, 1));
+    /// header.set_destination_addr(Ipv4Addr::LOCALHOST);
+    /// assert_eq!(header.source, [192, 168, 0, 1]);
+    /// assert_eq!(header.destination_addr(), Ipv4Addr::LOCALHOST);
+    /// ```
+    #[inline]
+    pub fn set_source_addr(&mut self, addr: core::net::Ipv4Addr) {
+        self.source = addr.octets();
+    }
+
+    /// Sets the destination address from an core::net::Ipv4Addr.
+    #[inline]
+    pub fn set_destination_addr(&mut self, addr: core::net::Ipv4Addr) {
+        self.destination = addr.octets();
+    }
+
     /// Returns a slice to the options part of the header (empty if no options are present).
     #[deprecated(
         since = "0.14.0",
@@ -397,12 +443,32 @@ impl Ipv4Header {
     ///
     /// for a laxer version which falls back to slice length when the `total_length`
     /// contains an inconsistent value.
+    ///
+    /// The version field is checked before the length of the slice (same as
+    /// in [`Ipv4HeaderSlice::from_slice`]). Data that does not start with an
+    /// IPv4 version number is therefore reported as an
+    /// [`err::ipv4::HeaderError::UnexpectedVersion`] error even if it is shorter
+    /// than an IPv4 header.
     pub fn from_slice(slice: &[u8]) -> Result<(Ipv4Header, &[u8]), err::ipv4::HeaderSliceError> {
         let header = Ipv4HeaderSlice::from_slice(slice)?.to_header();
         let rest = &slice[header.header_len()..];
         Ok((header, rest))
     }
 
+    /// Read an Ipv4Header from a slice and return the header & the number
+    /// of bytes consumed from the slice (the header length including the
+    /// options).
+    ///
+    /// Same as [`Ipv4Header::from_slice`] but returns the consumed length
+    /// instead of the unused part of the slice.
+    #[inline]
+    pub fn from_slice_with_len(
+        slice: &[u8],
+    ) -> Result<(Ipv4Header, usize), err::ipv4::HeaderSliceError> {
+        let (header, rest) = Ipv4Header::from_slice(slice)?;
+        Ok((header, slice.len() - rest.len()))
+    }
+
     /// Reads an IPv4 header from the current position (requires
     /// crate feature `std`).
     #[cfg(feature = "std")]
@@ -525,6 +591,77 @@ impl Ipv4Header {
         self.write_ipv4_header_internal(writer, self.header_checksum)
     }
 
+    /// Serialize the header to a given slice (this method automatically
+    /// calculates the checksum). Returns the unused part of the slice.
+    ///
+    /// Does not allocate & can be used without the `std` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{Ipv4Header, ip_number};
+    ///
+    /// let header = Ipv4Header::new(
+    ///     8, // payload length
+    ///     20, // time to live
+    ///     ip_number::UDP,
+    ///     [192, 168, 1, 1], // source
+    ///     [192, 168, 1, 2], // destination
+    /// ).unwrap();
+    ///
+    /// let mut buffer = [0u8; 28];
+    /// let rest = header.write_to_slice(&mut buffer).unwrap();
+    /// assert_eq!(8, rest.len());
+    /// ```
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], err::SliceWriteSpaceError> {
+        let header_len = self.header_len();
+        if slice.len() < header_len {
+            return Err(err::SliceWriteSpaceError {
+                required_len: header_len,
+                len: slice.len(),
+                layer: err::Layer::Ipv4Header,
+                layer_start_offset: 0,
+            });
+        }
+        slice[..header_len].copy_from_slice(&self.to_bytes());
+        slice[10..12].copy_from_slice(&self.calc_header_checksum().to_be_bytes());
+        Ok(&mut slice[header_len..])
+    }
+
+    /// Returns the serialized header as a statically sized array if the
+    /// header contains no options (otherwise `None` is returned). Note
+    /// that this method does NOT update & calculate the checksum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{Ipv4Header, ip_number};
+    ///
+    /// let mut header = Ipv4Header::new(
+    ///     8, // payload length
+    ///     20, // time to live
+    ///     ip_number::UDP,
+    ///     [192, 168, 1, 1], // source
+    ///     [192, 168, 1, 2], // destination
+    /// ).unwrap();
+    /// assert!(header.to_bytes_fixed().is_some());
+    ///
+    /// header.options = [1, 2, 3, 4].into();
+    /// assert_eq!(None, header.to_bytes_fixed());
+    /// ```
+    pub fn to_bytes_fixed(&self) -> Option<[u8; Ipv4Header::MIN_LEN]> {
+        if self.options.is_empty() {
+            let mut result = [0u8; Ipv4Header::MIN_LEN];
+            result.copy_from_slice(&self.to_bytes());
+            Some(result)
+        } else {
+            None
+        }
+    }
+
     /// Returns the serialized header (note that this method does NOT
     /// update & calculate the checksum).
     pub fn to_bytes(&self) -> ArrayVec<u8, { Ipv4Header::MAX_LEN }> {
@@ -673,6 +810,74 @@ impl Ipv4Header {
             .to_be()
     }
 
+    /// Returns the 12 byte pseudo header used when calculating the
+    /// checksum of an upper layer protocol (e.g. UDP or TCP) over IPv4.
+    ///
+    /// The pseudo header consists of the source address, destination
+    /// address, a zero byte, the `protocol` and the `payload_len` (length
+    /// of the upper layer header & payload). An error is returned if
+    /// `payload_len` does not fit into the 16 bit length field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv4Header};
+    ///
+    /// let header = Ipv4Header::new(0, 64, ip_number::UDP, [10, 0, 0, 1], [10, 0, 0, 2]).unwrap();
+    /// assert_eq!(
+    ///     header.pseudo_header_bytes(ip_number::UDP, 12).unwrap(),
+    ///     [10, 0, 0, 1, 10, 0, 0, 2, 0, 17, 0, 12]
+    /// );
+    /// ```
+    pub fn pseudo_header_bytes(
+        &self,
+        protocol: IpNumber,
+        payload_len: usize,
+    ) -> Result<[u8; 12], ValueTooBigError<usize>> {
+        const MAX_PAYLOAD_LEN: usize = u16::MAX as usize;
+        if MAX_PAYLOAD_LEN < payload_len {
+            return Err(ValueTooBigError {
+                actual: payload_len,
+                max_allowed: MAX_PAYLOAD_LEN,
+                value_type: ValueType::PseudoHeaderPayloadLengthIpv4,
+            });
+        }
+        let len_be = (payload_len as u16).to_be_bytes();
+        Ok([
+            self.source[0],
+            self.source[1],
+            self.source[2],
+            self.source[3],
+            self.destination[0],
+            self.destination[1],
+            self.destination[2],
+            self.destination[3],
+            0,
+            protocol.0,
+            len_be[0],
+            len_be[1],
+        ])
+    }
+
+    /// Returns true if the `header_checksum` field matches the checksum
+    /// calculated over the header fields (including the options).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv4Header};
+    ///
+    /// let mut header = Ipv4Header::new(0, 64, ip_number::UDP, [10, 0, 0, 1], [10, 0, 0, 2]).unwrap();
+    /// assert!(false == header.is_checksum_valid());
+    ///
+    /// header.header_checksum = header.calc_header_checksum();
+    /// assert!(header.is_checksum_valid());
+    /// ```
+    #[inline]
+    pub fn is_checksum_valid(&self) -> bool {
+        self.header_checksum == self.calc_header_checksum()
+    }
+
     /// Returns true if the payload is fragmented.
     ///
     /// Either data is missing (more_fragments set) or there is
@@ -681,6 +886,39 @@ impl Ipv4Header {
     pub fn is_fragmenting_payload(&self) -> bool {
         self.more_fragments || (0 != self.fragment_offset.value())
     }
+
+    /// Compares two headers while skipping the fields selected in
+    /// `ignore` (e.g. to compare a packet before & after it passed a
+    /// router). `PartialEq` still compares all fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{IpIgnoreFields, Ipv4Header, ip_number};
+    ///
+    /// let a = Ipv4Header::new(8, 64, ip_number::UDP, [10, 0, 0, 1], [10, 0, 0, 2]).unwrap();
+    /// let mut b = a.clone();
+    /// b.time_to_live = 63;
+    /// b.header_checksum = b.calc_header_checksum();
+    ///
+    /// assert!(b.equals_ignoring(&a, IpIgnoreFields::TTL | IpIgnoreFields::CHECKSUM));
+    /// assert!(false == b.equals_ignoring(&a, IpIgnoreFields::TTL));
+    /// ```
+    pub fn equals_ignoring(&self, other: &Ipv4Header, ignore: IpIgnoreFields) -> bool {
+        (ignore.contains(IpIgnoreFields::TOS) || (self.dscp == other.dscp && self.ecn == other.ecn))
+            && (ignore.contains(IpIgnoreFields::TTL) || self.time_to_live == other.time_to_live)
+            && (ignore.contains(IpIgnoreFields::CHECKSUM)
+                || self.header_checksum == other.header_checksum)
+            && self.total_len == other.total_len
+            && self.identification == other.identification
+            && self.dont_fragment == other.dont_fragment
+            && self.more_fragments == other.more_fragments
+            && self.fragment_offset == other.fragment_offset
+            && self.protocol == other.protocol
+            && self.source == other.source
+            && self.destination == other.destination
+            && self.options == other.options
+    }
 }
 
 impl Default for Ipv4Header {
@@ -703,6 +941,46 @@ impl Default for Ipv4Header {
     }
 }
 
+impl TryFrom<&[u8]> for Ipv4Header {
+    type Error = err::ipv4::HeaderSliceError;
+
+    /// Decodes the header from the start of the slice via
+    /// [`Ipv4Header::from_slice`] (any data after the header is ignored).
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ipv4Header::from_slice(value).map(|(header, _)| header)
+    }
+}
+
+/// Writes a compact single line summary of the header (addresses in
+/// dotted decimal notation).
+///
+/// ```
+/// use etherparse::{ip_number, Ipv4Header};
+///
+/// let header = Ipv4Header::new(20, 64, ip_number::TCP, [10, 0, 0, 1], [10, 0, 0, 2]).unwrap();
+/// assert_eq!(
+///     "IPv4 10.0.0.1 -> 10.0.0.2 proto=TCP len=40 ttl=64",
+///     format!("{}", header)
+/// );
+/// ```
+impl core::fmt::Display for Ipv4Header {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "IPv4 {} -> {}",
+            core::net::Ipv4Addr::from(self.source),
+            core::net::Ipv4Addr::from(self.destination)
+        )?;
+        if let Some(keyword) = self.protocol.keyword_str() {
+            write!(f, " proto={}", keyword)?;
+        } else {
+            write!(f, " proto={}", self.protocol.0)?;
+        }
+        write!(f, " len={} ttl={}", self.total_len, self.time_to_live)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -710,7 +988,7 @@ mod test {
         test_gens::*,
         *,
     };
-    use alloc::{format, vec::Vec};
+    use alloc::{format, string::ToString, vec::Vec};
     use arrayvec::ArrayVec;
     use proptest::prelude::*;
     use std::io::Cursor;
@@ -757,6 +1035,28 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn display(input in ipv4_any()) {
+            let protocol = if let Some(keyword) = input.protocol.keyword_str() {
+                keyword.to_string()
+            } else {
+                format!("{}", input.protocol.0)
+            };
+            assert_eq!(
+                format!(
+                    "IPv4 {}.{}.{}.{} -> {}.{}.{}.{} proto={} len={} ttl={}",
+                    input.source[0], input.source[1], input.source[2], input.source[3],
+                    input.destination[0], input.destination[1], input.destination[2], input.destination[3],
+                    protocol,
+                    input.total_len,
+                    input.time_to_live
+                ),
+                format!("{}", input)
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn eq(a in ipv4_any(),
@@ -1013,11 +1313,13 @@ mod test {
         //zero check
         assert!(header.set_payload_len(0).is_ok());
         assert_eq!(header.total_len, 24);
+        assert_eq!(header.payload_len(), Ok(0));
 
         //max check
         const MAX: usize = (core::u16::MAX as usize) - Ipv4Header::MIN_LEN - 4;
         assert!(header.set_payload_len(MAX).is_ok());
         assert_eq!(header.total_len, core::u16::MAX);
+        assert_eq!(header.payload_len(), Ok(MAX as u16));
 
         const OVER_MAX: usize = MAX + 1;
         assert_eq!(
@@ -1037,6 +1339,19 @@ mod test {
         }
     }
 
+    #[test]
+    fn options_iterator() {
+        let header = Ipv4Header {
+            options: [1, 148, 4, 0, 0, 0, 0, 0][..].try_into().unwrap(),
+            ..Default::default()
+        };
+        let mut iter = header.options_iterator();
+        assert_eq!(Some(Ok(Ipv4Option::Nop)), iter.next());
+        assert_eq!(Some(Ok(Ipv4Option::RouterAlert(0))), iter.next());
+        assert_eq!(Some(Ok(Ipv4Option::EndOfList)), iter.next());
+        assert_eq!(None, iter.next());
+    }
+
     #[test]
     #[allow(deprecated)]
     fn set_options() {
@@ -1129,6 +1444,12 @@ mod test {
                 let (actual_header, actual_rest) = Ipv4Header::from_slice(&buffer).unwrap();
                 assert_eq!(actual_header, header);
                 assert_eq!(actual_rest, &[1]);
+
+                // with len
+                assert_eq!(
+                    Ipv4Header::from_slice_with_len(&buffer).unwrap(),
+                    (header.clone(), header.header_len())
+                );
             }
 
             // unexpected end of slice
@@ -1184,6 +1505,30 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn try_from(header in ipv4_any()) {
+            // ok
+            {
+                let mut buffer = ArrayVec::<u8, { Ipv4Header::MAX_LEN + 1 }>::new();
+                buffer.try_extend_from_slice(&header.to_bytes()).unwrap();
+                buffer.try_extend_from_slice(&[1]).unwrap();
+                assert_eq!(Ipv4Header::try_from(&buffer[..]), Ok(header.clone()));
+            }
+
+            // error
+            {
+                let buffer = header.to_bytes();
+                let len = header.header_len() - 1;
+                assert_eq!(
+                    Ipv4Header::try_from(&buffer[..len]),
+                    Ipv4Header::from_slice(&buffer[..len]).map(|(h, _)| h)
+                );
+                assert!(Ipv4Header::try_from(&buffer[..len]).is_err());
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn read_and_read_without_version(header in ipv4_any()) {
@@ -1360,6 +1705,46 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn write_to_slice(base_header in ipv4_any()) {
+            let header = {
+                let mut header = base_header.clone();
+                // set the header checksum to something else to
+                // ensure it is calculated during the write call
+                header.header_checksum = 0;
+                header
+            };
+
+            // normal write
+            {
+                let mut buffer = [0u8; Ipv4Header::MAX_LEN + 1];
+                let rest_len = header.write_to_slice(&mut buffer).unwrap().len();
+                assert_eq!(buffer.len() - header.header_len(), rest_len);
+                let mut expected = header.clone();
+                expected.header_checksum = header.calc_header_checksum();
+                assert_eq!(
+                    expected,
+                    Ipv4HeaderSlice::from_slice(&buffer).unwrap().to_header()
+                );
+            }
+
+            // len too small
+            for len in 0..header.header_len() {
+                let mut buffer = [0u8; Ipv4Header::MAX_LEN];
+                assert_eq!(
+                    err::SliceWriteSpaceError {
+                        required_len: header.header_len(),
+                        len,
+                        layer: err::Layer::Ipv4Header,
+                        layer_start_offset: 0,
+                    },
+                    header.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn to_bytes(base_header in ipv4_any()) {
@@ -1371,6 +1756,28 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn to_bytes_fixed(base_header in ipv4_any()) {
+            if base_header.options.is_empty() {
+                assert_eq!(
+                    &base_header.to_bytes()[..],
+                    &base_header.to_bytes_fixed().unwrap()[..]
+                );
+            } else {
+                assert_eq!(None, base_header.to_bytes_fixed());
+            }
+            let header = Ipv4Header {
+                options: Ipv4Options::new(),
+                ..base_header
+            };
+            assert_eq!(
+                &header.to_bytes()[..],
+                &header.to_bytes_fixed().unwrap()[..]
+            );
+        }
+    }
+
     #[test]
     fn calc_header_checksum() {
         let base: Ipv4Header = Ipv4Header::new(
@@ -1408,6 +1815,90 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn pseudo_header_bytes(
+            header in ipv4_any(),
+            protocol in ip_number_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..64)
+        ) {
+            // ok
+            {
+                let len = UdpHeader::LEN + payload.len();
+                let actual = header.pseudo_header_bytes(protocol, len).unwrap();
+                let len_be = (len as u16).to_be_bytes();
+                assert_eq!(&actual[..4], &header.source);
+                assert_eq!(&actual[4..8], &header.destination);
+                assert_eq!(&actual[8..], &[0, protocol.0, len_be[0], len_be[1]]);
+            }
+
+            // matches the udp checksum calculation
+            {
+                let udp = UdpHeader {
+                    source_port: 1234,
+                    destination_port: 5678,
+                    length: (UdpHeader::LEN + payload.len()) as u16,
+                    checksum: 0,
+                };
+                let pseudo_header = header
+                    .pseudo_header_bytes(ip_number::UDP, UdpHeader::LEN + payload.len())
+                    .unwrap();
+                let expected = checksum::Sum16BitWords::new()
+                    .add_slice(&pseudo_header)
+                    .add_slice(&udp.to_bytes())
+                    .add_slice(&payload)
+                    .to_ones_complement_with_no_zero()
+                    .to_be();
+                assert_eq!(expected, udp.calc_checksum_ipv4(&header, &payload).unwrap());
+            }
+
+            // max len
+            assert!(header.pseudo_header_bytes(protocol, u16::MAX as usize).is_ok());
+
+            // len too big
+            assert_eq!(
+                header.pseudo_header_bytes(protocol, u16::MAX as usize + 1).unwrap_err(),
+                ValueTooBigError {
+                    actual: u16::MAX as usize + 1,
+                    max_allowed: u16::MAX as usize,
+                    value_type: ValueType::PseudoHeaderPayloadLengthIpv4,
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn addrs(
+            header in ipv4_any(),
+            source in any::<[u8;4]>(),
+            destination in any::<[u8;4]>()
+        ) {
+            use core::net::Ipv4Addr;
+
+            assert_eq!(header.source_addr(), Ipv4Addr::from(header.source));
+            assert_eq!(header.destination_addr(), Ipv4Addr::from(header.destination));
+
+            let mut header = header.clone();
+            header.set_source_addr(Ipv4Addr::from(source));
+            header.set_destination_addr(Ipv4Addr::from(destination));
+            assert_eq!(header.source, source);
+            assert_eq!(header.destination, destination);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn is_checksum_valid(header in ipv4_any()) {
+            let mut header = header.clone();
+            header.header_checksum = header.calc_header_checksum();
+            assert!(header.is_checksum_valid());
+
+            header.header_checksum = !header.header_checksum;
+            assert!(false == header.is_checksum_valid());
+        }
+    }
+
     #[test]
     fn is_fragmenting_payload() {
         // not fragmenting
@@ -1434,4 +1925,141 @@ mod test {
             assert!(header.is_fragmenting_payload());
         }
     }
+
+    proptest! {
+        #[test]
+        fn equals_ignoring(header in ipv4_any()) {
+            use IpIgnoreFields as I;
+
+            // identical headers
+            assert!(header.equals_ignoring(&header, I::NONE));
+            assert!(header.equals_ignoring(&header, I::ALL));
+
+            // ttl
+            {
+                let mut other = header.clone();
+                other.time_to_live = other.time_to_live.wrapping_add(1);
+                assert!(false == header.equals_ignoring(&other, I::NONE));
+                assert!(false == header.equals_ignoring(&other, I::CHECKSUM | I::TOS));
+                assert!(header.equals_ignoring(&other, I::TTL));
+            }
+            // checksum
+            {
+                let mut other = header.clone();
+                other.header_checksum = other.header_checksum.wrapping_add(1);
+                assert!(false == header.equals_ignoring(&other, I::NONE));
+                assert!(false == header.equals_ignoring(&other, I::TTL | I::TOS));
+                assert!(header.equals_ignoring(&other, I::CHECKSUM));
+            }
+            // dscp & ecn
+            {
+                let mut other = header.clone();
+                other.dscp = Ipv4Dscp::try_new((other.dscp.value() + 1) & Ipv4Dscp::MAX_U8).unwrap();
+                assert!(false == header.equals_ignoring(&other, I::TTL | I::CHECKSUM));
+                assert!(header.equals_ignoring(&other, I::TOS));
+
+                let mut other = header.clone();
+                other.ecn = Ipv4Ecn::try_new((other.ecn.value() + 1) & Ipv4Ecn::MAX_U8).unwrap();
+                assert!(false == header.equals_ignoring(&other, I::TTL | I::CHECKSUM));
+                assert!(header.equals_ignoring(&other, I::TOS));
+            }
+            // other fields are always compared
+            {
+                let mut other = header.clone();
+                other.identification = other.identification.wrapping_add(1);
+                assert!(false == header.equals_ignoring(&other, I::ALL));
+
+                let mut other = header.clone();
+                other.source[0] = other.source[0].wrapping_add(1);
+                assert!(false == header.equals_ignoring(&other, I::ALL));
+
+                let mut other = header.clone();
+                other.protocol = IpNumber(other.protocol.0.wrapping_add(1));
+                assert!(false == header.equals_ignoring(&other, I::ALL));
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_tokens, Token};
+        let header = Ipv4Header {
+            dscp: Ipv4Dscp::try_new(1).unwrap(),
+            ecn: Ipv4Ecn::try_new(2).unwrap(),
+            total_len: 3,
+            identification: 4,
+            dont_fragment: true,
+            more_fragments: false,
+            fragment_offset: IpFragOffset::try_new(5).unwrap(),
+            time_to_live: 6,
+            protocol: IpNumber::UDP,
+            header_checksum: 7,
+            source: [8, 9, 10, 11],
+            destination: [12, 13, 14, 15],
+            options: [16, 17, 18, 19].into(),
+        };
+        assert_tokens(
+            &header,
+            &[
+                Token::Struct {
+                    name: "Ipv4Header",
+                    len: 13,
+                },
+                Token::Str("dscp"),
+                Token::U8(1),
+                Token::Str("ecn"),
+                Token::U8(2),
+                Token::Str("total_len"),
+                Token::U16(3),
+                Token::Str("identification"),
+                Token::U16(4),
+                Token::Str("dont_fragment"),
+                Token::Bool(true),
+                Token::Str("more_fragments"),
+                Token::Bool(false),
+                Token::Str("fragment_offset"),
+                Token::U16(5),
+                Token::Str("time_to_live"),
+                Token::U8(6),
+                Token::Str("protocol"),
+                Token::U8(17),
+                Token::Str("header_checksum"),
+                Token::U16(7),
+                Token::Str("source"),
+                Token::Tuple { len: 4 },
+                Token::U8(8),
+                Token::U8(9),
+                Token::U8(10),
+                Token::U8(11),
+                Token::TupleEnd,
+                Token::Str("destination"),
+                Token::Tuple { len: 4 },
+                Token::U8(12),
+                Token::U8(13),
+                Token::U8(14),
+                Token::U8(15),
+                Token::TupleEnd,
+                Token::Str("options"),
+                Token::Bytes(&[16, 17, 18, 19]),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn from_slice_version_before_len() {
+        use err::ipv4::{HeaderError::UnexpectedVersion, HeaderSliceError::Content};
+        // IPv6 version (e.g. a mixed up buffer) shorter than an IPv4 header
+        for data in [&[0x60u8][..], &[0x60, 0, 0, 0][..]] {
+            assert_eq!(
+                Ipv4Header::from_slice(data).unwrap_err(),
+                Content(UnexpectedVersion { version_number: 6 })
+            );
+            assert_eq!(
+                Ipv4Header::from_slice_with_len(data).unwrap_err(),
+                Content(UnexpectedVersion { version_number: 6 })
+            );
+        }
+    }
 }
@@ -1,6 +1,5 @@
+use core::net::Ipv4Addr;
 use core::slice::from_raw_parts;
-#[cfg(feature = "std")]
-use std::net::Ipv4Addr;
 
 use crate::*;
 
@@ -26,10 +25,26 @@ impl<'a> Ipv4HeaderSlice<'a> {
     ///
     /// for a laxer version which falls back to slice length only when the total_length
     /// field in the header is inconsistent.
+    ///
+    /// The version field is checked before the length of the slice. Data that
+    /// does not start with an IPv4 version number (e.g. because it was
+    /// byte-swapped) is therefore reported as an
+    /// [`err::ipv4::HeaderError::UnexpectedVersion`] error even if it is shorter
+    /// than an IPv4 header.
     pub fn from_slice(slice: &'a [u8]) -> Result<Ipv4HeaderSlice<'a>, err::ipv4::HeaderSliceError> {
         use err::ipv4::HeaderError::*;
         use err::ipv4::HeaderSliceError::*;
 
+        // check version (done before the length check so that data that
+        // is not an IPv4 header at all, e.g. because of a wrong byte order,
+        // is not reported as a truncated IPv4 header)
+        if let Some(first) = slice.first() {
+            let version_number = first >> 4;
+            if 4 != version_number {
+                return Err(Content(UnexpectedVersion { version_number }));
+            }
+        }
+
         // check length
         if slice.len() < Ipv4Header::MIN_LEN {
             return Err(Len(err::LenError {
@@ -41,16 +56,8 @@ impl<'a> Ipv4HeaderSlice<'a> {
             }));
         }
 
-        // read version & ihl
-        let (version_number, ihl) = unsafe {
-            let value = slice.get_unchecked(0);
-            (value >> 4, value & 0xf)
-        };
-
-        // check version
-        if 4 != version_number {
-            return Err(Content(UnexpectedVersion { version_number }));
-        }
+        // read ihl
+        let ihl = unsafe { slice.get_unchecked(0) & 0xf };
 
         // check that the ihl is correct
         if ihl < 5 {
@@ -119,8 +126,15 @@ impl<'a> Ipv4HeaderSlice<'a> {
     }
 
     /// Read the "differentiated_services_code_point" from the slice.
+    #[deprecated(since = "0.15.0", note = "Use `Ipv4HeaderSlice::dscp` instead")]
     #[inline]
     pub fn dcp(&self) -> Ipv4Dscp {
+        self.dscp()
+    }
+
+    /// Read the "differentiated_services_code_point" from the slice.
+    #[inline]
+    pub fn dscp(&self) -> Ipv4Dscp {
         // SAFETY:
         // get_unchecked: Safe as the slice length is checked to be at least
         // Ipv4Header::MIN_LEN (20) in the constructor.
@@ -288,9 +302,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
         unsafe { get_unchecked_4_byte_array(self.slice.as_ptr().add(12)) }
     }
 
-    /// Return the ipv4 source address as an std::net::Ipv4Addr
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Return the ipv4 source address as an core::net::Ipv4Addr
     #[inline]
     pub fn source_addr(&self) -> Ipv4Addr {
         Ipv4Addr::from(self.source())
@@ -305,9 +317,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
         unsafe { get_unchecked_4_byte_array(self.slice.as_ptr().add(16)) }
     }
 
-    /// Return the ipv4 destination address as an std::net::Ipv4Addr
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Return the ipv4 destination address as an core::net::Ipv4Addr
     #[inline]
     pub fn destination_addr(&self) -> Ipv4Addr {
         Ipv4Addr::from(self.destination())
@@ -322,6 +332,38 @@ impl<'a> Ipv4HeaderSlice<'a> {
         unsafe { from_raw_parts(self.slice.as_ptr().add(20), self.slice.len() - 20) }
     }
 
+    /// Returns an iterator that decodes the ipv4 header options.
+    #[inline]
+    pub fn options_iterator(&self) -> Ipv4OptionsIterator<'a> {
+        Ipv4OptionsIterator::from_slice(self.options())
+    }
+
+    /// Returns true if the "header checksum" field matches the checksum
+    /// calculated over the raw header bytes (including the options).
+    #[inline]
+    pub fn is_checksum_valid(&self) -> bool {
+        self.header_checksum() == self.calc_header_checksum()
+    }
+
+    /// Calculates the checksum over the raw header bytes (skipping
+    /// the "header checksum" field).
+    fn calc_header_checksum(&self) -> u16 {
+        // SAFETY:
+        // Safe as the slice length is checked to be at least
+        // Ipv4Header::MIN_LEN (20) in the constructor.
+        let (before, after) = unsafe {
+            (
+                from_raw_parts(self.slice.as_ptr(), 10),
+                from_raw_parts(self.slice.as_ptr().add(12), self.slice.len() - 12),
+            )
+        };
+        checksum::Sum16BitWords::new()
+            .add_slice(before)
+            .add_slice(after)
+            .ones_complement()
+            .to_be()
+    }
+
     /// Returns true if the payload is fragmented.
     ///
     /// Either data is missing (more_fragments set) or there is
@@ -335,7 +377,7 @@ impl<'a> Ipv4HeaderSlice<'a> {
     #[inline]
     pub fn to_header(&self) -> Ipv4Header {
         Ipv4Header {
-            dscp: self.dcp(),
+            dscp: self.dscp(),
             ecn: self.ecn(),
             total_len: self.total_len(),
             identification: self.identification(),
@@ -439,6 +481,15 @@ mod test {
                             version_number,
                         })
                     );
+                    // version errors take precedence over length errors
+                    for len in 1..buffer.len() {
+                        assert_eq!(
+                            Ipv4HeaderSlice::from_slice(&buffer[..len]).unwrap_err(),
+                            Content(UnexpectedVersion{
+                                version_number,
+                            })
+                        );
+                    }
                 }
             }
 
@@ -474,7 +525,11 @@ mod test {
             assert_eq!(slice.slice(), &buffer[..]);
             assert_eq!(slice.version(), 4);
             assert_eq!(slice.ihl(), header.ihl());
-            assert_eq!(slice.dcp(), header.dscp);
+            assert_eq!(slice.dscp(), header.dscp);
+            #[allow(deprecated)]
+            {
+                assert_eq!(slice.dcp(), header.dscp);
+            }
             assert_eq!(slice.ecn(), header.ecn);
             assert_eq!(slice.total_len(), header.total_len);
             assert_eq!(slice.payload_len(), header.payload_len());
@@ -491,11 +546,10 @@ mod test {
         }
     }
 
-    #[cfg(feature = "std")]
     proptest! {
         #[test]
         fn getters_std(header in ipv4_any()) {
-            use std::net::Ipv4Addr;
+            use core::net::Ipv4Addr;
 
             let buffer = header.to_bytes();
             let slice = Ipv4HeaderSlice::from_slice(&buffer).unwrap();
@@ -505,6 +559,40 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn is_checksum_valid(header in ipv4_any()) {
+            // valid checksum
+            let mut buffer = {
+                let mut buffer = Vec::with_capacity(header.header_len());
+                header.write(&mut buffer).unwrap();
+                buffer
+            };
+            assert!(Ipv4HeaderSlice::from_slice(&buffer).unwrap().is_checksum_valid());
+
+            // modified checksum
+            buffer[10] = !buffer[10];
+            assert!(false == Ipv4HeaderSlice::from_slice(&buffer).unwrap().is_checksum_valid());
+            buffer[10] = !buffer[10];
+
+            // modified data
+            buffer[8] = buffer[8].wrapping_add(1);
+            assert!(false == Ipv4HeaderSlice::from_slice(&buffer).unwrap().is_checksum_valid());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn options_iterator(header in ipv4_any()) {
+            let buffer = header.to_bytes();
+            let slice = Ipv4HeaderSlice::from_slice(&buffer).unwrap();
+            assert_eq!(
+                slice.options_iterator(),
+                Ipv4OptionsIterator::from_slice(&header.options[..])
+            );
+        }
+    }
+
     #[test]
     fn is_fragmenting_payload() {
         // not fragmenting
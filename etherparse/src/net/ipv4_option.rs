@@ -0,0 +1,141 @@
+/// Decoded IPv4 header option (see [`crate::Ipv4OptionsIterator`]).
+///
+/// The option type byte consists of the "copied" flag (highest bit),
+/// the option class (2 bits) & the option number (5 bits).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Ipv4Option<'a> {
+    /// "End of Option List" option (single byte without a length).
+    EndOfList,
+    /// "No Operation" option (single byte without a length).
+    Nop,
+    /// "Record Route" option.
+    RecordRoute {
+        /// Pointer to the next free route data entry (relative
+        /// to the start of the option).
+        pointer: u8,
+        /// Route data (recorded IPv4 addresses).
+        route_data: &'a [u8],
+    },
+    /// "Internet Timestamp" option.
+    Timestamp {
+        /// Pointer to the next free timestamp entry (relative
+        /// to the start of the option).
+        pointer: u8,
+        /// Number of modules that could not register a timestamp (4 bits).
+        overflow: u8,
+        /// Flags specifying the content of the timestamp data (4 bits).
+        flags: u8,
+        /// Timestamp data (timestamps & optionally IPv4 addresses).
+        data: &'a [u8],
+    },
+    /// "Router Alert" option containing the 2 byte value.
+    RouterAlert(u16),
+    /// "Stream Identifier" option containing the 2 byte stream id.
+    StreamId(u16),
+    /// Option not decoded by etherparse.
+    Unknown {
+        /// Set if the option has to be copied into all fragments.
+        copied: bool,
+        /// Option class (2 bits).
+        class: u8,
+        /// Option number (5 bits).
+        number: u8,
+        /// Option data after the type & length bytes.
+        data: &'a [u8],
+    },
+}
+
+impl<'a> Ipv4Option<'a> {
+    /// Option type of the "End of Option List" option.
+    pub const TYPE_END_OF_LIST: u8 = 0;
+
+    /// Option type of the "No Operation" option.
+    pub const TYPE_NOP: u8 = 1;
+
+    /// Option type of the "Record Route" option.
+    pub const TYPE_RECORD_ROUTE: u8 = 7;
+
+    /// Option type of the "Internet Timestamp" option.
+    pub const TYPE_TIMESTAMP: u8 = 68;
+
+    /// Option type of the "Stream Identifier" option.
+    pub const TYPE_STREAM_ID: u8 = 136;
+
+    /// Option type of the "Router Alert" option.
+    pub const TYPE_ROUTER_ALERT: u8 = 148;
+
+    /// Returns the option type byte of the option.
+    pub fn option_type(&self) -> u8 {
+        use Ipv4Option::*;
+        match self {
+            EndOfList => Self::TYPE_END_OF_LIST,
+            Nop => Self::TYPE_NOP,
+            RecordRoute { .. } => Self::TYPE_RECORD_ROUTE,
+            Timestamp { .. } => Self::TYPE_TIMESTAMP,
+            RouterAlert(_) => Self::TYPE_ROUTER_ALERT,
+            StreamId(_) => Self::TYPE_STREAM_ID,
+            Unknown {
+                copied,
+                class,
+                number,
+                data: _,
+            } => (u8::from(*copied) << 7) | ((class & 0b11) << 5) | (number & 0b1_1111),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = Ipv4Option::RecordRoute {
+            pointer: 4,
+            route_data: &[1, 2, 3, 4],
+        };
+        assert_eq!(value.clone(), value);
+        assert_eq!(
+            format!("{:?}", value),
+            "RecordRoute { pointer: 4, route_data: [1, 2, 3, 4] }"
+        );
+    }
+
+    #[test]
+    fn option_type() {
+        use Ipv4Option::*;
+        assert_eq!(0, EndOfList.option_type());
+        assert_eq!(1, Nop.option_type());
+        assert_eq!(
+            7,
+            RecordRoute {
+                pointer: 4,
+                route_data: &[]
+            }
+            .option_type()
+        );
+        assert_eq!(
+            68,
+            Timestamp {
+                pointer: 5,
+                overflow: 0,
+                flags: 0,
+                data: &[]
+            }
+            .option_type()
+        );
+        assert_eq!(148, RouterAlert(0).option_type());
+        assert_eq!(136, StreamId(0).option_type());
+        assert_eq!(
+            0b1011_0101,
+            Unknown {
+                copied: true,
+                class: 1,
+                number: 0b1_0101,
+                data: &[]
+            }
+            .option_type()
+        );
+    }
+}
@@ -0,0 +1,92 @@
+/// Errors that can occur while decoding IPv4 header options
+/// (see [`crate::Ipv4OptionsIterator`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Ipv4OptionReadError {
+    /// The length of the option (or the length byte itself) runs past
+    /// the end of the options area.
+    UnexpectedEndOfSlice {
+        option_type: u8,
+        expected_len: usize,
+        actual_len: usize,
+    },
+
+    /// The length byte of the option has a value that is not allowed for
+    /// the option type (e.g. smaller than 2 or != 4 for a router alert).
+    UnexpectedSize { option_type: u8, size: u8 },
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Ipv4OptionReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+impl core::fmt::Display for Ipv4OptionReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use Ipv4OptionReadError::*;
+        match self {
+            UnexpectedEndOfSlice {
+                option_type,
+                expected_len,
+                actual_len,
+            } => write!(f, "Ipv4OptionReadError: Not enough data left in the IPv4 options to read option of type {} (expected at least {} bytes, only {} bytes available).", option_type, expected_len, actual_len),
+            UnexpectedSize { option_type, size } => write!(f, "Ipv4OptionReadError: Length value of the IPv4 option of type {} had unexpected value {}.", option_type, size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Ipv4OptionReadError::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = UnexpectedSize {
+            option_type: 1,
+            size: 2,
+        };
+        assert_eq!(value.clone(), value);
+        assert_eq!(
+            "UnexpectedSize { option_type: 1, size: 2 }",
+            format!("{:?}", value)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    proptest! {
+        #[test]
+        fn source(
+            arg_u8_0 in any::<u8>(),
+            arg_u8_1 in any::<u8>(),
+            arg_usize in any::<usize>()
+        ) {
+            use std::error::Error;
+            assert!(UnexpectedEndOfSlice{ option_type: arg_u8_0, expected_len: arg_usize, actual_len: arg_usize }.source().is_none());
+            assert!(UnexpectedSize{ option_type: arg_u8_0, size: arg_u8_1 }.source().is_none());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn fmt(
+            arg_u8_0 in any::<u8>(),
+            arg_u8_1 in any::<u8>(),
+            arg_usize_0 in any::<usize>(),
+            arg_usize_1 in any::<usize>()
+        ) {
+            assert_eq!(
+                &format!("Ipv4OptionReadError: Not enough data left in the IPv4 options to read option of type {} (expected at least {} bytes, only {} bytes available).", arg_u8_0, arg_usize_0, arg_usize_1),
+                &format!("{}", UnexpectedEndOfSlice{ option_type: arg_u8_0, expected_len: arg_usize_0, actual_len: arg_usize_1 })
+            );
+            assert_eq!(
+                &format!("Ipv4OptionReadError: Length value of the IPv4 option of type {} had unexpected value {}.", arg_u8_0, arg_u8_1),
+                &format!("{}", UnexpectedSize{ option_type: arg_u8_0, size: arg_u8_1 })
+            );
+        }
+    }
+}
@@ -82,6 +82,129 @@ impl Ipv4Options {
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
+
+    /// Serializes the given options & pads the result with
+    /// "End of Option List" bytes (0) to a multiple of 4 bytes.
+    ///
+    /// If the padded options are longer than 40 bytes a
+    /// [`crate::err::ipv4::BadOptionsLen`] error containing the padded
+    /// length is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{Ipv4Option, Ipv4Options, Ipv4OptionsIterator};
+    ///
+    /// let options = Ipv4Options::try_from_options(&[Ipv4Option::RouterAlert(0)]).unwrap();
+    /// assert_eq!(options.as_slice(), &[148, 4, 0, 0]);
+    /// assert_eq!(
+    ///     Ipv4OptionsIterator::from_slice(&options).collect::<Vec<_>>(),
+    ///     vec![Ok(Ipv4Option::RouterAlert(0))]
+    /// );
+    /// ```
+    pub fn try_from_options(
+        options: &[crate::Ipv4Option],
+    ) -> Result<Ipv4Options, crate::err::ipv4::BadOptionsLen> {
+        use crate::Ipv4Option::*;
+
+        // determine the length of the serialized options
+        let unpadded_len: usize = options
+            .iter()
+            .map(|option| match option {
+                EndOfList | Nop => 1,
+                RecordRoute { route_data, .. } => 3 + route_data.len(),
+                Timestamp { data, .. } => 4 + data.len(),
+                RouterAlert(_) | StreamId(_) => 4,
+                Unknown { data, .. } => 2 + data.len(),
+            })
+            .sum();
+        let len = unpadded_len.div_ceil(4) * 4;
+        if len > usize::from(Self::MAX_LEN) {
+            return Err(crate::err::ipv4::BadOptionsLen { bad_len: len });
+        }
+
+        // serialize (all length fields fit into an u8 as the total
+        // length was checked to be at most 40 bytes)
+        let mut result = Ipv4Options {
+            len: len as u8,
+            buf: [0; 40],
+        };
+        let mut offset = 0;
+        let mut push = |bytes: &[u8]| {
+            result.buf[offset..offset + bytes.len()].copy_from_slice(bytes);
+            offset += bytes.len();
+        };
+        for option in options {
+            let option_type = option.option_type();
+            match option {
+                EndOfList | Nop => push(&[option_type]),
+                RecordRoute {
+                    pointer,
+                    route_data,
+                } => {
+                    push(&[option_type, (3 + route_data.len()) as u8, *pointer]);
+                    push(route_data);
+                }
+                Timestamp {
+                    pointer,
+                    overflow,
+                    flags,
+                    data,
+                } => {
+                    push(&[
+                        option_type,
+                        (4 + data.len()) as u8,
+                        *pointer,
+                        (overflow << 4) | (flags & 0b1111),
+                    ]);
+                    push(data);
+                }
+                RouterAlert(value) | StreamId(value) => {
+                    let value = value.to_be_bytes();
+                    push(&[option_type, 4, value[0], value[1]]);
+                }
+                Unknown { data, .. } => {
+                    push(&[option_type, (2 + data.len()) as u8]);
+                    push(data);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns the options that have to be copied into every fragment
+    /// (options with the "copied" flag set, see RFC 791).
+    ///
+    /// "No Operation" & "End of Option List" options are dropped and the
+    /// result is padded with "End of Option List" bytes to a multiple of
+    /// 4 bytes. Decoding stops at the first malformed option (the option
+    /// & all following data are dropped).
+    #[cfg(feature = "std")]
+    pub(crate) fn copied_options(&self) -> Ipv4Options {
+        let mut result = Ipv4Options::new();
+        let mut len = 0;
+        let mut rest = self.as_slice();
+        while let [option_type, ..] = rest {
+            match *option_type {
+                crate::Ipv4Option::TYPE_END_OF_LIST => break,
+                crate::Ipv4Option::TYPE_NOP => rest = &rest[1..],
+                option_type => {
+                    let option_len = match rest.get(1) {
+                        Some(l) if 2 <= *l && usize::from(*l) <= rest.len() => usize::from(*l),
+                        _ => break,
+                    };
+                    if 0 != option_type & 0b1000_0000 {
+                        result.buf[len..len + option_len].copy_from_slice(&rest[..option_len]);
+                        len += option_len;
+                    }
+                    rest = &rest[option_len..];
+                }
+            }
+        }
+        // pad to a multiple of 4 bytes (the buffer is already zeroed)
+        result.len = (len.div_ceil(4) * 4) as u8;
+        result
+    }
 }
 
 impl TryFrom<&[u8]> for Ipv4Options {
@@ -110,6 +233,23 @@ impl TryFrom<&[u8]> for Ipv4Options {
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for Ipv4Options {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.as_slice())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Ipv4Options {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (buf, len) = deserializer.deserialize_bytes(crate::OptionsBufVisitor)?;
+        Ipv4Options::try_from(&buf[..len]).map_err(serde::de::Error::custom)
+    }
+}
+
 impl Default for Ipv4Options {
     #[inline]
     fn default() -> Self {
@@ -126,6 +266,13 @@ impl core::fmt::Debug for Ipv4Options {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for Ipv4Options {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=[u8]}", self.as_slice())
+    }
+}
+
 impl PartialEq for Ipv4Options {
     fn eq(&self, other: &Self) -> bool {
         self.as_slice() == other.as_slice()
@@ -306,6 +453,152 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn copied_options() {
+        // empty
+        assert_eq!(Ipv4Options::new().copied_options(), Ipv4Options::new());
+
+        // only options with the copied flag are kept (nop & end are dropped)
+        {
+            let options = Ipv4Options::from([
+                1, // nop
+                148, 4, 0, 0, // router alert (copied)
+                7, 7, 4, 1, 2, 3, 4, // record route (not copied)
+                137, 3, 9, // loose source route (copied)
+                0, 0, 0, 0, 0, // end of option list
+            ]);
+            assert_eq!(
+                options.copied_options(),
+                Ipv4Options::from([148, 4, 0, 0, 137, 3, 9, 0])
+            );
+        }
+
+        // no copied options
+        assert_eq!(
+            Ipv4Options::from([7, 3, 4, 0]).copied_options(),
+            Ipv4Options::new()
+        );
+
+        // decoding stops at malformed options
+        assert_eq!(
+            Ipv4Options::from([148, 4, 0, 0, 137, 1, 0, 0]).copied_options(),
+            Ipv4Options::from([148, 4, 0, 0])
+        );
+        assert_eq!(
+            Ipv4Options::from([148, 4, 0, 0, 137, 9, 0, 0]).copied_options(),
+            Ipv4Options::from([148, 4, 0, 0])
+        );
+    }
+
+    #[test]
+    fn try_from_options() {
+        use crate::{err::ipv4::BadOptionsLen, Ipv4Option::*, Ipv4OptionsIterator};
+        use alloc::vec::Vec;
+
+        // empty
+        assert_eq!(Ipv4Options::try_from_options(&[]), Ok(Ipv4Options::new()));
+
+        // all option types (round trip via the iterator)
+        {
+            let options = [
+                Nop,
+                RouterAlert(0x1234),
+                StreamId(0x5678),
+                RecordRoute {
+                    pointer: 4,
+                    route_data: &[1, 2, 3, 4],
+                },
+                Timestamp {
+                    pointer: 5,
+                    overflow: 0b1010,
+                    flags: 0b0001,
+                    data: &[5, 6, 7, 8],
+                },
+                Unknown {
+                    copied: true,
+                    class: 1,
+                    number: 3,
+                    data: &[9],
+                },
+            ];
+            let actual = Ipv4Options::try_from_options(&options).unwrap();
+            assert_eq!(
+                actual.as_slice(),
+                &[
+                    1, // nop
+                    148,
+                    4,
+                    0x12,
+                    0x34, // router alert
+                    136,
+                    4,
+                    0x56,
+                    0x78, // stream id
+                    7,
+                    7,
+                    4,
+                    1,
+                    2,
+                    3,
+                    4, // record route
+                    68,
+                    8,
+                    5,
+                    0b1010_0001,
+                    5,
+                    6,
+                    7,
+                    8, // timestamp
+                    0b1010_0011,
+                    3,
+                    9, // unknown
+                    0, // padding
+                ]
+            );
+            let mut expected: Vec<_> = options.iter().cloned().map(Ok).collect();
+            expected.push(Ok(EndOfList));
+            assert_eq!(
+                expected,
+                Ipv4OptionsIterator::from_slice(&actual).collect::<Vec<_>>()
+            );
+        }
+
+        // maximum length
+        {
+            let data = [0u8; 37];
+            let actual = Ipv4Options::try_from_options(&[RecordRoute {
+                pointer: 4,
+                route_data: &data,
+            }])
+            .unwrap();
+            assert_eq!(actual.len(), 40);
+            assert_eq!(actual[1], 40);
+        }
+
+        // too long
+        {
+            let data = [0u8; 38];
+            assert_eq!(
+                Ipv4Options::try_from_options(&[RecordRoute {
+                    pointer: 4,
+                    route_data: &data,
+                }]),
+                Err(BadOptionsLen { bad_len: 44 })
+            );
+            let data = [0u8; 300];
+            assert_eq!(
+                Ipv4Options::try_from_options(&[Unknown {
+                    copied: false,
+                    class: 0,
+                    number: 30,
+                    data: &data,
+                }]),
+                Err(BadOptionsLen { bad_len: 304 })
+            );
+        }
+    }
+
     #[test]
     fn default() {
         let actual: Ipv4Options = Default::default();
@@ -490,4 +783,36 @@ mod tests {
         let s: &mut [u8] = &mut options;
         assert_eq!(s, &[1, 2, 3, 4]);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+        let options = Ipv4Options::try_from(&[1, 2, 3, 4][..]).unwrap();
+        assert_tokens(&options, &[Token::Bytes(&[1, 2, 3, 4])]);
+
+        // sequence of bytes
+        assert_de_tokens(
+            &options,
+            &[
+                Token::Seq { len: Some(4) },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+                Token::U8(4),
+                Token::SeqEnd,
+            ],
+        );
+
+        // bad length
+        assert_de_tokens_error::<Ipv4Options>(
+            &[Token::Bytes(&[1, 2, 3])],
+            &format!("{}", crate::err::ipv4::BadOptionsLen { bad_len: 3 }),
+        );
+        assert_de_tokens_error::<Ipv4Options>(
+            &[Token::Bytes(&[0; 41])],
+            "invalid length 41, expected a byte array with a length of at most 40 bytes",
+        );
+    }
 }
@@ -0,0 +1,275 @@
+use crate::*;
+
+/// Allows iterating over the options of an IPv4 header.
+///
+/// Iteration ends after an [`Ipv4Option::EndOfList`] option or after an
+/// error was returned.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv4OptionsIterator<'a> {
+    /// Not yet decoded part of the options.
+    options: &'a [u8],
+}
+
+impl<'a> Ipv4OptionsIterator<'a> {
+    /// Creates an options iterator from a slice containing encoded IPv4 options.
+    #[inline]
+    pub fn from_slice(options: &'a [u8]) -> Ipv4OptionsIterator<'a> {
+        Ipv4OptionsIterator { options }
+    }
+
+    /// Returns the non processed part of the options slice.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.options
+    }
+}
+
+impl<'a> Iterator for Ipv4OptionsIterator<'a> {
+    type Item = Result<Ipv4Option<'a>, Ipv4OptionReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use Ipv4Option::*;
+        use Ipv4OptionReadError::*;
+
+        let result = match self.options {
+            [] => return None,
+            [Ipv4Option::TYPE_END_OF_LIST, ..] => Ok(EndOfList),
+            [Ipv4Option::TYPE_NOP, rest @ ..] => {
+                self.options = rest;
+                return Some(Ok(Nop));
+            }
+            [option_type] => Err(UnexpectedEndOfSlice {
+                option_type: *option_type,
+                expected_len: 2,
+                actual_len: 1,
+            }),
+            [option_type, len, ..] => {
+                let option_type = *option_type;
+                let len = *len;
+                if len < 2 {
+                    Err(UnexpectedSize {
+                        option_type,
+                        size: len,
+                    })
+                } else if self.options.len() < usize::from(len) {
+                    Err(UnexpectedEndOfSlice {
+                        option_type,
+                        expected_len: usize::from(len),
+                        actual_len: self.options.len(),
+                    })
+                } else {
+                    let (option, rest) = self.options.split_at(usize::from(len));
+                    let result = match (option_type, option) {
+                        (Ipv4Option::TYPE_RECORD_ROUTE, [_, _, pointer, route_data @ ..]) => {
+                            Ok(RecordRoute {
+                                pointer: *pointer,
+                                route_data,
+                            })
+                        }
+                        (Ipv4Option::TYPE_TIMESTAMP, [_, _, pointer, oflw_flg, data @ ..]) => {
+                            Ok(Timestamp {
+                                pointer: *pointer,
+                                overflow: oflw_flg >> 4,
+                                flags: oflw_flg & 0b1111,
+                                data,
+                            })
+                        }
+                        (Ipv4Option::TYPE_ROUTER_ALERT, [_, 4, a, b]) => {
+                            Ok(RouterAlert(u16::from_be_bytes([*a, *b])))
+                        }
+                        (Ipv4Option::TYPE_STREAM_ID, [_, 4, a, b]) => {
+                            Ok(StreamId(u16::from_be_bytes([*a, *b])))
+                        }
+                        (
+                            Ipv4Option::TYPE_RECORD_ROUTE
+                            | Ipv4Option::TYPE_TIMESTAMP
+                            | Ipv4Option::TYPE_ROUTER_ALERT
+                            | Ipv4Option::TYPE_STREAM_ID,
+                            _,
+                        ) => Err(UnexpectedSize {
+                            option_type,
+                            size: len,
+                        }),
+                        (_, [_, _, data @ ..]) => Ok(Unknown {
+                            copied: 0 != option_type & 0b1000_0000,
+                            class: (option_type >> 5) & 0b11,
+                            number: option_type & 0b1_1111,
+                            data,
+                        }),
+                        // unreachable as len is checked to be at least 2
+                        (_, _) => Err(UnexpectedSize {
+                            option_type,
+                            size: len,
+                        }),
+                    };
+                    if result.is_ok() {
+                        self.options = rest;
+                        return Some(result);
+                    }
+                    result
+                }
+            }
+        };
+
+        // in case of an error or the end of the list move the slice to an end position
+        let len = self.options.len();
+        self.options = &self.options[len..len];
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use Ipv4Option::*;
+    use Ipv4OptionReadError::*;
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = Ipv4OptionsIterator::from_slice(&[1]);
+        assert_eq!(iter.clone(), iter);
+        assert_eq!(
+            format!("{:?}", iter),
+            "Ipv4OptionsIterator { options: [1] }"
+        );
+    }
+
+    #[test]
+    fn next() {
+        // empty
+        {
+            let mut iter = Ipv4OptionsIterator::from_slice(&[]);
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+
+        // known & unknown options
+        {
+            let data = [
+                1, // nop
+                7,
+                7,
+                4,
+                192,
+                168,
+                1,
+                1, // record route
+                68,
+                8,
+                5,
+                0x12,
+                0,
+                0,
+                0,
+                1, // timestamp
+                148,
+                4,
+                0,
+                0, // router alert
+                136,
+                4,
+                0x12,
+                0x34, // stream id
+                0b1010_0011,
+                3,
+                9, // unknown
+                0,
+                0,
+                0, // end of list & padding
+            ];
+            let mut iter = Ipv4OptionsIterator::from_slice(&data);
+            assert_eq!(
+                iter.by_ref().collect::<Vec<_>>(),
+                [
+                    Ok(Nop),
+                    Ok(RecordRoute {
+                        pointer: 4,
+                        route_data: &[192, 168, 1, 1]
+                    }),
+                    Ok(Timestamp {
+                        pointer: 5,
+                        overflow: 1,
+                        flags: 2,
+                        data: &[0, 0, 0, 1]
+                    }),
+                    Ok(RouterAlert(0)),
+                    Ok(StreamId(0x1234)),
+                    Ok(Unknown {
+                        copied: true,
+                        class: 1,
+                        number: 3,
+                        data: &[9]
+                    }),
+                    Ok(EndOfList),
+                ]
+            );
+            assert!(iter.rest().is_empty());
+        }
+
+        // errors
+        for (data, expected) in [
+            (
+                &[7][..],
+                UnexpectedEndOfSlice {
+                    option_type: 7,
+                    expected_len: 2,
+                    actual_len: 1,
+                },
+            ),
+            (
+                &[200, 1][..],
+                UnexpectedSize {
+                    option_type: 200,
+                    size: 1,
+                },
+            ),
+            (
+                &[7, 8, 4, 1, 2, 3][..],
+                UnexpectedEndOfSlice {
+                    option_type: 7,
+                    expected_len: 8,
+                    actual_len: 6,
+                },
+            ),
+            (
+                &[7, 2][..],
+                UnexpectedSize {
+                    option_type: 7,
+                    size: 2,
+                },
+            ),
+            (
+                &[68, 3, 5][..],
+                UnexpectedSize {
+                    option_type: 68,
+                    size: 3,
+                },
+            ),
+            (
+                &[148, 5, 0, 0, 0][..],
+                UnexpectedSize {
+                    option_type: 148,
+                    size: 5,
+                },
+            ),
+            (
+                &[136, 3, 0][..],
+                UnexpectedSize {
+                    option_type: 136,
+                    size: 3,
+                },
+            ),
+        ] {
+            let mut buffer = Vec::with_capacity(data.len() + 1);
+            buffer.push(Ipv4Option::TYPE_NOP);
+            buffer.extend_from_slice(data);
+            let mut iter = Ipv4OptionsIterator::from_slice(&buffer);
+            assert_eq!(Some(Ok(Nop)), iter.next());
+            assert_eq!(data, iter.rest());
+            assert_eq!(Some(Err(expected)), iter.next());
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+    }
+}
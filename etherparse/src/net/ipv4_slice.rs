@@ -23,6 +23,19 @@ impl<'a> Ipv4Slice<'a> {
     /// If you want to ignore these kind of length errors based on the length
     /// fields in the IP headers use [`crate::LaxIpv4Slice::from_slice`] instead.
     pub fn from_slice(slice: &[u8]) -> Result<Ipv4Slice, SliceError> {
+        Ipv4Slice::from_slice_with_len_fallback(slice, false)
+    }
+
+    /// Separates and validates IPv4 headers like [`Ipv4Slice::from_slice`].
+    ///
+    /// If `len_fallback` is `true` and the given slice contains less data
+    /// then the `total_len` field indicates, the slice length is used instead
+    /// of returning an error. In this case the `len_source` of the payload is
+    /// set to [`LenSource::Slice`].
+    pub(crate) fn from_slice_with_len_fallback(
+        slice: &[u8],
+        len_fallback: bool,
+    ) -> Result<Ipv4Slice<'_>, SliceError> {
         use crate::ip_number::AUTH;
 
         // decode the header
@@ -47,21 +60,37 @@ impl<'a> Ipv4Slice<'a> {
         }
 
         // check slice length based on the total length
-        let header_payload = if slice.len() < header_total_len {
-            return Err(SliceError::Len(LenError {
-                required_len: header_total_len,
-                len: slice.len(),
-                len_source: LenSource::Slice,
-                layer: Layer::Ipv4Packet,
-                layer_start_offset: 0,
-            }));
-        } else {
-            unsafe {
-                core::slice::from_raw_parts(
-                    slice.as_ptr().add(header.slice().len()),
-                    header_total_len - header.slice().len(),
+        let (header_payload, len_source) = if slice.len() < header_total_len {
+            if len_fallback {
+                // SAFETY: Safe as the header slice is a sub-slice of slice.
+                (
+                    unsafe {
+                        core::slice::from_raw_parts(
+                            slice.as_ptr().add(header.slice().len()),
+                            slice.len() - header.slice().len(),
+                        )
+                    },
+                    LenSource::Slice,
                 )
+            } else {
+                return Err(SliceError::Len(LenError {
+                    required_len: header_total_len,
+                    len: slice.len(),
+                    len_source: LenSource::Slice,
+                    layer: Layer::Ipv4Packet,
+                    layer_start_offset: 0,
+                }));
             }
+        } else {
+            (
+                unsafe {
+                    core::slice::from_raw_parts(
+                        slice.as_ptr().add(header.slice().len()),
+                        header_total_len - header.slice().len(),
+                    )
+                },
+                LenSource::Ipv4HeaderTotalLen,
+            )
         };
 
         // decode the authentication header if needed
@@ -76,7 +105,7 @@ impl<'a> Ipv4Slice<'a> {
                     Err(err) => match err {
                         E::Len(mut l) => {
                             // change the length source to the ipv4 header
-                            l.len_source = LenSource::Ipv4HeaderTotalLen;
+                            l.len_source = len_source;
                             l.layer_start_offset += header.slice().len();
                             return Err(SliceError::Len(l));
                         }
@@ -98,7 +127,7 @@ impl<'a> Ipv4Slice<'a> {
                     payload: IpPayloadSlice {
                         ip_number,
                         fragmented,
-                        len_source: LenSource::Ipv4HeaderTotalLen,
+                        len_source,
                         payload,
                     },
                 })
@@ -109,7 +138,7 @@ impl<'a> Ipv4Slice<'a> {
                 payload: IpPayloadSlice {
                     ip_number,
                     fragmented,
-                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    len_source,
                     payload: header_payload,
                 },
             }),
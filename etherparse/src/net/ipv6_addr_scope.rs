@@ -0,0 +1,114 @@
+/// Scope/kind of an IPv6 address (based on the address prefix).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::Ipv6AddrScope;
+///
+/// assert_eq!(
+///     Ipv6AddrScope::LinkLocal,
+///     Ipv6AddrScope::from_octets(&[0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])
+/// );
+/// assert_eq!(
+///     Ipv6AddrScope::Global,
+///     Ipv6AddrScope::from_octets(&[0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Ipv6AddrScope {
+    /// The unspecified address `::`.
+    Unspecified,
+    /// The loopback address `::1`.
+    Loopback,
+    /// Link local unicast address (`fe80::/10`).
+    LinkLocal,
+    /// Unique local unicast address (`fc00::/7`).
+    UniqueLocal,
+    /// Multicast address (`ff00::/8`).
+    Multicast,
+    /// Any other address (global unicast & reserved ranges).
+    Global,
+}
+
+impl Ipv6AddrScope {
+    /// Determines the scope of the given IPv6 address.
+    pub const fn from_octets(addr: &[u8; 16]) -> Ipv6AddrScope {
+        use Ipv6AddrScope::*;
+        if 0xff == addr[0] {
+            Multicast
+        } else if 0xfe == addr[0] && 0x80 == addr[1] & 0b1100_0000 {
+            LinkLocal
+        } else if 0xfc == addr[0] & 0b1111_1110 {
+            UniqueLocal
+        } else if 0 == u128::from_be_bytes(*addr) {
+            Unspecified
+        } else if 1 == u128::from_be_bytes(*addr) {
+            Loopback
+        } else {
+            Global
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Ipv6AddrScope::*;
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let scope = LinkLocal;
+        assert_eq!(scope, scope.clone());
+        assert_eq!("LinkLocal", format!("{:?}", scope));
+    }
+
+    #[test]
+    fn from_octets() {
+        let tests = [
+            ([0u8; 16], Unspecified),
+            ([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1], Loopback),
+            ([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2], Global),
+            (
+                [0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                LinkLocal,
+            ),
+            (
+                [
+                    0xfe, 0xbf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+                    0xff, 0xff, 0xff,
+                ],
+                LinkLocal,
+            ),
+            (
+                [0xfe, 0xc0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                Global,
+            ),
+            (
+                [0xfc, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                UniqueLocal,
+            ),
+            (
+                [0xfd, 0x12, 0x34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                UniqueLocal,
+            ),
+            ([0xfb, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1], Global),
+            (
+                [0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                Multicast,
+            ),
+            (
+                [0xff, 0x0e, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01, 0x01],
+                Multicast,
+            ),
+            (
+                [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+                Global,
+            ),
+        ];
+        for (addr, expected) in tests {
+            assert_eq!(expected, Ipv6AddrScope::from_octets(&addr));
+        }
+    }
+}
@@ -0,0 +1,488 @@
+use super::super::*;
+use crate::err::ipv6_exts::ExtPayloadLenError;
+use arrayvec::ArrayVec;
+use core::fmt::{Debug, Formatter};
+
+/// IPv6 Destination Options extension header (next header value 60).
+///
+/// The options are stored undecoded and can be iterated as
+/// type-length-value elements via [`Ipv6DestOptionsHeader::options`].
+#[derive(Clone)]
+pub struct Ipv6DestOptionsHeader {
+    /// IP protocol number specifying the next header or transport layer protocol.
+    ///
+    /// See [IpNumber] or [ip_number] for a definition of the known values.
+    pub next_header: IpNumber,
+    /// Length of the extension header in 8 octets (minus the first 8 octets).
+    header_length: u8,
+    /// The options contained in the header (everything after the `header_length` field).
+    options_buffer: [u8; Ipv6DestOptionsHeader::MAX_OPTIONS_LEN],
+}
+
+impl Debug for Ipv6DestOptionsHeader {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), core::fmt::Error> {
+        let mut s = f.debug_struct("Ipv6DestOptionsHeader");
+        s.field("next_header", &self.next_header);
+        s.field("options", &self.raw_options());
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Ipv6DestOptionsHeader {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Ipv6DestOptionsHeader {{ next_header: {}, options: {=[u8]} }}",
+            self.next_header,
+            self.raw_options()
+        )
+    }
+}
+
+impl PartialEq for Ipv6DestOptionsHeader {
+    fn eq(&self, other: &Self) -> bool {
+        self.next_header == other.next_header && self.raw_options() == other.raw_options()
+    }
+}
+
+impl Eq for Ipv6DestOptionsHeader {}
+
+impl core::hash::Hash for Ipv6DestOptionsHeader {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.next_header.hash(state);
+        self.raw_options().hash(state);
+    }
+}
+
+impl Default for Ipv6DestOptionsHeader {
+    fn default() -> Self {
+        Ipv6DestOptionsHeader {
+            next_header: IpNumber(255),
+            header_length: 0,
+            options_buffer: [0; Ipv6DestOptionsHeader::MAX_OPTIONS_LEN],
+        }
+    }
+}
+
+impl Ipv6DestOptionsHeader {
+    /// Minimum length of an IPv6 destination options header in bytes/octets.
+    pub const MIN_LEN: usize = 8;
+
+    /// Maximum length of an IPv6 destination options header in bytes/octets.
+    ///
+    /// This number is calculated by multiplying the maximum "hdr ext len"
+    /// (0xff) with 8 and adding 8.
+    pub const MAX_LEN: usize = 8 + (8 * 0xff);
+
+    /// Minimum length of the options area.
+    pub const MIN_OPTIONS_LEN: usize = 6;
+
+    /// Maximum length of the options area.
+    pub const MAX_OPTIONS_LEN: usize = 0xff * 8 + 6;
+
+    /// Creates an IPv6 destination options header with the given raw options.
+    ///
+    /// `options` must have a length of at least 6 bytes and
+    /// `(options.len() + 2) % 8 == 0` must be fulfilled (pad the options
+    /// with Pad1 or PadN options if required). The maximum length is
+    /// [`Ipv6DestOptionsHeader::MAX_OPTIONS_LEN`] (2046 bytes).
+    ///
+    /// If options with a non supported length are passed a
+    /// [`crate::err::ipv6_exts::ExtPayloadLenError`] is returned.
+    pub fn new(
+        next_header: IpNumber,
+        options: &[u8],
+    ) -> Result<Ipv6DestOptionsHeader, ExtPayloadLenError> {
+        let mut result = Ipv6DestOptionsHeader {
+            next_header,
+            ..Default::default()
+        };
+        result.set_raw_options(options)?;
+        Ok(result)
+    }
+
+    /// Read an [`Ipv6DestOptionsHeader`] from a slice and return the header & unused parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(Ipv6DestOptionsHeader, &[u8]), err::LenError> {
+        let s = Ipv6DestOptionsHeaderSlice::from_slice(slice)?;
+        let rest = &slice[s.slice().len()..];
+        let header = s.to_header();
+        Ok((header, rest))
+    }
+
+    /// Read an IPv6 destination options header from the current reader position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut T,
+    ) -> Result<Ipv6DestOptionsHeader, std::io::Error> {
+        let mut start: [u8; 2] = [0; 2];
+        reader.read_exact(&mut start)?;
+
+        let mut result = Ipv6DestOptionsHeader {
+            next_header: IpNumber(start[0]),
+            header_length: start[1],
+            options_buffer: [0; Ipv6DestOptionsHeader::MAX_OPTIONS_LEN],
+        };
+        let options_len = result.raw_options().len();
+        reader.read_exact(&mut result.options_buffer[..options_len])?;
+        Ok(result)
+    }
+
+    /// Read an IPv6 destination options header from the current limited
+    /// reader position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_limited<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut crate::io::LimitedReader<T>,
+    ) -> Result<Ipv6DestOptionsHeader, err::io::LimitedReadError> {
+        // set layer so errors contain the correct layer & offset
+        reader.start_layer(err::Layer::Ipv6DestOptionsHeader);
+
+        let mut start: [u8; 2] = [0; 2];
+        reader.read_exact(&mut start)?;
+
+        let mut result = Ipv6DestOptionsHeader {
+            next_header: IpNumber(start[0]),
+            header_length: start[1],
+            options_buffer: [0; Ipv6DestOptionsHeader::MAX_OPTIONS_LEN],
+        };
+        let options_len = result.raw_options().len();
+        reader.read_exact(&mut result.options_buffer[..options_len])?;
+        Ok(result)
+    }
+
+    /// Writes a given IPv6 destination options header to the current position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<W: std::io::Write + Sized>(&self, writer: &mut W) -> Result<(), std::io::Error> {
+        writer.write_all(&[self.next_header.0, self.header_length])?;
+        writer.write_all(self.raw_options())?;
+        Ok(())
+    }
+
+    /// Returns the undecoded options area (everything after the
+    /// `header_length` field).
+    #[inline]
+    pub fn raw_options(&self) -> &[u8] {
+        &self.options_buffer[..(6 + usize::from(self.header_length) * 8)]
+    }
+
+    /// Sets the undecoded options area (everything after the
+    /// `header_length` field).
+    ///
+    /// `options` must have a length of at least 6 bytes and
+    /// `(options.len() + 2) % 8 == 0` must be fulfilled. The maximum length is
+    /// [`Ipv6DestOptionsHeader::MAX_OPTIONS_LEN`] (2046 bytes).
+    ///
+    /// If options with a non supported length are passed a
+    /// [`crate::err::ipv6_exts::ExtPayloadLenError`] is returned and
+    /// the header is not changed.
+    pub fn set_raw_options(&mut self, options: &[u8]) -> Result<(), ExtPayloadLenError> {
+        use ExtPayloadLenError::*;
+        if options.len() < Self::MIN_OPTIONS_LEN {
+            Err(TooSmall(options.len()))
+        } else if options.len() > Self::MAX_OPTIONS_LEN {
+            Err(TooBig(options.len()))
+        } else if 0 != (options.len() + 2) % 8 {
+            Err(Unaligned(options.len()))
+        } else {
+            self.options_buffer[..options.len()].copy_from_slice(options);
+            self.header_length = ((options.len() - 6) / 8) as u8;
+            Ok(())
+        }
+    }
+
+    /// Returns an iterator over the type-length-value options in the header.
+    #[inline]
+    pub fn options(&self) -> Ipv6OptionsIter<'_> {
+        Ipv6OptionsIter::from_slice(self.raw_options())
+    }
+
+    /// Returns the serialized header.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { Ipv6DestOptionsHeader::MAX_LEN }> {
+        let mut result = ArrayVec::new();
+        result.extend([self.next_header.0, self.header_length]);
+        // Unwrap Panic Safety:
+        // The following unwrap should never panic, as
+        // the options length can at most have the size max
+        // header length - 2 and as the internal buffer used to
+        // store the options has exactly this size.
+        result.try_extend_from_slice(self.raw_options()).unwrap();
+        result
+    }
+
+    /// Length of the header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        8 + usize::from(self.header_length) * 8
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{err::ipv6_exts::ExtPayloadLenError::*, test_gens::*};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn default() {
+        let header: Ipv6DestOptionsHeader = Default::default();
+        assert_eq!(header.next_header, IpNumber(255));
+        assert_eq!(header.raw_options(), &[0, 0, 0, 0, 0, 0]);
+        assert_eq!(header.header_len(), 8);
+    }
+
+    proptest! {
+        #[test]
+        fn debug(header in ipv6_dest_options_any()) {
+            assert_eq!(
+                format!(
+                    "Ipv6DestOptionsHeader {{ next_header: {:?}, options: {:?} }}",
+                    header.next_header,
+                    header.raw_options()
+                ),
+                format!("{:?}", header)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq(header in ipv6_dest_options_any()) {
+            assert_eq!(header.clone(), header);
+        }
+    }
+
+    #[test]
+    fn new_set_raw_options() {
+        // ok
+        for len in [6, 14, 22, Ipv6DestOptionsHeader::MAX_OPTIONS_LEN] {
+            let options: Vec<u8> = (0..len).map(|v| v as u8).collect();
+            let header = Ipv6DestOptionsHeader::new(ip_number::UDP, &options).unwrap();
+            assert_eq!(header.next_header, ip_number::UDP);
+            assert_eq!(header.raw_options(), &options[..]);
+            assert_eq!(header.header_len(), len + 2);
+        }
+        // errors
+        let options = [0u8; Ipv6DestOptionsHeader::MAX_OPTIONS_LEN + 8];
+        for (len, expected) in [
+            (0, TooSmall(0)),
+            (5, TooSmall(5)),
+            (7, Unaligned(7)),
+            (8, Unaligned(8)),
+            (
+                Ipv6DestOptionsHeader::MAX_OPTIONS_LEN + 8,
+                TooBig(Ipv6DestOptionsHeader::MAX_OPTIONS_LEN + 8),
+            ),
+        ] {
+            assert_eq!(
+                Ipv6DestOptionsHeader::new(ip_number::UDP, &options[..len]),
+                Err(expected.clone())
+            );
+            let mut header =
+                Ipv6DestOptionsHeader::new(ip_number::UDP, &[1, 4, 0, 0, 0, 0]).unwrap();
+            assert_eq!(header.set_raw_options(&options[..len]), Err(expected));
+            assert_eq!(header.raw_options(), &[1, 4, 0, 0, 0, 0]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(header in ipv6_dest_options_any()) {
+            // ok
+            {
+                let mut bytes = Vec::with_capacity(header.header_len() + 2);
+                bytes.extend_from_slice(&header.to_bytes());
+                bytes.push(1);
+                bytes.push(2);
+
+                let (actual_header, actual_rest) = Ipv6DestOptionsHeader::from_slice(&bytes).unwrap();
+                assert_eq!(actual_header, header);
+                assert_eq!(actual_rest, &[1, 2]);
+            }
+
+            // length error
+            {
+                let bytes = header.to_bytes();
+                for len in 0..bytes.len() {
+                    assert_eq!(
+                        Ipv6DestOptionsHeader::from_slice(&bytes[..len]).unwrap_err(),
+                        err::LenError{
+                            required_len: if len < Ipv6DestOptionsHeader::MIN_LEN {
+                                Ipv6DestOptionsHeader::MIN_LEN
+                            } else {
+                                header.header_len()
+                            },
+                            len,
+                            len_source: LenSource::Slice,
+                            layer: err::Layer::Ipv6DestOptionsHeader,
+                            layer_start_offset: 0,
+                        }
+                    );
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(header in ipv6_dest_options_any()) {
+            // ok
+            {
+                let mut bytes = Vec::with_capacity(header.header_len());
+                header.write(&mut bytes).unwrap();
+                assert_eq!(&bytes[..], &header.to_bytes()[..]);
+
+                let mut cursor = Cursor::new(&bytes);
+                let actual = Ipv6DestOptionsHeader::read(&mut cursor).unwrap();
+                assert_eq!(actual, header);
+                assert_eq!(cursor.position() as usize, header.header_len());
+            }
+
+            // not enough data
+            {
+                let bytes = header.to_bytes();
+                for len in 0..bytes.len() {
+                    let mut cursor = Cursor::new(&bytes[..len]);
+                    assert!(Ipv6DestOptionsHeader::read(&mut cursor).is_err());
+                }
+            }
+
+            // not enough space to write
+            {
+                let mut bytes = [0u8; Ipv6DestOptionsHeader::MAX_LEN];
+                for len in 0..header.header_len() {
+                    let mut cursor = Cursor::new(&mut bytes[..len]);
+                    assert!(header.write(&mut cursor).is_err());
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_limited(header in ipv6_dest_options_any()) {
+            use crate::{err::{Layer, LenError}, io::LimitedReader};
+
+            // ok
+            {
+                let bytes = header.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..]);
+                let mut reader = LimitedReader::new(
+                    &mut cursor,
+                    bytes.len(),
+                    LenSource::Slice,
+                    0,
+                    Layer::Ipv6Header
+                );
+                assert_eq!(header, Ipv6DestOptionsHeader::read_limited(&mut reader).unwrap());
+            }
+
+            // length error
+            {
+                let bytes = header.to_bytes();
+                for len in 0..header.header_len() {
+                    // io error
+                    {
+                        let mut cursor = Cursor::new(&bytes[..len]);
+                        let mut reader = LimitedReader::new(
+                            &mut cursor,
+                            bytes.len(),
+                            LenSource::Slice,
+                            0,
+                            Layer::Ipv6Header
+                        );
+                        assert!(
+                            Ipv6DestOptionsHeader::read_limited(&mut reader)
+                                .unwrap_err()
+                                .io()
+                                .is_some()
+                        );
+                    }
+                    // limited reader error
+                    {
+                        let mut cursor = Cursor::new(&bytes[..]);
+                        let mut reader = LimitedReader::new(
+                            &mut cursor,
+                            len,
+                            LenSource::Ipv6HeaderPayloadLen,
+                            0,
+                            Layer::Ipv6Header
+                        );
+                        assert_eq!(
+                            Ipv6DestOptionsHeader::read_limited(&mut reader)
+                                .unwrap_err()
+                                .len()
+                                .unwrap(),
+                            LenError {
+                                required_len: if len < 2 {
+                                    2
+                                } else {
+                                    bytes.len()
+                                },
+                                len,
+                                len_source: LenSource::Ipv6HeaderPayloadLen,
+                                layer: Layer::Ipv6DestOptionsHeader,
+                                layer_start_offset: 0
+                            }
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn options() {
+        let header = Ipv6DestOptionsHeader::new(
+            ip_number::UDP,
+            &[
+                0, // pad1
+                0xc9, 2, 0xaa, 0xbb, // home address like option
+                1, 0, // padN without data
+                1, 2, 0, 0, // padN
+                0, // pad1
+                0, // pad1
+                0, // pad1
+            ],
+        )
+        .unwrap();
+        let options: Vec<(u8, &[u8])> = header.options().collect();
+        assert_eq!(
+            options,
+            [
+                (0, &[][..]),
+                (0xc9, &[0xaa, 0xbb][..]),
+                (1, &[][..]),
+                (1, &[0, 0][..]),
+                (0, &[][..]),
+                (0, &[][..]),
+                (0, &[][..]),
+            ]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn hash(header in ipv6_dest_options_any()) {
+            use core::hash::{Hash, Hasher};
+            use std::collections::hash_map::DefaultHasher;
+
+            // bytes outside of the used area of the buffer
+            // should not influence the hash
+            let mut other = header.clone();
+            other.options_buffer[header.raw_options().len()..].fill(0xff);
+            assert_eq!(header, other);
+
+            let hash = |value: &Ipv6DestOptionsHeader| {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(hash(&header), hash(&other));
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing an IPv6 destination options extension header (next header value 60).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv6DestOptionsHeaderSlice<'a> {
+    /// Slice containing the header data.
+    slice: &'a [u8],
+}
+
+impl<'a> Ipv6DestOptionsHeaderSlice<'a> {
+    /// Creates a destination options header slice from a slice.
+    ///
+    /// The length of the header is determined by the "hdr ext len" field.
+    pub fn from_slice(slice: &'a [u8]) -> Result<Ipv6DestOptionsHeaderSlice<'a>, err::LenError> {
+        // check length
+        if slice.len() < Ipv6DestOptionsHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: Ipv6DestOptionsHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Ipv6DestOptionsHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check the length again now that the expected length is known
+        let len = ((slice[1] as usize) + 1) * 8;
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Ipv6DestOptionsHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(Ipv6DestOptionsHeaderSlice {
+            // SAFETY:
+            // Safe as the slice has been checked in the previous if
+            // to have at least the the length of the variable len.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Creates a destination options header slice from a slice (assumes slice
+    /// size & content was validated before).
+    ///
+    /// # Safety
+    ///
+    /// This method assumes that the slice was previously validated to contain
+    /// a valid destination options header. This means the slice length must
+    /// at least be at least 8 and `(slice[1] + 1)*8`.
+    ///
+    /// If these preconditions are not fulfilled the behavior of this function
+    /// and the methods of the return [`Ipv6DestOptionsHeaderSlice`] will be undefined.
+    pub unsafe fn from_slice_unchecked(slice: &'a [u8]) -> Ipv6DestOptionsHeaderSlice<'a> {
+        Ipv6DestOptionsHeaderSlice {
+            slice: from_raw_parts(slice.as_ptr(), ((*slice.get_unchecked(1) as usize) + 1) * 8),
+        }
+    }
+
+    /// Returns the slice containing the destination options header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the IP protocol number of the next header or transport layer protocol.
+    ///
+    /// See [IpNumber] or [ip_number] for a definition of the known values.
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        IpNumber(unsafe { *self.slice.get_unchecked(0) })
+    }
+
+    /// Returns the "hdr ext len" field (length of the header in 8 octets
+    /// minus the first 8 octets).
+    #[inline]
+    pub fn header_ext_len(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(1) }
+    }
+
+    /// Returns the undecoded options area (everything after the
+    /// "hdr ext len" field).
+    #[inline]
+    pub fn raw_options(&self) -> &'a [u8] {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { from_raw_parts(self.slice.as_ptr().add(2), self.slice.len() - 2) }
+    }
+
+    /// Returns an iterator over the type-length-value options in the header.
+    #[inline]
+    pub fn options(&self) -> Ipv6OptionsIter<'a> {
+        Ipv6OptionsIter::from_slice(self.raw_options())
+    }
+
+    /// Decode all the fields and copy the results to a [`Ipv6DestOptionsHeader`] struct.
+    pub fn to_header(&self) -> Ipv6DestOptionsHeader {
+        // Unwrap Panic Safety:
+        // The options length is guaranteed to be valid as it was
+        // derived from the "hdr ext len" field.
+        Ipv6DestOptionsHeader::new(self.next_header(), self.raw_options()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn debug(header in ipv6_dest_options_any()) {
+            let bytes = header.to_bytes();
+            let slice = Ipv6DestOptionsHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                format!("{:?}", slice),
+                format!("Ipv6DestOptionsHeaderSlice {{ slice: {:?} }}", slice.slice())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq(header in ipv6_dest_options_any()) {
+            let bytes = header.to_bytes();
+            let slice = Ipv6DestOptionsHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.clone(), slice);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(header in ipv6_dest_options_any()) {
+            let bytes = header.to_bytes();
+
+            // ok
+            {
+                let slice = Ipv6DestOptionsHeaderSlice::from_slice(&bytes).unwrap();
+                assert_eq!(slice.slice(), &bytes[..]);
+            }
+
+            // length error
+            for len in 0..bytes.len() {
+                assert_eq!(
+                    Ipv6DestOptionsHeaderSlice::from_slice(&bytes[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: if len < Ipv6DestOptionsHeader::MIN_LEN {
+                            Ipv6DestOptionsHeader::MIN_LEN
+                        } else {
+                            header.header_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::Ipv6DestOptionsHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_unchecked(header in ipv6_dest_options_any()) {
+            let bytes = header.to_bytes();
+            let slice = unsafe {
+                Ipv6DestOptionsHeaderSlice::from_slice_unchecked(&bytes)
+            };
+            assert_eq!(&bytes[..], slice.slice());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(header in ipv6_dest_options_any()) {
+            let bytes = header.to_bytes();
+            let slice = Ipv6DestOptionsHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.next_header(), header.next_header);
+            assert_eq!(slice.header_ext_len(), bytes[1]);
+            assert_eq!(slice.raw_options(), header.raw_options());
+            assert_eq!(
+                slice.options().collect::<Vec<_>>(),
+                header.options().collect::<Vec<_>>()
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(header in ipv6_dest_options_any()) {
+            let bytes = header.to_bytes();
+            let slice = Ipv6DestOptionsHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(header, slice.to_header());
+        }
+    }
+}
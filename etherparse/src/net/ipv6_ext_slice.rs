@@ -10,7 +10,6 @@ use crate::*;
 /// from <https://www.iana.org/assignments/ipv6-parameters/ipv6-parameters.xhtml>):
 ///
 /// * Encapsulating Security Payload \[[RFC4303](https://datatracker.ietf.org/doc/html/rfc4303)\]
-/// * Mobility Header \[[RFC6275](https://datatracker.ietf.org/doc/html/rfc6275)\]
 /// * Host Identity Protocol \[[RFC7401](https://datatracker.ietf.org/doc/html/rfc7401)\]
 /// * Shim6 Protocol \[[RFC5533](https://datatracker.ietf.org/doc/html/rfc5533)\]
 /// * 253 Use for experimentation and testing \[[RFC3692](https://datatracker.ietf.org/doc/html/rfc3692)\]\[[RFC4727](https://datatracker.ietf.org/doc/html/rfc4727)\]
@@ -27,6 +26,11 @@ pub enum Ipv6ExtensionSlice<'a> {
     DestinationOptions(Ipv6RawExtHeaderSlice<'a>),
     /// Authentication Header \[[RFC4302](https://datatracker.ietf.org/doc/html/rfc4302)\]
     Authentication(IpAuthHeaderSlice<'a>),
+    /// Mobility Header \[[RFC6275](https://datatracker.ietf.org/doc/html/rfc6275)\]
+    ///
+    /// Only returned by [`Ipv6ExtensionsIter`] (as the last element, the
+    /// mobility header terminates the extension header chain).
+    Mobility(Ipv6MobilityHeaderSlice<'a>),
 }
 
 #[cfg(test)]
@@ -76,6 +80,15 @@ mod test {
                 format!("{:?}", Authentication(slice.clone()))
             );
         }
+        {
+            let header = Ipv6MobilityHeader::new(IPV6_NO_NEXT_HEADER, 5, &[1, 2]).unwrap();
+            let bytes = header.to_bytes();
+            let slice = Ipv6MobilityHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                format!("Mobility({:?})", slice),
+                format!("{:?}", Mobility(slice.clone()))
+            );
+        }
     }
 
     #[test]
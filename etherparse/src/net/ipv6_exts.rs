@@ -20,7 +20,8 @@ use crate::{
 /// * Host Identity Protocol (HIP)
 /// * IP Mobility
 /// * Site Multihoming by IPv6 Intermediation (SHIM6)
-#[derive(Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Ipv6Extensions {
     pub hop_by_hop_options: Option<Ipv6RawExtHeader>,
     pub destination_options: Option<Ipv6RawExtHeader>,
@@ -171,6 +172,18 @@ impl Ipv6Extensions {
         //should not be hit
     }
 
+    /// Reads as many extension headers as possible from the slice like
+    /// [`Ipv6Extensions::from_slice`] but returns the number of bytes
+    /// consumed from the slice instead of the unparsed part of the slice.
+    #[inline]
+    pub fn from_slice_with_len(
+        start_ip_number: IpNumber,
+        slice: &[u8],
+    ) -> Result<(Ipv6Extensions, IpNumber, usize), err::ipv6_exts::HeaderSliceError> {
+        let (exts, next_header, rest) = Ipv6Extensions::from_slice(start_ip_number, slice)?;
+        Ok((exts, next_header, slice.len() - rest.len()))
+    }
+
     /// Reads as many extension headers as possible from the slice until a non IPv6 extension
     /// header or an error gets encountered.
     ///
@@ -548,7 +561,9 @@ impl Ipv6Extensions {
 
         // the hop by hop header is required to occur directly after the ipv6 header
         if IPV6_HOP_BY_HOP == next_protocol {
-            let header = Ipv6RawExtHeader::read_limited(reader).map_err(map_limited_err)?;
+            let header =
+                Ipv6RawExtHeader::read_limited_with_layer(reader, err::Layer::Ipv6HopByHopHeader)
+                    .map_err(map_limited_err)?;
             next_protocol = header.next_header;
             result.hop_by_hop_options = Some(header);
         }
@@ -566,8 +581,11 @@ impl Ipv6Extensions {
                             // more then one header of this type found -> abort parsing
                             return Ok((result, next_protocol));
                         } else {
-                            let header =
-                                Ipv6RawExtHeader::read_limited(reader).map_err(map_limited_err)?;
+                            let header = Ipv6RawExtHeader::read_limited_with_layer(
+                                reader,
+                                err::Layer::Ipv6DestOptionsHeader,
+                            )
+                            .map_err(map_limited_err)?;
                             next_protocol = header.next_header;
                             routing.final_destination_options = Some(header);
                         }
@@ -575,8 +593,11 @@ impl Ipv6Extensions {
                         // more then one header of this type found -> abort parsing
                         return Ok((result, next_protocol));
                     } else {
-                        let header =
-                            Ipv6RawExtHeader::read_limited(reader).map_err(map_limited_err)?;
+                        let header = Ipv6RawExtHeader::read_limited_with_layer(
+                            reader,
+                            err::Layer::Ipv6DestOptionsHeader,
+                        )
+                        .map_err(map_limited_err)?;
                         next_protocol = header.next_header;
                         result.destination_options = Some(header);
                     }
@@ -586,8 +607,11 @@ impl Ipv6Extensions {
                         // more then one header of this type found -> abort parsing
                         return Ok((result, next_protocol));
                     } else {
-                        let header =
-                            Ipv6RawExtHeader::read_limited(reader).map_err(map_limited_err)?;
+                        let header = Ipv6RawExtHeader::read_limited_with_layer(
+                            reader,
+                            err::Layer::Ipv6RouteHeader,
+                        )
+                        .map_err(map_limited_err)?;
                         next_protocol = header.next_header;
                         result.routing = Some(Ipv6RoutingExtensions {
                             routing: header,
@@ -1711,6 +1735,12 @@ mod test {
                     assert_eq!(next, expected_post_header);
                     assert_eq!(rest, &e.slice()[read_len..]);
 
+                    // with len
+                    assert_eq!(
+                        Ipv6Extensions::from_slice_with_len(ip_numbers[0], e.slice()).unwrap(),
+                        (header, next, read_len)
+                    );
+
                     // unexpected end of slice
                     {
                         let mut offset: usize = 0;
@@ -1993,6 +2023,45 @@ mod test {
         }
     }
 
+    #[test]
+    fn read_limited_layers() {
+        use crate::io::LimitedReader;
+        use err::Layer;
+        use ip_number::*;
+        use std::io::Cursor;
+
+        // the error layer identifies the truncated header
+        for (ip_number, layer) in [
+            (IPV6_HOP_BY_HOP, Layer::Ipv6HopByHopHeader),
+            (IPV6_DEST_OPTIONS, Layer::Ipv6DestOptionsHeader),
+            (IPV6_ROUTE, Layer::Ipv6RouteHeader),
+            (IPV6_FRAG, Layer::Ipv6FragHeader),
+        ] {
+            let bytes = [UDP.0, 0, 0, 0, 0, 0, 0, 0];
+            let mut cursor = Cursor::new(&bytes[..]);
+            let mut reader = LimitedReader::new(
+                &mut cursor,
+                bytes.len() - 1,
+                LenSource::Ipv6HeaderPayloadLen,
+                0,
+                Layer::Ipv6Header,
+            );
+            assert_eq!(
+                Ipv6Extensions::read_limited(&mut reader, ip_number)
+                    .unwrap_err()
+                    .len()
+                    .unwrap(),
+                err::LenError {
+                    required_len: 8,
+                    len: 7,
+                    len_source: LenSource::Ipv6HeaderPayloadLen,
+                    layer,
+                    layer_start_offset: 0,
+                }
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn read_limited(
@@ -0,0 +1,388 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Iterator walking the IPv6 extension header chain of a not yet
+/// validated slice.
+///
+/// Starting from the `next_header` value of the IPv6 header the iterator
+/// returns one [`Ipv6ExtensionSlice`] per extension header until an
+/// [`IpNumber`] is reached that is not a supported extension header
+/// (e.g. a transport protocol). Afterwards [`Ipv6ExtensionsIter::next_header`]
+/// & [`Ipv6ExtensionsIter::rest`] contain the final protocol number and
+/// the payload after the extension headers.
+///
+/// A mobility header ([`ip_number::MOBILITY`]) is returned as
+/// [`Ipv6ExtensionSlice::Mobility`] and terminates the iteration, as it
+/// carries the upper-layer message itself. [`Ipv6ExtensionsIter::next_header`]
+/// then contains its "payload proto" field (normally
+/// [`ip_number::IPV6_NO_NEXT_HEADER`]).
+///
+/// If an error is encountered it is returned as last element and the
+/// iteration stops. [`Ipv6ExtensionsIter::next_header`] &
+/// [`Ipv6ExtensionsIter::rest`] then point to the header that caused
+/// the error. To protect against maliciously long chains at most
+/// [`Ipv6ExtensionsIter::MAX_HEADERS`] extension headers are decoded
+/// (can be changed via [`Ipv6ExtensionsIter::with_max_headers`]), after
+/// that an [`err::ipv6_exts::HeaderError::TooManyHeaders`] error is
+/// returned.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ip_number, Ipv6ExtensionsIter, Ipv6ExtensionSlice, Ipv6RawExtHeader};
+///
+/// let header = Ipv6RawExtHeader::new_raw(ip_number::UDP, &[0; 6]).unwrap();
+/// let mut data = header.to_bytes().to_vec();
+/// data.extend_from_slice(&[1, 2, 3, 4]);
+///
+/// let mut iter = Ipv6ExtensionsIter::new(ip_number::IPV6_ROUTE, &data);
+/// assert!(matches!(iter.next(), Some(Ok(Ipv6ExtensionSlice::Routing(_)))));
+/// assert!(iter.next().is_none());
+/// assert_eq!(iter.next_header(), ip_number::UDP);
+/// assert_eq!(iter.rest(), &[1, 2, 3, 4]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv6ExtensionsIter<'a> {
+    /// IP number of the next not yet decoded header.
+    next_header: IpNumber,
+    /// Not yet decoded data.
+    rest: &'a [u8],
+    /// Number of extension headers decoded so far.
+    header_count: usize,
+    /// Maximum number of extension headers that will be decoded.
+    max_headers: usize,
+    /// Offset of `rest` relative to the start slice (used for errors).
+    offset: usize,
+    /// True if an error was encountered.
+    stopped: bool,
+}
+
+impl<'a> Ipv6ExtensionsIter<'a> {
+    /// Default maximum number of extension headers the iterator decodes
+    /// before returning an [`err::ipv6_exts::HeaderError::TooManyHeaders`] error.
+    pub const MAX_HEADERS: usize = 8;
+
+    /// Creates an iterator over the extension headers in the given slice
+    /// starting with the header identified by `next_header`.
+    #[inline]
+    pub fn new(next_header: IpNumber, slice: &'a [u8]) -> Ipv6ExtensionsIter<'a> {
+        Ipv6ExtensionsIter {
+            next_header,
+            rest: slice,
+            header_count: 0,
+            max_headers: Self::MAX_HEADERS,
+            offset: 0,
+            stopped: false,
+        }
+    }
+
+    /// Sets the maximum number of extension headers that are decoded
+    /// before an [`err::ipv6_exts::HeaderError::TooManyHeaders`] error
+    /// is returned (the default is [`Ipv6ExtensionsIter::MAX_HEADERS`]).
+    ///
+    /// All extension headers in the chain count towards the limit (including
+    /// repeated routing, fragment or destination options headers).
+    #[inline]
+    pub fn with_max_headers(mut self, max_headers: usize) -> Ipv6ExtensionsIter<'a> {
+        self.max_headers = max_headers;
+        self
+    }
+
+    /// IP number of the header following the already decoded extension
+    /// headers (after the iteration is done the transport protocol).
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        self.next_header
+    }
+
+    /// Data after the already decoded extension headers (after the
+    /// iteration is done the payload).
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+
+    /// Moves `rest` behind a header of the given length and sets the
+    /// next header.
+    #[inline]
+    fn advance(&mut self, len: usize, next_header: IpNumber) {
+        // SAFETY:
+        // All header slices are sub slices of rest, so len is
+        // guaranteed to be smaller or equal to the length of rest.
+        self.rest = unsafe { from_raw_parts(self.rest.as_ptr().add(len), self.rest.len() - len) };
+        self.offset += len;
+        self.next_header = next_header;
+        self.header_count += 1;
+    }
+}
+
+impl<'a> Iterator for Ipv6ExtensionsIter<'a> {
+    type Item = Result<Ipv6ExtensionSlice<'a>, err::ipv6_exts::HeaderSliceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use err::ipv6_exts::{HeaderError::*, HeaderSliceError::*};
+        use ip_number::*;
+        use Ipv6ExtensionSlice::*;
+
+        if self.stopped {
+            return None;
+        }
+
+        // check that the header is an extension header
+        match self.next_header {
+            IPV6_HOP_BY_HOP | IPV6_ROUTE | IPV6_DEST_OPTIONS | IPV6_FRAG | AUTH | MOBILITY => {}
+            // done parsing, the next header is not a known/supported header extension
+            _ => return None,
+        }
+
+        let result = if self.header_count >= self.max_headers {
+            Err(Content(TooManyHeaders {
+                max: self.max_headers,
+            }))
+        } else {
+            match self.next_header {
+                IPV6_HOP_BY_HOP if self.header_count > 0 => Err(Content(HopByHopNotAtStart)),
+                IPV6_HOP_BY_HOP | IPV6_ROUTE | IPV6_DEST_OPTIONS => {
+                    Ipv6RawExtHeaderSlice::from_slice(self.rest)
+                        .map_err(|err| Len(err.add_offset(self.offset)))
+                        .map(|slice| {
+                            let ext = match self.next_header {
+                                IPV6_HOP_BY_HOP => HopByHop(slice.clone()),
+                                IPV6_ROUTE => Routing(slice.clone()),
+                                _ => DestinationOptions(slice.clone()),
+                            };
+                            self.advance(slice.slice().len(), slice.next_header());
+                            ext
+                        })
+                }
+                IPV6_FRAG => Ipv6FragmentHeaderSlice::from_slice(self.rest)
+                    .map_err(|err| Len(err.add_offset(self.offset)))
+                    .map(|slice| {
+                        self.advance(slice.slice().len(), slice.next_header());
+                        Fragment(slice)
+                    }),
+                MOBILITY => Ipv6MobilityHeaderSlice::from_slice(self.rest)
+                    .map_err(|err| Len(err.add_offset(self.offset)))
+                    .map(|slice| {
+                        self.advance(slice.slice().len(), slice.next_header());
+                        // the mobility header is the last header in the chain
+                        self.stopped = true;
+                        Mobility(slice)
+                    }),
+                _ => IpAuthHeaderSlice::from_slice(self.rest)
+                    .map_err(|err| {
+                        use err::ip_auth::HeaderSliceError as I;
+                        match err {
+                            I::Len(err) => Len(err.add_offset(self.offset)),
+                            I::Content(err) => Content(IpAuth(err)),
+                        }
+                    })
+                    .map(|slice| {
+                        self.advance(slice.slice().len(), slice.next_header());
+                        Authentication(slice)
+                    }),
+            }
+        };
+
+        if result.is_err() {
+            self.stopped = true;
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ipv6_exts_test_helpers::*;
+    use super::*;
+    use crate::ip_number::*;
+    use crate::test_gens::*;
+    use alloc::{borrow::ToOwned, format, vec::Vec};
+    use proptest::prelude::*;
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = Ipv6ExtensionsIter::new(UDP, &[]);
+        assert_eq!(iter.clone(), iter);
+        assert_eq!(
+            format!("{:?}", iter),
+            format!(
+                "Ipv6ExtensionsIter {{ next_header: {:?}, rest: [], header_count: 0, max_headers: 8, offset: 0, stopped: false }}",
+                UDP
+            )
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn next(
+            header_size in any::<u8>(),
+            post_header in ip_number_any()
+                .prop_filter("Must be a non ipv6 header relevant ip number".to_owned(),
+                    |v| *v != MOBILITY && !EXTENSION_KNOWN_IP_NUMBERS.iter().any(|&x| v == &x)
+                )
+        ) {
+            /// Compare the iterator with the result of Ipv6ExtensionsSlice::from_slice
+            fn run_test(ip_numbers: &[IpNumber], header_sizes: &[u8]) {
+                let e = ExtensionTestPayload::new(ip_numbers, header_sizes);
+
+                // complete data
+                {
+                    let mut iter = Ipv6ExtensionsIter::new(ip_numbers[0], e.slice());
+                    let actual: Vec<_> = (&mut iter).collect();
+                    match Ipv6ExtensionsSlice::from_slice(ip_numbers[0], e.slice()) {
+                        Ok((slice, next_header, rest)) => {
+                            let expected: Vec<_> = slice.into_iter().map(Ok).collect();
+                            assert_eq!(expected, actual);
+                            assert_eq!(next_header, iter.next_header());
+                            assert_eq!(rest, iter.rest());
+                        }
+                        Err(err) => {
+                            assert_eq!(Some(&Err(err)), actual.last());
+                            assert_eq!(None, iter.next());
+                        }
+                    }
+                }
+
+                // length errors
+                for len in 0..e.slice().len() {
+                    let actual: Vec<_> = Ipv6ExtensionsIter::new(ip_numbers[0], &e.slice()[..len]).collect();
+                    let expected = Ipv6ExtensionsSlice::from_slice(ip_numbers[0], &e.slice()[..len]).unwrap_err();
+                    assert_eq!(Some(&Err(expected)), actual.last());
+                }
+            }
+
+            for first_header in &EXTENSION_KNOWN_IP_NUMBERS {
+                run_test(&[*first_header, post_header], &[header_size]);
+                for second_header in &EXTENSION_KNOWN_IP_NUMBERS {
+                    run_test(&[*first_header, *second_header, post_header], &[header_size]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mobility() {
+        let mobility = Ipv6MobilityHeader::new(
+            IPV6_NO_NEXT_HEADER,
+            Ipv6MobilityHeader::BINDING_UPDATE,
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+        )
+        .unwrap();
+        let dest = Ipv6RawExtHeader::new_raw(MOBILITY, &[0; 6]).unwrap();
+
+        // mobility header after an extension header
+        {
+            let mut data = Vec::new();
+            data.extend_from_slice(&dest.to_bytes());
+            data.extend_from_slice(&mobility.to_bytes());
+            data.extend_from_slice(&[1, 2]);
+
+            let mut iter = Ipv6ExtensionsIter::new(IPV6_DEST_OPTIONS, &data);
+            assert!(matches!(
+                iter.next(),
+                Some(Ok(Ipv6ExtensionSlice::DestinationOptions(_)))
+            ));
+            match iter.next() {
+                Some(Ok(Ipv6ExtensionSlice::Mobility(slice))) => {
+                    assert_eq!(slice.mh_type(), Ipv6MobilityHeader::BINDING_UPDATE);
+                    assert_eq!(slice.to_header(), mobility);
+                }
+                value => panic!("unexpected value {:?}", value),
+            }
+            assert_eq!(None, iter.next());
+            assert_eq!(IPV6_NO_NEXT_HEADER, iter.next_header());
+            assert_eq!(&[1, 2], iter.rest());
+        }
+
+        // mobility header terminates the chain even if the
+        // payload proto is an extension header
+        {
+            let mobility = Ipv6MobilityHeader::new(IPV6_DEST_OPTIONS, 0, &[0; 2]).unwrap();
+            let mut data = Vec::new();
+            data.extend_from_slice(&mobility.to_bytes());
+            data.extend_from_slice(&dest.to_bytes());
+
+            let mut iter = Ipv6ExtensionsIter::new(MOBILITY, &data);
+            assert!(matches!(
+                iter.next(),
+                Some(Ok(Ipv6ExtensionSlice::Mobility(_)))
+            ));
+            assert_eq!(None, iter.next());
+            assert_eq!(IPV6_DEST_OPTIONS, iter.next_header());
+            assert_eq!(&dest.to_bytes()[..], iter.rest());
+        }
+
+        // length error
+        {
+            let bytes = mobility.to_bytes();
+            for len in 0..bytes.len() {
+                let mut iter = Ipv6ExtensionsIter::new(MOBILITY, &bytes[..len]);
+                assert_eq!(
+                    Some(Err(err::ipv6_exts::HeaderSliceError::Len(
+                        Ipv6MobilityHeaderSlice::from_slice(&bytes[..len]).unwrap_err()
+                    ))),
+                    iter.next()
+                );
+                assert_eq!(None, iter.next());
+            }
+        }
+    }
+
+    #[test]
+    fn max_headers() {
+        use err::ipv6_exts::{HeaderError::*, HeaderSliceError::*};
+
+        // maximum number of headers is ok
+        {
+            let mut ip_numbers = [IPV6_DEST_OPTIONS; Ipv6ExtensionsIter::MAX_HEADERS + 1];
+            ip_numbers[Ipv6ExtensionsIter::MAX_HEADERS] = UDP;
+            let e = ExtensionTestPayload::new(&ip_numbers, &[0]);
+            let mut iter = Ipv6ExtensionsIter::new(IPV6_DEST_OPTIONS, e.slice());
+            assert_eq!(
+                Ipv6ExtensionsIter::MAX_HEADERS,
+                (&mut iter).filter(|v| v.is_ok()).count()
+            );
+            assert_eq!(UDP, iter.next_header());
+            assert!(iter.rest().is_empty());
+        }
+
+        // one header more triggers an error
+        {
+            let mut ip_numbers = [IPV6_DEST_OPTIONS; Ipv6ExtensionsIter::MAX_HEADERS + 2];
+            ip_numbers[Ipv6ExtensionsIter::MAX_HEADERS + 1] = UDP;
+            let e = ExtensionTestPayload::new(&ip_numbers, &[0]);
+            let mut iter = Ipv6ExtensionsIter::new(IPV6_DEST_OPTIONS, e.slice());
+            for _ in 0..Ipv6ExtensionsIter::MAX_HEADERS {
+                assert!(iter.next().unwrap().is_ok());
+            }
+            assert_eq!(
+                Some(Err(Content(TooManyHeaders {
+                    max: Ipv6ExtensionsIter::MAX_HEADERS
+                }))),
+                iter.next()
+            );
+            assert_eq!(None, iter.next());
+            assert_eq!(IPV6_DEST_OPTIONS, iter.next_header());
+            assert_eq!(8, iter.rest().len());
+        }
+
+        // custom limit
+        {
+            let ip_numbers = [IPV6_DEST_OPTIONS, IPV6_FRAG, IPV6_ROUTE, UDP];
+            let e = ExtensionTestPayload::new(&ip_numbers, &[0]);
+            for max in 0..ip_numbers.len() - 1 {
+                let mut iter =
+                    Ipv6ExtensionsIter::new(IPV6_DEST_OPTIONS, e.slice()).with_max_headers(max);
+                for _ in 0..max {
+                    assert!(iter.next().unwrap().is_ok());
+                }
+                assert_eq!(Some(Err(Content(TooManyHeaders { max }))), iter.next());
+                assert_eq!(None, iter.next());
+            }
+            let mut iter = Ipv6ExtensionsIter::new(IPV6_DEST_OPTIONS, e.slice())
+                .with_max_headers(ip_numbers.len() - 1);
+            assert!(iter.all(|v| v.is_ok()));
+        }
+    }
+}
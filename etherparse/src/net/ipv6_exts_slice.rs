@@ -33,21 +33,55 @@ impl<'a> Ipv6ExtensionsSlice<'a> {
         start_ip_number: IpNumber,
         start_slice: &'a [u8],
     ) -> Result<(Ipv6ExtensionsSlice, IpNumber, &'a [u8]), err::ipv6_exts::HeaderSliceError> {
+        Self::from_slice_with_max_headers(start_ip_number, start_slice, None)
+    }
+
+    /// Collects all ipv6 extension headers in a slice like
+    /// [`Ipv6ExtensionsSlice::from_slice`].
+    ///
+    /// If `max_headers` is set an [`err::ipv6_exts::HeaderError::TooManyHeaders`]
+    /// error is returned as soon as the chain contains more then `max_headers`
+    /// extension headers.
+    pub(crate) fn from_slice_with_max_headers(
+        start_ip_number: IpNumber,
+        start_slice: &'a [u8],
+        max_headers: Option<usize>,
+    ) -> Result<(Ipv6ExtensionsSlice<'a>, IpNumber, &'a [u8]), err::ipv6_exts::HeaderSliceError>
+    {
         let mut rest = start_slice;
         let mut next_header = start_ip_number;
         let mut fragmented = false;
+        let mut header_count = 0;
 
         use err::ipv6_exts::{HeaderError::*, HeaderSliceError::*};
         use ip_number::*;
 
+        // check the number of headers before decoding an extension header
+        let mut count_header = || {
+            if let Some(max) = max_headers {
+                if header_count >= max {
+                    return Err(Content(TooManyHeaders { max }));
+                }
+            }
+            header_count += 1;
+            Ok(())
+        };
+
         // the hop by hop header is required to occur directly after the ipv6 header
         if IPV6_HOP_BY_HOP == next_header {
+            count_header()?;
             let slice = Ipv6RawExtHeaderSlice::from_slice(rest).map_err(Len)?;
             rest = &rest[slice.slice().len()..];
             next_header = slice.next_header();
         }
 
         loop {
+            if matches!(
+                next_header,
+                IPV6_DEST_OPTIONS | IPV6_ROUTE | IPV6_FRAG | AUTH
+            ) {
+                count_header()?;
+            }
             match next_header {
                 IPV6_HOP_BY_HOP => {
                     return Err(Content(HopByHopNotAtStart));
@@ -600,6 +634,53 @@ mod test {
         }
     }
 
+    #[test]
+    fn from_slice_with_max_headers() {
+        use err::ipv6_exts::{HeaderError::*, HeaderSliceError::*};
+
+        // hop by hop, destination options, routing, fragment & udp
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(
+            &Ipv6RawExtHeader::new_raw(IPV6_DEST_OPTIONS, &[0; 6])
+                .unwrap()
+                .to_bytes(),
+        );
+        bytes.extend_from_slice(
+            &Ipv6RawExtHeader::new_raw(IPV6_ROUTE, &[0; 6])
+                .unwrap()
+                .to_bytes(),
+        );
+        bytes.extend_from_slice(
+            &Ipv6RawExtHeader::new_raw(IPV6_FRAG, &[0; 6])
+                .unwrap()
+                .to_bytes(),
+        );
+        bytes.extend_from_slice(
+            &Ipv6FragmentHeader::new(UDP, IpFragOffset::ZERO, false, 0).to_bytes(),
+        );
+
+        // no limit & limit big enough
+        for max in [None, Some(4), Some(5)] {
+            assert_eq!(
+                Ipv6ExtensionsSlice::from_slice_with_max_headers(IPV6_HOP_BY_HOP, &bytes, max),
+                Ipv6ExtensionsSlice::from_slice(IPV6_HOP_BY_HOP, &bytes)
+            );
+        }
+
+        // limit exceeded (hop by hop counts as well)
+        for max in 0..4 {
+            assert_eq!(
+                Ipv6ExtensionsSlice::from_slice_with_max_headers(
+                    IPV6_HOP_BY_HOP,
+                    &bytes,
+                    Some(max)
+                )
+                .unwrap_err(),
+                Content(TooManyHeaders { max })
+            );
+        }
+    }
+
     #[test]
     fn is_empty() {
         // empty
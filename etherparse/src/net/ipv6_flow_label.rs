@@ -49,6 +49,12 @@ use crate::err::ValueTooBigError;
 /// }
 /// ```
 #[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u32", into = "u32")
+)]
 pub struct Ipv6FlowLabel(u32);
 
 impl Ipv6FlowLabel {
@@ -1,7 +1,9 @@
 use super::super::*;
 
 /// IPv6 fragment header.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ipv6FragmentHeader {
     /// IP protocol number specifying the next header or transport layer protocol.
     ///
@@ -37,6 +39,50 @@ impl Ipv6FragmentHeader {
         }
     }
 
+    /// Create a new fragmentation header with the fragment offset given in
+    /// bytes (instead of multiples of 8 octets as in [`Ipv6FragmentHeader::new`]).
+    ///
+    /// The offset is converted to 8 octet units internally. If `offset_bytes`
+    /// is not a multiple of 8 an [`err::ipv6_exts::FragOffsetError::Unaligned`]
+    /// error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{err::ipv6_exts::FragOffsetError, ip_number, Ipv6FragmentHeader};
+    ///
+    /// let header = Ipv6FragmentHeader::for_fragment(ip_number::UDP, 1232, true, 1234).unwrap();
+    /// assert_eq!(header.fragment_offset.value(), 154);
+    /// assert_eq!(header.offset_bytes(), 1232);
+    ///
+    /// // offsets must be a multiple of 8
+    /// assert_eq!(
+    ///     Ipv6FragmentHeader::for_fragment(ip_number::UDP, 1230, true, 1234),
+    ///     Err(FragOffsetError::Unaligned(1230))
+    /// );
+    /// ```
+    pub const fn for_fragment(
+        next_header: IpNumber,
+        offset_bytes: u16,
+        more_fragments: bool,
+        identification: u32,
+    ) -> Result<Ipv6FragmentHeader, err::ipv6_exts::FragOffsetError> {
+        if 0 != offset_bytes % 8 {
+            Err(err::ipv6_exts::FragOffsetError::Unaligned(offset_bytes))
+        } else {
+            Ok(Ipv6FragmentHeader {
+                next_header,
+                fragment_offset: unsafe {
+                    // SAFE as the maximum u16 value divided by 8 has at
+                    // most 13 bits.
+                    IpFragOffset::new_unchecked(offset_bytes / 8)
+                },
+                more_fragments,
+                identification,
+            })
+        }
+    }
+
     /// Read an Ipv6FragmentHeader from a slice and return the header & unused parts of the slice.
     pub fn from_slice(slice: &[u8]) -> Result<(Ipv6FragmentHeader, &[u8]), err::LenError> {
         let s = Ipv6FragmentHeaderSlice::from_slice(slice)?;
@@ -164,6 +210,55 @@ impl Ipv6FragmentHeader {
         self.more_fragments || (0 != self.fragment_offset.value())
     }
 
+    /// Returns the fragment offset in bytes (`fragment_offset * 8`).
+    #[inline]
+    pub const fn offset_bytes(&self) -> u16 {
+        self.fragment_offset.value() * 8
+    }
+
+    /// Returns the byte range the payload of this fragment occupies in
+    /// the reassembled (fragmentable part of the) original packet.
+    ///
+    /// The range starts at `fragment_offset * 8` and has the length
+    /// `payload_len`. For the last fragment (more fragments flag not set)
+    /// the end of the range is the length of the reassembled payload.
+    ///
+    /// # Errors
+    ///
+    /// [RFC 8200](https://datatracker.ietf.org/doc/html/rfc8200) requires
+    /// that the payload length of all fragments except the last one is
+    /// a multiple of 8. If the more fragments flag is set and `payload_len`
+    /// is not a multiple of 8, a [`err::reassembly::FragmentError::UnalignedLen`]
+    /// error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{Ipv6FragmentHeader, IpFragOffset, ip_number};
+    ///
+    /// let header = Ipv6FragmentHeader::new(
+    ///     ip_number::UDP,
+    ///     IpFragOffset::try_new(3).unwrap(),
+    ///     false, // last fragment
+    ///     1234,
+    /// );
+    /// assert_eq!(Ok(24..34), header.payload_range(10));
+    /// ```
+    pub fn payload_range(
+        &self,
+        payload_len: usize,
+    ) -> Result<core::ops::Range<usize>, err::reassembly::FragmentError> {
+        let offset = usize::from(self.fragment_offset.value()) * 8;
+        if self.more_fragments && 0 != payload_len % 8 {
+            Err(err::reassembly::FragmentError::UnalignedLen {
+                offset,
+                len: payload_len,
+            })
+        } else {
+            Ok(offset..offset + payload_len)
+        }
+    }
+
     /// Returns the serialized form of the header as a statically
     /// sized byte array.
     #[inline]
@@ -188,6 +283,17 @@ impl Ipv6FragmentHeader {
     }
 }
 
+impl TryFrom<&[u8]> for Ipv6FragmentHeader {
+    type Error = err::LenError;
+
+    /// Decodes the header from the start of the slice via
+    /// [`Ipv6FragmentHeader::from_slice`] (any data after the header is ignored).
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ipv6FragmentHeader::from_slice(value).map(|(header, _)| header)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{test_gens::*, *};
@@ -195,6 +301,16 @@ mod test {
     use proptest::prelude::*;
     use std::io::Cursor;
 
+    #[test]
+    fn default() {
+        let actual: Ipv6FragmentHeader = Default::default();
+        assert_eq!(actual.next_header, IpNumber(255));
+        assert_eq!(actual.fragment_offset, IpFragOffset::ZERO);
+        assert_eq!(actual.more_fragments, false);
+        assert_eq!(actual.identification, 0);
+        assert_eq!(false, actual.is_fragmenting_payload());
+    }
+
     proptest! {
         #[test]
         fn debug(input in ipv6_fragment_any()) {
@@ -239,6 +355,48 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn for_fragment(
+            next_header in ip_number_any(),
+            offset_bytes in any::<u16>(),
+            more_fragments in any::<bool>(),
+            identification in any::<u32>(),
+        ) {
+            let actual = Ipv6FragmentHeader::for_fragment(
+                next_header,
+                offset_bytes,
+                more_fragments,
+                identification
+            );
+            if 0 == offset_bytes % 8 {
+                let actual = actual.unwrap();
+                assert_eq!(
+                    actual,
+                    Ipv6FragmentHeader::new(
+                        next_header,
+                        IpFragOffset::try_new(offset_bytes / 8).unwrap(),
+                        more_fragments,
+                        identification
+                    )
+                );
+                assert_eq!(offset_bytes, actual.offset_bytes());
+            } else {
+                assert_eq!(
+                    actual.unwrap_err(),
+                    err::ipv6_exts::FragOffsetError::Unaligned(offset_bytes)
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn offset_bytes(input in ipv6_fragment_any()) {
+            assert_eq!(input.fragment_offset.value() * 8, input.offset_bytes());
+        }
+    }
+
     proptest! {
         #[test]
         fn from_slice(
@@ -272,6 +430,33 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn try_from(
+            input in ipv6_fragment_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(8 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // ok
+            assert_eq!(Ipv6FragmentHeader::try_from(&buffer[..]), Ok(input));
+
+            // error
+            assert_eq!(
+                Ipv6FragmentHeader::try_from(&buffer[..Ipv6FragmentHeader::LEN - 1]),
+                Err(err::LenError{
+                    required_len: Ipv6FragmentHeader::LEN,
+                    len: Ipv6FragmentHeader::LEN - 1,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Ipv6FragHeader,
+                    layer_start_offset: 0,
+                })
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn read(
@@ -338,6 +523,41 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn payload_range(
+            offset in 0u16..0b0001_1111_1111_1111u16,
+            identification in any::<u32>(),
+            next_header in ip_number_any(),
+            payload_len in 0usize..2000,
+        ) {
+            let mut header = Ipv6FragmentHeader::new(
+                next_header,
+                offset.try_into().unwrap(),
+                false,
+                identification
+            );
+            let start = usize::from(offset) * 8;
+
+            // last fragment (no alignment required)
+            assert_eq!(Ok(start..start + payload_len), header.payload_range(payload_len));
+
+            // non final fragment
+            header.more_fragments = true;
+            let aligned = payload_len - payload_len % 8;
+            assert_eq!(Ok(start..start + aligned), header.payload_range(aligned));
+            for unaligned in aligned + 1..aligned + 8 {
+                assert_eq!(
+                    Err(err::reassembly::FragmentError::UnalignedLen {
+                        offset: start,
+                        len: unaligned,
+                    }),
+                    header.payload_range(unaligned)
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn is_fragmenting_payload(
@@ -425,4 +645,28 @@ mod test {
             }
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_tokens, Token};
+        assert_tokens(
+            &Ipv6FragmentHeader::new(IpNumber::UDP, IpFragOffset::try_new(1).unwrap(), true, 2),
+            &[
+                Token::Struct {
+                    name: "Ipv6FragmentHeader",
+                    len: 4,
+                },
+                Token::Str("next_header"),
+                Token::U8(17),
+                Token::Str("fragment_offset"),
+                Token::U16(1),
+                Token::Str("more_fragments"),
+                Token::Bool(true),
+                Token::Str("identification"),
+                Token::U32(2),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -1,8 +1,12 @@
 use crate::{err::ValueTooBigError, *};
 
 /// IPv6 header according to rfc8200.
-#[derive(Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ipv6Header {
+    /// Traffic class containing the "Differentiated Services Code Point"
+    /// (upper 6 bits) & the "Explicit Congestion Notification" (lower 2 bits).
     pub traffic_class: u8,
     /// If non 0 serves as a hint to router and switches with multiple outbound paths that these packets should stay on the same path, so that they will not be reordered.
     pub flow_label: Ipv6FlowLabel,
@@ -56,6 +60,12 @@ impl Ipv6Header {
     ///
     /// for a laxer version which falls back to slice length when the `payload_length`
     /// contains an inconsistent value.
+    ///
+    /// The version field is checked before the length of the slice (same as
+    /// in [`crate::Ipv6HeaderSlice::from_slice`]). Data that does not start with
+    /// an IPv6 version number is therefore reported as an
+    /// [`crate::err::ipv6::HeaderError::UnexpectedVersion`] error even if it is
+    /// shorter than an IPv6 header.
     #[inline]
     pub fn from_slice(slice: &[u8]) -> Result<(Ipv6Header, &[u8]), err::ipv6::HeaderSliceError> {
         Ok((
@@ -288,20 +298,106 @@ impl Ipv6Header {
         writer.write_all(&self.to_bytes())
     }
 
-    /// Return the ipv6 source address as an std::net::Ipv6Addr
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Return the ipv6 source address as an core::net::Ipv6Addr
     #[inline]
-    pub fn source_addr(&self) -> std::net::Ipv6Addr {
-        std::net::Ipv6Addr::from(self.source)
+    pub fn source_addr(&self) -> core::net::Ipv6Addr {
+        core::net::Ipv6Addr::from(self.source)
     }
 
-    /// Return the ipv6 destination address as an std::net::Ipv6Addr
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Return the ipv6 destination address as an core::net::Ipv6Addr
+    #[inline]
+    pub fn destination_addr(&self) -> core::net::Ipv6Addr {
+        core::net::Ipv6Addr::from(self.destination)
+    }
+
+    /// Sets the source address from an core::net::Ipv6Addr.
+    ///
+    /// ```
+    /// use core::net::Ipv6Addr;
+    /// use etherparse::Ipv6Header;
+    ///
+    /// let mut header = Ipv6Header::default();
+    /// header.set_source_addr(Ipv6Addr::LOCALHOST);
+    /// header.set_destination_addr("2001:db8::1".parse().unwrap());
+    /// assert_eq!(header.source_addr(), Ipv6Addr::LOCALHOST);
+    /// assert_eq!(header.destination[..2], [0x20, 0x01]);
+    /// ```
+    #[inline]
+    pub fn set_source_addr(&mut self, addr: core::net::Ipv6Addr) {
+        self.source = addr.octets();
+    }
+
+    /// Sets the destination address from an core::net::Ipv6Addr.
+    #[inline]
+    pub fn set_destination_addr(&mut self, addr: core::net::Ipv6Addr) {
+        self.destination = addr.octets();
+    }
+
+    /// Returns the scope of the source address.
+    ///
+    /// ```
+    /// use etherparse::{Ipv6AddrScope, Ipv6Header};
+    ///
+    /// let header = Ipv6Header {
+    ///     source: [0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    ///     destination: [0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(Ipv6AddrScope::LinkLocal, header.source_scope());
+    /// assert_eq!(Ipv6AddrScope::Multicast, header.destination_scope());
+    /// assert!(header.is_source_link_local());
+    /// assert!(header.is_destination_multicast());
+    /// ```
+    #[inline]
+    pub const fn source_scope(&self) -> Ipv6AddrScope {
+        Ipv6AddrScope::from_octets(&self.source)
+    }
+
+    /// Returns the scope of the destination address.
+    #[inline]
+    pub const fn destination_scope(&self) -> Ipv6AddrScope {
+        Ipv6AddrScope::from_octets(&self.destination)
+    }
+
+    /// Returns true if the source address is a link local unicast
+    /// address (`fe80::/10`).
+    #[inline]
+    pub const fn is_source_link_local(&self) -> bool {
+        matches!(self.source_scope(), Ipv6AddrScope::LinkLocal)
+    }
+
+    /// Returns true if the destination address is a link local unicast
+    /// address (`fe80::/10`).
     #[inline]
-    pub fn destination_addr(&self) -> std::net::Ipv6Addr {
-        std::net::Ipv6Addr::from(self.destination)
+    pub const fn is_destination_link_local(&self) -> bool {
+        matches!(self.destination_scope(), Ipv6AddrScope::LinkLocal)
+    }
+
+    /// Returns true if the source address is a multicast address
+    /// (`ff00::/8`).
+    #[inline]
+    pub const fn is_source_multicast(&self) -> bool {
+        matches!(self.source_scope(), Ipv6AddrScope::Multicast)
+    }
+
+    /// Returns true if the destination address is a multicast address
+    /// (`ff00::/8`).
+    #[inline]
+    pub const fn is_destination_multicast(&self) -> bool {
+        matches!(self.destination_scope(), Ipv6AddrScope::Multicast)
+    }
+
+    /// Returns true if the source address is the loopback address (`::1`).
+    #[inline]
+    pub const fn is_source_loopback(&self) -> bool {
+        matches!(self.source_scope(), Ipv6AddrScope::Loopback)
+    }
+
+    /// Returns true if the destination address is the loopback address
+    /// (`::1`).
+    #[inline]
+    pub const fn is_destination_loopback(&self) -> bool {
+        matches!(self.destination_scope(), Ipv6AddrScope::Loopback)
     }
 
     /// Length of the serialized header in bytes.
@@ -330,6 +426,129 @@ impl Ipv6Header {
         Ok(())
     }
 
+    /// Sets the "payload length" field to the given length of the payload
+    /// (extension headers & upper layer data after the IPv6 header).
+    ///
+    /// If the length can not be represented in the 16 bit "payload length"
+    /// field an [`err::ipv6::PayloadLenError::PayloadTooLargeForField`] error
+    /// is returned & the field is left unchanged. Payloads of this size have
+    /// to be sent as jumbograms (see
+    /// [RFC 2675](https://datatracker.ietf.org/doc/html/rfc2675)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{err::ipv6::PayloadLenError, Ipv6Header};
+    ///
+    /// let mut header = Ipv6Header::default();
+    /// header.set_payload_len(1234).unwrap();
+    /// assert_eq!(header.payload_len(), 1234);
+    ///
+    /// // too large for the field (requires a jumbogram)
+    /// assert_eq!(
+    ///     header.set_payload_len(0x1_0000),
+    ///     Err(PayloadLenError::PayloadTooLargeForField { payload_len: 0x1_0000 })
+    /// );
+    /// assert_eq!(header.payload_len(), 1234);
+    /// ```
+    pub fn set_payload_len(&mut self, len: usize) -> Result<(), err::ipv6::PayloadLenError> {
+        if usize::from(u16::MAX) < len {
+            Err(err::ipv6::PayloadLenError::PayloadTooLargeForField { payload_len: len })
+        } else {
+            self.payload_length = len as u16;
+            Ok(())
+        }
+    }
+
+    /// Returns the value of the "payload length" field (length of the
+    /// extension headers & upper layer data after the IPv6 header).
+    ///
+    /// Note that the field is zero for jumbograms, in which case the
+    /// length is stored in the "Jumbo Payload" option of the hop-by-hop
+    /// header.
+    #[inline]
+    pub fn payload_len(&self) -> u16 {
+        self.payload_length
+    }
+
+    /// Returns the 40 byte pseudo header used when calculating the
+    /// checksum of an upper layer protocol (e.g. UDP, TCP or ICMPv6) over
+    /// IPv6.
+    ///
+    /// The pseudo header consists of the source address, destination
+    /// address, the 32 bit `payload_len` (length of the upper layer header &
+    /// payload), three zero bytes and the `next_header` (ip number of the
+    /// upper layer protocol). An error is returned if `payload_len` does
+    /// not fit into the 32 bit length field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6Header};
+    ///
+    /// let header = Ipv6Header {
+    ///     source: [1; 16],
+    ///     destination: [2; 16],
+    ///     ..Default::default()
+    /// };
+    /// let pseudo_header = header.pseudo_header_bytes(ip_number::UDP, 12).unwrap();
+    /// assert_eq!(&pseudo_header[..16], &[1; 16]);
+    /// assert_eq!(&pseudo_header[16..32], &[2; 16]);
+    /// assert_eq!(&pseudo_header[32..], &[0, 0, 0, 12, 0, 0, 0, 17]);
+    /// ```
+    pub fn pseudo_header_bytes(
+        &self,
+        next_header: IpNumber,
+        payload_len: usize,
+    ) -> Result<[u8; 40], ValueTooBigError<usize>> {
+        use crate::err::ValueType;
+        const MAX_PAYLOAD_LEN: usize = u32::MAX as usize;
+        if MAX_PAYLOAD_LEN < payload_len {
+            return Err(ValueTooBigError {
+                actual: payload_len,
+                max_allowed: MAX_PAYLOAD_LEN,
+                value_type: ValueType::PseudoHeaderPayloadLengthIpv6,
+            });
+        }
+        let mut result = [0u8; 40];
+        result[..16].copy_from_slice(&self.source);
+        result[16..32].copy_from_slice(&self.destination);
+        result[32..36].copy_from_slice(&(payload_len as u32).to_be_bytes());
+        result[39] = next_header.0;
+        Ok(result)
+    }
+
+    /// Returns the "Differentiated Services Code Point" stored in the
+    /// upper 6 bits of the traffic class.
+    #[inline]
+    pub fn dscp(&self) -> Ipv4Dscp {
+        // SAFETY: Safe as the bitshift by 2 guarantees that the
+        //         value is not bigger then 6 bits.
+        unsafe { Ipv4Dscp::new_unchecked(self.traffic_class >> 2) }
+    }
+
+    /// Returns the "Explicit Congestion Notification" stored in the
+    /// lower 2 bits of the traffic class.
+    #[inline]
+    pub fn ecn(&self) -> Ipv4Ecn {
+        // SAFETY: Safe as value has been bitmasked to two bits.
+        unsafe { Ipv4Ecn::new_unchecked(self.traffic_class & 0b0000_0011) }
+    }
+
+    /// Sets the "Differentiated Services Code Point" (upper 6 bits of
+    /// the traffic class) without changing the ECN bits.
+    #[inline]
+    pub fn set_dscp(&mut self, dscp: Ipv4Dscp) {
+        self.traffic_class = (dscp.value() << 2) | (self.traffic_class & 0b0000_0011);
+    }
+
+    /// Sets the "Explicit Congestion Notification" (lower 2 bits of
+    /// the traffic class) without changing the DSCP bits.
+    #[inline]
+    pub fn set_ecn(&mut self, ecn: Ipv4Ecn) {
+        self.traffic_class = (self.traffic_class & 0b1111_1100) | ecn.value();
+    }
+
     /// Returns the serialized form of the header as a statically
     /// sized byte array.
     #[rustfmt::skip]
@@ -357,6 +576,91 @@ impl Ipv6Header {
             self.destination[12], self.destination[13], self.destination[14], self.destination[15],
         ]
     }
+
+    /// Compares two headers while skipping the fields selected in
+    /// `ignore` (e.g. to compare a packet before & after it passed a
+    /// router). `PartialEq` still compares all fields.
+    ///
+    /// [`IpIgnoreFields::TTL`] skips the hop limit & [`IpIgnoreFields::TOS`]
+    /// the traffic class. [`IpIgnoreFields::CHECKSUM`] has no effect as
+    /// IPv6 headers contain no checksum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{IpIgnoreFields, Ipv6Header};
+    ///
+    /// let a = Ipv6Header {
+    ///     hop_limit: 64,
+    ///     ..Default::default()
+    /// };
+    /// let b = Ipv6Header {
+    ///     hop_limit: 63,
+    ///     ..a.clone()
+    /// };
+    /// assert!(b.equals_ignoring(&a, IpIgnoreFields::TTL));
+    /// assert!(false == b.equals_ignoring(&a, IpIgnoreFields::TOS));
+    /// ```
+    pub fn equals_ignoring(&self, other: &Ipv6Header, ignore: IpIgnoreFields) -> bool {
+        (ignore.contains(IpIgnoreFields::TOS) || self.traffic_class == other.traffic_class)
+            && (ignore.contains(IpIgnoreFields::TTL) || self.hop_limit == other.hop_limit)
+            && self.flow_label == other.flow_label
+            && self.payload_length == other.payload_length
+            && self.next_header == other.next_header
+            && self.source == other.source
+            && self.destination == other.destination
+    }
+}
+
+impl TryFrom<&[u8]> for Ipv6Header {
+    type Error = err::ipv6::HeaderSliceError;
+
+    /// Decodes the header from the start of the slice via
+    /// [`Ipv6Header::from_slice`] (any data after the header is ignored).
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ipv6Header::from_slice(value).map(|(header, _)| header)
+    }
+}
+
+/// Writes a compact single line summary of the header (addresses in
+/// colon separated hex notation).
+///
+/// ```
+/// use etherparse::{ip_number, Ipv6Header};
+///
+/// let header = Ipv6Header {
+///     payload_length: 20,
+///     next_header: ip_number::UDP,
+///     hop_limit: 64,
+///     source: [0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+///     destination: [0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2],
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     "IPv6 fe80::1 -> fe80::2 next=UDP len=20 hop_limit=64",
+///     format!("{}", header)
+/// );
+/// ```
+impl core::fmt::Display for Ipv6Header {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "IPv6 {} -> {}",
+            core::net::Ipv6Addr::from(self.source),
+            core::net::Ipv6Addr::from(self.destination)
+        )?;
+        if let Some(keyword) = self.next_header.keyword_str() {
+            write!(f, " next={}", keyword)?;
+        } else {
+            write!(f, " next={}", self.next_header.0)?;
+        }
+        write!(
+            f,
+            " len={} hop_limit={}",
+            self.payload_length, self.hop_limit
+        )
+    }
 }
 
 #[cfg(test)]
@@ -364,7 +668,7 @@ mod test {
     use crate::{
         err::ipv6::HeaderError::*, err::ipv6::HeaderSliceError::*, ip_number::*, test_gens::*, *,
     };
-    use alloc::format;
+    use alloc::{format, vec::Vec};
     use arrayvec::ArrayVec;
     use proptest::*;
     use std::io::Cursor;
@@ -399,6 +703,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn display() {
+        let header = Ipv6Header {
+            payload_length: 1234,
+            next_header: ip_number::TCP,
+            hop_limit: 2,
+            source: [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+            destination: [0; 16],
+            ..Default::default()
+        };
+        assert_eq!(
+            "IPv6 2001:db8::1 -> :: next=TCP len=1234 hop_limit=2",
+            format!("{}", header)
+        );
+
+        // unknown next header
+        let header = Ipv6Header {
+            next_header: IpNumber(253),
+            ..header
+        };
+        assert_eq!(
+            "IPv6 2001:db8::1 -> :: next=253 len=1234 hop_limit=2",
+            format!("{}", header)
+        );
+    }
+
     proptest! {
         #[test]
         fn clone_eq(header in ipv6_any()) {
@@ -406,6 +736,25 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn hash(header in ipv6_any()) {
+            use std::collections::hash_map::DefaultHasher;
+            use core::hash::{Hash, Hasher};
+            let a = {
+                let mut hasher = DefaultHasher::new();
+                header.hash(&mut hasher);
+                hasher.finish()
+            };
+            let b = {
+                let mut hasher = DefaultHasher::new();
+                header.clone().hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(a, b);
+        }
+    }
+
     proptest! {
         #[test]
         #[allow(deprecated)]
@@ -499,6 +848,34 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn try_from(header in ipv6_any()) {
+            // ok
+            {
+                let mut bytes = Vec::with_capacity(Ipv6Header::LEN + 1);
+                bytes.extend_from_slice(&header.to_bytes());
+                bytes.push(1);
+                assert_eq!(Ipv6Header::try_from(&bytes[..]), Ok(header.clone()));
+            }
+
+            // error
+            {
+                let bytes = header.to_bytes();
+                assert_eq!(
+                    Ipv6Header::try_from(&bytes[..Ipv6Header::LEN - 1]),
+                    Err(err::ipv6::HeaderSliceError::Len(err::LenError{
+                        required_len: Ipv6Header::LEN,
+                        len: Ipv6Header::LEN - 1,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::Ipv6Header,
+                        layer_start_offset: 0,
+                    }))
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn read(
@@ -1013,6 +1390,232 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn set_addrs(
+            header in ipv6_any(),
+            source in proptest::prelude::any::<[u8;16]>(),
+            destination in proptest::prelude::any::<[u8;16]>()
+        ) {
+            use core::net::Ipv6Addr;
+
+            let mut header = header.clone();
+            header.set_source_addr(Ipv6Addr::from(source));
+            header.set_destination_addr(Ipv6Addr::from(destination));
+            assert_eq!(header.source, source);
+            assert_eq!(header.destination, destination);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn scope(header in ipv6_any()) {
+            let source = Ipv6AddrScope::from_octets(&header.source);
+            let destination = Ipv6AddrScope::from_octets(&header.destination);
+            assert_eq!(source, header.source_scope());
+            assert_eq!(destination, header.destination_scope());
+            assert_eq!(source == Ipv6AddrScope::LinkLocal, header.is_source_link_local());
+            assert_eq!(destination == Ipv6AddrScope::LinkLocal, header.is_destination_link_local());
+            assert_eq!(source == Ipv6AddrScope::Multicast, header.is_source_multicast());
+            assert_eq!(destination == Ipv6AddrScope::Multicast, header.is_destination_multicast());
+            assert_eq!(source == Ipv6AddrScope::Loopback, header.is_source_loopback());
+            assert_eq!(destination == Ipv6AddrScope::Loopback, header.is_destination_loopback());
+
+            // consistent with the core::net classification
+            assert_eq!(header.source_addr().is_multicast(), header.is_source_multicast());
+            assert_eq!(header.source_addr().is_loopback(), header.is_source_loopback());
+            assert_eq!(
+                header.destination_addr().is_unspecified(),
+                destination == Ipv6AddrScope::Unspecified
+            );
+        }
+    }
+
+    #[test]
+    fn scope_helpers() {
+        let link_local = [0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        let multicast = [0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        let loopback = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+        let header = Ipv6Header {
+            source: link_local,
+            destination: multicast,
+            ..Default::default()
+        };
+        assert!(header.is_source_link_local());
+        assert!(false == header.is_source_multicast());
+        assert!(false == header.is_source_loopback());
+        assert!(header.is_destination_multicast());
+        assert!(false == header.is_destination_link_local());
+        assert!(false == header.is_destination_loopback());
+
+        let header = Ipv6Header {
+            source: multicast,
+            destination: loopback,
+            ..Default::default()
+        };
+        assert!(header.is_source_multicast());
+        assert!(header.is_destination_loopback());
+
+        let header = Ipv6Header {
+            source: loopback,
+            destination: link_local,
+            ..Default::default()
+        };
+        assert!(header.is_source_loopback());
+        assert!(header.is_destination_link_local());
+    }
+
+    proptest! {
+        #[test]
+        fn set_payload_len(
+            header in ipv6_any(),
+            len in proptest::prelude::any::<u16>(),
+            too_big in (usize::from(u16::MAX) + 1)..usize::MAX,
+        ) {
+            // ok
+            {
+                let mut header = header.clone();
+                header.set_payload_len(len.into()).unwrap();
+                assert_eq!(header.payload_length, len);
+                assert_eq!(header.payload_len(), len);
+            }
+            // too big for the field
+            {
+                let mut actual = header.clone();
+                assert_eq!(
+                    actual.set_payload_len(too_big),
+                    Err(err::ipv6::PayloadLenError::PayloadTooLargeForField {
+                        payload_len: too_big
+                    })
+                );
+                assert_eq!(actual, header);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn pseudo_header_bytes(
+            header in ipv6_any(),
+            next_header in ip_number_any(),
+            payload in proptest::collection::vec(proptest::prelude::any::<u8>(), 0..64)
+        ) {
+            // ok
+            {
+                let len = UdpHeader::LEN + payload.len();
+                let actual = header.pseudo_header_bytes(next_header, len).unwrap();
+                assert_eq!(&actual[..16], &header.source);
+                assert_eq!(&actual[16..32], &header.destination);
+                assert_eq!(&actual[32..36], &(len as u32).to_be_bytes());
+                assert_eq!(&actual[36..], &[0, 0, 0, next_header.0]);
+            }
+
+            // matches the udp checksum calculation
+            {
+                let udp = UdpHeader {
+                    source_port: 1234,
+                    destination_port: 5678,
+                    length: (UdpHeader::LEN + payload.len()) as u16,
+                    checksum: 0,
+                };
+                let pseudo_header = header
+                    .pseudo_header_bytes(ip_number::UDP, UdpHeader::LEN + payload.len())
+                    .unwrap();
+                let expected = checksum::Sum16BitWords::new()
+                    .add_slice(&pseudo_header)
+                    .add_slice(&udp.to_bytes())
+                    .add_slice(&payload)
+                    .to_ones_complement_with_no_zero()
+                    .to_be();
+                assert_eq!(expected, udp.calc_checksum_ipv6(&header, &payload).unwrap());
+            }
+
+            // max len
+            assert!(header.pseudo_header_bytes(next_header, u32::MAX as usize).is_ok());
+
+            // len too big
+            #[cfg(target_pointer_width = "64")]
+            assert_eq!(
+                header.pseudo_header_bytes(next_header, u32::MAX as usize + 1).unwrap_err(),
+                err::ValueTooBigError {
+                    actual: u32::MAX as usize + 1,
+                    max_allowed: u32::MAX as usize,
+                    value_type: err::ValueType::PseudoHeaderPayloadLengthIpv6,
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn dscp_ecn(
+            header in ipv6_any(),
+            dscp in 0u8..=Ipv4Dscp::MAX_U8,
+            ecn in 0u8..=Ipv4Ecn::MAX_U8
+        ) {
+            // getters
+            assert_eq!(header.dscp().value(), header.traffic_class >> 2);
+            assert_eq!(header.ecn().value(), header.traffic_class & 0b11);
+
+            // set dscp
+            {
+                let mut h = header.clone();
+                h.set_dscp(Ipv4Dscp::try_new(dscp).unwrap());
+                assert_eq!(h.dscp().value(), dscp);
+                assert_eq!(h.ecn(), header.ecn());
+                assert_eq!(h.traffic_class, (dscp << 2) | (header.traffic_class & 0b11));
+            }
+
+            // set ecn
+            {
+                let mut h = header.clone();
+                h.set_ecn(Ipv4Ecn::try_new(ecn).unwrap());
+                assert_eq!(h.ecn().value(), ecn);
+                assert_eq!(h.dscp(), header.dscp());
+                assert_eq!(h.traffic_class, (header.traffic_class & 0b1111_1100) | ecn);
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn equals_ignoring(header in ipv6_any()) {
+            use IpIgnoreFields as I;
+
+            // identical headers
+            assert!(header.equals_ignoring(&header, I::NONE));
+            assert!(header.equals_ignoring(&header, I::ALL));
+
+            // hop limit
+            {
+                let mut other = header.clone();
+                other.hop_limit = other.hop_limit.wrapping_add(1);
+                assert!(false == header.equals_ignoring(&other, I::NONE));
+                assert!(false == header.equals_ignoring(&other, I::CHECKSUM | I::TOS));
+                assert!(header.equals_ignoring(&other, I::TTL));
+            }
+            // traffic class
+            {
+                let mut other = header.clone();
+                other.traffic_class = other.traffic_class.wrapping_add(1);
+                assert!(false == header.equals_ignoring(&other, I::NONE));
+                assert!(false == header.equals_ignoring(&other, I::CHECKSUM | I::TTL));
+                assert!(header.equals_ignoring(&other, I::TOS));
+            }
+            // other fields are always compared
+            {
+                let mut other = header.clone();
+                other.payload_length = other.payload_length.wrapping_add(1);
+                assert!(false == header.equals_ignoring(&other, I::ALL));
+
+                let mut other = header.clone();
+                other.destination[15] = other.destination[15].wrapping_add(1);
+                assert!(false == header.equals_ignoring(&other, I::ALL));
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn to_bytes(header in ipv6_any()) {
@@ -1023,4 +1626,54 @@ mod test {
             );
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_tokens, Token};
+        let header = Ipv6Header {
+            traffic_class: 1,
+            flow_label: Ipv6FlowLabel::try_new(2).unwrap(),
+            payload_length: 3,
+            next_header: IpNumber::TCP,
+            hop_limit: 4,
+            source: [5; 16],
+            destination: [6; 16],
+        };
+        let mut tokens = alloc::vec![
+            Token::Struct {
+                name: "Ipv6Header",
+                len: 7,
+            },
+            Token::Str("traffic_class"),
+            Token::U8(1),
+            Token::Str("flow_label"),
+            Token::U32(2),
+            Token::Str("payload_length"),
+            Token::U16(3),
+            Token::Str("next_header"),
+            Token::U8(6),
+            Token::Str("hop_limit"),
+            Token::U8(4),
+        ];
+        for (name, value) in [("source", 5), ("destination", 6)] {
+            tokens.push(Token::Str(name));
+            tokens.push(Token::Tuple { len: 16 });
+            tokens.extend([Token::U8(value); 16]);
+            tokens.push(Token::TupleEnd);
+        }
+        tokens.push(Token::StructEnd);
+        assert_tokens(&header, &tokens);
+    }
+
+    #[test]
+    fn from_slice_version_before_len() {
+        // IPv4 version (e.g. a mixed up buffer) shorter than an IPv6 header
+        for data in [&[0x45u8][..], &[0x45, 0, 0, 0][..]] {
+            assert_eq!(
+                Ipv6Header::from_slice(data).unwrap_err(),
+                Content(UnexpectedVersion { version_number: 4 })
+            );
+        }
+    }
 }
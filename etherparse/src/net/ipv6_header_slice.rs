@@ -9,9 +9,25 @@ pub struct Ipv6HeaderSlice<'a> {
 
 impl<'a> Ipv6HeaderSlice<'a> {
     /// Creates a slice containing an ipv6 header (without header extensions).
+    ///
+    /// The version field is checked before the length of the slice. Data that
+    /// does not start with an IPv6 version number (e.g. because it was
+    /// byte-swapped) is therefore reported as an
+    /// [`err::ipv6::HeaderError::UnexpectedVersion`] error even if it is shorter
+    /// than an IPv6 header.
     pub fn from_slice(slice: &'a [u8]) -> Result<Ipv6HeaderSlice<'a>, err::ipv6::HeaderSliceError> {
         use err::ipv6::{HeaderError::*, HeaderSliceError::*};
 
+        // check version (done before the length check so that data that
+        // is not an IPv6 header at all, e.g. because of a wrong byte order,
+        // is not reported as a truncated IPv6 header)
+        if let Some(first) = slice.first() {
+            let version_number = first >> 4;
+            if 6 != version_number {
+                return Err(Content(UnexpectedVersion { version_number }));
+            }
+        }
+
         // check length
         if slice.len() < Ipv6Header::LEN {
             return Err(Len(err::LenError {
@@ -23,18 +39,6 @@ impl<'a> Ipv6HeaderSlice<'a> {
             }));
         }
 
-        // read version & ihl
-        //
-        // SAFETY:
-        // This is safe as the slice len is checked to be
-        // at least 40 bytes at the start of the function.
-        let version_number = unsafe { slice.get_unchecked(0) >> 4 };
-
-        // check version
-        if 6 != version_number {
-            return Err(Content(UnexpectedVersion { version_number }));
-        }
-
         // all good
         Ok(Ipv6HeaderSlice {
             // SAFETY:
@@ -86,6 +90,25 @@ impl<'a> Ipv6HeaderSlice<'a> {
         unsafe { (self.slice.get_unchecked(0) << 4) | (self.slice.get_unchecked(1) >> 4) }
     }
 
+    /// Read the "Differentiated Services Code Point" (upper 6 bits of
+    /// the traffic class) from the slice.
+    #[inline]
+    pub fn dscp(&self) -> Ipv4Dscp {
+        // SAFETY:
+        // Safe as the bitshift by 2 guarantees that the
+        // value is not bigger then 6 bits.
+        unsafe { Ipv4Dscp::new_unchecked(self.traffic_class() >> 2) }
+    }
+
+    /// Read the "Explicit Congestion Notification" (lower 2 bits of
+    /// the traffic class) from the slice.
+    #[inline]
+    pub fn ecn(&self) -> Ipv4Ecn {
+        // SAFETY:
+        // Safe as value has been bitmasked to two bits.
+        unsafe { Ipv4Ecn::new_unchecked(self.traffic_class() & 0b0000_0011) }
+    }
+
     /// Read the "flow label" field from the slice.
     #[inline]
     pub fn flow_label(&self) -> Ipv6FlowLabel {
@@ -147,12 +170,10 @@ impl<'a> Ipv6HeaderSlice<'a> {
         unsafe { get_unchecked_16_byte_array(self.slice.as_ptr().add(8)) }
     }
 
-    /// Return the ipv6 source address as an std::net::Ipv6Addr
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Return the ipv6 source address as an core::net::Ipv6Addr
     #[inline]
-    pub fn source_addr(&self) -> std::net::Ipv6Addr {
-        std::net::Ipv6Addr::from(self.source())
+    pub fn source_addr(&self) -> core::net::Ipv6Addr {
+        core::net::Ipv6Addr::from(self.source())
     }
 
     /// Returns a slice containing the IPv6 destination address.
@@ -165,12 +186,10 @@ impl<'a> Ipv6HeaderSlice<'a> {
         unsafe { get_unchecked_16_byte_array(self.slice.as_ptr().add(24)) }
     }
 
-    /// Return the ipv6 destination address as an std::net::Ipv6Addr
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    /// Return the ipv6 destination address as an core::net::Ipv6Addr
     #[inline]
-    pub fn destination_addr(&self) -> std::net::Ipv6Addr {
-        std::net::Ipv6Addr::from(self.destination())
+    pub fn destination_addr(&self) -> core::net::Ipv6Addr {
+        core::net::Ipv6Addr::from(self.destination())
     }
 
     /// Decode all the fields and copy the results to a Ipv6Header struct
@@ -190,12 +209,73 @@ impl<'a> Ipv6HeaderSlice<'a> {
     pub const fn header_len(&self) -> usize {
         Ipv6Header::LEN
     }
+
+    /// Walks the extension header chain in `following` (the data directly
+    /// after the IPv6 header) starting with the `next_header` field of this
+    /// header and returns the slice containing all extension headers, the
+    /// IP number of the first non extension header (e.g. the transport
+    /// protocol) & the payload after the extension headers.
+    ///
+    /// A mobility header terminates the chain and is included in the
+    /// returned extension header slice (the returned IP number is then its
+    /// "payload proto" field).
+    ///
+    /// No data is copied, the returned slices are sub-slices of `following`.
+    /// The same guards as in [`Ipv6ExtensionsIter`] apply: at most
+    /// [`Ipv6ExtensionsIter::MAX_HEADERS`] extension headers are walked before
+    /// an [`err::ipv6_exts::HeaderError::TooManyHeaders`] error is returned
+    /// and an error is returned if an extension header exceeds the length of
+    /// `following`. The offsets in the errors are relative to the start of
+    /// `following`.
+    ///
+    /// Note that `following` is not limited to the `payload_length` of the
+    /// header. If the slice could contain trailing data, limit it before
+    /// calling this method (or use [`Ipv6Slice`] which does this for you).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6Header, Ipv6HeaderSlice, Ipv6RawExtHeader};
+    ///
+    /// let ext = Ipv6RawExtHeader::new_raw(ip_number::UDP, &[0; 6]).unwrap();
+    /// let header = Ipv6Header {
+    ///     next_header: ip_number::IPV6_DEST_OPTIONS,
+    ///     payload_length: (ext.header_len() + 4) as u16,
+    ///     ..Default::default()
+    /// };
+    /// let mut packet = header.to_bytes().to_vec();
+    /// packet.extend_from_slice(&ext.to_bytes());
+    /// packet.extend_from_slice(&[1, 2, 3, 4]);
+    ///
+    /// let slice = Ipv6HeaderSlice::from_slice(&packet).unwrap();
+    /// let (exts, payload_ip_number, payload) = slice
+    ///     .extension_header_slice(&packet[slice.header_len()..])
+    ///     .unwrap();
+    /// assert_eq!(exts, &ext.to_bytes()[..]);
+    /// assert_eq!(payload_ip_number, ip_number::UDP);
+    /// assert_eq!(payload, &[1, 2, 3, 4]);
+    /// ```
+    pub fn extension_header_slice(
+        &self,
+        following: &'a [u8],
+    ) -> Result<(&'a [u8], IpNumber, &'a [u8]), err::ipv6_exts::HeaderSliceError> {
+        let mut iter = Ipv6ExtensionsIter::new(self.next_header(), following);
+        for ext in iter.by_ref() {
+            ext?;
+        }
+        let rest = iter.rest();
+        Ok((
+            &following[..following.len() - rest.len()],
+            iter.next_header(),
+            rest,
+        ))
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::{err::ipv6::HeaderError::*, err::ipv6::HeaderSliceError::*, test_gens::*, *};
-    use alloc::format;
+    use alloc::{format, vec::Vec};
     use proptest::*;
 
     #[test]
@@ -241,6 +321,13 @@ mod test {
                     Ipv6HeaderSlice::from_slice(&bytes).unwrap_err(),
                     Content(UnexpectedVersion{ version_number: bad_version })
                 );
+                // version errors take precedence over length errors
+                for len in 1..bytes.len() {
+                    assert_eq!(
+                        Ipv6HeaderSlice::from_slice(&bytes[..len]).unwrap_err(),
+                        Content(UnexpectedVersion{ version_number: bad_version })
+                    );
+                }
             }
 
             // length error
@@ -282,6 +369,8 @@ mod test {
             assert_eq!(actual.slice(), &bytes[..]);
             assert_eq!(actual.version(), 6);
             assert_eq!(actual.traffic_class(), header.traffic_class);
+            assert_eq!(actual.dscp(), header.dscp());
+            assert_eq!(actual.ecn(), header.ecn());
             assert_eq!(actual.flow_label(), header.flow_label);
             assert_eq!(actual.payload_length(), header.payload_length);
             assert_eq!(actual.next_header(), header.next_header);
@@ -291,14 +380,13 @@ mod test {
         }
     }
 
-    #[cfg(feature = "std")]
     proptest! {
         #[test]
         fn getters_std(header in ipv6_any()) {
             let bytes = header.to_bytes();
             let actual = Ipv6HeaderSlice::from_slice(&bytes).unwrap();
-            assert_eq!(actual.source_addr(), std::net::Ipv6Addr::from(header.source));
-            assert_eq!(actual.destination_addr(), std::net::Ipv6Addr::from(header.destination));
+            assert_eq!(actual.source_addr(), core::net::Ipv6Addr::from(header.source));
+            assert_eq!(actual.destination_addr(), core::net::Ipv6Addr::from(header.destination));
         }
     }
 
@@ -310,4 +398,74 @@ mod test {
             assert_eq!(actual.to_header(), header);
         }
     }
+
+    #[test]
+    fn extension_header_slice() {
+        use err::ipv6_exts::{HeaderError::*, HeaderSliceError::*};
+        use ip_number::*;
+
+        let header_bytes = |next_header: IpNumber| {
+            Ipv6Header {
+                next_header,
+                ..Default::default()
+            }
+            .to_bytes()
+        };
+
+        // no extension headers
+        {
+            let bytes = header_bytes(UDP);
+            let slice = Ipv6HeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                slice.extension_header_slice(&[1, 2]),
+                Ok((&[][..], UDP, &[1u8, 2][..]))
+            );
+        }
+
+        // multiple extension headers
+        {
+            let hop_by_hop = Ipv6RawExtHeader::new_raw(IPV6_ROUTE, &[0; 6]).unwrap();
+            let routing = Ipv6RawExtHeader::new_raw(TCP, &[1; 14]).unwrap();
+            let mut following = Vec::new();
+            following.extend_from_slice(&hop_by_hop.to_bytes());
+            following.extend_from_slice(&routing.to_bytes());
+            following.extend_from_slice(&[3, 4, 5]);
+
+            let bytes = header_bytes(IPV6_HOP_BY_HOP);
+            let slice = Ipv6HeaderSlice::from_slice(&bytes).unwrap();
+            let exts_len = hop_by_hop.header_len() + routing.header_len();
+            assert_eq!(
+                slice.extension_header_slice(&following),
+                Ok((&following[..exts_len], TCP, &following[exts_len..]))
+            );
+
+            // length error
+            for len in 0..exts_len {
+                let err = slice.extension_header_slice(&following[..len]).unwrap_err();
+                assert!(matches!(err, Len(_)));
+                assert_eq!(
+                    Some(Err(err)),
+                    Ipv6ExtensionsIter::new(IPV6_HOP_BY_HOP, &following[..len])
+                        .find(|e| e.is_err())
+                );
+            }
+        }
+
+        // too many headers
+        {
+            let dest = Ipv6RawExtHeader::new_raw(IPV6_DEST_OPTIONS, &[0; 6]).unwrap();
+            let mut following = Vec::new();
+            for _ in 0..=Ipv6ExtensionsIter::MAX_HEADERS {
+                following.extend_from_slice(&dest.to_bytes());
+            }
+            let bytes = header_bytes(IPV6_DEST_OPTIONS);
+            let slice = Ipv6HeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                slice.extension_header_slice(&following),
+                Err(Content(TooManyHeaders {
+                    max: Ipv6ExtensionsIter::MAX_HEADERS
+                }))
+            );
+        }
+    }
 }
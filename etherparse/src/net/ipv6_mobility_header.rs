@@ -0,0 +1,467 @@
+use super::super::*;
+use crate::err::ipv6_exts::ExtPayloadLenError;
+use arrayvec::ArrayVec;
+use core::fmt::{Debug, Formatter};
+
+/// IPv6 Mobility header (next header value 135) as defined in
+/// [RFC 6275](https://datatracker.ietf.org/doc/html/rfc6275#section-6.1).
+///
+/// The Mobility header is used by Mobile IPv6 signaling messages
+/// (e.g. binding updates). It is the last header in an IPv6 packet
+/// (the `next_header` field is normally set to
+/// [`ip_number::IPV6_NO_NEXT_HEADER`]) and the message specific data is
+/// stored undecoded (see [`Ipv6MobilityHeader::message_data`]).
+#[derive(Clone)]
+pub struct Ipv6MobilityHeader {
+    /// IP protocol number of the header directly following the mobility
+    /// header ("Payload Proto" field, normally [`ip_number::IPV6_NO_NEXT_HEADER`]).
+    pub next_header: IpNumber,
+    /// Length of the mobility header in 8 octets (minus the first 8 octets).
+    header_length: u8,
+    /// Type of the mobility message (see the `Ipv6MobilityHeader::*`
+    /// constants for the known values).
+    pub mh_type: u8,
+    /// Reserved field (should be zero).
+    pub reserved: u8,
+    /// Checksum of the mobility header (calculated including an
+    /// IPv6 pseudo header, see [`Ipv6MobilityHeader::calc_checksum`]).
+    pub checksum: u16,
+    /// The message data contained in the header (everything after the checksum).
+    message_data_buffer: [u8; Ipv6MobilityHeader::MAX_MESSAGE_DATA_LEN],
+}
+
+impl Debug for Ipv6MobilityHeader {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), core::fmt::Error> {
+        let mut s = f.debug_struct("Ipv6MobilityHeader");
+        s.field("next_header", &self.next_header);
+        s.field("mh_type", &self.mh_type);
+        s.field("reserved", &self.reserved);
+        s.field("checksum", &self.checksum);
+        s.field("message_data", &self.message_data());
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Ipv6MobilityHeader {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Ipv6MobilityHeader {{ next_header: {}, mh_type: {}, reserved: {}, checksum: {}, message_data: {=[u8]} }}",
+            self.next_header,
+            self.mh_type,
+            self.reserved,
+            self.checksum,
+            self.message_data()
+        )
+    }
+}
+
+impl PartialEq for Ipv6MobilityHeader {
+    fn eq(&self, other: &Self) -> bool {
+        self.next_header == other.next_header
+            && self.mh_type == other.mh_type
+            && self.reserved == other.reserved
+            && self.checksum == other.checksum
+            && self.message_data() == other.message_data()
+    }
+}
+
+impl Eq for Ipv6MobilityHeader {}
+
+impl core::hash::Hash for Ipv6MobilityHeader {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.next_header.hash(state);
+        self.mh_type.hash(state);
+        self.reserved.hash(state);
+        self.checksum.hash(state);
+        self.message_data().hash(state);
+    }
+}
+
+impl Default for Ipv6MobilityHeader {
+    fn default() -> Self {
+        Ipv6MobilityHeader {
+            next_header: ip_number::IPV6_NO_NEXT_HEADER,
+            header_length: 0,
+            mh_type: 0,
+            reserved: 0,
+            checksum: 0,
+            message_data_buffer: [0; Ipv6MobilityHeader::MAX_MESSAGE_DATA_LEN],
+        }
+    }
+}
+
+impl Ipv6MobilityHeader {
+    /// Minimum length of an IPv6 mobility header in bytes/octets.
+    pub const MIN_LEN: usize = 8;
+
+    /// Maximum length of an IPv6 mobility header in bytes/octets.
+    ///
+    /// This number is calculated by multiplying the maximum "header len"
+    /// (0xff) with 8 and adding 8.
+    pub const MAX_LEN: usize = 8 + (8 * 0xff);
+
+    /// Minimum length of the message data.
+    pub const MIN_MESSAGE_DATA_LEN: usize = 2;
+
+    /// Maximum length of the message data.
+    pub const MAX_MESSAGE_DATA_LEN: usize = 0xff * 8 + 2;
+
+    /// Mobility header type of a "Binding Refresh Request" message.
+    pub const BINDING_REFRESH_REQUEST: u8 = 0;
+    /// Mobility header type of a "Home Test Init" message.
+    pub const HOME_TEST_INIT: u8 = 1;
+    /// Mobility header type of a "Care-of Test Init" message.
+    pub const CARE_OF_TEST_INIT: u8 = 2;
+    /// Mobility header type of a "Home Test" message.
+    pub const HOME_TEST: u8 = 3;
+    /// Mobility header type of a "Care-of Test" message.
+    pub const CARE_OF_TEST: u8 = 4;
+    /// Mobility header type of a "Binding Update" message.
+    pub const BINDING_UPDATE: u8 = 5;
+    /// Mobility header type of a "Binding Acknowledgement" message.
+    pub const BINDING_ACKNOWLEDGEMENT: u8 = 6;
+    /// Mobility header type of a "Binding Error" message.
+    pub const BINDING_ERROR: u8 = 7;
+
+    /// Creates an IPv6 mobility header with the given message data
+    /// (the checksum is set to 0).
+    ///
+    /// `message_data` must have a length of at least 2 bytes and
+    /// `(message_data.len() + 6) % 8 == 0` must be fulfilled (pad the data
+    /// with Pad1 or PadN options if required). The maximum length is
+    /// [`Ipv6MobilityHeader::MAX_MESSAGE_DATA_LEN`] (2042 bytes).
+    ///
+    /// If data with a non supported length is passed a
+    /// [`crate::err::ipv6_exts::ExtPayloadLenError`] is returned.
+    pub fn new(
+        next_header: IpNumber,
+        mh_type: u8,
+        message_data: &[u8],
+    ) -> Result<Ipv6MobilityHeader, ExtPayloadLenError> {
+        let mut result = Ipv6MobilityHeader {
+            next_header,
+            mh_type,
+            ..Default::default()
+        };
+        result.set_message_data(message_data)?;
+        Ok(result)
+    }
+
+    /// Read an [`Ipv6MobilityHeader`] from a slice and return the header & unused parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(Ipv6MobilityHeader, &[u8]), err::LenError> {
+        let s = Ipv6MobilityHeaderSlice::from_slice(slice)?;
+        let rest = &slice[s.slice().len()..];
+        let header = s.to_header();
+        Ok((header, rest))
+    }
+
+    /// Read an IPv6 mobility header from the current reader position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut T,
+    ) -> Result<Ipv6MobilityHeader, std::io::Error> {
+        let mut start: [u8; 6] = [0; 6];
+        reader.read_exact(&mut start)?;
+
+        let mut result = Ipv6MobilityHeader {
+            next_header: IpNumber(start[0]),
+            header_length: start[1],
+            mh_type: start[2],
+            reserved: start[3],
+            checksum: u16::from_be_bytes([start[4], start[5]]),
+            message_data_buffer: [0; Ipv6MobilityHeader::MAX_MESSAGE_DATA_LEN],
+        };
+        let data_len = result.message_data().len();
+        reader.read_exact(&mut result.message_data_buffer[..data_len])?;
+        Ok(result)
+    }
+
+    /// Writes a given IPv6 mobility header to the current position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<W: std::io::Write + Sized>(&self, writer: &mut W) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Returns the undecoded message data (everything after the
+    /// checksum field).
+    #[inline]
+    pub fn message_data(&self) -> &[u8] {
+        &self.message_data_buffer[..(2 + usize::from(self.header_length) * 8)]
+    }
+
+    /// Sets the undecoded message data (everything after the
+    /// checksum field).
+    ///
+    /// `message_data` must have a length of at least 2 bytes and
+    /// `(message_data.len() + 6) % 8 == 0` must be fulfilled. The maximum
+    /// length is [`Ipv6MobilityHeader::MAX_MESSAGE_DATA_LEN`] (2042 bytes).
+    ///
+    /// If data with a non supported length is passed a
+    /// [`crate::err::ipv6_exts::ExtPayloadLenError`] is returned and
+    /// the header is not changed.
+    pub fn set_message_data(&mut self, message_data: &[u8]) -> Result<(), ExtPayloadLenError> {
+        use ExtPayloadLenError::*;
+        if message_data.len() < Self::MIN_MESSAGE_DATA_LEN {
+            Err(TooSmall(message_data.len()))
+        } else if message_data.len() > Self::MAX_MESSAGE_DATA_LEN {
+            Err(TooBig(message_data.len()))
+        } else if 0 != (message_data.len() + 6) % 8 {
+            Err(Unaligned(message_data.len()))
+        } else {
+            self.message_data_buffer[..message_data.len()].copy_from_slice(message_data);
+            self.header_length = ((message_data.len() - 2) / 8) as u8;
+            Ok(())
+        }
+    }
+
+    /// Calculates the checksum of the mobility header based on the
+    /// given IPv6 source & destination address (the "checksum" field
+    /// itself is ignored).
+    pub fn calc_checksum(&self, source_ip: [u8; 16], destination_ip: [u8; 16]) -> u16 {
+        checksum::Sum16BitWords::new()
+            .add_16bytes(source_ip)
+            .add_16bytes(destination_ip)
+            .add_4bytes((self.header_len() as u32).to_be_bytes())
+            .add_2bytes([0, ip_number::MOBILITY.0])
+            .add_2bytes([self.next_header.0, self.header_length])
+            .add_2bytes([self.mh_type, self.reserved])
+            .add_slice(self.message_data())
+            .ones_complement()
+            .to_be()
+    }
+
+    /// Returns the serialized header.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { Ipv6MobilityHeader::MAX_LEN }> {
+        let mut result = ArrayVec::new();
+        let checksum_be = self.checksum.to_be_bytes();
+        result.extend([
+            self.next_header.0,
+            self.header_length,
+            self.mh_type,
+            self.reserved,
+            checksum_be[0],
+            checksum_be[1],
+        ]);
+        // Unwrap Panic Safety:
+        // The following unwrap should never panic, as
+        // the message data length can at most have the size max
+        // header length - 6 and as the internal buffer used to
+        // store the data has exactly this size.
+        result.try_extend_from_slice(self.message_data()).unwrap();
+        result
+    }
+
+    /// Length of the header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        8 + usize::from(self.header_length) * 8
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{err::ipv6_exts::ExtPayloadLenError::*, test_gens::*};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn default() {
+        let header: Ipv6MobilityHeader = Default::default();
+        assert_eq!(header.next_header, ip_number::IPV6_NO_NEXT_HEADER);
+        assert_eq!(header.mh_type, 0);
+        assert_eq!(header.reserved, 0);
+        assert_eq!(header.checksum, 0);
+        assert_eq!(header.message_data(), &[0, 0]);
+        assert_eq!(header.header_len(), 8);
+    }
+
+    proptest! {
+        #[test]
+        fn debug(header in ipv6_mobility_any()) {
+            assert_eq!(
+                format!(
+                    "Ipv6MobilityHeader {{ next_header: {:?}, mh_type: {}, reserved: {}, checksum: {}, message_data: {:?} }}",
+                    header.next_header,
+                    header.mh_type,
+                    header.reserved,
+                    header.checksum,
+                    header.message_data()
+                ),
+                format!("{:?}", header)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq(header in ipv6_mobility_any()) {
+            assert_eq!(header.clone(), header);
+        }
+    }
+
+    #[test]
+    fn new_set_message_data() {
+        // ok
+        for len in [2, 10, 18, Ipv6MobilityHeader::MAX_MESSAGE_DATA_LEN] {
+            let data: Vec<u8> = (0..len).map(|v| v as u8).collect();
+            let header = Ipv6MobilityHeader::new(
+                ip_number::IPV6_NO_NEXT_HEADER,
+                Ipv6MobilityHeader::BINDING_UPDATE,
+                &data,
+            )
+            .unwrap();
+            assert_eq!(header.next_header, ip_number::IPV6_NO_NEXT_HEADER);
+            assert_eq!(header.mh_type, Ipv6MobilityHeader::BINDING_UPDATE);
+            assert_eq!(header.checksum, 0);
+            assert_eq!(header.message_data(), &data[..]);
+            assert_eq!(header.header_len(), len + 6);
+        }
+        // errors
+        let data = [0u8; Ipv6MobilityHeader::MAX_MESSAGE_DATA_LEN + 8];
+        for (len, expected) in [
+            (0, TooSmall(0)),
+            (1, TooSmall(1)),
+            (3, Unaligned(3)),
+            (8, Unaligned(8)),
+            (
+                Ipv6MobilityHeader::MAX_MESSAGE_DATA_LEN + 8,
+                TooBig(Ipv6MobilityHeader::MAX_MESSAGE_DATA_LEN + 8),
+            ),
+        ] {
+            assert_eq!(
+                Ipv6MobilityHeader::new(ip_number::IPV6_NO_NEXT_HEADER, 0, &data[..len]),
+                Err(expected.clone())
+            );
+            let mut header =
+                Ipv6MobilityHeader::new(ip_number::IPV6_NO_NEXT_HEADER, 0, &[1, 2]).unwrap();
+            assert_eq!(header.set_message_data(&data[..len]), Err(expected));
+            assert_eq!(header.message_data(), &[1, 2]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(header in ipv6_mobility_any()) {
+            // ok
+            {
+                let mut bytes = Vec::with_capacity(header.header_len() + 2);
+                bytes.extend_from_slice(&header.to_bytes());
+                bytes.push(1);
+                bytes.push(2);
+
+                let (actual_header, actual_rest) = Ipv6MobilityHeader::from_slice(&bytes).unwrap();
+                assert_eq!(actual_header, header);
+                assert_eq!(actual_rest, &[1, 2]);
+            }
+
+            // length error
+            {
+                let bytes = header.to_bytes();
+                for len in 0..bytes.len() {
+                    assert_eq!(
+                        Ipv6MobilityHeader::from_slice(&bytes[..len]).unwrap_err(),
+                        err::LenError{
+                            required_len: if len < Ipv6MobilityHeader::MIN_LEN {
+                                Ipv6MobilityHeader::MIN_LEN
+                            } else {
+                                header.header_len()
+                            },
+                            len,
+                            len_source: LenSource::Slice,
+                            layer: err::Layer::Ipv6MobilityHeader,
+                            layer_start_offset: 0,
+                        }
+                    );
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(header in ipv6_mobility_any()) {
+            // ok
+            {
+                let mut bytes = Vec::with_capacity(header.header_len());
+                header.write(&mut bytes).unwrap();
+                assert_eq!(&bytes[..], &header.to_bytes()[..]);
+
+                let mut cursor = Cursor::new(&bytes);
+                let actual = Ipv6MobilityHeader::read(&mut cursor).unwrap();
+                assert_eq!(actual, header);
+                assert_eq!(cursor.position() as usize, header.header_len());
+            }
+
+            // not enough data
+            {
+                let bytes = header.to_bytes();
+                for len in 0..bytes.len() {
+                    let mut cursor = Cursor::new(&bytes[..len]);
+                    assert!(Ipv6MobilityHeader::read(&mut cursor).is_err());
+                }
+            }
+
+            // not enough space to write
+            {
+                let mut bytes = [0u8; Ipv6MobilityHeader::MAX_LEN];
+                for len in 0..header.header_len() {
+                    let mut cursor = Cursor::new(&mut bytes[..len]);
+                    assert!(header.write(&mut cursor).is_err());
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn calc_checksum(
+            header in ipv6_mobility_any(),
+            source in any::<[u8;16]>(),
+            destination in any::<[u8;16]>()
+        ) {
+            let expected = {
+                let mut bytes = Vec::new();
+                bytes.extend_from_slice(&source);
+                bytes.extend_from_slice(&destination);
+                bytes.extend_from_slice(&(header.header_len() as u32).to_be_bytes());
+                bytes.extend_from_slice(&[0, 0, 0, ip_number::MOBILITY.0]);
+                let mut header_bytes = header.to_bytes();
+                header_bytes[4] = 0;
+                header_bytes[5] = 0;
+                bytes.extend_from_slice(&header_bytes);
+                checksum::Sum16BitWords::new()
+                    .add_slice(&bytes)
+                    .ones_complement()
+                    .to_be()
+            };
+            assert_eq!(expected, header.calc_checksum(source, destination));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn hash(header in ipv6_mobility_any()) {
+            use core::hash::{Hash, Hasher};
+            use std::collections::hash_map::DefaultHasher;
+
+            // bytes outside of the used area of the buffer
+            // should not influence the hash
+            let mut other = header.clone();
+            other.message_data_buffer[header.message_data().len()..].fill(0xff);
+            assert_eq!(header, other);
+
+            let hash = |value: &Ipv6MobilityHeader| {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(hash(&header), hash(&other));
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing an IPv6 mobility header (next header value 135).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv6MobilityHeaderSlice<'a> {
+    /// Slice containing the header data.
+    slice: &'a [u8],
+}
+
+impl<'a> Ipv6MobilityHeaderSlice<'a> {
+    /// Creates a mobility header slice from a slice.
+    ///
+    /// The length of the header is determined by the "header len" field.
+    pub fn from_slice(slice: &'a [u8]) -> Result<Ipv6MobilityHeaderSlice<'a>, err::LenError> {
+        // check length
+        if slice.len() < Ipv6MobilityHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: Ipv6MobilityHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Ipv6MobilityHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check the length again now that the expected length is known
+        let len = ((slice[1] as usize) + 1) * 8;
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Ipv6MobilityHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(Ipv6MobilityHeaderSlice {
+            // SAFETY:
+            // Safe as the slice has been checked in the previous if
+            // to have at least the the length of the variable len.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Creates a mobility header slice from a slice (assumes slice
+    /// size & content was validated before).
+    ///
+    /// # Safety
+    ///
+    /// This method assumes that the slice was previously validated to contain
+    /// a valid mobility header. This means the slice length must
+    /// at least be at least 8 and `(slice[1] + 1)*8`.
+    ///
+    /// If these preconditions are not fulfilled the behavior of this function
+    /// and the methods of the return [`Ipv6MobilityHeaderSlice`] will be undefined.
+    pub unsafe fn from_slice_unchecked(slice: &'a [u8]) -> Ipv6MobilityHeaderSlice<'a> {
+        Ipv6MobilityHeaderSlice {
+            slice: from_raw_parts(slice.as_ptr(), ((*slice.get_unchecked(1) as usize) + 1) * 8),
+        }
+    }
+
+    /// Returns the slice containing the mobility header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the IP protocol number of the header following the
+    /// mobility header ("Payload Proto" field, normally
+    /// [`ip_number::IPV6_NO_NEXT_HEADER`]).
+    #[inline]
+    pub fn next_header(&self) -> IpNumber {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        IpNumber(unsafe { *self.slice.get_unchecked(0) })
+    }
+
+    /// Returns the "header len" field (length of the header in 8 octets
+    /// minus the first 8 octets).
+    #[inline]
+    pub fn header_ext_len(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(1) }
+    }
+
+    /// Returns the "MH type" field identifying the mobility message (see
+    /// the `Ipv6MobilityHeader::*` constants for the known values).
+    #[inline]
+    pub fn mh_type(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(2) }
+    }
+
+    /// Returns the "reserved" field.
+    #[inline]
+    pub fn reserved(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(3) }
+    }
+
+    /// Returns the "checksum" field.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Returns the undecoded message data (everything after the
+    /// checksum field).
+    #[inline]
+    pub fn message_data(&self) -> &'a [u8] {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { from_raw_parts(self.slice.as_ptr().add(6), self.slice.len() - 6) }
+    }
+
+    /// Returns true if the checksum in the slice is correct for the
+    /// given IPv6 source & destination address.
+    pub fn is_checksum_valid(&self, source_ip: [u8; 16], destination_ip: [u8; 16]) -> bool {
+        checksum::Sum16BitWords::new()
+            .add_16bytes(source_ip)
+            .add_16bytes(destination_ip)
+            .add_4bytes((self.slice.len() as u32).to_be_bytes())
+            .add_2bytes([0, ip_number::MOBILITY.0])
+            .add_slice(self.slice)
+            .ones_complement()
+            == 0
+    }
+
+    /// Decode all the fields and copy the results to a [`Ipv6MobilityHeader`] struct.
+    pub fn to_header(&self) -> Ipv6MobilityHeader {
+        // Unwrap Panic Safety:
+        // The message data length is guaranteed to be valid as it was
+        // derived from the "header len" field.
+        let mut header =
+            Ipv6MobilityHeader::new(self.next_header(), self.mh_type(), self.message_data())
+                .unwrap();
+        header.reserved = self.reserved();
+        header.checksum = self.checksum();
+        header
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::format;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn debug(header in ipv6_mobility_any()) {
+            let bytes = header.to_bytes();
+            let slice = Ipv6MobilityHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                format!("{:?}", slice),
+                format!("Ipv6MobilityHeaderSlice {{ slice: {:?} }}", slice.slice())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq(header in ipv6_mobility_any()) {
+            let bytes = header.to_bytes();
+            let slice = Ipv6MobilityHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.clone(), slice);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(header in ipv6_mobility_any()) {
+            let bytes = header.to_bytes();
+
+            // ok
+            {
+                let slice = Ipv6MobilityHeaderSlice::from_slice(&bytes).unwrap();
+                assert_eq!(slice.slice(), &bytes[..]);
+            }
+
+            // length error
+            for len in 0..bytes.len() {
+                assert_eq!(
+                    Ipv6MobilityHeaderSlice::from_slice(&bytes[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: if len < Ipv6MobilityHeader::MIN_LEN {
+                            Ipv6MobilityHeader::MIN_LEN
+                        } else {
+                            header.header_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::Ipv6MobilityHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_unchecked(header in ipv6_mobility_any()) {
+            let bytes = header.to_bytes();
+            let slice = unsafe {
+                Ipv6MobilityHeaderSlice::from_slice_unchecked(&bytes)
+            };
+            assert_eq!(&bytes[..], slice.slice());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(header in ipv6_mobility_any()) {
+            let bytes = header.to_bytes();
+            let slice = Ipv6MobilityHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.next_header(), header.next_header);
+            assert_eq!(slice.header_ext_len(), bytes[1]);
+            assert_eq!(slice.mh_type(), header.mh_type);
+            assert_eq!(slice.reserved(), header.reserved);
+            assert_eq!(slice.checksum(), header.checksum);
+            assert_eq!(slice.message_data(), header.message_data());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn is_checksum_valid(
+            header in ipv6_mobility_any(),
+            source in any::<[u8;16]>(),
+            destination in any::<[u8;16]>()
+        ) {
+            let mut header = header;
+            header.checksum = header.calc_checksum(source, destination);
+            let mut bytes = header.to_bytes();
+            {
+                let slice = Ipv6MobilityHeaderSlice::from_slice(&bytes).unwrap();
+                assert!(slice.is_checksum_valid(source, destination));
+            }
+            // modified data
+            bytes[2] = bytes[2].wrapping_add(1);
+            {
+                let slice = Ipv6MobilityHeaderSlice::from_slice(&bytes).unwrap();
+                assert!(false == slice.is_checksum_valid(source, destination));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(header in ipv6_mobility_any()) {
+            let bytes = header.to_bytes();
+            let slice = Ipv6MobilityHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(header, slice.to_header());
+        }
+    }
+}
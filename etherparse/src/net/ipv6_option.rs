@@ -0,0 +1,252 @@
+/// Decoded option of an IPv6 "Hop-by-Hop Options" or "Destination Options"
+/// header (see [`crate::Ipv6OptionsIter`]).
+///
+/// Options with a known type but an unexpected data length are returned
+/// as [`Ipv6Option::Unknown`].
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{Ipv6Option, Ipv6OptionsIter};
+///
+/// // router alert (MLD) followed by a PadN option
+/// let options = [5, 2, 0, 0, 1, 0];
+/// let decoded: Vec<Ipv6Option> = Ipv6OptionsIter::from_slice(&options)
+///     .map(Ipv6Option::from)
+///     .collect();
+/// assert_eq!(
+///     decoded,
+///     vec![Ipv6Option::RouterAlert(0), Ipv6Option::PadN(&[])]
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Ipv6Option<'a> {
+    /// "Pad1" option (single byte without length & data).
+    Pad1,
+    /// "PadN" option containing the padding bytes.
+    PadN(&'a [u8]),
+    /// "Router Alert" option (RFC 2711) containing the 2 byte value.
+    RouterAlert(u16),
+    /// "Jumbo Payload" option (RFC 2675) containing the length of the
+    /// IPv6 packet in bytes excluding the IPv6 header (replaces the
+    /// "payload length" field of the IPv6 header, which is set to zero).
+    JumboPayload(u32),
+    /// Option not decoded by etherparse.
+    Unknown {
+        /// Option type byte.
+        option_type: u8,
+        /// Option data after the type & length bytes.
+        data: &'a [u8],
+    },
+}
+
+impl<'a> Ipv6Option<'a> {
+    /// Option type of the "Pad1" option.
+    pub const TYPE_PAD1: u8 = 0;
+
+    /// Option type of the "PadN" option.
+    pub const TYPE_PADN: u8 = 1;
+
+    /// Option type of the "Router Alert" option.
+    pub const TYPE_ROUTER_ALERT: u8 = 5;
+
+    /// Option type of the "Jumbo Payload" option.
+    pub const TYPE_JUMBO_PAYLOAD: u8 = 194;
+
+    /// Router alert value indicating that the datagram contains a
+    /// Multicast Listener Discovery message.
+    pub const ROUTER_ALERT_MLD: u16 = 0;
+
+    /// Router alert value indicating that the datagram contains a
+    /// RSVP message.
+    pub const ROUTER_ALERT_RSVP: u16 = 1;
+
+    /// Router alert value indicating that the datagram contains an
+    /// Active Networks message.
+    pub const ROUTER_ALERT_ACTIVE_NETWORKS: u16 = 2;
+
+    /// Decodes an option based on the option type & the option data (as
+    /// returned by [`crate::Ipv6OptionsIter`]).
+    pub fn from_type_and_data(option_type: u8, data: &'a [u8]) -> Ipv6Option<'a> {
+        use Ipv6Option::*;
+        match (option_type, data) {
+            (Self::TYPE_PAD1, []) => Pad1,
+            (Self::TYPE_PADN, data) => PadN(data),
+            (Self::TYPE_ROUTER_ALERT, [a, b]) => RouterAlert(u16::from_be_bytes([*a, *b])),
+            (Self::TYPE_JUMBO_PAYLOAD, [a, b, c, d]) => {
+                JumboPayload(u32::from_be_bytes([*a, *b, *c, *d]))
+            }
+            (option_type, data) => Unknown { option_type, data },
+        }
+    }
+
+    /// Returns the option type byte of the option.
+    pub fn option_type(&self) -> u8 {
+        use Ipv6Option::*;
+        match self {
+            Pad1 => Self::TYPE_PAD1,
+            PadN(_) => Self::TYPE_PADN,
+            RouterAlert(_) => Self::TYPE_ROUTER_ALERT,
+            JumboPayload(_) => Self::TYPE_JUMBO_PAYLOAD,
+            Unknown { option_type, .. } => *option_type,
+        }
+    }
+
+    /// Returns the value of the "Jumbo Payload" option in the given
+    /// options area (if present).
+    pub(crate) fn find_jumbo_payload(options: &[u8]) -> Option<u32> {
+        crate::Ipv6OptionsIter::from_slice(options).find_map(|(option_type, data)| {
+            match Ipv6Option::from_type_and_data(option_type, data) {
+                Ipv6Option::JumboPayload(value) => Some(value),
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns the value of the "Jumbo Payload" option if the data after an
+    /// IPv6 header (`ipv6_payload`) starts with a hop by hop header (as
+    /// indicated by `next_header`) containing the option.
+    ///
+    /// Values smaller or equal to 65535 are invalid according to RFC 2675
+    /// and are treated as if no "Jumbo Payload" option is present.
+    pub(crate) fn jumbo_payload_after_ipv6_header(
+        next_header: crate::IpNumber,
+        ipv6_payload: &[u8],
+    ) -> Option<u32> {
+        if crate::ip_number::IPV6_HOP_BY_HOP != next_header {
+            return None;
+        }
+        crate::Ipv6RawExtHeaderSlice::from_slice(ipv6_payload)
+            .ok()
+            .and_then(|hop_by_hop| hop_by_hop.jumbo_payload())
+            .filter(|value| *value > u32::from(u16::MAX))
+    }
+}
+
+impl<'a> From<(u8, &'a [u8])> for Ipv6Option<'a> {
+    #[inline]
+    fn from(value: (u8, &'a [u8])) -> Self {
+        Ipv6Option::from_type_and_data(value.0, value.1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let option = Ipv6Option::RouterAlert(1);
+        assert_eq!(option, option.clone());
+        assert_eq!("RouterAlert(1)", format!("{:?}", option));
+    }
+
+    #[test]
+    fn from_type_and_data() {
+        use Ipv6Option::*;
+        let tests: [(u8, &[u8], Ipv6Option); 9] = [
+            (0, &[], Pad1),
+            (1, &[], PadN(&[])),
+            (1, &[0, 0, 0], PadN(&[0, 0, 0])),
+            (5, &[0x12, 0x34], RouterAlert(0x1234)),
+            (194, &[0x12, 0x34, 0x56, 0x78], JumboPayload(0x1234_5678)),
+            // unexpected lengths
+            (
+                0,
+                &[1],
+                Unknown {
+                    option_type: 0,
+                    data: &[1],
+                },
+            ),
+            (
+                5,
+                &[1],
+                Unknown {
+                    option_type: 5,
+                    data: &[1],
+                },
+            ),
+            (
+                194,
+                &[1, 2, 3],
+                Unknown {
+                    option_type: 194,
+                    data: &[1, 2, 3],
+                },
+            ),
+            // unknown type
+            (
+                7,
+                &[1, 2],
+                Unknown {
+                    option_type: 7,
+                    data: &[1, 2],
+                },
+            ),
+        ];
+        for (option_type, data, expected) in tests {
+            let actual = Ipv6Option::from_type_and_data(option_type, data);
+            assert_eq!(expected, actual);
+            assert_eq!(expected, Ipv6Option::from((option_type, data)));
+            assert_eq!(option_type, actual.option_type());
+        }
+    }
+
+    #[test]
+    fn find_jumbo_payload() {
+        assert_eq!(None, Ipv6Option::find_jumbo_payload(&[]));
+        assert_eq!(None, Ipv6Option::find_jumbo_payload(&[5, 2, 0, 0, 1, 0]));
+        assert_eq!(
+            Some(0x1_0000),
+            Ipv6Option::find_jumbo_payload(&[0, 0, 194, 4, 0, 1, 0, 0])
+        );
+        // truncated
+        assert_eq!(None, Ipv6Option::find_jumbo_payload(&[194, 4, 0, 1, 0]));
+    }
+
+    #[test]
+    fn jumbo_payload_after_ipv6_header() {
+        let hop_by_hop = [
+            ip_number::UDP.0,
+            0,
+            Ipv6Option::TYPE_JUMBO_PAYLOAD,
+            4,
+            0,
+            1,
+            0,
+            0,
+        ];
+        assert_eq!(
+            Some(0x1_0000),
+            Ipv6Option::jumbo_payload_after_ipv6_header(ip_number::IPV6_HOP_BY_HOP, &hop_by_hop)
+        );
+        // other header
+        assert_eq!(
+            None,
+            Ipv6Option::jumbo_payload_after_ipv6_header(ip_number::IPV6_DEST_OPTIONS, &hop_by_hop)
+        );
+        // too short
+        assert_eq!(
+            None,
+            Ipv6Option::jumbo_payload_after_ipv6_header(
+                ip_number::IPV6_HOP_BY_HOP,
+                &hop_by_hop[..7]
+            )
+        );
+        // jumbo payload lengths <= 65535 are invalid (RFC 2675)
+        for value in [0u32, 1, 0xffff] {
+            let mut hop_by_hop = hop_by_hop;
+            hop_by_hop[4..8].copy_from_slice(&value.to_be_bytes());
+            assert_eq!(
+                None,
+                Ipv6Option::jumbo_payload_after_ipv6_header(
+                    ip_number::IPV6_HOP_BY_HOP,
+                    &hop_by_hop
+                )
+            );
+        }
+    }
+}
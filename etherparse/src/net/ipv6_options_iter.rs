@@ -0,0 +1,115 @@
+/// Iterator over the type-length-value encoded options of an IPv6
+/// "Hop-by-Hop Options" or "Destination Options" header.
+///
+/// Every option is returned as a tuple of the option type and the
+/// option data. The "Pad1" option (type 0) consists only of the type
+/// byte and is returned with empty data, all other options (including
+/// "PadN", type 1) are decoded using their length field.
+///
+/// If an option is truncated (length field pointing beyond the end of the
+/// options area) the iteration ends and the non decoded bytes can be
+/// accessed via [`Ipv6OptionsIter::rest`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv6OptionsIter<'a> {
+    /// Not yet decoded part of the options area.
+    rest: &'a [u8],
+}
+
+impl<'a> Ipv6OptionsIter<'a> {
+    /// Option type of the "Pad1" option (single byte without length & data).
+    pub const PAD1: u8 = 0;
+
+    /// Option type of the "PadN" option.
+    pub const PADN: u8 = 1;
+
+    /// Creates an iterator over the options contained in the given slice
+    /// (options area of a hop-by-hop or destination options header).
+    #[inline]
+    pub fn from_slice(options: &'a [u8]) -> Ipv6OptionsIter<'a> {
+        Ipv6OptionsIter { rest: options }
+    }
+
+    /// Returns the part of the options area that was not yet decoded.
+    ///
+    /// If this is not empty after the iterator returned `None` the
+    /// last option was truncated.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for Ipv6OptionsIter<'a> {
+    type Item = (u8, &'a [u8]);
+
+    fn next(&mut self) -> Option<(u8, &'a [u8])> {
+        match self.rest {
+            [] => None,
+            [Self::PAD1, rest @ ..] => {
+                self.rest = rest;
+                Some((Self::PAD1, &[]))
+            }
+            [option_type, len, rest @ ..] if usize::from(*len) <= rest.len() => {
+                let (data, rest) = rest.split_at(usize::from(*len));
+                self.rest = rest;
+                Some((*option_type, data))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = Ipv6OptionsIter::from_slice(&[0]);
+        assert_eq!(iter.clone(), iter);
+        assert_eq!(format!("{:?}", iter), "Ipv6OptionsIter { rest: [0] }");
+    }
+
+    #[test]
+    fn next() {
+        // empty
+        {
+            let mut iter = Ipv6OptionsIter::from_slice(&[]);
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+
+        // pad1, padn & other options
+        {
+            let data = [0, 1, 0, 0, 1, 3, 0, 0, 0, 5, 2, 1, 2];
+            let mut iter = Ipv6OptionsIter::from_slice(&data);
+            assert_eq!(Some((0, &[][..])), iter.next());
+            assert_eq!(Some((1, &[][..])), iter.next());
+            assert_eq!(Some((0, &[][..])), iter.next());
+            assert_eq!(Some((1, &[0, 0, 0][..])), iter.next());
+            assert_eq!(Some((5, &[1, 2][..])), iter.next());
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+
+        // truncated length
+        {
+            let data = [0, 5];
+            let mut iter = Ipv6OptionsIter::from_slice(&data);
+            assert_eq!(Some((0, &[][..])), iter.next());
+            assert_eq!(None, iter.next());
+            assert_eq!(&[5], iter.rest());
+        }
+
+        // truncated data
+        {
+            let data = [5, 3, 1, 2];
+            let iter = Ipv6OptionsIter::from_slice(&data);
+            let mut iter_clone = iter.clone();
+            assert_eq!(iter.collect::<Vec<_>>(), []);
+            assert_eq!(None, iter_clone.next());
+            assert_eq!(&data, iter_clone.rest());
+        }
+    }
+}
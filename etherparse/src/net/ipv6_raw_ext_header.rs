@@ -45,6 +45,18 @@ impl Debug for Ipv6RawExtHeader {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for Ipv6RawExtHeader {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Ipv6RawExtHeader {{ next_header: {}, payload: {=[u8]} }}",
+            self.next_header,
+            self.payload()
+        )
+    }
+}
+
 impl PartialEq for Ipv6RawExtHeader {
     fn eq(&self, other: &Self) -> bool {
         self.next_header == other.next_header && self.payload() == other.payload()
@@ -53,6 +65,13 @@ impl PartialEq for Ipv6RawExtHeader {
 
 impl Eq for Ipv6RawExtHeader {}
 
+impl core::hash::Hash for Ipv6RawExtHeader {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.next_header.hash(state);
+        self.payload().hash(state);
+    }
+}
+
 impl Default for Ipv6RawExtHeader {
     fn default() -> Self {
         Ipv6RawExtHeader {
@@ -139,6 +158,26 @@ impl Ipv6RawExtHeader {
         &self.payload_buffer[..(6 + usize::from(self.header_length) * 8)]
     }
 
+    /// Returns an iterator over the type-length-value options in the payload
+    /// (only meaningful for "Hop-by-Hop Options" & "Destination Options"
+    /// headers).
+    ///
+    /// Use [`Ipv6Option::from`] to decode the returned options.
+    #[inline]
+    pub fn options(&self) -> Ipv6OptionsIter<'_> {
+        Ipv6OptionsIter::from_slice(self.payload())
+    }
+
+    /// Returns the value of the "Jumbo Payload" option if present (only
+    /// meaningful for "Hop-by-Hop Options" headers).
+    ///
+    /// The value is the length of the IPv6 packet excluding the IPv6 header
+    /// (replacing the zero "payload length" field of the IPv6 header).
+    #[inline]
+    pub fn jumbo_payload(&self) -> Option<u32> {
+        Ipv6Option::find_jumbo_payload(self.payload())
+    }
+
     /// Sets the payload (content of the header after the `next_header` & `header_length` fields).
     ///
     /// Note that `payload` must have at least the length of 6 bytes and only supports
@@ -184,14 +223,25 @@ impl Ipv6RawExtHeader {
         })
     }
 
-    /// Read an fragment header from the current limited reader position.
+    /// Read an extension header from the current limited reader position.
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn read_limited<T: std::io::Read + std::io::Seek + Sized>(
         reader: &mut crate::io::LimitedReader<T>,
+    ) -> Result<Ipv6RawExtHeader, err::io::LimitedReadError> {
+        Ipv6RawExtHeader::read_limited_with_layer(reader, err::Layer::Ipv6ExtHeader)
+    }
+
+    /// Read an extension header from the current limited reader position
+    /// and report errors with the given layer (e.g. to identify the header
+    /// as hop by hop or destination options header).
+    #[cfg(feature = "std")]
+    pub(crate) fn read_limited_with_layer<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut crate::io::LimitedReader<T>,
+        layer: err::Layer,
     ) -> Result<Ipv6RawExtHeader, err::io::LimitedReadError> {
         // set layer start
-        reader.start_layer(err::Layer::Ipv6ExtHeader);
+        reader.start_layer(layer);
 
         // read next & len
         let (next_header, header_length) = {
@@ -380,6 +430,28 @@ mod test {
         }
     }
 
+    #[test]
+    fn options_jumbo_payload() {
+        // router alert & padding
+        {
+            let header = Ipv6RawExtHeader::new_raw(ip_number::UDP, &[5, 2, 0, 1, 1, 0]).unwrap();
+            assert_eq!(
+                header.options().map(Ipv6Option::from).collect::<Vec<_>>(),
+                [Ipv6Option::RouterAlert(1), Ipv6Option::PadN(&[])]
+            );
+            assert_eq!(None, header.jumbo_payload());
+        }
+        // jumbo payload
+        {
+            let header = Ipv6RawExtHeader::new_raw(ip_number::UDP, &[194, 4, 0, 1, 0, 0]).unwrap();
+            assert_eq!(
+                header.options().map(Ipv6Option::from).collect::<Vec<_>>(),
+                [Ipv6Option::JumboPayload(0x1_0000)]
+            );
+            assert_eq!(Some(0x1_0000), header.jumbo_payload());
+        }
+    }
+
     proptest! {
         #[test]
         fn set_payload(
@@ -497,4 +569,25 @@ mod test {
             assert_eq!(header.header_len(), header.to_bytes().len());
         }
     }
+
+    proptest! {
+        #[test]
+        fn hash(header in ipv6_raw_ext_any()) {
+            use core::hash::{Hash, Hasher};
+            use std::collections::hash_map::DefaultHasher;
+
+            // bytes outside of the used area of the buffer
+            // should not influence the hash
+            let mut other = header.clone();
+            other.payload_buffer[header.payload().len()..].fill(0xff);
+            assert_eq!(header, other);
+
+            let hash = |value: &Ipv6RawExtHeader| {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(hash(&header), hash(&other));
+        }
+    }
 }
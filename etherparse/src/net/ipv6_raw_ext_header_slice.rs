@@ -112,6 +112,26 @@ impl<'a> Ipv6RawExtHeaderSlice<'a> {
         unsafe { from_raw_parts(self.slice.as_ptr().add(2), self.slice.len() - 2) }
     }
 
+    /// Returns an iterator over the type-length-value options in the payload
+    /// (only meaningful for "Hop-by-Hop Options" & "Destination Options"
+    /// headers).
+    ///
+    /// Use [`Ipv6Option::from`] to decode the returned options.
+    #[inline]
+    pub fn options(&self) -> Ipv6OptionsIter<'a> {
+        Ipv6OptionsIter::from_slice(self.payload())
+    }
+
+    /// Returns the value of the "Jumbo Payload" option if present (only
+    /// meaningful for "Hop-by-Hop Options" headers).
+    ///
+    /// The value is the length of the IPv6 packet excluding the IPv6 header
+    /// (replacing the zero "payload length" field of the IPv6 header).
+    #[inline]
+    pub fn jumbo_payload(&self) -> Option<u32> {
+        Ipv6Option::find_jumbo_payload(self.payload())
+    }
+
     /// Convert the slice to an [Ipv6RawExtHeader].
     ///
     /// Decode some of the fields and copy the results to a
@@ -231,4 +251,28 @@ mod test {
             assert_eq!(header, slice.to_header());
         }
     }
+
+    #[test]
+    fn options_jumbo_payload() {
+        // router alert & padding
+        {
+            let bytes = [ip_number::UDP.0, 0, 5, 2, 0, 1, 1, 0];
+            let slice = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                slice.options().map(Ipv6Option::from).collect::<Vec<_>>(),
+                [Ipv6Option::RouterAlert(1), Ipv6Option::PadN(&[])]
+            );
+            assert_eq!(None, slice.jumbo_payload());
+        }
+        // jumbo payload
+        {
+            let bytes = [ip_number::UDP.0, 0, 194, 4, 0, 1, 0, 0];
+            let slice = Ipv6RawExtHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(
+                slice.options().map(Ipv6Option::from).collect::<Vec<_>>(),
+                [Ipv6Option::JumboPayload(0x1_0000)]
+            );
+            assert_eq!(Some(0x1_0000), slice.jumbo_payload());
+        }
+    }
 }
@@ -2,7 +2,8 @@ use crate::*;
 
 /// In case a route header is present it is also possible
 /// to attach a "final destination" header.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Ipv6RoutingExtensions {
     pub routing: Ipv6RawExtHeader,
     pub final_destination_options: Option<Ipv6RawExtHeader>,
@@ -0,0 +1,655 @@
+use super::super::*;
+use crate::err::ipv6_exts::{RoutingDataLenError, SegmentsLeftError};
+use arrayvec::ArrayVec;
+use core::fmt::{Debug, Formatter};
+
+/// IPv6 Routing extension header (next header value 43).
+///
+/// Contains the fields common to all routing types and the routing type
+/// specific data as an undecoded slice (see [`Ipv6RoutingHeader::data`]).
+///
+/// For the "Segment Routing Header" (routing type 4, see
+/// [RFC8754](https://datatracker.ietf.org/doc/html/rfc8754)) the segment
+/// list can be accessed via [`Ipv6RoutingHeader::segment_list`].
+#[derive(Clone)]
+pub struct Ipv6RoutingHeader {
+    /// IP protocol number specifying the next header or transport layer protocol.
+    ///
+    /// See [IpNumber] or [ip_number] for a definition of the known values.
+    pub next_header: IpNumber,
+    /// Identifier of the routing header variant.
+    pub routing_type: u8,
+    /// Number of route segments remaining (number of explicitly listed
+    /// intermediate nodes still to be visited before reaching the final
+    /// destination).
+    pub segments_left: u8,
+    /// Length of the extension header in 8 octets (minus the first 8 octets).
+    header_length: u8,
+    /// The routing type specific data (everything after the `segments_left` field).
+    data_buffer: [u8; Ipv6RoutingHeader::MAX_DATA_LEN],
+}
+
+impl Debug for Ipv6RoutingHeader {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), core::fmt::Error> {
+        let mut s = f.debug_struct("Ipv6RoutingHeader");
+        s.field("next_header", &self.next_header);
+        s.field("routing_type", &self.routing_type);
+        s.field("segments_left", &self.segments_left);
+        s.field("data", &self.data());
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Ipv6RoutingHeader {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Ipv6RoutingHeader {{ next_header: {}, routing_type: {}, segments_left: {}, data: {=[u8]} }}",
+            self.next_header,
+            self.routing_type,
+            self.segments_left,
+            self.data()
+        )
+    }
+}
+
+impl PartialEq for Ipv6RoutingHeader {
+    fn eq(&self, other: &Self) -> bool {
+        self.next_header == other.next_header
+            && self.routing_type == other.routing_type
+            && self.segments_left == other.segments_left
+            && self.data() == other.data()
+    }
+}
+
+impl Eq for Ipv6RoutingHeader {}
+
+impl core::hash::Hash for Ipv6RoutingHeader {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.next_header.hash(state);
+        self.routing_type.hash(state);
+        self.segments_left.hash(state);
+        self.data().hash(state);
+    }
+}
+
+impl Default for Ipv6RoutingHeader {
+    fn default() -> Self {
+        Ipv6RoutingHeader {
+            next_header: IpNumber(255),
+            routing_type: 0,
+            segments_left: 0,
+            header_length: 0,
+            data_buffer: [0; Ipv6RoutingHeader::MAX_DATA_LEN],
+        }
+    }
+}
+
+impl Ipv6RoutingHeader {
+    /// Minimum length of an IPv6 routing header in bytes/octets.
+    pub const MIN_LEN: usize = 8;
+
+    /// Maximum length of an IPv6 routing header in bytes/octets.
+    ///
+    /// This number is calculated by multiplying the maximum "hdr ext len"
+    /// (0xff) with 8 and adding 8.
+    pub const MAX_LEN: usize = 8 + (8 * 0xff);
+
+    /// Minimum length of the routing type specific data.
+    pub const MIN_DATA_LEN: usize = 4;
+
+    /// Maximum length of the routing type specific data.
+    pub const MAX_DATA_LEN: usize = 0xff * 8 + 4;
+
+    /// Routing type of the "Segment Routing Header" (SRH)
+    /// ([RFC8754](https://datatracker.ietf.org/doc/html/rfc8754)).
+    pub const SEGMENT_ROUTING_TYPE: u8 = 4;
+
+    /// Creates an IPv6 routing header with the given data.
+    ///
+    /// `data` is the routing type specific data (everything after the
+    /// `segments_left` field). It must have a length of at least 4 bytes and
+    /// `(data.len() + 4) % 8 == 0` must be fulfilled. The maximum length is
+    /// [`Ipv6RoutingHeader::MAX_DATA_LEN`] (2044 bytes).
+    ///
+    /// If a data with a non supported length is passed a
+    /// [`crate::err::ipv6_exts::RoutingDataLenError`] is returned.
+    pub fn new(
+        next_header: IpNumber,
+        routing_type: u8,
+        segments_left: u8,
+        data: &[u8],
+    ) -> Result<Ipv6RoutingHeader, RoutingDataLenError> {
+        let mut result = Ipv6RoutingHeader {
+            next_header,
+            routing_type,
+            segments_left,
+            ..Default::default()
+        };
+        result.set_data(data)?;
+        Ok(result)
+    }
+
+    /// Read an [`Ipv6RoutingHeader`] from a slice and return the header & unused parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(Ipv6RoutingHeader, &[u8]), err::LenError> {
+        let s = Ipv6RoutingHeaderSlice::from_slice(slice)?;
+        let rest = &slice[s.slice().len()..];
+        let header = s.to_header();
+        Ok((header, rest))
+    }
+
+    /// Read an IPv6 routing header from the current reader position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut T,
+    ) -> Result<Ipv6RoutingHeader, std::io::Error> {
+        let mut start: [u8; 4] = [0; 4];
+        reader.read_exact(&mut start)?;
+
+        let mut result = Ipv6RoutingHeader {
+            next_header: IpNumber(start[0]),
+            header_length: start[1],
+            routing_type: start[2],
+            segments_left: start[3],
+            data_buffer: [0; Ipv6RoutingHeader::MAX_DATA_LEN],
+        };
+        let data_len = result.data().len();
+        reader.read_exact(&mut result.data_buffer[..data_len])?;
+        Ok(result)
+    }
+
+    /// Read an IPv6 routing header from the current limited reader position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_limited<T: std::io::Read + std::io::Seek + Sized>(
+        reader: &mut crate::io::LimitedReader<T>,
+    ) -> Result<Ipv6RoutingHeader, err::io::LimitedReadError> {
+        // set layer so errors contain the correct layer & offset
+        reader.start_layer(err::Layer::Ipv6RouteHeader);
+
+        let mut start: [u8; 4] = [0; 4];
+        reader.read_exact(&mut start)?;
+
+        let mut result = Ipv6RoutingHeader {
+            next_header: IpNumber(start[0]),
+            header_length: start[1],
+            routing_type: start[2],
+            segments_left: start[3],
+            data_buffer: [0; Ipv6RoutingHeader::MAX_DATA_LEN],
+        };
+        let data_len = result.data().len();
+        reader.read_exact(&mut result.data_buffer[..data_len])?;
+        Ok(result)
+    }
+
+    /// Writes a given IPv6 routing header to the current position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<W: std::io::Write + Sized>(&self, writer: &mut W) -> Result<(), std::io::Error> {
+        writer.write_all(&[
+            self.next_header.0,
+            self.header_length,
+            self.routing_type,
+            self.segments_left,
+        ])?;
+        writer.write_all(self.data())?;
+        Ok(())
+    }
+
+    /// Returns the routing type specific data (everything after the
+    /// `segments_left` field).
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.data_buffer[..(4 + usize::from(self.header_length) * 8)]
+    }
+
+    /// Sets the routing type specific data (everything after the
+    /// `segments_left` field).
+    ///
+    /// `data` must have a length of at least 4 bytes and
+    /// `(data.len() + 4) % 8 == 0` must be fulfilled. The maximum length is
+    /// [`Ipv6RoutingHeader::MAX_DATA_LEN`] (2044 bytes).
+    ///
+    /// If a data with a non supported length is passed a
+    /// [`crate::err::ipv6_exts::RoutingDataLenError`] is returned and
+    /// the header is not changed.
+    pub fn set_data(&mut self, data: &[u8]) -> Result<(), RoutingDataLenError> {
+        use RoutingDataLenError::*;
+        if data.len() < Self::MIN_DATA_LEN {
+            Err(TooSmall(data.len()))
+        } else if data.len() > Self::MAX_DATA_LEN {
+            Err(TooBig(data.len()))
+        } else if 0 != (data.len() + 4) % 8 {
+            Err(Unaligned(data.len()))
+        } else {
+            self.data_buffer[..data.len()].copy_from_slice(data);
+            self.header_length = ((data.len() - 4) / 8) as u8;
+            Ok(())
+        }
+    }
+
+    /// Returns an iterator over the IPv6 addresses in the segment list
+    /// if the header is a "Segment Routing Header" (routing type 4).
+    ///
+    /// Returns `None` if the routing type is not 4. Segments not fully
+    /// contained in the header data are not returned.
+    #[inline]
+    pub fn segment_list(&self) -> Option<Ipv6SegmentListIter<'_>> {
+        Ipv6SegmentListIter::from_routing_data(self.routing_type, self.data())
+    }
+
+    /// Returns the currently active segment of a "Segment Routing Header"
+    /// (routing type 4), which is the entry `Segment List[segments_left]`.
+    ///
+    /// Returns `None` if the routing type is not 4 or if `segments_left`
+    /// is not a valid index into the segment list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6RoutingHeader};
+    ///
+    /// let mut data = vec![1, 0, 0, 0]; // last entry, flags & tag
+    /// data.extend_from_slice(&[1; 16]); // Segment List[0]
+    /// data.extend_from_slice(&[2; 16]); // Segment List[1]
+    /// let mut header = Ipv6RoutingHeader::new(
+    ///     ip_number::UDP,
+    ///     Ipv6RoutingHeader::SEGMENT_ROUTING_TYPE,
+    ///     1, // segments left
+    ///     &data,
+    /// ).unwrap();
+    /// assert_eq!(header.active_segment(), Some([2; 16].into()));
+    ///
+    /// // processing as segment endpoint
+    /// header.decrement_segments_left().unwrap();
+    /// assert_eq!(header.active_segment(), Some([1; 16].into()));
+    ///
+    /// // serialize the modified header
+    /// let bytes = header.to_bytes();
+    /// assert_eq!(bytes[3], 0);
+    /// ```
+    pub fn active_segment(&self) -> Option<core::net::Ipv6Addr> {
+        self.segment_list()?
+            .nth(usize::from(self.segments_left))
+            .map(core::net::Ipv6Addr::from)
+    }
+
+    /// Decrements the `segments_left` field (as done by the node identified
+    /// by the active segment when processing the routing header).
+    ///
+    /// A [`SegmentsLeftError::NoSegmentsLeft`] error is returned if
+    /// `segments_left` is already zero. For "Segment Routing Headers"
+    /// (routing type 4) a [`SegmentsLeftError::ExceedsSegmentList`] error
+    /// is returned if `segments_left` is bigger then the number of segments
+    /// in the segment list. In both cases the header is not changed.
+    pub fn decrement_segments_left(&mut self) -> Result<(), SegmentsLeftError> {
+        if 0 == self.segments_left {
+            return Err(SegmentsLeftError::NoSegmentsLeft);
+        }
+        if let Some(segments) = self.segment_list() {
+            if segments.len() < usize::from(self.segments_left) {
+                return Err(SegmentsLeftError::ExceedsSegmentList {
+                    segments_left: self.segments_left,
+                    segment_count: segments.len(),
+                });
+            }
+        }
+        self.segments_left -= 1;
+        Ok(())
+    }
+
+    /// Returns the serialized header.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { Ipv6RoutingHeader::MAX_LEN }> {
+        let mut result = ArrayVec::new();
+        result.extend([
+            self.next_header.0,
+            self.header_length,
+            self.routing_type,
+            self.segments_left,
+        ]);
+        // Unwrap Panic Safety:
+        // The following unwrap should never panic, as
+        // the data length can at most have the size max
+        // header length - 4 and as the internal buffer used to
+        // store the data has exactly this size.
+        result.try_extend_from_slice(self.data()).unwrap();
+        result
+    }
+
+    /// Length of the header in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        8 + usize::from(self.header_length) * 8
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{err::ipv6_exts::RoutingDataLenError::*, test_gens::*};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn default() {
+        let header: Ipv6RoutingHeader = Default::default();
+        assert_eq!(header.next_header, IpNumber(255));
+        assert_eq!(header.routing_type, 0);
+        assert_eq!(header.segments_left, 0);
+        assert_eq!(header.data(), &[0, 0, 0, 0]);
+        assert_eq!(header.header_len(), 8);
+    }
+
+    proptest! {
+        #[test]
+        fn debug(header in ipv6_routing_any()) {
+            assert_eq!(
+                format!(
+                    "Ipv6RoutingHeader {{ next_header: {:?}, routing_type: {}, segments_left: {}, data: {:?} }}",
+                    header.next_header,
+                    header.routing_type,
+                    header.segments_left,
+                    header.data()
+                ),
+                format!("{:?}", header)
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq(header in ipv6_routing_any()) {
+            assert_eq!(header.clone(), header);
+        }
+    }
+
+    #[test]
+    fn new_set_data() {
+        // ok
+        for len in [4, 12, 20, Ipv6RoutingHeader::MAX_DATA_LEN] {
+            let data: Vec<u8> = (0..len).map(|v| v as u8).collect();
+            let header = Ipv6RoutingHeader::new(ip_number::UDP, 1, 2, &data).unwrap();
+            assert_eq!(header.next_header, ip_number::UDP);
+            assert_eq!(header.routing_type, 1);
+            assert_eq!(header.segments_left, 2);
+            assert_eq!(header.data(), &data[..]);
+            assert_eq!(header.header_len(), len + 4);
+        }
+        // errors
+        let data = [0u8; Ipv6RoutingHeader::MAX_DATA_LEN + 8];
+        for (len, expected) in [
+            (0, TooSmall(0)),
+            (3, TooSmall(3)),
+            (5, Unaligned(5)),
+            (8, Unaligned(8)),
+            (
+                Ipv6RoutingHeader::MAX_DATA_LEN + 8,
+                TooBig(Ipv6RoutingHeader::MAX_DATA_LEN + 8),
+            ),
+        ] {
+            assert_eq!(
+                Ipv6RoutingHeader::new(ip_number::UDP, 1, 2, &data[..len]),
+                Err(expected.clone())
+            );
+            let mut header = Ipv6RoutingHeader::new(ip_number::UDP, 1, 2, &[1, 2, 3, 4]).unwrap();
+            assert_eq!(header.set_data(&data[..len]), Err(expected));
+            assert_eq!(header.data(), &[1, 2, 3, 4]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(header in ipv6_routing_any()) {
+            // ok
+            {
+                let mut bytes = Vec::with_capacity(header.header_len() + 2);
+                bytes.extend_from_slice(&header.to_bytes());
+                bytes.push(1);
+                bytes.push(2);
+
+                let (actual_header, actual_rest) = Ipv6RoutingHeader::from_slice(&bytes).unwrap();
+                assert_eq!(actual_header, header);
+                assert_eq!(actual_rest, &[1, 2]);
+            }
+
+            // length error
+            {
+                let bytes = header.to_bytes();
+                for len in 0..bytes.len() {
+                    assert_eq!(
+                        Ipv6RoutingHeader::from_slice(&bytes[..len]).unwrap_err(),
+                        err::LenError{
+                            required_len: if len < Ipv6RoutingHeader::MIN_LEN {
+                                Ipv6RoutingHeader::MIN_LEN
+                            } else {
+                                header.header_len()
+                            },
+                            len,
+                            len_source: LenSource::Slice,
+                            layer: err::Layer::Ipv6RouteHeader,
+                            layer_start_offset: 0,
+                        }
+                    );
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(header in ipv6_routing_any()) {
+            // ok
+            {
+                let mut bytes = Vec::with_capacity(header.header_len());
+                header.write(&mut bytes).unwrap();
+                assert_eq!(&bytes[..], &header.to_bytes()[..]);
+
+                let mut cursor = Cursor::new(&bytes);
+                let actual = Ipv6RoutingHeader::read(&mut cursor).unwrap();
+                assert_eq!(actual, header);
+                assert_eq!(cursor.position() as usize, header.header_len());
+            }
+
+            // not enough data
+            {
+                let bytes = header.to_bytes();
+                for len in 0..bytes.len() {
+                    let mut cursor = Cursor::new(&bytes[..len]);
+                    assert!(Ipv6RoutingHeader::read(&mut cursor).is_err());
+                }
+            }
+
+            // not enough space to write
+            {
+                let mut bytes = [0u8; Ipv6RoutingHeader::MAX_LEN];
+                for len in 0..header.header_len() {
+                    let mut cursor = Cursor::new(&mut bytes[..len]);
+                    assert!(header.write(&mut cursor).is_err());
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_limited(header in ipv6_routing_any()) {
+            use crate::{err::{Layer, LenError}, io::LimitedReader};
+
+            // ok
+            {
+                let bytes = header.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..]);
+                let mut reader = LimitedReader::new(
+                    &mut cursor,
+                    bytes.len(),
+                    LenSource::Slice,
+                    0,
+                    Layer::Ipv6Header
+                );
+                assert_eq!(header, Ipv6RoutingHeader::read_limited(&mut reader).unwrap());
+            }
+
+            // length error
+            {
+                let bytes = header.to_bytes();
+                for len in 0..header.header_len() {
+                    // io error
+                    {
+                        let mut cursor = Cursor::new(&bytes[..len]);
+                        let mut reader = LimitedReader::new(
+                            &mut cursor,
+                            bytes.len(),
+                            LenSource::Slice,
+                            0,
+                            Layer::Ipv6Header
+                        );
+                        assert!(
+                            Ipv6RoutingHeader::read_limited(&mut reader)
+                                .unwrap_err()
+                                .io()
+                                .is_some()
+                        );
+                    }
+                    // limited reader error
+                    {
+                        let mut cursor = Cursor::new(&bytes[..]);
+                        let mut reader = LimitedReader::new(
+                            &mut cursor,
+                            len,
+                            LenSource::Ipv6HeaderPayloadLen,
+                            0,
+                            Layer::Ipv6Header
+                        );
+                        assert_eq!(
+                            Ipv6RoutingHeader::read_limited(&mut reader)
+                                .unwrap_err()
+                                .len()
+                                .unwrap(),
+                            LenError {
+                                required_len: if len < 4 {
+                                    4
+                                } else {
+                                    bytes.len()
+                                },
+                                len,
+                                len_source: LenSource::Ipv6HeaderPayloadLen,
+                                layer: Layer::Ipv6RouteHeader,
+                                layer_start_offset: 0
+                            }
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn segment_list() {
+        // non segment routing header
+        {
+            let header = Ipv6RoutingHeader::new(ip_number::UDP, 0, 0, &[1, 0, 0, 0]).unwrap();
+            assert!(header.segment_list().is_none());
+        }
+
+        // segment routing header with two segments
+        {
+            let mut data = Vec::new();
+            // last entry, flags & tag
+            data.extend_from_slice(&[1, 0, 0, 0]);
+            data.extend_from_slice(&[1; 16]);
+            data.extend_from_slice(&[2; 16]);
+            let header = Ipv6RoutingHeader::new(
+                ip_number::UDP,
+                Ipv6RoutingHeader::SEGMENT_ROUTING_TYPE,
+                1,
+                &data,
+            )
+            .unwrap();
+            let segments: Vec<[u8; 16]> = header.segment_list().unwrap().collect();
+            assert_eq!(segments, [[1; 16], [2; 16]]);
+        }
+    }
+
+    #[test]
+    fn active_segment_decrement_segments_left() {
+        use crate::err::ipv6_exts::SegmentsLeftError::*;
+
+        let mut data = Vec::new();
+        // last entry, flags & tag
+        data.extend_from_slice(&[1, 0, 0, 0]);
+        data.extend_from_slice(&[1; 16]);
+        data.extend_from_slice(&[2; 16]);
+
+        // segment routing header
+        {
+            let mut header = Ipv6RoutingHeader::new(
+                ip_number::UDP,
+                Ipv6RoutingHeader::SEGMENT_ROUTING_TYPE,
+                2,
+                &data,
+            )
+            .unwrap();
+
+            // segments_left equal to the number of segments (no active segment)
+            assert_eq!(header.active_segment(), None);
+            assert_eq!(header.decrement_segments_left(), Ok(()));
+            assert_eq!(header.segments_left, 1);
+            assert_eq!(header.active_segment(), Some([2; 16].into()));
+            assert_eq!(header.decrement_segments_left(), Ok(()));
+            assert_eq!(header.segments_left, 0);
+            assert_eq!(header.active_segment(), Some([1; 16].into()));
+            assert_eq!(header.decrement_segments_left(), Err(NoSegmentsLeft));
+            assert_eq!(header.segments_left, 0);
+
+            // serialized header contains the decremented value
+            let (decoded, _) = Ipv6RoutingHeader::from_slice(&header.to_bytes()).unwrap();
+            assert_eq!(decoded, header);
+
+            // segments_left bigger then the segment list
+            header.segments_left = 3;
+            assert_eq!(header.active_segment(), None);
+            assert_eq!(
+                header.decrement_segments_left(),
+                Err(ExceedsSegmentList {
+                    segments_left: 3,
+                    segment_count: 2
+                })
+            );
+            assert_eq!(header.segments_left, 3);
+        }
+
+        // non segment routing header
+        {
+            let mut header = Ipv6RoutingHeader::new(ip_number::UDP, 0, 3, &data).unwrap();
+            assert_eq!(header.active_segment(), None);
+            assert_eq!(header.decrement_segments_left(), Ok(()));
+            assert_eq!(header.segments_left, 2);
+            header.segments_left = 0;
+            assert_eq!(header.decrement_segments_left(), Err(NoSegmentsLeft));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn hash(header in ipv6_routing_any()) {
+            use core::hash::{Hash, Hasher};
+            use std::collections::hash_map::DefaultHasher;
+
+            // bytes outside of the used area of the buffer
+            // should not influence the hash
+            let mut other = header.clone();
+            other.data_buffer[header.data().len()..].fill(0xff);
+            assert_eq!(header, other);
+
+            let hash = |value: &Ipv6RoutingHeader| {
+                let mut hasher = DefaultHasher::new();
+                value.hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(hash(&header), hash(&other));
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::*;
+
+/// Iterator over the IPv6 addresses in the segment list of an
+/// IPv6 "Segment Routing Header" (SRH, routing type 4,
+/// [RFC8754](https://datatracker.ietf.org/doc/html/rfc8754)).
+///
+/// The segments are returned in the order they are stored in
+/// the header (`Segment List[0]` first, which is the last segment
+/// of the path).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ipv6SegmentListIter<'a> {
+    /// Number of segments that still have to be returned.
+    remaining: usize,
+    /// Not yet returned part of the segment list.
+    rest: &'a [u8],
+}
+
+impl<'a> Ipv6SegmentListIter<'a> {
+    /// Length of an entry in the segment list.
+    const SEGMENT_LEN: usize = 16;
+
+    /// Creates an iterator from the routing type specific data of a routing
+    /// header (data after the `segments_left` field).
+    ///
+    /// Returns `None` if the routing type is not
+    /// [`Ipv6RoutingHeader::SEGMENT_ROUTING_TYPE`].
+    pub(crate) fn from_routing_data(
+        routing_type: u8,
+        data: &'a [u8],
+    ) -> Option<Ipv6SegmentListIter<'a>> {
+        if routing_type != Ipv6RoutingHeader::SEGMENT_ROUTING_TYPE || data.len() < 4 {
+            None
+        } else {
+            // "last entry" contains the index of the last entry in the segment list
+            let last_entry = usize::from(data[0]);
+            let rest = &data[4..];
+            Some(Ipv6SegmentListIter {
+                remaining: core::cmp::min(last_entry + 1, rest.len() / Self::SEGMENT_LEN),
+                rest,
+            })
+        }
+    }
+}
+
+impl<'a> Iterator for Ipv6SegmentListIter<'a> {
+    type Item = [u8; 16];
+
+    fn next(&mut self) -> Option<[u8; 16]> {
+        if 0 == self.remaining {
+            None
+        } else {
+            let (segment, rest) = self.rest.split_at(Self::SEGMENT_LEN);
+            self.remaining -= 1;
+            self.rest = rest;
+            // Unwrap Panic Safety:
+            // segment is guaranteed to have the length 16 by split_at.
+            Some(segment.try_into().unwrap())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for Ipv6SegmentListIter<'a> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = Ipv6SegmentListIter::from_routing_data(4, &[0, 0, 0, 0]).unwrap();
+        assert_eq!(iter.clone(), iter);
+        assert_eq!(
+            format!("{:?}", iter),
+            "Ipv6SegmentListIter { remaining: 0, rest: [] }"
+        );
+    }
+
+    #[test]
+    fn from_routing_data() {
+        // wrong routing type
+        assert!(Ipv6SegmentListIter::from_routing_data(3, &[0, 0, 0, 0]).is_none());
+        // too little data
+        assert!(Ipv6SegmentListIter::from_routing_data(4, &[0, 0, 0]).is_none());
+    }
+
+    #[test]
+    fn next() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&[2, 0, 0, 0]);
+        data.extend_from_slice(&[1; 16]);
+        data.extend_from_slice(&[2; 16]);
+        data.extend_from_slice(&[3; 16]);
+        // tlv data after the segment list
+        data.extend_from_slice(&[4; 20]);
+
+        // all segments
+        {
+            let iter = Ipv6SegmentListIter::from_routing_data(4, &data).unwrap();
+            assert_eq!(3, iter.len());
+            assert_eq!(iter.collect::<Vec<_>>(), [[1; 16], [2; 16], [3; 16]]);
+        }
+
+        // last entry limits the segments
+        {
+            data[0] = 0;
+            let iter = Ipv6SegmentListIter::from_routing_data(4, &data).unwrap();
+            assert_eq!(iter.collect::<Vec<_>>(), [[1; 16]]);
+        }
+
+        // data limits the segments
+        {
+            data[0] = 0xff;
+            let iter = Ipv6SegmentListIter::from_routing_data(4, &data[..4 + 16 * 2 + 15]).unwrap();
+            assert_eq!(iter.collect::<Vec<_>>(), [[1; 16], [2; 16]]);
+        }
+    }
+}
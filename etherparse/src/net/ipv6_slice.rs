@@ -21,6 +21,16 @@ impl<'a> Ipv6Slice<'a> {
     /// If you want to ignore these kind of length errors based on the length
     /// fields in the IP headers use [`Ipv6Slice::from_slice_lax`] instead.
     pub fn from_slice(slice: &'a [u8]) -> Result<Ipv6Slice<'a>, SliceError> {
+        Self::from_slice_with_max_exts(slice, None)
+    }
+
+    /// Separates and validates the IPv6 header & extension headers like
+    /// [`Ipv6Slice::from_slice`] but limits the number of decoded extension
+    /// headers to `max_exts` (if set).
+    pub(crate) fn from_slice_with_max_exts(
+        slice: &'a [u8],
+        max_exts: Option<usize>,
+    ) -> Result<Ipv6Slice<'a>, SliceError> {
         // try reading the header
         let header = Ipv6HeaderSlice::from_slice(slice).map_err(|err| {
             use crate::err::ipv6::HeaderSliceError::*;
@@ -33,20 +43,35 @@ impl<'a> Ipv6Slice<'a> {
         // restrict slice by the length specified in the header
         let (header_payload, len_source) =
             if 0 == header.payload_length() && slice.len() > Ipv6Header::LEN {
-                // In case the payload_length is 0 assume that the entire
-                // rest of the slice is part of the packet until the jumbogram
-                // parameters can be parsed.
-
-                // TODO: Add payload length parsing from the jumbogram
-                (
-                    unsafe {
-                        core::slice::from_raw_parts(
-                            slice.as_ptr().add(Ipv6Header::LEN),
-                            slice.len() - Ipv6Header::LEN,
-                        )
-                    },
-                    LenSource::Slice,
-                )
+                // SAFETY: Safe as the slice is longer then Ipv6Header::LEN.
+                let rest = unsafe {
+                    core::slice::from_raw_parts(
+                        slice.as_ptr().add(Ipv6Header::LEN),
+                        slice.len() - Ipv6Header::LEN,
+                    )
+                };
+                if let Some(jumbo_len) =
+                    Ipv6Option::jumbo_payload_after_ipv6_header(header.next_header(), rest)
+                {
+                    // jumbogram, the payload length is determined by the
+                    // "jumbo payload" option in the hop by hop header
+                    let payload_len = usize::try_from(jumbo_len).unwrap_or(usize::MAX);
+                    if rest.len() < payload_len {
+                        return Err(SliceError::Len(LenError {
+                            required_len: Ipv6Header::LEN.saturating_add(payload_len),
+                            len: slice.len(),
+                            len_source: LenSource::Slice,
+                            layer: Layer::Ipv6Packet,
+                            layer_start_offset: 0,
+                        }));
+                    }
+                    (&rest[..payload_len], LenSource::Ipv6JumboPayloadLen)
+                } else {
+                    // In case the payload_length is 0 & no jumbo payload
+                    // option is present assume that the entire rest of the
+                    // slice is part of the packet.
+                    (rest, LenSource::Slice)
+                }
             } else {
                 let payload_len = usize::from(header.payload_length());
                 let expected_len = Ipv6Header::LEN + payload_len;
@@ -71,6 +96,105 @@ impl<'a> Ipv6Slice<'a> {
                 }
             };
 
+        // parse extension headers
+        let (exts, payload_ip_number, payload) = Ipv6ExtensionsSlice::from_slice_with_max_headers(
+            header.next_header(),
+            header_payload,
+            max_exts,
+        )
+        .map_err(|err| {
+            // modify length errors
+            use crate::err::ipv6_exts::HeaderSliceError::*;
+            match err {
+                Len(mut err) => {
+                    err.len_source = if LenSource::Ipv6JumboPayloadLen == len_source {
+                        len_source
+                    } else {
+                        LenSource::Ipv6HeaderPayloadLen
+                    };
+                    err.layer_start_offset += Ipv6Header::LEN;
+                    SliceError::Len(err)
+                }
+                Content(err) => SliceError::Exts(err),
+            }
+        })?;
+
+        let fragmented = exts.is_fragmenting_payload();
+        Ok(Ipv6Slice {
+            header,
+            exts,
+            payload: IpPayloadSlice {
+                ip_number: payload_ip_number,
+                fragmented,
+                len_source,
+                payload,
+            },
+        })
+    }
+
+    /// Separates and validates IPv6 headers (including extension headers)
+    /// like [`Ipv6Slice::from_slice`] but uses the given `payload_len`
+    /// instead of the "payload length" field in the IPv6 header (or the
+    /// "Jumbo Payload" option) to determine the end of the packet.
+    ///
+    /// This is useful for jumbograms & packets for which the real payload
+    /// length is known from another source (e.g. an outer layer or a
+    /// capture with a wrong length field). The `payload_len` is the length
+    /// of the data after the IPv6 header (including the extension headers).
+    ///
+    /// The `len_source` of the returned [`IpPayloadSlice`] is set to
+    /// [`LenSource::Ipv6PayloadLenOverride`]. An [`crate::err::LenError`]
+    /// is returned if the slice is shorter then the IPv6 header plus the
+    /// given `payload_len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, Ipv6Header, Ipv6Slice, LenSource};
+    ///
+    /// let header = Ipv6Header {
+    ///     payload_length: 0, // e.g. not yet set
+    ///     next_header: ip_number::UDP,
+    ///     ..Default::default()
+    /// };
+    /// let mut packet = Vec::new();
+    /// header.write(&mut packet).unwrap();
+    /// packet.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+    ///
+    /// let slice = Ipv6Slice::from_slice_with_payload_len(&packet, 4).unwrap();
+    /// assert_eq!(slice.payload().payload, &[1, 2, 3, 4]);
+    /// assert_eq!(slice.payload().len_source, LenSource::Ipv6PayloadLenOverride);
+    /// ```
+    pub fn from_slice_with_payload_len(
+        slice: &'a [u8],
+        payload_len: usize,
+    ) -> Result<Ipv6Slice<'a>, SliceError> {
+        // try reading the header
+        let header = Ipv6HeaderSlice::from_slice(slice).map_err(|err| {
+            use crate::err::ipv6::HeaderSliceError::*;
+            match err {
+                Len(err) => SliceError::Len(err),
+                Content(err) => SliceError::Header(err),
+            }
+        })?;
+
+        // restrict slice by the given payload length
+        let expected_len = Ipv6Header::LEN.saturating_add(payload_len);
+        if slice.len() < expected_len {
+            return Err(SliceError::Len(LenError {
+                required_len: expected_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::Ipv6Packet,
+                layer_start_offset: 0,
+            }));
+        }
+        // SAFETY: Safe as the slice length was verified above to be at
+        // least Ipv6Header::LEN + payload_len.
+        let header_payload = unsafe {
+            core::slice::from_raw_parts(slice.as_ptr().add(Ipv6Header::LEN), payload_len)
+        };
+
         // parse extension headers
         let (exts, payload_ip_number, payload) =
             Ipv6ExtensionsSlice::from_slice(header.next_header(), header_payload).map_err(
@@ -79,7 +203,7 @@ impl<'a> Ipv6Slice<'a> {
                     use crate::err::ipv6_exts::HeaderSliceError::*;
                     match err {
                         Len(mut err) => {
-                            err.len_source = LenSource::Ipv6HeaderPayloadLen;
+                            err.len_source = LenSource::Ipv6PayloadLenOverride;
                             err.layer_start_offset += Ipv6Header::LEN;
                             SliceError::Len(err)
                         }
@@ -95,7 +219,7 @@ impl<'a> Ipv6Slice<'a> {
             payload: IpPayloadSlice {
                 ip_number: payload_ip_number,
                 fragmented,
-                len_source,
+                len_source: LenSource::Ipv6PayloadLenOverride,
                 payload,
             },
         })
@@ -135,7 +259,14 @@ impl<'a> Ipv6Slice<'a> {
     /// field in the IPv6 header is
     ///
     /// * Bigger then the given slice (payload cannot fully be seperated).
-    /// * The value `0`.
+    /// * The value `0` and no "Jumbo Payload" option is present in a
+    ///   hop by hop header (or the slice is smaller then the jumbo payload length).
+    ///
+    /// The version field is checked before the length of the slice (same as
+    /// in [`Ipv6HeaderSlice::from_slice`]). Data that does not start with an
+    /// IPv6 version number is therefore reported as an
+    /// [`err::ipv6::HeaderError::UnexpectedVersion`] error even if it is shorter
+    /// than an IPv6 header.
     pub fn from_slice_lax(slice: &'a [u8]) -> Result<Ipv6Slice<'a>, SliceError> {
         // try reading the header
         let header = Ipv6HeaderSlice::from_slice(slice).map_err(|err| {
@@ -149,20 +280,26 @@ impl<'a> Ipv6Slice<'a> {
         // restrict slice by the length specified in the header
         let (header_payload, len_source) =
             if 0 == header.payload_length() && slice.len() > Ipv6Header::LEN {
-                // In case the payload_length is 0 assume that the entire
-                // rest of the slice is part of the packet until the jumbogram
-                // parameters can be parsed.
-
-                // TODO: Add payload length parsing from the jumbogram
-                (
-                    unsafe {
-                        core::slice::from_raw_parts(
-                            slice.as_ptr().add(Ipv6Header::LEN),
-                            slice.len() - Ipv6Header::LEN,
-                        )
-                    },
-                    LenSource::Slice,
-                )
+                // SAFETY: Safe as the slice is longer then Ipv6Header::LEN.
+                let rest = unsafe {
+                    core::slice::from_raw_parts(
+                        slice.as_ptr().add(Ipv6Header::LEN),
+                        slice.len() - Ipv6Header::LEN,
+                    )
+                };
+                match Ipv6Option::jumbo_payload_after_ipv6_header(header.next_header(), rest)
+                    .map(|v| usize::try_from(v).unwrap_or(usize::MAX))
+                {
+                    // jumbogram, the payload length is determined by the
+                    // "jumbo payload" option in the hop by hop header
+                    Some(payload_len) if payload_len <= rest.len() => {
+                        (&rest[..payload_len], LenSource::Ipv6JumboPayloadLen)
+                    }
+                    // In case the payload_length is 0 (and no usable jumbo
+                    // payload option is present) assume that the entire rest
+                    // of the slice is part of the packet.
+                    _ => (rest, LenSource::Slice),
+                }
             } else {
                 let payload_len = usize::from(header.payload_length());
                 let expected_len = Ipv6Header::LEN + payload_len;
@@ -526,6 +663,134 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn from_slice_with_payload_len(
+            ipv6_base in ipv6_any(),
+            auth_base in ip_auth_any(),
+            header_payload_len in any::<u16>()
+        ) {
+            let payload: [u8;6] = [1,2,3,4,5,6];
+
+            // build packet (with a payload length field that is ignored)
+            let data = {
+                let mut data = Vec::with_capacity(
+                    ipv6_base.header_len() +
+                    auth_base.header_len() +
+                    payload.len() +
+                    4
+                );
+                let mut ipv6 = ipv6_base.clone();
+                ipv6.payload_length = header_payload_len;
+                ipv6.next_header = AUTH;
+                let mut auth = auth_base.clone();
+                auth.next_header = UDP;
+                data.extend_from_slice(&ipv6.to_bytes());
+                data.extend_from_slice(&auth.to_bytes());
+                data.extend_from_slice(&payload);
+                data.extend_from_slice(&[0,0,0,0]);
+                data
+            };
+
+            // ok (payload length smaller then the slice)
+            {
+                let payload_len = auth_base.header_len() + payload.len();
+                let actual = Ipv6Slice::from_slice_with_payload_len(&data, payload_len).unwrap();
+                prop_assert_eq!(actual.header().slice(), &data[..ipv6_base.header_len()]);
+                let (expected, _, _) = Ipv6ExtensionsSlice::from_slice(
+                    AUTH,
+                    &data[ipv6_base.header_len()..ipv6_base.header_len() + payload_len]
+                ).unwrap();
+                prop_assert_eq!(actual.extensions(), &expected);
+                prop_assert_eq!(
+                    actual.payload(),
+                    &IpPayloadSlice{
+                        ip_number: UDP.into(),
+                        fragmented: false,
+                        len_source: LenSource::Ipv6PayloadLenOverride,
+                        payload: &payload,
+                    }
+                );
+            }
+
+            // ok (payload length equal to the slice)
+            {
+                let actual = Ipv6Slice::from_slice_with_payload_len(
+                    &data,
+                    data.len() - ipv6_base.header_len()
+                ).unwrap();
+                prop_assert_eq!(
+                    actual.payload().payload,
+                    &data[ipv6_base.header_len() + auth_base.header_len()..]
+                );
+            }
+
+            // header length error
+            for len in 0..Ipv6Header::LEN {
+                prop_assert_eq!(
+                    Ipv6Slice::from_slice_with_payload_len(&data[..len], 0).unwrap_err(),
+                    SliceError::Len(LenError{
+                        required_len: Ipv6Header::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::Ipv6Header,
+                        layer_start_offset: 0
+                    })
+                );
+            }
+
+            // payload length bigger then the slice
+            {
+                let payload_len = data.len() - ipv6_base.header_len() + 1;
+                prop_assert_eq!(
+                    Ipv6Slice::from_slice_with_payload_len(&data, payload_len).unwrap_err(),
+                    SliceError::Len(LenError{
+                        required_len: data.len() + 1,
+                        len: data.len(),
+                        len_source: LenSource::Slice,
+                        layer: Layer::Ipv6Packet,
+                        layer_start_offset: 0,
+                    })
+                );
+                prop_assert_eq!(
+                    Ipv6Slice::from_slice_with_payload_len(&data, usize::MAX).unwrap_err(),
+                    SliceError::Len(LenError{
+                        required_len: usize::MAX,
+                        len: data.len(),
+                        len_source: LenSource::Slice,
+                        layer: Layer::Ipv6Packet,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+
+            // payload length smaller then the auth header
+            prop_assert_eq!(
+                Ipv6Slice::from_slice_with_payload_len(&data, auth_base.header_len() - 1).unwrap_err(),
+                SliceError::Len(LenError{
+                    required_len: auth_base.header_len(),
+                    len: auth_base.header_len() - 1,
+                    len_source: LenSource::Ipv6PayloadLenOverride,
+                    layer: Layer::IpAuthHeader,
+                    layer_start_offset: ipv6_base.header_len(),
+                })
+            );
+
+            // header content error
+            {
+                use crate::err::ipv6::HeaderError;
+                let mut data = data.clone();
+                data[0] = data[0] & 0x0f; // version 0
+                prop_assert_eq!(
+                    Ipv6Slice::from_slice_with_payload_len(&data, 0).unwrap_err(),
+                    SliceError::Header(
+                        HeaderError::UnexpectedVersion{ version_number: 0 }
+                    )
+                );
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn from_slice_lax(
@@ -761,6 +1026,79 @@ mod test {
         }
     }
 
+    #[test]
+    fn from_slice_jumbogram() {
+        use crate::ip_number::IPV6_HOP_BY_HOP;
+
+        // jumbogram with a hop by hop header containing a jumbo payload option
+        // jumbo payload lengths have to be bigger then 65535 (RFC 2675)
+        let payload: Vec<u8> = (0..0x1_0000u32).map(|v| v as u8).collect();
+        let jumbo_len = 8 + payload.len();
+        let mut data = Vec::new();
+        data.extend_from_slice(
+            &Ipv6Header {
+                payload_length: 0,
+                next_header: IPV6_HOP_BY_HOP,
+                ..Default::default()
+            }
+            .to_bytes(),
+        );
+        data.extend_from_slice(&[UDP.0, 0, Ipv6Option::TYPE_JUMBO_PAYLOAD, 4]);
+        data.extend_from_slice(&(jumbo_len as u32).to_be_bytes());
+        data.extend_from_slice(&payload);
+        // data after the packet
+        data.extend_from_slice(&[5, 6]);
+
+        // ok
+        for actual in [
+            Ipv6Slice::from_slice(&data).unwrap(),
+            Ipv6Slice::from_slice_lax(&data).unwrap(),
+        ] {
+            assert_eq!(
+                actual.payload(),
+                &IpPayloadSlice {
+                    ip_number: UDP,
+                    fragmented: false,
+                    len_source: LenSource::Ipv6JumboPayloadLen,
+                    payload: &payload,
+                }
+            );
+            assert_eq!(
+                Some(jumbo_len as u32),
+                Ipv6RawExtHeaderSlice::from_slice(actual.extensions().slice())
+                    .unwrap()
+                    .jumbo_payload()
+            );
+        }
+
+        // slice smaller then the jumbo payload length
+        {
+            let len = Ipv6Header::LEN + jumbo_len - 1;
+            assert_eq!(
+                Ipv6Slice::from_slice(&data[..len]).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: Ipv6Header::LEN + jumbo_len,
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: Layer::Ipv6Packet,
+                    layer_start_offset: 0,
+                })
+            );
+            let actual = Ipv6Slice::from_slice_lax(&data[..len]).unwrap();
+            assert_eq!(actual.payload().len_source, LenSource::Slice);
+            assert_eq!(actual.payload().payload, &payload[..payload.len() - 1]);
+        }
+
+        // jumbo payload lengths smaller then 65536 are invalid and ignored
+        {
+            let mut data = data.clone();
+            data[Ipv6Header::LEN + 4..Ipv6Header::LEN + 8].copy_from_slice(&4u32.to_be_bytes());
+            let actual = Ipv6Slice::from_slice(&data).unwrap();
+            assert_eq!(actual.payload().len_source, LenSource::Slice);
+            assert_eq!(actual.payload().payload, &data[Ipv6Header::LEN + 8..]);
+        }
+    }
+
     #[test]
     fn is_payload_fragmented() {
         use crate::ip_number::{IPV6_FRAG, UDP};
@@ -49,22 +49,16 @@ impl<'a> LaxIpSlice<'a> {
         }
     }
 
-    /// Return the source address as an std::net::Ipvddr (requires
-    /// crate feature `std`).
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-    pub fn source_addr(&self) -> std::net::IpAddr {
+    /// Return the source address as an core::net::IpAddr.
+    pub fn source_addr(&self) -> core::net::IpAddr {
         match self {
             LaxIpSlice::Ipv4(s) => s.header().source_addr().into(),
             LaxIpSlice::Ipv6(s) => s.header().source_addr().into(),
         }
     }
 
-    /// Return the destination address as an std::net::IpAddr (requires
-    /// crate feature `std`).
-    #[cfg(feature = "std")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-    pub fn destination_addr(&self) -> std::net::IpAddr {
+    /// Return the destination address as an core::net::IpAddr.
+    pub fn destination_addr(&self) -> core::net::IpAddr {
         match self {
             LaxIpSlice::Ipv4(s) => s.header().destination_addr().into(),
             LaxIpSlice::Ipv6(s) => s.header().destination_addr().into(),
@@ -142,7 +136,8 @@ impl<'a> LaxIpSlice<'a> {
     /// if the `payload_length` is
     ///
     /// * Bigger then the given slice (payload cannot fully be seperated).
-    /// * The value `0`.
+    /// * The value `0` and no "Jumbo Payload" option is present in a
+    ///   hop by hop header (or the slice is smaller then the jumbo payload length).
     pub fn from_slice(
         slice: &[u8],
     ) -> Result<
@@ -355,19 +350,31 @@ impl<'a> LaxIpSlice<'a> {
                     let payload_len = usize::from(header.payload_length());
                     let (header_payload, len_source, incomplete) =
                         if 0 == payload_len && slice.len() > Ipv6Header::LEN {
-                            // zero set as payload len, assume jumbograms or unitialized
-                            // length and use the slice length as a fallback value
-                            // TODO: Add payload length parsing from the jumbogram for the zero case
-                            (
-                                unsafe {
-                                    core::slice::from_raw_parts(
-                                        slice.as_ptr().add(Ipv6Header::LEN),
-                                        slice.len() - Ipv6Header::LEN,
-                                    )
-                                },
-                                LenSource::Slice,
-                                false,
+                            // SAFETY: Safe as the slice is longer then Ipv6Header::LEN.
+                            let rest = unsafe {
+                                core::slice::from_raw_parts(
+                                    slice.as_ptr().add(Ipv6Header::LEN),
+                                    slice.len() - Ipv6Header::LEN,
+                                )
+                            };
+                            match Ipv6Option::jumbo_payload_after_ipv6_header(
+                                header.next_header(),
+                                rest,
                             )
+                            .map(|v| usize::try_from(v).unwrap_or(usize::MAX))
+                            {
+                                // jumbogram, the payload length is determined by the
+                                // "jumbo payload" option in the hop by hop header
+                                Some(jumbo_len) if jumbo_len <= rest.len() => {
+                                    (&rest[..jumbo_len], LenSource::Ipv6JumboPayloadLen, false)
+                                }
+                                // slice is smaller then the jumbo payload length
+                                Some(_) => (rest, LenSource::Slice, true),
+                                // zero set as payload len & no jumbo payload option,
+                                // assume unitialized length and use the slice length
+                                // as a fallback value
+                                None => (rest, LenSource::Slice, false),
+                            }
                         } else if slice.len() - Ipv6Header::LEN < payload_len {
                             // slice is smaller then the assumed payload length
                             (
@@ -442,8 +449,8 @@ mod test {
     use super::*;
     use crate::test_gens::*;
     use alloc::{format, vec::Vec};
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
     use proptest::prelude::*;
-    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
     #[test]
     fn debug_clone_eq() {
@@ -531,7 +538,6 @@ mod test {
         }
     }
 
-    #[cfg(feature = "std")]
     #[test]
     fn source_addr() {
         // ipv4
@@ -565,7 +571,6 @@ mod test {
         }
     }
 
-    #[cfg(feature = "std")]
     #[test]
     fn destination_addr() {
         use crate::ip_number::UDP;
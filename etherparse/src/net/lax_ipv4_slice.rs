@@ -68,7 +68,12 @@ impl<'a> LaxIpv4Slice<'a> {
     /// if the `len_source` value in the returned [`LaxIpPayloadSlice`] is set to
     /// [`LenSource::Slice`]. If a substitution was not needed `len_source`
     /// is set to [`LenSource::Ipv4HeaderTotalLen`].
-
+    ///
+    /// The version field is checked before the length of the slice (same as
+    /// in [`Ipv4HeaderSlice::from_slice`]). Data that does not start with an
+    /// IPv4 version number is therefore reported as an
+    /// [`err::ipv4::HeaderError::UnexpectedVersion`] error even if it is shorter
+    /// than an IPv4 header.
     pub fn from_slice(
         slice: &[u8],
     ) -> Result<(LaxIpv4Slice, Option<err::ip_auth::HeaderSliceError>), err::ipv4::HeaderSliceError>
@@ -524,4 +529,16 @@ mod test {
             assert!(slice.is_payload_fragmented());
         }
     }
+
+    #[test]
+    fn from_slice_version_before_len() {
+        use err::ipv4::{HeaderError::UnexpectedVersion, HeaderSliceError::Content};
+        // IPv6 version (e.g. a mixed up buffer) shorter than an IPv4 header
+        for data in [&[0x60u8][..], &[0x60, 0, 0, 0][..]] {
+            assert_eq!(
+                LaxIpv4Slice::from_slice(data).unwrap_err(),
+                Content(UnexpectedVersion { version_number: 6 })
+            );
+        }
+    }
 }
@@ -65,7 +65,14 @@ impl<'a> LaxIpv6Slice<'a> {
     /// field in the IPv6 header is
     ///
     /// * Bigger then the given slice (payload cannot fully be seperated).
-    /// * The value `0`.
+    /// * The value `0` and no "Jumbo Payload" option is present in a
+    ///   hop by hop header (or the slice is smaller then the jumbo payload length).
+    ///
+    /// The version field is checked before the length of the slice (same as
+    /// in [`Ipv6HeaderSlice::from_slice`]). Data that does not start with an
+    /// IPv6 version number is therefore reported as an
+    /// [`err::ipv6::HeaderError::UnexpectedVersion`] error even if it is shorter
+    /// than an IPv6 header.
     pub fn from_slice(
         slice: &'a [u8],
     ) -> Result<
@@ -81,21 +88,28 @@ impl<'a> LaxIpv6Slice<'a> {
         // restrict slice by the length specified in the header
         let (header_payload, len_source, incomplete) =
             if 0 == header.payload_length() && slice.len() > Ipv6Header::LEN {
-                // In case the payload_length is 0 assume that the entire
-                // rest of the slice is part of the packet until the jumbogram
-                // parameters can be parsed.
-
-                // TODO: Add payload length parsing from the jumbogram
-                (
-                    unsafe {
-                        core::slice::from_raw_parts(
-                            slice.as_ptr().add(Ipv6Header::LEN),
-                            slice.len() - Ipv6Header::LEN,
-                        )
-                    },
-                    LenSource::Slice,
-                    false,
-                )
+                // SAFETY: Safe as the slice is longer then Ipv6Header::LEN.
+                let rest = unsafe {
+                    core::slice::from_raw_parts(
+                        slice.as_ptr().add(Ipv6Header::LEN),
+                        slice.len() - Ipv6Header::LEN,
+                    )
+                };
+                match Ipv6Option::jumbo_payload_after_ipv6_header(header.next_header(), rest)
+                    .map(|v| usize::try_from(v).unwrap_or(usize::MAX))
+                {
+                    // jumbogram, the payload length is determined by the
+                    // "jumbo payload" option in the hop by hop header
+                    Some(jumbo_len) if jumbo_len <= rest.len() => {
+                        (&rest[..jumbo_len], LenSource::Ipv6JumboPayloadLen, false)
+                    }
+                    // slice is smaller then the jumbo payload length
+                    Some(_) => (rest, LenSource::Slice, true),
+                    // In case the payload_length is 0 (and no jumbo payload
+                    // option is present) assume that the entire rest of the
+                    // slice is part of the packet.
+                    None => (rest, LenSource::Slice, false),
+                }
             } else {
                 let payload_len = usize::from(header.payload_length());
                 let expected_len = Ipv6Header::LEN + payload_len;
@@ -521,6 +535,60 @@ mod test {
         }
     }
 
+    #[test]
+    fn from_slice_jumbogram() {
+        // jumbo payload lengths have to be bigger then 65535 (RFC 2675)
+        let payload: Vec<u8> = (0..0x1_0000u32).map(|v| v as u8).collect();
+        let jumbo_len = 8 + payload.len();
+        let mut data = Vec::new();
+        data.extend_from_slice(
+            &Ipv6Header {
+                payload_length: 0,
+                next_header: ip_number::IPV6_HOP_BY_HOP,
+                ..Default::default()
+            }
+            .to_bytes(),
+        );
+        data.extend_from_slice(&[ip_number::UDP.0, 0, Ipv6Option::TYPE_JUMBO_PAYLOAD, 4]);
+        data.extend_from_slice(&(jumbo_len as u32).to_be_bytes());
+        data.extend_from_slice(&payload);
+        // data after the packet
+        data.extend_from_slice(&[5, 6]);
+
+        // ok
+        {
+            let (actual, stop_err) = LaxIpv6Slice::from_slice(&data).unwrap();
+            assert_eq!(None, stop_err);
+            assert_eq!(
+                actual.payload(),
+                &LaxIpPayloadSlice {
+                    incomplete: false,
+                    ip_number: ip_number::UDP,
+                    fragmented: false,
+                    len_source: LenSource::Ipv6JumboPayloadLen,
+                    payload: &payload,
+                }
+            );
+        }
+
+        // slice smaller then the jumbo payload length
+        {
+            let (actual, stop_err) =
+                LaxIpv6Slice::from_slice(&data[..Ipv6Header::LEN + jumbo_len - 1]).unwrap();
+            assert_eq!(None, stop_err);
+            assert_eq!(
+                actual.payload(),
+                &LaxIpPayloadSlice {
+                    incomplete: true,
+                    ip_number: ip_number::UDP,
+                    fragmented: false,
+                    len_source: LenSource::Slice,
+                    payload: &payload[..payload.len() - 1],
+                }
+            );
+        }
+    }
+
     #[test]
     fn is_payload_fragmented() {
         use crate::ip_number::{IPV6_FRAG, UDP};
@@ -572,4 +640,20 @@ mod test {
                 .is_payload_fragmented());
         }
     }
+
+    #[test]
+    fn from_slice_version_before_len() {
+        use ipv6::{HeaderError::UnexpectedVersion, HeaderSliceError::Content};
+        // IPv4 version (e.g. a mixed up buffer) shorter than an IPv6 header
+        for data in [&[0x45u8][..], &[0x45, 0, 0, 0][..]] {
+            assert_eq!(
+                LaxIpv6Slice::from_slice(data).unwrap_err(),
+                Content(UnexpectedVersion { version_number: 4 })
+            );
+            assert_eq!(
+                Ipv6Slice::from_slice_lax(data).unwrap_err(),
+                ipv6::SliceError::Header(UnexpectedVersion { version_number: 4 })
+            );
+        }
+    }
 }
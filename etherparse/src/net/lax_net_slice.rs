@@ -32,6 +32,33 @@ impl<'a> LaxNetSlice<'a> {
             LaxNetSlice::Ipv6(s) => Some(&s.payload),
         }
     }
+
+    /// Returns the slice containing the IP header, the IPv4 options & the
+    /// decoded IP extension headers (but not the IP payload).
+    pub fn header_slice(&self) -> &'a [u8] {
+        match self {
+            LaxNetSlice::Ipv4(s) => {
+                let header = s.header.slice();
+                let exts_len = s.exts.auth.map(|auth| auth.slice().len()).unwrap_or(0);
+                unsafe {
+                    // SAFETY: Safe as the extension headers directly follow
+                    // the IPv4 header in the sliced data.
+                    core::slice::from_raw_parts(header.as_ptr(), header.len() + exts_len)
+                }
+            }
+            LaxNetSlice::Ipv6(s) => {
+                let header = s.header.slice();
+                unsafe {
+                    // SAFETY: Safe as the extension headers directly follow
+                    // the IPv6 header in the sliced data.
+                    core::slice::from_raw_parts(
+                        header.as_ptr(),
+                        header.len() + s.exts.slice().len(),
+                    )
+                }
+            }
+        }
+    }
 }
 
 impl<'a> From<LaxIpSlice<'a>> for LaxNetSlice<'a> {
@@ -146,6 +173,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn header_slice() {
+        let payload = [1, 2, 3, 4];
+
+        // ipv4 with options & auth header
+        {
+            let mut bytes = Vec::new();
+            PacketBuilder::ip(IpHeaders::Ipv4(
+                Ipv4Header {
+                    options: [1, 2, 3, 4].into(),
+                    ..Default::default()
+                },
+                Ipv4Extensions {
+                    auth: Some(IpAuthHeader::new(IpNumber::UDP, 1, 2, &[3; 4]).unwrap()),
+                },
+            ))
+            .udp(1, 2)
+            .write(&mut bytes, &payload)
+            .unwrap();
+            let s = LaxNetSlice::Ipv4(LaxIpv4Slice::from_slice(&bytes).unwrap().0);
+            assert_eq!(s.header_slice(), &bytes[..Ipv4Header::MIN_LEN + 4 + 16]);
+        }
+
+        // ipv6 with extension header
+        {
+            let mut bytes = Vec::new();
+            PacketBuilder::ip(IpHeaders::Ipv6(
+                Default::default(),
+                Ipv6Extensions {
+                    hop_by_hop_options: Some(
+                        Ipv6RawExtHeader::new_raw(IpNumber::UDP, &[0; 6]).unwrap(),
+                    ),
+                    ..Default::default()
+                },
+            ))
+            .udp(1, 2)
+            .write(&mut bytes, &payload)
+            .unwrap();
+            let s = LaxNetSlice::Ipv6(LaxIpv6Slice::from_slice(&bytes).unwrap().0);
+            assert_eq!(s.header_slice(), &bytes[..Ipv6Header::LEN + 8]);
+        }
+    }
+
     #[test]
     fn from() {
         // IpSlice::Ipv4
@@ -1,15 +1,51 @@
+mod arp_hardware_id;
+pub use arp_hardware_id::*;
+
+mod arp_operation;
+pub use arp_operation::*;
+
+mod arp_packet;
+pub use arp_packet::*;
+
+mod arp_packet_slice;
+pub use arp_packet_slice::*;
+
+mod esp_header;
+pub use esp_header::*;
+
+mod esp_header_slice;
+pub use esp_header_slice::*;
+
+mod esp_slice;
+pub use esp_slice::*;
+
+mod gre_header;
+pub use gre_header::*;
+
+mod gre_header_slice;
+pub use gre_header_slice::*;
+
+mod gre_tunnel_headers;
+pub use gre_tunnel_headers::*;
+
 mod ip_auth_header;
 pub use ip_auth_header::*;
 
 mod ip_auth_header_slice;
 pub use ip_auth_header_slice::*;
 
+mod ip_chain_end;
+pub use ip_chain_end::*;
+
 mod ip_frag_offset;
 pub use ip_frag_offset::*;
 
 mod ip_headers;
 pub use ip_headers::*;
 
+mod ip_ignore_fields;
+pub use ip_ignore_fields::*;
+
 mod ip_number_impl;
 pub use ip_number_impl::*;
 
@@ -37,9 +73,18 @@ pub use ipv4_header::*;
 mod ipv4_header_slice;
 pub use ipv4_header_slice::*;
 
+mod ipv4_option;
+pub use ipv4_option::*;
+
+mod ipv4_option_read_error;
+pub use ipv4_option_read_error::*;
+
 mod ipv4_options;
 pub use ipv4_options::*;
 
+mod ipv4_options_iterator;
+pub use ipv4_options_iterator::*;
+
 mod ipv4_slice;
 pub use ipv4_slice::*;
 
@@ -52,6 +97,9 @@ pub use ipv6_ext_slice_iter::*;
 mod ipv6_exts;
 pub use ipv6_exts::*;
 
+mod ipv6_exts_iter;
+pub use ipv6_exts_iter::*;
+
 mod ipv6_exts_slice;
 pub use ipv6_exts_slice::*;
 
@@ -76,9 +124,39 @@ pub use ipv6_raw_ext_header::*;
 mod ipv6_raw_ext_header_slice;
 pub use ipv6_raw_ext_header_slice::*;
 
+mod ipv6_addr_scope;
+pub use ipv6_addr_scope::*;
+
+mod ipv6_dest_options_header;
+pub use ipv6_dest_options_header::*;
+
+mod ipv6_dest_options_header_slice;
+pub use ipv6_dest_options_header_slice::*;
+
+mod ipv6_mobility_header;
+pub use ipv6_mobility_header::*;
+
+mod ipv6_mobility_header_slice;
+pub use ipv6_mobility_header_slice::*;
+
+mod ipv6_option;
+pub use ipv6_option::*;
+
+mod ipv6_options_iter;
+pub use ipv6_options_iter::*;
+
 mod ipv6_routing_exts;
 pub use ipv6_routing_exts::*;
 
+mod ipv6_routing_header;
+pub use ipv6_routing_header::*;
+
+mod ipv6_routing_header_slice;
+pub use ipv6_routing_header_slice::*;
+
+mod ipv6_segment_list_iter;
+pub use ipv6_segment_list_iter::*;
+
 mod ipv6_slice;
 pub use ipv6_slice::*;
 
@@ -5,7 +5,8 @@ use crate::*;
 pub type IpHeader = NetHeaders;
 
 /// Headers on the network layer (e.g. IP, ARP, ...).
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[allow(clippy::large_enum_variant)]
 pub enum NetHeaders {
     /// IPv4 header & extension headers.
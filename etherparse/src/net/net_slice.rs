@@ -15,16 +15,50 @@ pub enum NetSlice<'a> {
     Ipv4(Ipv4Slice<'a>),
     /// The ipv6 header & the decoded extension headers.
     Ipv6(Ipv6Slice<'a>),
+    /// The ARP packet.
+    Arp(ArpPacketSlice<'a>),
 }
 
 impl<'a> NetSlice<'a> {
     /// Returns a reference to ip payload if the net slice contains
-    /// an ipv4 or ipv6 slice.
+    /// an ipv4 or ipv6 slice (`None` for ARP).
     #[inline]
     pub fn ip_payload_ref(&self) -> Option<&IpPayloadSlice<'a>> {
         match self {
             NetSlice::Ipv4(s) => Some(&s.payload),
             NetSlice::Ipv6(s) => Some(&s.payload),
+            NetSlice::Arp(_) => None,
+        }
+    }
+
+    /// Returns the slice containing the network headers.
+    ///
+    /// For IPv4 & IPv6 this includes the IPv4 options & the decoded IP
+    /// extension headers (but not the IP payload). For ARP the complete
+    /// ARP packet is returned.
+    pub fn header_slice(&self) -> &'a [u8] {
+        match self {
+            NetSlice::Ipv4(s) => {
+                let header = s.header.slice();
+                let exts_len = s.exts.auth.map(|auth| auth.slice().len()).unwrap_or(0);
+                unsafe {
+                    // SAFETY: Safe as the extension headers directly follow
+                    // the IPv4 header in the sliced data.
+                    core::slice::from_raw_parts(header.as_ptr(), header.len() + exts_len)
+                }
+            }
+            NetSlice::Ipv6(s) => {
+                let header = s.header.slice();
+                unsafe {
+                    // SAFETY: Safe as the extension headers directly follow
+                    // the IPv6 header in the sliced data.
+                    core::slice::from_raw_parts(
+                        header.as_ptr(),
+                        header.len() + s.exts.slice().len(),
+                    )
+                }
+            }
+            NetSlice::Arp(s) => s.slice(),
         }
     }
 }
@@ -137,6 +171,76 @@ mod tests {
                 })
             );
         }
+        // arp
+        {
+            let bytes = ArpPacket::new_eth_ipv4(
+                ArpOperation::REQUEST,
+                [1, 2, 3, 4, 5, 6],
+                [7, 8, 9, 10],
+                [0; 6],
+                [11, 12, 13, 14],
+            )
+            .to_bytes();
+            let s = NetSlice::Arp(ArpPacketSlice::from_slice(&bytes).unwrap());
+            assert_eq!(s.ip_payload_ref(), None);
+        }
+    }
+
+    #[test]
+    fn header_slice() {
+        let payload = [1, 2, 3, 4];
+
+        // ipv4 with options & auth header
+        {
+            let mut bytes = Vec::new();
+            PacketBuilder::ip(IpHeaders::Ipv4(
+                Ipv4Header {
+                    options: [1, 2, 3, 4].into(),
+                    ..Default::default()
+                },
+                Ipv4Extensions {
+                    auth: Some(IpAuthHeader::new(IpNumber::UDP, 1, 2, &[3; 4]).unwrap()),
+                },
+            ))
+            .udp(1, 2)
+            .write(&mut bytes, &payload)
+            .unwrap();
+            let s = NetSlice::Ipv4(Ipv4Slice::from_slice(&bytes).unwrap());
+            assert_eq!(s.header_slice(), &bytes[..Ipv4Header::MIN_LEN + 4 + 16]);
+        }
+
+        // ipv6 with extension header
+        {
+            let mut bytes = Vec::new();
+            PacketBuilder::ip(IpHeaders::Ipv6(
+                Default::default(),
+                Ipv6Extensions {
+                    hop_by_hop_options: Some(
+                        Ipv6RawExtHeader::new_raw(IpNumber::UDP, &[0; 6]).unwrap(),
+                    ),
+                    ..Default::default()
+                },
+            ))
+            .udp(1, 2)
+            .write(&mut bytes, &payload)
+            .unwrap();
+            let s = NetSlice::Ipv6(Ipv6Slice::from_slice(&bytes).unwrap());
+            assert_eq!(s.header_slice(), &bytes[..Ipv6Header::LEN + 8]);
+        }
+
+        // arp
+        {
+            let bytes = ArpPacket::new_eth_ipv4(
+                ArpOperation::REQUEST,
+                [1, 2, 3, 4, 5, 6],
+                [7, 8, 9, 10],
+                [0; 6],
+                [11, 12, 13, 14],
+            )
+            .to_bytes();
+            let s = NetSlice::Arp(ArpPacketSlice::from_slice(&bytes).unwrap());
+            assert_eq!(s.header_slice(), &bytes[..]);
+        }
     }
 
     #[test]
@@ -0,0 +1,223 @@
+use crate::*;
+use std::vec::Vec;
+
+/// Decoded packet headers & payload that own their data (do not
+/// borrow the buffer the packet was parsed from).
+///
+/// Created via [`SlicedPacket::to_owned_headers`], which copies the
+/// headers of a [`SlicedPacket`] into their header structs and the
+/// payload into a [`Vec`]. This allows storing parsed packets (e.g.
+/// sending them through a channel) after the original buffer has been
+/// dropped or reused.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{OwnedPacketHeaders, PacketBuilder, SlicedPacket};
+///
+/// let owned: OwnedPacketHeaders = {
+///     let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+///         .single_vlan(123.try_into().unwrap())
+///         .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+///         .udp(21, 1234);
+///     let mut packet = Vec::<u8>::with_capacity(builder.size(4));
+///     builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+///
+///     SlicedPacket::from_ethernet(&packet).unwrap().to_owned_headers()
+/// };
+///
+/// // the packet buffer is no longer needed
+/// assert!(owned.vlan.is_some());
+/// assert_eq!(owned.payload, [1, 2, 3, 4]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct OwnedPacketHeaders {
+    /// Ethernet II header if present.
+    pub link: Option<Ethernet2Header>,
+    /// Single or double vlan headers if present.
+    pub vlan: Option<VlanHeader>,
+    /// IPv4 or IPv6 header and IP extension headers if present.
+    pub net: Option<NetHeaders>,
+    /// TCP, UDP, ICMPv4 or ICMPv6 header if present.
+    pub transport: Option<TransportHeader>,
+    /// Payload after the last header present in the struct.
+    ///
+    /// Layers that can not be represented by the fields above (e.g.
+    /// IEEE 802.3, PPPoE, ARP, IGMP or ESP) are part of the payload.
+    pub payload: Vec<u8>,
+}
+
+impl<'a> From<&SlicedPacket<'a>> for OwnedPacketHeaders {
+    fn from(value: &SlicedPacket<'a>) -> Self {
+        let link = match &value.link {
+            Some(LinkSlice::Ethernet2(eth)) => Some(eth.to_header()),
+            _ => None,
+        };
+        let vlan = value.vlan.as_ref().map(|v| v.to_header());
+        let net = match &value.net {
+            Some(NetSlice::Ipv4(ipv4)) => Some(NetHeaders::Ipv4(
+                ipv4.header().to_header(),
+                ipv4.extensions().to_header(),
+            )),
+            Some(NetSlice::Ipv6(ipv6)) => Some(NetHeaders::Ipv6(
+                ipv6.header().to_header(),
+                Ipv6Extensions::from_slice_lax(
+                    ipv6.header().next_header(),
+                    ipv6.extensions().slice(),
+                )
+                .0,
+            )),
+            Some(NetSlice::Arp(_)) | None => None,
+        };
+
+        use TransportSlice::*;
+        let (transport, payload) = match &value.transport {
+            Some(Udp(udp)) => (Some(TransportHeader::Udp(udp.to_header())), udp.payload()),
+            Some(Tcp(tcp)) => (Some(TransportHeader::Tcp(tcp.to_header())), tcp.payload()),
+            Some(Icmpv4(icmp)) => (Some(TransportHeader::Icmpv4(icmp.header())), icmp.payload()),
+            Some(Icmpv6(icmp)) => (Some(TransportHeader::Icmpv6(icmp.header())), icmp.payload()),
+            Some(Igmp(_)) | Some(Esp(_)) | Some(Dccp(_)) | None => (
+                None,
+                if net.is_some() {
+                    value.ip_payload().map(|p| p.payload).unwrap_or(&[])
+                } else {
+                    value.ether_payload().map(|p| p.payload).unwrap_or(&[])
+                },
+            ),
+        };
+
+        OwnedPacketHeaders {
+            link,
+            vlan,
+            net,
+            transport,
+            payload: payload.to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::{format, vec, vec::Vec};
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = OwnedPacketHeaders {
+            link: None,
+            vlan: None,
+            net: None,
+            transport: None,
+            payload: vec![1, 2, 3],
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!("{:?}", value),
+            "OwnedPacketHeaders { link: None, vlan: None, net: None, transport: None, payload: [1, 2, 3] }"
+        );
+    }
+
+    /// Checks that the owned headers match the headers decoded via [`PacketHeaders`].
+    fn assert_matches_packet_headers(data: &[u8]) {
+        let actual = SlicedPacket::from_ethernet(data)
+            .unwrap()
+            .to_owned_headers();
+        let expected = PacketHeaders::from_ethernet_slice(data).unwrap();
+        assert_eq!(actual.link, expected.link);
+        assert_eq!(actual.vlan, expected.vlan);
+        assert_eq!(actual.net, expected.net);
+        assert_eq!(actual.transport, expected.transport);
+        assert_eq!(&actual.payload[..], expected.payload.slice());
+    }
+
+    #[test]
+    fn from_sliced_packet() {
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        // double vlan, ipv6 with extension headers & udp
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .double_vlan(1.try_into().unwrap(), 2.try_into().unwrap())
+                .ipv6([0; 16], [1; 16], 20)
+                .hop_by_hop(Ipv6RawExtHeader::new_raw(ip_number::UDP, &[0; 6]).unwrap())
+                .udp(21, 1234);
+            let mut data = Vec::new();
+            builder.write(&mut data, &payload).unwrap();
+            assert_matches_packet_headers(&data);
+
+            let actual = SlicedPacket::from_ethernet(&data)
+                .unwrap()
+                .to_owned_headers();
+            assert!(matches!(actual.vlan, Some(VlanHeader::Double(_))));
+            let (_, exts) = actual.net.as_ref().unwrap().ipv6_ref().unwrap();
+            assert!(exts.hop_by_hop_options.is_some());
+        }
+
+        // single vlan, ipv4 & tcp
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .single_vlan(1.try_into().unwrap())
+                .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                .tcp(21, 1234, 1, 2);
+            let mut data = Vec::new();
+            builder.write(&mut data, &payload).unwrap();
+            assert_matches_packet_headers(&data);
+        }
+
+        // icmpv4 & icmpv6
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                .icmpv4_echo_request(1, 2);
+            let mut data = Vec::new();
+            builder.write(&mut data, &payload).unwrap();
+            assert_matches_packet_headers(&data);
+
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv6([0; 16], [1; 16], 20)
+                .icmpv6_echo_reply(1, 2);
+            let mut data = Vec::new();
+            builder.write(&mut data, &payload).unwrap();
+            assert_matches_packet_headers(&data);
+        }
+
+        // unknown ip payload
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]).ipv4(
+                [192, 168, 1, 1],
+                [192, 168, 1, 2],
+                20,
+            );
+            let mut data = Vec::new();
+            builder
+                .write(&mut data, IpNumber::EXPERIMENTAL_AND_TESTING_0, &payload)
+                .unwrap();
+            let actual = SlicedPacket::from_ethernet(&data)
+                .unwrap()
+                .to_owned_headers();
+            assert!(actual.net.is_some());
+            assert!(actual.transport.is_none());
+            assert_eq!(actual.payload, payload);
+        }
+
+        // unknown ether type
+        {
+            let mut data = Vec::new();
+            data.extend_from_slice(
+                &Ethernet2Header {
+                    source: [1, 2, 3, 4, 5, 6],
+                    destination: [7, 8, 9, 10, 11, 12],
+                    ether_type: 0x1234.into(),
+                }
+                .to_bytes(),
+            );
+            data.extend_from_slice(&payload);
+            let actual = SlicedPacket::from_ethernet(&data)
+                .unwrap()
+                .to_owned_headers();
+            assert!(actual.link.is_some());
+            assert!(actual.net.is_none());
+            assert_eq!(actual.payload, payload);
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use crate::err::packet::BuildWriteError;
+use crate::err::{packet::BuildWriteError, SliceWriteSpaceError};
 
 use super::*;
 
-use std::{io, marker};
+use std::{io, marker, vec::Vec};
 
 /// Helper for building packets.
 ///
@@ -60,6 +60,8 @@ use std::{io, marker};
 ///     * [`PacketBuilderStep<VlanHeader>::ipv6`]
 /// * Options after an IP header was added:
 ///     * [`PacketBuilderStep<IpHeaders>::write`]
+///     * [`PacketBuilderStep<IpHeaders>::ipv4_options`]
+///     * [`PacketBuilderStep<IpHeaders>::ipv4_options_raw`]
 ///     * [`PacketBuilderStep<IpHeaders>::tcp`]
 ///     * [`PacketBuilderStep<IpHeaders>::udp`]
 ///     * [`PacketBuilderStep<IpHeaders>::icmpv4`]
@@ -84,9 +86,11 @@ use std::{io, marker};
 ///     * [`PacketBuilderStep<TcpHeader>::cwr`]
 ///     * [`PacketBuilderStep<TcpHeader>::options`]
 ///     * [`PacketBuilderStep<TcpHeader>::options_raw`]
+///     * [`PacketBuilderStep<TcpHeader>::checksum`]
 /// * Options after an UDP header was added:
 ///     * [`PacketBuilderStep<UdpHeader>::write`]
 ///     * [`PacketBuilderStep<UdpHeader>::size`]
+///     * [`PacketBuilderStep<UdpHeader>::checksum`]
 /// * Options after an ICMPv4 header was added:
 ///     * [`PacketBuilderStep<Icmpv4Header>::write`]
 ///     * [`PacketBuilderStep<Icmpv4Header>::size`]
@@ -137,6 +141,8 @@ impl PacketBuilder {
                 vlan_header: None,
                 ip_header: None,
                 transport_header: None,
+                ip_ext_error: None,
+                checksum_mode: ChecksumMode::Compute,
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
@@ -179,6 +185,8 @@ impl PacketBuilder {
                 vlan_header: None,
                 ip_header: None,
                 transport_header: None,
+                ip_ext_error: None,
+                checksum_mode: ChecksumMode::Compute,
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
@@ -226,6 +234,8 @@ impl PacketBuilder {
                 vlan_header: None,
                 ip_header: None,
                 transport_header: None,
+                ip_ext_error: None,
+                checksum_mode: ChecksumMode::Compute,
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
@@ -303,11 +313,97 @@ impl PacketBuilder {
                 vlan_header: None,
                 ip_header: None,
                 transport_header: None,
+                ip_ext_error: None,
+                checksum_mode: ChecksumMode::Compute,
             },
             _marker: marker::PhantomData::<Ethernet2Header> {},
         }
         .ip(ip_header)
     }
+
+    /// Starts a packet with the given IPv4 header (no link layer is written).
+    ///
+    /// The fields `total_len`, `protocol` & `header_checksum` will be
+    /// overwritten based on the rest of the packet. All other fields
+    /// (e.g. `identification`, `dscp` or the options) are written as given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::*;
+    /// #
+    /// let mut ip_header = Ipv4Header::new(
+    ///     0, // will be replaced during write
+    ///     64, // time_to_live
+    ///     ip_number::UDP, // will be replaced during write
+    ///     [10, 0, 0, 1], // source
+    ///     [10, 0, 0, 2], // destination
+    /// ).unwrap();
+    /// ip_header.identification = 1234;
+    ///
+    /// let builder = PacketBuilder::ipv4_header(ip_header)
+    ///    .udp(21,    //source port
+    ///         1234); //destination port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    ///
+    /// // the packet starts directly with the IPv4 header
+    /// let (ip, _) = Ipv4Header::from_slice(&result).unwrap();
+    /// assert_eq!(ip.identification, 1234);
+    /// ```
+    #[inline]
+    pub fn ipv4_header(ip_header: Ipv4Header) -> PacketBuilderStep<IpHeaders> {
+        PacketBuilder::ip(IpHeaders::Ipv4(ip_header, Default::default()))
+    }
+
+    /// Starts a packet with the given IPv6 header (no link layer is written).
+    ///
+    /// The fields `payload_length` & `next_header` will be overwritten
+    /// based on the rest of the packet. All other fields (e.g.
+    /// `traffic_class` or `flow_label`) are written as given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use etherparse::*;
+    /// #
+    /// let builder = PacketBuilder::ipv6_header(Ipv6Header {
+    ///         flow_label: Ipv6FlowLabel::try_new(1234).unwrap(),
+    ///         hop_limit: 64,
+    ///         source: [1; 16],
+    ///         destination: [2; 16],
+    ///         // payload_length & next_header will be replaced during write
+    ///         ..Default::default()
+    ///     })
+    ///    .udp(21,    //source port
+    ///         1234); //destination port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    ///
+    /// // the packet starts directly with the IPv6 header
+    /// let (ip, _) = Ipv6Header::from_slice(&result).unwrap();
+    /// assert_eq!(ip.flow_label.value(), 1234);
+    /// ```
+    #[inline]
+    pub fn ipv6_header(ip_header: Ipv6Header) -> PacketBuilderStep<IpHeaders> {
+        PacketBuilder::ip(IpHeaders::Ipv6(ip_header, Default::default()))
+    }
 }
 
 struct PacketImpl {
@@ -315,6 +411,11 @@ struct PacketImpl {
     ip_header: Option<IpHeaders>,
     vlan_header: Option<VlanHeader>,
     transport_header: Option<TransportHeader>,
+    /// Error caused by an invalid call while adding IPv6 extension
+    /// headers or IPv4 options (returned when the packet gets written).
+    ip_ext_error: Option<BuildWriteError>,
+    /// How the UDP & TCP checksums are filled in.
+    checksum_mode: ChecksumMode,
 }
 
 ///An unfinished packet that is build with the packet builder
@@ -781,6 +882,293 @@ impl PacketBuilderStep<VlanHeader> {
 
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl PacketBuilderStep<IpHeaders> {
+    /// Adds an IPv6 hop-by-hop options extension header.
+    ///
+    /// The hop-by-hop options header has to be the first extension header
+    /// directly after the IPv6 header. If any other extension header was
+    /// already added a [`BuildWriteError::Ipv6Exts`] error with the value
+    /// [`crate::err::ipv6_exts::ExtsWalkError::HopByHopNotAtStart`] is
+    /// returned when the packet gets written.
+    ///
+    /// The `next_header` field of the given header is ignored and set
+    /// automatically when the packet gets written.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, Ipv6RawExtHeader, ip_number};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ipv6(
+    ///         //source
+    ///         [11,12,13,14,15,16,17,18,19,10,21,22,23,24,25,26],
+    ///         //destination
+    ///         [31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46],
+    ///         //hop_limit
+    ///         47)
+    ///    // router alert option followed by a padn option
+    ///    .hop_by_hop(
+    ///         Ipv6RawExtHeader::new_raw(
+    ///             ip_number::UDP, // overwritten during write
+    ///             &[5, 2, 0, 0, 1, 0]
+    ///         ).unwrap()
+    ///     )
+    ///    .udp(21,    //source port
+    ///         1234); //destination port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the IP header is an IPv4 header a [`BuildWriteError::Ipv6ExtsInIpv4`]
+    /// is returned when the packet gets written.
+    pub fn hop_by_hop(self, header: Ipv6RawExtHeader) -> PacketBuilderStep<IpHeaders> {
+        self.add_ipv6_ext(|exts| {
+            if exts.destination_options.is_some()
+                || exts.routing.is_some()
+                || exts.fragment.is_some()
+                || exts.auth.is_some()
+            {
+                Some(BuildWriteError::Ipv6Exts(
+                    err::ipv6_exts::ExtsWalkError::HopByHopNotAtStart,
+                ))
+            } else {
+                exts.hop_by_hop_options = Some(header);
+                None
+            }
+        })
+    }
+
+    /// Adds an IPv6 destination options extension header.
+    ///
+    /// If a routing header was added before the destination options
+    /// header is placed after the routing header (destination options
+    /// for the final destination). Otherwise the destination options
+    /// are placed before a routing header (destination options for the
+    /// destinations listed in the routing header).
+    ///
+    /// The `next_header` field of the given header is ignored and set
+    /// automatically when the packet gets written.
+    ///
+    /// # Errors
+    ///
+    /// If the IP header is an IPv4 header a [`BuildWriteError::Ipv6ExtsInIpv4`]
+    /// is returned when the packet gets written.
+    pub fn destination_options(self, header: Ipv6RawExtHeader) -> PacketBuilderStep<IpHeaders> {
+        self.add_ipv6_ext(|exts| {
+            if let Some(routing) = exts.routing.as_mut() {
+                routing.final_destination_options = Some(header);
+            } else {
+                exts.destination_options = Some(header);
+            }
+            None
+        })
+    }
+
+    /// Adds an IPv6 routing extension header.
+    ///
+    /// The `next_header` field of the given header is ignored and set
+    /// automatically when the packet gets written.
+    ///
+    /// # Errors
+    ///
+    /// If the IP header is an IPv4 header a [`BuildWriteError::Ipv6ExtsInIpv4`]
+    /// is returned when the packet gets written.
+    pub fn routing(self, header: Ipv6RawExtHeader) -> PacketBuilderStep<IpHeaders> {
+        self.add_ipv6_ext(|exts| {
+            let final_destination_options = exts
+                .routing
+                .take()
+                .and_then(|r| r.final_destination_options);
+            exts.routing = Some(Ipv6RoutingExtensions {
+                routing: header,
+                final_destination_options,
+            });
+            None
+        })
+    }
+
+    /// Adds an IPv6 fragment extension header.
+    ///
+    /// The `next_header` field of the given header is ignored and set
+    /// automatically when the packet gets written. Note that the builder
+    /// does not fragment the payload, the given payload is written as is
+    /// after the headers (use `write_fragmented` or `to_fragments` to
+    /// split a packet into fragments, both return a
+    /// [`BuildWriteError::FragmentHeaderPresent`] error if a fragment
+    /// header was added via this method).
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, Ipv6FragmentHeader, IpFragOffset, ip_number};
+    /// #
+    /// let builder = PacketBuilder::
+    ///     ipv6(
+    ///         //source
+    ///         [11,12,13,14,15,16,17,18,19,10,21,22,23,24,25,26],
+    ///         //destination
+    ///         [31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46],
+    ///         //hop_limit
+    ///         47)
+    ///    .fragment(Ipv6FragmentHeader::new(
+    ///         ip_number::UDP, // overwritten during write
+    ///         IpFragOffset::ZERO,
+    ///         true, // more fragments
+    ///         1234, // identification
+    ///     ))
+    ///    .udp(21,    //source port
+    ///         1234); //destination port
+    ///
+    /// //payload of the first udp fragment
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the IP header is an IPv4 header a [`BuildWriteError::Ipv6ExtsInIpv4`]
+    /// is returned when the packet gets written.
+    pub fn fragment(self, header: Ipv6FragmentHeader) -> PacketBuilderStep<IpHeaders> {
+        self.add_ipv6_ext(|exts| {
+            exts.fragment = Some(header);
+            None
+        })
+    }
+
+    /// Sets the options of the IPv4 header.
+    ///
+    /// The options are serialized & padded with "End of Option List"
+    /// bytes to a multiple of 4 bytes. The "ihl" field & the header
+    /// checksum are calculated when the packet gets written.
+    ///
+    /// # Example
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use etherparse::{PacketBuilder, Ipv4Option, SlicedPacket, NetSlice};
+    /// #
+    /// let builder = PacketBuilder::
+    ///    ipv4([192,168,1,1],  //source ip
+    ///          [224,0,0,22],  //destination ip
+    ///          1)             //time to life
+    ///    .ipv4_options(&[Ipv4Option::RouterAlert(0)])
+    ///    .unwrap()
+    ///    .udp(21,    //source port
+    ///         1234); //destination port
+    ///
+    /// //payload of the udp packet
+    /// let payload = [1,2,3,4,5,6,7,8];
+    ///
+    /// //get some memory to store the result
+    /// let mut result = Vec::<u8>::with_capacity(
+    ///                     builder.size(payload.len()));
+    ///
+    /// //serialize
+    /// builder.write(&mut result, &payload).unwrap();
+    ///
+    /// // the options are present in the written header
+    /// let sliced = SlicedPacket::from_ip(&result).unwrap();
+    /// if let Some(NetSlice::Ipv4(ipv4)) = sliced.net {
+    ///     assert_eq!(ipv4.header().ihl(), 6);
+    ///     assert_eq!(
+    ///         ipv4.header().options_iterator().collect::<Vec<_>>(),
+    ///         vec![Ok(Ipv4Option::RouterAlert(0))]
+    ///     );
+    /// } else {
+    ///     panic!("expected an IPv4 packet");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the padded options are longer than 40 bytes an
+    /// [`err::ipv4::BadOptionsLen`] error is returned.
+    ///
+    /// If the IP header is an IPv6 header a [`BuildWriteError::Ipv4OptionsInIpv6`]
+    /// is returned when the packet gets written.
+    pub fn ipv4_options(
+        self,
+        options: &[Ipv4Option],
+    ) -> Result<PacketBuilderStep<IpHeaders>, err::ipv4::BadOptionsLen> {
+        let options = Ipv4Options::try_from_options(options)?;
+        Ok(self.set_ipv4_options(options))
+    }
+
+    /// Sets the options of the IPv4 header (setting the bytes directly).
+    ///
+    /// The options are padded with "End of Option List" bytes (0) to a
+    /// multiple of 4 bytes. The "ihl" field & the header checksum are
+    /// calculated when the packet gets written.
+    ///
+    /// # Errors
+    ///
+    /// If the padded options are longer than 40 bytes an
+    /// [`err::ipv4::BadOptionsLen`] error is returned.
+    ///
+    /// If the IP header is an IPv6 header a [`BuildWriteError::Ipv4OptionsInIpv6`]
+    /// is returned when the packet gets written.
+    pub fn ipv4_options_raw(
+        self,
+        options: &[u8],
+    ) -> Result<PacketBuilderStep<IpHeaders>, err::ipv4::BadOptionsLen> {
+        let len = options.len().div_ceil(4) * 4;
+        if len > usize::from(Ipv4Options::MAX_LEN) {
+            return Err(err::ipv4::BadOptionsLen { bad_len: len });
+        }
+        let mut buf = [0u8; 40];
+        buf[..options.len()].copy_from_slice(options);
+        // Unwrap Panic Safety:
+        // The length is a multiple of 4 and at most 40.
+        let options = Ipv4Options::try_from(&buf[..len]).unwrap();
+        Ok(self.set_ipv4_options(options))
+    }
+
+    fn set_ipv4_options(mut self, options: Ipv4Options) -> PacketBuilderStep<IpHeaders> {
+        if self.state.ip_ext_error.is_none() {
+            match self.state.ip_header.as_mut() {
+                Some(IpHeaders::Ipv4(header, _)) => header.options = options,
+                _ => self.state.ip_ext_error = Some(BuildWriteError::Ipv4OptionsInIpv6),
+            }
+        }
+        self
+    }
+
+    /// Calls `add` with the IPv6 extensions & records the returned
+    /// error (or an error if the ip header is an IPv4 header).
+    fn add_ipv6_ext<F>(mut self, add: F) -> PacketBuilderStep<IpHeaders>
+    where
+        F: FnOnce(&mut Ipv6Extensions) -> Option<BuildWriteError>,
+    {
+        if self.state.ip_ext_error.is_none() {
+            self.state.ip_ext_error = match self.state.ip_header.as_mut() {
+                Some(IpHeaders::Ipv6(_, exts)) => add(exts),
+                _ => Some(BuildWriteError::Ipv6ExtsInIpv4),
+            };
+        }
+        self
+    }
+
     /// Adds an ICMPv4 header of the given [`Icmpv4Type`] to the packet.
     ///
     /// If an ICMPv4 header gets added the payload used during the builders `write`
@@ -893,12 +1281,16 @@ impl PacketBuilderStep<IpHeaders> {
 
     /// Adds an ICMPv4 echo request packet.
     ///
+    /// The payload passed to `write` is used as the data of the echo request
+    /// and the ICMPv4 checksum is calculated over the ICMPv4 header & the
+    /// payload when the packet is written.
+    ///
     /// # Example
     ///
     /// Basic usage:
     ///
     /// ```
-    /// # use etherparse::PacketBuilder;
+    /// # use etherparse::{PacketBuilder, SlicedPacket, TransportSlice};
     /// #
     /// let builder = PacketBuilder::
     ///    ipv4([192,168,1,1],  //source ip
@@ -918,6 +1310,15 @@ impl PacketBuilderStep<IpHeaders> {
     ///
     /// // serialize
     /// builder.write(&mut result, &payload).unwrap();
+    ///
+    /// // the checksum was calculated during the write
+    /// let sliced = SlicedPacket::from_ip(&result).unwrap();
+    /// if let Some(TransportSlice::Icmpv4(icmp)) = sliced.transport {
+    ///     assert!(icmp.is_checksum_valid());
+    ///     assert_eq!(&payload, icmp.payload());
+    /// } else {
+    ///     panic!("expected an ICMPv4 packet");
+    /// }
     /// ```
     pub fn icmpv4_echo_request(mut self, id: u16, seq: u16) -> PacketBuilderStep<Icmpv4Header> {
         let echo_header = IcmpEchoHeader { id, seq };
@@ -932,6 +1333,10 @@ impl PacketBuilderStep<IpHeaders> {
 
     /// Adds an ICMPv4 echo reply packet.
     ///
+    /// The payload passed to `write` is used as the data of the echo reply
+    /// and the ICMPv4 checksum is calculated over the ICMPv4 header & the
+    /// payload when the packet is written.
+    ///
     /// # Example
     ///
     /// Basic usage:
@@ -1087,21 +1492,27 @@ impl PacketBuilderStep<IpHeaders> {
         }
     }
 
-    /// Adds an ICMPv6 echo reply packet.
+    /// Adds an ICMPv6 echo request packet.
+    ///
+    /// The payload passed to `write` is used as the data of the echo request.
+    /// The ICMPv6 checksum is calculated during `write` and includes the IPv6
+    /// pseudo-header (source & destination address of the IPv6 header, the
+    /// length of the ICMPv6 message & the next header value). Extension
+    /// headers are not part of the pseudo-header length.
     ///
     /// # Example
     ///
     /// Basic usage:
     ///
     /// ```
-    /// # use etherparse::PacketBuilder;
+    /// # use etherparse::{PacketBuilder, SlicedPacket, TransportSlice};
     /// #
+    /// let source = [11,12,13,14,15,16,17,18,19,10,21,22,23,24,25,26];
+    /// let destination = [31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46];
     /// let builder = PacketBuilder::
     ///     ipv6(
-    ///         //source
-    ///         [11,12,13,14,15,16,17,18,19,10,21,22,23,24,25,26],
-    ///         //destination
-    ///         [31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46],
+    ///         source,
+    ///         destination,
     ///         //hop_limit
     ///         47)
     ///    .icmpv6_echo_request(
@@ -1118,6 +1529,15 @@ impl PacketBuilderStep<IpHeaders> {
     ///
     /// //serialize
     /// builder.write(&mut result, &payload).unwrap();
+    ///
+    /// // the checksum (including the pseudo-header) was calculated during the write
+    /// let sliced = SlicedPacket::from_ip(&result).unwrap();
+    /// if let Some(TransportSlice::Icmpv6(icmp)) = sliced.transport {
+    ///     assert!(icmp.is_checksum_valid(source, destination));
+    ///     assert_eq!(&payload, icmp.payload());
+    /// } else {
+    ///     panic!("expected an ICMPv6 packet");
+    /// }
     /// ```
     pub fn icmpv6_echo_request(mut self, id: u16, seq: u16) -> PacketBuilderStep<Icmpv6Header> {
         let echo_header = IcmpEchoHeader { id, seq };
@@ -1130,7 +1550,11 @@ impl PacketBuilderStep<IpHeaders> {
         }
     }
 
-    /// Adds an ICMPv6 echo request packet.
+    /// Adds an ICMPv6 echo reply packet.
+    ///
+    /// The payload passed to `write` is used as the data of the echo reply.
+    /// The ICMPv6 checksum is calculated during `write` and includes the IPv6
+    /// pseudo-header.
     ///
     /// # Example
     ///
@@ -1331,6 +1755,99 @@ impl PacketBuilderStep<IpHeaders> {
         final_write(self, writer, payload)
     }
 
+    /// Write all the headers with the given ip number and a payload of
+    /// `payload_len` bytes read from the given reader (see
+    /// [`PacketBuilderStep<UdpHeader>::write_with_payload_reader`] for details).
+    ///
+    /// `last_next_header_ip_number` will be set in the last extension header
+    /// or if no extension header exists the ip header as the "next header" or
+    /// "protocol number". As no transport checksum is calculated the payload
+    /// is only read once (no seeking is needed).
+    pub fn write_with_payload_reader<T: io::Write + Sized, R: io::Read>(
+        mut self,
+        writer: &mut T,
+        last_next_header_ip_number: IpNumber,
+        payload_len: usize,
+        reader: &mut R,
+    ) -> Result<(), BuildWriteError> {
+        self.state
+            .ip_header
+            .as_mut()
+            .unwrap()
+            .set_next_headers(last_next_header_ip_number);
+        final_write_with_payload_reader(
+            self,
+            writer,
+            payload_len,
+            checksum::Sum16BitWords::new(),
+            reader,
+        )
+    }
+
+    /// Write all the headers and the payload with the given ip number to
+    /// the given slice & return the number of bytes written.
+    ///
+    /// `last_next_header_ip_number` will be set in the last extension header
+    /// or if no extension header exists the ip header as the "next header" or
+    /// "protocol number".
+    ///
+    /// The slice must be at least [`PacketBuilderStep::size`] bytes long,
+    /// otherwise a [`BuildWriteError::Space`] error is returned.
+    pub fn write_to_slice(
+        mut self,
+        slice: &mut [u8],
+        last_next_header_ip_number: IpNumber,
+        payload: &[u8],
+    ) -> Result<usize, BuildWriteError> {
+        self.state
+            .ip_header
+            .as_mut()
+            .unwrap()
+            .set_next_headers(last_next_header_ip_number);
+        final_write_to_slice(self, slice, payload)
+    }
+
+    /// Serializes the packet with the given ip number, splits it into IP
+    /// fragments that fit into the given `mtu` & writes the fragments one
+    /// after another to the writer (see
+    /// [`PacketBuilderStep<UdpHeader>::write_fragmented`] for details).
+    pub fn write_fragmented<T: io::Write + Sized>(
+        self,
+        writer: &mut T,
+        mtu: usize,
+        identification: u32,
+        last_next_header_ip_number: IpNumber,
+        payload: &[u8],
+    ) -> Result<usize, BuildWriteError> {
+        final_write_fragmented(
+            self,
+            writer,
+            mtu,
+            identification,
+            Some(last_next_header_ip_number),
+            payload,
+        )
+    }
+
+    /// Serializes the packet with the given ip number and splits it into IP
+    /// fragments that fit into the given `mtu` (see
+    /// [`PacketBuilderStep<UdpHeader>::write_fragmented`] for details).
+    pub fn to_fragments(
+        self,
+        mtu: usize,
+        identification: u32,
+        last_next_header_ip_number: IpNumber,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+        final_to_fragments(
+            self,
+            mtu,
+            identification,
+            Some(last_next_header_ip_number),
+            payload,
+        )
+    }
+
     ///Returns the size of the packet when it is serialized
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
@@ -1348,6 +1865,57 @@ impl PacketBuilderStep<Icmpv4Header> {
         final_write(self, writer, payload)
     }
 
+    /// Write all the headers and a payload of `payload_len` bytes read from
+    /// the given reader (see
+    /// [`PacketBuilderStep<UdpHeader>::write_with_payload_reader`] for details).
+    pub fn write_with_payload_reader<T: io::Write + Sized, R: io::Read + io::Seek>(
+        self,
+        writer: &mut T,
+        payload_len: usize,
+        reader: &mut R,
+    ) -> Result<(), BuildWriteError> {
+        final_write_with_seekable_payload_reader(self, writer, payload_len, reader)
+    }
+
+    /// Write all the headers and the payload to the given slice & return
+    /// the number of bytes written.
+    ///
+    /// The slice must be at least [`PacketBuilderStep::size`] bytes long,
+    /// otherwise a [`BuildWriteError::Space`] error is returned.
+    pub fn write_to_slice(
+        self,
+        slice: &mut [u8],
+        payload: &[u8],
+    ) -> Result<usize, BuildWriteError> {
+        final_write_to_slice(self, slice, payload)
+    }
+
+    /// Serializes the packet, splits it into IP fragments that fit into
+    /// the given `mtu` & writes the fragments one after another to the
+    /// writer (see [`PacketBuilderStep<UdpHeader>::write_fragmented`] for
+    /// details).
+    pub fn write_fragmented<T: io::Write + Sized>(
+        self,
+        writer: &mut T,
+        mtu: usize,
+        identification: u32,
+        payload: &[u8],
+    ) -> Result<usize, BuildWriteError> {
+        final_write_fragmented(self, writer, mtu, identification, None, payload)
+    }
+
+    /// Serializes the packet and splits it into IP fragments that fit
+    /// into the given `mtu` (see [`PacketBuilderStep<UdpHeader>::write_fragmented`]
+    /// for details).
+    pub fn to_fragments(
+        self,
+        mtu: usize,
+        identification: u32,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+        final_to_fragments(self, mtu, identification, None, payload)
+    }
+
     /// Returns the size of the packet when it is serialized
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
@@ -1365,6 +1933,57 @@ impl PacketBuilderStep<Icmpv6Header> {
         final_write(self, writer, payload)
     }
 
+    /// Write all the headers and a payload of `payload_len` bytes read from
+    /// the given reader (see
+    /// [`PacketBuilderStep<UdpHeader>::write_with_payload_reader`] for details).
+    pub fn write_with_payload_reader<T: io::Write + Sized, R: io::Read + io::Seek>(
+        self,
+        writer: &mut T,
+        payload_len: usize,
+        reader: &mut R,
+    ) -> Result<(), BuildWriteError> {
+        final_write_with_seekable_payload_reader(self, writer, payload_len, reader)
+    }
+
+    /// Write all the headers and the payload to the given slice & return
+    /// the number of bytes written.
+    ///
+    /// The slice must be at least [`PacketBuilderStep::size`] bytes long,
+    /// otherwise a [`BuildWriteError::Space`] error is returned.
+    pub fn write_to_slice(
+        self,
+        slice: &mut [u8],
+        payload: &[u8],
+    ) -> Result<usize, BuildWriteError> {
+        final_write_to_slice(self, slice, payload)
+    }
+
+    /// Serializes the packet, splits it into IP fragments that fit into
+    /// the given `mtu` & writes the fragments one after another to the
+    /// writer (see [`PacketBuilderStep<UdpHeader>::write_fragmented`] for
+    /// details).
+    pub fn write_fragmented<T: io::Write + Sized>(
+        self,
+        writer: &mut T,
+        mtu: usize,
+        identification: u32,
+        payload: &[u8],
+    ) -> Result<usize, BuildWriteError> {
+        final_write_fragmented(self, writer, mtu, identification, None, payload)
+    }
+
+    /// Serializes the packet and splits it into IP fragments that fit
+    /// into the given `mtu` (see [`PacketBuilderStep<UdpHeader>::write_fragmented`]
+    /// for details).
+    pub fn to_fragments(
+        self,
+        mtu: usize,
+        identification: u32,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+        final_to_fragments(self, mtu, identification, None, payload)
+    }
+
     ///Returns the size of the packet when it is serialized
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
@@ -1373,6 +1992,30 @@ impl PacketBuilderStep<Icmpv6Header> {
 
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl PacketBuilderStep<UdpHeader> {
+    /// Set how the UDP checksum is filled in (default [`ChecksumMode::Compute`]).
+    ///
+    /// Writing a packet with [`ChecksumMode::Zero`] & an IPv6 header results
+    /// in a [`BuildWriteError::ZeroUdpChecksumInIpv6`] error, as IPv6 requires
+    /// an UDP checksum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ChecksumMode, PacketBuilder};
+    ///
+    /// let builder = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .udp(21, 1234)
+    ///     // leave the checksum to the network card
+    ///     .checksum(ChecksumMode::Placeholder);
+    ///
+    /// let mut result = Vec::<u8>::with_capacity(builder.size(4));
+    /// builder.write(&mut result, &[1, 2, 3, 4]).unwrap();
+    /// ```
+    pub fn checksum(mut self, mode: ChecksumMode) -> PacketBuilderStep<UdpHeader> {
+        self.state.checksum_mode = mode;
+        self
+    }
+
     ///Write all the headers and the payload.
     pub fn write<T: io::Write + Sized>(
         self,
@@ -1382,6 +2025,205 @@ impl PacketBuilderStep<UdpHeader> {
         final_write(self, writer, payload)
     }
 
+    /// Write all the headers and a payload of `payload_len` bytes read from
+    /// the given reader (without loading the complete payload into memory).
+    ///
+    /// The length fields of the headers are set based on `payload_len`. To
+    /// calculate the checksum the payload is read twice: once to sum it up
+    /// & a second time (after seeking back to the start position of the
+    /// reader) to write it. After a successful write the reader is
+    /// positioned directly after the payload.
+    ///
+    /// If the reader contains less then `payload_len` bytes an
+    /// [`std::io::ErrorKind::UnexpectedEof`] error is returned (note that
+    /// in this case parts of the packet might already have been written).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, PayloadSlice, SlicedPacket};
+    /// use std::io::Cursor;
+    ///
+    /// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .udp(21, 1234);
+    ///
+    /// // e.g. a file
+    /// let mut reader = Cursor::new(vec![1u8; 1000]);
+    ///
+    /// let mut packet = Vec::<u8>::with_capacity(builder.size(1000));
+    /// builder.write_with_payload_reader(&mut packet, 1000, &mut reader).unwrap();
+    ///
+    /// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+    /// assert_eq!(Some(PayloadSlice::Udp(&[1u8; 1000])), sliced.payload());
+    /// ```
+    pub fn write_with_payload_reader<T: io::Write + Sized, R: io::Read + io::Seek>(
+        self,
+        writer: &mut T,
+        payload_len: usize,
+        reader: &mut R,
+    ) -> Result<(), BuildWriteError> {
+        final_write_with_seekable_payload_reader(self, writer, payload_len, reader)
+    }
+
+    /// Write all the headers and the payload to the given slice & return
+    /// the number of bytes written.
+    ///
+    /// The slice must be at least [`PacketBuilderStep::size`] bytes long,
+    /// otherwise a [`BuildWriteError::Space`] error is returned.
+    pub fn write_to_slice(
+        self,
+        slice: &mut [u8],
+        payload: &[u8],
+    ) -> Result<usize, BuildWriteError> {
+        final_write_to_slice(self, slice, payload)
+    }
+
+    /// Serializes the packet, splits it into IP fragments that fit into
+    /// the given `mtu` & writes the fragments (including the link layer
+    /// headers) one after another to the writer.
+    ///
+    /// Every fragment is written via a single `write_all` call & the number
+    /// of written fragments is returned.
+    ///
+    /// The `mtu` is the maximum size of an IP packet (IP header, extension
+    /// headers & payload) in bytes, excluding the Ethernet II & VLAN headers.
+    /// If the packet already fits into the `mtu` it is written unchanged as
+    /// the only fragment.
+    ///
+    /// For IPv6 packets a fragment header with the given `identification`
+    /// is inserted after the hop by hop, destination options & routing
+    /// headers (the "unfragmentable part" that is repeated in every
+    /// fragment). The fragment
+    /// offsets are multiples of 8 and the "more fragments" flag is cleared
+    /// in the last fragment. The transport checksum is calculated over the
+    /// complete payload before the packet gets split.
+    ///
+    /// For IPv4 packets the "more fragments" flag & fragment offset of the
+    /// IPv4 header are set in every fragment and the `total_len` & header
+    /// checksum are recalculated. The `identification` argument is ignored
+    /// for IPv4 packets, instead the identification of the IPv4 header is
+    /// shared by all fragments. Only IPv4 options with the "copied" flag
+    /// set are repeated in the fragments after the first one (RFC 791). If the "don't fragment" flag is set (the default for
+    /// [`PacketBuilder::ipv4`]) and the packet does not fit into the `mtu` a
+    /// [`BuildWriteError::DontFragment`] error is returned.
+    ///
+    /// A [`BuildWriteError::MtuTooSmall`] error is returned if the `mtu` is too
+    /// small to contain the unfragmentable part & at least 8 bytes of payload.
+    /// A [`BuildWriteError::FragmentHeaderPresent`] error is returned if a
+    /// fragment header was already added via
+    /// [`PacketBuilderStep<IpHeaders>::fragment`] (independent of the
+    /// packet size).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{Ipv6Header, PacketBuilder, SlicedPacket, NetSlice};
+    ///
+    /// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .ipv6([0; 16], [1; 16], 20)
+    ///     .udp(21, 1234);
+    /// let payload = [0u8; 3000];
+    ///
+    /// let mut written = Vec::new();
+    /// let count = builder.write_fragmented(&mut written, 1280, 1234, &payload).unwrap();
+    /// assert_eq!(3, count);
+    ///
+    /// // decode the first fragment
+    /// let first = SlicedPacket::from_ethernet(&written).unwrap();
+    /// match first.net {
+    ///     Some(NetSlice::Ipv6(ipv6)) => {
+    ///         // the ip packet fits into the mtu
+    ///         assert!(Ipv6Header::LEN + usize::from(ipv6.header().payload_length()) <= 1280);
+    ///         assert!(ipv6.is_payload_fragmented());
+    ///     }
+    ///     _ => panic!("expected ipv6"),
+    /// }
+    /// ```
+    pub fn write_fragmented<T: io::Write + Sized>(
+        self,
+        writer: &mut T,
+        mtu: usize,
+        identification: u32,
+        payload: &[u8],
+    ) -> Result<usize, BuildWriteError> {
+        final_write_fragmented(self, writer, mtu, identification, None, payload)
+    }
+
+    /// Serializes the packet and splits it into IP fragments that fit
+    /// into the given `mtu` & returns the serialized fragments (including
+    /// the link layer headers).
+    ///
+    /// See [`PacketBuilderStep<UdpHeader>::write_fragmented`] for details
+    /// on how the packet is split.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket, NetSlice};
+    ///
+    /// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .ipv6([0; 16], [1; 16], 20)
+    ///     .udp(21, 1234);
+    /// let payload = [0u8; 3000];
+    ///
+    /// let fragments = builder.to_fragments(1280, 1234, &payload).unwrap();
+    /// assert_eq!(3, fragments.len());
+    /// for fragment in &fragments {
+    ///     // every ip packet fits into the mtu
+    ///     assert!(fragment.len() - 14 <= 1280);
+    ///     let sliced = SlicedPacket::from_ethernet(fragment).unwrap();
+    ///     match sliced.net {
+    ///         Some(NetSlice::Ipv6(ipv6)) => assert!(ipv6.is_payload_fragmented()),
+    ///         _ => panic!("expected ipv6"),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// IPv4 packets can only be fragmented if the "don't fragment" flag is
+    /// not set:
+    ///
+    /// ```
+    /// use etherparse::{IpHeaders, Ipv4Header, PacketBuilder, SlicedPacket, NetSlice};
+    ///
+    /// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .ip(IpHeaders::Ipv4(
+    ///         Ipv4Header {
+    ///             identification: 1234,
+    ///             dont_fragment: false,
+    ///             time_to_live: 20,
+    ///             source: [192, 168, 1, 1],
+    ///             destination: [192, 168, 1, 2],
+    ///             ..Default::default()
+    ///         },
+    ///         Default::default(),
+    ///     ))
+    ///     .udp(21, 1234);
+    /// let payload = [0u8; 3000];
+    ///
+    /// // the identification argument is only used for IPv6 packets
+    /// let fragments = builder.to_fragments(1500, 0, &payload).unwrap();
+    /// assert_eq!(3, fragments.len());
+    /// for fragment in &fragments {
+    ///     let sliced = SlicedPacket::from_ethernet(fragment).unwrap();
+    ///     match sliced.net {
+    ///         Some(NetSlice::Ipv4(ipv4)) => {
+    ///             assert!(ipv4.is_payload_fragmented());
+    ///             assert_eq!(1234, ipv4.header().identification());
+    ///         }
+    ///         _ => panic!("expected ipv4"),
+    ///     }
+    /// }
+    /// ```
+    pub fn to_fragments(
+        self,
+        mtu: usize,
+        identification: u32,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+        final_to_fragments(self, mtu, identification, None, payload)
+    }
+
     ///Returns the size of the packet when it is serialized
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
@@ -1390,6 +2232,16 @@ impl PacketBuilderStep<UdpHeader> {
 
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl PacketBuilderStep<TcpHeader> {
+    /// Set how the TCP checksum is filled in (default [`ChecksumMode::Compute`]).
+    ///
+    /// A checksum of zero is not valid for TCP, [`ChecksumMode::Zero`] &
+    /// [`ChecksumMode::Placeholder`] should only be used if the checksum gets
+    /// calculated by the network card (checksum offloading).
+    pub fn checksum(mut self, mode: ChecksumMode) -> PacketBuilderStep<TcpHeader> {
+        self.state.checksum_mode = mode;
+        self
+    }
+
     ///Set ns flag (ECN-nonce - concealment protection; experimental: see RFC 3540)
     pub fn ns(mut self) -> PacketBuilderStep<TcpHeader> {
         self.state
@@ -1543,6 +2395,57 @@ impl PacketBuilderStep<TcpHeader> {
         final_write(self, writer, payload)
     }
 
+    /// Write all the headers and a payload of `payload_len` bytes read from
+    /// the given reader (see
+    /// [`PacketBuilderStep<UdpHeader>::write_with_payload_reader`] for details).
+    pub fn write_with_payload_reader<T: io::Write + Sized, R: io::Read + io::Seek>(
+        self,
+        writer: &mut T,
+        payload_len: usize,
+        reader: &mut R,
+    ) -> Result<(), BuildWriteError> {
+        final_write_with_seekable_payload_reader(self, writer, payload_len, reader)
+    }
+
+    /// Write all the headers and the payload to the given slice & return
+    /// the number of bytes written.
+    ///
+    /// The slice must be at least [`PacketBuilderStep::size`] bytes long,
+    /// otherwise a [`BuildWriteError::Space`] error is returned.
+    pub fn write_to_slice(
+        self,
+        slice: &mut [u8],
+        payload: &[u8],
+    ) -> Result<usize, BuildWriteError> {
+        final_write_to_slice(self, slice, payload)
+    }
+
+    /// Serializes the packet, splits it into IP fragments that fit into
+    /// the given `mtu` & writes the fragments one after another to the
+    /// writer (see [`PacketBuilderStep<UdpHeader>::write_fragmented`] for
+    /// details).
+    pub fn write_fragmented<T: io::Write + Sized>(
+        self,
+        writer: &mut T,
+        mtu: usize,
+        identification: u32,
+        payload: &[u8],
+    ) -> Result<usize, BuildWriteError> {
+        final_write_fragmented(self, writer, mtu, identification, None, payload)
+    }
+
+    /// Serializes the packet and splits it into IP fragments that fit
+    /// into the given `mtu` (see [`PacketBuilderStep<UdpHeader>::write_fragmented`]
+    /// for details).
+    pub fn to_fragments(
+        self,
+        mtu: usize,
+        identification: u32,
+        payload: &[u8],
+    ) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+        final_to_fragments(self, mtu, identification, None, payload)
+    }
+
     ///Returns the size of the packet when it is serialized
     pub fn size(&self, payload_size: usize) -> usize {
         final_size(self, payload_size)
@@ -1554,9 +2457,90 @@ fn final_write<T: io::Write + Sized, B>(
     builder: PacketBuilderStep<B>,
     writer: &mut T,
     payload: &[u8],
+) -> Result<(), BuildWriteError> {
+    final_write_headers(builder, writer, FinalPayload::Slice(payload))?;
+    writer.write_all(payload).map_err(BuildWriteError::Io)
+}
+
+/// Payload of a packet serialized via [`final_write_headers`].
+enum FinalPayload<'a> {
+    /// Payload present in memory.
+    Slice(&'a [u8]),
+    /// Payload written separately after the headers (only the length
+    /// & the sum of the 16 bit words of the payload are known).
+    Streamed {
+        len: usize,
+        sum: checksum::Sum16BitWords,
+    },
+}
+
+impl FinalPayload<'_> {
+    /// Length of the payload in bytes.
+    fn len(&self) -> usize {
+        match self {
+            FinalPayload::Slice(payload) => payload.len(),
+            FinalPayload::Streamed { len, .. } => *len,
+        }
+    }
+
+    /// Slice used to calculate the transport checksum (empty for streamed
+    /// payloads, their sum gets added via [`FinalPayload::add_to_checksum`]).
+    fn checksum_slice(&self) -> &[u8] {
+        match self {
+            FinalPayload::Slice(payload) => payload,
+            FinalPayload::Streamed { .. } => &[],
+        }
+    }
+
+    /// Adds the sum of a streamed payload to a transport checksum that was
+    /// calculated via [`FinalPayload::checksum_slice`] (empty payload).
+    fn add_to_checksum(&self, transport: &mut TransportHeader) {
+        if let FinalPayload::Streamed { len, sum } = self {
+            // As the ones complement sum is calculated modulo 0xffff the
+            // inverted checksum (= sum of the header) can be combined with
+            // the sum of the payload. TCP & ICMPv6 contain the payload
+            // length in the pseudo header, which is also added up as it is
+            // missing in the checksum calculated without payload.
+            let with_payload = |checksum: u16, add_len: bool| {
+                let mut sum = sum.clone().add_2bytes((!checksum).to_be_bytes());
+                if add_len {
+                    sum.add_4bytes((*len as u32).to_be_bytes())
+                } else {
+                    sum
+                }
+            };
+            match transport {
+                TransportHeader::Udp(udp) => {
+                    udp.checksum = with_payload(udp.checksum, false)
+                        .to_ones_complement_with_no_zero()
+                        .to_be();
+                }
+                TransportHeader::Tcp(tcp) => {
+                    tcp.checksum = with_payload(tcp.checksum, true).ones_complement().to_be();
+                }
+                TransportHeader::Icmpv4(icmp) => {
+                    icmp.checksum = with_payload(icmp.checksum, false).ones_complement().to_be();
+                }
+                TransportHeader::Icmpv6(icmp) => {
+                    icmp.checksum = with_payload(icmp.checksum, true).ones_complement().to_be();
+                }
+            }
+        }
+    }
+}
+
+/// Write all the headers of the packet (without the payload).
+fn final_write_headers<T: io::Write + Sized, B>(
+    builder: PacketBuilderStep<B>,
+    writer: &mut T,
+    payload: FinalPayload,
 ) -> Result<(), BuildWriteError> {
     use BuildWriteError::*;
 
+    if let Some(err) = builder.state.ip_ext_error {
+        return Err(err);
+    }
+
     let ip_ether_type = {
         use crate::IpHeaders::*;
         match builder.state.ip_header {
@@ -1656,16 +2640,17 @@ fn final_write<T: io::Write + Sized, B>(
                     }
 
                     //ip protocol number & next header values of the extension header
-                    ip.protocol = ext.set_next_headers(match transport {
+                    let transport_ip_number = match transport {
                         Icmpv4(_) => ip_number::ICMP,
                         Icmpv6(_) => ip_number::IPV6_ICMP,
                         Udp(_) => ip_number::UDP,
                         Tcp(_) => ip_number::TCP,
-                    });
+                    };
+                    ip.protocol = ext.set_next_headers(transport_ip_number);
 
                     //calculate the udp checksum
                     transport
-                        .update_checksum_ipv4(&ip, payload)
+                        .update_checksum_ipv4(&ip, payload.checksum_slice())
                         .map_err(|err| {
                             use err::packet::TransportChecksumError as I;
                             match err {
@@ -1673,6 +2658,19 @@ fn final_write<T: io::Write + Sized, B>(
                                 I::Icmpv6InIpv4 => Icmpv6InIpv4,
                             }
                         })?;
+                    payload.add_to_checksum(&mut transport);
+                    if ChecksumMode::Compute != builder.state.checksum_mode {
+                        let pseudo_header_sum = checksum::Sum16BitWords::new()
+                            .add_4bytes(ip.source)
+                            .add_4bytes(ip.destination)
+                            .add_2bytes([0, transport_ip_number.0])
+                            .add_2bytes((transport_size as u16).to_be_bytes());
+                        apply_checksum_mode(
+                            &mut transport,
+                            builder.state.checksum_mode,
+                            pseudo_header_sum,
+                        );
+                    }
 
                     //write (will automatically calculate the checksum)
                     ip.write(writer).map_err(Io)?;
@@ -1700,17 +2698,36 @@ fn final_write<T: io::Write + Sized, B>(
                     }
 
                     //set the protocol
-                    ip.next_header = ext.set_next_headers(match transport {
+                    let transport_ip_number = match transport {
                         Icmpv4(_) => ip_number::ICMP,
                         Icmpv6(_) => ip_number::IPV6_ICMP,
                         Udp(_) => ip_number::UDP,
                         Tcp(_) => ip_number::TCP,
-                    });
+                    };
+                    ip.next_header = ext.set_next_headers(transport_ip_number);
 
                     //calculate the udp checksum
                     transport
-                        .update_checksum_ipv6(&ip, payload)
+                        .update_checksum_ipv6(&ip, payload.checksum_slice())
                         .map_err(PayloadLen)?;
+                    payload.add_to_checksum(&mut transport);
+                    if ChecksumMode::Compute != builder.state.checksum_mode {
+                        if ChecksumMode::Zero == builder.state.checksum_mode
+                            && matches!(transport, Udp(_))
+                        {
+                            return Err(ZeroUdpChecksumInIpv6);
+                        }
+                        let pseudo_header_sum = checksum::Sum16BitWords::new()
+                            .add_16bytes(ip.source)
+                            .add_16bytes(ip.destination)
+                            .add_4bytes((transport_size as u32).to_be_bytes())
+                            .add_2bytes([0, transport_ip_number.0]);
+                        apply_checksum_mode(
+                            &mut transport,
+                            builder.state.checksum_mode,
+                            pseudo_header_sum,
+                        );
+                    }
 
                     //write (will automatically calculate the checksum)
                     ip.write(writer).map_err(Io)?;
@@ -1724,14 +2741,393 @@ fn final_write<T: io::Write + Sized, B>(
                 }
             }
 
-            //finally write the udp header & payload
+            //finally write the udp header
             transport.write(writer).map_err(Io)?;
         }
     }
-    writer.write_all(payload).map_err(Io)?;
     Ok(())
 }
 
+/// Error returned if a payload reader contains less then the given
+/// payload length.
+fn payload_reader_eof_error() -> BuildWriteError {
+    BuildWriteError::Io(io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        "payload reader contained less data then the given payload length",
+    ))
+}
+
+/// Write all the headers and the payload read from the given reader.
+///
+/// The payload is read twice: once to calculate the transport checksum &
+/// a second time (after seeking back to the start position) to write it.
+fn final_write_with_seekable_payload_reader<T: io::Write + Sized, R: io::Read + io::Seek, B>(
+    builder: PacketBuilderStep<B>,
+    writer: &mut T,
+    payload_len: usize,
+    reader: &mut R,
+) -> Result<(), BuildWriteError> {
+    use BuildWriteError::*;
+
+    // sum up the payload (needed to calculate the transport checksum)
+    let mut sum = checksum::Sum16BitWords::new();
+    if builder.state.transport_header.is_some() {
+        let start = reader.stream_position().map_err(Io)?;
+        // chunks have an even length (except the last one) so no
+        // padding gets added between the chunks
+        let mut buffer = [0u8; 4096];
+        let mut remaining = payload_len;
+        while remaining > 0 {
+            let chunk = &mut buffer[..core::cmp::min(remaining, 4096)];
+            reader.read_exact(chunk).map_err(|err| {
+                if io::ErrorKind::UnexpectedEof == err.kind() {
+                    payload_reader_eof_error()
+                } else {
+                    Io(err)
+                }
+            })?;
+            sum = sum.add_slice(chunk);
+            remaining -= chunk.len();
+        }
+        reader.seek(io::SeekFrom::Start(start)).map_err(Io)?;
+    }
+
+    final_write_with_payload_reader(builder, writer, payload_len, sum, reader)
+}
+
+/// Write all the headers and the payload read from the given reader.
+///
+/// The payload is only read once, `payload_sum` is used to calculate the
+/// transport checksum (if a transport header is present).
+fn final_write_with_payload_reader<T: io::Write + Sized, R: io::Read, B>(
+    builder: PacketBuilderStep<B>,
+    writer: &mut T,
+    payload_len: usize,
+    payload_sum: checksum::Sum16BitWords,
+    reader: &mut R,
+) -> Result<(), BuildWriteError> {
+    final_write_headers(
+        builder,
+        writer,
+        FinalPayload::Streamed {
+            len: payload_len,
+            sum: payload_sum,
+        },
+    )?;
+
+    // copy the payload
+    let copied = io::copy(
+        &mut io::Read::take(&mut *reader, payload_len as u64),
+        writer,
+    )
+    .map_err(BuildWriteError::Io)?;
+    if copied != payload_len as u64 {
+        return Err(payload_reader_eof_error());
+    }
+    Ok(())
+}
+
+/// Replaces the calculated UDP or TCP checksum based on the given
+/// checksum mode (ICMP checksums are not modified).
+fn apply_checksum_mode(
+    transport: &mut TransportHeader,
+    mode: ChecksumMode,
+    pseudo_header_sum: checksum::Sum16BitWords,
+) {
+    let value = match mode {
+        ChecksumMode::Compute => return,
+        ChecksumMode::Zero => 0,
+        // the not complemented sum is expected by the checksum offloading
+        ChecksumMode::Placeholder => (!pseudo_header_sum.ones_complement()).to_be(),
+    };
+    match transport {
+        TransportHeader::Udp(udp) => udp.checksum = value,
+        TransportHeader::Tcp(tcp) => tcp.checksum = value,
+        TransportHeader::Icmpv4(_) | TransportHeader::Icmpv6(_) => {}
+    }
+}
+
+/// Write all the headers and the payload to the given slice & return the
+/// number of bytes written.
+fn final_write_to_slice<B>(
+    mut builder: PacketBuilderStep<B>,
+    slice: &mut [u8],
+    payload: &[u8],
+) -> Result<usize, BuildWriteError> {
+    if let Some(err) = builder.state.ip_ext_error.take() {
+        return Err(err);
+    }
+    let size = final_size(&builder, payload.len());
+    if slice.len() < size {
+        use crate::IpHeaders::*;
+        return Err(BuildWriteError::Space(SliceWriteSpaceError {
+            required_len: size,
+            len: slice.len(),
+            layer: match (&builder.state.ethernet2_header, &builder.state.ip_header) {
+                (Some(_), _) => err::Layer::Ethernet2Header,
+                (None, Some(Ipv4(_, _))) => err::Layer::Ipv4Header,
+                (None, _) => err::Layer::Ipv6Header,
+            },
+            layer_start_offset: 0,
+        }));
+    }
+    // writing to a slice via io::Write does not allocate
+    let mut writer = &mut slice[..size];
+    final_write(builder, &mut writer, payload)?;
+    Ok(size)
+}
+
+/// Serializes the packet & splits it into IP fragments fitting into the `mtu`.
+///
+/// Every fragment is passed to `emit` (in order) & the number of fragments
+/// is returned. If `last_next_header` is set it is used as the next header
+/// of the last IP (extension) header (needed if no transport header is
+/// present).
+fn final_fragments<B, F: FnMut(&[u8]) -> Result<(), BuildWriteError>>(
+    mut builder: PacketBuilderStep<B>,
+    mtu: usize,
+    identification: u32,
+    last_next_header: Option<IpNumber>,
+    payload: &[u8],
+    mut emit: F,
+) -> Result<usize, BuildWriteError> {
+    use crate::IpHeaders::*;
+
+    if let Some(err) = builder.state.ip_ext_error.take() {
+        return Err(err);
+    }
+    if let Some(Ipv6(_, exts)) = builder.state.ip_header.as_ref() {
+        if exts.fragment.is_some() {
+            return Err(BuildWriteError::FragmentHeaderPresent);
+        }
+    }
+
+    // length of the ethernet & vlan headers
+    let link_len = final_size(&builder, 0)
+        - match builder.state.ip_header.as_ref() {
+            Some(ip) => ip.header_len(),
+            None => 0,
+        }
+        - builder
+            .state
+            .transport_header
+            .as_ref()
+            .map(|t| t.header_len())
+            .unwrap_or(0);
+
+    // packets that fit into the mtu can be written directly
+    let ip_len = final_size(&builder, payload.len()) - link_len;
+    if ip_len <= mtu {
+        if let Some(last_next_header) = last_next_header {
+            if let Some(ip) = builder.state.ip_header.as_mut() {
+                ip.set_next_headers(last_next_header);
+            }
+        }
+        let mut packet = Vec::with_capacity(link_len + ip_len);
+        final_write(builder, &mut packet, payload)?;
+        emit(&packet)?;
+        return Ok(1);
+    }
+
+    match builder.state.ip_header.as_mut() {
+        Some(Ipv6(_, exts)) => {
+            // length of the extension headers that are repeated in every fragment
+            let unfragmentable_len = exts
+                .hop_by_hop_options
+                .as_ref()
+                .map(|h| h.header_len())
+                .unwrap_or(0)
+                + exts
+                    .destination_options
+                    .as_ref()
+                    .map(|h| h.header_len())
+                    .unwrap_or(0)
+                + exts
+                    .routing
+                    .as_ref()
+                    .map(|r| r.routing.header_len())
+                    .unwrap_or(0);
+            let fragment_header_len =
+                Ipv6Header::LEN + unfragmentable_len + Ipv6FragmentHeader::LEN;
+            if mtu < fragment_header_len + 8 {
+                return Err(BuildWriteError::MtuTooSmall {
+                    mtu,
+                    min_mtu: fragment_header_len + 8,
+                });
+            }
+
+            // insert a fragment header (the next header is set when the
+            // packet gets written & the other values per fragment)
+            exts.fragment = Some(Ipv6FragmentHeader::new(
+                ip_number::UDP,
+                IpFragOffset::ZERO,
+                false,
+                identification,
+            ));
+            if let Some(last_next_header) = last_next_header {
+                if let Some(ip) = builder.state.ip_header.as_mut() {
+                    ip.set_next_headers(last_next_header);
+                }
+            }
+
+            // serialize the complete packet
+            let mut packet = Vec::with_capacity(link_len + ip_len + Ipv6FragmentHeader::LEN);
+            final_write(builder, &mut packet, payload)?;
+
+            // split the fragmentable part
+            let fragment_start = link_len + Ipv6Header::LEN + unfragmentable_len;
+            let next_header = IpNumber(packet[fragment_start]);
+            let (unfragmentable, rest) = packet.split_at(fragment_start);
+            let fragmentable = &rest[Ipv6FragmentHeader::LEN..];
+            let max_chunk_len = (mtu - fragment_header_len) / 8 * 8;
+
+            let mut fragment =
+                Vec::with_capacity(unfragmentable.len() + Ipv6FragmentHeader::LEN + max_chunk_len);
+            let mut count = 0;
+            for (index, chunk) in fragmentable.chunks(max_chunk_len).enumerate() {
+                let offset = index * max_chunk_len;
+                let more_fragments = offset + chunk.len() < fragmentable.len();
+                let fragment_header = Ipv6FragmentHeader::new(
+                    next_header,
+                    // SAFETY: The offset is smaller then the IPv6 payload length
+                    // (verified by final_write to fit into an u16), so the offset
+                    // divided by 8 fits into the 13 bits of the fragment offset.
+                    unsafe { IpFragOffset::new_unchecked((offset / 8) as u16) },
+                    more_fragments,
+                    identification,
+                );
+
+                fragment.clear();
+                fragment.extend_from_slice(unfragmentable);
+                // update the payload length of the ipv6 header
+                let payload_length =
+                    (unfragmentable_len + Ipv6FragmentHeader::LEN + chunk.len()) as u16;
+                fragment[link_len + 4..link_len + 6].copy_from_slice(&payload_length.to_be_bytes());
+                fragment.extend_from_slice(&fragment_header.to_bytes());
+                fragment.extend_from_slice(chunk);
+                emit(&fragment)?;
+                count += 1;
+            }
+            Ok(count)
+        }
+        Some(Ipv4(header, _)) => {
+            if header.dont_fragment {
+                return Err(BuildWriteError::DontFragment {
+                    mtu,
+                    packet_len: ip_len,
+                });
+            }
+            let header_len = header.header_len();
+            if mtu < header_len + 8 {
+                return Err(BuildWriteError::MtuTooSmall {
+                    mtu,
+                    min_mtu: header_len + 8,
+                });
+            }
+            let mut header = header.clone();
+            if let Some(last_next_header) = last_next_header {
+                if let Some(ip) = builder.state.ip_header.as_mut() {
+                    ip.set_next_headers(last_next_header);
+                }
+            }
+
+            // serialize the complete packet
+            let mut packet = Vec::with_capacity(link_len + ip_len);
+            final_write(builder, &mut packet, payload)?;
+
+            // the protocol gets set during the write based on the transport header
+            header.protocol = IpNumber(packet[link_len + 9]);
+
+            // only options with the "copied" flag are repeated in the
+            // fragments after the first one (RFC 791)
+            let mut later_header = header.clone();
+            later_header.options = header.options.copied_options();
+
+            // split the payload (including the ipv4 extension headers)
+            let (link, rest) = packet.split_at(link_len);
+            let fragmentable = &rest[header_len..];
+            let max_chunk_len = (mtu - header_len) / 8 * 8;
+
+            let mut fragment = Vec::with_capacity(link_len + header_len + max_chunk_len);
+            let mut count = 0;
+            for (index, chunk) in fragmentable.chunks(max_chunk_len).enumerate() {
+                let offset = index * max_chunk_len;
+                // the chunk length is based on the first header as the
+                // following headers can only be shorter
+                let header = if 0 == index {
+                    &mut header
+                } else {
+                    &mut later_header
+                };
+                header.more_fragments = offset + chunk.len() < fragmentable.len();
+                // SAFETY: The offset is smaller then the IPv4 total length
+                // (verified by final_write to fit into an u16), so the offset
+                // divided by 8 fits into the 13 bits of the fragment offset.
+                header.fragment_offset =
+                    unsafe { IpFragOffset::new_unchecked((offset / 8) as u16) };
+                header.total_len = (header.header_len() + chunk.len()) as u16;
+                header.header_checksum = header.calc_header_checksum();
+
+                fragment.clear();
+                fragment.extend_from_slice(link);
+                fragment.extend_from_slice(&header.to_bytes());
+                fragment.extend_from_slice(chunk);
+                emit(&fragment)?;
+                count += 1;
+            }
+            Ok(count)
+        }
+        None => Err(BuildWriteError::MtuTooSmall {
+            mtu,
+            min_mtu: ip_len,
+        }),
+    }
+}
+
+/// Serializes the packet, splits it into IP fragments fitting into the `mtu`
+/// & returns the serialized fragments.
+fn final_to_fragments<B>(
+    builder: PacketBuilderStep<B>,
+    mtu: usize,
+    identification: u32,
+    last_next_header: Option<IpNumber>,
+    payload: &[u8],
+) -> Result<Vec<Vec<u8>>, BuildWriteError> {
+    let mut result = Vec::new();
+    final_fragments(
+        builder,
+        mtu,
+        identification,
+        last_next_header,
+        payload,
+        |fragment| {
+            result.push(fragment.to_vec());
+            Ok(())
+        },
+    )?;
+    Ok(result)
+}
+
+/// Serializes the packet, splits it into IP fragments fitting into the `mtu`
+/// & writes the fragments one after another to the writer.
+fn final_write_fragmented<B, T: io::Write + Sized>(
+    builder: PacketBuilderStep<B>,
+    writer: &mut T,
+    mtu: usize,
+    identification: u32,
+    last_next_header: Option<IpNumber>,
+    payload: &[u8],
+) -> Result<usize, BuildWriteError> {
+    final_fragments(
+        builder,
+        mtu,
+        identification,
+        last_next_header,
+        payload,
+        |fragment| writer.write_all(fragment).map_err(BuildWriteError::Io),
+    )
+}
+
 ///Returns the size of the packet when it is serialized
 fn final_size<B>(builder: &PacketBuilderStep<B>, payload_size: usize) -> usize {
     use crate::IpHeaders::*;
@@ -1772,7 +3168,9 @@ mod white_box_tests {
                     ethernet2_header: None,
                     ip_header: None,
                     vlan_header: None,
-                    transport_header: None
+                    transport_header: None,
+                    ip_ext_error: None,
+                    checksum_mode: ChecksumMode::Compute,
                 },
                 _marker: marker::PhantomData::<UdpHeader> {}
             }
@@ -1791,6 +3189,8 @@ mod white_box_tests {
                     ip_header: None,
                     vlan_header: None,
                     transport_header: None,
+                    ip_ext_error: None,
+                    checksum_mode: ChecksumMode::Compute,
                 },
                 _marker: marker::PhantomData::<UdpHeader> {},
             },
@@ -1861,10 +3261,112 @@ mod test {
             UdpHeader::with_ipv4_checksum(22, 23, &ip_expected, &in_payload).unwrap();
         assert_eq!(udp_actual, udp_expected);
 
-        //payload
-        let mut actual_payload: [u8; 4] = [0; 4];
-        cursor.read_exact(&mut actual_payload).unwrap();
-        assert_eq!(actual_payload, in_payload);
+        //payload
+        let mut actual_payload: [u8; 4] = [0; 4];
+        cursor.read_exact(&mut actual_payload).unwrap();
+        assert_eq!(actual_payload, in_payload);
+    }
+
+    #[test]
+    fn write_to_slice() {
+        let payload = [1, 2, 3, 4];
+        let eth = || PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]);
+
+        // compare the slice results with the results of write
+        macro_rules! check {
+            ($builder:expr, $($args:expr),*) => {{
+                let mut expected = Vec::new();
+                $builder.write(&mut expected, $($args),*).unwrap();
+                assert_eq!(expected.len(), $builder.size(payload.len()));
+
+                // ok (slice bigger then needed)
+                let mut buffer = vec![0u8; expected.len() + 2];
+                assert_eq!(
+                    expected.len(),
+                    $builder.write_to_slice(&mut buffer, $($args),*).unwrap()
+                );
+                assert_eq!(&expected[..], &buffer[..expected.len()]);
+                assert_eq!(&[0, 0], &buffer[expected.len()..]);
+
+                // slice too small
+                let mut buffer = vec![0u8; expected.len() - 1];
+                assert_eq!(
+                    $builder
+                        .write_to_slice(&mut buffer, $($args),*)
+                        .unwrap_err()
+                        .space(),
+                    Some(&SliceWriteSpaceError {
+                        required_len: expected.len(),
+                        len: expected.len() - 1,
+                        layer: err::Layer::Ethernet2Header,
+                        layer_start_offset: 0,
+                    })
+                );
+            }};
+        }
+
+        check!(
+            eth().ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21),
+            ip_number::UDP,
+            &payload
+        );
+        check!(
+            eth()
+                .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+                .udp(22, 23),
+            &payload
+        );
+        check!(
+            eth()
+                .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+                .tcp(22, 23, 1234, 4000),
+            &payload
+        );
+        check!(
+            eth()
+                .ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+                .icmpv4_echo_request(1, 2),
+            &payload
+        );
+        check!(
+            eth().ipv6([0; 16], [1; 16], 21).icmpv6_echo_request(1, 2),
+            &payload
+        );
+
+        // error layer without an ethernet header
+        {
+            let mut buffer = [0u8; 1];
+            assert_eq!(
+                PacketBuilder::ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+                    .udp(22, 23)
+                    .write_to_slice(&mut buffer, &payload)
+                    .unwrap_err()
+                    .space()
+                    .unwrap()
+                    .layer,
+                err::Layer::Ipv4Header
+            );
+            assert_eq!(
+                PacketBuilder::ipv6([0; 16], [1; 16], 21)
+                    .udp(22, 23)
+                    .write_to_slice(&mut buffer, &payload)
+                    .unwrap_err()
+                    .space()
+                    .unwrap()
+                    .layer,
+                err::Layer::Ipv6Header
+            );
+        }
+
+        // other errors are still forwarded
+        {
+            let mut buffer = [0u8; 1000];
+            assert!(PacketBuilder::ipv4([13, 14, 15, 16], [17, 18, 19, 20], 21)
+                .icmpv6_echo_request(1, 2)
+                .write_to_slice(&mut buffer, &payload)
+                .unwrap_err()
+                .is_icmpv6_in_ipv4());
+        }
     }
 
     #[test]
@@ -2139,6 +3641,483 @@ mod test {
         cursor.read_exact(&mut actual_payload).unwrap();
         assert_eq!(actual_payload, in_payload);
     }
+    #[test]
+    fn ipv6_exts_udp() {
+        let hop_by_hop = Ipv6RawExtHeader::new_raw(ip_number::TCP, &[1, 2, 3, 4, 5, 6]).unwrap();
+        let dest_options =
+            Ipv6RawExtHeader::new_raw(ip_number::TCP, &[7, 8, 9, 10, 11, 12]).unwrap();
+        let routing = Ipv6RawExtHeader::new_raw(ip_number::TCP, &[13, 14, 15, 16, 17, 18]).unwrap();
+        let final_dest_options =
+            Ipv6RawExtHeader::new_raw(ip_number::TCP, &[19, 20, 21, 22, 23, 24]).unwrap();
+        let fragment = Ipv6FragmentHeader::new(ip_number::TCP, IpFragOffset::ZERO, false, 1234);
+
+        let in_payload = [24, 25, 26, 27];
+        let builder = PacketBuilder::ipv6(
+            //source
+            [
+                11, 12, 13, 14, 15, 16, 17, 18, 19, 10, 21, 22, 23, 24, 25, 26,
+            ],
+            //destination
+            [
+                31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+            ],
+            //hop_limit
+            47,
+        )
+        .hop_by_hop(hop_by_hop.clone())
+        .destination_options(dest_options.clone())
+        .routing(routing.clone())
+        .destination_options(final_dest_options.clone())
+        .fragment(fragment.clone())
+        .udp(22, 23);
+        let size = builder.size(in_payload.len());
+        let mut serialized = Vec::new();
+        builder.write(&mut serialized, &in_payload).unwrap();
+        assert_eq!(size, serialized.len());
+        assert_eq!(
+            Ipv6Header::LEN + 4 * 8 + Ipv6FragmentHeader::LEN + UdpHeader::LEN + in_payload.len(),
+            serialized.len()
+        );
+
+        // decode & check the next header chain
+        let headers = PacketHeaders::from_ip_slice(&serialized).unwrap();
+        let (ip, exts) = match headers.net {
+            Some(NetHeaders::Ipv6(ip, exts)) => (ip, exts),
+            _ => panic!("expected ipv6 header"),
+        };
+        assert_eq!(ip.next_header, ip_number::IPV6_HOP_BY_HOP);
+        assert_eq!(
+            exts,
+            Ipv6Extensions {
+                hop_by_hop_options: Some(
+                    Ipv6RawExtHeader::new_raw(ip_number::IPV6_DEST_OPTIONS, &[1, 2, 3, 4, 5, 6])
+                        .unwrap()
+                ),
+                destination_options: Some(
+                    Ipv6RawExtHeader::new_raw(ip_number::IPV6_ROUTE, &[7, 8, 9, 10, 11, 12])
+                        .unwrap()
+                ),
+                routing: Some(Ipv6RoutingExtensions {
+                    routing: Ipv6RawExtHeader::new_raw(
+                        ip_number::IPV6_FRAG,
+                        &[13, 14, 15, 16, 17, 18]
+                    )
+                    .unwrap(),
+                    final_destination_options: Some(
+                        Ipv6RawExtHeader::new_raw(ip_number::UDP, &[19, 20, 21, 22, 23, 24])
+                            .unwrap()
+                    ),
+                }),
+                fragment: Some(Ipv6FragmentHeader {
+                    next_header: ip_number::IPV6_DEST_OPTIONS,
+                    ..fragment
+                }),
+                auth: None,
+            }
+        );
+        assert_eq!(
+            headers.transport,
+            Some(TransportHeader::Udp(
+                UdpHeader::with_ipv6_checksum(22, 23, &ip, &in_payload).unwrap()
+            ))
+        );
+        assert_eq!(headers.payload.slice(), &in_payload);
+    }
+
+    #[test]
+    fn ipv6_exts_icmpv6_echo() {
+        let source = [
+            11, 12, 13, 14, 15, 16, 17, 18, 19, 10, 21, 22, 23, 24, 25, 26,
+        ];
+        let destination = [
+            31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+        ];
+        let hop_by_hop = Ipv6RawExtHeader::new_raw(ip_number::UDP, &[1, 2, 3, 4, 5, 6]).unwrap();
+
+        // odd & even payload lengths (odd lengths require padding in the checksum)
+        for in_payload in [&[][..], &[1][..], &[1, 2, 3, 4, 5, 6, 7][..]] {
+            for (is_request, builder) in [
+                (
+                    true,
+                    PacketBuilder::ipv6(source, destination, 47)
+                        .hop_by_hop(hop_by_hop.clone())
+                        .icmpv6_echo_request(123, 456),
+                ),
+                (
+                    false,
+                    PacketBuilder::ipv6(source, destination, 47)
+                        .hop_by_hop(hop_by_hop.clone())
+                        .icmpv6_echo_reply(123, 456),
+                ),
+            ] {
+                let mut serialized = Vec::new();
+                builder.write(&mut serialized, in_payload).unwrap();
+
+                let sliced = SlicedPacket::from_ip(&serialized).unwrap();
+                let icmp = match sliced.transport {
+                    Some(TransportSlice::Icmpv6(icmp)) => icmp,
+                    _ => panic!("expected icmpv6 packet"),
+                };
+                let echo = IcmpEchoHeader { id: 123, seq: 456 };
+                assert_eq!(
+                    icmp.icmp_type(),
+                    if is_request {
+                        Icmpv6Type::EchoRequest(echo)
+                    } else {
+                        Icmpv6Type::EchoReply(echo)
+                    }
+                );
+                assert_eq!(icmp.payload(), in_payload);
+
+                // the pseudo-header length only covers the icmp message
+                // (not the extension headers)
+                assert!(icmp.is_checksum_valid(source, destination));
+                assert_eq!(
+                    icmp.checksum(),
+                    Icmpv6Header::with_checksum(icmp.icmp_type(), source, destination, in_payload)
+                        .unwrap()
+                        .checksum
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ipv6_exts_errors() {
+        let raw = Ipv6RawExtHeader::new_raw(ip_number::UDP, &[0; 6]).unwrap();
+        let fragment = Ipv6FragmentHeader::new(ip_number::UDP, IpFragOffset::ZERO, false, 0);
+
+        // hop by hop options after another extension header
+        for builder in [
+            PacketBuilder::ipv6([0; 16], [0; 16], 1).destination_options(raw.clone()),
+            PacketBuilder::ipv6([0; 16], [0; 16], 1).routing(raw.clone()),
+            PacketBuilder::ipv6([0; 16], [0; 16], 1).fragment(fragment.clone()),
+        ] {
+            let builder = builder.hop_by_hop(raw.clone()).udp(1, 2);
+            assert_eq!(
+                Some(&err::ipv6_exts::ExtsWalkError::HopByHopNotAtStart),
+                builder.write(&mut Vec::new(), &[]).unwrap_err().ipv6_exts()
+            );
+        }
+        {
+            let builder = PacketBuilder::ipv6([0; 16], [0; 16], 1)
+                .fragment(fragment.clone())
+                .hop_by_hop(raw.clone())
+                .udp(1, 2);
+            let mut buffer = [0u8; 200];
+            assert_eq!(
+                Some(&err::ipv6_exts::ExtsWalkError::HopByHopNotAtStart),
+                builder
+                    .write_to_slice(&mut buffer, &[])
+                    .unwrap_err()
+                    .ipv6_exts()
+            );
+        }
+
+        // ipv6 extension headers in an ipv4 packet
+        for builder in [
+            PacketBuilder::ipv4([0; 4], [0; 4], 1).hop_by_hop(raw.clone()),
+            PacketBuilder::ipv4([0; 4], [0; 4], 1).destination_options(raw.clone()),
+            PacketBuilder::ipv4([0; 4], [0; 4], 1).routing(raw.clone()),
+            PacketBuilder::ipv4([0; 4], [0; 4], 1).fragment(fragment.clone()),
+        ] {
+            assert!(builder
+                .write(&mut Vec::new(), ip_number::UDP, &[])
+                .unwrap_err()
+                .is_ipv6_exts_in_ipv4());
+        }
+    }
+
+    #[test]
+    fn ipv4_options() {
+        let in_payload = [1, 2, 3, 4];
+
+        // options via elements & raw bytes
+        for (builder, expected_options) in [
+            (
+                PacketBuilder::ipv4([1, 2, 3, 4], [224, 0, 0, 22], 1)
+                    .ipv4_options(&[Ipv4Option::RouterAlert(0)])
+                    .unwrap(),
+                &[148, 4, 0, 0][..],
+            ),
+            (
+                PacketBuilder::ipv4([1, 2, 3, 4], [224, 0, 0, 22], 1)
+                    .ipv4_options_raw(&[1, 148, 4, 0, 0])
+                    .unwrap(),
+                &[1, 148, 4, 0, 0, 0, 0, 0][..],
+            ),
+            (
+                PacketBuilder::ipv4([1, 2, 3, 4], [224, 0, 0, 22], 1)
+                    .ipv4_options_raw(&[0; 40])
+                    .unwrap(),
+                &[0; 40][..],
+            ),
+        ] {
+            let builder = builder.udp(22, 23);
+            let mut serialized = Vec::new();
+            builder.write(&mut serialized, &in_payload).unwrap();
+            assert_eq!(
+                Ipv4Header::MIN_LEN + expected_options.len() + UdpHeader::LEN + in_payload.len(),
+                serialized.len()
+            );
+
+            let (ip, rest) = Ipv4Header::from_slice(&serialized).unwrap();
+            assert_eq!(ip.options.as_slice(), expected_options);
+            assert_eq!(
+                usize::from(ip.ihl()) * 4,
+                Ipv4Header::MIN_LEN + expected_options.len()
+            );
+            assert_eq!(ip.header_checksum, ip.calc_header_checksum());
+            assert_eq!(usize::from(ip.total_len), serialized.len(),);
+            let (udp, payload) = UdpHeader::from_slice(rest).unwrap();
+            assert_eq!(udp.checksum, udp.calc_checksum_ipv4(&ip, payload).unwrap());
+            assert_eq!(payload, &in_payload);
+        }
+
+        // too long
+        assert_eq!(
+            Some(err::ipv4::BadOptionsLen { bad_len: 44 }),
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1)
+                .ipv4_options_raw(&[0; 41])
+                .err()
+        );
+        assert_eq!(
+            Some(err::ipv4::BadOptionsLen { bad_len: 44 }),
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1)
+                .ipv4_options(&[Ipv4Option::RecordRoute {
+                    pointer: 4,
+                    route_data: &[0; 40]
+                }])
+                .err()
+        );
+
+        // ipv6 header
+        {
+            let builder = PacketBuilder::ipv6([0; 16], [0; 16], 1)
+                .ipv4_options(&[Ipv4Option::RouterAlert(0)])
+                .unwrap()
+                .udp(22, 23);
+            assert!(builder
+                .write(&mut Vec::new(), &in_payload)
+                .unwrap_err()
+                .is_ipv4_options_in_ipv6());
+        }
+    }
+
+    #[test]
+    fn checksum_mode() {
+        let in_payload = [1, 2, 3, 4, 5];
+
+        // udp & tcp over ipv4 & ipv6 (with & without extension headers)
+        for (ipv6, exts) in [(false, false), (true, false), (true, true)] {
+            for tcp in [false, true] {
+                let write = |mode: ChecksumMode| -> Result<Vec<u8>, BuildWriteError> {
+                    let ip = if ipv6 {
+                        let ip = PacketBuilder::ipv6([1; 16], [2; 16], 1);
+                        if exts {
+                            ip.hop_by_hop(
+                                Ipv6RawExtHeader::new_raw(ip_number::UDP, &[0; 6]).unwrap(),
+                            )
+                        } else {
+                            ip
+                        }
+                    } else {
+                        PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1)
+                    };
+                    let mut serialized = Vec::new();
+                    if tcp {
+                        ip.tcp(22, 23, 1234, 4000)
+                            .checksum(mode)
+                            .write(&mut serialized, &in_payload)?;
+                    } else {
+                        ip.udp(22, 23)
+                            .checksum(mode)
+                            .write(&mut serialized, &in_payload)?;
+                    }
+                    Ok(serialized)
+                };
+                let checksum_of = |serialized: &Vec<u8>| -> (u16, u16) {
+                    let sliced = SlicedPacket::from_ip(serialized).unwrap();
+                    match sliced.transport.unwrap() {
+                        TransportSlice::Udp(udp) => (
+                            udp.checksum(),
+                            checksum::Sum16BitWords::new()
+                                .add_slice(udp.slice())
+                                .to_ones_complement_with_no_zero()
+                                .to_be(),
+                        ),
+                        TransportSlice::Tcp(tcp) => (
+                            tcp.checksum(),
+                            checksum::Sum16BitWords::new()
+                                .add_slice(tcp.slice())
+                                .to_ones_complement_with_no_zero()
+                                .to_be(),
+                        ),
+                        _ => unreachable!(),
+                    }
+                };
+
+                let computed = write(ChecksumMode::Compute).unwrap();
+                let (expected_checksum, _) = checksum_of(&computed);
+
+                // zero
+                if ipv6 && false == tcp {
+                    assert!(write(ChecksumMode::Zero)
+                        .unwrap_err()
+                        .is_zero_udp_checksum_in_ipv6());
+                } else {
+                    let serialized = write(ChecksumMode::Zero).unwrap();
+                    assert_eq!(computed.len(), serialized.len());
+                    assert_eq!(0, checksum_of(&serialized).0);
+                }
+
+                // placeholder (summing up the transport header & payload
+                // starting with the placeholder results in the checksum)
+                {
+                    let serialized = write(ChecksumMode::Placeholder).unwrap();
+                    assert_eq!(computed.len(), serialized.len());
+                    let (placeholder, offloaded) = checksum_of(&serialized);
+                    assert_ne!(placeholder, expected_checksum);
+                    assert_eq!(offloaded, expected_checksum);
+                }
+            }
+        }
+
+        // icmp checksums are not affected
+        {
+            let mut serialized = Vec::new();
+            PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1)
+                .icmpv4_echo_request(1, 2)
+                .write(&mut serialized, &in_payload)
+                .unwrap();
+            let sliced = SlicedPacket::from_ip(&serialized).unwrap();
+            match sliced.transport.unwrap() {
+                TransportSlice::Icmpv4(icmp) => assert!(icmp.is_checksum_valid()),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn write_with_payload_reader() {
+        use std::io::{Cursor, ErrorKind, Seek, SeekFrom};
+
+        for len in [0, 1, 2, 3, 7, 4095, 4096, 4097, 9001] {
+            let payload: Vec<u8> = (0..len).map(|v| (v * 7 + 3) as u8).collect();
+            // reader with data before & after the payload
+            let reader_data = {
+                let mut data = vec![0xff; 3];
+                data.extend_from_slice(&payload);
+                data.extend_from_slice(&[0xee; 5]);
+                data
+            };
+            let new_reader = || {
+                let mut reader = Cursor::new(&reader_data);
+                reader.seek(SeekFrom::Start(3)).unwrap();
+                reader
+            };
+
+            macro_rules! check {
+                ($builder:expr) => {{
+                    let mut expected = Vec::new();
+                    $builder.write(&mut expected, &payload).unwrap();
+
+                    let mut reader = new_reader();
+                    let mut actual = Vec::new();
+                    $builder
+                        .write_with_payload_reader(&mut actual, len, &mut reader)
+                        .unwrap();
+                    assert_eq!(expected, actual);
+                    assert_eq!(3 + len as u64, reader.position());
+                }};
+            }
+
+            // udp
+            check!(PacketBuilder::ethernet2([1; 6], [2; 6])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1)
+                .udp(22, 23));
+            check!(PacketBuilder::ipv6([1; 16], [2; 16], 1).udp(22, 23));
+            check!(PacketBuilder::ipv6([1; 16], [2; 16], 1)
+                .hop_by_hop(Ipv6RawExtHeader::new_raw(ip_number::UDP, &[0; 6]).unwrap())
+                .udp(22, 23));
+            check!(PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1)
+                .udp(22, 23)
+                .checksum(ChecksumMode::Zero));
+            check!(PacketBuilder::ipv6([1; 16], [2; 16], 1)
+                .udp(22, 23)
+                .checksum(ChecksumMode::Placeholder));
+
+            // tcp
+            check!(PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1)
+                .tcp(22, 23, 1234, 4000)
+                .options(&[TcpOptionElement::MaximumSegmentSize(1400)])
+                .unwrap());
+            check!(PacketBuilder::ipv6([1; 16], [2; 16], 1)
+                .tcp(22, 23, 1234, 4000)
+                .syn());
+
+            // icmp
+            check!(PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1).icmpv4_echo_request(1, 2));
+            check!(PacketBuilder::ipv6([1; 16], [2; 16], 1).icmpv6_echo_reply(1, 2));
+
+            // no transport header
+            {
+                let builder = || PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1);
+                let mut expected = Vec::new();
+                builder()
+                    .write(&mut expected, ip_number::ARIS, &payload)
+                    .unwrap();
+
+                let mut reader = new_reader();
+                let mut actual = Vec::new();
+                builder()
+                    .write_with_payload_reader(&mut actual, ip_number::ARIS, len, &mut reader)
+                    .unwrap();
+                assert_eq!(expected, actual);
+                assert_eq!(3 + len as u64, reader.position());
+
+                // readers that can not seek are supported as the
+                // payload is only read once
+                let mut reader = &payload[..];
+                let mut actual = Vec::new();
+                builder()
+                    .write_with_payload_reader(&mut actual, ip_number::ARIS, len, &mut reader)
+                    .unwrap();
+                assert_eq!(expected, actual);
+                assert!(reader.is_empty());
+            }
+        }
+
+        // reader contains less data then the payload length
+        {
+            let mut reader = Cursor::new([1, 2, 3]);
+            let err = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1)
+                .udp(22, 23)
+                .write_with_payload_reader(&mut Vec::new(), 4, &mut reader)
+                .unwrap_err();
+            assert_eq!(ErrorKind::UnexpectedEof, err.io().unwrap().kind());
+
+            let mut reader = Cursor::new([1, 2, 3]);
+            let err = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1)
+                .write_with_payload_reader(&mut Vec::new(), ip_number::ARIS, 4, &mut reader)
+                .unwrap_err();
+            assert_eq!(ErrorKind::UnexpectedEof, err.io().unwrap().kind());
+        }
+
+        // payload length too big
+        {
+            let mut reader = Cursor::new([0u8; 0]);
+            let err = PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 1)
+                .write_with_payload_reader(
+                    &mut Vec::new(),
+                    ip_number::ARIS,
+                    usize::from(u16::MAX),
+                    &mut reader,
+                )
+                .unwrap_err();
+            assert!(err.payload_len().is_some());
+        }
+    }
 
     #[test]
     fn ipv4_custom_udp() {
@@ -2195,6 +4174,99 @@ mod test {
         assert_eq!(actual_payload, in_payload);
     }
 
+    #[test]
+    fn ipv4_header_tcp() {
+        let in_payload = [24, 25, 26, 27];
+        let ip_header = {
+            let mut ip_header = Ipv4Header::new(
+                0,                //payload_len will be replaced during write
+                12,               //time_to_live
+                ip_number::UDP,   //will be replaced during write
+                [13, 14, 15, 16], //source
+                [17, 18, 19, 20], //destination
+            )
+            .unwrap();
+            ip_header.identification = 1234;
+            ip_header.dscp = Ipv4Dscp::try_new(3).unwrap();
+            ip_header.options = [1, 2, 3, 4].into();
+            ip_header
+        };
+        let builder = PacketBuilder::ipv4_header(ip_header.clone()).tcp(22, 23, 24, 25);
+        let mut serialized = Vec::with_capacity(builder.size(in_payload.len()));
+        builder.write(&mut serialized, &in_payload).unwrap();
+
+        // starts directly with the ip header
+        let (ip_actual, rest) = Ipv4Header::from_slice(&serialized).unwrap();
+        let ip_expected = {
+            let mut ip_expected = ip_header.clone();
+            ip_expected.protocol = ip_number::TCP;
+            ip_expected
+                .set_payload_len(TcpHeader::MIN_LEN + in_payload.len())
+                .unwrap();
+            ip_expected.header_checksum = ip_expected.calc_header_checksum();
+            ip_expected
+        };
+        assert_eq!(ip_actual, ip_expected);
+
+        let (tcp_actual, rest) = TcpHeader::from_slice(rest).unwrap();
+        let mut tcp_expected = TcpHeader::new(22, 23, 24, 25);
+        tcp_expected.checksum = tcp_expected
+            .calc_checksum_ipv4(&ip_expected, &in_payload)
+            .unwrap();
+        assert_eq!(tcp_actual, tcp_expected);
+        assert_eq!(rest, &in_payload);
+
+        // same result as PacketBuilder::ip
+        let mut serialized_ip = Vec::new();
+        PacketBuilder::ip(IpHeaders::Ipv4(ip_header, Default::default()))
+            .tcp(22, 23, 24, 25)
+            .write(&mut serialized_ip, &in_payload)
+            .unwrap();
+        assert_eq!(serialized, serialized_ip);
+    }
+
+    #[test]
+    fn ipv6_header_tcp() {
+        let in_payload = [24, 25, 26, 27];
+        let ip_header = Ipv6Header {
+            traffic_class: 5,
+            flow_label: Ipv6FlowLabel::try_new(1234).unwrap(),
+            payload_length: 0,           //will be replaced during write
+            next_header: ip_number::UDP, //will be replaced during write
+            hop_limit: 47,
+            source: [1; 16],
+            destination: [2; 16],
+        };
+        let builder = PacketBuilder::ipv6_header(ip_header.clone()).tcp(22, 23, 24, 25);
+        let mut serialized = Vec::with_capacity(builder.size(in_payload.len()));
+        builder.write(&mut serialized, &in_payload).unwrap();
+
+        // starts directly with the ip header
+        let (ip_actual, rest) = Ipv6Header::from_slice(&serialized).unwrap();
+        let ip_expected = Ipv6Header {
+            payload_length: (TcpHeader::MIN_LEN + in_payload.len()) as u16,
+            next_header: ip_number::TCP,
+            ..ip_header.clone()
+        };
+        assert_eq!(ip_actual, ip_expected);
+
+        let (tcp_actual, rest) = TcpHeader::from_slice(rest).unwrap();
+        let mut tcp_expected = TcpHeader::new(22, 23, 24, 25);
+        tcp_expected.checksum = tcp_expected
+            .calc_checksum_ipv6(&ip_expected, &in_payload)
+            .unwrap();
+        assert_eq!(tcp_actual, tcp_expected);
+        assert_eq!(rest, &in_payload);
+
+        // same result as PacketBuilder::ip
+        let mut serialized_ip = Vec::new();
+        PacketBuilder::ip(IpHeaders::Ipv6(ip_header, Default::default()))
+            .tcp(22, 23, 24, 25)
+            .write(&mut serialized_ip, &in_payload)
+            .unwrap();
+        assert_eq!(serialized, serialized_ip);
+    }
+
     #[test]
     fn udp_builder_eth_ipv6_udp() {
         //generate
@@ -2849,13 +4921,9 @@ mod test {
             .unwrap();
 
         let decoded = PacketHeaders::from_ethernet_slice(&serialized[..]).unwrap();
-        let dec_options: Vec<Result<TcpOptionElement, TcpOptionReadError>> = decoded
-            .transport
-            .unwrap()
-            .tcp()
-            .unwrap()
-            .options_iterator()
-            .collect();
+        let tcp = decoded.transport.unwrap().tcp().unwrap();
+        let dec_options: Vec<Result<TcpOptionElement, TcpOptionReadError>> =
+            tcp.options_iterator().collect();
         assert_eq!(&[Ok(MaximumSegmentSize(1234)), Ok(Noop)], &dec_options[..]);
     }
 
@@ -3407,4 +5475,446 @@ mod test {
             }
         }
     }
+
+    /// Parses the given ipv6 fragments, checks that they fit into the mtu
+    /// and returns the reassembled fragmentable part.
+    #[cfg(feature = "std")]
+    fn reassemble_ipv6_fragments(fragments: &[Vec<u8>], link_len: usize, mtu: usize) -> Vec<u8> {
+        let mut buffer = reassembly::Ipv6FragmentBuffer::new(
+            reassembly::Ipv6FragmentBuffer::DEFAULT_MAX_PAYLOAD_LEN,
+        );
+        let mut result = None;
+        for (index, fragment) in fragments.iter().enumerate() {
+            assert!(fragment.len() - link_len <= mtu);
+            let ipv6 = Ipv6Slice::from_slice(&fragment[link_len..]).unwrap();
+            assert_eq!(
+                usize::from(ipv6.header().payload_length()) + Ipv6Header::LEN,
+                fragment.len() - link_len
+            );
+            let exts = Ipv6Extensions::from_slice_lax(
+                ipv6.header().next_header(),
+                ipv6.extensions().slice(),
+            )
+            .0;
+            let frag = exts.fragment.unwrap();
+            assert_eq!(frag.more_fragments, index + 1 < fragments.len());
+            // all fragments except the last one have to be a multiple of 8 bytes
+            if frag.more_fragments {
+                assert_eq!(0, ipv6.payload().payload.len() % 8);
+            }
+            assert_eq!(None, result);
+            result = buffer
+                .add(
+                    0,
+                    ipv6.header().source(),
+                    ipv6.header().destination(),
+                    &frag,
+                    ipv6.payload().payload,
+                )
+                .unwrap();
+        }
+        result.unwrap()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_fragments_ipv6() {
+        let payload: Vec<u8> = (0..3000u32).map(|v| v as u8).collect();
+        let builder = || {
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv6([11; 16], [12; 16], 20)
+                .udp(21, 1234)
+        };
+
+        // fragmented
+        {
+            let fragments = builder().to_fragments(1280, 1234, &payload).unwrap();
+            assert_eq!(3, fragments.len());
+            let reassembled = reassemble_ipv6_fragments(&fragments, Ethernet2Header::LEN, 1280);
+
+            // compare with the unfragmented transport layer
+            let mut expected = Vec::new();
+            builder().write(&mut expected, &payload).unwrap();
+            let expected_transport = &expected[Ethernet2Header::LEN + Ipv6Header::LEN..];
+            assert_eq!(&reassembled[..], expected_transport);
+            for fragment in fragments.iter() {
+                assert_eq!(
+                    &fragment[..Ethernet2Header::LEN],
+                    &expected[..Ethernet2Header::LEN]
+                );
+                let ipv6 = Ipv6Slice::from_slice(&fragment[Ethernet2Header::LEN..]).unwrap();
+                let frag = Ipv6FragmentHeader::from_slice(ipv6.extensions().slice())
+                    .unwrap()
+                    .0;
+                assert_eq!(frag.identification, 1234);
+            }
+        }
+
+        // fitting into the mtu
+        {
+            let fragments = builder().to_fragments(1280, 1234, &payload[..100]).unwrap();
+            let mut expected = Vec::new();
+            builder().write(&mut expected, &payload[..100]).unwrap();
+            assert_eq!(fragments, vec![expected]);
+        }
+
+        // mtu too small
+        assert!(matches!(
+            builder().to_fragments(55, 1234, &payload),
+            Err(BuildWriteError::MtuTooSmall {
+                mtu: 55,
+                min_mtu: 56
+            })
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_fragments_ipv6_exts() {
+        let payload: Vec<u8> = (0..2000u32).map(|v| v as u8).collect();
+        let hop_by_hop = Ipv6RawExtHeader::new_raw(ip_number::UDP, &[1; 6]).unwrap();
+        let routing = Ipv6RawExtHeader::new_raw(ip_number::UDP, &[2; 14]).unwrap();
+        let builder = || {
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .single_vlan(1.try_into().unwrap())
+                .ipv6([11; 16], [12; 16], 20)
+                .hop_by_hop(hop_by_hop.clone())
+                .routing(routing.clone())
+                .tcp(21, 1234, 1, 2)
+        };
+        let link_len = Ethernet2Header::LEN + SingleVlanHeader::LEN;
+
+        let fragments = builder().to_fragments(1000, 5678, &payload).unwrap();
+        assert_eq!(3, fragments.len());
+        for fragment in fragments.iter() {
+            let ipv6 = Ipv6Slice::from_slice(&fragment[link_len..]).unwrap();
+            let exts = Ipv6Extensions::from_slice_lax(
+                ipv6.header().next_header(),
+                ipv6.extensions().slice(),
+            )
+            .0;
+            assert_eq!(
+                exts.hop_by_hop_options.unwrap().payload(),
+                hop_by_hop.payload()
+            );
+            assert_eq!(exts.routing.unwrap().routing.payload(), routing.payload());
+            let frag = exts.fragment.unwrap();
+            assert_eq!(frag.identification, 5678);
+            assert_eq!(frag.next_header, ip_number::TCP);
+        }
+
+        let reassembled = reassemble_ipv6_fragments(&fragments, link_len, 1000);
+        let mut expected = Vec::new();
+        builder().write(&mut expected, &payload).unwrap();
+        let unfragmentable_len = Ipv6Header::LEN + hop_by_hop.header_len() + routing.header_len();
+        assert_eq!(&reassembled[..], &expected[link_len + unfragmentable_len..]);
+
+        // mtu too small
+        let min_mtu = unfragmentable_len + Ipv6FragmentHeader::LEN + 8;
+        assert!(matches!(
+            builder().to_fragments(min_mtu - 1, 5678, &payload),
+            Err(BuildWriteError::MtuTooSmall { mtu, min_mtu: m }) if mtu == min_mtu - 1 && m == min_mtu
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_fragments_ip_headers() {
+        let payload: Vec<u8> = (0..3000u32).map(|v| v as u8).collect();
+        let builder = || {
+            PacketBuilder::ip(IpHeaders::Ipv6(
+                Ipv6Header {
+                    source: [11; 16],
+                    destination: [12; 16],
+                    hop_limit: 20,
+                    ..Default::default()
+                },
+                Default::default(),
+            ))
+        };
+
+        let fragments = builder()
+            .to_fragments(1280, 1234, ip_number::EXPERIMENTAL_AND_TESTING_0, &payload)
+            .unwrap();
+        assert_eq!(3, fragments.len());
+        for fragment in fragments.iter() {
+            let ipv6 = Ipv6Slice::from_slice(fragment).unwrap();
+            assert_eq!(
+                ipv6.payload().ip_number,
+                ip_number::EXPERIMENTAL_AND_TESTING_0
+            );
+        }
+        assert_eq!(reassemble_ipv6_fragments(&fragments, 0, 1280), payload);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_fragments_ipv4() {
+        let payload: Vec<u8> = (0..3000u32).map(|v| v as u8).collect();
+        let ip_header = Ipv4Header {
+            identification: 1234,
+            dont_fragment: false,
+            time_to_live: 20,
+            source: [1, 2, 3, 4],
+            destination: [5, 6, 7, 8],
+            // router alert (copied flag set) & record route (copied flag not set)
+            options: [148, 4, 0, 0, 7, 3, 4, 0].into(),
+            ..Default::default()
+        };
+        let builder = |ip_header: &Ipv4Header| {
+            PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .single_vlan(1.try_into().unwrap())
+                .ip(IpHeaders::Ipv4(ip_header.clone(), Default::default()))
+                .udp(21, 1234)
+        };
+        let link_len = Ethernet2Header::LEN + SingleVlanHeader::LEN;
+
+        // fragmented
+        {
+            let fragments = builder(&ip_header).to_fragments(1000, 0, &payload).unwrap();
+            assert_eq!(4, fragments.len());
+
+            let mut expected = Vec::new();
+            builder(&ip_header).write(&mut expected, &payload).unwrap();
+            let header_len = ip_header.header_len();
+
+            let mut reassembled = Vec::new();
+            for (index, fragment) in fragments.iter().enumerate() {
+                assert!(fragment.len() - link_len <= 1000);
+                assert_eq!(&fragment[..link_len], &expected[..link_len]);
+
+                // from_slice verifies the total length
+                let ipv4 = Ipv4Slice::from_slice(&fragment[link_len..]).unwrap();
+                let header = ipv4.header().to_header();
+                assert_eq!(header.header_checksum, header.calc_header_checksum());
+                assert_eq!(usize::from(header.total_len), fragment.len() - link_len);
+                assert_eq!(header.identification, 1234);
+                if 0 == index {
+                    assert_eq!(header.options, ip_header.options);
+                } else {
+                    // only the options with the copied flag are repeated
+                    assert_eq!(header.options, Ipv4Options::from([148, 4, 0, 0]));
+                }
+                assert_eq!(header.protocol, ip_number::UDP);
+                assert_eq!(header.more_fragments, index + 1 < fragments.len());
+                assert_eq!(
+                    usize::from(header.fragment_offset.value()) * 8,
+                    reassembled.len()
+                );
+                if header.more_fragments {
+                    assert_eq!(0, ipv4.payload().payload.len() % 8);
+                }
+                reassembled.extend_from_slice(ipv4.payload().payload);
+            }
+            assert_eq!(&reassembled[..], &expected[link_len + header_len..]);
+        }
+
+        // ip headers step
+        {
+            let fragments =
+                PacketBuilder::ip(IpHeaders::Ipv4(ip_header.clone(), Default::default()))
+                    .to_fragments(1000, 0, ip_number::EXPERIMENTAL_AND_TESTING_0, &payload)
+                    .unwrap();
+            assert_eq!(4, fragments.len());
+            for fragment in fragments.iter() {
+                let ipv4 = Ipv4Slice::from_slice(fragment).unwrap();
+                assert_eq!(
+                    ipv4.header().protocol(),
+                    ip_number::EXPERIMENTAL_AND_TESTING_0
+                );
+            }
+        }
+
+        // fitting into the mtu (dont fragment is ignored)
+        {
+            let mut ip_header = ip_header.clone();
+            ip_header.dont_fragment = true;
+            let fragments = builder(&ip_header)
+                .to_fragments(1500, 0, &payload[..100])
+                .unwrap();
+            let mut expected = Vec::new();
+            builder(&ip_header)
+                .write(&mut expected, &payload[..100])
+                .unwrap();
+            assert_eq!(fragments, vec![expected]);
+        }
+
+        // dont fragment set
+        {
+            let mut ip_header = ip_header.clone();
+            ip_header.dont_fragment = true;
+            let result = builder(&ip_header).to_fragments(1500, 0, &payload);
+            assert!(matches!(
+                result,
+                Err(BuildWriteError::DontFragment { mtu: 1500, packet_len })
+                    if packet_len == ip_header.header_len() + UdpHeader::LEN + payload.len()
+            ));
+        }
+
+        // mtu too small
+        {
+            let result = builder(&ip_header).to_fragments(35, 0, &payload);
+            assert!(matches!(
+                result,
+                Err(BuildWriteError::MtuTooSmall {
+                    mtu: 35,
+                    min_mtu: 36
+                })
+            ));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn write_fragmented() {
+        let payload: Vec<u8> = (0..3000u32).map(|v| v as u8).collect();
+
+        // ipv6 (udp step)
+        {
+            let builder = || {
+                PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                    .ipv6([11; 16], [12; 16], 20)
+                    .udp(21, 1234)
+            };
+            let expected = builder().to_fragments(1280, 1234, &payload).unwrap();
+            let mut actual = Vec::new();
+            assert_eq!(
+                3,
+                builder()
+                    .write_fragmented(&mut actual, 1280, 1234, &payload)
+                    .unwrap()
+            );
+            assert_eq!(actual, expected.concat());
+
+            // fitting into the mtu
+            let mut actual = Vec::new();
+            assert_eq!(
+                1,
+                builder()
+                    .write_fragmented(&mut actual, 1280, 1234, &payload[..100])
+                    .unwrap()
+            );
+            let mut expected = Vec::new();
+            builder().write(&mut expected, &payload[..100]).unwrap();
+            assert_eq!(actual, expected);
+        }
+
+        // ip headers step
+        {
+            let builder = || PacketBuilder::ipv6([11; 16], [12; 16], 20);
+            let expected = builder()
+                .to_fragments(1280, 1234, ip_number::EXPERIMENTAL_AND_TESTING_0, &payload)
+                .unwrap();
+            let mut actual = Vec::new();
+            assert_eq!(
+                3,
+                builder()
+                    .write_fragmented(
+                        &mut actual,
+                        1280,
+                        1234,
+                        ip_number::EXPERIMENTAL_AND_TESTING_0,
+                        &payload
+                    )
+                    .unwrap()
+            );
+            assert_eq!(actual, expected.concat());
+        }
+
+        // error if a fragment header was already added (independent of
+        // the packet fitting into the mtu)
+        for payload_len in [100, payload.len()] {
+            let builder = || {
+                PacketBuilder::ipv6([11; 16], [12; 16], 20)
+                    .fragment(Ipv6FragmentHeader::new(
+                        ip_number::UDP,
+                        IpFragOffset::ZERO,
+                        false,
+                        4321,
+                    ))
+                    .udp(21, 1234)
+            };
+            let mut actual = Vec::new();
+            assert!(builder()
+                .write_fragmented(&mut actual, 1280, 1234, &payload[..payload_len])
+                .unwrap_err()
+                .is_fragment_header_present());
+            assert!(actual.is_empty());
+            assert!(builder()
+                .to_fragments(1280, 1234, &payload[..payload_len])
+                .unwrap_err()
+                .is_fragment_header_present());
+        }
+
+        // ipv4 (tcp step)
+        {
+            let builder = || {
+                PacketBuilder::ip(IpHeaders::Ipv4(
+                    Ipv4Header {
+                        identification: 1234,
+                        dont_fragment: false,
+                        time_to_live: 20,
+                        source: [1, 2, 3, 4],
+                        destination: [5, 6, 7, 8],
+                        ..Default::default()
+                    },
+                    Default::default(),
+                ))
+                .tcp(21, 1234, 1, 2)
+            };
+            let expected = builder().to_fragments(1000, 0, &payload).unwrap();
+            let mut actual = Vec::new();
+            assert_eq!(
+                expected.len(),
+                builder()
+                    .write_fragmented(&mut actual, 1000, 0, &payload)
+                    .unwrap()
+            );
+            assert_eq!(actual, expected.concat());
+        }
+
+        // icmp steps
+        {
+            let mut actual = Vec::new();
+            let count = PacketBuilder::ipv6([11; 16], [12; 16], 20)
+                .icmpv6_echo_request(1, 2)
+                .write_fragmented(&mut actual, 1280, 1234, &payload)
+                .unwrap();
+            assert_eq!(3, count);
+
+            let mut actual = Vec::new();
+            let count = PacketBuilder::ip(IpHeaders::Ipv4(
+                Ipv4Header {
+                    dont_fragment: false,
+                    ..Default::default()
+                },
+                Default::default(),
+            ))
+            .icmpv4_echo_request(1, 2)
+            .write_fragmented(&mut actual, 1000, 0, &payload)
+            .unwrap();
+            assert_eq!(4, count);
+        }
+
+        // io error
+        {
+            let mut buffer = [0u8; 100];
+            let err = PacketBuilder::ipv6([11; 16], [12; 16], 20)
+                .udp(21, 1234)
+                .write_fragmented(&mut &mut buffer[..], 1280, 1234, &payload)
+                .unwrap_err();
+            assert!(err.io().is_some());
+        }
+
+        // mtu too small
+        assert!(matches!(
+            PacketBuilder::ipv6([11; 16], [12; 16], 20)
+                .udp(21, 1234)
+                .write_fragmented(&mut Vec::new(), 55, 1234, &payload),
+            Err(BuildWriteError::MtuTooSmall {
+                mtu: 55,
+                min_mtu: 56
+            })
+        ));
+    }
 }
@@ -12,7 +12,7 @@ use super::*;
 ///
 /// depending on your starting header to parse the headers in a slice and get this
 /// struct as a result.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct PacketHeaders<'a> {
     /// Ethernet II header if present.
     pub link: Option<Ethernet2Header>,
@@ -20,6 +20,11 @@ pub struct PacketHeaders<'a> {
     pub vlan: Option<VlanHeader>,
     /// IPv4 or IPv6 header and IP extension headers if present.
     pub net: Option<NetHeaders>,
+    /// GRE header and the encapsulated IPv4 or IPv6 headers if present.
+    ///
+    /// If the GRE tunnel contains an IPv4 or IPv6 packet the `transport`
+    /// & `payload` fields refer to the encapsulated packet.
+    pub tunnel: Option<GreTunnelHeaders>,
     /// TCP or UDP header if present.
     pub transport: Option<TransportHeader>,
     /// Payload of the last parsed layer.
@@ -157,6 +162,7 @@ impl<'a> PacketHeaders<'a> {
             link: None,
             vlan: None,
             net: None,
+            tunnel: None,
             transport: None,
             payload: PayloadSlice::Ether(EtherPayloadSlice {
                 ether_type,
@@ -223,15 +229,14 @@ impl<'a> PacketHeaders<'a> {
                 result.net = Some(ip.into());
                 result.payload = PayloadSlice::Ip(ip_payload.clone());
 
-                // decode transport layer
-                let (transport, payload) = read_transport(ip_payload).map_err(|err| {
-                    use err::tcp::HeaderSliceError as I;
-                    match err {
-                        I::Len(err) => Len(add_offset(err, rest)),
-                        I::Content(err) => Tcp(err),
-                    }
-                })?;
+                // decode tunnel & transport layer
+                let (tunnel, transport, payload) =
+                    read_tunnel_and_transport(ip_payload).map_err(|err| match err {
+                        Len(err) => Len(add_offset(err, rest)),
+                        err => err,
+                    })?;
 
+                result.tunnel = tunnel;
                 result.transport = transport;
                 result.payload = payload;
             }
@@ -251,15 +256,14 @@ impl<'a> PacketHeaders<'a> {
                 result.net = Some(ip.into());
                 result.payload = PayloadSlice::Ip(ip_payload.clone());
 
-                // decode transport layer
-                let (transport, payload) = read_transport(ip_payload).map_err(|err| {
-                    use err::tcp::HeaderSliceError as I;
-                    match err {
-                        I::Len(err) => Len(add_offset(err, rest)),
-                        I::Content(err) => Tcp(err),
-                    }
-                })?;
+                // decode tunnel & transport layer
+                let (tunnel, transport, payload) =
+                    read_tunnel_and_transport(ip_payload).map_err(|err| match err {
+                        Len(err) => Len(add_offset(err, rest)),
+                        err => err,
+                    })?;
 
+                result.tunnel = tunnel;
                 result.transport = transport;
                 result.payload = payload;
             }
@@ -328,6 +332,7 @@ impl<'a> PacketHeaders<'a> {
             link: None,
             vlan: None,
             net: Some(ip_header.into()),
+            tunnel: None,
             transport: None,
             payload: PayloadSlice::Ip(ip_payload.clone()),
         };
@@ -335,22 +340,21 @@ impl<'a> PacketHeaders<'a> {
         // cache rest for offset addition
         let rest = ip_payload.payload;
 
-        // try to parse the transport header (only if data is not fragmented)
-        let (transport, payload) = read_transport(ip_payload).map_err(|err| {
-            use err::tcp::HeaderSliceError as I;
-            match err {
-                I::Len(mut err) => {
+        // try to parse the tunnel & transport header (only if data is not fragmented)
+        let (tunnel, transport, payload) =
+            read_tunnel_and_transport(ip_payload).map_err(|err| match err {
+                Len(mut err) => {
                     err.layer_start_offset += unsafe {
                         // SAFETY: Safe as rest is a subslice of slice.
                         rest.as_ptr().offset_from(slice.as_ptr()) as usize
                     };
                     Len(err)
                 }
-                I::Content(err) => Tcp(err),
-            }
-        })?;
+                err => err,
+            })?;
 
         // update output
+        result.tunnel = tunnel;
         result.transport = transport;
         result.payload = payload;
 
@@ -358,6 +362,111 @@ impl<'a> PacketHeaders<'a> {
     }
 }
 
+/// helper function to process a GRE tunnel (if present) and the transport headers
+///
+/// Length errors have their offset set relative to the start of the ip payload.
+fn read_tunnel_and_transport(
+    ip_payload: IpPayloadSlice,
+) -> Result<
+    (
+        Option<GreTunnelHeaders>,
+        Option<TransportHeader>,
+        PayloadSlice,
+    ),
+    err::packet::SliceError,
+> {
+    use err::packet::SliceError::*;
+
+    // helper function to convert transport errors
+    let from_transport_err = |err: err::tcp::HeaderSliceError| {
+        use err::tcp::HeaderSliceError as I;
+        match err {
+            I::Len(err) => Len(err),
+            I::Content(err) => Tcp(err),
+        }
+    };
+
+    if ip_payload.fragmented || ip_number::GRE != ip_payload.ip_number {
+        return read_transport(ip_payload)
+            .map(|(transport, payload)| (None, transport, payload))
+            .map_err(from_transport_err);
+    }
+
+    // helper function to set the len source & offset in len errors
+    let start = ip_payload.payload;
+    let adapt_len_err = |mut len_error: LenError, rest: &[u8]| -> LenError {
+        // only change the len source if the lower layer has not set it
+        if LenSource::Slice == len_error.len_source {
+            len_error.len_source = ip_payload.len_source;
+        }
+        len_error.layer_start_offset += unsafe {
+            // SAFETY: Safe as rest is a subslice of start.
+            rest.as_ptr().offset_from(start.as_ptr()) as usize
+        };
+        len_error
+    };
+
+    let (gre, gre_payload) =
+        GreHeader::from_slice(ip_payload.payload).map_err(|err| Len(adapt_len_err(err, start)))?;
+
+    // only decode the encapsulated packet for GRE version 0
+    let inner = if 0 == gre.version {
+        match gre.protocol_type {
+            ether_type::IPV4 => Some(IpHeaders::from_ipv4_slice(gre_payload).map_err(|err| {
+                use err::ipv4::SliceError as I;
+                match err {
+                    I::Len(err) => Len(adapt_len_err(err, gre_payload)),
+                    I::Header(err) => Ipv4(err),
+                    I::Exts(err) => Ipv4Exts(err),
+                }
+            })?),
+            ether_type::IPV6 => Some(IpHeaders::from_ipv6_slice(gre_payload).map_err(|err| {
+                use err::ipv6::SliceError as I;
+                match err {
+                    I::Len(err) => Len(adapt_len_err(err, gre_payload)),
+                    I::Header(err) => Ipv6(err),
+                    I::Exts(err) => Ipv6Exts(err),
+                }
+            })?),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    match inner {
+        Some((inner_ip, inner_payload)) => {
+            let inner_rest = inner_payload.payload;
+            let (transport, payload) = read_transport(inner_payload).map_err(|err| {
+                use err::tcp::HeaderSliceError as I;
+                match err {
+                    I::Len(err) => Len(adapt_len_err(err, inner_rest)),
+                    I::Content(err) => Tcp(err),
+                }
+            })?;
+            Ok((
+                Some(GreTunnelHeaders {
+                    gre,
+                    net: Some(inner_ip.into()),
+                }),
+                transport,
+                payload,
+            ))
+        }
+        None => {
+            let ether_type = gre.protocol_type;
+            Ok((
+                Some(GreTunnelHeaders { gre, net: None }),
+                None,
+                PayloadSlice::Ether(EtherPayloadSlice {
+                    ether_type,
+                    payload: gre_payload,
+                }),
+            ))
+        }
+    }
+}
+
 /// helper function to process transport headers
 fn read_transport(
     ip_payload: IpPayloadSlice,
@@ -435,6 +544,7 @@ mod test {
             link: None,
             vlan: None,
             net: None,
+            tunnel: None,
             transport: None,
             payload: PayloadSlice::Ether(EtherPayloadSlice {
                 ether_type: EtherType(0),
@@ -444,10 +554,11 @@ mod test {
         assert_eq!(
             &format!("{:?}", header),
             &format!(
-                "PacketHeaders {{ link: {:?}, vlan: {:?}, net: {:?}, transport: {:?}, payload: {:?} }}",
+                "PacketHeaders {{ link: {:?}, vlan: {:?}, net: {:?}, tunnel: {:?}, transport: {:?}, payload: {:?} }}",
                 header.link,
                 header.vlan,
                 header.net,
+                header.tunnel,
                 header.transport,
                 header.payload
             )
@@ -460,6 +571,7 @@ mod test {
             link: None,
             vlan: None,
             net: None,
+            tunnel: None,
             transport: None,
             payload: PayloadSlice::Ether(EtherPayloadSlice {
                 ether_type: EtherType(0),
@@ -1251,4 +1363,142 @@ mod test {
             assert_eq!(err, PacketHeaders::from_ip_slice(&data).unwrap_err());
         }
     }
+
+    #[test]
+    fn gre() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4];
+        let udp = UdpHeader {
+            source_port: 1,
+            destination_port: 2,
+            length: (UdpHeader::LEN + payload.len()) as u16,
+            checksum: 0,
+        };
+        let inner_ip = Ipv4Header::new(
+            (UdpHeader::LEN + payload.len()) as u16,
+            3,
+            ip_number::UDP,
+            [4, 5, 6, 7],
+            [8, 9, 10, 11],
+        )
+        .unwrap();
+
+        // helper to build an ipv4 packet containing a gre header & payload
+        let build = |gre: &GreHeader, gre_payload: &[u8]| -> (Ipv4Header, Vec<u8>) {
+            let outer_ip = Ipv4Header::new(
+                (gre.header_len() + gre_payload.len()) as u16,
+                12,
+                ip_number::GRE,
+                [13, 14, 15, 16],
+                [17, 18, 19, 20],
+            )
+            .unwrap();
+            let mut data = Vec::new();
+            data.extend_from_slice(&outer_ip.to_bytes());
+            data.extend_from_slice(&gre.to_bytes());
+            data.extend_from_slice(gre_payload);
+            (outer_ip, data)
+        };
+
+        // gre with inner ipv4 & udp
+        {
+            let gre = GreHeader {
+                protocol_type: ether_type::IPV4,
+                key: Some(21),
+                ..Default::default()
+            };
+            let mut inner = Vec::new();
+            inner.extend_from_slice(&inner_ip.to_bytes());
+            inner.extend_from_slice(&udp.to_bytes());
+            inner.extend_from_slice(&payload);
+            let (outer_ip, data) = build(&gre, &inner);
+
+            let expected_tunnel = Some(GreTunnelHeaders {
+                gre: gre.clone(),
+                net: Some(NetHeaders::Ipv4(inner_ip.clone(), Default::default())),
+            });
+            for result in [
+                PacketHeaders::from_ip_slice(&data).unwrap(),
+                PacketHeaders::from_ether_type(ether_type::IPV4, &data).unwrap(),
+            ] {
+                assert_eq!(
+                    result.net,
+                    Some(NetHeaders::Ipv4(outer_ip.clone(), Default::default()))
+                );
+                assert_eq!(result.tunnel, expected_tunnel);
+                assert_eq!(result.transport, Some(TransportHeader::Udp(udp.clone())));
+                assert_eq!(result.payload.slice(), &payload);
+            }
+
+            // length error in the inner udp header
+            let inner_start = Ipv4Header::MIN_LEN + gre.header_len();
+            let udp_start = inner_start + Ipv4Header::MIN_LEN;
+            let mut short = data[..udp_start + 4].to_vec();
+            // adapt the total length fields of the outer & inner ipv4 header
+            let total_len = short.len() as u16;
+            short[2..4].copy_from_slice(&total_len.to_be_bytes());
+            let inner_total_len = (short.len() - inner_start) as u16;
+            short[inner_start + 2..inner_start + 4].copy_from_slice(&inner_total_len.to_be_bytes());
+            assert_eq!(
+                PacketHeaders::from_ip_slice(&short).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: UdpHeader::LEN,
+                    len: 4,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: err::Layer::UdpHeader,
+                    layer_start_offset: udp_start,
+                })
+            );
+        }
+
+        // gre with unknown protocol type
+        {
+            let gre = GreHeader {
+                protocol_type: ether_type::ARP,
+                sequence_number: Some(22),
+                ..Default::default()
+            };
+            let (_, data) = build(&gre, &payload);
+            let result = PacketHeaders::from_ip_slice(&data).unwrap();
+            assert_eq!(
+                result.tunnel,
+                Some(GreTunnelHeaders {
+                    gre: gre.clone(),
+                    net: None
+                })
+            );
+            assert_eq!(result.transport, None);
+            assert_eq!(
+                result.payload,
+                PayloadSlice::Ether(EtherPayloadSlice {
+                    ether_type: ether_type::ARP,
+                    payload: &payload,
+                })
+            );
+        }
+
+        // gre length error
+        {
+            let gre = GreHeader {
+                protocol_type: ether_type::IPV4,
+                checksum: Some(0),
+                ..Default::default()
+            };
+            let (_, data) = build(&gre, &[]);
+            let mut data = data[..data.len() - 1].to_vec();
+            let total_len = data.len() as u16;
+            data[2..4].copy_from_slice(&total_len.to_be_bytes());
+            assert_eq!(
+                PacketHeaders::from_ip_slice(&data).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: gre.header_len(),
+                    len: gre.header_len() - 1,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: err::Layer::GreHeader,
+                    layer_start_offset: Ipv4Header::MIN_LEN,
+                })
+            );
+        }
+    }
 }
@@ -0,0 +1,659 @@
+use crate::*;
+
+/// Layer that is decoded next by a [`PacketSlicer`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum NextLayer {
+    Ethernet2,
+    OuterVlan,
+    InnerVlan,
+    Pppoe,
+    Arp,
+    Ip,
+    Ipv4,
+    Ipv6,
+    Icmpv4,
+    Icmpv6,
+    Udp,
+    Tcp,
+    Igmp,
+    Esp,
+    Dccp,
+    /// No further layers can be decoded (remaining data is payload).
+    Payload,
+}
+
+impl NextLayer {
+    /// Returns the layer identified by an ether type.
+    fn from_ether_type(ether_type: EtherType, allow_inner_vlan: bool) -> NextLayer {
+        use ether_type::*;
+        match ether_type {
+            IPV4 => NextLayer::Ipv4,
+            IPV6 => NextLayer::Ipv6,
+            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME
+                if allow_inner_vlan =>
+            {
+                NextLayer::InnerVlan
+            }
+            ARP => NextLayer::Arp,
+            PPPOE_DISCOVERY | PPPOE_SESSION => NextLayer::Pppoe,
+            _ => NextLayer::Payload,
+        }
+    }
+
+    /// Returns the layer identified by the ip number of an ip payload.
+    fn from_ip_payload(payload: &IpPayloadSlice) -> NextLayer {
+        // only try to decode the transport layer if the payload
+        // is not fragmented
+        if payload.fragmented {
+            return NextLayer::Payload;
+        }
+        match payload.ip_number {
+            ip_number::ICMP => NextLayer::Icmpv4,
+            ip_number::UDP => NextLayer::Udp,
+            ip_number::TCP => NextLayer::Tcp,
+            ip_number::IPV6_ICMP => NextLayer::Icmpv6,
+            ip_number::IGMP => NextLayer::Igmp,
+            ip_number::ENCAP_SEC => NextLayer::Esp,
+            ip_number::DCCP => NextLayer::Dccp,
+            _ => NextLayer::Payload,
+        }
+    }
+}
+
+/// Slices a packet one layer at a time.
+///
+/// In contrast to [`SlicedPacket`] the layers are only decoded when
+/// requested via [`PacketSlicer::next_layer`]. This allows to stop
+/// the decoding as soon as the required layers are found (e.g. after
+/// the IP header if only the addresses are relevant).
+///
+/// The same layers as in [`SlicedPacket`] (with the default
+/// [`ParseOptions`]) are decoded & the same errors are returned.
+///
+/// # Example
+///
+/// ```
+/// # use etherparse::{PacketBuilder, PacketSlicer, LayerSlice};
+/// # let builder = PacketBuilder::
+/// #    ethernet2([1,2,3,4,5,6],     //source mac
+/// #               [7,8,9,10,11,12]) //destination mac
+/// #    .ipv4([192,168,1,1], //source ip
+/// #          [192,168,1,2], //destination ip
+/// #          20)            //time to life
+/// #    .udp(21,    //source port
+/// #         1234); //destination port
+/// # let payload = [1,2,3,4,5,6,7,8];
+/// # let mut packet = Vec::<u8>::with_capacity(builder.size(payload.len()));
+/// # builder.write(&mut packet, &payload).unwrap();
+/// let mut slicer = PacketSlicer::new(&packet);
+/// loop {
+///     match slicer.next_layer() {
+///         Ok(Some(LayerSlice::Ipv4(ipv4))) => {
+///             // stop after the ip header
+///             println!("source: {:?}", ipv4.header().source_addr());
+///             break;
+///         }
+///         Ok(Some(other)) => println!("{:?}", other),
+///         Ok(None) => break,
+///         Err(err) => {
+///             println!("Err {:?}", err);
+///             break;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PacketSlicer<'a> {
+    /// Data that has not yet been decoded.
+    rest: &'a [u8],
+    /// Offset of `rest` in the original slice.
+    offset: usize,
+    /// Source of the length of `rest`.
+    len_source: LenSource,
+    /// Layer that is decoded next.
+    next: NextLayer,
+}
+
+impl<'a> PacketSlicer<'a> {
+    /// Creates a slicer for a packet starting with an Ethernet II header.
+    #[inline]
+    pub fn new(slice: &'a [u8]) -> PacketSlicer<'a> {
+        PacketSlicer {
+            rest: slice,
+            offset: 0,
+            len_source: LenSource::Slice,
+            next: NextLayer::Ethernet2,
+        }
+    }
+
+    /// Creates a slicer for a packet with the given `ether_type` (e.g. the
+    /// data after an Ethernet II header).
+    ///
+    /// See [`SlicedPacket::from_ether_type`] for the supported ether types.
+    /// If the ether type is not supported the slicer returns no layers.
+    #[inline]
+    pub fn from_ether_type(ether_type: EtherType, slice: &'a [u8]) -> PacketSlicer<'a> {
+        use ether_type::*;
+        PacketSlicer {
+            rest: slice,
+            offset: 0,
+            len_source: LenSource::Slice,
+            next: match ether_type {
+                VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
+                    NextLayer::OuterVlan
+                }
+                other => NextLayer::from_ether_type(other, false),
+            },
+        }
+    }
+
+    /// Creates a slicer for a packet starting with an IPv4 or IPv6 header.
+    #[inline]
+    pub fn from_ip(slice: &'a [u8]) -> PacketSlicer<'a> {
+        PacketSlicer {
+            rest: slice,
+            offset: 0,
+            len_source: LenSource::Slice,
+            next: NextLayer::Ip,
+        }
+    }
+
+    /// Returns the data that has not yet been decoded.
+    ///
+    /// After the last layer has been returned this is the payload
+    /// of that layer (e.g. the UDP payload).
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+
+    /// Returns the offset of [`PacketSlicer::rest`] in the original slice.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the source of the length of [`PacketSlicer::rest`]
+    /// (e.g. an IP header length field).
+    #[inline]
+    pub fn len_source(&self) -> LenSource {
+        self.len_source
+    }
+
+    /// Decodes the next layer & advances the slicer behind it.
+    ///
+    /// Returns `Ok(None)` if there are no more layers that can be decoded
+    /// (the remaining data can be accessed via [`PacketSlicer::rest`]). After
+    /// an error was returned no further layers are decoded.
+    pub fn next_layer(&mut self) -> Result<Option<LayerSlice<'a>>, err::packet::SliceError> {
+        let result = self.slice_next();
+        if result.is_err() {
+            self.next = NextLayer::Payload;
+        }
+        result
+    }
+
+    /// Sets the rest to a sub-slice of the current rest.
+    fn set_rest(&mut self, rest: &'a [u8]) {
+        self.offset += unsafe {
+            // SAFETY: All passed slices are sub-slices of self.rest.
+            // therefor calculating the offset from it is safe and
+            // the result should always be a positive number.
+            rest.as_ptr().offset_from(self.rest.as_ptr()) as usize
+        };
+        self.rest = rest;
+    }
+
+    /// Adds the offset & len source to a length error of a transport layer.
+    fn transport_len_err(&self, mut err: err::LenError) -> err::LenError {
+        err.layer_start_offset += self.offset;
+        if LenSource::Slice == err.len_source {
+            err.len_source = self.len_source;
+        }
+        err
+    }
+
+    fn slice_next(&mut self) -> Result<Option<LayerSlice<'a>>, err::packet::SliceError> {
+        use err::packet::SliceError::*;
+
+        match self.next {
+            NextLayer::Ethernet2 => {
+                let eth = Ethernet2Slice::from_slice_without_fcs(self.rest)
+                    .map_err(|err| Len(err.add_offset(self.offset)))?;
+                self.set_rest(eth.payload_slice());
+                self.next = match eth.ether_type() {
+                    ether_type::VLAN_TAGGED_FRAME
+                    | ether_type::PROVIDER_BRIDGING
+                    | ether_type::VLAN_DOUBLE_TAGGED_FRAME => NextLayer::OuterVlan,
+                    other => NextLayer::from_ether_type(other, false),
+                };
+                Ok(Some(LayerSlice::Ethernet2(eth)))
+            }
+            NextLayer::OuterVlan | NextLayer::InnerVlan => {
+                let vlan = SingleVlanSlice::from_slice(self.rest)
+                    .map_err(|err| Len(err.add_offset(self.offset)))?;
+                self.set_rest(vlan.payload_slice());
+                self.next = NextLayer::from_ether_type(
+                    vlan.ether_type(),
+                    NextLayer::OuterVlan == self.next,
+                );
+                Ok(Some(LayerSlice::Vlan(vlan)))
+            }
+            NextLayer::Pppoe => {
+                let pppoe = PppoeHeaderSlice::from_slice(self.rest)
+                    .map_err(|err| Len(err.add_offset(self.offset)))?;
+                // cut off data after the pppoe payload (e.g. ethernet padding)
+                let header_len = pppoe.slice().len();
+                self.set_rest(&self.rest[header_len..header_len + pppoe.payload_len()]);
+                self.next = match pppoe.ppp_protocol() {
+                    Some(PppoeHeader::PPP_PROTOCOL_IPV4) => NextLayer::Ipv4,
+                    Some(PppoeHeader::PPP_PROTOCOL_IPV6) => NextLayer::Ipv6,
+                    _ => NextLayer::Payload,
+                };
+                Ok(Some(LayerSlice::Pppoe(pppoe)))
+            }
+            NextLayer::Arp => {
+                let arp = ArpPacketSlice::from_slice(self.rest)
+                    .map_err(|err| Len(err.add_offset(self.offset)))?;
+                self.set_rest(&self.rest[arp.slice().len()..]);
+                self.next = NextLayer::Payload;
+                Ok(Some(LayerSlice::Arp(arp)))
+            }
+            NextLayer::Ip => {
+                let ip = IpSlice::from_slice_with_max_ipv6_exts(
+                    self.rest,
+                    Some(Ipv6ExtensionsIter::MAX_HEADERS),
+                )
+                .map_err(|err| {
+                    use err::ip::SliceError as I;
+                    match err {
+                        I::Len(mut err) => {
+                            err.layer_start_offset += self.offset;
+                            Len(err)
+                        }
+                        I::IpHeaders(err) => match err {
+                            err::ip::HeadersError::Ip(err) => Ip(err),
+                            err::ip::HeadersError::Ipv4Ext(err) => Ipv4Exts(err),
+                            err::ip::HeadersError::Ipv6Ext(err) => Ipv6Exts(err),
+                        },
+                    }
+                })?;
+                let payload = ip.payload().clone();
+                self.set_rest(payload.payload);
+                self.len_source = payload.len_source;
+                self.next = NextLayer::from_ip_payload(&payload);
+                Ok(Some(match ip {
+                    IpSlice::Ipv4(ipv4) => LayerSlice::Ipv4(ipv4),
+                    IpSlice::Ipv6(ipv6) => LayerSlice::Ipv6(ipv6),
+                }))
+            }
+            NextLayer::Ipv4 => {
+                let ipv4 = Ipv4Slice::from_slice(self.rest).map_err(|err| {
+                    use err::ipv4::SliceError as I;
+                    match err {
+                        I::Len(mut err) => {
+                            err.layer_start_offset += self.offset;
+                            Len(err)
+                        }
+                        I::Header(err) => Ipv4(err),
+                        I::Exts(err) => Ipv4Exts(err),
+                    }
+                })?;
+                let payload = ipv4.payload().clone();
+                self.set_rest(payload.payload);
+                self.len_source = payload.len_source;
+                self.next = NextLayer::from_ip_payload(&payload);
+                Ok(Some(LayerSlice::Ipv4(ipv4)))
+            }
+            NextLayer::Ipv6 => {
+                let ipv6 = Ipv6Slice::from_slice_with_max_exts(
+                    self.rest,
+                    Some(Ipv6ExtensionsIter::MAX_HEADERS),
+                )
+                .map_err(|err| {
+                    use err::ipv6::SliceError as I;
+                    match err {
+                        I::Len(mut err) => {
+                            err.layer_start_offset += self.offset;
+                            Len(err)
+                        }
+                        I::Header(err) => Ipv6(err),
+                        I::Exts(err) => Ipv6Exts(err),
+                    }
+                })?;
+                let payload = ipv6.payload().clone();
+                self.set_rest(payload.payload);
+                self.len_source = payload.len_source;
+                self.next = NextLayer::from_ip_payload(&payload);
+                Ok(Some(LayerSlice::Ipv6(ipv6)))
+            }
+            NextLayer::Icmpv4 => {
+                let icmp = Icmpv4Slice::from_slice(self.rest)
+                    .map_err(|err| Len(self.transport_len_err(err)))?;
+                self.set_rest(icmp.payload());
+                self.next = NextLayer::Payload;
+                Ok(Some(LayerSlice::Icmpv4(icmp)))
+            }
+            NextLayer::Icmpv6 => {
+                let icmp = Icmpv6Slice::from_slice(self.rest)
+                    .map_err(|err| Len(self.transport_len_err(err)))?;
+                self.set_rest(icmp.payload());
+                self.next = NextLayer::Payload;
+                Ok(Some(LayerSlice::Icmpv6(icmp)))
+            }
+            NextLayer::Udp => {
+                let udp = UdpSlice::from_slice(self.rest)
+                    .map_err(|err| Len(self.transport_len_err(err)))?;
+                self.set_rest(udp.payload());
+                if LenSource::Slice != udp.payload_len_source() {
+                    self.len_source = udp.payload_len_source();
+                }
+                self.next = NextLayer::Payload;
+                Ok(Some(LayerSlice::Udp(udp)))
+            }
+            NextLayer::Tcp => {
+                let tcp = TcpSlice::from_slice(self.rest).map_err(|err| {
+                    use err::tcp::HeaderSliceError as I;
+                    match err {
+                        I::Len(err) => Len(self.transport_len_err(err)),
+                        I::Content(err) => Tcp(err),
+                    }
+                })?;
+                self.set_rest(tcp.payload());
+                self.next = NextLayer::Payload;
+                Ok(Some(LayerSlice::Tcp(tcp)))
+            }
+            NextLayer::Igmp => {
+                let igmp = IgmpSlice::from_slice(self.rest)
+                    .map_err(|err| Len(self.transport_len_err(err)))?;
+                self.set_rest(&self.rest[igmp.slice().len()..]);
+                self.next = NextLayer::Payload;
+                Ok(Some(LayerSlice::Igmp(igmp)))
+            }
+            NextLayer::Esp => {
+                let esp = EspSlice::from_slice(self.rest)
+                    .map_err(|err| Len(self.transport_len_err(err)))?;
+                self.set_rest(&self.rest[esp.slice().len()..]);
+                self.next = NextLayer::Payload;
+                Ok(Some(LayerSlice::Esp(esp)))
+            }
+            NextLayer::Dccp => {
+                let dccp = DccpSlice::from_slice(self.rest).map_err(|err| {
+                    use err::dccp::HeaderSliceError as I;
+                    match err {
+                        I::Len(err) => Len(self.transport_len_err(err)),
+                        I::Content(err) => Dccp(err),
+                    }
+                })?;
+                self.set_rest(dccp.payload());
+                self.next = NextLayer::Payload;
+                Ok(Some(LayerSlice::Dccp(dccp)))
+            }
+            NextLayer::Payload => Ok(None),
+        }
+    }
+}
+
+impl<'a> Iterator for PacketSlicer<'a> {
+    type Item = Result<LayerSlice<'a>, err::packet::SliceError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_layer().transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    /// Checks that the slicer returns the same layers & errors
+    /// as [`SlicedPacket`] for all lengths of the given data.
+    fn assert_matches_sliced_packet(
+        data: &[u8],
+        slicer: for<'b> fn(&'b [u8]) -> PacketSlicer<'b>,
+        sliced: for<'b> fn(&'b [u8]) -> Result<SlicedPacket<'b>, err::packet::SliceError>,
+    ) {
+        for len in 0..=data.len() {
+            let layers: Result<Vec<_>, _> = slicer(&data[..len]).collect();
+            match sliced(&data[..len]) {
+                Ok(expected) => {
+                    let layers = layers.unwrap();
+                    let mut vlans = Vec::new();
+                    for layer in layers.iter() {
+                        match layer {
+                            LayerSlice::Ethernet2(eth) => {
+                                assert_eq!(
+                                    Some(&LinkSlice::Ethernet2(eth.clone())),
+                                    expected.link.as_ref()
+                                );
+                            }
+                            LayerSlice::Vlan(vlan) => vlans.push(vlan.clone()),
+                            LayerSlice::Pppoe(pppoe) => {
+                                assert_eq!(Some(pppoe), expected.pppoe.as_ref())
+                            }
+                            LayerSlice::Arp(arp) => {
+                                assert_eq!(Some(&NetSlice::Arp(arp.clone())), expected.net.as_ref())
+                            }
+                            LayerSlice::Ipv4(ipv4) => assert_eq!(
+                                Some(&NetSlice::Ipv4(ipv4.clone())),
+                                expected.net.as_ref()
+                            ),
+                            LayerSlice::Ipv6(ipv6) => assert_eq!(
+                                Some(&NetSlice::Ipv6(ipv6.clone())),
+                                expected.net.as_ref()
+                            ),
+                            LayerSlice::Icmpv4(s) => assert_eq!(
+                                Some(&TransportSlice::Icmpv4(s.clone())),
+                                expected.transport.as_ref()
+                            ),
+                            LayerSlice::Icmpv6(s) => assert_eq!(
+                                Some(&TransportSlice::Icmpv6(s.clone())),
+                                expected.transport.as_ref()
+                            ),
+                            LayerSlice::Udp(s) => assert_eq!(
+                                Some(&TransportSlice::Udp(s.clone())),
+                                expected.transport.as_ref()
+                            ),
+                            LayerSlice::Tcp(s) => assert_eq!(
+                                Some(&TransportSlice::Tcp(s.clone())),
+                                expected.transport.as_ref()
+                            ),
+                            LayerSlice::Igmp(s) => assert_eq!(
+                                Some(&TransportSlice::Igmp(s.clone())),
+                                expected.transport.as_ref()
+                            ),
+                            LayerSlice::Esp(s) => assert_eq!(
+                                Some(&TransportSlice::Esp(s.clone())),
+                                expected.transport.as_ref()
+                            ),
+                            LayerSlice::Dccp(s) => assert_eq!(
+                                Some(&TransportSlice::Dccp(s.clone())),
+                                expected.transport.as_ref()
+                            ),
+                        }
+                    }
+                    assert_eq!(
+                        layers.len(),
+                        usize::from(matches!(expected.link, Some(LinkSlice::Ethernet2(_))))
+                            + vlans.len()
+                            + usize::from(expected.pppoe.is_some())
+                            + usize::from(expected.net.is_some())
+                            + usize::from(expected.transport.is_some())
+                    );
+                    match &expected.vlan {
+                        None => assert!(vlans.is_empty()),
+                        Some(VlanSlice::SingleVlan(s)) => {
+                            assert_eq!(&vlans[..], core::slice::from_ref(s))
+                        }
+                        Some(VlanSlice::DoubleVlan(d)) => {
+                            assert_eq!(&vlans[..], &[d.outer(), d.inner()])
+                        }
+                    }
+                }
+                Err(expected) => {
+                    assert_eq!(expected, layers.unwrap_err());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let slicer = PacketSlicer::from_ip(&[]);
+        assert_eq!(slicer.clone(), slicer);
+        assert_eq!(
+            format!("{:?}", slicer),
+            "PacketSlicer { rest: [], offset: 0, len_source: Slice, next: Ip }"
+        );
+    }
+
+    #[test]
+    fn ethernet_vlan_ipv4_udp() {
+        let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+            .double_vlan(0x123.try_into().unwrap(), 0x234.try_into().unwrap())
+            .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+            .udp(21, 1234);
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+        let mut data = Vec::with_capacity(builder.size(payload.len()));
+        builder.write(&mut data, &payload).unwrap();
+
+        // layers & rest
+        {
+            let mut slicer = PacketSlicer::new(&data);
+            assert!(matches!(
+                slicer.next_layer(),
+                Ok(Some(LayerSlice::Ethernet2(_)))
+            ));
+            assert_eq!(Ethernet2Header::LEN, slicer.offset());
+            assert!(matches!(slicer.next_layer(), Ok(Some(LayerSlice::Vlan(_)))));
+            assert!(matches!(slicer.next_layer(), Ok(Some(LayerSlice::Vlan(_)))));
+            assert!(matches!(slicer.next_layer(), Ok(Some(LayerSlice::Ipv4(_)))));
+            assert_eq!(LenSource::Ipv4HeaderTotalLen, slicer.len_source());
+            assert!(matches!(slicer.next_layer(), Ok(Some(LayerSlice::Udp(_)))));
+            assert_eq!(None, slicer.next_layer().unwrap());
+            assert_eq!(None, slicer.next_layer().unwrap());
+            assert_eq!(&payload, slicer.rest());
+            assert_eq!(data.len() - payload.len(), slicer.offset());
+        }
+
+        assert_matches_sliced_packet(
+            &data,
+            |d| PacketSlicer::new(d),
+            |d| SlicedPacket::from_ethernet(d),
+        );
+        assert_matches_sliced_packet(
+            &data[Ethernet2Header::LEN..],
+            |d| PacketSlicer::from_ether_type(ether_type::VLAN_TAGGED_FRAME, d),
+            |d| SlicedPacket::from_ether_type(ether_type::VLAN_TAGGED_FRAME, d),
+        );
+    }
+
+    #[test]
+    fn ip_transports() {
+        let payload = [1, 2, 3, 4];
+        let packets = [
+            {
+                let builder = PacketBuilder::ipv6([1; 16], [2; 16], 20).tcp(1, 2, 3, 4);
+                let mut data = Vec::with_capacity(builder.size(payload.len()));
+                builder.write(&mut data, &payload).unwrap();
+                data
+            },
+            {
+                let builder = PacketBuilder::ipv4([1; 4], [2; 4], 20).icmpv4_echo_request(1, 2);
+                let mut data = Vec::with_capacity(builder.size(payload.len()));
+                builder.write(&mut data, &payload).unwrap();
+                data
+            },
+            {
+                let builder = PacketBuilder::ipv6([1; 16], [2; 16], 20).icmpv6_echo_request(1, 2);
+                let mut data = Vec::with_capacity(builder.size(payload.len()));
+                builder.write(&mut data, &payload).unwrap();
+                data
+            },
+        ];
+        for data in packets {
+            let mut slicer = PacketSlicer::from_ip(&data);
+            assert!(slicer.next_layer().unwrap().is_some());
+            assert!(slicer.next_layer().unwrap().is_some());
+            assert_eq!(None, slicer.next_layer().unwrap());
+            assert_eq!(&payload, slicer.rest());
+
+            assert_matches_sliced_packet(
+                &data,
+                |d| PacketSlicer::from_ip(d),
+                |d| SlicedPacket::from_ip(d),
+            );
+        }
+    }
+
+    #[test]
+    fn pppoe() {
+        let mut ip = Vec::new();
+        PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 64)
+            .udp(1, 2)
+            .write(&mut ip, &[9, 10])
+            .unwrap();
+
+        let mut data = Vec::new();
+        data.extend_from_slice(
+            &PppoeHeader {
+                version: 1,
+                pppoe_type: 1,
+                code: PppoeHeader::CODE_SESSION_DATA,
+                session_id: 0x1234,
+                payload_length: (ip.len() + 2) as u16,
+                ppp_protocol: Some(PppoeHeader::PPP_PROTOCOL_IPV4),
+            }
+            .to_bytes(),
+        );
+        data.extend_from_slice(&ip);
+        // ethernet padding
+        data.extend_from_slice(&[0; 4]);
+
+        let mut slicer = PacketSlicer::from_ether_type(ether_type::PPPOE_SESSION, &data);
+        assert!(matches!(
+            slicer.next_layer(),
+            Ok(Some(LayerSlice::Pppoe(_)))
+        ));
+        assert_eq!(&ip, slicer.rest());
+
+        assert_matches_sliced_packet(
+            &data,
+            |d| PacketSlicer::from_ether_type(ether_type::PPPOE_SESSION, d),
+            |d| SlicedPacket::from_ether_type(ether_type::PPPOE_SESSION, d),
+        );
+    }
+
+    #[test]
+    fn unknown_ether_type() {
+        let data = [1, 2, 3, 4];
+        let mut slicer = PacketSlicer::from_ether_type(EtherType(0x1234), &data);
+        assert_eq!(None, slicer.next_layer().unwrap());
+        assert_eq!(&data, slicer.rest());
+        assert_eq!(0, slicer.offset());
+    }
+
+    #[test]
+    fn fragmented() {
+        let builder = PacketBuilder::ipv4([1; 4], [2; 4], 20).udp(1, 2);
+        let mut data = Vec::with_capacity(builder.size(4));
+        builder.write(&mut data, &[1, 2, 3, 4]).unwrap();
+        // set the more fragments flag
+        data[6] |= 0b0010_0000;
+
+        let mut slicer = PacketSlicer::from_ip(&data);
+        assert!(matches!(slicer.next_layer(), Ok(Some(LayerSlice::Ipv4(_)))));
+        assert_eq!(None, slicer.next_layer().unwrap());
+        assert_eq!(&data[Ipv4Header::MIN_LEN..], slicer.rest());
+    }
+
+    #[test]
+    fn no_layers_after_error() {
+        let mut slicer = PacketSlicer::new(&[1, 2, 3]);
+        assert!(slicer.next_layer().is_err());
+        assert_eq!(None, slicer.next_layer().unwrap());
+        assert!(slicer.next().is_none());
+    }
+}
@@ -0,0 +1,181 @@
+/// Options controlling how [`crate::SlicedPacket`] decodes a packet.
+///
+/// The default options only decode protocols that can be identified
+/// via the fields of the preceding headers (e.g. ether type or IP number).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ParseOptions, VxlanHeader};
+///
+/// let options = ParseOptions {
+///     vxlan_port: Some(VxlanHeader::IANA_PORT),
+///     ..Default::default()
+/// };
+/// assert_eq!(options.vxlan_port, Some(4789));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ParseOptions {
+    /// UDP destination port on which VXLAN encapsulated packets are expected.
+    ///
+    /// If set, the payload of UDP packets with a matching destination port
+    /// is decoded as a VXLAN header followed by an Ethernet II frame (see
+    /// [`crate::SlicedPacket::vxlan`]). As VXLAN can only be identified
+    /// via the port this is disabled by default (`None`).
+    pub vxlan_port: Option<u16>,
+
+    /// UDP destination port on which GTP-U encapsulated packets are expected.
+    ///
+    /// If set, the payload of UDP packets with a matching destination port
+    /// is decoded as a GTP-U header & extension headers. For G-PDU messages
+    /// the payload is additionally decoded as an IPv4 or IPv6 packet (see
+    /// [`crate::SlicedPacket::gtpu`]). As GTP-U can only be identified via
+    /// the port this is disabled by default (`None`). If the same port is
+    /// configured for VXLAN, VXLAN decoding takes precedence.
+    pub gtpu_port: Option<u16>,
+
+    /// UDP destination port on which Geneve encapsulated packets are expected.
+    ///
+    /// If set, the payload of UDP packets with a matching destination port
+    /// is decoded as a Geneve header & options. Depending on the protocol
+    /// type field the payload is additionally decoded as an Ethernet II
+    /// frame ([`crate::EtherType::TRANSPARENT_ETHERNET_BRIDGING`]) or as
+    /// an IPv4 or IPv6 packet (see [`crate::SlicedPacket::geneve`]). As
+    /// Geneve can only be identified via the port this is disabled by
+    /// default (`None`). If the same port is configured for VXLAN or GTP-U,
+    /// VXLAN & GTP-U decoding take precedence.
+    pub geneve_port: Option<u16>,
+
+    /// Continue decoding IPv4 packets whose `total_len` field indicates more
+    /// data than is present in the slice (e.g. captures truncated by a snaplen).
+    ///
+    /// If set to `true` the available data is used as the IPv4 payload
+    /// instead of returning a length error. The fallback can be detected by
+    /// checking if [`crate::IpPayloadSlice::len_source`] is set to
+    /// [`crate::LenSource::Slice`] (instead of
+    /// [`crate::LenSource::Ipv4HeaderTotalLen`]). Length errors in the
+    /// following layers then also report [`crate::LenSource::Slice`] as
+    /// their length source. Disabled by default (strict decoding).
+    pub lenient_ipv4_total_len: bool,
+
+    /// Verify that the UDP header `length` field matches the payload length
+    /// determined by the IP header.
+    ///
+    /// By default a UDP `length` smaller than the IP payload is accepted and
+    /// the data after the UDP payload is ignored. If set to `true` a
+    /// [`crate::err::LenError`] with the layer [`crate::err::Layer::UdpPayload`]
+    /// is returned instead (`required_len` set to the UDP `length` & `len` to
+    /// the IP payload length). A UDP `length` of zero (used by jumbograms) and
+    /// payloads whose length is only determined by the slice are not checked.
+    /// Disabled by default.
+    pub strict_udp_len: bool,
+
+    /// Decode TCP headers with an invalid "data offset" instead of
+    /// returning an error.
+    ///
+    /// If set to `true` TCP headers are decoded via
+    /// [`crate::TcpSlice::from_slice_lax`]: a "data offset" smaller than 5
+    /// or pointing behind the end of the available data is clamped to the
+    /// valid range. The anomaly can be detected via
+    /// [`crate::TcpSlice::data_offset_error`]. Disabled by default (strict
+    /// decoding).
+    pub lenient_tcp_data_offset: bool,
+
+    /// Maximum number of IPv6 extension headers that are decoded in a
+    /// single IPv6 header chain.
+    ///
+    /// All extension headers count towards the limit (including repeated
+    /// routing, fragment or destination options headers). If the limit is
+    /// exceeded an [`crate::err::ipv6_exts::HeaderError::TooManyHeaders`]
+    /// error is returned. This bounds the work spent on maliciously long
+    /// extension header chains. Defaults to
+    /// [`crate::Ipv6ExtensionsIter::MAX_HEADERS`] (8), set it to
+    /// `usize::MAX` to decode chains of any length.
+    pub max_extension_headers: usize,
+
+    /// Ethernet II frames end with a 4 byte FCS (frame check sequence).
+    ///
+    /// Some capture sources include the FCS at the end of the frame. If set
+    /// to `true` the last 4 bytes of frames decoded via
+    /// [`crate::SlicedPacket::from_ethernet_with_options`] are excluded
+    /// from the payload & made available via [`crate::Ethernet2Slice::fcs`].
+    /// Disabled by default.
+    pub has_fcs: bool,
+
+    /// Verify the FCS of Ethernet II frames (only used if
+    /// [`ParseOptions::has_fcs`] is set).
+    ///
+    /// If set to `true` the CRC32 of the frame is calculated (see
+    /// [`crate::checksum::crc32_ethernet`]) and a
+    /// [`crate::err::packet::SliceError::Fcs`] error is returned if it
+    /// does not match the FCS. Disabled by default.
+    pub validate_fcs: bool,
+
+    /// Stop decoding after the IP header & IP extension headers.
+    ///
+    /// If set to `true` the transport layer is not decoded and the
+    /// transport header remains part of the IP payload (see
+    /// [`crate::SlicedPacket::ip_payload`]). This avoids the work of
+    /// decoding e.g. TCP options if only the IP layer is needed (see
+    /// [`crate::SlicedPacket::from_ethernet_ip_only`]). Disabled by default.
+    pub stop_after_ip: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            vxlan_port: None,
+            gtpu_port: None,
+            geneve_port: None,
+            lenient_ipv4_total_len: false,
+            strict_udp_len: false,
+            lenient_tcp_data_offset: false,
+            max_extension_headers: crate::Ipv6ExtensionsIter::MAX_HEADERS,
+            has_fcs: false,
+            validate_fcs: false,
+            stop_after_ip: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn default() {
+        let options: ParseOptions = Default::default();
+        assert_eq!(options.vxlan_port, None);
+        assert_eq!(options.gtpu_port, None);
+        assert_eq!(options.geneve_port, None);
+        assert_eq!(options.lenient_ipv4_total_len, false);
+        assert_eq!(options.strict_udp_len, false);
+        assert_eq!(options.lenient_tcp_data_offset, false);
+        assert_eq!(options.max_extension_headers, 8);
+        assert_eq!(options.has_fcs, false);
+        assert_eq!(options.validate_fcs, false);
+        assert_eq!(options.stop_after_ip, false);
+    }
+
+    #[test]
+    fn clone_eq_debug() {
+        let options = ParseOptions {
+            vxlan_port: Some(4789),
+            gtpu_port: Some(2152),
+            geneve_port: Some(6081),
+            lenient_ipv4_total_len: true,
+            strict_udp_len: true,
+            lenient_tcp_data_offset: true,
+            max_extension_headers: 4,
+            has_fcs: true,
+            validate_fcs: true,
+            stop_after_ip: true,
+        };
+        assert_eq!(options, options.clone());
+        assert_eq!(
+            format!("{:?}", options),
+            "ParseOptions { vxlan_port: Some(4789), gtpu_port: Some(2152), geneve_port: Some(6081), lenient_ipv4_total_len: true, strict_udp_len: true, lenient_tcp_data_offset: true, max_extension_headers: 4, has_fcs: true, validate_fcs: true, stop_after_ip: true }"
+        );
+    }
+}
@@ -19,6 +19,8 @@ pub enum PayloadSlice<'a> {
     /// Payload part of an ICMP V4 message. Check [`crate::Icmpv6Type`]
     /// for a description what will be part of the payload.
     Icmpv6(&'a [u8]),
+    /// DCCP payload (application data).
+    Dccp(&'a [u8]),
 }
 
 impl<'a> PayloadSlice<'a> {
@@ -30,6 +32,7 @@ impl<'a> PayloadSlice<'a> {
             PayloadSlice::Tcp(s) => s,
             PayloadSlice::Icmpv4(s) => s,
             PayloadSlice::Icmpv6(s) => s,
+            PayloadSlice::Dccp(s) => s,
         }
     }
 }
@@ -99,5 +102,6 @@ mod test {
         assert_eq!(Tcp(&payload).slice(), &payload);
         assert_eq!(Icmpv4(&payload).slice(), &payload);
         assert_eq!(Icmpv6(&payload).slice(), &payload);
+        assert_eq!(Dccp(&payload).slice(), &payload);
     }
 }
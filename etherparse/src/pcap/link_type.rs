@@ -0,0 +1,131 @@
+/// Link layer type (`LINKTYPE_*` value) stored in the global header of a
+/// pcap file, identifying the first header in each record.
+///
+/// You can access the underlying `u32` value by using `.0` and any `u32`
+/// can be converted to a `LinkType`:
+///
+/// ```
+/// use etherparse::pcap::LinkType;
+///
+/// assert_eq!(LinkType::ETHERNET.0, 1);
+/// assert_eq!(LinkType::ETHERNET, LinkType(1));
+///
+/// // convert to LinkType using the from & into trait
+/// let link_type: LinkType = 1.into();
+/// assert_eq!(LinkType::ETHERNET, link_type);
+///
+/// // convert to u32 using the from & into trait
+/// let num: u32 = LinkType::ETHERNET.into();
+/// assert_eq!(1, num);
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+pub struct LinkType(pub u32);
+
+impl LinkType {
+    /// BSD loopback encapsulation (4 byte protocol family in host byte order).
+    pub const NULL: LinkType = Self(0);
+    /// Ethernet II (or IEEE 802.3) frames.
+    pub const ETHERNET: LinkType = Self(1);
+    /// Raw IPv4 or IPv6 packets without a link layer header.
+    pub const RAW: LinkType = Self(101);
+    /// OpenBSD loopback encapsulation (4 byte protocol family in network byte order).
+    pub const LOOP: LinkType = Self(108);
+    /// Linux cooked capture v1 (see [`crate::LinuxSllHeader`]).
+    pub const LINUX_SLL: LinkType = Self(113);
+    /// Raw IPv4 packets without a link layer header.
+    pub const IPV4: LinkType = Self(228);
+    /// Raw IPv6 packets without a link layer header.
+    pub const IPV6: LinkType = Self(229);
+    /// Linux cooked capture v2 (see [`crate::LinuxSll2Header`]).
+    pub const LINUX_SLL2: LinkType = Self(276);
+}
+
+impl From<u32> for LinkType {
+    #[inline]
+    fn from(val: u32) -> Self {
+        LinkType(val)
+    }
+}
+
+impl From<LinkType> for u32 {
+    #[inline]
+    fn from(val: LinkType) -> Self {
+        val.0
+    }
+}
+
+impl core::fmt::Debug for LinkType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::NULL => write!(f, "{} (NULL)", self.0),
+            Self::ETHERNET => write!(f, "{} (ETHERNET)", self.0),
+            Self::RAW => write!(f, "{} (RAW)", self.0),
+            Self::LOOP => write!(f, "{} (LOOP)", self.0),
+            Self::LINUX_SLL => write!(f, "{} (LINUX_SLL)", self.0),
+            Self::IPV4 => write!(f, "{} (IPV4)", self.0),
+            Self::IPV6 => write!(f, "{} (IPV6)", self.0),
+            Self::LINUX_SLL2 => write!(f, "{} (LINUX_SLL2)", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for LinkType {
+    fn format(&self, f: defmt::Formatter) {
+        match *self {
+            Self::NULL => defmt::write!(f, "{=u32} (NULL)", self.0),
+            Self::ETHERNET => defmt::write!(f, "{=u32} (ETHERNET)", self.0),
+            Self::RAW => defmt::write!(f, "{=u32} (RAW)", self.0),
+            Self::LOOP => defmt::write!(f, "{=u32} (LOOP)", self.0),
+            Self::LINUX_SLL => defmt::write!(f, "{=u32} (LINUX_SLL)", self.0),
+            Self::IPV4 => defmt::write!(f, "{=u32} (IPV4)", self.0),
+            Self::IPV6 => defmt::write!(f, "{=u32} (IPV6)", self.0),
+            Self::LINUX_SLL2 => defmt::write!(f, "{=u32} (LINUX_SLL2)", self.0),
+            _ => defmt::write!(f, "{=u32}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LinkType;
+    use alloc::format;
+
+    #[test]
+    fn to_u32() {
+        assert_eq!(1, u32::from(LinkType::ETHERNET));
+        assert_eq!(276, u32::from(LinkType::LINUX_SLL2));
+    }
+
+    #[test]
+    fn from_u32() {
+        assert_eq!(LinkType::from(113), LinkType::LINUX_SLL);
+        assert_eq!(LinkType::from(1234), LinkType(1234));
+    }
+
+    #[test]
+    fn dbg() {
+        let pairs = &[
+            (LinkType::NULL, "0 (NULL)"),
+            (LinkType::ETHERNET, "1 (ETHERNET)"),
+            (LinkType::RAW, "101 (RAW)"),
+            (LinkType::LOOP, "108 (LOOP)"),
+            (LinkType::LINUX_SLL, "113 (LINUX_SLL)"),
+            (LinkType::IPV4, "228 (IPV4)"),
+            (LinkType::IPV6, "229 (IPV6)"),
+            (LinkType::LINUX_SLL2, "276 (LINUX_SLL2)"),
+            (LinkType(2), "2"),
+        ];
+
+        for (input, expected) in pairs {
+            assert_eq!(expected, &format!("{:?}", input));
+        }
+    }
+
+    #[test]
+    fn default() {
+        let value: LinkType = Default::default();
+        assert_eq!(LinkType(0), value);
+    }
+}
@@ -0,0 +1,11 @@
+mod link_type;
+pub use link_type::*;
+
+mod pcap_header;
+pub use pcap_header::*;
+
+mod pcap_reader;
+pub use pcap_reader::*;
+
+mod pcap_record;
+pub use pcap_record::*;
@@ -0,0 +1,226 @@
+use super::LinkType;
+use crate::{
+    err::{pcap::*, Layer, LenError},
+    LenSource,
+};
+
+/// Global header at the start of a classic pcap file.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PcapHeader {
+    /// True if all the fields in the file are stored in big endian
+    /// byte order (determined via the magic number).
+    pub big_endian: bool,
+    /// True if the sub-second part of the record timestamps contain
+    /// nanoseconds instead of microseconds (determined via the magic number).
+    pub nanosecond_timestamps: bool,
+    /// Major version of the file format (usually 2).
+    pub version_major: u16,
+    /// Minor version of the file format (usually 4).
+    pub version_minor: u16,
+    /// Time zone offset in seconds (in practice always 0).
+    pub this_zone: i32,
+    /// Accuracy of the timestamps (in practice always 0).
+    pub sigfigs: u32,
+    /// Maximum number of bytes captured per packet.
+    pub snaplen: u32,
+    /// Link layer type of the records in the file.
+    pub link_type: LinkType,
+}
+
+impl PcapHeader {
+    /// Serialized size of the pcap global header in bytes/octets.
+    pub const LEN: usize = 24;
+
+    /// Magic number of a pcap file with microsecond timestamps.
+    pub const MAGIC_MICROSECONDS: u32 = 0xa1b2_c3d4;
+
+    /// Magic number of a pcap file with nanosecond timestamps.
+    pub const MAGIC_NANOSECONDS: u32 = 0xa1b2_3c4d;
+
+    /// Read a [`PcapHeader`] from a slice and return the header & unused
+    /// parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(PcapHeader, &[u8]), HeaderSliceError> {
+        if slice.len() < PcapHeader::LEN {
+            return Err(HeaderSliceError::Len(LenError {
+                required_len: PcapHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::PcapHeader,
+                layer_start_offset: 0,
+            }));
+        }
+
+        let magic = [slice[0], slice[1], slice[2], slice[3]];
+        let (big_endian, nanosecond_timestamps) = match u32::from_be_bytes(magic) {
+            PcapHeader::MAGIC_MICROSECONDS => (true, false),
+            PcapHeader::MAGIC_NANOSECONDS => (true, true),
+            _ => match u32::from_le_bytes(magic) {
+                PcapHeader::MAGIC_MICROSECONDS => (false, false),
+                PcapHeader::MAGIC_NANOSECONDS => (false, true),
+                _ => {
+                    return Err(HeaderSliceError::Content(HeaderError::UnknownMagicNumber {
+                        magic_number: u32::from_be_bytes(magic),
+                    }))
+                }
+            },
+        };
+
+        let read_u16 = |offset: usize| {
+            let bytes = [slice[offset], slice[offset + 1]];
+            if big_endian {
+                u16::from_be_bytes(bytes)
+            } else {
+                u16::from_le_bytes(bytes)
+            }
+        };
+        let read_u32 = |offset: usize| {
+            let bytes = [
+                slice[offset],
+                slice[offset + 1],
+                slice[offset + 2],
+                slice[offset + 3],
+            ];
+            if big_endian {
+                u32::from_be_bytes(bytes)
+            } else {
+                u32::from_le_bytes(bytes)
+            }
+        };
+
+        Ok((
+            PcapHeader {
+                big_endian,
+                nanosecond_timestamps,
+                version_major: read_u16(4),
+                version_minor: read_u16(6),
+                this_zone: read_u32(8) as i32,
+                sigfigs: read_u32(12),
+                snaplen: read_u32(16),
+                link_type: LinkType(read_u32(20)),
+            },
+            &slice[PcapHeader::LEN..],
+        ))
+    }
+
+    /// Returns the serialized form of the header as a statically
+    /// sized byte array (in the byte order given by `big_endian`).
+    pub fn to_bytes(&self) -> [u8; 24] {
+        let magic = if self.nanosecond_timestamps {
+            PcapHeader::MAGIC_NANOSECONDS
+        } else {
+            PcapHeader::MAGIC_MICROSECONDS
+        };
+        let mut result = [0u8; PcapHeader::LEN];
+        if self.big_endian {
+            result[0..4].copy_from_slice(&magic.to_be_bytes());
+            result[4..6].copy_from_slice(&self.version_major.to_be_bytes());
+            result[6..8].copy_from_slice(&self.version_minor.to_be_bytes());
+            result[8..12].copy_from_slice(&self.this_zone.to_be_bytes());
+            result[12..16].copy_from_slice(&self.sigfigs.to_be_bytes());
+            result[16..20].copy_from_slice(&self.snaplen.to_be_bytes());
+            result[20..24].copy_from_slice(&self.link_type.0.to_be_bytes());
+        } else {
+            result[0..4].copy_from_slice(&magic.to_le_bytes());
+            result[4..6].copy_from_slice(&self.version_major.to_le_bytes());
+            result[6..8].copy_from_slice(&self.version_minor.to_le_bytes());
+            result[8..12].copy_from_slice(&self.this_zone.to_le_bytes());
+            result[12..16].copy_from_slice(&self.sigfigs.to_le_bytes());
+            result[16..20].copy_from_slice(&self.snaplen.to_le_bytes());
+            result[20..24].copy_from_slice(&self.link_type.0.to_le_bytes());
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec::Vec;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice_to_bytes(
+            big_endian in any::<bool>(),
+            nanosecond_timestamps in any::<bool>(),
+            version_major in any::<u16>(),
+            version_minor in any::<u16>(),
+            this_zone in any::<i32>(),
+            sigfigs in any::<u32>(),
+            snaplen in any::<u32>(),
+            link_type in any::<u32>(),
+            rest in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let header = PcapHeader {
+                big_endian,
+                nanosecond_timestamps,
+                version_major,
+                version_minor,
+                this_zone,
+                sigfigs,
+                snaplen,
+                link_type: LinkType(link_type),
+            };
+            let mut data = Vec::new();
+            data.extend_from_slice(&header.to_bytes());
+            data.extend_from_slice(&rest);
+
+            // ok
+            {
+                let (actual, actual_rest) = PcapHeader::from_slice(&data).unwrap();
+                assert_eq!(actual, header);
+                assert_eq!(actual_rest, &rest[..]);
+            }
+
+            // len error
+            for len in 0..PcapHeader::LEN {
+                assert_eq!(
+                    PcapHeader::from_slice(&data[..len]).unwrap_err(),
+                    HeaderSliceError::Len(LenError {
+                        required_len: PcapHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: Layer::PcapHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_known_bytes() {
+        // little endian, microseconds, ethernet
+        let data = [
+            0xd4, 0xc3, 0xb2, 0xa1, 2, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0, 0, 1, 0, 0,
+            0,
+        ];
+        assert_eq!(
+            PcapHeader::from_slice(&data).unwrap().0,
+            PcapHeader {
+                big_endian: false,
+                nanosecond_timestamps: false,
+                version_major: 2,
+                version_minor: 4,
+                this_zone: 0,
+                sigfigs: 0,
+                snaplen: 0xffff,
+                link_type: LinkType::ETHERNET,
+            }
+        );
+    }
+
+    #[test]
+    fn from_slice_unknown_magic() {
+        // pcapng section header block
+        let mut data = [0u8; 24];
+        data[..4].copy_from_slice(&[0x0a, 0x0d, 0x0d, 0x0a]);
+        assert_eq!(
+            PcapHeader::from_slice(&data).unwrap_err(),
+            HeaderSliceError::Content(HeaderError::UnknownMagicNumber {
+                magic_number: 0x0a0d0d0a
+            })
+        );
+    }
+}
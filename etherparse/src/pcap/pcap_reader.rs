@@ -0,0 +1,369 @@
+use super::*;
+use crate::{
+    err::{pcap::HeaderSliceError, Layer, LenError},
+    LenSource,
+};
+
+/// Iterator over the records of a classic pcap file stored in memory.
+///
+/// The global header is decoded when the reader is created, the records are
+/// decoded lazily while iterating. Each record contains the captured bytes
+/// of a packet, starting with the header identified by the link type of the
+/// file ([`PcapReader::link_type`]).
+///
+/// pcapng files are not supported.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{pcap::{LinkType, PcapReader}, SlicedPacket};
+///
+/// # let file = {
+/// #     use etherparse::{pcap::PcapHeader, PacketBuilder};
+/// #     let mut file = Vec::new();
+/// #     file.extend_from_slice(&PcapHeader {
+/// #         version_major: 2,
+/// #         version_minor: 4,
+/// #         snaplen: 0xffff,
+/// #         link_type: LinkType::ETHERNET,
+/// #         ..Default::default()
+/// #     }.to_bytes());
+/// #     let builder = PacketBuilder::ethernet2([1; 6], [2; 6])
+/// #         .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+/// #         .udp(21, 1234);
+/// #     let mut packet = Vec::new();
+/// #     builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+/// #     file.extend_from_slice(&1u32.to_le_bytes());
+/// #     file.extend_from_slice(&2u32.to_le_bytes());
+/// #     file.extend_from_slice(&(packet.len() as u32).to_le_bytes());
+/// #     file.extend_from_slice(&(packet.len() as u32).to_le_bytes());
+/// #     file.extend_from_slice(&packet);
+/// #     file
+/// # };
+/// let reader = PcapReader::new(&file).unwrap();
+/// let link_type = reader.link_type();
+///
+/// for record in reader {
+///     let record = record.unwrap();
+///     println!("{:?} ({} bytes)", record.timestamp, record.original_len);
+///
+///     let sliced = match link_type {
+///         LinkType::ETHERNET => SlicedPacket::from_ethernet(record.data),
+///         LinkType::LINUX_SLL => SlicedPacket::from_linux_sll(record.data),
+///         LinkType::LINUX_SLL2 => SlicedPacket::from_linux_sll2(record.data),
+///         LinkType::RAW | LinkType::IPV4 | LinkType::IPV6 => SlicedPacket::from_ip(record.data),
+///         _ => continue,
+///     };
+///     println!("{:?}", sliced);
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PcapReader<'a> {
+    header: PcapHeader,
+    rest: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> PcapReader<'a> {
+    /// Decodes the global header of the given pcap file and returns a reader
+    /// iterating over the records of the file.
+    pub fn new(data: &'a [u8]) -> Result<PcapReader<'a>, HeaderSliceError> {
+        let (header, rest) = PcapHeader::from_slice(data)?;
+        Ok(PcapReader {
+            header,
+            rest,
+            offset: PcapHeader::LEN,
+        })
+    }
+
+    /// Global header of the pcap file.
+    #[inline]
+    pub fn header(&self) -> &PcapHeader {
+        &self.header
+    }
+
+    /// Link layer type of the records (shortcut for `header().link_type`).
+    #[inline]
+    pub fn link_type(&self) -> LinkType {
+        self.header.link_type
+    }
+
+    /// Offset of the next record in the file.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Remaining (not yet decoded) part of the file.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+
+    fn read_u32(&self, offset: usize) -> u32 {
+        let bytes = [
+            self.rest[offset],
+            self.rest[offset + 1],
+            self.rest[offset + 2],
+            self.rest[offset + 3],
+        ];
+        if self.header.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        }
+    }
+}
+
+impl<'a> Iterator for PcapReader<'a> {
+    type Item = Result<PcapRecord<'a>, LenError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        if self.rest.len() < PcapRecord::HEADER_LEN {
+            let err = LenError {
+                required_len: PcapRecord::HEADER_LEN,
+                len: self.rest.len(),
+                len_source: LenSource::Slice,
+                layer: Layer::PcapRecord,
+                layer_start_offset: self.offset,
+            };
+            self.rest = &[];
+            return Some(Err(err));
+        }
+
+        let ts_sec = self.read_u32(0);
+        let ts_frac = self.read_u32(4);
+        let captured_len = self.read_u32(8);
+        let original_len = self.read_u32(12);
+
+        // the captured length might not be representable as an usize
+        // on 16 & 32 bit systems (reported as usize::MAX required len)
+        let record_len = usize::try_from(captured_len)
+            .ok()
+            .and_then(|v| v.checked_add(PcapRecord::HEADER_LEN));
+        let record_len = match record_len {
+            Some(record_len) if record_len <= self.rest.len() => record_len,
+            _ => {
+                let err = LenError {
+                    required_len: record_len.unwrap_or(usize::MAX),
+                    len: self.rest.len(),
+                    len_source: LenSource::Slice,
+                    layer: Layer::PcapRecord,
+                    layer_start_offset: self.offset,
+                };
+                self.rest = &[];
+                return Some(Err(err));
+            }
+        };
+
+        let timestamp = if self.header.nanosecond_timestamps {
+            core::time::Duration::new(u64::from(ts_sec), ts_frac)
+        } else {
+            core::time::Duration::new(u64::from(ts_sec), 0)
+                + core::time::Duration::from_micros(u64::from(ts_frac))
+        };
+        let data = &self.rest[PcapRecord::HEADER_LEN..record_len];
+        self.rest = &self.rest[record_len..];
+        self.offset += record_len;
+
+        Some(Ok(PcapRecord {
+            timestamp,
+            captured_len,
+            original_len,
+            data,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::pcap::HeaderError;
+    use alloc::vec::Vec;
+    use core::time::Duration;
+
+    fn record(
+        big_endian: bool,
+        ts_sec: u32,
+        ts_frac: u32,
+        original_len: u32,
+        data: &[u8],
+    ) -> Vec<u8> {
+        let mut result = Vec::new();
+        for value in [ts_sec, ts_frac, data.len() as u32, original_len] {
+            if big_endian {
+                result.extend_from_slice(&value.to_be_bytes());
+            } else {
+                result.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        result.extend_from_slice(data);
+        result
+    }
+
+    #[test]
+    fn new() {
+        // ok
+        {
+            let header = PcapHeader {
+                big_endian: true,
+                snaplen: 1234,
+                link_type: LinkType::RAW,
+                ..Default::default()
+            };
+            let data = header.to_bytes();
+            let reader = PcapReader::new(&data).unwrap();
+            assert_eq!(reader.header(), &header);
+            assert_eq!(reader.link_type(), LinkType::RAW);
+            assert_eq!(reader.offset(), PcapHeader::LEN);
+            assert!(reader.rest().is_empty());
+        }
+        // error
+        assert_eq!(
+            PcapReader::new(&[0u8; 24]).unwrap_err(),
+            HeaderSliceError::Content(HeaderError::UnknownMagicNumber { magic_number: 0 })
+        );
+    }
+
+    #[test]
+    fn iter() {
+        for big_endian in [false, true] {
+            for nanosecond_timestamps in [false, true] {
+                let header = PcapHeader {
+                    big_endian,
+                    nanosecond_timestamps,
+                    version_major: 2,
+                    version_minor: 4,
+                    snaplen: 0xffff,
+                    link_type: LinkType::ETHERNET,
+                    ..Default::default()
+                };
+                let mut data = Vec::new();
+                data.extend_from_slice(&header.to_bytes());
+                data.extend_from_slice(&record(big_endian, 1, 2, 3, &[1, 2, 3]));
+                data.extend_from_slice(&record(big_endian, 4, 5, 10, &[4, 5]));
+                data.extend_from_slice(&record(big_endian, 6, 7, 0, &[]));
+
+                let frac = |value: u32| {
+                    if nanosecond_timestamps {
+                        value
+                    } else {
+                        value * 1000
+                    }
+                };
+
+                let mut reader = PcapReader::new(&data).unwrap();
+                assert_eq!(
+                    reader.next(),
+                    Some(Ok(PcapRecord {
+                        timestamp: Duration::new(1, frac(2)),
+                        captured_len: 3,
+                        original_len: 3,
+                        data: &[1, 2, 3],
+                    }))
+                );
+                assert_eq!(reader.offset(), PcapHeader::LEN + 16 + 3);
+                assert_eq!(
+                    reader.next(),
+                    Some(Ok(PcapRecord {
+                        timestamp: Duration::new(4, frac(5)),
+                        captured_len: 2,
+                        original_len: 10,
+                        data: &[4, 5],
+                    }))
+                );
+                assert_eq!(
+                    reader.next(),
+                    Some(Ok(PcapRecord {
+                        timestamp: Duration::new(6, frac(7)),
+                        captured_len: 0,
+                        original_len: 0,
+                        data: &[],
+                    }))
+                );
+                assert_eq!(reader.next(), None);
+            }
+        }
+    }
+
+    #[test]
+    fn iter_microsecond_overflow() {
+        // out of range microsecond values are carried over to the seconds
+        let mut data = Vec::new();
+        data.extend_from_slice(&PcapHeader::default().to_bytes());
+        data.extend_from_slice(&record(false, 1, 1_500_000, 0, &[]));
+        let mut reader = PcapReader::new(&data).unwrap();
+        assert_eq!(
+            reader.next().unwrap().unwrap().timestamp,
+            Duration::new(2, 500_000_000)
+        );
+    }
+
+    #[test]
+    fn iter_len_errors() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&PcapHeader::default().to_bytes());
+        data.extend_from_slice(&record(false, 1, 2, 4, &[1, 2, 3, 4]));
+
+        // record header too short
+        for len in 1..PcapRecord::HEADER_LEN {
+            let mut reader = PcapReader::new(&data[..PcapHeader::LEN + len]).unwrap();
+            assert_eq!(
+                reader.next(),
+                Some(Err(LenError {
+                    required_len: PcapRecord::HEADER_LEN,
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: Layer::PcapRecord,
+                    layer_start_offset: PcapHeader::LEN,
+                }))
+            );
+            assert_eq!(reader.next(), None);
+        }
+
+        // record data too short
+        for len in PcapRecord::HEADER_LEN..PcapRecord::HEADER_LEN + 4 {
+            let mut reader = PcapReader::new(&data[..PcapHeader::LEN + len]).unwrap();
+            assert_eq!(
+                reader.next(),
+                Some(Err(LenError {
+                    required_len: PcapRecord::HEADER_LEN + 4,
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: Layer::PcapRecord,
+                    layer_start_offset: PcapHeader::LEN,
+                }))
+            );
+            assert_eq!(reader.next(), None);
+        }
+
+        // maximum captured length (not representable on 16 & 32 bit systems)
+        {
+            let mut data = Vec::new();
+            data.extend_from_slice(&PcapHeader::default().to_bytes());
+            data.extend_from_slice(&record(false, 1, 2, 4, &[1, 2, 3, 4]));
+            data[PcapHeader::LEN + 8..PcapHeader::LEN + 12]
+                .copy_from_slice(&u32::MAX.to_le_bytes());
+
+            let mut reader = PcapReader::new(&data).unwrap();
+            assert_eq!(
+                reader.next(),
+                Some(Err(LenError {
+                    required_len: usize::try_from(
+                        u64::from(u32::MAX) + PcapRecord::HEADER_LEN as u64
+                    )
+                    .unwrap_or(usize::MAX),
+                    len: PcapRecord::HEADER_LEN + 4,
+                    len_source: LenSource::Slice,
+                    layer: Layer::PcapRecord,
+                    layer_start_offset: PcapHeader::LEN,
+                }))
+            );
+            assert_eq!(reader.next(), None);
+        }
+    }
+}
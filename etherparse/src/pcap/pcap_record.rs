@@ -0,0 +1,65 @@
+/// Record (captured packet) in a classic pcap file.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PcapRecord<'a> {
+    /// Time the packet was captured (relative to the UNIX epoch).
+    pub timestamp: core::time::Duration,
+    /// Number of bytes of the packet present in the file (length of `data`).
+    pub captured_len: u32,
+    /// Length of the packet when it was captured (can be bigger than
+    /// `captured_len` if the packet was truncated to the snaplen).
+    pub original_len: u32,
+    /// Captured bytes of the packet.
+    pub data: &'a [u8],
+}
+
+impl PcapRecord<'_> {
+    /// Serialized size of the header in front of each record in bytes/octets.
+    pub const HEADER_LEN: usize = 16;
+
+    /// Returns true if the packet was truncated when it was captured
+    /// (`captured_len` smaller than `original_len`).
+    #[inline]
+    pub fn is_truncated(&self) -> bool {
+        self.captured_len < self.original_len
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let r = PcapRecord {
+            timestamp: core::time::Duration::new(1, 2),
+            captured_len: 3,
+            original_len: 4,
+            data: &[1, 2, 3],
+        };
+        assert_eq!(r, r.clone());
+        assert_eq!(
+            format!(
+                "PcapRecord {{ timestamp: {:?}, captured_len: 3, original_len: 4, data: [1, 2, 3] }}",
+                r.timestamp
+            ),
+            format!("{:?}", r)
+        );
+    }
+
+    #[test]
+    fn is_truncated() {
+        let r = PcapRecord {
+            timestamp: core::time::Duration::new(0, 0),
+            captured_len: 3,
+            original_len: 3,
+            data: &[1, 2, 3],
+        };
+        assert!(!r.is_truncated());
+        assert!(PcapRecord {
+            original_len: 4,
+            ..r
+        }
+        .is_truncated());
+    }
+}
@@ -0,0 +1,290 @@
+use super::OverlapPolicy;
+use crate::err::reassembly::FragmentError;
+use core::ops::Range;
+use std::vec::Vec;
+
+/// Keeps track of the received payload sections of a fragmented packet
+/// and the data contained in them.
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub(crate) struct FragmentSections {
+    /// Reassembled data (sections that were not yet received are zeroed).
+    data: Vec<u8>,
+    /// Sorted & merged (no two ranges are touching or overlapping)
+    /// ranges of the already received data.
+    sections: Vec<Range<usize>>,
+    /// End of the payload (set as soon as the last fragment is received).
+    end: Option<usize>,
+}
+
+impl FragmentSections {
+    /// Creates an empty sections tracker.
+    pub fn new() -> FragmentSections {
+        FragmentSections::default()
+    }
+
+    /// Adds the payload of a fragment at the given offset (in bytes).
+    ///
+    /// Data overlapping already received sections is handled based on
+    /// the given `overlap_policy`. On error the state of the sections
+    /// is not modified.
+    pub fn add(
+        &mut self,
+        offset: usize,
+        payload: &[u8],
+        more_fragments: bool,
+        max_len: usize,
+        overlap_policy: OverlapPolicy,
+    ) -> Result<(), FragmentError> {
+        use FragmentError::*;
+
+        let len = payload.len();
+        let end = offset + len;
+
+        // non final fragments are required to have a multiple of 8 octets
+        if more_fragments && 0 != len % 8 {
+            return Err(UnalignedLen { offset, len });
+        }
+
+        if end > max_len {
+            return Err(MaxLenExceeded {
+                max_len,
+                required_len: end,
+            });
+        }
+
+        // verify the end of the packet stays consistent
+        if let Some(previous_end) = self.end {
+            if (more_fragments && end > previous_end) || (!more_fragments && end != previous_end) {
+                return Err(ConflictingEnd { previous_end, end });
+            }
+        } else if !more_fragments {
+            if let Some(last) = self.sections.last() {
+                if last.end > end {
+                    return Err(ConflictingEnd {
+                        previous_end: last.end,
+                        end,
+                    });
+                }
+            }
+        }
+
+        // range of the sections overlapping or touching the new data
+        let first = self.sections.partition_point(|s| s.end < offset);
+        let last = self.sections.partition_point(|s| s.start <= end);
+        let overlapping = self.sections[first..last]
+            .iter()
+            .any(|s| s.end > offset && s.start < end);
+        if overlapping && OverlapPolicy::Reject == overlap_policy {
+            return Err(Overlapping { offset, len });
+        }
+
+        // copy data
+        if self.data.len() < end {
+            self.data.resize(end, 0);
+        }
+        if overlapping && OverlapPolicy::FirstWins == overlap_policy {
+            // only fill the gaps between the already received sections
+            let mut gap_start = offset;
+            for s in self.sections[first..last].iter() {
+                if s.start > gap_start {
+                    self.data[gap_start..s.start]
+                        .copy_from_slice(&payload[gap_start - offset..s.start - offset]);
+                }
+                gap_start = gap_start.max(s.end);
+            }
+            if gap_start < end {
+                self.data[gap_start..end].copy_from_slice(&payload[gap_start - offset..]);
+            }
+        } else {
+            self.data[offset..end].copy_from_slice(payload);
+        }
+
+        // insert & merge the new section
+        if first < last {
+            let start = offset.min(self.sections[first].start);
+            let merged_end = end.max(self.sections[last - 1].end);
+            self.sections.drain(first + 1..last);
+            self.sections[first] = start..merged_end;
+        } else if len > 0 {
+            self.sections.insert(first, offset..end);
+        }
+
+        if !more_fragments {
+            self.end = Some(end);
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if the last fragment and all data before it was received.
+    pub fn is_complete(&self) -> bool {
+        match self.end {
+            None => false,
+            Some(0) => true,
+            Some(end) => 1 == self.sections.len() && self.sections[0] == (0..end),
+        }
+    }
+
+    /// Length of the data currently buffered (including not yet
+    /// received sections before the last received byte).
+    pub fn data_len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the reassembled data.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::reassembly::FragmentError::*;
+    use alloc::vec;
+    use OverlapPolicy::*;
+
+    #[test]
+    fn add_in_order() {
+        let mut s = FragmentSections::new();
+        s.add(0, &[1; 8], true, 1000, Reject).unwrap();
+        assert_eq!(false, s.is_complete());
+        s.add(8, &[2; 8], true, 1000, Reject).unwrap();
+        assert_eq!(false, s.is_complete());
+        s.add(16, &[3; 3], false, 1000, Reject).unwrap();
+        assert!(s.is_complete());
+        assert_eq!(
+            s.into_data(),
+            vec![1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3]
+        );
+    }
+
+    #[test]
+    fn add_out_of_order() {
+        let mut s = FragmentSections::new();
+        s.add(16, &[3; 3], false, 1000, Reject).unwrap();
+        assert_eq!(false, s.is_complete());
+        s.add(0, &[1; 8], true, 1000, Reject).unwrap();
+        assert_eq!(false, s.is_complete());
+        s.add(8, &[2; 8], true, 1000, Reject).unwrap();
+        assert!(s.is_complete());
+        assert_eq!(
+            s.into_data(),
+            vec![1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3]
+        );
+    }
+
+    #[test]
+    fn add_empty_final() {
+        let mut s = FragmentSections::new();
+        s.add(0, &[], false, 1000, Reject).unwrap();
+        assert!(s.is_complete());
+        assert!(s.into_data().is_empty());
+    }
+
+    #[test]
+    fn add_errors() {
+        // unaligned
+        {
+            let mut s = FragmentSections::new();
+            assert_eq!(
+                s.add(0, &[0; 7], true, 1000, Reject),
+                Err(UnalignedLen { offset: 0, len: 7 })
+            );
+            // unaligned is fine in the last fragment
+            s.add(0, &[0; 7], false, 1000, Reject).unwrap();
+        }
+        // max len
+        {
+            let mut s = FragmentSections::new();
+            assert_eq!(
+                s.add(8, &[0; 8], true, 15, Reject),
+                Err(MaxLenExceeded {
+                    max_len: 15,
+                    required_len: 16
+                })
+            );
+            s.add(8, &[0; 8], true, 16, Reject).unwrap();
+        }
+        // overlapping
+        {
+            let mut s = FragmentSections::new();
+            s.add(8, &[0; 16], true, 1000, Reject).unwrap();
+            for (offset, len) in [(0, 16), (8, 8), (16, 16), (8, 16), (0, 32)] {
+                assert_eq!(
+                    s.add(offset, &vec![0; len], true, 1000, Reject),
+                    Err(Overlapping { offset, len })
+                );
+            }
+            s.add(0, &[0; 8], true, 1000, Reject).unwrap();
+            s.add(24, &[0; 8], true, 1000, Reject).unwrap();
+        }
+        // conflicting end
+        {
+            let mut s = FragmentSections::new();
+            s.add(16, &[0; 8], true, 1000, Reject).unwrap();
+            // last fragment ending before already received data
+            match s.add(0, &[0; 8], false, 1000, Reject) {
+                Err(ConflictingEnd { previous_end, end }) => {
+                    assert_eq!(24, previous_end);
+                    assert_eq!(8, end);
+                }
+                other => panic!("unexpected result {:?}", other),
+            }
+            s.add(24, &[0; 4], false, 1000, Reject).unwrap();
+            assert_eq!(
+                s.add(32, &[0; 8], true, 1000, Reject),
+                Err(ConflictingEnd {
+                    previous_end: 28,
+                    end: 40
+                })
+            );
+            assert_eq!(
+                s.add(32, &[0; 8], false, 1000, Reject),
+                Err(ConflictingEnd {
+                    previous_end: 28,
+                    end: 40
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn add_overlap_policies() {
+        // first wins
+        {
+            let mut s = FragmentSections::new();
+            s.add(8, &[1; 8], true, 1000, FirstWins).unwrap();
+            s.add(24, &[2; 8], true, 1000, FirstWins).unwrap();
+            s.add(0, &[3; 40], false, 1000, FirstWins).unwrap();
+            assert!(s.is_complete());
+            let mut expected = vec![3; 40];
+            expected[8..16].copy_from_slice(&[1; 8]);
+            expected[24..32].copy_from_slice(&[2; 8]);
+            assert_eq!(s.into_data(), expected);
+        }
+        // last wins
+        {
+            let mut s = FragmentSections::new();
+            s.add(8, &[1; 8], true, 1000, LastWins).unwrap();
+            s.add(24, &[2; 8], true, 1000, LastWins).unwrap();
+            s.add(0, &[3; 32], true, 1000, LastWins).unwrap();
+            s.add(32, &[4; 4], false, 1000, LastWins).unwrap();
+            assert!(s.is_complete());
+            let mut expected = vec![3; 36];
+            expected[32..].copy_from_slice(&[4; 4]);
+            assert_eq!(s.into_data(), expected);
+        }
+        // overlap with a single section
+        {
+            let mut s = FragmentSections::new();
+            s.add(0, &[1; 16], true, 1000, FirstWins).unwrap();
+            s.add(8, &[2; 16], false, 1000, FirstWins).unwrap();
+            assert!(s.is_complete());
+            assert_eq!(s.data_len(), 24);
+            let mut expected = vec![1; 24];
+            expected[16..].copy_from_slice(&[2; 8]);
+            assert_eq!(s.into_data(), expected);
+        }
+    }
+}
@@ -0,0 +1,497 @@
+use super::{FragmentSections, OverlapPolicy};
+use crate::{err::reassembly::FragmentError, *};
+use std::{collections::HashMap, vec::Vec};
+
+/// Values identifying the fragments belonging to the same
+/// original IPv4 packet.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Ipv4FragmentId {
+    /// IPv4 source address.
+    pub source: [u8; 4],
+    /// IPv4 destination address.
+    pub destination: [u8; 4],
+    /// Identification value present in the IPv4 header.
+    pub identification: u16,
+    /// IP number of the protocol contained in the payload.
+    pub protocol: IpNumber,
+}
+
+/// Buffer for reassembling the payloads of fragmented IPv4 packets.
+///
+/// Fragments are grouped by their [`Ipv4FragmentId`] (source, destination,
+/// identification & protocol) and placed based on the fragment offset &
+/// "more fragments" flag of the IPv4 header. As soon as all fragments of a
+/// packet have been added the reassembled payload is returned.
+///
+/// Overlapping fragments are handled based on the configured
+/// [`OverlapPolicy`] (by default they are rejected). Whenever an error
+/// is returned all the buffered data of the packet the fragment belongs
+/// to is discarded.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{
+///     ip_number::UDP, Ipv4Header, Ipv4HeaderSlice,
+///     reassembly::{Ipv4FragmentBuffer, OverlapPolicy},
+/// };
+///
+/// let mut buffer = Ipv4FragmentBuffer::default()
+///     .with_overlap_policy(OverlapPolicy::FirstWins)
+///     .with_max_buffered_len(1024 * 1024);
+///
+/// let mut header = Ipv4Header::new(8, 20, UDP, [192, 168, 1, 1], [192, 168, 1, 2]).unwrap();
+/// header.identification = 1234;
+/// header.dont_fragment = false;
+///
+/// // first fragment (offset is in 8 octet units)
+/// header.more_fragments = true;
+/// let bytes = header.to_bytes();
+/// let first = Ipv4HeaderSlice::from_slice(&bytes).unwrap();
+/// let result = buffer.add(0, &first, &[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+/// assert_eq!(None, result);
+///
+/// // last fragment
+/// header.more_fragments = false;
+/// header.fragment_offset = 1.try_into().unwrap();
+/// let bytes = header.to_bytes();
+/// let last = Ipv4HeaderSlice::from_slice(&bytes).unwrap();
+/// let result = buffer.add(0, &last, &[9, 10]).unwrap();
+/// assert_eq!(Some(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]), result);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ipv4FragmentBuffer {
+    /// Maximum allowed length of a reassembled payload.
+    max_payload_len: usize,
+    /// Maximum number of bytes buffered over all incomplete packets.
+    max_buffered_len: usize,
+    /// Number of bytes currently buffered over all incomplete packets.
+    buffered_len: usize,
+    /// How fragments overlapping already received data are handled.
+    overlap_policy: OverlapPolicy,
+    /// Incomplete packets together with the timestamp of their first fragment.
+    groups: HashMap<Ipv4FragmentId, (u64, FragmentSections)>,
+}
+
+impl Ipv4FragmentBuffer {
+    /// Default maximum length of a reassembled payload (maximum
+    /// representable by the IPv4 "total length" field minus the
+    /// minimum IPv4 header length).
+    pub const DEFAULT_MAX_PAYLOAD_LEN: usize = 0xffff - Ipv4Header::MIN_LEN;
+
+    /// Creates a new buffer that rejects reassembled payloads bigger
+    /// then `max_payload_len` bytes.
+    ///
+    /// Overlapping fragments are rejected and the number of buffered
+    /// bytes is not limited (see [`Ipv4FragmentBuffer::with_overlap_policy`]
+    /// & [`Ipv4FragmentBuffer::with_max_buffered_len`]).
+    pub fn new(max_payload_len: usize) -> Ipv4FragmentBuffer {
+        Ipv4FragmentBuffer {
+            max_payload_len,
+            max_buffered_len: usize::MAX,
+            buffered_len: 0,
+            overlap_policy: OverlapPolicy::Reject,
+            groups: HashMap::new(),
+        }
+    }
+
+    /// Returns the buffer with the given policy for overlapping fragments.
+    pub fn with_overlap_policy(mut self, overlap_policy: OverlapPolicy) -> Ipv4FragmentBuffer {
+        self.overlap_policy = overlap_policy;
+        self
+    }
+
+    /// Returns the buffer with the given maximum number of bytes that
+    /// are allowed to be buffered over all incomplete packets.
+    pub fn with_max_buffered_len(mut self, max_buffered_len: usize) -> Ipv4FragmentBuffer {
+        self.max_buffered_len = max_buffered_len;
+        self
+    }
+
+    /// Maximum allowed length of a reassembled payload.
+    #[inline]
+    pub fn max_payload_len(&self) -> usize {
+        self.max_payload_len
+    }
+
+    /// Maximum number of bytes allowed to be buffered over all
+    /// incomplete packets.
+    #[inline]
+    pub fn max_buffered_len(&self) -> usize {
+        self.max_buffered_len
+    }
+
+    /// Number of bytes currently buffered over all incomplete packets.
+    #[inline]
+    pub fn buffered_len(&self) -> usize {
+        self.buffered_len
+    }
+
+    /// Policy used for fragments overlapping already received data.
+    #[inline]
+    pub fn overlap_policy(&self) -> OverlapPolicy {
+        self.overlap_policy
+    }
+
+    /// Adds a fragment to the buffer and returns the reassembled payload
+    /// in case the packet is complete.
+    ///
+    /// `timestamp` is a caller defined monotonic value (e.g. seconds
+    /// since the capture started) that is stored for the first fragment of
+    /// a packet and used by [`Ipv4FragmentBuffer::remove_older_than`].
+    ///
+    /// `payload` is the data following the IPv4 header (including
+    /// IPv4 extension headers).
+    ///
+    /// Headers that don't fragment the payload (offset 0 and more fragments
+    /// not set) directly return their payload without buffering.
+    ///
+    /// In case of an error all buffered data of the packet the fragment
+    /// belongs to gets discarded.
+    pub fn add(
+        &mut self,
+        timestamp: u64,
+        header: &Ipv4HeaderSlice,
+        payload: &[u8],
+    ) -> Result<Option<Vec<u8>>, FragmentError> {
+        let offset = usize::from(header.fragments_offset().value()) * 8;
+
+        // unfragmented packets can directly be returned
+        if false == header.is_fragmenting_payload() {
+            return if payload.len() > self.max_payload_len {
+                Err(FragmentError::MaxLenExceeded {
+                    max_len: self.max_payload_len,
+                    required_len: payload.len(),
+                })
+            } else {
+                Ok(Some(payload.to_vec()))
+            };
+        }
+
+        let id = Ipv4FragmentId {
+            source: header.source(),
+            destination: header.destination(),
+            identification: header.identification(),
+            protocol: header.protocol(),
+        };
+
+        let (_, sections) = self
+            .groups
+            .entry(id.clone())
+            .or_insert_with(|| (timestamp, FragmentSections::new()));
+        let prev_data_len = sections.data_len();
+
+        // check that the buffered data stays in the configured bounds
+        let required_len =
+            self.buffered_len - prev_data_len + prev_data_len.max(offset + payload.len());
+        let result = if required_len > self.max_buffered_len {
+            Err(FragmentError::MaxBufferedLenExceeded {
+                max_len: self.max_buffered_len,
+                required_len,
+            })
+        } else {
+            sections.add(
+                offset,
+                payload,
+                header.more_fragments(),
+                self.max_payload_len,
+                self.overlap_policy,
+            )
+        };
+
+        if let Err(err) = result {
+            self.groups.remove(&id);
+            self.buffered_len -= prev_data_len;
+            return Err(err);
+        }
+        self.buffered_len = self.buffered_len - prev_data_len + sections.data_len();
+
+        if sections.is_complete() {
+            Ok(self.groups.remove(&id).map(|(_, s)| {
+                self.buffered_len -= s.data_len();
+                s.into_data()
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Discards all incomplete packets whose first fragment was added
+    /// with a timestamp smaller then the given `timestamp`.
+    ///
+    /// Returns the number of discarded packets.
+    pub fn remove_older_than(&mut self, timestamp: u64) -> usize {
+        let before = self.groups.len();
+        let mut removed_len = 0;
+        self.groups.retain(|_, (t, s)| {
+            let keep = *t >= timestamp;
+            if !keep {
+                removed_len += s.data_len();
+            }
+            keep
+        });
+        self.buffered_len -= removed_len;
+        before - self.groups.len()
+    }
+
+    /// Number of incomplete packets currently buffered.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns true if no incomplete packets are buffered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Discards all buffered fragments.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.groups.clear();
+        self.buffered_len = 0;
+    }
+}
+
+impl Default for Ipv4FragmentBuffer {
+    fn default() -> Self {
+        Ipv4FragmentBuffer::new(Ipv4FragmentBuffer::DEFAULT_MAX_PAYLOAD_LEN)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::reassembly::FragmentError::*;
+    use alloc::{format, vec, vec::Vec};
+
+    fn header(source: [u8; 4], offset: u16, more: bool, id: u16) -> Ipv4Header {
+        let mut header = Ipv4Header::new(0, 20, ip_number::UDP, source, [5, 6, 7, 8]).unwrap();
+        header.identification = id;
+        header.dont_fragment = false;
+        header.more_fragments = more;
+        header.fragment_offset = offset.try_into().unwrap();
+        header
+    }
+
+    fn add(
+        buffer: &mut Ipv4FragmentBuffer,
+        timestamp: u64,
+        header: &Ipv4Header,
+        payload: &[u8],
+    ) -> Result<Option<Vec<u8>>, FragmentError> {
+        let bytes = header.to_bytes();
+        buffer.add(
+            timestamp,
+            &Ipv4HeaderSlice::from_slice(&bytes).unwrap(),
+            payload,
+        )
+    }
+
+    #[test]
+    fn id_debug_clone_eq() {
+        let id = Ipv4FragmentId {
+            source: [1, 2, 3, 4],
+            destination: [5, 6, 7, 8],
+            identification: 2,
+            protocol: ip_number::UDP,
+        };
+        assert_eq!(id, id.clone());
+        assert_eq!(
+            format!(
+                "Ipv4FragmentId {{ source: [1, 2, 3, 4], destination: [5, 6, 7, 8], identification: 2, protocol: {:?} }}",
+                ip_number::UDP
+            ),
+            format!("{:?}", id)
+        );
+    }
+
+    #[test]
+    fn default() {
+        let buffer: Ipv4FragmentBuffer = Default::default();
+        assert_eq!(
+            Ipv4FragmentBuffer::DEFAULT_MAX_PAYLOAD_LEN,
+            buffer.max_payload_len()
+        );
+        assert_eq!(usize::MAX, buffer.max_buffered_len());
+        assert_eq!(0, buffer.buffered_len());
+        assert_eq!(OverlapPolicy::Reject, buffer.overlap_policy());
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn with() {
+        let buffer = Ipv4FragmentBuffer::new(100)
+            .with_overlap_policy(OverlapPolicy::LastWins)
+            .with_max_buffered_len(1000);
+        assert_eq!(100, buffer.max_payload_len());
+        assert_eq!(1000, buffer.max_buffered_len());
+        assert_eq!(OverlapPolicy::LastWins, buffer.overlap_policy());
+    }
+
+    #[test]
+    fn add_unfragmented() {
+        let mut buffer = Ipv4FragmentBuffer::new(4);
+        assert_eq!(
+            add(&mut buffer, 0, &header([0; 4], 0, false, 1), &[1, 2, 3, 4]),
+            Ok(Some(vec![1, 2, 3, 4]))
+        );
+        assert!(buffer.is_empty());
+        assert_eq!(
+            add(
+                &mut buffer,
+                0,
+                &header([0; 4], 0, false, 1),
+                &[1, 2, 3, 4, 5]
+            ),
+            Err(MaxLenExceeded {
+                max_len: 4,
+                required_len: 5
+            })
+        );
+    }
+
+    #[test]
+    fn add_reassemble() {
+        let payload: Vec<u8> = (0..40u8).collect();
+        let mut buffer = Ipv4FragmentBuffer::default();
+
+        // interleave two packets with different ids & one with different addresses
+        assert_eq!(
+            None,
+            add(&mut buffer, 0, &header([0; 4], 3, false, 1), &payload[24..]).unwrap()
+        );
+        assert_eq!(
+            None,
+            add(&mut buffer, 0, &header([0; 4], 0, true, 2), &payload[..8]).unwrap()
+        );
+        assert_eq!(
+            None,
+            add(&mut buffer, 0, &header([1; 4], 0, true, 1), &payload[..8]).unwrap()
+        );
+        assert_eq!(
+            None,
+            add(&mut buffer, 0, &header([0; 4], 0, true, 1), &payload[..8]).unwrap()
+        );
+        assert_eq!(3, buffer.len());
+        assert_eq!(40 + 8 + 8, buffer.buffered_len());
+        assert_eq!(
+            Some(payload.clone()),
+            add(&mut buffer, 0, &header([0; 4], 1, true, 1), &payload[8..24]).unwrap()
+        );
+        assert_eq!(2, buffer.len());
+        assert_eq!(8 + 8, buffer.buffered_len());
+    }
+
+    #[test]
+    fn add_overlapping() {
+        // reject
+        {
+            let mut buffer = Ipv4FragmentBuffer::default();
+            add(&mut buffer, 0, &header([0; 4], 0, true, 1), &[0; 16]).unwrap();
+            assert_eq!(
+                add(&mut buffer, 0, &header([0; 4], 1, true, 1), &[0; 16]),
+                Err(Overlapping { offset: 8, len: 16 })
+            );
+            // packet gets discarded on error
+            assert!(buffer.is_empty());
+            assert_eq!(0, buffer.buffered_len());
+        }
+        // first wins
+        {
+            let mut buffer =
+                Ipv4FragmentBuffer::default().with_overlap_policy(OverlapPolicy::FirstWins);
+            add(&mut buffer, 0, &header([0; 4], 0, true, 1), &[1; 16]).unwrap();
+            assert_eq!(
+                add(&mut buffer, 0, &header([0; 4], 1, false, 1), &[2; 16]),
+                Ok(Some([&[1u8; 16][..], &[2; 8]].concat()))
+            );
+            assert_eq!(0, buffer.buffered_len());
+        }
+        // last wins
+        {
+            let mut buffer =
+                Ipv4FragmentBuffer::default().with_overlap_policy(OverlapPolicy::LastWins);
+            add(&mut buffer, 0, &header([0; 4], 0, true, 1), &[1; 16]).unwrap();
+            assert_eq!(
+                add(&mut buffer, 0, &header([0; 4], 1, false, 1), &[2; 16]),
+                Ok(Some([&[1u8; 8][..], &[2; 16]].concat()))
+            );
+        }
+    }
+
+    #[test]
+    fn add_max_len() {
+        let mut buffer = Ipv4FragmentBuffer::new(16);
+        add(&mut buffer, 0, &header([0; 4], 0, true, 1), &[0; 8]).unwrap();
+        assert_eq!(
+            add(&mut buffer, 0, &header([0; 4], 1, false, 1), &[0; 9]),
+            Err(MaxLenExceeded {
+                max_len: 16,
+                required_len: 17
+            })
+        );
+        assert!(buffer.is_empty());
+        assert_eq!(0, buffer.buffered_len());
+    }
+
+    #[test]
+    fn add_max_buffered_len() {
+        let mut buffer = Ipv4FragmentBuffer::default().with_max_buffered_len(24);
+        add(&mut buffer, 0, &header([0; 4], 0, true, 1), &[0; 8]).unwrap();
+        add(&mut buffer, 0, &header([0; 4], 1, true, 2), &[0; 8]).unwrap();
+        assert_eq!(24, buffer.buffered_len());
+
+        // additional data for an existing packet
+        assert_eq!(
+            add(&mut buffer, 0, &header([0; 4], 1, true, 1), &[0; 8]),
+            Err(MaxBufferedLenExceeded {
+                max_len: 24,
+                required_len: 32
+            })
+        );
+        assert_eq!(1, buffer.len());
+        assert_eq!(16, buffer.buffered_len());
+
+        // data for a new packet
+        assert_eq!(
+            add(&mut buffer, 0, &header([0; 4], 1, true, 3), &[0; 8]),
+            Err(MaxBufferedLenExceeded {
+                max_len: 24,
+                required_len: 32
+            })
+        );
+        assert_eq!(1, buffer.len());
+        assert_eq!(16, buffer.buffered_len());
+
+        // completing a packet frees the buffered data
+        assert_eq!(
+            add(&mut buffer, 0, &header([0; 4], 0, true, 2), &[0; 8]),
+            Ok(None)
+        );
+        assert_eq!(
+            add(&mut buffer, 0, &header([0; 4], 2, false, 2), &[0; 8]),
+            Ok(Some(vec![0; 24]))
+        );
+        assert!(buffer.is_empty());
+        assert_eq!(0, buffer.buffered_len());
+    }
+
+    #[test]
+    fn remove_older_than() {
+        let mut buffer = Ipv4FragmentBuffer::default();
+        add(&mut buffer, 1, &header([0; 4], 0, true, 1), &[0; 8]).unwrap();
+        add(&mut buffer, 5, &header([0; 4], 0, true, 2), &[0; 16]).unwrap();
+        // later fragments don't update the timestamp of the packet
+        add(&mut buffer, 10, &header([0; 4], 2, true, 1), &[0; 8]).unwrap();
+        assert_eq!(24 + 16, buffer.buffered_len());
+        assert_eq!(0, buffer.remove_older_than(1));
+        assert_eq!(1, buffer.remove_older_than(5));
+        assert_eq!(1, buffer.len());
+        assert_eq!(16, buffer.buffered_len());
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(0, buffer.buffered_len());
+    }
+}
@@ -0,0 +1,325 @@
+use super::{FragmentSections, OverlapPolicy};
+use crate::{err::reassembly::FragmentError, *};
+use std::{collections::HashMap, vec::Vec};
+
+/// Values identifying the fragments belonging to the same
+/// original IPv6 packet.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Ipv6FragmentId {
+    /// IPv6 source address.
+    pub source: [u8; 16],
+    /// IPv6 destination address.
+    pub destination: [u8; 16],
+    /// Identification value present in the fragment header.
+    pub identification: u32,
+    /// IP number of the header following the fragment header.
+    pub next_header: IpNumber,
+}
+
+/// Buffer for reassembling the payloads of fragmented IPv6 packets.
+///
+/// Fragments are grouped by their [`Ipv6FragmentId`] (source, destination,
+/// identification & next header). As soon as all fragments of a
+/// packet have been added the reassembled payload is returned.
+///
+/// Overlapping fragments are rejected as required by
+/// [RFC5722](https://datatracker.ietf.org/doc/html/rfc5722). Whenever
+/// an error is returned all the buffered data of the packet the fragment
+/// belongs to is discarded.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{ip_number::UDP, Ipv6FragmentHeader, reassembly::Ipv6FragmentBuffer};
+///
+/// let mut buffer = Ipv6FragmentBuffer::new(u16::MAX.into());
+/// let source = [0; 16];
+/// let destination = [1; 16];
+///
+/// // first fragment (offset is in 8 octet units)
+/// let first = Ipv6FragmentHeader::new(UDP, 0.try_into().unwrap(), true, 1234);
+/// let result = buffer.add(0, source, destination, &first, &[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+/// assert_eq!(None, result);
+///
+/// // last fragment
+/// let last = Ipv6FragmentHeader::new(UDP, 1.try_into().unwrap(), false, 1234);
+/// let result = buffer.add(0, source, destination, &last, &[9, 10]).unwrap();
+/// assert_eq!(Some(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]), result);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ipv6FragmentBuffer {
+    /// Maximum allowed length of a reassembled payload.
+    max_payload_len: usize,
+    /// Incomplete packets together with the timestamp of their first fragment.
+    groups: HashMap<Ipv6FragmentId, (u64, FragmentSections)>,
+}
+
+impl Ipv6FragmentBuffer {
+    /// Default maximum length of a reassembled payload (maximum
+    /// representable by the IPv6 "payload length" field).
+    pub const DEFAULT_MAX_PAYLOAD_LEN: usize = 0xffff;
+
+    /// Creates a new buffer that rejects reassembled payloads bigger
+    /// then `max_payload_len` bytes.
+    pub fn new(max_payload_len: usize) -> Ipv6FragmentBuffer {
+        Ipv6FragmentBuffer {
+            max_payload_len,
+            groups: HashMap::new(),
+        }
+    }
+
+    /// Maximum allowed length of a reassembled payload.
+    #[inline]
+    pub fn max_payload_len(&self) -> usize {
+        self.max_payload_len
+    }
+
+    /// Adds a fragment to the buffer and returns the reassembled payload
+    /// in case the packet is complete.
+    ///
+    /// `timestamp` is a caller defined monotonic value (e.g. seconds
+    /// since the capture started) that is stored for the first fragment of
+    /// a packet and used by [`Ipv6FragmentBuffer::remove_older_than`].
+    ///
+    /// `payload` is the data following the fragment header (the fragmentable
+    /// part of the original packet).
+    ///
+    /// Headers that don't fragment the payload (offset 0 and more fragments
+    /// not set) directly return their payload without buffering.
+    ///
+    /// In case of an error all buffered data of the packet the fragment
+    /// belongs to gets discarded.
+    pub fn add(
+        &mut self,
+        timestamp: u64,
+        source: [u8; 16],
+        destination: [u8; 16],
+        header: &Ipv6FragmentHeader,
+        payload: &[u8],
+    ) -> Result<Option<Vec<u8>>, FragmentError> {
+        let offset = usize::from(header.fragment_offset.value()) * 8;
+
+        // unfragmented packets can directly be returned
+        if false == header.is_fragmenting_payload() {
+            return if payload.len() > self.max_payload_len {
+                Err(FragmentError::MaxLenExceeded {
+                    max_len: self.max_payload_len,
+                    required_len: payload.len(),
+                })
+            } else {
+                Ok(Some(payload.to_vec()))
+            };
+        }
+
+        let id = Ipv6FragmentId {
+            source,
+            destination,
+            identification: header.identification,
+            next_header: header.next_header,
+        };
+
+        let (_, sections) = self
+            .groups
+            .entry(id.clone())
+            .or_insert_with(|| (timestamp, FragmentSections::new()));
+
+        if let Err(err) = sections.add(
+            offset,
+            payload,
+            header.more_fragments,
+            self.max_payload_len,
+            OverlapPolicy::Reject,
+        ) {
+            self.groups.remove(&id);
+            return Err(err);
+        }
+
+        if sections.is_complete() {
+            Ok(self.groups.remove(&id).map(|(_, s)| s.into_data()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Discards all incomplete packets whose first fragment was added
+    /// with a timestamp smaller then the given `timestamp`.
+    ///
+    /// Returns the number of discarded packets.
+    pub fn remove_older_than(&mut self, timestamp: u64) -> usize {
+        let before = self.groups.len();
+        self.groups.retain(|_, (t, _)| *t >= timestamp);
+        before - self.groups.len()
+    }
+
+    /// Number of incomplete packets currently buffered.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns true if no incomplete packets are buffered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Discards all buffered fragments.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.groups.clear()
+    }
+}
+
+impl Default for Ipv6FragmentBuffer {
+    fn default() -> Self {
+        Ipv6FragmentBuffer::new(Ipv6FragmentBuffer::DEFAULT_MAX_PAYLOAD_LEN)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::reassembly::FragmentError::*;
+    use alloc::{format, vec, vec::Vec};
+
+    fn frag(offset: u16, more: bool, id: u32) -> Ipv6FragmentHeader {
+        Ipv6FragmentHeader::new(ip_number::UDP, offset.try_into().unwrap(), more, id)
+    }
+
+    #[test]
+    fn id_debug_clone_eq() {
+        let id = Ipv6FragmentId {
+            source: [0; 16],
+            destination: [1; 16],
+            identification: 2,
+            next_header: ip_number::UDP,
+        };
+        assert_eq!(id, id.clone());
+        assert_eq!(
+            format!(
+                "Ipv6FragmentId {{ source: {:?}, destination: {:?}, identification: 2, next_header: {:?} }}",
+                [0u8; 16],
+                [1u8; 16],
+                ip_number::UDP
+            ),
+            format!("{:?}", id)
+        );
+    }
+
+    #[test]
+    fn default() {
+        let buffer: Ipv6FragmentBuffer = Default::default();
+        assert_eq!(
+            Ipv6FragmentBuffer::DEFAULT_MAX_PAYLOAD_LEN,
+            buffer.max_payload_len()
+        );
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn add_unfragmented() {
+        let mut buffer = Ipv6FragmentBuffer::new(4);
+        assert_eq!(
+            buffer.add(0, [0; 16], [0; 16], &frag(0, false, 1), &[1, 2, 3, 4]),
+            Ok(Some(vec![1, 2, 3, 4]))
+        );
+        assert!(buffer.is_empty());
+        assert_eq!(
+            buffer.add(0, [0; 16], [0; 16], &frag(0, false, 1), &[1, 2, 3, 4, 5]),
+            Err(MaxLenExceeded {
+                max_len: 4,
+                required_len: 5
+            })
+        );
+    }
+
+    #[test]
+    fn add_reassemble() {
+        let payload: Vec<u8> = (0..40u8).collect();
+        let mut buffer = Ipv6FragmentBuffer::default();
+
+        // interleave two packets with different ids & one with different addresses
+        assert_eq!(
+            None,
+            buffer
+                .add(0, [0; 16], [0; 16], &frag(3, false, 1), &payload[24..])
+                .unwrap()
+        );
+        assert_eq!(
+            None,
+            buffer
+                .add(0, [0; 16], [0; 16], &frag(0, true, 2), &payload[..8])
+                .unwrap()
+        );
+        assert_eq!(
+            None,
+            buffer
+                .add(0, [1; 16], [0; 16], &frag(0, true, 1), &payload[..8])
+                .unwrap()
+        );
+        assert_eq!(
+            None,
+            buffer
+                .add(0, [0; 16], [0; 16], &frag(0, true, 1), &payload[..8])
+                .unwrap()
+        );
+        assert_eq!(3, buffer.len());
+        assert_eq!(
+            Some(payload.clone()),
+            buffer
+                .add(0, [0; 16], [0; 16], &frag(1, true, 1), &payload[8..24])
+                .unwrap()
+        );
+        assert_eq!(2, buffer.len());
+    }
+
+    #[test]
+    fn add_overlapping() {
+        let mut buffer = Ipv6FragmentBuffer::default();
+        buffer
+            .add(0, [0; 16], [0; 16], &frag(0, true, 1), &[0; 16])
+            .unwrap();
+        assert_eq!(
+            buffer.add(0, [0; 16], [0; 16], &frag(1, true, 1), &[0; 16]),
+            Err(Overlapping { offset: 8, len: 16 })
+        );
+        // packet gets discarded on error
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn add_max_len() {
+        let mut buffer = Ipv6FragmentBuffer::new(16);
+        buffer
+            .add(0, [0; 16], [0; 16], &frag(0, true, 1), &[0; 8])
+            .unwrap();
+        assert_eq!(
+            buffer.add(0, [0; 16], [0; 16], &frag(1, false, 1), &[0; 9]),
+            Err(MaxLenExceeded {
+                max_len: 16,
+                required_len: 17
+            })
+        );
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn remove_older_than() {
+        let mut buffer = Ipv6FragmentBuffer::default();
+        buffer
+            .add(1, [0; 16], [0; 16], &frag(0, true, 1), &[0; 8])
+            .unwrap();
+        buffer
+            .add(5, [0; 16], [0; 16], &frag(0, true, 2), &[0; 8])
+            .unwrap();
+        // later fragments don't update the timestamp of the packet
+        buffer
+            .add(10, [0; 16], [0; 16], &frag(2, true, 1), &[0; 8])
+            .unwrap();
+        assert_eq!(0, buffer.remove_older_than(1));
+        assert_eq!(1, buffer.remove_older_than(5));
+        assert_eq!(1, buffer.len());
+        buffer.clear();
+        assert!(buffer.is_empty());
+    }
+}
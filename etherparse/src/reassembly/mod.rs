@@ -0,0 +1,11 @@
+mod fragment_sections;
+pub(crate) use fragment_sections::*;
+
+mod ipv4_fragment_buffer;
+pub use ipv4_fragment_buffer::*;
+
+mod ipv6_fragment_buffer;
+pub use ipv6_fragment_buffer::*;
+
+mod overlap_policy;
+pub use overlap_policy::*;
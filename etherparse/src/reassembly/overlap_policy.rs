@@ -0,0 +1,39 @@
+/// Defines how a reassembly buffer handles fragments whose payload
+/// overlaps with the payload of an already received fragment.
+///
+/// Operating systems differ in how they handle overlapping fragments
+/// (which is e.g. exploited to evade intrusion detection systems). The
+/// policy allows to mirror the behavior of the receiving system.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum OverlapPolicy {
+    /// Overlapping fragments cause a
+    /// [`crate::err::reassembly::FragmentError::Overlapping`] error and
+    /// the packet gets discarded (required for IPv6 by
+    /// [RFC5722](https://datatracker.ietf.org/doc/html/rfc5722)).
+    #[default]
+    Reject,
+    /// Data that was already received is kept & only the
+    /// not yet received parts of an overlapping fragment are used.
+    FirstWins,
+    /// Data of an overlapping fragment overwrites the already
+    /// received data.
+    LastWins,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn default() {
+        assert_eq!(OverlapPolicy::Reject, Default::default());
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = OverlapPolicy::FirstWins;
+        assert_eq!(value, value.clone());
+        assert_eq!("FirstWins", format!("{:?}", value));
+    }
+}
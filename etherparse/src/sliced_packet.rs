@@ -48,10 +48,31 @@ pub struct SlicedPacket<'a> {
     pub link: Option<LinkSlice<'a>>,
     /// Single or double vlan headers if present.
     pub vlan: Option<VlanSlice<'a>>,
-    /// IPv4 or IPv6 header, IP extension headers & payload if present.
+    /// PPPoE header (including the PPP protocol field in case of session data) if present.
+    pub pppoe: Option<PppoeHeaderSlice<'a>>,
+    /// IPv4 or IPv6 header, IP extension headers & payload or ARP packet if present.
     pub net: Option<NetSlice<'a>>,
-    /// TCP or UDP header & payload if present.
+    /// TCP, UDP, ICMP, IGMP or ESP header & payload if present.
     pub transport: Option<TransportSlice<'a>>,
+    /// VXLAN header & the slices of the encapsulated Ethernet II frame.
+    ///
+    /// Only decoded if enabled via [`ParseOptions::vxlan_port`] and the
+    /// destination port of the UDP header matches. The UDP payload in
+    /// `transport` still contains the complete VXLAN packet.
+    pub vxlan: Option<VxlanSlice<'a>>,
+    /// GTP-U header, extension headers & the slices of the encapsulated
+    /// IP packet.
+    ///
+    /// Only decoded if enabled via [`ParseOptions::gtpu_port`] and the
+    /// destination port of the UDP header matches. The UDP payload in
+    /// `transport` still contains the complete GTP-U packet.
+    pub gtpu: Option<GtpuSlice<'a>>,
+    /// Geneve header, options & the slices of the encapsulated packet.
+    ///
+    /// Only decoded if enabled via [`ParseOptions::geneve_port`] and the
+    /// destination port of the UDP header matches. The UDP payload in
+    /// `transport` still contains the complete Geneve packet.
+    pub geneve: Option<GeneveSlice<'a>>,
 }
 
 impl<'a> SlicedPacket<'a> {
@@ -60,6 +81,10 @@ impl<'a> SlicedPacket<'a> {
     /// The result is returned as a [`SlicedPacket`] struct. This function assumes the given data starts
     /// with an ethernet II header.
     ///
+    /// If the ether type field contains a value smaller or equal to 1500 the frame
+    /// is decoded as an IEEE 802.3 frame with an LLC header & optional SNAP header
+    /// instead (see [`LinkSlice::Ieee8023`]).
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -91,7 +116,86 @@ impl<'a> SlicedPacket<'a> {
     /// }
     /// ```
     pub fn from_ethernet(data: &'a [u8]) -> Result<SlicedPacket, err::packet::SliceError> {
-        SlicedPacketCursor::new(data).slice_ethernet2()
+        SlicedPacket::from_ethernet_with_options(data, &Default::default())
+    }
+
+    /// Separates a network packet slice into different slices containing the headers
+    /// from the ethernet header downwards using the given [`ParseOptions`].
+    ///
+    /// Behaves like [`SlicedPacket::from_ethernet`] but additionally decodes the
+    /// protocols enabled in the options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ParseOptions, SlicedPacket, VxlanHeader};
+    /// # let packet = [0u8; 14];
+    ///
+    /// let options = ParseOptions {
+    ///     // decode UDP payloads sent to port 4789 as VXLAN
+    ///     vxlan_port: Some(VxlanHeader::IANA_PORT),
+    ///     ..Default::default()
+    /// };
+    /// match SlicedPacket::from_ethernet_with_options(&packet, &options) {
+    ///     Err(value) => println!("Err {:?}", value),
+    ///     Ok(value) => {
+    ///         println!("link: {:?}", value.link);
+    ///         println!("transport: {:?}", value.transport);
+    ///         if let Some(vxlan) = value.vxlan {
+    ///             println!("vni: {:?}", vxlan.header.vni());
+    ///             println!("inner net: {:?}", vxlan.net);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn from_ethernet_with_options(
+        data: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        SlicedPacketCursor::new(data, options).slice_ethernet2()
+    }
+
+    /// Separates a network packet slice starting with an Ethernet II
+    /// header into slices but stops after the IP header & IP extension
+    /// headers.
+    ///
+    /// The link, VLAN & IP layers are decoded like in
+    /// [`SlicedPacket::from_ethernet`], but `transport` is always `None`
+    /// and the transport header stays part of the IP payload. Use this
+    /// if only the IP layer is needed (e.g. addresses & protocol) to skip
+    /// the work of decoding the transport layer (see
+    /// [`ParseOptions::stop_after_ip`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, PacketBuilder, SlicedPacket, TcpHeader};
+    ///
+    /// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .tcp(21, 1234, 1, 2);
+    /// let mut packet = Vec::<u8>::with_capacity(builder.size(4));
+    /// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+    ///
+    /// let sliced = SlicedPacket::from_ethernet_ip_only(&packet).unwrap();
+    /// assert!(sliced.link.is_some());
+    /// assert!(sliced.transport.is_none());
+    ///
+    /// // the tcp header is still part of the ip payload
+    /// let ip_payload = sliced.ip_payload().unwrap();
+    /// assert_eq!(ip_number::TCP, ip_payload.ip_number);
+    /// assert_eq!(TcpHeader::MIN_LEN + 4, ip_payload.payload.len());
+    /// ```
+    pub fn from_ethernet_ip_only(
+        data: &'a [u8],
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        SlicedPacket::from_ethernet_with_options(
+            data,
+            &ParseOptions {
+                stop_after_ip: true,
+                ..Default::default()
+            },
+        )
     }
 
     /// Separates a network packet slice into different slices containing the headers using
@@ -105,6 +209,9 @@ impl<'a> SlicedPacket<'a> {
     /// * `ether_type::VLAN_TAGGED_FRAME`
     /// * `ether_type::PROVIDER_BRIDGING`
     /// * `ether_type::VLAN_DOUBLE_TAGGED_FRAME`
+    /// * `ether_type::ARP`
+    /// * `ether_type::PPPOE_DISCOVERY`
+    /// * `ether_type::PPPOE_SESSION`
     ///
     /// If an unsupported ether type is given the given slice will be set as payload
     /// and all other fields will be set to `None`.
@@ -150,27 +257,124 @@ impl<'a> SlicedPacket<'a> {
         ether_type: EtherType,
         data: &'a [u8],
     ) -> Result<SlicedPacket, err::packet::SliceError> {
+        SlicedPacket::from_ether_type_with_options(ether_type, data, &Default::default())
+    }
+
+    /// Separates a network packet slice into different slices containing the headers
+    /// using the given `ether_type` number to identify the first header and the given
+    /// [`ParseOptions`].
+    ///
+    /// Behaves like [`SlicedPacket::from_ether_type`] but additionally decodes the
+    /// protocols enabled in the options.
+    pub fn from_ether_type_with_options(
+        ether_type: EtherType,
+        data: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
         use ether_type::*;
         match ether_type {
-            IPV4 => SlicedPacketCursor::new(data).slice_ipv4(),
-            IPV6 => SlicedPacketCursor::new(data).slice_ipv6(),
+            IPV4 => SlicedPacketCursor::new(data, options).slice_ipv4(),
+            IPV6 => SlicedPacketCursor::new(data, options).slice_ipv6(),
             VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => {
-                SlicedPacketCursor::new(data).slice_vlan()
+                SlicedPacketCursor::new(data, options).slice_vlan()
             }
+            ARP => SlicedPacketCursor::new(data, options).slice_arp(),
+            PPPOE_DISCOVERY | PPPOE_SESSION => SlicedPacketCursor::new(data, options).slice_pppoe(),
             _ => Ok(SlicedPacket {
                 link: None,
                 vlan: None,
+                pppoe: None,
                 net: None,
                 transport: None,
+                vxlan: None,
+                gtpu: None,
+                geneve: None,
             }),
         }
     }
 
+    /// Separates a network packet slice into different slices containing the headers
+    /// from the Linux cooked capture v1 (SLL) header downwards.
+    ///
+    /// This function assumes the given data starts with a [`LinuxSllHeader`]
+    /// (e.g. packets from a pcap file with the link type `LINKTYPE_LINUX_SLL`).
+    /// The protocol type field of the header is used as ether type to decode
+    /// the following layers. If the protocol type is not a supported ether type
+    /// (see [`SlicedPacket::from_ether_type`]) only the `link` field is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ether_type, LinuxSllHeader, LinuxSllPacketType, PacketBuilder, SlicedPacket};
+    ///
+    /// let sll = LinuxSllHeader {
+    ///     packet_type: LinuxSllPacketType::HOST,
+    ///     protocol_type: ether_type::IPV4,
+    ///     ..Default::default()
+    /// };
+    /// let builder = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20).udp(21, 1234);
+    ///
+    /// let mut packet = Vec::new();
+    /// packet.extend_from_slice(&sll.to_bytes());
+    /// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+    ///
+    /// let sliced = SlicedPacket::from_linux_sll(&packet).unwrap();
+    /// assert_eq!(sliced.link.unwrap().payload_ether_type(), ether_type::IPV4);
+    /// assert!(sliced.net.is_some());
+    /// assert!(sliced.transport.is_some());
+    /// ```
+    pub fn from_linux_sll(data: &'a [u8]) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        SlicedPacket::from_linux_sll_with_options(data, &Default::default())
+    }
+
+    /// Separates a network packet slice into different slices containing the headers
+    /// from the Linux cooked capture v1 (SLL) header downwards using the given
+    /// [`ParseOptions`].
+    ///
+    /// Behaves like [`SlicedPacket::from_linux_sll`] but additionally decodes the
+    /// protocols enabled in the options.
+    pub fn from_linux_sll_with_options(
+        data: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        SlicedPacketCursor::new(data, options).slice_linux_sll()
+    }
+
+    /// Separates a network packet slice into different slices containing the headers
+    /// from the Linux cooked capture v2 (SLL2) header downwards.
+    ///
+    /// This function assumes the given data starts with a [`LinuxSll2Header`]
+    /// (e.g. packets from a pcap file with the link type `LINKTYPE_LINUX_SLL2`).
+    /// The protocol type field of the header is used as ether type to decode
+    /// the following layers. If the protocol type is not a supported ether type
+    /// (see [`SlicedPacket::from_ether_type`]) only the `link` field is set.
+    pub fn from_linux_sll2(data: &'a [u8]) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        SlicedPacket::from_linux_sll2_with_options(data, &Default::default())
+    }
+
+    /// Separates a network packet slice into different slices containing the headers
+    /// from the Linux cooked capture v2 (SLL2) header downwards using the given
+    /// [`ParseOptions`].
+    ///
+    /// Behaves like [`SlicedPacket::from_linux_sll2`] but additionally decodes the
+    /// protocols enabled in the options.
+    pub fn from_linux_sll2_with_options(
+        data: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        SlicedPacketCursor::new(data, options).slice_linux_sll2()
+    }
+
     /// Separates a network packet slice into different slices containing the headers from the ip header downwards.
     ///
     /// The result is returned as a [`SlicedPacket`] struct. This function assumes the given data starts
     /// with an IPv4 or IPv6 header.
     ///
+    /// The IP version is detected automatically based on the version number in the first
+    /// 4 bits of the data, which makes this function suited for raw IP captures without a
+    /// link layer (e.g. `DLT_RAW` or tun interfaces). If the version number is neither 4
+    /// nor 6 an [`err::ip::HeaderError::UnsupportedIpVersion`] error is returned.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -202,8 +406,129 @@ impl<'a> SlicedPacket<'a> {
     ///     }
     /// }
     /// ```
+    ///
+    /// Data with an unknown IP version:
+    ///
+    /// ```
+    /// use etherparse::{err::{ip::HeaderError, packet::SliceError}, SlicedPacket};
+    ///
+    /// assert_eq!(
+    ///     SlicedPacket::from_ip(&[0x50, 0, 0, 0]).unwrap_err(),
+    ///     SliceError::Ip(HeaderError::UnsupportedIpVersion { version_number: 5 })
+    /// );
+    /// ```
     pub fn from_ip(data: &'a [u8]) -> Result<SlicedPacket, err::packet::SliceError> {
-        SlicedPacketCursor::new(data).slice_ip()
+        SlicedPacket::from_ip_with_options(data, &Default::default())
+    }
+
+    /// Separates a network packet slice into different slices containing the headers
+    /// from the ip header downwards using the given [`ParseOptions`].
+    ///
+    /// Behaves like [`SlicedPacket::from_ip`] but additionally decodes the
+    /// protocols enabled in the options.
+    pub fn from_ip_with_options(
+        data: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        SlicedPacketCursor::new(data, options).slice_ip()
+    }
+
+    /// Separates a network packet slice into different slices by guessing if
+    /// the data starts with an Ethernet II header or directly with an IP header.
+    ///
+    /// This is a heuristic intended for captures from inconsistent sources
+    /// (e.g. a misconfigured capture mixing Ethernet II frames & raw IP
+    /// packets). Whenever the link type is known prefer the dedicated
+    /// functions like [`SlicedPacket::from_ethernet`] or [`SlicedPacket::from_ip`].
+    ///
+    /// The guess is made as follows:
+    ///
+    /// 1. If the ether type field of a potential Ethernet II header contains
+    ///    an ether type that is decoded by [`SlicedPacket::from_ether_type`]
+    ///    and the data can be sliced as an Ethernet II frame, the result is
+    ///    returned with [`GuessedLinkType::Ethernet2`].
+    /// 2. Otherwise the IP version in the first 4 bits is checked. If it is
+    ///    4 or 6 and the data can be sliced as an IP packet, the result is
+    ///    returned with [`GuessedLinkType::Ipv4`] or [`GuessedLinkType::Ipv6`].
+    /// 3. If both fail the data is sliced as an Ethernet II frame and the
+    ///    result or error of that is returned.
+    ///
+    /// Note that the guess can be wrong, e.g. the source IP address of a
+    /// raw IPv4 packet can look like a known ether type or the destination
+    /// MAC address of an Ethernet II frame can start with a 4 or 6.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{GuessedLinkType, PacketBuilder, SlicedPacket};
+    ///
+    /// // raw ip packet without a link layer
+    /// let builder = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20).udp(21, 1234);
+    /// let mut packet = Vec::new();
+    /// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+    ///
+    /// let (sliced, guess) = SlicedPacket::from_guess(&packet).unwrap();
+    /// assert_eq!(guess, GuessedLinkType::Ipv4);
+    /// assert!(sliced.link.is_none());
+    /// assert!(sliced.transport.is_some());
+    /// ```
+    pub fn from_guess(
+        data: &'a [u8],
+    ) -> Result<(SlicedPacket<'a>, GuessedLinkType), err::packet::SliceError> {
+        SlicedPacket::from_guess_with_options(data, &Default::default())
+    }
+
+    /// Separates a network packet slice into different slices by guessing if
+    /// the data starts with an Ethernet II header or directly with an IP header
+    /// using the given [`ParseOptions`].
+    ///
+    /// Behaves like [`SlicedPacket::from_guess`] but additionally decodes the
+    /// protocols enabled in the options.
+    pub fn from_guess_with_options(
+        data: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<(SlicedPacket<'a>, GuessedLinkType), err::packet::SliceError> {
+        use ether_type::*;
+
+        // try ethernet first if the ether type is one that gets decoded
+        let ethernet_result = if data.len() >= Ethernet2Header::LEN {
+            match EtherType(u16::from_be_bytes([data[12], data[13]])) {
+                IPV4
+                | IPV6
+                | ARP
+                | VLAN_TAGGED_FRAME
+                | PROVIDER_BRIDGING
+                | VLAN_DOUBLE_TAGGED_FRAME
+                | PPPOE_DISCOVERY
+                | PPPOE_SESSION => {
+                    let result = SlicedPacket::from_ethernet_with_options(data, options);
+                    if let Ok(sliced) = result {
+                        return Ok((sliced, GuessedLinkType::Ethernet2));
+                    }
+                    Some(result)
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        // fall back to raw ip based on the version number
+        let ip_guess = match data.first().map(|v| v >> 4) {
+            Some(4) => Some(GuessedLinkType::Ipv4),
+            Some(6) => Some(GuessedLinkType::Ipv6),
+            _ => None,
+        };
+        if let Some(guess) = ip_guess {
+            if let Ok(sliced) = SlicedPacket::from_ip_with_options(data, options) {
+                return Ok((sliced, guess));
+            }
+        }
+
+        // nothing fitted, report the ethernet result
+        ethernet_result
+            .unwrap_or_else(|| SlicedPacket::from_ethernet_with_options(data, options))
+            .map(|sliced| (sliced, GuessedLinkType::Ethernet2))
     }
 
     /// If the slice in the `payload` field contains an ethernet payload
@@ -212,26 +537,30 @@ impl<'a> SlicedPacket<'a> {
     /// The ether type number can come from an ethernet II header or a
     /// VLAN header depending on which headers are present.
     ///
-    /// In case that `ip` and/or `transport` fields are the filled None
+    /// In case that `pppoe`, `ip` and/or `transport` fields are the filled None
     /// is returned, as the payload contents then are defined by a
     /// lower layer protocol described in these fields.
     pub fn payload_ether_type(&self) -> Option<EtherType> {
-        if self.net.is_some() || self.transport.is_some() {
+        if self.pppoe.is_some() || self.net.is_some() || self.transport.is_some() {
             None
-        } else if let Some(vlan) = &self.vlan {
-            use VlanSlice::*;
-            match vlan {
-                SingleVlan(s) => Some(s.ether_type()),
-                DoubleVlan(d) => Some(d.inner().ether_type()),
-            }
-        } else if let Some(link) = &self.link {
-            use LinkSlice::*;
-            match link {
-                Ethernet2(eth) => Some(eth.ether_type()),
-                EtherPayload(e) => Some(e.ether_type),
-            }
         } else {
-            None
+            self.link_payload_ether_type()
+        }
+    }
+
+    /// Returns the ether type after the link layer & all VLAN headers
+    /// (if an ethernet layer is present).
+    ///
+    /// In contrast to [`SlicedPacket::payload_ether_type`] the ether type
+    /// is also returned if the payload was decoded further (e.g. as an IP
+    /// packet). If VLAN headers are present the ether type of the most
+    /// inner VLAN header is returned. `None` is returned if no link layer
+    /// is present (e.g. when slicing started at the IP layer).
+    pub fn link_payload_ether_type(&self) -> Option<EtherType> {
+        if let Some(vlan) = &self.vlan {
+            Some(vlan.payload_ether_type())
+        } else {
+            self.link.as_ref().map(|link| link.payload_ether_type())
         }
     }
 
@@ -249,6 +578,9 @@ impl<'a> SlicedPacket<'a> {
         } else if let Some(eth) = self.link.as_ref() {
             match eth {
                 LinkSlice::Ethernet2(e) => Some(e.payload()),
+                LinkSlice::Ieee8023(_) => Some(eth.payload()),
+                LinkSlice::LinuxSll(s) => Some(s.payload()),
+                LinkSlice::LinuxSll2(s) => Some(s.payload()),
                 LinkSlice::EtherPayload(e) => Some(e.clone()),
             }
         } else {
@@ -264,239 +596,3600 @@ impl<'a> SlicedPacket<'a> {
             match net {
                 Ipv4(v) => Some(v.payload()),
                 Ipv6(v) => Some(v.payload()),
+                Arp(_) => None,
             }
         } else {
             None
         }
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::err::{packet::SliceError, Layer, LenError};
-    use crate::test_gens::*;
-    use crate::test_packet::TestPacket;
-    use proptest::prelude::*;
+    /// Returns the protocol in which the chain of the IP header & IP
+    /// extension headers ends (`None` if no IP header is present).
+    ///
+    /// Allows to distinguish an IPv6 packet without payload by design (the
+    /// last "next header" field is [`IpNumber::IPV6_NO_NEXT_HEADER`]) from
+    /// a packet ending in a transport protocol or in an unknown protocol.
+    /// The classification is based on the IP number only & is also
+    /// returned if the transport layer was not decoded (e.g. because the
+    /// payload is fragmented).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{IpChainEnd, IpNumber, Ipv6Header, SlicedPacket};
+    ///
+    /// let header = Ipv6Header {
+    ///     next_header: IpNumber::IPV6_NO_NEXT_HEADER,
+    ///     hop_limit: 20,
+    ///     ..Default::default()
+    /// };
+    /// let packet = header.to_bytes();
+    ///
+    /// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+    /// assert_eq!(Some(IpChainEnd::NoNextHeader), sliced.ip_chain_end());
+    /// ```
+    pub fn ip_chain_end(&self) -> Option<IpChainEnd> {
+        self.ip_payload()
+            .map(|payload| IpChainEnd::from_ip_number(payload.ip_number))
+    }
 
-    const VLAN_ETHER_TYPES: [EtherType; 3] = [
-        ether_type::VLAN_TAGGED_FRAME,
-        ether_type::PROVIDER_BRIDGING,
-        ether_type::VLAN_DOUBLE_TAGGED_FRAME,
-    ];
+    /// Returns the length of the TCP payload (segment length without the TCP
+    /// header & options) if a TCP header was decoded.
+    ///
+    /// As TCP has no length field of its own, the payload length is the IP
+    /// payload length (determined by the IPv4 total length or IPv6 payload
+    /// length field minus IP options & extension headers) minus the TCP
+    /// header length (data offset * 4). Bytes after the IP packet (e.g.
+    /// ethernet padding) are not counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket};
+    ///
+    /// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .tcp(21, 1234, 1, 2);
+    /// let mut packet = Vec::<u8>::with_capacity(builder.size(4));
+    /// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+    ///
+    /// // pad the frame to the minimum ethernet length
+    /// packet.resize(60, 0);
+    ///
+    /// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+    /// assert_eq!(Some(4), sliced.tcp_payload_len());
+    /// ```
+    pub fn tcp_payload_len(&self) -> Option<usize> {
+        match self.transport.as_ref() {
+            Some(TransportSlice::Tcp(tcp)) => Some(tcp.payload_len()),
+            _ => None,
+        }
+    }
 
-    #[test]
-    fn clone_eq() {
-        let header = SlicedPacket {
-            link: None,
-            vlan: None,
-            net: None,
-            transport: None,
+    /// Returns the payload of the most inner decoded layer, limited by the
+    /// length fields of the decoded headers (e.g. the UDP length or the IPv4
+    /// total length).
+    ///
+    /// Bytes after the declared lengths (e.g. ethernet padding) are not part
+    /// of the payload and can be accessed via [`SlicedPacket::trailer`].
+    ///
+    /// The IP payload is returned for transport protocols without a
+    /// dedicated [`PayloadSlice`] variant (IGMP & ESP) and for IP fragments.
+    /// `None` is returned if the most inner decoded layer is an ARP packet
+    /// or a PPPoE header without a decoded IP packet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, PayloadSlice, SlicedPacket};
+    ///
+    /// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .udp(21, 1234);
+    /// let mut packet = Vec::<u8>::with_capacity(builder.size(4));
+    /// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+    ///
+    /// // pad the frame to the minimum ethernet length
+    /// packet.resize(60, 0);
+    ///
+    /// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+    /// assert_eq!(Some(PayloadSlice::Udp(&[1, 2, 3, 4])), sliced.payload());
+    /// assert_eq!(Some(&[0u8; 60 - 46][..]), sliced.trailer());
+    /// ```
+    pub fn payload(&self) -> Option<PayloadSlice<'a>> {
+        match self.transport.as_ref() {
+            Some(TransportSlice::Udp(s)) => return Some(PayloadSlice::Udp(s.payload())),
+            Some(TransportSlice::Tcp(s)) => return Some(PayloadSlice::Tcp(s.payload())),
+            Some(TransportSlice::Icmpv4(s)) => return Some(PayloadSlice::Icmpv4(s.payload())),
+            Some(TransportSlice::Icmpv6(s)) => return Some(PayloadSlice::Icmpv6(s.payload())),
+            Some(TransportSlice::Dccp(s)) => return Some(PayloadSlice::Dccp(s.payload())),
+            Some(TransportSlice::Igmp(_)) | Some(TransportSlice::Esp(_)) | None => {}
+        }
+        if let Some(net) = self.net.as_ref() {
+            return net.ip_payload_ref().map(|p| PayloadSlice::Ip(p.clone()));
+        }
+        if self.pppoe.is_some() {
+            return None;
+        }
+        self.ether_payload().map(PayloadSlice::Ether)
+    }
+
+    /// Returns the bytes after the end of the most inner decoded layer
+    /// (as declared by the length fields of the headers) up to the end
+    /// of the link layer payload.
+    ///
+    /// These bytes are usually ethernet padding (frames are padded to a
+    /// minimum length of 60 bytes), but they can also contain data that is
+    /// not covered by any declared length (e.g. bytes between the end of an
+    /// UDP packet as declared by the UDP length field and the end of the IP
+    /// packet). Together with [`SlicedPacket::payload`] this allows to detect
+    /// such bytes.
+    ///
+    /// Note that the payload of IEEE 802.3 frames is already limited by the
+    /// 802.3 length field, bytes after it are not part of the link payload.
+    ///
+    /// `None` is returned if no link layer is present (e.g. when slicing
+    /// started at the IP layer) or if there is no decoded layer after the
+    /// link layer & PPPoE headers with a declared end.
+    pub fn trailer(&self) -> Option<&'a [u8]> {
+        let inner = match self.net.as_ref() {
+            Some(NetSlice::Arp(arp)) => arp.slice(),
+            _ => self.payload()?.slice(),
         };
-        assert_eq!(header.clone(), header);
+        let outer = self.ether_payload()?.payload;
+        let offset = (inner.as_ptr() as usize).checked_sub(outer.as_ptr() as usize)?;
+        outer.get(offset + inner.len()..)
     }
 
-    #[test]
-    fn debug() {
-        use alloc::format;
-        let header = SlicedPacket {
-            link: None,
-            vlan: None,
-            net: None,
-            transport: None,
+    /// Returns the addresses, ports & protocol identifying the flow of
+    /// the packet (the "5-tuple").
+    ///
+    /// `None` is returned if the packet contains no IPv4 or IPv6 header
+    /// or if the transport layer is not TCP or UDP (e.g. because the
+    /// packet is an IP fragment that is not the first fragment).
+    ///
+    /// Use [`FlowTuple::normalized`] to get the same value for both
+    /// directions of a flow.
+    pub fn flow_identifier(&self) -> Option<FlowTuple> {
+        let (protocol, source_port, destination_port) = match self.transport.as_ref()? {
+            TransportSlice::Udp(udp) => (ip_number::UDP, udp.source_port(), udp.destination_port()),
+            TransportSlice::Tcp(tcp) => (ip_number::TCP, tcp.source_port(), tcp.destination_port()),
+            _ => return None,
         };
-        assert_eq!(
-            format!("{:?}", header),
-            format!(
-                "SlicedPacket {{ link: {:?}, vlan: {:?}, net: {:?}, transport: {:?} }}",
-                header.link, header.vlan, header.net, header.transport,
-            )
-        );
+        match self.net.as_ref()? {
+            NetSlice::Ipv4(ipv4) => Some(FlowTuple::Ipv4 {
+                source: ipv4.header().source(),
+                destination: ipv4.header().destination(),
+                source_port,
+                destination_port,
+                protocol,
+            }),
+            NetSlice::Ipv6(ipv6) => Some(FlowTuple::Ipv6 {
+                source: ipv6.header().source(),
+                destination: ipv6.header().destination(),
+                source_port,
+                destination_port,
+                protocol,
+            }),
+            NetSlice::Arp(_) => None,
+        }
     }
 
-    #[test]
-    fn ether_payload() {
-        use alloc::vec::*;
+    /// Returns the set of layers present in the packet.
+    ///
+    /// The set is derived from the already decoded slices. IPv4 & IPv6
+    /// extension headers, the LLC & SNAP headers of IEEE 802.3 frames as
+    /// well as the headers of decoded tunnels (VXLAN, GTP-U & Geneve) are
+    /// included. Layers of packets encapsulated in a tunnel are not
+    /// part of the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{err::Layer, PacketBuilder, SlicedPacket};
+    ///
+    /// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .single_vlan(0x123.try_into().unwrap())
+    ///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .udp(21, 1234);
+    /// let mut packet = Vec::<u8>::with_capacity(builder.size(0));
+    /// builder.write(&mut packet, &[]).unwrap();
+    ///
+    /// let layers = SlicedPacket::from_ethernet(&packet).unwrap().layers();
+    /// assert!(layers.contains(Layer::VlanHeader));
+    /// assert!(layers.contains(Layer::UdpHeader));
+    /// assert!(false == layers.contains(Layer::TcpHeader));
+    /// ```
+    pub fn layers(&self) -> LayerSet {
+        use err::Layer;
 
-        // no content
-        assert_eq!(
-            SlicedPacket {
-                link: None,
-                vlan: None,
-                net: None,
-                transport: None,
+        let mut result = LayerSet::new();
+        if let Some(link) = &self.link {
+            match link {
+                LinkSlice::Ethernet2(_) => result.insert(Layer::Ethernet2Header),
+                LinkSlice::Ieee8023(ieee8023) => {
+                    result.insert(Layer::Ieee8023Header);
+                    result.insert(Layer::LlcHeader);
+                    if ieee8023.snap().is_some() {
+                        result.insert(Layer::SnapHeader);
+                    }
+                }
+                LinkSlice::LinuxSll(_) => result.insert(Layer::LinuxSllHeader),
+                LinkSlice::LinuxSll2(_) => result.insert(Layer::LinuxSll2Header),
+                LinkSlice::EtherPayload(_) => result.insert(Layer::EtherPayload),
             }
-            .ether_payload(),
-            None
-        );
+        }
+        if self.vlan.is_some() {
+            result.insert(Layer::VlanHeader);
+        }
+        if self.pppoe.is_some() {
+            result.insert(Layer::PppoeHeader);
+        }
+        if let Some(net) = &self.net {
+            match net {
+                NetSlice::Ipv4(ipv4) => {
+                    result.insert(Layer::Ipv4Header);
+                    if ipv4.extensions().auth.is_some() {
+                        result.insert(Layer::IpAuthHeader);
+                    }
+                }
+                NetSlice::Ipv6(ipv6) => {
+                    result.insert(Layer::Ipv6Header);
+                    for ext in ipv6.extensions().clone() {
+                        use Ipv6ExtensionSlice::*;
+                        result.insert(match ext {
+                            HopByHop(_) => Layer::Ipv6HopByHopHeader,
+                            Routing(_) => Layer::Ipv6RouteHeader,
+                            Fragment(_) => Layer::Ipv6FragHeader,
+                            DestinationOptions(_) => Layer::Ipv6DestOptionsHeader,
+                            Authentication(_) => Layer::IpAuthHeader,
+                            Mobility(_) => Layer::Ipv6MobilityHeader,
+                        });
+                    }
+                }
+                NetSlice::Arp(_) => result.insert(Layer::Arp),
+            }
+        }
+        if let Some(transport) = &self.transport {
+            result.insert(match transport {
+                TransportSlice::Icmpv4(_) => Layer::Icmpv4,
+                TransportSlice::Icmpv6(_) => Layer::Icmpv6,
+                TransportSlice::Udp(_) => Layer::UdpHeader,
+                TransportSlice::Tcp(_) => Layer::TcpHeader,
+                TransportSlice::Igmp(_) => Layer::Igmp,
+                TransportSlice::Esp(_) => Layer::EspHeader,
+                TransportSlice::Dccp(_) => Layer::DccpHeader,
+            });
+        }
+        if self.vxlan.is_some() {
+            result.insert(Layer::VxlanHeader);
+        }
+        if self.gtpu.is_some() {
+            result.insert(Layer::GtpuHeader);
+        }
+        if self.geneve.is_some() {
+            result.insert(Layer::GeneveHeader);
+        }
+        result
+    }
+
+    /// Returns the summed length of all decoded headers in bytes (link,
+    /// VLAN, PPPoE, IP header including options & extension headers and
+    /// the transport header).
+    ///
+    /// IPv4 options & IPv6 extension headers are included, as well as
+    /// the LLC & SNAP headers of IEEE 802.3 frames. An ARP packet & an
+    /// IGMP message are counted as a header without payload. Headers
+    /// of tunnels decoded inside an UDP payload (VXLAN & GTP-U) are
+    /// not included as the UDP payload still contains them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket};
+    ///
+    /// let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+    ///     .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .udp(21, 1234);
+    /// let mut packet = Vec::<u8>::with_capacity(builder.size(4));
+    /// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+    ///
+    /// let sliced = SlicedPacket::from_ethernet(&packet).unwrap();
+    /// // ethernet II (14) + IPv4 (20) + UDP (8)
+    /// assert_eq!(42, sliced.header_len());
+    /// assert_eq!(&[1, 2, 3, 4], &packet[sliced.payload_offset()..]);
+    /// ```
+    pub fn header_len(&self) -> usize {
+        let link_len = match &self.link {
+            Some(LinkSlice::Ethernet2(eth)) => eth.header_len(),
+            Some(LinkSlice::Ieee8023(s)) => s.header_len(),
+            Some(LinkSlice::LinuxSll(s)) => s.header_len(),
+            Some(LinkSlice::LinuxSll2(s)) => s.header_len(),
+            Some(LinkSlice::EtherPayload(_)) | None => 0,
+        };
+        let vlan_len = match &self.vlan {
+            Some(VlanSlice::SingleVlan(s)) => s.header_len(),
+            Some(VlanSlice::DoubleVlan(d)) => d.header_len(),
+            None => 0,
+        };
+        let pppoe_len = self.pppoe.as_ref().map(|p| p.slice().len()).unwrap_or(0);
+        let net_len = match &self.net {
+            Some(NetSlice::Ipv4(ipv4)) => {
+                ipv4.header().slice().len()
+                    + ipv4
+                        .extensions()
+                        .auth
+                        .map(|auth| auth.slice().len())
+                        .unwrap_or(0)
+            }
+            Some(NetSlice::Ipv6(ipv6)) => {
+                ipv6.header().slice().len() + ipv6.extensions().slice().len()
+            }
+            Some(NetSlice::Arp(arp)) => arp.slice().len(),
+            None => 0,
+        };
+        let transport_len = match &self.transport {
+            Some(TransportSlice::Icmpv4(s)) => s.header_len(),
+            Some(TransportSlice::Icmpv6(s)) => s.header_len(),
+            Some(TransportSlice::Udp(s)) => s.header_slice().len(),
+            Some(TransportSlice::Tcp(s)) => s.header_slice().len(),
+            Some(TransportSlice::Igmp(s)) => s.slice().len(),
+            Some(TransportSlice::Esp(_)) => EspHeader::LEN,
+            Some(TransportSlice::Dccp(s)) => s.header_len(),
+            None => 0,
+        };
+        link_len + vlan_len + pppoe_len + net_len + transport_len
+    }
+
+    /// Returns the offset of the payload of the most inner decoded layer
+    /// relative to the start of the sliced data.
+    ///
+    /// As all decoded headers directly follow each other this is the
+    /// same value as [`SlicedPacket::header_len`].
+    #[inline]
+    pub fn payload_offset(&self) -> usize {
+        self.header_len()
+    }
+
+    /// Serializes the headers & the payload of the sliced layers into a
+    /// newly allocated vector.
+    ///
+    /// The bytes of all headers present in the sliced packet are
+    /// concatenated, followed by the payload of the most inner decoded
+    /// layer. Data that is not part of any layer (e.g. ethernet padding
+    /// after an IP packet) is not included.
+    ///
+    /// If `recompute_checksums` is set to `true` the IPv4 header checksum
+    /// and the UDP, TCP, ICMPv4, ICMPv6, IGMP & DCCP checksums are
+    /// recalculated in the returned bytes. A zero UDP checksum in an IPv4 packet is kept
+    /// as it indicates that the checksum is not used. If `recompute_checksums`
+    /// is `false` the checksums are copied unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{PacketBuilder, SlicedPacket};
+    ///
+    /// let builder = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+    ///     .udp(21, 1234);
+    /// let mut packet = Vec::<u8>::with_capacity(builder.size(4));
+    /// builder.write(&mut packet, &[1, 2, 3, 4]).unwrap();
+    ///
+    /// // change the destination port
+    /// packet[22..24].copy_from_slice(&4321u16.to_be_bytes());
+    ///
+    /// // re-serialize with a corrected UDP checksum
+    /// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+    /// let rebuilt = sliced.to_vec(true);
+    /// assert_eq!(&rebuilt[22..24], &4321u16.to_be_bytes());
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_vec(&self, recompute_checksums: bool) -> std::vec::Vec<u8> {
+        let mut result = std::vec::Vec::new();
+
+        // link layer & vlan headers
+        match &self.link {
+            Some(LinkSlice::Ethernet2(eth)) => result.extend_from_slice(eth.header_slice()),
+            Some(LinkSlice::Ieee8023(s)) => result.extend_from_slice(s.header_slice()),
+            Some(LinkSlice::LinuxSll(s)) => result.extend_from_slice(s.header_slice()),
+            Some(LinkSlice::LinuxSll2(s)) => result.extend_from_slice(s.header_slice()),
+            _ => {}
+        }
+        match &self.vlan {
+            Some(VlanSlice::SingleVlan(s)) => result.extend_from_slice(s.header_slice()),
+            Some(VlanSlice::DoubleVlan(d)) => {
+                result.extend_from_slice(d.outer().header_slice());
+                result.extend_from_slice(d.inner().header_slice());
+            }
+            None => {}
+        }
+        if let Some(pppoe) = &self.pppoe {
+            result.extend_from_slice(pppoe.slice());
+        }
+
+        // network layer
+        let net_start = result.len();
+        match &self.net {
+            Some(NetSlice::Ipv4(ipv4)) => {
+                result.extend_from_slice(ipv4.header().slice());
+                if let Some(auth) = ipv4.extensions().auth {
+                    result.extend_from_slice(auth.slice());
+                }
+            }
+            Some(NetSlice::Ipv6(ipv6)) => {
+                result.extend_from_slice(ipv6.header().slice());
+                result.extend_from_slice(ipv6.extensions().slice());
+            }
+            Some(NetSlice::Arp(arp)) => result.extend_from_slice(arp.slice()),
+            None => {}
+        }
+
+        // transport layer or the payload of the most inner layer
+        let transport_start = result.len();
+        if let Some(transport) = &self.transport {
+            use TransportSlice::*;
+            result.extend_from_slice(match transport {
+                Icmpv4(s) => s.slice(),
+                Icmpv6(s) => s.slice(),
+                Udp(s) => s.slice(),
+                Tcp(s) => s.slice(),
+                Igmp(s) => s.slice(),
+                Esp(s) => s.slice(),
+                Dccp(s) => s.slice(),
+            });
+        } else if let Some(ip_payload) = self.ip_payload() {
+            result.extend_from_slice(ip_payload.payload);
+        } else if self.net.is_none() {
+            if let Some(ether_payload) = self.ether_payload() {
+                let payload = ether_payload.payload;
+                if let Some(pppoe) = &self.pppoe {
+                    // skip the pppoe header & cut off data after the pppoe payload
+                    let rest = &payload[pppoe.slice().len().min(payload.len())..];
+                    result.extend_from_slice(&rest[..pppoe.payload_len().min(rest.len())]);
+                } else {
+                    result.extend_from_slice(payload);
+                }
+            }
+        }
+
+        if recompute_checksums {
+            if let Some(NetSlice::Ipv4(ipv4)) = &self.net {
+                let checksum = ipv4.header().to_header().calc_header_checksum();
+                result[net_start + 10..net_start + 12].copy_from_slice(&checksum.to_be_bytes());
+            }
+            if let Some((offset, checksum)) = self.calc_transport_checksum() {
+                let start = transport_start + offset;
+                result[start..start + 2].copy_from_slice(&checksum.to_be_bytes());
+            }
+        }
+
+        result
+    }
+
+    /// Copies the headers into their owned header structs & the payload
+    /// into a `Vec` (see [`OwnedPacketHeaders`] for details).
+    ///
+    /// The returned value does not borrow the sliced buffer, VLAN tags
+    /// and IP extension headers are preserved.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_owned_headers(&self) -> OwnedPacketHeaders {
+        OwnedPacketHeaders::from(self)
+    }
+
+    /// Writes the headers & the payload of the sliced layers to the
+    /// given writer (see [`SlicedPacket::to_vec`] for details).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(
+        &self,
+        writer: &mut T,
+        recompute_checksums: bool,
+    ) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_vec(recompute_checksums))
+    }
+
+    /// Calculates the checksum of the transport layer and returns it together
+    /// with the offset of the checksum field in the transport layer.
+    ///
+    /// Returns `None` if no checksum can be calculated (e.g. unknown IP
+    /// addresses or the checksum is not used). ESP has no transport layer
+    /// checksum, so `None` is always returned for it.
+    pub(crate) fn calc_transport_checksum(&self) -> Option<(usize, u16)> {
+        use TransportSlice::*;
+
+        let (ipv4_addrs, ipv6_addrs) = match &self.net {
+            Some(NetSlice::Ipv4(ipv4)) => (
+                Some((ipv4.header().source(), ipv4.header().destination())),
+                None,
+            ),
+            Some(NetSlice::Ipv6(ipv6)) => (
+                None,
+                Some((ipv6.header().source(), ipv6.header().destination())),
+            ),
+            _ => (None, None),
+        };
+
+        match self.transport.as_ref()? {
+            Udp(udp) => {
+                let header = udp.to_header();
+                let checksum = if let Some((source, destination)) = ipv4_addrs {
+                    if 0 == header.checksum {
+                        // zero indicates that no checksum is used
+                        return None;
+                    }
+                    header.calc_checksum_ipv4_raw(source, destination, udp.payload())
+                } else {
+                    let (source, destination) = ipv6_addrs?;
+                    header.calc_checksum_ipv6_raw(source, destination, udp.payload())
+                };
+                checksum.ok().map(|checksum| (6, checksum))
+            }
+            Tcp(tcp) => {
+                let header = tcp.to_header();
+                let checksum = if let Some((source, destination)) = ipv4_addrs {
+                    header.calc_checksum_ipv4_raw(source, destination, tcp.payload())
+                } else {
+                    let (source, destination) = ipv6_addrs?;
+                    header.calc_checksum_ipv6_raw(source, destination, tcp.payload())
+                };
+                checksum.ok().map(|checksum| (16, checksum))
+            }
+            Icmpv4(icmp) => Some((2, icmp.icmp_type().calc_checksum(icmp.payload()))),
+            Icmpv6(icmp) => {
+                let (source, destination) = ipv6_addrs?;
+                icmp.icmp_type()
+                    .calc_checksum(source, destination, icmp.payload())
+                    .ok()
+                    .map(|checksum| (2, checksum))
+            }
+            Igmp(igmp) => Some((2, igmp.calc_checksum())),
+            Dccp(dccp) => {
+                let checksum = if let Some((source, destination)) = ipv4_addrs {
+                    dccp.calc_checksum_ipv4(source, destination)
+                } else {
+                    let (source, destination) = ipv6_addrs?;
+                    dccp.calc_checksum_ipv6(source, destination)
+                };
+                checksum.ok().map(|checksum| (6, checksum))
+            }
+            Esp(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::err::{packet::SliceError, Layer, LenError};
+    use crate::test_gens::*;
+    use crate::test_packet::TestPacket;
+    use proptest::prelude::*;
+
+    const VLAN_ETHER_TYPES: [EtherType; 3] = [
+        ether_type::VLAN_TAGGED_FRAME,
+        ether_type::PROVIDER_BRIDGING,
+        ether_type::VLAN_DOUBLE_TAGGED_FRAME,
+    ];
+
+    #[test]
+    fn clone_eq() {
+        let header = SlicedPacket {
+            link: None,
+            vlan: None,
+            pppoe: None,
+            net: None,
+            transport: None,
+            vxlan: None,
+            gtpu: None,
+            geneve: None,
+        };
+        assert_eq!(header.clone(), header);
+    }
+
+    #[test]
+    fn debug() {
+        use alloc::format;
+        let header = SlicedPacket {
+            link: None,
+            vlan: None,
+            pppoe: None,
+            net: None,
+            transport: None,
+            vxlan: None,
+            gtpu: None,
+            geneve: None,
+        };
+        assert_eq!(
+            format!("{:?}", header),
+            format!(
+                "SlicedPacket {{ link: {:?}, vlan: {:?}, pppoe: {:?}, net: {:?}, transport: {:?}, vxlan: {:?}, gtpu: {:?}, geneve: {:?} }}",
+                header.link, header.vlan, header.pppoe, header.net, header.transport, header.vxlan, header.gtpu, header.geneve,
+            )
+        );
+    }
+
+    #[test]
+    fn ether_payload() {
+        use alloc::vec::*;
+
+        // no content
+        assert_eq!(
+            SlicedPacket {
+                link: None,
+                vlan: None,
+                pppoe: None,
+                net: None,
+                transport: None,
+                vxlan: None,
+                gtpu: None,
+                geneve: None,
+            }
+            .ether_payload(),
+            None
+        );
+
+        // only ethernet header II
+        {
+            let payload = [1, 2, 3, 4];
+            let mut buf = Vec::with_capacity(Ethernet2Header::LEN + 4);
+            buf.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type: EtherType::WAKE_ON_LAN,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            buf.extend_from_slice(&payload);
+            assert_eq!(
+                SlicedPacket::from_ethernet(&buf).unwrap().ether_payload(),
+                Some(EtherPayloadSlice {
+                    ether_type: EtherType::WAKE_ON_LAN,
+                    payload: &payload
+                })
+            );
+        }
+
+        // ether type payload
+        {
+            let payload = [1, 2, 3, 4];
+            assert_eq!(
+                SlicedPacket {
+                    link: Some(LinkSlice::EtherPayload(EtherPayloadSlice {
+                        ether_type: EtherType::WAKE_ON_LAN,
+                        payload: &payload
+                    })),
+                    vlan: None,
+                    pppoe: None,
+                    net: None,
+                    transport: None,
+                    vxlan: None,
+                    gtpu: None,
+                    geneve: None,
+                }
+                .ether_payload(),
+                Some(EtherPayloadSlice {
+                    ether_type: EtherType::WAKE_ON_LAN,
+                    payload: &payload
+                })
+            );
+        }
+
+        // single vlan header
+        {
+            let payload = [1, 2, 3, 4];
+            let mut buf = Vec::with_capacity(Ethernet2Header::LEN + SingleVlanHeader::LEN + 4);
+            buf.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type: EtherType::VLAN_TAGGED_FRAME,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            buf.extend_from_slice(
+                &SingleVlanHeader {
+                    ether_type: EtherType::WAKE_ON_LAN,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            buf.extend_from_slice(&payload);
+            assert_eq!(
+                SlicedPacket::from_ethernet(&buf).unwrap().ether_payload(),
+                Some(EtherPayloadSlice {
+                    ether_type: EtherType::WAKE_ON_LAN,
+                    payload: &payload
+                })
+            );
+        }
+
+        // double vlan header
+        {
+            let payload = [1, 2, 3, 4];
+            let mut buf = Vec::with_capacity(Ethernet2Header::LEN + SingleVlanHeader::LEN * 2 + 4);
+            buf.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type: EtherType::VLAN_DOUBLE_TAGGED_FRAME,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            buf.extend_from_slice(
+                &SingleVlanHeader {
+                    ether_type: EtherType::VLAN_TAGGED_FRAME,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            buf.extend_from_slice(
+                &SingleVlanHeader {
+                    ether_type: EtherType::WAKE_ON_LAN,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            buf.extend_from_slice(&payload);
+            assert_eq!(
+                SlicedPacket::from_ethernet(&buf).unwrap().ether_payload(),
+                Some(EtherPayloadSlice {
+                    ether_type: EtherType::WAKE_ON_LAN,
+                    payload: &payload
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn ip_payload() {
+        use alloc::vec::*;
+
+        // no content
+        assert_eq!(
+            SlicedPacket {
+                link: None,
+                vlan: None,
+                pppoe: None,
+                net: None,
+                transport: None,
+                vxlan: None,
+                gtpu: None,
+                geneve: None,
+            }
+            .ip_payload(),
+            None
+        );
+
+        // ipv4
+        {
+            let payload = [1, 2, 3, 4];
+            let mut buf = Vec::with_capacity(Ipv4Header::MIN_LEN + 4);
+            buf.extend_from_slice(
+                &Ipv4Header {
+                    protocol: IpNumber::ARIS,
+                    total_len: Ipv4Header::MIN_LEN_U16 + 4,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            buf.extend_from_slice(&payload);
+            assert_eq!(
+                SlicedPacket::from_ip(&buf).unwrap().ip_payload(),
+                Some(&IpPayloadSlice {
+                    payload: &payload,
+                    ip_number: IpNumber::ARIS,
+                    fragmented: false,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                })
+            );
+        }
+
+        // ipv6
+        {
+            let payload = [1, 2, 3, 4];
+            let mut buf = Vec::with_capacity(Ipv6Header::LEN + 4);
+            buf.extend_from_slice(
+                &Ipv6Header {
+                    payload_length: 4,
+                    next_header: IpNumber::ARGUS,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            buf.extend_from_slice(&payload);
+            assert_eq!(
+                SlicedPacket::from_ip(&buf).unwrap().ip_payload(),
+                Some(&IpPayloadSlice {
+                    payload: &payload,
+                    ip_number: IpNumber::ARGUS,
+                    fragmented: false,
+                    len_source: LenSource::Ipv6HeaderPayloadLen,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn flow_identifier() {
+        use alloc::vec::Vec;
+
+        // ipv4 udp
+        {
+            let builder = PacketBuilder::ethernet2([0; 6], [0; 6])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(1234, 53);
+            let mut buf = Vec::with_capacity(builder.size(0));
+            builder.write(&mut buf, &[]).unwrap();
+            assert_eq!(
+                SlicedPacket::from_ethernet(&buf).unwrap().flow_identifier(),
+                Some(FlowTuple::Ipv4 {
+                    source: [1, 2, 3, 4],
+                    destination: [5, 6, 7, 8],
+                    source_port: 1234,
+                    destination_port: 53,
+                    protocol: ip_number::UDP,
+                })
+            );
+        }
+
+        // ipv6 tcp
+        {
+            let builder = PacketBuilder::ipv6([1; 16], [2; 16], 20).tcp(80, 1234, 0, 0);
+            let mut buf = Vec::with_capacity(builder.size(0));
+            builder.write(&mut buf, &[]).unwrap();
+            assert_eq!(
+                SlicedPacket::from_ip(&buf).unwrap().flow_identifier(),
+                Some(FlowTuple::Ipv6 {
+                    source: [1; 16],
+                    destination: [2; 16],
+                    source_port: 80,
+                    destination_port: 1234,
+                    protocol: ip_number::TCP,
+                })
+            );
+        }
+
+        // icmp (no ports)
+        {
+            let builder =
+                PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20).icmpv4_echo_request(1, 2);
+            let mut buf = Vec::with_capacity(builder.size(0));
+            builder.write(&mut buf, &[]).unwrap();
+            assert_eq!(SlicedPacket::from_ip(&buf).unwrap().flow_identifier(), None);
+        }
+
+        // ip payload without a decoded transport layer
+        {
+            let mut buf = Vec::with_capacity(Ipv4Header::MIN_LEN);
+            buf.extend_from_slice(
+                &Ipv4Header {
+                    protocol: IpNumber::ARIS,
+                    total_len: Ipv4Header::MIN_LEN_U16,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            assert_eq!(SlicedPacket::from_ip(&buf).unwrap().flow_identifier(), None);
+        }
+
+        // no net layer
+        assert_eq!(
+            SlicedPacket {
+                link: None,
+                vlan: None,
+                pppoe: None,
+                net: None,
+                transport: None,
+                vxlan: None,
+                gtpu: None,
+                geneve: None,
+            }
+            .flow_identifier(),
+            None
+        );
+    }
+
+    #[test]
+    fn layers() {
+        use alloc::vec::Vec;
+        use err::Layer;
+
+        // ethernet ii, vlan, ipv6 with extension headers & tcp
+        {
+            let builder = PacketBuilder::ethernet2([0; 6], [0; 6])
+                .single_vlan(VlanId::try_new(1).unwrap())
+                .ip(IpHeaders::Ipv6(
+                    Ipv6Header {
+                        next_header: ip_number::IPV6_HOP_BY_HOP,
+                        hop_limit: 20,
+                        ..Default::default()
+                    },
+                    Ipv6Extensions {
+                        hop_by_hop_options: Some(
+                            Ipv6RawExtHeader::new_raw(0.into(), &[1, 2, 3, 4, 5, 6]).unwrap(),
+                        ),
+                        destination_options: Some(
+                            Ipv6RawExtHeader::new_raw(0.into(), &[1, 2, 3, 4, 5, 6]).unwrap(),
+                        ),
+                        ..Default::default()
+                    },
+                ))
+                .tcp(1, 2, 3, 4);
+            let mut buf = Vec::with_capacity(builder.size(0));
+            builder.write(&mut buf, &[]).unwrap();
+            let layers = SlicedPacket::from_ethernet(&buf).unwrap().layers();
+            assert_eq!(
+                layers,
+                LayerSet::new()
+                    .with(Layer::Ethernet2Header)
+                    .with(Layer::VlanHeader)
+                    .with(Layer::Ipv6Header)
+                    .with(Layer::Ipv6HopByHopHeader)
+                    .with(Layer::Ipv6DestOptionsHeader)
+                    .with(Layer::TcpHeader)
+            );
+        }
+
+        // ipv4 with auth header & icmpv4
+        {
+            let builder = PacketBuilder::ip(IpHeaders::Ipv4(
+                Ipv4Header {
+                    protocol: ip_number::AUTH,
+                    time_to_live: 20,
+                    ..Default::default()
+                },
+                Ipv4Extensions {
+                    auth: Some(IpAuthHeader::new(0.into(), 1, 2, &[3, 4, 5, 6]).unwrap()),
+                },
+            ))
+            .icmpv4_echo_request(1, 2);
+            let mut buf = Vec::with_capacity(builder.size(0));
+            builder.write(&mut buf, &[]).unwrap();
+            assert_eq!(
+                SlicedPacket::from_ip(&buf).unwrap().layers(),
+                LayerSet::new()
+                    .with(Layer::Ipv4Header)
+                    .with(Layer::IpAuthHeader)
+                    .with(Layer::Icmpv4)
+            );
+        }
+
+        // ip payload without a decoded transport layer
+        {
+            let mut buf = Vec::with_capacity(Ipv4Header::MIN_LEN);
+            buf.extend_from_slice(
+                &Ipv4Header {
+                    protocol: IpNumber::ARIS,
+                    total_len: Ipv4Header::MIN_LEN_U16,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            assert_eq!(
+                SlicedPacket::from_ip(&buf).unwrap().layers(),
+                LayerSet::from(Layer::Ipv4Header)
+            );
+        }
+
+        // nothing decoded
+        assert!(SlicedPacket {
+            link: None,
+            vlan: None,
+            pppoe: None,
+            net: None,
+            transport: None,
+            vxlan: None,
+            gtpu: None,
+            geneve: None,
+        }
+        .layers()
+        .is_empty());
+    }
+
+    #[test]
+    fn vxlan() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4];
+
+        // inner ethernet frame
+        let inner_eth = Ethernet2Header {
+            source: [1, 2, 3, 4, 5, 6],
+            destination: [7, 8, 9, 10, 11, 12],
+            ether_type: EtherType::IPV4,
+        };
+        let inner_udp = UdpHeader {
+            source_port: 1234,
+            destination_port: 5678,
+            length: (UdpHeader::LEN + payload.len()) as u16,
+            checksum: 0,
+        };
+        let inner_ip = Ipv4Header::new(
+            inner_udp.length,
+            20,
+            ip_number::UDP,
+            [192, 168, 1, 1],
+            [192, 168, 1, 2],
+        )
+        .unwrap();
+        let vxlan = VxlanHeader::new(VxlanVni::try_new(0x123456).unwrap());
+
+        let mut vxlan_payload = Vec::new();
+        vxlan_payload.extend_from_slice(&vxlan.to_bytes());
+        vxlan_payload.extend_from_slice(&inner_eth.to_bytes());
+        vxlan_payload.extend_from_slice(&inner_ip.to_bytes());
+        vxlan_payload.extend_from_slice(&inner_udp.to_bytes());
+        vxlan_payload.extend_from_slice(&payload);
+
+        // helper to build the outer packet
+        let build = |destination_port: u16, udp_payload: &[u8]| -> Vec<u8> {
+            let udp = UdpHeader {
+                source_port: 49152,
+                destination_port,
+                length: (UdpHeader::LEN + udp_payload.len()) as u16,
+                checksum: 0,
+            };
+            let ip = Ipv4Header::new(udp.length, 20, ip_number::UDP, [10, 0, 0, 1], [10, 0, 0, 2])
+                .unwrap();
+            let mut result = Vec::new();
+            result.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type: EtherType::IPV4,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            result.extend_from_slice(&ip.to_bytes());
+            result.extend_from_slice(&udp.to_bytes());
+            result.extend_from_slice(udp_payload);
+            result
+        };
+
+        let options = ParseOptions {
+            vxlan_port: Some(VxlanHeader::IANA_PORT),
+            ..Default::default()
+        };
+
+        // vxlan decoding disabled
+        {
+            let data = build(VxlanHeader::IANA_PORT, &vxlan_payload);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert!(result.vxlan.is_none());
+            match result.transport {
+                Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &vxlan_payload[..]),
+                _ => panic!("expected udp"),
+            }
+        }
+
+        // vxlan decoding enabled but other port
+        {
+            let data = build(VxlanHeader::IANA_PORT + 1, &vxlan_payload);
+            let result = SlicedPacket::from_ethernet_with_options(&data, &options).unwrap();
+            assert!(result.vxlan.is_none());
+        }
+
+        // vxlan decoding enabled
+        {
+            let data = build(VxlanHeader::IANA_PORT, &vxlan_payload);
+            let results = [
+                SlicedPacket::from_ethernet_with_options(&data, &options).unwrap(),
+                SlicedPacket::from_ether_type_with_options(
+                    EtherType::IPV4,
+                    &data[Ethernet2Header::LEN..],
+                    &options,
+                )
+                .unwrap(),
+                SlicedPacket::from_ip_with_options(&data[Ethernet2Header::LEN..], &options)
+                    .unwrap(),
+            ];
+            for result in results {
+                let actual = result.vxlan.unwrap();
+                assert_eq!(actual.header.to_header(), vxlan);
+                match actual.link {
+                    Some(LinkSlice::Ethernet2(eth)) => assert_eq!(eth.to_header(), inner_eth),
+                    _ => panic!("expected ethernet 2"),
+                }
+                assert!(actual.vlan.is_none());
+                match actual.net {
+                    Some(NetSlice::Ipv4(ipv4)) => assert_eq!(ipv4.header().to_header(), inner_ip),
+                    _ => panic!("expected ipv4"),
+                }
+                match actual.transport {
+                    Some(TransportSlice::Udp(udp)) => {
+                        assert_eq!(udp.to_header(), inner_udp);
+                        assert_eq!(udp.payload(), &payload);
+                    }
+                    _ => panic!("expected udp"),
+                }
+            }
+        }
+
+        // vxlan header length error
+        {
+            let data = build(
+                VxlanHeader::IANA_PORT,
+                &vxlan_payload[..VxlanHeader::LEN - 1],
+            );
+            assert_eq!(
+                SlicedPacket::from_ethernet_with_options(&data, &options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: VxlanHeader::LEN,
+                    len: VxlanHeader::LEN - 1,
+                    len_source: LenSource::UdpHeaderLen,
+                    layer: Layer::VxlanHeader,
+                    layer_start_offset: Ethernet2Header::LEN + Ipv4Header::MIN_LEN + UdpHeader::LEN,
+                })
+            );
+        }
+
+        // inner ethernet length error
+        {
+            let data = build(
+                VxlanHeader::IANA_PORT,
+                &vxlan_payload[..VxlanHeader::LEN + 1],
+            );
+            assert_eq!(
+                SlicedPacket::from_ethernet_with_options(&data, &options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: Ethernet2Header::LEN,
+                    len: 1,
+                    len_source: LenSource::UdpHeaderLen,
+                    layer: Layer::Ethernet2Header,
+                    layer_start_offset: Ethernet2Header::LEN
+                        + Ipv4Header::MIN_LEN
+                        + UdpHeader::LEN
+                        + VxlanHeader::LEN,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn gtpu() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4];
+
+        // inner ip packet
+        let inner_udp = UdpHeader {
+            source_port: 1234,
+            destination_port: 5678,
+            length: (UdpHeader::LEN + payload.len()) as u16,
+            checksum: 0,
+        };
+        let inner_ip = Ipv4Header::new(
+            inner_udp.length,
+            20,
+            ip_number::UDP,
+            [192, 168, 1, 1],
+            [192, 168, 1, 2],
+        )
+        .unwrap();
+        let extensions = [1, 0x12, 0x34, 0];
+        let inner_len = Ipv4Header::MIN_LEN + usize::from(inner_udp.length);
+        let gtpu = GtpuHeader {
+            length: (4 + extensions.len() + inner_len) as u16,
+            teid: 0x1234_5678,
+            sequence_number: Some(1),
+            next_extension_header_type: Some(0x85),
+            ..Default::default()
+        };
+
+        let mut gtpu_payload = Vec::new();
+        gtpu_payload.extend_from_slice(&gtpu.to_bytes());
+        gtpu_payload.extend_from_slice(&extensions);
+        gtpu_payload.extend_from_slice(&inner_ip.to_bytes());
+        gtpu_payload.extend_from_slice(&inner_udp.to_bytes());
+        gtpu_payload.extend_from_slice(&payload);
+
+        // helper to build the outer packet
+        let build = |destination_port: u16, udp_payload: &[u8]| -> Vec<u8> {
+            let udp = UdpHeader {
+                source_port: GtpuHeader::IANA_PORT,
+                destination_port,
+                length: (UdpHeader::LEN + udp_payload.len()) as u16,
+                checksum: 0,
+            };
+            let ip = Ipv4Header::new(udp.length, 20, ip_number::UDP, [10, 0, 0, 1], [10, 0, 0, 2])
+                .unwrap();
+            let mut result = Vec::new();
+            result.extend_from_slice(&ip.to_bytes());
+            result.extend_from_slice(&udp.to_bytes());
+            result.extend_from_slice(udp_payload);
+            result
+        };
+        let gtpu_offset = Ipv4Header::MIN_LEN + UdpHeader::LEN;
+
+        let options = ParseOptions {
+            gtpu_port: Some(GtpuHeader::IANA_PORT),
+            ..Default::default()
+        };
+
+        // gtpu decoding disabled
+        {
+            let data = build(GtpuHeader::IANA_PORT, &gtpu_payload);
+            let result = SlicedPacket::from_ip(&data).unwrap();
+            assert!(result.gtpu.is_none());
+            match result.transport {
+                Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &gtpu_payload[..]),
+                _ => panic!("expected udp"),
+            }
+        }
+
+        // gtpu decoding enabled but other port
+        {
+            let data = build(GtpuHeader::IANA_PORT + 1, &gtpu_payload);
+            let result = SlicedPacket::from_ip_with_options(&data, &options).unwrap();
+            assert!(result.gtpu.is_none());
+        }
+
+        // vxlan takes precedence if the same port is configured (the
+        // GTP-U packet gets decoded as VXLAN packet with an invalid
+        // IEEE 802.3 frame as inner packet)
+        {
+            let data = build(GtpuHeader::IANA_PORT, &gtpu_payload);
+            let result = SlicedPacket::from_ip_with_options(
+                &data,
+                &ParseOptions {
+                    vxlan_port: Some(GtpuHeader::IANA_PORT),
+                    gtpu_port: Some(GtpuHeader::IANA_PORT),
+                    ..Default::default()
+                },
+            );
+            assert_eq!(
+                result.unwrap_err(),
+                err::packet::SliceError::Len(err::LenError {
+                    required_len: LlcHeader::MIN_LEN,
+                    len: 0,
+                    len_source: LenSource::Ieee8023Len,
+                    layer: err::Layer::LlcHeader,
+                    layer_start_offset: Ipv4Header::MIN_LEN
+                        + UdpHeader::LEN
+                        + VxlanHeader::LEN
+                        + Ieee8023Slice::MAC_HEADER_LEN,
+                })
+            );
+        }
+
+        // g-pdu
+        {
+            let data = build(GtpuHeader::IANA_PORT, &gtpu_payload);
+            let result = SlicedPacket::from_ip_with_options(&data, &options).unwrap();
+            let actual = result.gtpu.unwrap();
+            assert_eq!(actual.header.to_header(), gtpu);
+            assert_eq!(actual.extensions, &extensions);
+            assert_eq!(
+                actual.payload,
+                &gtpu_payload[gtpu.header_len() + extensions.len()..]
+            );
+            let mut ext_iter = actual.extension_headers();
+            assert_eq!(ext_iter.next().unwrap().unwrap().content(), &[0x12, 0x34]);
+            assert!(ext_iter.next().is_none());
+            match actual.net {
+                Some(NetSlice::Ipv4(ipv4)) => assert_eq!(ipv4.header().to_header(), inner_ip),
+                _ => panic!("expected ipv4"),
+            }
+            match actual.transport {
+                Some(TransportSlice::Udp(udp)) => {
+                    assert_eq!(udp.to_header(), inner_udp);
+                    assert_eq!(udp.payload(), &payload);
+                }
+                _ => panic!("expected udp"),
+            }
+        }
+
+        // other message type (payload not decoded) & trailing data
+        {
+            let header = GtpuHeader {
+                message_type: GtpuHeader::MESSAGE_TYPE_ECHO_REQUEST,
+                length: 2,
+                ..Default::default()
+            };
+            let mut udp_payload = Vec::new();
+            udp_payload.extend_from_slice(&header.to_bytes());
+            udp_payload.extend_from_slice(&[1, 2, 3]);
+            let data = build(GtpuHeader::IANA_PORT, &udp_payload);
+            let result = SlicedPacket::from_ip_with_options(&data, &options).unwrap();
+            let actual = result.gtpu.unwrap();
+            assert_eq!(actual.header.to_header(), header);
+            assert_eq!(actual.extensions, &[]);
+            assert_eq!(actual.payload, &[1, 2]);
+            assert!(actual.net.is_none());
+            assert!(actual.transport.is_none());
+        }
+
+        // gtpu header length error
+        {
+            let data = build(
+                GtpuHeader::IANA_PORT,
+                &gtpu_payload[..GtpuHeader::MIN_LEN - 1],
+            );
+            assert_eq!(
+                SlicedPacket::from_ip_with_options(&data, &options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: GtpuHeader::MIN_LEN,
+                    len: GtpuHeader::MIN_LEN - 1,
+                    len_source: LenSource::UdpHeaderLen,
+                    layer: Layer::GtpuHeader,
+                    layer_start_offset: gtpu_offset,
+                })
+            );
+        }
+
+        // length field bigger then the available data
+        {
+            let data = build(
+                GtpuHeader::IANA_PORT,
+                &gtpu_payload[..gtpu_payload.len() - 1],
+            );
+            assert_eq!(
+                SlicedPacket::from_ip_with_options(&data, &options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: gtpu_payload.len(),
+                    len: gtpu_payload.len() - 1,
+                    len_source: LenSource::UdpHeaderLen,
+                    layer: Layer::GtpuPayload,
+                    layer_start_offset: gtpu_offset,
+                })
+            );
+        }
+
+        // length field smaller then the optional fields
+        {
+            let mut udp_payload = gtpu_payload.clone();
+            udp_payload[2..4].copy_from_slice(&3u16.to_be_bytes());
+            let data = build(GtpuHeader::IANA_PORT, &udp_payload);
+            assert_eq!(
+                SlicedPacket::from_ip_with_options(&data, &options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: GtpuHeader::MAX_LEN,
+                    len: GtpuHeader::MIN_LEN + 3,
+                    len_source: LenSource::GtpuHeaderLen,
+                    layer: Layer::GtpuHeader,
+                    layer_start_offset: gtpu_offset,
+                })
+            );
+        }
+
+        // extension header length error
+        {
+            let mut udp_payload = gtpu_payload.clone();
+            udp_payload[2..4].copy_from_slice(&6u16.to_be_bytes());
+            let data = build(GtpuHeader::IANA_PORT, &udp_payload);
+            assert_eq!(
+                SlicedPacket::from_ip_with_options(&data, &options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: 4,
+                    len: 2,
+                    len_source: LenSource::GtpuHeaderLen,
+                    layer: Layer::GtpuHeader,
+                    layer_start_offset: gtpu_offset + GtpuHeader::MAX_LEN,
+                })
+            );
+        }
+
+        // inner ip length error
+        {
+            let mut udp_payload = gtpu_payload.clone();
+            let len = 4 + extensions.len() + 1;
+            udp_payload[2..4].copy_from_slice(&(len as u16).to_be_bytes());
+            let data = build(GtpuHeader::IANA_PORT, &udp_payload);
+            assert_eq!(
+                SlicedPacket::from_ip_with_options(&data, &options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: Ipv4Header::MIN_LEN,
+                    len: 1,
+                    len_source: LenSource::GtpuHeaderLen,
+                    layer: Layer::Ipv4Header,
+                    layer_start_offset: gtpu_offset + GtpuHeader::MAX_LEN + extensions.len(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn geneve() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4];
+
+        // inner packet
+        let inner_eth = Ethernet2Header {
+            source: [1, 2, 3, 4, 5, 6],
+            destination: [7, 8, 9, 10, 11, 12],
+            ether_type: EtherType::IPV4,
+        };
+        let inner_udp = UdpHeader {
+            source_port: 1234,
+            destination_port: 5678,
+            length: (UdpHeader::LEN + payload.len()) as u16,
+            checksum: 0,
+        };
+        let inner_ip = Ipv4Header::new(
+            inner_udp.length,
+            20,
+            ip_number::UDP,
+            [192, 168, 1, 1],
+            [192, 168, 1, 2],
+        )
+        .unwrap();
+        let options = [0x01, 0x02, 0x80, 1, 1, 2, 3, 4];
+
+        // helper to build the geneve header, options & inner packet
+        let build_geneve = |protocol_type: EtherType| -> Vec<u8> {
+            let header = GeneveHeader {
+                opt_len: (options.len() / 4) as u8,
+                critical: true,
+                protocol_type,
+                vni: VxlanVni::try_new(0x123456).unwrap(),
+                ..Default::default()
+            };
+            let mut result = Vec::new();
+            result.extend_from_slice(&header.to_bytes());
+            result.extend_from_slice(&options);
+            if EtherType::TRANSPARENT_ETHERNET_BRIDGING == protocol_type {
+                result.extend_from_slice(&inner_eth.to_bytes());
+            }
+            result.extend_from_slice(&inner_ip.to_bytes());
+            result.extend_from_slice(&inner_udp.to_bytes());
+            result.extend_from_slice(&payload);
+            result
+        };
+
+        // helper to build the outer packet
+        let build = |destination_port: u16, udp_payload: &[u8]| -> Vec<u8> {
+            let udp = UdpHeader {
+                source_port: 49152,
+                destination_port,
+                length: (UdpHeader::LEN + udp_payload.len()) as u16,
+                checksum: 0,
+            };
+            let ip = Ipv4Header::new(udp.length, 20, ip_number::UDP, [10, 0, 0, 1], [10, 0, 0, 2])
+                .unwrap();
+            let mut result = Vec::new();
+            result.extend_from_slice(&ip.to_bytes());
+            result.extend_from_slice(&udp.to_bytes());
+            result.extend_from_slice(udp_payload);
+            result
+        };
+        let geneve_offset = Ipv4Header::MIN_LEN + UdpHeader::LEN;
+
+        let parse_options = ParseOptions {
+            geneve_port: Some(GeneveHeader::IANA_PORT),
+            ..Default::default()
+        };
+
+        // geneve decoding disabled
+        {
+            let geneve_payload = build_geneve(EtherType::TRANSPARENT_ETHERNET_BRIDGING);
+            let data = build(GeneveHeader::IANA_PORT, &geneve_payload);
+            let result = SlicedPacket::from_ip(&data).unwrap();
+            assert!(result.geneve.is_none());
+            match result.transport {
+                Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &geneve_payload[..]),
+                _ => panic!("expected udp"),
+            }
+        }
+
+        // geneve decoding enabled but other port
+        {
+            let data = build(
+                GeneveHeader::IANA_PORT + 1,
+                &build_geneve(EtherType::TRANSPARENT_ETHERNET_BRIDGING),
+            );
+            let result = SlicedPacket::from_ip_with_options(&data, &parse_options).unwrap();
+            assert!(result.geneve.is_none());
+        }
+
+        // vxlan takes precedence
+        {
+            let data = build(
+                GeneveHeader::IANA_PORT,
+                &build_geneve(EtherType::TRANSPARENT_ETHERNET_BRIDGING),
+            );
+            let result = SlicedPacket::from_ip_with_options(
+                &data,
+                &ParseOptions {
+                    vxlan_port: Some(GeneveHeader::IANA_PORT),
+                    geneve_port: Some(GeneveHeader::IANA_PORT),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            assert!(result.vxlan.is_some());
+            assert!(result.geneve.is_none());
+        }
+
+        // encapsulated ethernet frame
+        {
+            let geneve_payload = build_geneve(EtherType::TRANSPARENT_ETHERNET_BRIDGING);
+            let data = build(GeneveHeader::IANA_PORT, &geneve_payload);
+            let result = SlicedPacket::from_ip_with_options(&data, &parse_options).unwrap();
+            let actual = result.geneve.unwrap();
+            assert_eq!(actual.header.slice(), &geneve_payload[..16]);
+            assert_eq!(actual.header.vni().value(), 0x123456);
+            assert!(actual.header.critical());
+            assert_eq!(&geneve_payload[16..], actual.payload);
+            let mut iter = actual.options();
+            assert_eq!(Some(Ok((0x0102, 0x80, &[1, 2, 3, 4][..]))), iter.next());
+            assert_eq!(None, iter.next());
+            match actual.link {
+                Some(LinkSlice::Ethernet2(eth)) => assert_eq!(eth.to_header(), inner_eth),
+                _ => panic!("expected ethernet 2"),
+            }
+            assert!(actual.vlan.is_none());
+            match actual.net {
+                Some(NetSlice::Ipv4(ipv4)) => assert_eq!(ipv4.header().to_header(), inner_ip),
+                _ => panic!("expected ipv4"),
+            }
+            match actual.transport {
+                Some(TransportSlice::Udp(udp)) => {
+                    assert_eq!(udp.to_header(), inner_udp);
+                    assert_eq!(udp.payload(), &payload);
+                }
+                _ => panic!("expected udp"),
+            }
+        }
+
+        // encapsulated ip packet
+        {
+            let data = build(GeneveHeader::IANA_PORT, &build_geneve(EtherType::IPV4));
+            let result = SlicedPacket::from_ip_with_options(&data, &parse_options).unwrap();
+            let actual = result.geneve.unwrap();
+            assert!(actual.link.is_none());
+            match actual.net {
+                Some(NetSlice::Ipv4(ipv4)) => assert_eq!(ipv4.header().to_header(), inner_ip),
+                _ => panic!("expected ipv4"),
+            }
+            match actual.transport {
+                Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &payload),
+                _ => panic!("expected udp"),
+            }
+        }
+
+        // unknown protocol type (payload not decoded)
+        {
+            let geneve_payload = build_geneve(EtherType(0x1234));
+            let data = build(GeneveHeader::IANA_PORT, &geneve_payload);
+            let result = SlicedPacket::from_ip_with_options(&data, &parse_options).unwrap();
+            let actual = result.geneve.unwrap();
+            assert_eq!(&geneve_payload[16..], actual.payload);
+            assert!(actual.link.is_none());
+            assert!(actual.net.is_none());
+            assert!(actual.transport.is_none());
+        }
+
+        // header length error (options longer than the udp payload)
+        {
+            let geneve_payload = build_geneve(EtherType::IPV4);
+            let data = build(GeneveHeader::IANA_PORT, &geneve_payload[..12]);
+            assert_eq!(
+                SlicedPacket::from_ip_with_options(&data, &parse_options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: 16,
+                    len: 12,
+                    len_source: LenSource::UdpHeaderLen,
+                    layer: Layer::GeneveHeader,
+                    layer_start_offset: geneve_offset,
+                })
+            );
+        }
+
+        // option length error
+        {
+            let mut geneve_payload = build_geneve(EtherType::IPV4);
+            // option length bigger than the options
+            geneve_payload[GeneveHeader::MIN_LEN + 3] = 2;
+            let data = build(GeneveHeader::IANA_PORT, &geneve_payload);
+            assert_eq!(
+                SlicedPacket::from_ip_with_options(&data, &parse_options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: 12,
+                    len: 8,
+                    len_source: LenSource::GeneveHeaderOptLen,
+                    layer: Layer::GeneveHeader,
+                    layer_start_offset: geneve_offset + GeneveHeader::MIN_LEN,
+                })
+            );
+        }
+
+        // inner length error
+        {
+            let geneve_payload = build_geneve(EtherType::TRANSPARENT_ETHERNET_BRIDGING);
+            let data = build(GeneveHeader::IANA_PORT, &geneve_payload[..17]);
+            assert_eq!(
+                SlicedPacket::from_ip_with_options(&data, &parse_options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: Ethernet2Header::LEN,
+                    len: 1,
+                    len_source: LenSource::UdpHeaderLen,
+                    layer: Layer::Ethernet2Header,
+                    layer_start_offset: geneve_offset + 16,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn ieee8023() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4];
+        let udp = UdpHeader {
+            source_port: 1234,
+            destination_port: 5678,
+            length: (UdpHeader::LEN + payload.len()) as u16,
+            checksum: 0,
+        };
+        let ip = Ipv4Header::new(
+            udp.length,
+            20,
+            ip_number::UDP,
+            [192, 168, 1, 1],
+            [192, 168, 1, 2],
+        )
+        .unwrap();
+        let snap = SnapHeader {
+            oui: SnapHeader::OUI_ETHER_TYPE,
+            protocol_id: EtherType::IPV4.0,
+        };
+        let len = LlcHeader::MIN_LEN + SnapHeader::LEN + ip.header_len() + usize::from(udp.length);
+
+        let mut packet = Vec::new();
+        packet.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+        packet.extend_from_slice(&(len as u16).to_be_bytes());
+        packet.extend_from_slice(&LlcHeader::default().to_bytes());
+        packet.extend_from_slice(&snap.to_bytes());
+        packet.extend_from_slice(&ip.to_bytes());
+        packet.extend_from_slice(&udp.to_bytes());
+        packet.extend_from_slice(&payload);
+        // ethernet padding
+        packet.extend_from_slice(&[0, 0]);
+
+        // ip packet in snap header
+        {
+            let actual = SlicedPacket::from_ethernet(&packet).unwrap();
+            match actual.link.as_ref().unwrap() {
+                LinkSlice::Ieee8023(s) => {
+                    assert_eq!(s.llc(), LlcHeader::default());
+                    assert_eq!(s.snap(), Some(snap.clone()));
+                }
+                _ => panic!("expected ieee 802.3 link slice"),
+            }
+            assert_eq!(actual.header_len(), 14 + 8 + 20 + 8);
+            assert_eq!(
+                actual
+                    .net
+                    .as_ref()
+                    .unwrap()
+                    .ip_payload_ref()
+                    .unwrap()
+                    .payload,
+                &packet[14 + 8 + 20..packet.len() - 2]
+            );
+            match actual.transport.as_ref().unwrap() {
+                TransportSlice::Udp(u) => assert_eq!(u.payload(), &payload),
+                _ => panic!("expected udp"),
+            }
+        }
+
+        // llc without snap header (payload is not decoded)
+        {
+            let mut packet = packet.clone();
+            packet[14] = 0x42;
+            packet[15] = 0x42;
+            let actual = SlicedPacket::from_ethernet(&packet).unwrap();
+            assert_eq!(
+                actual.ether_payload(),
+                Some(EtherPayloadSlice {
+                    ether_type: EtherType(len as u16),
+                    payload: &packet[14 + 3..packet.len() - 2],
+                })
+            );
+            assert!(actual.net.is_none());
+            assert!(actual.transport.is_none());
+        }
+
+        // length error in the llc header
+        {
+            let mut packet = packet.clone();
+            packet[12..14].copy_from_slice(&2u16.to_be_bytes());
+            assert_eq!(
+                SlicedPacket::from_ethernet(&packet).unwrap_err(),
+                err::packet::SliceError::Len(err::LenError {
+                    required_len: LlcHeader::MIN_LEN,
+                    len: 2,
+                    len_source: LenSource::Ieee8023Len,
+                    layer: err::Layer::LlcHeader,
+                    layer_start_offset: 14,
+                })
+            );
+        }
+
+        // lax parsing
+        {
+            let actual = LaxSlicedPacket::from_ethernet(&packet).unwrap();
+            assert!(matches!(actual.link, Some(LinkSlice::Ieee8023(_))));
+            assert!(actual.stop_err.is_none());
+            match actual.transport.as_ref().unwrap() {
+                TransportSlice::Udp(u) => assert_eq!(u.payload(), &payload),
+                _ => panic!("expected udp"),
+            }
+        }
+    }
+
+    #[test]
+    fn lenient_ipv4_total_len() {
+        use alloc::vec::Vec;
+
+        let build = |ip_number: IpNumber| {
+            let mut result = Vec::new();
+            result.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type: EtherType::IPV4,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            let udp = UdpHeader {
+                source_port: 1,
+                destination_port: 2,
+                length: (UdpHeader::LEN + 4) as u16,
+                checksum: 0,
+            };
+            let ip =
+                Ipv4Header::new(udp.length, 20, ip_number, [1, 2, 3, 4], [5, 6, 7, 8]).unwrap();
+            result.extend_from_slice(&ip.to_bytes());
+            result.extend_from_slice(&udp.to_bytes());
+            result.extend_from_slice(&[1, 2, 3, 4]);
+            // truncate the packet (e.g. snaplen)
+            result.truncate(result.len() - 2);
+            result
+        };
+        let options = ParseOptions {
+            lenient_ipv4_total_len: true,
+            ..Default::default()
+        };
+        let ip_offset = Ethernet2Header::LEN;
+
+        // strict decoding
+        {
+            let data = build(ip_number::UDP);
+            assert_eq!(
+                SlicedPacket::from_ethernet(&data).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: Ipv4Header::MIN_LEN + UdpHeader::LEN + 4,
+                    len: Ipv4Header::MIN_LEN + UdpHeader::LEN + 2,
+                    len_source: LenSource::Slice,
+                    layer: Layer::Ipv4Packet,
+                    layer_start_offset: ip_offset,
+                })
+            );
+        }
+
+        // lenient decoding with a not decoded payload
+        {
+            let data = build(IpNumber(253));
+            let results = [
+                SlicedPacket::from_ethernet_with_options(&data, &options).unwrap(),
+                SlicedPacket::from_ether_type_with_options(
+                    EtherType::IPV4,
+                    &data[ip_offset..],
+                    &options,
+                )
+                .unwrap(),
+                SlicedPacket::from_ip_with_options(&data[ip_offset..], &options).unwrap(),
+            ];
+            for result in results {
+                match result.net {
+                    Some(NetSlice::Ipv4(ipv4)) => {
+                        assert_eq!(ipv4.header().total_len(), 20 + 8 + 4);
+                        assert_eq!(ipv4.payload().len_source, LenSource::Slice);
+                        assert_eq!(
+                            ipv4.payload().payload,
+                            &data[ip_offset + Ipv4Header::MIN_LEN..]
+                        );
+                    }
+                    _ => panic!("expected ipv4"),
+                }
+                assert!(result.transport.is_none());
+            }
+        }
+
+        // lenient decoding where the total length is big enough
+        {
+            let data = build(IpNumber(253));
+            let data = &data[ip_offset..];
+            let mut data = data.to_vec();
+            // shrink total length to the available data
+            let header = Ipv4Header {
+                total_len: data.len() as u16,
+                ..Ipv4HeaderSlice::from_slice(&data).unwrap().to_header()
+            };
+            data[..Ipv4Header::MIN_LEN].copy_from_slice(&header.to_bytes());
+            let result = SlicedPacket::from_ip_with_options(&data, &options).unwrap();
+            match result.net {
+                Some(NetSlice::Ipv4(ipv4)) => {
+                    assert_eq!(ipv4.payload().len_source, LenSource::Ipv4HeaderTotalLen);
+                }
+                _ => panic!("expected ipv4"),
+            }
+        }
+
+        // lenient decoding with length errors in a following layer
+        {
+            let data = build(ip_number::UDP);
+            assert_eq!(
+                SlicedPacket::from_ethernet_with_options(&data, &options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: UdpHeader::LEN + 4,
+                    len: UdpHeader::LEN + 2,
+                    len_source: LenSource::Slice,
+                    layer: Layer::UdpPayload,
+                    layer_start_offset: ip_offset + Ipv4Header::MIN_LEN,
+                })
+            );
+        }
+
+        // ipv4 header errors are reported as ip errors when decoding from ip
+        {
+            let mut data = build(IpNumber(253));
+            data[ip_offset] = 0x44;
+            assert_eq!(
+                SlicedPacket::from_ip_with_options(&data[ip_offset..], &options).unwrap_err(),
+                SlicedPacket::from_ip(&data[ip_offset..]).unwrap_err(),
+            );
+        }
+    }
+
+    #[test]
+    fn max_extension_headers() {
+        use alloc::vec::Vec;
+
+        // ipv6 packet with the given number of destination options headers
+        let build = |ext_count: usize| {
+            let mut exts = Vec::new();
+            for i in 0..ext_count {
+                let next = if i + 1 == ext_count {
+                    ip_number::UDP
+                } else {
+                    ip_number::IPV6_DEST_OPTIONS
+                };
+                exts.extend_from_slice(
+                    &Ipv6RawExtHeader::new_raw(next, &[0; 6]).unwrap().to_bytes(),
+                );
+            }
+            let udp = UdpHeader {
+                source_port: 1,
+                destination_port: 2,
+                length: UdpHeader::LEN as u16,
+                checksum: 0,
+            };
+            let ip = Ipv6Header {
+                payload_length: (exts.len() + UdpHeader::LEN) as u16,
+                next_header: ip_number::IPV6_DEST_OPTIONS,
+                ..Default::default()
+            };
+            let mut result = Vec::new();
+            result.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type: EtherType::IPV6,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            result.extend_from_slice(&ip.to_bytes());
+            result.extend_from_slice(&exts);
+            result.extend_from_slice(&udp.to_bytes());
+            result
+        };
+        let ip_offset = Ethernet2Header::LEN;
+
+        // default limit
+        {
+            let max = Ipv6ExtensionsIter::MAX_HEADERS;
+            let data = build(max);
+            assert!(SlicedPacket::from_ethernet(&data)
+                .unwrap()
+                .transport
+                .is_some());
+            assert!(SlicedPacket::from_ip(&data[ip_offset..])
+                .unwrap()
+                .transport
+                .is_some());
+
+            let data = build(max + 1);
+            let expected =
+                SliceError::Ipv6Exts(err::ipv6_exts::HeaderError::TooManyHeaders { max });
+            assert_eq!(SlicedPacket::from_ethernet(&data).unwrap_err(), expected);
+            assert_eq!(
+                SlicedPacket::from_ip(&data[ip_offset..]).unwrap_err(),
+                expected
+            );
+        }
+
+        // custom limit
+        {
+            let options = ParseOptions {
+                max_extension_headers: 2,
+                ..Default::default()
+            };
+            let data = build(2);
+            assert!(SlicedPacket::from_ethernet_with_options(&data, &options).is_ok());
+
+            let data = build(3);
+            let expected =
+                SliceError::Ipv6Exts(err::ipv6_exts::HeaderError::TooManyHeaders { max: 2 });
+            assert_eq!(
+                SlicedPacket::from_ethernet_with_options(&data, &options).unwrap_err(),
+                expected
+            );
+            assert_eq!(
+                SlicedPacket::from_ip_with_options(&data[ip_offset..], &options).unwrap_err(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn ip_chain_end() {
+        use alloc::vec::Vec;
+
+        // ipv6 without payload
+        {
+            let mut buf = Vec::new();
+            buf.extend_from_slice(
+                &Ipv6Header {
+                    next_header: ip_number::IPV6_NO_NEXT_HEADER,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            let sliced = SlicedPacket::from_ip(&buf).unwrap();
+            assert_eq!(Some(IpChainEnd::NoNextHeader), sliced.ip_chain_end());
+            assert!(sliced.transport.is_none());
+        }
+
+        // ipv6 extension header chain ending in "no next header"
+        {
+            let builder = PacketBuilder::ip(IpHeaders::Ipv6(
+                Ipv6Header {
+                    next_header: ip_number::IPV6_HOP_BY_HOP,
+                    ..Default::default()
+                },
+                Ipv6Extensions {
+                    hop_by_hop_options: Some(
+                        Ipv6RawExtHeader::new_raw(
+                            ip_number::IPV6_NO_NEXT_HEADER,
+                            &[1, 2, 3, 4, 5, 6],
+                        )
+                        .unwrap(),
+                    ),
+                    ..Default::default()
+                },
+            ));
+            let mut buf = Vec::with_capacity(builder.size(0));
+            builder
+                .write(&mut buf, ip_number::IPV6_NO_NEXT_HEADER, &[])
+                .unwrap();
+            assert_eq!(
+                Some(IpChainEnd::NoNextHeader),
+                SlicedPacket::from_ip(&buf).unwrap().ip_chain_end()
+            );
+        }
+
+        // transport protocol
+        {
+            let builder = PacketBuilder::ipv6([1; 16], [2; 16], 20).udp(1, 2);
+            let mut buf = Vec::with_capacity(builder.size(0));
+            builder.write(&mut buf, &[]).unwrap();
+            assert_eq!(
+                Some(IpChainEnd::Transport(ip_number::UDP)),
+                SlicedPacket::from_ip(&buf).unwrap().ip_chain_end()
+            );
+        }
+
+        // unknown protocol
+        {
+            let mut buf = Vec::new();
+            buf.extend_from_slice(
+                &Ipv4Header {
+                    protocol: IpNumber::ARIS,
+                    total_len: Ipv4Header::MIN_LEN_U16,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            assert_eq!(
+                Some(IpChainEnd::Unknown(IpNumber::ARIS)),
+                SlicedPacket::from_ip(&buf).unwrap().ip_chain_end()
+            );
+        }
+
+        // no ip layer
+        assert_eq!(
+            None,
+            SlicedPacket {
+                link: None,
+                vlan: None,
+                pppoe: None,
+                net: None,
+                transport: None,
+                vxlan: None,
+                gtpu: None,
+                geneve: None,
+            }
+            .ip_chain_end()
+        );
+    }
+
+    #[test]
+    fn from_ethernet_ip_only() {
+        use alloc::vec::Vec;
+
+        // ipv4 & tcp
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                .tcp(21, 1234, 1, 2);
+            let mut buf = Vec::with_capacity(builder.size(4));
+            builder.write(&mut buf, &[1, 2, 3, 4]).unwrap();
+
+            let full = SlicedPacket::from_ethernet(&buf).unwrap();
+            let actual = SlicedPacket::from_ethernet_ip_only(&buf).unwrap();
+            assert_eq!(full.link, actual.link);
+            assert_eq!(full.vlan, actual.vlan);
+            assert_eq!(full.net, actual.net);
+            assert!(full.transport.is_some());
+            assert_eq!(None, actual.transport);
+            assert_eq!(
+                actual.ip_payload().unwrap().payload,
+                &buf[Ethernet2Header::LEN + Ipv4Header::MIN_LEN..]
+            );
+
+            // errors in the transport header are not detected
+            let mut buf = buf.clone();
+            buf[Ethernet2Header::LEN + Ipv4Header::MIN_LEN + 12] = 2 << 4;
+            assert!(SlicedPacket::from_ethernet(&buf).is_err());
+            let actual = SlicedPacket::from_ethernet_ip_only(&buf).unwrap();
+            assert!(actual.net.is_some());
+            assert_eq!(None, actual.transport);
+        }
+
+        // vlan, ipv6 & udp
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .single_vlan(VlanId::try_new(1).unwrap())
+                .ipv6([1; 16], [2; 16], 20)
+                .udp(21, 1234);
+            let mut buf = Vec::with_capacity(builder.size(4));
+            builder.write(&mut buf, &[1, 2, 3, 4]).unwrap();
+
+            let full = SlicedPacket::from_ethernet(&buf).unwrap();
+            let actual = SlicedPacket::from_ethernet_ip_only(&buf).unwrap();
+            assert_eq!(full.link, actual.link);
+            assert_eq!(full.vlan, actual.vlan);
+            assert_eq!(full.net, actual.net);
+            assert_eq!(None, actual.transport);
+            assert_eq!(ip_number::UDP, actual.ip_payload().unwrap().ip_number);
+        }
+
+        // option also applies to packets starting with the ip header
+        {
+            let builder = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20).udp(1, 2);
+            let mut buf = Vec::with_capacity(builder.size(0));
+            builder.write(&mut buf, &[]).unwrap();
+            let options = ParseOptions {
+                stop_after_ip: true,
+                ..Default::default()
+            };
+            let actual = SlicedPacket::from_ip_with_options(&buf, &options).unwrap();
+            assert!(actual.net.is_some());
+            assert_eq!(None, actual.transport);
+        }
+    }
+
+    #[test]
+    fn strict_udp_len() {
+        use alloc::vec::Vec;
+
+        // build an ipv4 packet where the udp length is smaller then the ip payload
+        let build = |udp_length: u16| {
+            let mut result = Vec::new();
+            let udp = UdpHeader {
+                source_port: 1,
+                destination_port: 2,
+                length: udp_length,
+                checksum: 0,
+            };
+            let ip = Ipv4Header::new(
+                (UdpHeader::LEN + 4) as u16,
+                20,
+                ip_number::UDP,
+                [1, 2, 3, 4],
+                [5, 6, 7, 8],
+            )
+            .unwrap();
+            result.extend_from_slice(&ip.to_bytes());
+            result.extend_from_slice(&udp.to_bytes());
+            result.extend_from_slice(&[1, 2, 3, 4]);
+            result
+        };
+        let options = ParseOptions {
+            strict_udp_len: true,
+            ..Default::default()
+        };
+
+        // matching lengths & zero length
+        for udp_length in [(UdpHeader::LEN + 4) as u16, 0] {
+            let data = build(udp_length);
+            let actual = SlicedPacket::from_ip_with_options(&data, &options).unwrap();
+            match actual.transport {
+                Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &[1, 2, 3, 4]),
+                _ => panic!("expected udp"),
+            }
+        }
+
+        // udp length smaller then the ip payload
+        {
+            let data = build((UdpHeader::LEN + 2) as u16);
+
+            // accepted by default
+            let actual = SlicedPacket::from_ip(&data).unwrap();
+            match actual.transport {
+                Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &[1, 2]),
+                _ => panic!("expected udp"),
+            }
+
+            // error if strict
+            assert_eq!(
+                SlicedPacket::from_ip_with_options(&data, &options).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: UdpHeader::LEN + 2,
+                    len: UdpHeader::LEN + 4,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::UdpPayload,
+                    layer_start_offset: Ipv4Header::MIN_LEN,
+                })
+            );
+        }
+
+        // udp length bigger then the ip payload (always an error)
+        {
+            let data = build((UdpHeader::LEN + 6) as u16);
+            for actual in [
+                SlicedPacket::from_ip(&data).unwrap_err(),
+                SlicedPacket::from_ip_with_options(&data, &options).unwrap_err(),
+            ] {
+                assert_eq!(
+                    actual,
+                    SliceError::Len(LenError {
+                        required_len: UdpHeader::LEN + 6,
+                        len: UdpHeader::LEN + 4,
+                        len_source: LenSource::Ipv4HeaderTotalLen,
+                        layer: Layer::UdpPayload,
+                        layer_start_offset: Ipv4Header::MIN_LEN,
+                    })
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lenient_tcp_data_offset() {
+        use alloc::vec::Vec;
+
+        // build an ipv4 packet with the given tcp data offset
+        let build = |data_offset: u8| {
+            let mut result = Vec::new();
+            let mut tcp = TcpHeader::new(1, 2, 3, 4).to_bytes();
+            tcp[12] = data_offset << 4;
+            let ip = Ipv4Header::new(
+                (TcpHeader::MIN_LEN + 8) as u16,
+                20,
+                ip_number::TCP,
+                [1, 2, 3, 4],
+                [5, 6, 7, 8],
+            )
+            .unwrap();
+            result.extend_from_slice(&ip.to_bytes());
+            result.extend_from_slice(&tcp);
+            result.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+            result
+        };
+        let options = ParseOptions {
+            lenient_tcp_data_offset: true,
+            ..Default::default()
+        };
+
+        // data offset too small
+        {
+            let data = build(2);
+            assert_eq!(
+                SlicedPacket::from_ip(&data).unwrap_err(),
+                SliceError::Tcp(err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 2 })
+            );
+            let actual = SlicedPacket::from_ip_with_options(&data, &options).unwrap();
+            match actual.transport {
+                Some(TransportSlice::Tcp(tcp)) => {
+                    assert_eq!(tcp.header_len(), TcpHeader::MIN_LEN);
+                    assert_eq!(tcp.payload(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+                    assert_eq!(
+                        tcp.data_offset_error(),
+                        Some(err::tcp::HeaderSliceError::Content(
+                            err::tcp::HeaderError::DataOffsetTooSmall { data_offset: 2 }
+                        ))
+                    );
+                }
+                _ => panic!("expected tcp"),
+            }
+        }
+
+        // data offset pointing behind the ip payload
+        {
+            let data = build(9);
+            assert_eq!(
+                SlicedPacket::from_ip(&data).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: 36,
+                    len: 28,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::TcpHeader,
+                    layer_start_offset: Ipv4Header::MIN_LEN,
+                })
+            );
+            let actual = SlicedPacket::from_ip_with_options(&data, &options).unwrap();
+            match actual.transport {
+                Some(TransportSlice::Tcp(tcp)) => {
+                    assert_eq!(tcp.data_offset(), 9);
+                    assert_eq!(tcp.header_len(), 28);
+                    assert_eq!(tcp.options(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+                    assert!(tcp.payload().is_empty());
+                    assert!(tcp.data_offset_error().is_some());
+                }
+                _ => panic!("expected tcp"),
+            }
+        }
+
+        // header without options still required
+        {
+            let data = build(5);
+            let data = &data[..Ipv4Header::MIN_LEN + TcpHeader::MIN_LEN - 1];
+            assert!(SlicedPacket::from_ip_with_options(data, &options).is_err());
+        }
+    }
+
+    #[test]
+    fn fcs() {
+        use alloc::vec::Vec;
+
+        // ethernet ipv4 udp frame followed by the fcs
+        let frame = {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(1, 2);
+            let mut result = Vec::new();
+            builder.write(&mut result, &[1, 2, 3, 4]).unwrap();
+            result
+        };
+        let fcs = checksum::crc32_ethernet(&frame).to_le_bytes();
+        let mut data = frame.clone();
+        data.extend_from_slice(&fcs);
+
+        let options = ParseOptions {
+            has_fcs: true,
+            ..Default::default()
+        };
+        let validate = ParseOptions {
+            has_fcs: true,
+            validate_fcs: true,
+            ..Default::default()
+        };
+
+        // fcs excluded from the payload
+        for options in [&options, &validate] {
+            let actual = SlicedPacket::from_ethernet_with_options(&data, options).unwrap();
+            match actual.link.as_ref() {
+                Some(LinkSlice::Ethernet2(eth)) => {
+                    assert_eq!(eth.fcs(), Some(fcs));
+                    assert_eq!(eth.payload_slice(), &frame[Ethernet2Header::LEN..]);
+                }
+                _ => panic!("expected ethernet2"),
+            }
+            match actual.transport {
+                Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &[1, 2, 3, 4]),
+                _ => panic!("expected udp"),
+            }
+        }
+
+        // by default the fcs is treated as part of the frame
+        {
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            match actual.link.as_ref() {
+                Some(LinkSlice::Ethernet2(eth)) => assert_eq!(eth.fcs(), None),
+                _ => panic!("expected ethernet2"),
+            }
+        }
+
+        // invalid fcs
+        {
+            let mut data = data.clone();
+            let last = data.len() - 1;
+            data[last] ^= 0xff;
+            let received =
+                u32::from_le_bytes([data[last - 3], data[last - 2], data[last - 1], data[last]]);
+
+            // only detected if validation is enabled
+            assert!(SlicedPacket::from_ethernet_with_options(&data, &options).is_ok());
+            assert_eq!(
+                SlicedPacket::from_ethernet_with_options(&data, &validate).unwrap_err(),
+                SliceError::Fcs(err::FcsError {
+                    received,
+                    calculated: checksum::crc32_ethernet(&frame),
+                })
+            );
+        }
+
+        // length error
+        {
+            assert_eq!(
+                SlicedPacket::from_ethernet_with_options(
+                    &data[..Ethernet2Header::LEN + 3],
+                    &options
+                )
+                .unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: Ethernet2Header::LEN + 4,
+                    len: Ethernet2Header::LEN + 3,
+                    len_source: LenSource::Slice,
+                    layer: Layer::Ethernet2Header,
+                    layer_start_offset: 0,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn tcp_payload_len() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        // tcp with ipv4 options & ethernet padding
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ip(IpHeaders::Ipv4(
+                    Ipv4Header {
+                        time_to_live: 20,
+                        options: [1, 2, 3, 4].into(),
+                        ..Default::default()
+                    },
+                    Default::default(),
+                ))
+                .tcp(1, 2, 3, 4)
+                .options(&[TcpOptionElement::MaximumSegmentSize(1400)])
+                .unwrap();
+            let mut data = Vec::new();
+            builder.write(&mut data, &payload).unwrap();
+            data.extend_from_slice(&[0; 6]);
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(Some(payload.len()), actual.tcp_payload_len());
+        }
+
+        // tcp with ipv6
+        {
+            let builder = PacketBuilder::ipv6([0; 16], [0; 16], 20).tcp(1, 2, 3, 4);
+            let mut data = Vec::new();
+            builder.write(&mut data, &payload).unwrap();
+            let actual = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(Some(payload.len()), actual.tcp_payload_len());
+        }
+
+        // udp
+        {
+            let builder = PacketBuilder::ipv4([0; 4], [0; 4], 20).udp(1, 2);
+            let mut data = Vec::new();
+            builder.write(&mut data, &payload).unwrap();
+            let actual = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(None, actual.tcp_payload_len());
+        }
+    }
+
+    #[test]
+    fn jumbogram() {
+        use alloc::vec::Vec;
+
+        // ipv6 jumbogram (payload length set via the jumbo payload option
+        // in the hop by hop header & udp length set to zero)
+        let udp_payload = [0xab; 70_000];
+        let jumbo_len = 8 + UdpHeader::LEN + udp_payload.len();
+        let mut data = Vec::new();
+        data.extend_from_slice(
+            &Ipv6Header {
+                payload_length: 0,
+                next_header: ip_number::IPV6_HOP_BY_HOP,
+                ..Default::default()
+            }
+            .to_bytes(),
+        );
+        data.extend_from_slice(&[ip_number::UDP.0, 0, Ipv6Option::TYPE_JUMBO_PAYLOAD, 4]);
+        data.extend_from_slice(&(jumbo_len as u32).to_be_bytes());
+        data.extend_from_slice(
+            &UdpHeader {
+                source_port: 1,
+                destination_port: 2,
+                length: 0,
+                checksum: 0,
+            }
+            .to_bytes(),
+        );
+        data.extend_from_slice(&udp_payload);
+        // trailer
+        data.extend_from_slice(&[1, 2, 3]);
+
+        // sliced packet
+        {
+            let actual = SlicedPacket::from_ip(&data).unwrap();
+            assert_eq!(
+                LenSource::Ipv6JumboPayloadLen,
+                actual.ip_payload().unwrap().len_source
+            );
+            match &actual.transport {
+                Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &udp_payload[..]),
+                _ => panic!("expected udp"),
+            }
+        }
+
+        // lax sliced packet
+        {
+            let actual = LaxSlicedPacket::from_ip(&data).unwrap();
+            let ip_payload = actual.ip_payload().unwrap();
+            assert_eq!(LenSource::Ipv6JumboPayloadLen, ip_payload.len_source);
+            assert_eq!(false, ip_payload.incomplete);
+            assert_eq!(UdpHeader::LEN + udp_payload.len(), ip_payload.payload.len());
+        }
+
+        // packet headers
+        {
+            let actual = PacketHeaders::from_ip_slice(&data).unwrap();
+            assert_eq!(actual.payload.slice(), &udp_payload[..]);
+        }
+
+        // cut off packet
+        {
+            let len = data.len() - 4;
+            assert_eq!(
+                SlicedPacket::from_ip(&data[..len]).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: Ipv6Header::LEN + jumbo_len,
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: Layer::Ipv6Packet,
+                    layer_start_offset: 0,
+                })
+            );
+            let actual = LaxSlicedPacket::from_ip(&data[..len]).unwrap();
+            let ip_payload = actual.ip_payload().unwrap();
+            assert_eq!(LenSource::Slice, ip_payload.len_source);
+            assert!(ip_payload.incomplete);
+        }
+    }
+
+    #[test]
+    fn payload_and_trailer() {
+        use alloc::vec::Vec;
+
+        let eth_ipv4_udp = {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .udp(1, 2);
+            let mut result = Vec::new();
+            builder.write(&mut result, &[1, 2, 3, 4]).unwrap();
+            result
+        };
+
+        // udp with padding
+        {
+            let mut data = eth_ipv4_udp.clone();
+            data.extend_from_slice(&[0xff; 4]);
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(Some(PayloadSlice::Udp(&[1, 2, 3, 4])), actual.payload());
+            assert_eq!(Some(&[0xff; 4][..]), actual.trailer());
+        }
+
+        // udp length shorter than the ip payload
+        {
+            let mut data = eth_ipv4_udp.clone();
+            data[14 + 20 + 5] -= 2;
+            data.extend_from_slice(&[0xff; 4]);
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(Some(PayloadSlice::Udp(&[1, 2])), actual.payload());
+            assert_eq!(Some(&[3, 4, 0xff, 0xff, 0xff, 0xff][..]), actual.trailer());
+        }
+
+        // udp length field zero (fallback to the ipv4 total length)
+        {
+            let mut data = eth_ipv4_udp.clone();
+            data[14 + 20 + 4] = 0;
+            data[14 + 20 + 5] = 0;
+            data.extend_from_slice(&[0xff; 4]);
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(Some(PayloadSlice::Udp(&[1, 2, 3, 4])), actual.payload());
+            assert_eq!(Some(&[0xff; 4][..]), actual.trailer());
+        }
+
+        // ipv6 & tcp behind a vlan header
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .single_vlan(VlanId::try_new(1).unwrap())
+                .ipv6([1; 16], [2; 16], 20)
+                .tcp(1, 2, 3, 4);
+            let mut data = Vec::new();
+            builder.write(&mut data, &[1, 2, 3, 4]).unwrap();
+            data.extend_from_slice(&[5, 6]);
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(Some(PayloadSlice::Tcp(&[1, 2, 3, 4])), actual.payload());
+            assert_eq!(Some(&[5, 6][..]), actual.trailer());
+        }
+
+        // tcp, icmpv4 & icmpv6
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv6([1; 16], [2; 16], 20)
+                .tcp(1, 2, 3, 4);
+            let mut data = Vec::new();
+            builder.write(&mut data, &[1, 2, 3, 4]).unwrap();
+            data.push(5);
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(Some(PayloadSlice::Tcp(&[1, 2, 3, 4])), actual.payload());
+            assert_eq!(Some(&[5][..]), actual.trailer());
+        }
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv4([1, 2, 3, 4], [5, 6, 7, 8], 20)
+                .icmpv4_echo_request(1, 2);
+            let mut data = Vec::new();
+            builder.write(&mut data, &[1, 2, 3, 4]).unwrap();
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(Some(PayloadSlice::Icmpv4(&[1, 2, 3, 4])), actual.payload());
+            assert_eq!(Some(&[][..]), actual.trailer());
+        }
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12])
+                .ipv6([1; 16], [2; 16], 20)
+                .icmpv6_echo_request(1, 2);
+            let mut data = Vec::new();
+            builder.write(&mut data, &[1, 2, 3, 4]).unwrap();
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(Some(PayloadSlice::Icmpv6(&[1, 2, 3, 4])), actual.payload());
+            assert_eq!(Some(&[][..]), actual.trailer());
+        }
+
+        // ip payload of unknown transport protocols
+        {
+            let builder = PacketBuilder::ethernet2([1, 2, 3, 4, 5, 6], [7, 8, 9, 10, 11, 12]).ipv4(
+                [1, 2, 3, 4],
+                [5, 6, 7, 8],
+                20,
+            );
+            let mut data = Vec::new();
+            builder
+                .write(&mut data, ip_number::EXP0, &[1, 2, 3, 4])
+                .unwrap();
+            data.push(5);
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(Some(&[1, 2, 3, 4][..]), actual.payload().map(|p| p.slice()));
+            assert!(matches!(actual.payload(), Some(PayloadSlice::Ip(_))));
+            assert_eq!(Some(&[5][..]), actual.trailer());
+        }
+
+        // no link layer
+        {
+            let actual = SlicedPacket::from_ip(&eth_ipv4_udp[14..]).unwrap();
+            assert_eq!(Some(PayloadSlice::Udp(&[1, 2, 3, 4])), actual.payload());
+            assert_eq!(None, actual.trailer());
+        }
+
+        // unknown ether type
+        {
+            let mut data = eth_ipv4_udp.clone();
+            data[12] = 0xff;
+            data[13] = 0xff;
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(
+                Some(PayloadSlice::Ether(EtherPayloadSlice {
+                    ether_type: EtherType(0xffff),
+                    payload: &data[14..]
+                })),
+                actual.payload()
+            );
+            assert_eq!(Some(&[][..]), actual.trailer());
+        }
+
+        // arp with padding
+        {
+            let arp = ArpPacket::new(
+                ArpHardwareId::ETHERNET,
+                EtherType::IPV4,
+                ArpOperation::REQUEST,
+                &[1, 2, 3, 4, 5, 6],
+                &[7, 8, 9, 10],
+                &[0; 6],
+                &[11, 12, 13, 14],
+            )
+            .unwrap();
+            let mut data = Vec::new();
+            data.extend_from_slice(
+                &Ethernet2Header {
+                    source: [1; 6],
+                    destination: [2; 6],
+                    ether_type: EtherType::ARP,
+                }
+                .to_bytes(),
+            );
+            data.extend_from_slice(&arp.to_bytes());
+            data.extend_from_slice(&[0; 18]);
+            let actual = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(None, actual.payload());
+            assert_eq!(Some(&[0; 18][..]), actual.trailer());
+        }
+    }
+
+    #[test]
+    fn linux_sll() {
+        use alloc::vec::Vec;
+
+        let ip_udp = {
+            let builder = PacketBuilder::ipv6([1; 16], [2; 16], 20).udp(1, 2);
+            let mut result = Vec::new();
+            builder.write(&mut result, &[1, 2, 3, 4]).unwrap();
+            result
+        };
+        let sll = LinuxSllHeader {
+            packet_type: LinuxSllPacketType::OUTGOING,
+            arp_hrd_type: ArpHardwareId::ETHERNET,
+            sender_address_valid_length: 6,
+            sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+            protocol_type: EtherType::IPV6,
+        };
+        let sll2 = LinuxSll2Header {
+            protocol_type: EtherType::IPV6,
+            reserved: 0,
+            interface_index: 3,
+            arp_hrd_type: ArpHardwareId::ETHERNET,
+            packet_type: LinuxSllPacketType::HOST,
+            sender_address_valid_length: 6,
+            sender_address: [1, 2, 3, 4, 5, 6, 0, 0],
+        };
+
+        // sll
+        {
+            let mut data = Vec::new();
+            data.extend_from_slice(&sll.to_bytes());
+            data.extend_from_slice(&ip_udp);
+
+            let actual = SlicedPacket::from_linux_sll(&data).unwrap();
+            match actual.link.as_ref() {
+                Some(LinkSlice::LinuxSll(s)) => assert_eq!(s.to_header(), sll),
+                _ => panic!("expected linux sll"),
+            }
+            assert_eq!(actual.header_len(), LinuxSllHeader::LEN + 40 + 8);
+            match actual.transport.as_ref() {
+                Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &[1, 2, 3, 4]),
+                _ => panic!("expected udp"),
+            }
+            #[cfg(feature = "std")]
+            assert_eq!(actual.to_vec(false), data);
+
+            // length error
+            assert_eq!(
+                SlicedPacket::from_linux_sll(&data[..LinuxSllHeader::LEN - 1]).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: LinuxSllHeader::LEN,
+                    len: LinuxSllHeader::LEN - 1,
+                    len_source: LenSource::Slice,
+                    layer: Layer::LinuxSllHeader,
+                    layer_start_offset: 0,
+                })
+            );
+
+            // error in the following layer gets the offset of the sll header
+            assert_eq!(
+                SlicedPacket::from_linux_sll(&data[..LinuxSllHeader::LEN + 1]).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: Ipv6Header::LEN,
+                    len: 1,
+                    len_source: LenSource::Slice,
+                    layer: Layer::Ipv6Header,
+                    layer_start_offset: LinuxSllHeader::LEN,
+                })
+            );
+
+            // unknown protocol (e.g. netlink)
+            let mut data = Vec::new();
+            data.extend_from_slice(
+                &LinuxSllHeader {
+                    protocol_type: EtherType(0x10),
+                    ..sll.clone()
+                }
+                .to_bytes(),
+            );
+            data.extend_from_slice(&[1, 2, 3, 4]);
+            let actual = SlicedPacket::from_linux_sll(&data).unwrap();
+            assert_eq!(
+                actual.ether_payload(),
+                Some(EtherPayloadSlice {
+                    ether_type: EtherType(0x10),
+                    payload: &[1, 2, 3, 4]
+                })
+            );
+            assert!(actual.net.is_none());
+        }
+
+        // sll2
+        {
+            let mut data = Vec::new();
+            data.extend_from_slice(&sll2.to_bytes());
+            data.extend_from_slice(&ip_udp);
+
+            let actual = SlicedPacket::from_linux_sll2(&data).unwrap();
+            match actual.link.as_ref() {
+                Some(LinkSlice::LinuxSll2(s)) => assert_eq!(s.to_header(), sll2),
+                _ => panic!("expected linux sll2"),
+            }
+            assert_eq!(actual.header_len(), LinuxSll2Header::LEN + 40 + 8);
+            match actual.transport.as_ref() {
+                Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &[1, 2, 3, 4]),
+                _ => panic!("expected udp"),
+            }
+            #[cfg(feature = "std")]
+            assert_eq!(actual.to_vec(false), data);
+
+            // length error
+            assert_eq!(
+                SlicedPacket::from_linux_sll2(&data[..LinuxSll2Header::LEN - 1]).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: LinuxSll2Header::LEN,
+                    len: LinuxSll2Header::LEN - 1,
+                    len_source: LenSource::Slice,
+                    layer: Layer::LinuxSll2Header,
+                    layer_start_offset: 0,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn arp() {
+        use alloc::vec::Vec;
+
+        let arp = ArpPacket::new_eth_ipv4(
+            ArpOperation::REQUEST,
+            [1, 2, 3, 4, 5, 6],
+            [192, 168, 1, 1],
+            [0; 6],
+            [192, 168, 1, 2],
+        );
+        let vlan = SingleVlanHeader {
+            ether_type: EtherType::ARP,
+            ..Default::default()
+        };
+
+        let build = |ether_type: EtherType, vlans: &[SingleVlanHeader], arp_bytes: &[u8]| {
+            let mut result = Vec::new();
+            result.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            for vlan in vlans {
+                result.extend_from_slice(&vlan.to_bytes());
+            }
+            result.extend_from_slice(arp_bytes);
+            // ethernet padding
+            result.extend_from_slice(&[0; 4]);
+            result
+        };
+
+        // ok
+        {
+            let outer_vlan = SingleVlanHeader {
+                ether_type: EtherType::VLAN_TAGGED_FRAME,
+                ..Default::default()
+            };
+            let single = build(
+                EtherType::VLAN_TAGGED_FRAME,
+                core::slice::from_ref(&vlan),
+                &arp.to_bytes(),
+            );
+            let double = build(
+                EtherType::PROVIDER_BRIDGING,
+                &[outer_vlan, vlan.clone()],
+                &arp.to_bytes(),
+            );
+            let no_vlan = build(EtherType::ARP, &[], &arp.to_bytes());
+            let results = [
+                SlicedPacket::from_ethernet(&no_vlan).unwrap(),
+                SlicedPacket::from_ethernet(&single).unwrap(),
+                SlicedPacket::from_ethernet(&double).unwrap(),
+                SlicedPacket::from_ether_type(EtherType::ARP, &no_vlan[Ethernet2Header::LEN..])
+                    .unwrap(),
+            ];
+            for result in results {
+                match result.net.as_ref() {
+                    Some(NetSlice::Arp(actual)) => {
+                        assert_eq!(actual.to_packet(), arp);
+                        assert_eq!(actual.sender_mac(), Some([1, 2, 3, 4, 5, 6]));
+                        assert_eq!(actual.target_ipv4(), Some([192, 168, 1, 2]));
+                    }
+                    _ => panic!("expected arp"),
+                }
+                assert!(result.ip_payload().is_none());
+                assert!(result.transport.is_none());
+            }
+        }
+
+        // length error
+        {
+            let bytes = arp.to_bytes();
+            let data = build(
+                EtherType::VLAN_TAGGED_FRAME,
+                &[vlan],
+                &bytes[..bytes.len() - 5],
+            );
+            assert_eq!(
+                SlicedPacket::from_ethernet(&data).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: arp.packet_len(),
+                    len: arp.packet_len() - 1,
+                    len_source: LenSource::Slice,
+                    layer: Layer::Arp,
+                    layer_start_offset: Ethernet2Header::LEN + SingleVlanHeader::LEN,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn igmp() {
+        use alloc::vec::Vec;
+
+        let message = {
+            let mut m = Igmpv2Message {
+                igmp_type: igmp::TYPE_V2_MEMBERSHIP_REPORT,
+                max_response_time: 0,
+                checksum: 0,
+                group_address: [224, 0, 0, 251],
+            };
+            m.checksum = m.calc_checksum();
+            m
+        };
+
+        let build = |payload: &[u8]| {
+            let mut result = Vec::new();
+            result.extend_from_slice(
+                &Ipv4Header::new(
+                    payload.len() as u16,
+                    1,
+                    ip_number::IGMP,
+                    [192, 168, 1, 1],
+                    [224, 0, 0, 251],
+                )
+                .unwrap()
+                .to_bytes(),
+            );
+            result.extend_from_slice(payload);
+            result
+        };
+
+        // ok
+        {
+            let data = build(&message.to_bytes());
+            let result = SlicedPacket::from_ip(&data).unwrap();
+            match result.transport.as_ref() {
+                Some(TransportSlice::Igmp(actual)) => {
+                    assert_eq!(actual.slice(), &message.to_bytes());
+                    assert!(actual.is_checksum_valid());
+                    assert_eq!(actual.message(), IgmpMessage::Igmpv2(message.clone()));
+                }
+                _ => panic!("expected igmp"),
+            }
+            assert_eq!(result.header_len(), data.len());
+        }
+
+        // length error (missing group record of an igmpv3 report)
+        {
+            let data = build(&[igmp::TYPE_V3_MEMBERSHIP_REPORT, 0, 0, 0, 0, 0, 0, 1]);
+            assert_eq!(
+                SlicedPacket::from_ip(&data).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: Igmpv3MembershipReport::HEADER_LEN + Igmpv3GroupRecord::MIN_LEN,
+                    len: Igmpv3MembershipReport::HEADER_LEN,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::Igmp,
+                    layer_start_offset: Ipv4Header::MIN_LEN,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn pppoe() {
+        use alloc::vec::Vec;
+
+        let build = |ether_type: EtherType, pppoe: &PppoeHeader, payload: &[u8]| {
+            let mut result = Vec::new();
+            result.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            result.extend_from_slice(&pppoe.to_bytes());
+            result.extend_from_slice(payload);
+            // ethernet padding
+            result.extend_from_slice(&[0; 4]);
+            result
+        };
+
+        // session data containing ipv4 & ipv6
+        for (ppp_protocol, ip_payload) in [
+            (PppoeHeader::PPP_PROTOCOL_IPV4, {
+                let mut v = Vec::new();
+                PacketBuilder::ipv4([1, 2, 3, 4], [5, 6, 7, 8], 64)
+                    .udp(1, 2)
+                    .write(&mut v, &[9, 10])
+                    .unwrap();
+                v
+            }),
+            (PppoeHeader::PPP_PROTOCOL_IPV6, {
+                let mut v = Vec::new();
+                PacketBuilder::ipv6([1; 16], [2; 16], 64)
+                    .udp(1, 2)
+                    .write(&mut v, &[9, 10])
+                    .unwrap();
+                v
+            }),
+        ] {
+            let pppoe = PppoeHeader {
+                version: 1,
+                pppoe_type: 1,
+                code: PppoeHeader::CODE_SESSION_DATA,
+                session_id: 0x1234,
+                payload_length: (ip_payload.len() + 2) as u16,
+                ppp_protocol: Some(ppp_protocol),
+            };
+            let data = build(EtherType::PPPOE_SESSION, &pppoe, &ip_payload);
+            let results = [
+                SlicedPacket::from_ethernet(&data).unwrap(),
+                SlicedPacket::from_ether_type(
+                    EtherType::PPPOE_SESSION,
+                    &data[Ethernet2Header::LEN..],
+                )
+                .unwrap(),
+            ];
+            for result in results {
+                assert_eq!(result.pppoe.as_ref().unwrap().to_header(), pppoe);
+                assert!(result.net.is_some());
+                assert_eq!(result.payload_ether_type(), None);
+                match result.transport.as_ref() {
+                    Some(TransportSlice::Udp(udp)) => assert_eq!(udp.payload(), &[9, 10]),
+                    _ => panic!("expected udp"),
+                }
+            }
+        }
+
+        // discovery
+        {
+            let pppoe = PppoeHeader {
+                version: 1,
+                pppoe_type: 1,
+                code: PppoeHeader::CODE_PADI,
+                session_id: 0,
+                payload_length: 4,
+                ppp_protocol: None,
+            };
+            let data = build(EtherType::PPPOE_DISCOVERY, &pppoe, &[1, 2, 3, 4]);
+            let result = SlicedPacket::from_ethernet(&data).unwrap();
+            assert_eq!(result.pppoe.as_ref().unwrap().to_header(), pppoe);
+            assert!(result.net.is_none());
+            assert!(result.transport.is_none());
+        }
+
+        // length error
+        {
+            let pppoe = PppoeHeader {
+                version: 1,
+                pppoe_type: 1,
+                code: PppoeHeader::CODE_SESSION_DATA,
+                session_id: 0,
+                payload_length: 100,
+                ppp_protocol: Some(PppoeHeader::PPP_PROTOCOL_IPV4),
+            };
+            let data = build(EtherType::PPPOE_SESSION, &pppoe, &[]);
+            assert_eq!(
+                SlicedPacket::from_ethernet(&data).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: PppoeHeader::MIN_LEN + 100,
+                    len: PppoeHeader::MAX_LEN + 4,
+                    len_source: LenSource::Slice,
+                    layer: Layer::PppoeHeader,
+                    layer_start_offset: Ethernet2Header::LEN,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn esp() {
+        use alloc::vec::Vec;
+
+        let esp = EspHeader {
+            spi: 0x1234_5678,
+            sequence_number: 1,
+        };
+        let encrypted = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        // ipv4
+        {
+            let mut data = Vec::new();
+            data.extend_from_slice(
+                &Ipv4Header::new(
+                    (EspHeader::LEN + encrypted.len()) as u16,
+                    1,
+                    ip_number::ENCAP_SEC,
+                    [192, 168, 1, 1],
+                    [192, 168, 1, 2],
+                )
+                .unwrap()
+                .to_bytes(),
+            );
+            data.extend_from_slice(&esp.to_bytes());
+            data.extend_from_slice(&encrypted);
+
+            let result = SlicedPacket::from_ip(&data).unwrap();
+            match result.transport.as_ref() {
+                Some(TransportSlice::Esp(actual)) => {
+                    assert_eq!(actual.to_header(), esp);
+                    assert_eq!(actual.payload(), &encrypted);
+                }
+                _ => panic!("expected esp"),
+            }
+            assert_eq!(result.payload_offset(), data.len() - encrypted.len());
+
+            // length error
+            let data = {
+                let mut data = Vec::new();
+                data.extend_from_slice(
+                    &Ipv4Header::new(
+                        (EspHeader::LEN - 1) as u16,
+                        1,
+                        ip_number::ENCAP_SEC,
+                        [192, 168, 1, 1],
+                        [192, 168, 1, 2],
+                    )
+                    .unwrap()
+                    .to_bytes(),
+                );
+                data.extend_from_slice(&esp.to_bytes()[..EspHeader::LEN - 1]);
+                data
+            };
+            assert_eq!(
+                SlicedPacket::from_ip(&data).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: EspHeader::LEN,
+                    len: EspHeader::LEN - 1,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::EspHeader,
+                    layer_start_offset: Ipv4Header::MIN_LEN,
+                })
+            );
+        }
+
+        // ipv6 (with an extension header before the esp header)
+        {
+            let mut data = Vec::new();
+            data.extend_from_slice(
+                &Ipv6Header {
+                    payload_length: (8 + EspHeader::LEN + encrypted.len()) as u16,
+                    next_header: ip_number::IPV6_DEST_OPTIONS,
+                    hop_limit: 1,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            data.extend_from_slice(
+                &Ipv6RawExtHeader::new_raw(ip_number::ENCAP_SEC, &[0; 6])
+                    .unwrap()
+                    .to_bytes(),
+            );
+            data.extend_from_slice(&esp.to_bytes());
+            data.extend_from_slice(&encrypted);
+
+            let result = SlicedPacket::from_ip(&data).unwrap();
+            match result.transport.as_ref() {
+                Some(TransportSlice::Esp(actual)) => {
+                    assert_eq!(actual.spi(), esp.spi);
+                    assert_eq!(actual.sequence_number(), esp.sequence_number);
+                    assert_eq!(actual.payload(), &encrypted);
+                }
+                _ => panic!("expected esp"),
+            }
+        }
+    }
+
+    #[test]
+    fn dccp() {
+        use alloc::vec::Vec;
+
+        let dccp = DccpHeader {
+            source_port: 1234,
+            destination_port: 5678,
+            data_offset: 6,
+            ccval: 1,
+            checksum_coverage: 0,
+            checksum: 0x1234,
+            packet_type: DccpHeader::PACKET_TYPE_DATA_ACK,
+            extended_sequence_number: true,
+            sequence_number: 0x0102_0304_0506,
+        };
+        // acknowledgement number subheader
+        let ack = [0, 0, 0, 0, 0, 0, 0, 1];
+        let payload = [1, 2, 3, 4];
+
+        // ipv4
+        {
+            let mut data = Vec::new();
+            data.extend_from_slice(
+                &Ipv4Header::new(
+                    (DccpHeader::MAX_LEN + ack.len() + payload.len()) as u16,
+                    1,
+                    ip_number::DCCP,
+                    [192, 168, 1, 1],
+                    [192, 168, 1, 2],
+                )
+                .unwrap()
+                .to_bytes(),
+            );
+            data.extend_from_slice(&dccp.to_bytes());
+            data.extend_from_slice(&ack);
+            data.extend_from_slice(&payload);
+
+            let result = SlicedPacket::from_ip(&data).unwrap();
+            match result.transport.as_ref() {
+                Some(TransportSlice::Dccp(actual)) => {
+                    assert_eq!(actual.to_header(), dccp);
+                    assert_eq!(actual.additional_fields_and_options(), &ack);
+                    assert_eq!(actual.payload(), &payload);
+                }
+                _ => panic!("expected dccp"),
+            }
+            assert_eq!(Some(PayloadSlice::Dccp(&payload)), result.payload());
+            assert_eq!(result.payload_offset(), data.len() - payload.len());
+
+            // length error
+            let data = {
+                let mut data = Vec::new();
+                data.extend_from_slice(
+                    &Ipv4Header::new(
+                        (DccpHeader::MAX_LEN + ack.len() - 1) as u16,
+                        1,
+                        ip_number::DCCP,
+                        [192, 168, 1, 1],
+                        [192, 168, 1, 2],
+                    )
+                    .unwrap()
+                    .to_bytes(),
+                );
+                data.extend_from_slice(&dccp.to_bytes());
+                data.extend_from_slice(&ack[..ack.len() - 1]);
+                data
+            };
+            assert_eq!(
+                SlicedPacket::from_ip(&data).unwrap_err(),
+                SliceError::Len(LenError {
+                    required_len: DccpHeader::MAX_LEN + ack.len(),
+                    len: DccpHeader::MAX_LEN + ack.len() - 1,
+                    len_source: LenSource::Ipv4HeaderTotalLen,
+                    layer: Layer::DccpHeader,
+                    layer_start_offset: Ipv4Header::MIN_LEN,
+                })
+            );
+        }
+
+        // ipv6 with data offset error
+        {
+            let dccp = DccpHeader {
+                data_offset: 3,
+                ..dccp.clone()
+            };
+            let mut data = Vec::new();
+            data.extend_from_slice(
+                &Ipv6Header {
+                    payload_length: (DccpHeader::MAX_LEN + payload.len()) as u16,
+                    next_header: ip_number::DCCP,
+                    hop_limit: 1,
+                    ..Default::default()
+                }
+                .to_bytes(),
+            );
+            data.extend_from_slice(&dccp.to_bytes());
+            data.extend_from_slice(&payload);
+            assert_eq!(
+                SlicedPacket::from_ip(&data).unwrap_err(),
+                SliceError::Dccp(err::dccp::HeaderError::DataOffsetTooSmall {
+                    data_offset: 3,
+                    extended_sequence_number: true,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn byte_swapped_ip() {
+        use alloc::vec::Vec;
+
+        let build = |ipv6: bool| {
+            let builder = PacketBuilder::ethernet2([1; 6], [2; 6]);
+            let mut packet = Vec::new();
+            if ipv6 {
+                builder
+                    .ipv6([3; 16], [4; 16], 20)
+                    .udp(21, 1234)
+                    .write(&mut packet, &[1, 2, 3, 4])
+                    .unwrap();
+            } else {
+                builder
+                    .ipv4([3; 4], [4; 4], 20)
+                    .udp(21, 1234)
+                    .write(&mut packet, &[1, 2, 3, 4])
+                    .unwrap();
+            }
+            // swap the byte order of the 16 bit words in the ip packet
+            for word in packet[Ethernet2Header::LEN..].chunks_exact_mut(2) {
+                word.swap(0, 1);
+            }
+            packet
+        };
+
+        // ipv4 (the version error should be reported even if the
+        // data is too short for an ipv4 header)
+        {
+            let packet = build(false);
+            for len in Ethernet2Header::LEN + 1..=packet.len() {
+                assert_eq!(
+                    SlicedPacket::from_ethernet(&packet[..len]),
+                    Err(SliceError::Ipv4(
+                        err::ipv4::HeaderError::UnexpectedVersion { version_number: 0 }
+                    ))
+                );
+                assert_eq!(
+                    SlicedPacket::from_ip(&packet[Ethernet2Header::LEN..len]),
+                    Err(SliceError::Ip(err::ip::HeaderError::UnsupportedIpVersion {
+                        version_number: 0
+                    }))
+                );
+            }
+        }
+
+        // ipv6
+        {
+            let packet = build(true);
+            for len in Ethernet2Header::LEN + 1..=packet.len() {
+                assert_eq!(
+                    SlicedPacket::from_ethernet(&packet[..len]),
+                    Err(SliceError::Ipv6(
+                        err::ipv6::HeaderError::UnexpectedVersion { version_number: 0 }
+                    ))
+                );
+                assert_eq!(
+                    SlicedPacket::from_ip(&packet[Ethernet2Header::LEN..len]),
+                    Err(SliceError::Ip(err::ip::HeaderError::UnsupportedIpVersion {
+                        version_number: 0
+                    }))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_guess() {
+        use alloc::vec::Vec;
+
+        let payload = [1, 2, 3, 4];
+        let build = |builder: PacketBuilderStep<UdpHeader>| {
+            let mut result = Vec::new();
+            builder.write(&mut result, &payload).unwrap();
+            result
+        };
+
+        // ethernet II with a known ether type
+        {
+            let data = build(
+                PacketBuilder::ethernet2([0x45; 6], [0x60; 6])
+                    .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                    .udp(21, 1234),
+            );
+            let (sliced, guess) = SlicedPacket::from_guess(&data).unwrap();
+            assert_eq!(guess, GuessedLinkType::Ethernet2);
+            assert_eq!(sliced, SlicedPacket::from_ethernet(&data).unwrap());
+        }
 
-        // only ethernet header II
+        // raw ipv4
         {
-            let payload = [1, 2, 3, 4];
-            let mut buf = Vec::with_capacity(Ethernet2Header::LEN + 4);
-            buf.extend_from_slice(
-                &Ethernet2Header {
-                    ether_type: EtherType::WAKE_ON_LAN,
-                    ..Default::default()
-                }
-                .to_bytes(),
-            );
-            buf.extend_from_slice(&payload);
-            assert_eq!(
-                SlicedPacket::from_ethernet(&buf).unwrap().ether_payload(),
-                Some(EtherPayloadSlice {
-                    ether_type: EtherType::WAKE_ON_LAN,
-                    payload: &payload
-                })
-            );
+            let data =
+                build(PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20).udp(21, 1234));
+            let (sliced, guess) = SlicedPacket::from_guess(&data).unwrap();
+            assert_eq!(guess, GuessedLinkType::Ipv4);
+            assert_eq!(sliced, SlicedPacket::from_ip(&data).unwrap());
         }
 
-        // ether type payload
+        // raw ipv4 with a source address looking like a known ether type
         {
-            let payload = [1, 2, 3, 4];
-            assert_eq!(
-                SlicedPacket {
-                    link: Some(LinkSlice::EtherPayload(EtherPayloadSlice {
-                        ether_type: EtherType::WAKE_ON_LAN,
-                        payload: &payload
-                    })),
-                    vlan: None,
-                    net: None,
-                    transport: None,
-                }
-                .ether_payload(),
-                Some(EtherPayloadSlice {
-                    ether_type: EtherType::WAKE_ON_LAN,
-                    payload: &payload
-                })
-            );
+            let data = build(PacketBuilder::ipv4([8, 0, 1, 1], [192, 168, 1, 2], 20).udp(21, 1234));
+            let (sliced, guess) = SlicedPacket::from_guess(&data).unwrap();
+            assert_eq!(guess, GuessedLinkType::Ipv4);
+            assert_eq!(sliced, SlicedPacket::from_ip(&data).unwrap());
         }
 
-        // single vlan header
+        // raw ipv6
         {
-            let payload = [1, 2, 3, 4];
-            let mut buf = Vec::with_capacity(Ethernet2Header::LEN + SingleVlanHeader::LEN + 4);
-            buf.extend_from_slice(
+            let data = build(PacketBuilder::ipv6([0; 16], [1; 16], 20).udp(21, 1234));
+            let (sliced, guess) = SlicedPacket::from_guess(&data).unwrap();
+            assert_eq!(guess, GuessedLinkType::Ipv6);
+            assert_eq!(sliced, SlicedPacket::from_ip(&data).unwrap());
+        }
+
+        // ethernet II with an unknown ether type
+        {
+            let mut data = Vec::new();
+            data.extend_from_slice(
                 &Ethernet2Header {
-                    ether_type: EtherType::VLAN_TAGGED_FRAME,
-                    ..Default::default()
-                }
-                .to_bytes(),
-            );
-            buf.extend_from_slice(
-                &SingleVlanHeader {
-                    ether_type: EtherType::WAKE_ON_LAN,
+                    ether_type: EtherType(0x1234),
                     ..Default::default()
                 }
                 .to_bytes(),
             );
-            buf.extend_from_slice(&payload);
-            assert_eq!(
-                SlicedPacket::from_ethernet(&buf).unwrap().ether_payload(),
-                Some(EtherPayloadSlice {
-                    ether_type: EtherType::WAKE_ON_LAN,
-                    payload: &payload
-                })
-            );
+            data.extend_from_slice(&payload);
+            let (sliced, guess) = SlicedPacket::from_guess(&data).unwrap();
+            assert_eq!(guess, GuessedLinkType::Ethernet2);
+            assert_eq!(sliced, SlicedPacket::from_ethernet(&data).unwrap());
         }
 
-        // double vlan header
+        // ethernet error if nothing fits
         {
-            let payload = [1, 2, 3, 4];
-            let mut buf = Vec::with_capacity(Ethernet2Header::LEN + SingleVlanHeader::LEN * 2 + 4);
-            buf.extend_from_slice(
+            let mut data = Vec::new();
+            data.extend_from_slice(
                 &Ethernet2Header {
-                    ether_type: EtherType::VLAN_DOUBLE_TAGGED_FRAME,
+                    ether_type: EtherType::IPV4,
                     ..Default::default()
                 }
                 .to_bytes(),
             );
-            buf.extend_from_slice(
-                &SingleVlanHeader {
-                    ether_type: EtherType::VLAN_TAGGED_FRAME,
-                    ..Default::default()
-                }
-                .to_bytes(),
-            );
-            buf.extend_from_slice(
-                &SingleVlanHeader {
-                    ether_type: EtherType::WAKE_ON_LAN,
-                    ..Default::default()
-                }
-                .to_bytes(),
+            data.extend_from_slice(&payload);
+            assert_eq!(
+                SlicedPacket::from_guess(&data).unwrap_err(),
+                SlicedPacket::from_ethernet(&data).unwrap_err()
             );
-            buf.extend_from_slice(&payload);
             assert_eq!(
-                SlicedPacket::from_ethernet(&buf).unwrap().ether_payload(),
-                Some(EtherPayloadSlice {
-                    ether_type: EtherType::WAKE_ON_LAN,
-                    payload: &payload
-                })
+                SlicedPacket::from_guess(&data[..4]).unwrap_err(),
+                SlicedPacket::from_ethernet(&data[..4]).unwrap_err()
             );
         }
     }
 
     #[test]
-    fn ip_payload() {
-        use alloc::vec::*;
+    fn to_vec() {
+        use alloc::vec::Vec;
 
-        // no content
-        assert_eq!(
-            SlicedPacket {
-                link: None,
-                vlan: None,
-                net: None,
-                transport: None,
+        let payload = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let build = |builder: &dyn Fn(&mut Vec<u8>)| {
+            let mut result = Vec::new();
+            builder(&mut result);
+            result
+        };
+
+        // packets & the offsets of their checksums
+        let tests: [(Vec<u8>, &[usize]); 6] = [
+            (
+                build(&|r| {
+                    PacketBuilder::ethernet2([1; 6], [2; 6])
+                        .single_vlan(VlanId::try_new(12).unwrap())
+                        .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                        .udp(1234, 5678)
+                        .write(r, &payload)
+                        .unwrap()
+                }),
+                &[18 + 10, 38 + 6],
+            ),
+            (
+                build(&|r| {
+                    PacketBuilder::ethernet2([1; 6], [2; 6])
+                        .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                        .tcp(1234, 5678, 1, 1024)
+                        .write(r, &payload)
+                        .unwrap()
+                }),
+                &[14 + 10, 34 + 16],
+            ),
+            (
+                build(&|r| {
+                    PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                        .icmpv4_echo_request(1, 2)
+                        .write(r, &payload)
+                        .unwrap()
+                }),
+                &[10, 20 + 2],
+            ),
+            (
+                build(&|r| {
+                    PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                        .icmpv6_echo_request(1, 2)
+                        .write(r, &payload)
+                        .unwrap()
+                }),
+                &[40 + 2],
+            ),
+            (
+                build(&|r| {
+                    PacketBuilder::ipv6([1; 16], [2; 16], 20)
+                        .udp(1234, 5678)
+                        .write(r, &payload)
+                        .unwrap()
+                }),
+                &[40 + 6],
+            ),
+            (
+                build(&|r| {
+                    PacketBuilder::ethernet2([1; 6], [2; 6])
+                        .double_vlan(VlanId::try_new(1).unwrap(), VlanId::try_new(2).unwrap())
+                        .ipv6([1; 16], [2; 16], 20)
+                        .tcp(1234, 5678, 1, 1024)
+                        .write(r, &payload)
+                        .unwrap()
+                }),
+                &[22 + 40 + 16],
+            ),
+        ];
+
+        for (packet, checksum_offsets) in tests.iter() {
+            // packets without an ethernet header start with the ip version
+            let from_ip = matches!(packet[0] >> 4, 4 | 6);
+            let slice = |data: &[u8]| -> Vec<u8> {
+                let mut result = Vec::new();
+                let sliced = if from_ip {
+                    SlicedPacket::from_ip(data).unwrap()
+                } else {
+                    SlicedPacket::from_ethernet(data).unwrap()
+                };
+                sliced.write(&mut result, true).unwrap();
+                assert_eq!(result, sliced.to_vec(true));
+                assert_eq!(data, &sliced.to_vec(false)[..]);
+                result
+            };
+
+            // unchanged
+            assert_eq!(&slice(packet), packet);
+
+            // invalid checksums
+            let mut corrupted = packet.clone();
+            for offset in checksum_offsets.iter() {
+                corrupted[*offset] = !corrupted[*offset];
+                corrupted[*offset + 1] = !corrupted[*offset + 1];
             }
-            .ip_payload(),
-            None
-        );
+            assert_eq!(&slice(&corrupted), packet);
+        }
 
-        // ipv4
+        // ethernet padding is not included
         {
-            let payload = [1, 2, 3, 4];
-            let mut buf = Vec::with_capacity(Ipv4Header::MIN_LEN + 4);
-            buf.extend_from_slice(
-                &Ipv4Header {
-                    protocol: IpNumber::ARIS,
-                    total_len: Ipv4Header::MIN_LEN_U16 + 4,
+            let mut packet = build(&|r| {
+                PacketBuilder::ethernet2([1; 6], [2; 6])
+                    .ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                    .udp(1234, 5678)
+                    .write(r, &[])
+                    .unwrap()
+            });
+            let len = packet.len();
+            packet.resize(60, 0);
+            assert_eq!(
+                SlicedPacket::from_ethernet(&packet).unwrap().to_vec(true),
+                &packet[..len]
+            );
+        }
+
+        // zero udp checksum in ipv4 is kept
+        {
+            let mut packet = build(&|r| {
+                PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                    .udp(1234, 5678)
+                    .write(r, &payload)
+                    .unwrap()
+            });
+            packet[26] = 0;
+            packet[27] = 0;
+            assert_eq!(SlicedPacket::from_ip(&packet).unwrap().to_vec(true), packet);
+        }
+
+        // dccp checksum
+        {
+            let dccp = DccpHeader {
+                checksum_coverage: 2,
+                ..Default::default()
+            };
+            let mut ip = Ipv4Header::new(
+                (dccp.header_len() + payload.len()) as u16,
+                20,
+                ip_number::DCCP,
+                [192, 168, 1, 1],
+                [192, 168, 1, 2],
+            )
+            .unwrap();
+            ip.header_checksum = ip.calc_header_checksum();
+            let mut packet = Vec::new();
+            packet.extend_from_slice(&ip.to_bytes());
+            packet.extend_from_slice(&dccp.to_bytes());
+            packet.extend_from_slice(&payload);
+            let checksum = DccpSlice::from_slice(&packet[20..])
+                .unwrap()
+                .calc_checksum_ipv4([192, 168, 1, 1], [192, 168, 1, 2])
+                .unwrap();
+            packet[26..28].copy_from_slice(&checksum.to_be_bytes());
+
+            let mut corrupted = packet.clone();
+            corrupted[26] = !corrupted[26];
+            corrupted[27] = !corrupted[27];
+            assert_eq!(
+                SlicedPacket::from_ip(&corrupted).unwrap().to_vec(true),
+                packet
+            );
+        }
+
+        // payloads without a decoded ip layer
+        {
+            // unknown ether type
+            let mut packet = Vec::new();
+            packet.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type: EtherType(0x1234),
                     ..Default::default()
                 }
                 .to_bytes(),
             );
-            buf.extend_from_slice(&payload);
+            packet.extend_from_slice(&payload);
             assert_eq!(
-                SlicedPacket::from_ip(&buf).unwrap().ip_payload(),
-                Some(&IpPayloadSlice {
-                    payload: &payload,
-                    ip_number: IpNumber::ARIS,
-                    fragmented: false,
-                    len_source: LenSource::Ipv4HeaderTotalLen,
-                })
+                SlicedPacket::from_ethernet(&packet).unwrap().to_vec(true),
+                packet
             );
-        }
 
-        // ipv6
-        {
-            let payload = [1, 2, 3, 4];
-            let mut buf = Vec::with_capacity(Ipv6Header::LEN + 4);
-            buf.extend_from_slice(
-                &Ipv6Header {
-                    payload_length: 4,
-                    next_header: IpNumber::ARGUS,
+            // pppoe with unknown ppp protocol (followed by padding)
+            let mut packet = Vec::new();
+            packet.extend_from_slice(
+                &Ethernet2Header {
+                    ether_type: ether_type::PPPOE_SESSION,
                     ..Default::default()
                 }
                 .to_bytes(),
             );
-            buf.extend_from_slice(&payload);
+            packet.extend_from_slice(
+                &PppoeHeader {
+                    version: 1,
+                    pppoe_type: 1,
+                    code: PppoeHeader::CODE_SESSION_DATA,
+                    session_id: 1,
+                    payload_length: (2 + payload.len()) as u16,
+                    ppp_protocol: Some(0xc021),
+                }
+                .to_bytes(),
+            );
+            packet.extend_from_slice(&payload);
+            let len = packet.len();
+            packet.extend_from_slice(&[0, 0]);
             assert_eq!(
-                SlicedPacket::from_ip(&buf).unwrap().ip_payload(),
-                Some(&IpPayloadSlice {
-                    payload: &payload,
-                    ip_number: IpNumber::ARGUS,
-                    fragmented: false,
-                    len_source: LenSource::Ipv6HeaderPayloadLen,
-                })
+                SlicedPacket::from_ethernet(&packet).unwrap().to_vec(true),
+                &packet[..len]
             );
         }
     }
@@ -516,7 +4209,7 @@ mod test {
             let eth = Ethernet2Header {
                 source: [1, 2, 3, 4, 5, 6],
                 destination: [1, 2, 3, 4, 5, 6],
-                ether_type: 0.into(),
+                ether_type: ether_type::WAKE_ON_LAN,
             };
             let test = TestPacket {
                 link: Some(eth.clone()),
@@ -646,7 +4339,7 @@ mod test {
         for fragmented in [false, true] {
             let ipv4 = {
                 let mut ipv4 =
-                    Ipv4Header::new(0, 1, 2.into(), [3, 4, 5, 6], [7, 8, 9, 10]).unwrap();
+                    Ipv4Header::new(0, 1, 3.into(), [3, 4, 5, 6], [7, 8, 9, 10]).unwrap();
                 ipv4.more_fragments = fragmented;
                 ipv4
             };
@@ -1198,12 +4891,28 @@ mod test {
             // check if fragmenting
             let is_fragmented = test.is_ip_payload_fragmented();
 
+            // check the header length
+            assert_eq!(
+                result.header_len(),
+                test.len(expected_payload)
+                    - expected_payload.len()
+                    - if is_fragmented {
+                        test.transport.as_ref().map(|t| t.header_len()).unwrap_or(0)
+                    } else {
+                        0
+                    }
+            );
+            assert_eq!(result.payload_offset(), result.header_len());
+
             // check headers
             assert_eq!(
                 test.link,
                 match result.link.as_ref() {
                     Some(s) => match s {
                         LinkSlice::Ethernet2(e) => Some(e.to_header()),
+                        LinkSlice::Ieee8023(_) => None,
+                        LinkSlice::LinuxSll(_) => None,
+                        LinkSlice::LinuxSll2(_) => None,
                         LinkSlice::EtherPayload(_) => None,
                     },
                     None => None,
@@ -1227,6 +4936,7 @@ mod test {
                             .unwrap()
                             .0,
                         ),
+                        NetSlice::Arp(_) => unreachable!(),
                     }
                 })
             );
@@ -1252,6 +4962,9 @@ mod test {
                     Some(S::Tcp(s)) => {
                         assert_eq!(&test.transport, &Some(H::Tcp(s.to_header())));
                     }
+                    Some(S::Igmp(_)) => unreachable!(),
+                    Some(S::Esp(_)) => unreachable!(),
+                    Some(S::Dccp(_)) => unreachable!(),
                     None => {
                         assert_eq!(&test.transport, &None);
                     }
@@ -1358,8 +5071,12 @@ mod test {
                 let s = SlicedPacket{
                     link: None,
                     vlan: None,
+                    pppoe: None,
                     net: None,
                     transport: None,
+                    vxlan: None,
+                    gtpu: None,
+                    geneve: None,
                 };
                 assert_eq!(None, s.payload_ether_type());
             }
@@ -1452,4 +5169,117 @@ mod test {
             }
         }
     }
+
+    proptest! {
+        #[test]
+        fn link_payload_ether_type(
+            ref eth in ethernet_2_unknown(),
+            ref vlan_outer in vlan_single_unknown(),
+            ref vlan_inner in vlan_single_unknown(),
+            ref ipv4 in ipv4_unknown(),
+        ) {
+            use IpHeaders::*;
+            use alloc::vec::Vec;
+
+            // empty
+            {
+                let s = SlicedPacket{
+                    link: None,
+                    vlan: None,
+                    pppoe: None,
+                    net: None,
+                    transport: None,
+                    vxlan: None,
+                    gtpu: None,
+                    geneve: None,
+                };
+                assert_eq!(None, s.link_payload_ether_type());
+            }
+
+            // only ethernet
+            {
+                let mut serialized = Vec::with_capacity(eth.header_len());
+                eth.write(&mut serialized).unwrap();
+                assert_eq!(
+                    Some(eth.ether_type),
+                    SlicedPacket::from_ethernet(&serialized)
+                        .unwrap()
+                        .link_payload_ether_type()
+                );
+            }
+
+            // ether payload without ethernet header
+            {
+                let s = SlicedPacket{
+                    link: Some(LinkSlice::EtherPayload(EtherPayloadSlice{
+                        ether_type: eth.ether_type,
+                        payload: &[],
+                    })),
+                    vlan: None,
+                    pppoe: None,
+                    net: None,
+                    transport: None,
+                    vxlan: None,
+                    gtpu: None,
+                    geneve: None,
+                };
+                assert_eq!(Some(eth.ether_type), s.link_payload_ether_type());
+            }
+
+            // with double vlan
+            {
+                let mut eth_mod = eth.clone();
+                eth_mod.ether_type = ether_type::VLAN_TAGGED_FRAME;
+
+                let mut vlan_outer_mod = vlan_outer.clone();
+                vlan_outer_mod.ether_type = ether_type::VLAN_TAGGED_FRAME;
+
+                let mut serialized = Vec::with_capacity(
+                    eth_mod.header_len() +
+                    vlan_outer_mod.header_len() +
+                    vlan_inner.header_len()
+                );
+                eth_mod.write(&mut serialized).unwrap();
+                vlan_outer_mod.write(&mut serialized).unwrap();
+                vlan_inner.write(&mut serialized).unwrap();
+                assert_eq!(
+                    Some(vlan_inner.ether_type),
+                    SlicedPacket::from_ethernet(&serialized)
+                        .unwrap()
+                        .link_payload_ether_type()
+                );
+            }
+
+            // with vlan & ip
+            {
+                let builder = PacketBuilder::ethernet2(eth.source, eth.destination)
+                    .single_vlan(vlan_outer.vlan_id)
+                    .ip(Ipv4(ipv4.clone(), Default::default()));
+
+                let mut serialized = Vec::with_capacity(builder.size(0));
+                builder.write(&mut serialized, ipv4.protocol, &[]).unwrap();
+
+                assert_eq!(
+                    Some(ether_type::IPV4),
+                    SlicedPacket::from_ethernet(&serialized)
+                        .unwrap()
+                        .link_payload_ether_type()
+                );
+            }
+
+            // starting at the ip layer
+            {
+                let builder = PacketBuilder::ip(Ipv4(ipv4.clone(), Default::default()));
+                let mut serialized = Vec::with_capacity(builder.size(0));
+                builder.write(&mut serialized, ipv4.protocol, &[]).unwrap();
+
+                assert_eq!(
+                    None,
+                    SlicedPacket::from_ip(&serialized)
+                        .unwrap()
+                        .link_payload_ether_type()
+                );
+            }
+        }
+    }
 }
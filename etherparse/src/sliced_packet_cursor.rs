@@ -5,20 +5,26 @@ pub(crate) struct SlicedPacketCursor<'a> {
     pub slice: &'a [u8],
     pub offset: usize,
     pub len_source: LenSource,
+    pub options: ParseOptions,
     pub result: SlicedPacket<'a>,
 }
 
 impl<'a> SlicedPacketCursor<'a> {
-    pub fn new(slice: &'a [u8]) -> SlicedPacketCursor<'a> {
+    pub fn new(slice: &'a [u8], options: &ParseOptions) -> SlicedPacketCursor<'a> {
         SlicedPacketCursor {
             slice,
             offset: 0,
             len_source: LenSource::Slice,
+            options: options.clone(),
             result: SlicedPacket {
                 link: None,
                 vlan: None,
+                pppoe: None,
                 net: None,
                 transport: None,
+                vxlan: None,
+                gtpu: None,
+                geneve: None,
             },
         }
     }
@@ -36,12 +42,37 @@ impl<'a> SlicedPacketCursor<'a> {
         use ether_type::*;
         use LinkSlice::*;
 
-        let result = Ethernet2Slice::from_slice_without_fcs(self.slice)
-            .map_err(|err| Len(err.add_offset(self.offset)))?;
+        let result = if self.options.has_fcs {
+            let result = Ethernet2Slice::from_slice_with_crc32_fcs(self.slice)
+                .map_err(|err| Len(err.add_offset(self.offset)))?;
+
+            // exclude the fcs from the data decoded in the following layers
+            let (frame, fcs) = self.slice.split_at(self.slice.len() - 4);
+            if self.options.validate_fcs {
+                let received = u32::from_le_bytes([fcs[0], fcs[1], fcs[2], fcs[3]]);
+                let calculated = checksum::crc32_ethernet(frame);
+                if received != calculated {
+                    return Err(Fcs(err::FcsError {
+                        received,
+                        calculated,
+                    }));
+                }
+            }
+            self.slice = frame;
+            result
+        } else {
+            Ethernet2Slice::from_slice_without_fcs(self.slice)
+                .map_err(|err| Len(err.add_offset(self.offset)))?
+        };
 
         //cache the ether_type for later
         let ether_type = result.ether_type();
 
+        // ether types up to 1500 are the length field of an IEEE 802.3 frame
+        if ether_type.0 <= Ieee8023Slice::MAX_PAYLOAD_LEN {
+            return self.slice_ieee8023();
+        }
+
         //set the new data
         self.move_by(result.header_len());
         self.result.link = Some(Ethernet2(result));
@@ -51,6 +82,84 @@ impl<'a> SlicedPacketCursor<'a> {
             IPV4 => self.slice_ipv4(),
             IPV6 => self.slice_ipv6(),
             VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => self.slice_vlan(),
+            ARP => self.slice_arp(),
+            PPPOE_DISCOVERY | PPPOE_SESSION => self.slice_pppoe(),
+            _ => Ok(self.result),
+        }
+    }
+
+    pub fn slice_ieee8023(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        use err::packet::SliceError::*;
+        use ether_type::*;
+
+        let result = Ieee8023Slice::from_slice(self.slice)
+            .map_err(|err| Len(err.add_offset(self.offset)))?;
+        let payload_len = result.payload_slice().len();
+        let ether_type = result.payload_ether_type();
+
+        //set the new data & cut off data after the 802.3 payload (e.g. ethernet padding)
+        self.move_by(result.header_len());
+        self.slice = &self.slice[..payload_len];
+        self.result.link = Some(LinkSlice::Ieee8023(result));
+
+        //continue parsing (if required)
+        match ether_type {
+            Some(IPV4) => self.slice_ipv4(),
+            Some(IPV6) => self.slice_ipv6(),
+            Some(VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME) => {
+                self.slice_vlan()
+            }
+            Some(ARP) => self.slice_arp(),
+            Some(PPPOE_DISCOVERY | PPPOE_SESSION) => self.slice_pppoe(),
+            _ => Ok(self.result),
+        }
+    }
+
+    pub fn slice_linux_sll(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        use err::packet::SliceError::*;
+
+        let result = LinuxSllSlice::from_slice(self.slice)
+            .map_err(|err| Len(err.add_offset(self.offset)))?;
+        let protocol_type = result.protocol_type();
+
+        //set the new data
+        self.move_by(result.header_len());
+        self.result.link = Some(LinkSlice::LinuxSll(result));
+
+        //continue parsing (if required)
+        self.slice_linux_sll_payload(protocol_type)
+    }
+
+    pub fn slice_linux_sll2(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        use err::packet::SliceError::*;
+
+        let result = LinuxSll2Slice::from_slice(self.slice)
+            .map_err(|err| Len(err.add_offset(self.offset)))?;
+        let protocol_type = result.protocol_type();
+
+        //set the new data
+        self.move_by(result.header_len());
+        self.result.link = Some(LinkSlice::LinuxSll2(result));
+
+        //continue parsing (if required)
+        self.slice_linux_sll_payload(protocol_type)
+    }
+
+    fn slice_linux_sll_payload(
+        self,
+        protocol_type: EtherType,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        use ether_type::*;
+
+        // only the protocols that are identified via an ether type
+        // are decoded (other values, like netlink protocol numbers,
+        // don't match any of the ether types below).
+        match protocol_type {
+            IPV4 => self.slice_ipv4(),
+            IPV6 => self.slice_ipv6(),
+            VLAN_TAGGED_FRAME | PROVIDER_BRIDGING | VLAN_DOUBLE_TAGGED_FRAME => self.slice_vlan(),
+            ARP => self.slice_arp(),
+            PPPOE_DISCOVERY | PPPOE_SESSION => self.slice_pppoe(),
             _ => Ok(self.result),
         }
     }
@@ -84,20 +193,72 @@ impl<'a> SlicedPacketCursor<'a> {
                 match inner_ether_type {
                     IPV4 => self.slice_ipv4(),
                     IPV6 => self.slice_ipv6(),
+                    ARP => self.slice_arp(),
+                    PPPOE_DISCOVERY | PPPOE_SESSION => self.slice_pppoe(),
                     _ => Ok(self.result),
                 }
             }
             IPV4 => self.slice_ipv4(),
             IPV6 => self.slice_ipv6(),
+            ARP => self.slice_arp(),
+            PPPOE_DISCOVERY | PPPOE_SESSION => self.slice_pppoe(),
             _ => Ok(self.result),
         }
     }
 
+    pub fn slice_pppoe(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        use err::packet::SliceError::*;
+
+        let result = PppoeHeaderSlice::from_slice(self.slice)
+            .map_err(|err| Len(err.add_offset(self.offset)))?;
+        let payload_len = result.payload_len();
+        let ppp_protocol = result.ppp_protocol();
+
+        //set the new data & cut off data after the pppoe payload (e.g. ethernet padding)
+        self.move_by(result.slice().len());
+        self.slice = &self.slice[..payload_len];
+        self.result.pppoe = Some(result);
+
+        //continue parsing (if required)
+        match ppp_protocol {
+            Some(PppoeHeader::PPP_PROTOCOL_IPV4) => self.slice_ipv4(),
+            Some(PppoeHeader::PPP_PROTOCOL_IPV6) => self.slice_ipv6(),
+            _ => Ok(self.result),
+        }
+    }
+
+    pub fn slice_arp(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        use err::packet::SliceError::*;
+
+        let result = ArpPacketSlice::from_slice(self.slice)
+            .map_err(|err| Len(err.add_offset(self.offset)))?;
+
+        //set the new data
+        self.move_by(result.slice().len());
+        self.result.net = Some(NetSlice::Arp(result));
+
+        Ok(self.result)
+    }
+
     pub fn slice_ip(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
         use err::packet::SliceError::*;
 
+        // the ipv4 specific path supports the total length fallback
+        if self.options.lenient_ipv4_total_len && Some(4) == self.slice.first().map(|b| b >> 4) {
+            return self.slice_ipv4().map_err(|err| match err {
+                Ipv4(err::ipv4::HeaderError::HeaderLengthSmallerThanHeader { ihl }) => {
+                    Ip(err::ip::HeaderError::Ipv4HeaderLengthSmallerThanHeader { ihl })
+                }
+                err => err,
+            });
+        }
+
         // slice header, extension headers and identify payload range
-        let ip = IpSlice::from_slice(self.slice).map_err(|err| {
+        let ip = IpSlice::from_slice_with_max_ipv6_exts(
+            self.slice,
+            Some(self.options.max_extension_headers),
+        )
+        .map_err(|err| {
             use err::ip::SliceError as I;
             match err {
                 I::Len(mut err) => {
@@ -127,22 +288,10 @@ impl<'a> SlicedPacketCursor<'a> {
         self.result.net = Some(ip.into());
 
         // continue to the lower layers
-        if payload.fragmented {
+        if payload.fragmented || self.options.stop_after_ip {
             Ok(self.result)
         } else {
-            match payload.ip_number {
-                ip_number::ICMP => self.slice_icmp4().map_err(Len),
-                ip_number::UDP => self.slice_udp().map_err(Len),
-                ip_number::TCP => self.slice_tcp().map_err(|err| {
-                    use err::tcp::HeaderSliceError as I;
-                    match err {
-                        I::Len(err) => Len(err),
-                        I::Content(err) => Tcp(err),
-                    }
-                }),
-                ip_number::IPV6_ICMP => self.slice_icmp6().map_err(Len),
-                _ => Ok(self.result),
-            }
+            self.slice_transport(payload.ip_number)
         }
     }
 
@@ -150,7 +299,11 @@ impl<'a> SlicedPacketCursor<'a> {
         use err::packet::SliceError::*;
 
         // slice ipv4 header & extension headers
-        let ipv4 = Ipv4Slice::from_slice(self.slice).map_err(|err| {
+        let ipv4 = Ipv4Slice::from_slice_with_len_fallback(
+            self.slice,
+            self.options.lenient_ipv4_total_len,
+        )
+        .map_err(|err| {
             use err::ipv4::SliceError as I;
             match err {
                 I::Len(mut err) => {
@@ -176,29 +329,21 @@ impl<'a> SlicedPacketCursor<'a> {
         self.slice = payload.payload;
         self.result.net = Some(NetSlice::Ipv4(ipv4));
 
-        if payload.fragmented {
+        if payload.fragmented || self.options.stop_after_ip {
             Ok(self.result)
         } else {
-            match payload.ip_number {
-                ip_number::UDP => self.slice_udp().map_err(Len),
-                ip_number::TCP => self.slice_tcp().map_err(|err| {
-                    use err::tcp::HeaderSliceError as I;
-                    match err {
-                        I::Len(err) => Len(err),
-                        I::Content(err) => Tcp(err),
-                    }
-                }),
-                ip_number::ICMP => self.slice_icmp4().map_err(Len),
-                ip_number::IPV6_ICMP => self.slice_icmp6().map_err(Len),
-                _ => Ok(self.result),
-            }
+            self.slice_transport(payload.ip_number)
         }
     }
 
     pub fn slice_ipv6(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
         use err::packet::SliceError::*;
 
-        let ipv6 = Ipv6Slice::from_slice(self.slice).map_err(|err| {
+        let ipv6 = Ipv6Slice::from_slice_with_max_exts(
+            self.slice,
+            Some(self.options.max_extension_headers),
+        )
+        .map_err(|err| {
             use err::ipv6::SliceError as I;
             match err {
                 I::Len(mut err) => {
@@ -228,24 +373,42 @@ impl<'a> SlicedPacketCursor<'a> {
         self.result.net = Some(NetSlice::Ipv6(ipv6));
 
         // only try to decode the transport layer if the payload
-        // is not fragmented
-        if payload.fragmented {
+        // is not fragmented (and decoding was not disabled)
+        if payload.fragmented || self.options.stop_after_ip {
             Ok(self.result)
         } else {
-            //parse the data bellow
-            match payload.ip_number {
-                ip_number::ICMP => self.slice_icmp4().map_err(Len),
-                ip_number::UDP => self.slice_udp().map_err(Len),
-                ip_number::TCP => self.slice_tcp().map_err(|err| {
-                    use err::tcp::HeaderSliceError as I;
-                    match err {
-                        I::Len(err) => Len(err),
-                        I::Content(err) => Tcp(err),
-                    }
-                }),
-                ip_number::IPV6_ICMP => self.slice_icmp6().map_err(Len),
-                _ => Ok(self.result),
-            }
+            self.slice_transport(payload.ip_number)
+        }
+    }
+
+    /// Decodes the transport layer identified by the given IP number
+    /// (returns the result unchanged if the protocol is not supported).
+    pub fn slice_transport(
+        self,
+        ip_number: IpNumber,
+    ) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        use err::packet::SliceError::*;
+        match ip_number {
+            ip_number::ICMP => self.slice_icmp4().map_err(Len),
+            ip_number::UDP => self.slice_udp(),
+            ip_number::TCP => self.slice_tcp().map_err(|err| {
+                use err::tcp::HeaderSliceError as I;
+                match err {
+                    I::Len(err) => Len(err),
+                    I::Content(err) => Tcp(err),
+                }
+            }),
+            ip_number::IPV6_ICMP => self.slice_icmp6().map_err(Len),
+            ip_number::IGMP => self.slice_igmp().map_err(Len),
+            ip_number::ENCAP_SEC => self.slice_esp().map_err(Len),
+            ip_number::DCCP => self.slice_dccp().map_err(|err| {
+                use err::dccp::HeaderSliceError as I;
+                match err {
+                    I::Len(err) => Len(err),
+                    I::Content(err) => Dccp(err),
+                }
+            }),
+            _ => Ok(self.result),
         }
     }
 
@@ -286,10 +449,29 @@ impl<'a> SlicedPacketCursor<'a> {
         Ok(self.result)
     }
 
-    pub fn slice_udp(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
+    pub fn slice_igmp(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
         use crate::TransportSlice::*;
 
-        let result = UdpSlice::from_slice(self.slice).map_err(|mut err| {
+        let result = IgmpSlice::from_slice(self.slice).map_err(|mut err| {
+            err.layer_start_offset += self.offset;
+            if LenSource::Slice == err.len_source {
+                err.len_source = self.len_source;
+            }
+            err
+        })?;
+
+        //set the new data
+        self.move_by(result.slice().len());
+        self.result.transport = Some(Igmp(result));
+
+        //done
+        Ok(self.result)
+    }
+
+    pub fn slice_esp(mut self) -> Result<SlicedPacket<'a>, err::LenError> {
+        use crate::TransportSlice::*;
+
+        let result = EspSlice::from_slice(self.slice).map_err(|mut err| {
             err.layer_start_offset += self.offset;
             if LenSource::Slice == err.len_source {
                 err.len_source = self.len_source;
@@ -297,6 +479,90 @@ impl<'a> SlicedPacketCursor<'a> {
             err
         })?;
 
+        //set the new data
+        self.move_by(result.slice().len());
+        self.result.transport = Some(Esp(result));
+
+        //done
+        Ok(self.result)
+    }
+
+    pub fn slice_dccp(mut self) -> Result<SlicedPacket<'a>, err::dccp::HeaderSliceError> {
+        use crate::TransportSlice::*;
+
+        let result = DccpSlice::from_slice(self.slice).map_err(|mut err| {
+            use err::dccp::HeaderSliceError::Len;
+            if let Len(err) = &mut err {
+                err.layer_start_offset += self.offset;
+                if LenSource::Slice == err.len_source {
+                    err.len_source = self.len_source;
+                }
+            }
+            err
+        })?;
+
+        //set the new data
+        self.move_by(result.slice().len());
+        self.result.transport = Some(Dccp(result));
+
+        //done
+        Ok(self.result)
+    }
+
+    pub fn slice_udp(mut self) -> Result<SlicedPacket<'a>, err::packet::SliceError> {
+        use crate::TransportSlice::*;
+        use err::packet::SliceError::*;
+
+        let result = UdpSlice::from_slice(self.slice).map_err(|mut err| {
+            err.layer_start_offset += self.offset;
+            if LenSource::Slice == err.len_source {
+                err.len_source = self.len_source;
+            }
+            Len(err)
+        })?;
+
+        // check that the udp length matches the length of the ip payload
+        // (only if enabled via the parse options)
+        if self.options.strict_udp_len
+            && LenSource::Slice != self.len_source
+            && 0 != result.length()
+            && result.slice().len() != self.slice.len()
+        {
+            return Err(Len(err::LenError {
+                required_len: result.slice().len(),
+                len: self.slice.len(),
+                len_source: self.len_source,
+                layer: err::Layer::UdpPayload,
+                layer_start_offset: self.offset,
+            }));
+        }
+
+        // decode vxlan, gtp-u & geneve (only if enabled via the parse options)
+        let len_source = if LenSource::Slice == result.payload_len_source() {
+            self.len_source
+        } else {
+            result.payload_len_source()
+        };
+        if Some(result.destination_port()) == self.options.vxlan_port {
+            self.result.vxlan = Some(SlicedPacketCursor::slice_vxlan(
+                result.payload(),
+                self.offset + UdpHeader::LEN,
+                len_source,
+            )?);
+        } else if Some(result.destination_port()) == self.options.gtpu_port {
+            self.result.gtpu = Some(SlicedPacketCursor::slice_gtpu(
+                result.payload(),
+                self.offset + UdpHeader::LEN,
+                len_source,
+            )?);
+        } else if Some(result.destination_port()) == self.options.geneve_port {
+            self.result.geneve = Some(SlicedPacketCursor::slice_geneve(
+                result.payload(),
+                self.offset + UdpHeader::LEN,
+                len_source,
+            )?);
+        }
+
         //set the new data
         self.move_by(result.slice().len());
         self.result.transport = Some(Udp(result));
@@ -305,10 +571,187 @@ impl<'a> SlicedPacketCursor<'a> {
         Ok(self.result)
     }
 
+    fn slice_vxlan(
+        slice: &'a [u8],
+        offset: usize,
+        len_source: LenSource,
+    ) -> Result<VxlanSlice<'a>, err::packet::SliceError> {
+        use err::packet::SliceError::*;
+
+        // helper function to set the len source in len errors
+        let set_len_source = |mut err: err::LenError| -> err::packet::SliceError {
+            if LenSource::Slice == err.len_source {
+                err.len_source = len_source;
+            }
+            Len(err)
+        };
+
+        let header = VxlanHeaderSlice::from_slice(slice)
+            .map_err(|err| set_len_source(err.add_offset(offset)))?;
+
+        // decode the encapsulated ethernet frame (nested vxlan
+        // packets are not decoded)
+        let mut cursor =
+            SlicedPacketCursor::new(&slice[header.slice().len()..], &Default::default());
+        cursor.offset = offset + header.slice().len();
+        cursor.len_source = len_source;
+        let inner = cursor.slice_ethernet2().map_err(|err| match err {
+            Len(err) => set_len_source(err),
+            err => err,
+        })?;
+
+        Ok(VxlanSlice {
+            header,
+            link: inner.link,
+            vlan: inner.vlan,
+            net: inner.net,
+            transport: inner.transport,
+        })
+    }
+
+    fn slice_gtpu(
+        slice: &'a [u8],
+        offset: usize,
+        len_source: LenSource,
+    ) -> Result<GtpuSlice<'a>, err::packet::SliceError> {
+        use err::packet::SliceError::*;
+
+        // helper function to set the len source in len errors
+        let set_len_source = |mut err: err::LenError, len_source: LenSource| {
+            if LenSource::Slice == err.len_source {
+                err.len_source = len_source;
+            }
+            Len(err)
+        };
+
+        let header = GtpuHeaderSlice::from_slice(slice)
+            .map_err(|err| set_len_source(err.add_offset(offset), len_source))?;
+
+        // limit the data to the length given in the header
+        let len = GtpuHeader::MIN_LEN + usize::from(header.length());
+        if slice.len() < len {
+            return Err(set_len_source(
+                err::LenError {
+                    required_len: len,
+                    len: slice.len(),
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::GtpuPayload,
+                    layer_start_offset: offset,
+                },
+                len_source,
+            ));
+        }
+        if len < header.slice().len() {
+            return Err(Len(err::LenError {
+                required_len: header.slice().len(),
+                len,
+                len_source: LenSource::GtpuHeaderLen,
+                layer: err::Layer::GtpuHeader,
+                layer_start_offset: offset,
+            }));
+        }
+        let data = &slice[header.slice().len()..len];
+
+        // skip the extension headers
+        let ext_offset = offset + header.slice().len();
+        let mut extension_headers = header.extension_headers(data);
+        for ext in &mut extension_headers {
+            ext.map_err(|err| {
+                set_len_source(err.add_offset(ext_offset), LenSource::GtpuHeaderLen)
+            })?;
+        }
+        let payload = extension_headers.rest();
+        let extensions = &data[..data.len() - payload.len()];
+
+        // decode the encapsulated ip packet of G-PDU messages (nested
+        // tunnels are not decoded)
+        let (net, transport) = if GtpuHeader::MESSAGE_TYPE_G_PDU == header.message_type() {
+            let mut cursor = SlicedPacketCursor::new(payload, &Default::default());
+            cursor.offset = ext_offset + extensions.len();
+            cursor.len_source = LenSource::GtpuHeaderLen;
+            let inner = cursor.slice_ip().map_err(|err| match err {
+                Len(err) => set_len_source(err, LenSource::GtpuHeaderLen),
+                err => err,
+            })?;
+            (inner.net, inner.transport)
+        } else {
+            (None, None)
+        };
+
+        Ok(GtpuSlice {
+            header,
+            extensions,
+            payload,
+            net,
+            transport,
+        })
+    }
+
+    fn slice_geneve(
+        slice: &'a [u8],
+        offset: usize,
+        len_source: LenSource,
+    ) -> Result<GeneveSlice<'a>, err::packet::SliceError> {
+        use err::packet::SliceError::*;
+
+        // helper function to set the len source in len errors
+        let set_len_source = |mut err: err::LenError, len_source: LenSource| {
+            if LenSource::Slice == err.len_source {
+                err.len_source = len_source;
+            }
+            Len(err)
+        };
+
+        let header = GeneveHeaderSlice::from_slice(slice)
+            .map_err(|err| set_len_source(err.add_offset(offset), len_source))?;
+
+        // validate the options
+        let options_offset = offset + GeneveHeader::MIN_LEN;
+        for option in header.options() {
+            option.map_err(|err| {
+                set_len_source(
+                    err.add_offset(options_offset),
+                    LenSource::GeneveHeaderOptLen,
+                )
+            })?;
+        }
+
+        // decode the encapsulated packet based on the protocol type
+        // (nested tunnels are not decoded)
+        let payload = &slice[header.slice().len()..];
+        let mut cursor = SlicedPacketCursor::new(payload, &Default::default());
+        cursor.offset = offset + header.slice().len();
+        cursor.len_source = len_source;
+        let inner = match header.protocol_type() {
+            ether_type::TRANSPARENT_ETHERNET_BRIDGING => cursor.slice_ethernet2(),
+            ether_type::IPV4 => cursor.slice_ipv4(),
+            ether_type::IPV6 => cursor.slice_ipv6(),
+            _ => Ok(cursor.result),
+        }
+        .map_err(|err| match err {
+            Len(err) => set_len_source(err, len_source),
+            err => err,
+        })?;
+
+        Ok(GeneveSlice {
+            header,
+            payload,
+            link: inner.link,
+            vlan: inner.vlan,
+            net: inner.net,
+            transport: inner.transport,
+        })
+    }
+
     pub fn slice_tcp(mut self) -> Result<SlicedPacket<'a>, err::tcp::HeaderSliceError> {
         use crate::TransportSlice::*;
 
-        let result = TcpSlice::from_slice(self.slice).map_err(|mut err| {
+        let result = if self.options.lenient_tcp_data_offset {
+            TcpSlice::from_slice_lax(self.slice).map_err(err::tcp::HeaderSliceError::Len)
+        } else {
+            TcpSlice::from_slice(self.slice)
+        }
+        .map_err(|mut err| {
             use err::tcp::HeaderSliceError::Len;
             if let Len(err) = &mut err {
                 err.layer_start_offset += self.offset;
@@ -42,8 +42,8 @@ prop_compose! {
         pcp in vlan_pcp_any(),
         drop_eligible_indicator in any::<bool>(),
         vlan_id in vlan_id_any(),
-        ether_type in ether_type_any().prop_filter("ether_type must be unknown",
-            |v| !ETHERNET_KNOWN_ETHER_TYPES.iter().any(|&x| v == &x)))
+        ether_type in ether_type_any().prop_filter("ether_type must be unknown & not an IEEE 802.3 length",
+            |v| !ETHERNET_KNOWN_ETHER_TYPES.iter().any(|&x| v == &x) && v.0 > Ieee8023Slice::MAX_PAYLOAD_LEN))
         -> SingleVlanHeader
     {
         SingleVlanHeader {
@@ -98,20 +98,65 @@ prop_compose! {
     }
 }
 
+prop_compose! {
+    pub fn linux_sll_any()(
+        packet_type in any::<u16>(),
+        arp_hrd_type in any::<u16>(),
+        sender_address_valid_length in any::<u16>(),
+        sender_address in prop::array::uniform8(any::<u8>()),
+        protocol_type in ether_type_any())
+        -> LinuxSllHeader
+    {
+        LinuxSllHeader {
+            packet_type: LinuxSllPacketType(packet_type),
+            arp_hrd_type: ArpHardwareId(arp_hrd_type),
+            sender_address_valid_length,
+            sender_address,
+            protocol_type,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn linux_sll2_any()(
+        protocol_type in ether_type_any(),
+        reserved in any::<u16>(),
+        interface_index in any::<u32>(),
+        arp_hrd_type in any::<u16>(),
+        packet_type in any::<u8>(),
+        sender_address_valid_length in any::<u8>(),
+        sender_address in prop::array::uniform8(any::<u8>()))
+        -> LinuxSll2Header
+    {
+        LinuxSll2Header {
+            protocol_type,
+            reserved,
+            interface_index,
+            arp_hrd_type: ArpHardwareId(arp_hrd_type),
+            packet_type: LinuxSllPacketType(u16::from(packet_type)),
+            sender_address_valid_length,
+            sender_address,
+        }
+    }
+}
+
 pub static ETHERNET_KNOWN_ETHER_TYPES: &'static [EtherType] = &[
     ether_type::IPV4,
     ether_type::IPV6,
     ether_type::VLAN_TAGGED_FRAME,
     ether_type::PROVIDER_BRIDGING,
     ether_type::VLAN_DOUBLE_TAGGED_FRAME,
+    ether_type::ARP,
+    ether_type::PPPOE_DISCOVERY,
+    ether_type::PPPOE_SESSION,
 ];
 
 prop_compose! {
     pub fn ethernet_2_unknown()(
         source in prop::array::uniform6(any::<u8>()),
         dest in prop::array::uniform6(any::<u8>()),
-        ether_type in ether_type_any().prop_filter("ether_type must be unknown",
-            |v| !ETHERNET_KNOWN_ETHER_TYPES.iter().any(|&x| v == &x)))
+        ether_type in ether_type_any().prop_filter("ether_type must be unknown & not an IEEE 802.3 length",
+            |v| !ETHERNET_KNOWN_ETHER_TYPES.iter().any(|&x| v == &x) && v.0 > Ieee8023Slice::MAX_PAYLOAD_LEN))
         -> Ethernet2Header
     {
         Ethernet2Header {
@@ -122,6 +167,36 @@ prop_compose! {
     }
 }
 
+prop_compose! {
+    pub fn llc_any()(
+        dsap in any::<u8>(),
+        ssap in any::<u8>(),
+        control in any::<u8>(),
+        ext in any::<u8>())
+        -> LlcHeader
+    {
+        LlcHeader {
+            dsap,
+            ssap,
+            control,
+            control_ext: if control & 0b11 == 0b11 { None } else { Some(ext) },
+        }
+    }
+}
+
+prop_compose! {
+    pub fn snap_any()(
+        oui in prop::array::uniform3(any::<u8>()),
+        protocol_id in any::<u16>())
+        -> SnapHeader
+    {
+        SnapHeader {
+            oui,
+            protocol_id,
+        }
+    }
+}
+
 prop_compose! {
     pub fn vlan_single_with(ether_type: EtherType)(
         pcp in vlan_pcp_any(),
@@ -254,6 +329,10 @@ static IPV4_KNOWN_PROTOCOLS: &[IpNumber] = &[
     ip_number::TCP,
     ip_number::AUTH,
     ip_number::IPV6_ICMP,
+    ip_number::GRE,
+    ip_number::IGMP,
+    ip_number::ENCAP_SEC,
+    ip_number::DCCP,
 ];
 
 prop_compose! {
@@ -359,8 +438,11 @@ static IPV6_KNOWN_NEXT_HEADERS: &[IpNumber] = &[
     ip_number::MOBILITY,
     ip_number::HIP,
     ip_number::SHIM6,
+    ip_number::GRE,
+    ip_number::IGMP,
+    ip_number::ENCAP_SEC,
+    ip_number::DCCP,
     // currently not supported:
-    // - EncapsulatingSecurityPayload
     // - ExperimentalAndTesting0
     // - ExperimentalAndTesting1
 ];
@@ -418,6 +500,92 @@ prop_compose! {
     }
 }
 
+prop_compose! {
+    pub fn ipv6_routing_with(
+        next_header: IpNumber,
+        len: u8
+    ) (
+        next_header in proptest::strategy::Just(next_header),
+        routing_type in any::<u8>(),
+        segments_left in any::<u8>(),
+        data in proptest::collection::vec(any::<u8>(), (len as usize)*8 + 4)
+    ) -> Ipv6RoutingHeader
+    {
+        Ipv6RoutingHeader::new(
+            next_header,
+            routing_type,
+            segments_left,
+            &data[..]
+        ).unwrap()
+    }
+}
+
+prop_compose! {
+    pub fn ipv6_routing_any()
+        (
+            next_header in ip_number_any(),
+            len in any::<u8>()
+        ) (
+            result in ipv6_routing_with(next_header, len)
+    ) -> Ipv6RoutingHeader
+    {
+        result
+    }
+}
+
+prop_compose! {
+    pub fn ipv6_dest_options_with(
+        next_header: IpNumber,
+        len: u8
+    ) (
+        next_header in proptest::strategy::Just(next_header),
+        options in proptest::collection::vec(any::<u8>(), (len as usize)*8 + 6)
+    ) -> Ipv6DestOptionsHeader
+    {
+        Ipv6DestOptionsHeader::new(
+            next_header,
+            &options[..]
+        ).unwrap()
+    }
+}
+
+prop_compose! {
+    pub fn ipv6_dest_options_any()
+        (
+            next_header in ip_number_any(),
+            len in any::<u8>()
+        ) (
+            result in ipv6_dest_options_with(next_header, len)
+    ) -> Ipv6DestOptionsHeader
+    {
+        result
+    }
+}
+
+prop_compose! {
+    pub fn ipv6_mobility_any()
+        (
+            next_header in ip_number_any(),
+            len in any::<u8>()
+        ) (
+            next_header in proptest::strategy::Just(next_header),
+            mh_type in any::<u8>(),
+            reserved in any::<u8>(),
+            checksum in any::<u16>(),
+            message_data in proptest::collection::vec(any::<u8>(), (len as usize)*8 + 2)
+    ) -> Ipv6MobilityHeader
+    {
+        let mut result = Ipv6MobilityHeader::new(
+            next_header,
+            mh_type,
+            &message_data[..]
+        ).unwrap();
+        result.reserved = reserved;
+        result.checksum = checksum;
+        result
+    }
+}
+
 prop_compose! {
     pub fn ipv6_extensions_with(next_header: IpNumber)
     (
@@ -437,7 +605,14 @@ prop_compose! {
     {
         let mut result = Ipv6Extensions {
             hop_by_hop_options: if has_hop_by_hop_options {
-                Some(hop_by_hop_options)
+                // avoid accidental "jumbo payload" options as they change
+                // how the payload length is determined
+                let payload: alloc::vec::Vec<u8> = hop_by_hop_options
+                    .payload()
+                    .iter()
+                    .map(|v| if Ipv6Option::TYPE_JUMBO_PAYLOAD == *v { 0 } else { *v })
+                    .collect();
+                Some(Ipv6RawExtHeader::new_raw(hop_by_hop_options.next_header, &payload).unwrap())
             } else {
                 None
             },
@@ -580,6 +755,237 @@ prop_compose! {
     }
 }
 
+prop_compose! {
+    pub fn arp_any()(
+            hw_addr_size in 0usize..=255,
+            proto_addr_size in 0usize..=255)(
+            hw_addr_type in any::<u16>(),
+            proto_addr_type in any::<u16>(),
+            operation in any::<u16>(),
+            sender_hw_addr in proptest::collection::vec(any::<u8>(), hw_addr_size),
+            sender_protocol_addr in proptest::collection::vec(any::<u8>(), proto_addr_size),
+            target_hw_addr in proptest::collection::vec(any::<u8>(), hw_addr_size),
+            target_protocol_addr in proptest::collection::vec(any::<u8>(), proto_addr_size))
+        -> ArpPacket
+    {
+        ArpPacket::new(
+            ArpHardwareId(hw_addr_type),
+            EtherType(proto_addr_type),
+            ArpOperation(operation),
+            &sender_hw_addr,
+            &sender_protocol_addr,
+            &target_hw_addr,
+            &target_protocol_addr,
+        ).unwrap()
+    }
+}
+
+prop_compose! {
+    pub fn gre_any()(
+            version in 0u8..8,
+            protocol_type in any::<u16>(),
+            checksum in proptest::option::of(any::<u16>()),
+            key in proptest::option::of(any::<u32>()),
+            sequence_number in proptest::option::of(any::<u32>()))
+        -> GreHeader
+    {
+        GreHeader {
+            version,
+            protocol_type: EtherType(protocol_type),
+            checksum,
+            key,
+            sequence_number,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn sctp_any()(
+            source_port in any::<u16>(),
+            destination_port in any::<u16>(),
+            verification_tag in any::<u32>(),
+            checksum in any::<u32>())
+        -> SctpHeader
+    {
+        SctpHeader {
+            source_port,
+            destination_port,
+            verification_tag,
+            checksum,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn igmpv2_any()(
+            igmp_type in any::<u8>(),
+            max_response_time in any::<u8>(),
+            checksum in any::<u16>(),
+            group_address in prop::array::uniform4(any::<u8>()))
+        -> Igmpv2Message
+    {
+        Igmpv2Message {
+            igmp_type,
+            max_response_time,
+            checksum,
+            group_address,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn mpls_any()(
+            label in 0u32..=MplsHeader::MAX_LABEL,
+            traffic_class in 0u8..=MplsHeader::MAX_TRAFFIC_CLASS,
+            bottom_of_stack in any::<bool>(),
+            ttl in any::<u8>())
+        -> MplsHeader
+    {
+        MplsHeader {
+            label,
+            traffic_class,
+            bottom_of_stack,
+            ttl,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn pppoe_any()(
+            version in 0u8..16,
+            pppoe_type in 0u8..16,
+            code in prop_oneof![Just(PppoeHeader::CODE_SESSION_DATA), any::<u8>()],
+            session_id in any::<u16>(),
+            payload_length in 0u16..200,
+            ppp_protocol in any::<u16>())
+        -> PppoeHeader
+    {
+        PppoeHeader {
+            version,
+            pppoe_type,
+            code,
+            session_id,
+            payload_length,
+            ppp_protocol: if PppoeHeader::CODE_SESSION_DATA == code {
+                Some(ppp_protocol)
+            } else {
+                None
+            },
+        }
+    }
+}
+
+prop_compose! {
+    pub fn esp_any()(
+            spi in any::<u32>(),
+            sequence_number in any::<u32>())
+        -> EspHeader
+    {
+        EspHeader {
+            spi,
+            sequence_number,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn dccp_any()(
+            source_port in any::<u16>(),
+            destination_port in any::<u16>(),
+            ccval in 0u8..16,
+            checksum_coverage in 0u8..16,
+            checksum in any::<u16>(),
+            packet_type in 0u8..16,
+            extended_sequence_number in any::<bool>(),
+            sequence_number_48 in 0u64..(1 << 48))
+        -> DccpHeader
+    {
+        DccpHeader {
+            source_port,
+            destination_port,
+            data_offset: if extended_sequence_number { 4 } else { 3 },
+            ccval,
+            checksum_coverage,
+            checksum,
+            packet_type,
+            extended_sequence_number,
+            sequence_number: if extended_sequence_number {
+                sequence_number_48
+            } else {
+                sequence_number_48 & 0xff_ffff
+            },
+        }
+    }
+}
+
+prop_compose! {
+    pub fn gtpu_any()(
+            version in 0u8..8,
+            protocol_type in any::<bool>(),
+            message_type in any::<u8>(),
+            length in any::<u16>(),
+            teid in any::<u32>(),
+            sequence_number in proptest::option::of(any::<u16>()),
+            n_pdu_number in proptest::option::of(any::<u8>()),
+            next_extension_header_type in proptest::option::of(any::<u8>()))
+        -> GtpuHeader
+    {
+        GtpuHeader {
+            version,
+            protocol_type,
+            message_type,
+            length,
+            teid,
+            sequence_number,
+            n_pdu_number,
+            next_extension_header_type,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn vxlan_vni_any()
+        (value in 0u32..=0b11111111_11111111_11111111u32)
+        -> VxlanVni
+    {
+        VxlanVni::try_new(value).unwrap()
+    }
+}
+
+prop_compose! {
+    pub fn vxlan_any()(
+            flags in any::<u8>(),
+            vni in vxlan_vni_any())
+        -> VxlanHeader
+    {
+        VxlanHeader {
+            flags,
+            vni,
+        }
+    }
+}
+
+prop_compose! {
+    pub fn geneve_any()(
+            version in 0u8..4,
+            opt_len in 0u8..=GeneveHeader::MAX_OPT_LEN,
+            oam in any::<bool>(),
+            critical in any::<bool>(),
+            protocol_type in any::<u16>(),
+            vni in vxlan_vni_any())
+        -> GeneveHeader
+    {
+        GeneveHeader {
+            version,
+            opt_len,
+            oam,
+            critical,
+            protocol_type: EtherType(protocol_type),
+            vni,
+        }
+    }
+}
+
 prop_compose! {
     pub fn tcp_any()
         (data_offset in TcpHeader::MIN_DATA_OFFSET..(TcpHeader::MAX_DATA_OFFSET + 1))
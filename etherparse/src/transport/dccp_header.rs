@@ -0,0 +1,338 @@
+use crate::*;
+use arrayvec::ArrayVec;
+
+/// DCCP (Datagram Congestion Control Protocol) generic header according to
+/// [RFC 4340](https://datatracker.ietf.org/doc/html/rfc4340#section-5.1).
+///
+/// Depending on the "extended sequence number" flag ("X") the generic
+/// header has a length of 16 bytes (48 bit sequence number) or 12 bytes
+/// (24 bit sequence number). The additional fields (e.g. acknowledgement
+/// number or service code) & options following the generic header are
+/// not part of this struct (see [`DccpSlice::additional_fields_and_options`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DccpHeader {
+    /// Source port of the packet.
+    pub source_port: u16,
+    /// Destination port of the packet.
+    pub destination_port: u16,
+    /// Offset from the start of the DCCP header to the start of the
+    /// application data in 32 bit words (includes the generic header,
+    /// the additional fields & the options).
+    pub data_offset: u8,
+    /// Value used by the congestion control mechanism of the sender
+    /// ("CCVal", 4 bits, only the lower 4 bits are serialized).
+    pub ccval: u8,
+    /// Checksum coverage ("CsCov", 4 bits, only the lower 4 bits are
+    /// serialized). Determines which parts of the packet are covered by
+    /// the checksum (0 means the whole packet is covered).
+    pub checksum_coverage: u8,
+    /// Checksum of the DCCP header, the pseudo header & the covered
+    /// part of the payload.
+    pub checksum: u16,
+    /// Type of the packet (4 bits, only the lower 4 bits are serialized,
+    /// e.g. [`DccpHeader::PACKET_TYPE_DATA`]).
+    pub packet_type: u8,
+    /// Flag indicating that the 48 bit extended sequence number
+    /// is used ("X").
+    pub extended_sequence_number: bool,
+    /// Sequence number of the packet (48 bits if `extended_sequence_number`
+    /// is set, otherwise 24 bits. Only the lower 48 or 24 bits
+    /// are serialized).
+    pub sequence_number: u64,
+}
+
+impl DccpHeader {
+    /// Length of a DCCP generic header with a 24 bit sequence number
+    /// in bytes/octets.
+    pub const MIN_LEN: usize = 12;
+
+    /// Length of a DCCP generic header with a 48 bit sequence number
+    /// in bytes/octets.
+    pub const MAX_LEN: usize = 16;
+
+    /// Packet type of a "DCCP-Request" packet.
+    pub const PACKET_TYPE_REQUEST: u8 = 0;
+
+    /// Packet type of a "DCCP-Response" packet.
+    pub const PACKET_TYPE_RESPONSE: u8 = 1;
+
+    /// Packet type of a "DCCP-Data" packet.
+    pub const PACKET_TYPE_DATA: u8 = 2;
+
+    /// Packet type of a "DCCP-Ack" packet.
+    pub const PACKET_TYPE_ACK: u8 = 3;
+
+    /// Packet type of a "DCCP-DataAck" packet.
+    pub const PACKET_TYPE_DATA_ACK: u8 = 4;
+
+    /// Packet type of a "DCCP-CloseReq" packet.
+    pub const PACKET_TYPE_CLOSE_REQ: u8 = 5;
+
+    /// Packet type of a "DCCP-Close" packet.
+    pub const PACKET_TYPE_CLOSE: u8 = 6;
+
+    /// Packet type of a "DCCP-Reset" packet.
+    pub const PACKET_TYPE_RESET: u8 = 7;
+
+    /// Packet type of a "DCCP-Sync" packet.
+    pub const PACKET_TYPE_SYNC: u8 = 8;
+
+    /// Packet type of a "DCCP-SyncAck" packet.
+    pub const PACKET_TYPE_SYNC_ACK: u8 = 9;
+
+    /// Read a DCCP generic header from a slice and return the header &
+    /// unused parts of the slice (starting with the additional fields
+    /// & options).
+    pub fn from_slice(slice: &[u8]) -> Result<(DccpHeader, &[u8]), err::LenError> {
+        let s = DccpHeaderSlice::from_slice(slice)?;
+        Ok((s.to_header(), &slice[s.slice().len()..]))
+    }
+
+    /// Reads a DCCP generic header from the current position of the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<DccpHeader, std::io::Error> {
+        let mut buffer = [0u8; DccpHeader::MAX_LEN];
+        reader.read_exact(&mut buffer[..DccpHeader::MIN_LEN])?;
+        let len = DccpHeaderSlice::header_len_from_flags(buffer[8]);
+        reader.read_exact(&mut buffer[DccpHeader::MIN_LEN..len])?;
+        // SAFETY:
+        // Safe as the buffer contains at least the required
+        // number of bytes given by the "X" flag.
+        Ok(unsafe { DccpHeaderSlice::from_slice_unchecked(&buffer[..len]) }.to_header())
+    }
+
+    /// Writes the DCCP generic header to the given writer (additional
+    /// fields & options are not written).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the serialized generic header in bytes (additional
+    /// fields & options excluded).
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        if self.extended_sequence_number {
+            DccpHeader::MAX_LEN
+        } else {
+            DccpHeader::MIN_LEN
+        }
+    }
+
+    /// Returns the serialized generic header.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { DccpHeader::MAX_LEN }> {
+        let source_port_be = self.source_port.to_be_bytes();
+        let destination_port_be = self.destination_port.to_be_bytes();
+        let checksum_be = self.checksum.to_be_bytes();
+        let seq_be = self.sequence_number.to_be_bytes();
+
+        let mut result = ArrayVec::new();
+        result.extend([
+            source_port_be[0],
+            source_port_be[1],
+            destination_port_be[0],
+            destination_port_be[1],
+            self.data_offset,
+            ((self.ccval & 0b1111) << 4) | (self.checksum_coverage & 0b1111),
+            checksum_be[0],
+            checksum_be[1],
+            ((self.packet_type & 0b1111) << 1) | u8::from(self.extended_sequence_number),
+        ]);
+        if self.extended_sequence_number {
+            result.extend([
+                0, seq_be[2], seq_be[3], seq_be[4], seq_be[5], seq_be[6], seq_be[7],
+            ]);
+        } else {
+            result.extend([seq_be[5], seq_be[6], seq_be[7]]);
+        }
+        result
+    }
+}
+
+impl Default for DccpHeader {
+    fn default() -> Self {
+        DccpHeader {
+            source_port: 0,
+            destination_port: 0,
+            data_offset: (DccpHeader::MAX_LEN / 4) as u8,
+            ccval: 0,
+            checksum_coverage: 0,
+            checksum: 0,
+            packet_type: DccpHeader::PACKET_TYPE_DATA,
+            extended_sequence_number: true,
+            sequence_number: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn default() {
+        let header: DccpHeader = Default::default();
+        assert_eq!(0, header.source_port);
+        assert_eq!(0, header.destination_port);
+        assert_eq!(4, header.data_offset);
+        assert_eq!(0, header.ccval);
+        assert_eq!(0, header.checksum_coverage);
+        assert_eq!(0, header.checksum);
+        assert_eq!(DccpHeader::PACKET_TYPE_DATA, header.packet_type);
+        assert!(header.extended_sequence_number);
+        assert_eq!(0, header.sequence_number);
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            header in dccp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(header.header_len() + dummy_data.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+
+            // ok
+            {
+                let (actual, rest) = DccpHeader::from_slice(&buffer).unwrap();
+                assert_eq!(actual, header);
+                assert_eq!(rest, &dummy_data[..]);
+            }
+
+            // length error
+            for len in 0..header.header_len() {
+                assert_eq!(
+                    DccpHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: if len < DccpHeader::MIN_LEN {
+                            DccpHeader::MIN_LEN
+                        } else {
+                            header.header_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::DccpHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(header in dccp_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(header.header_len());
+                header.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &header.to_bytes()[..]);
+
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(header, DccpHeader::read(&mut cursor).unwrap());
+                assert_eq!(header.header_len(), cursor.position() as usize);
+            }
+
+            // io errors
+            for len in 0..header.header_len() {
+                let bytes = header.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(DccpHeader::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; DccpHeader::MAX_LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(header.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn header_len() {
+        let mut header: DccpHeader = Default::default();
+        assert_eq!(16, header.header_len());
+        header.extended_sequence_number = false;
+        assert_eq!(12, header.header_len());
+    }
+
+    #[test]
+    fn to_bytes() {
+        // 48 bit sequence number
+        assert_eq!(
+            &DccpHeader {
+                source_port: 0x1234,
+                destination_port: 0x5678,
+                data_offset: 5,
+                ccval: 0xa,
+                checksum_coverage: 0x3,
+                checksum: 0x9abc,
+                packet_type: DccpHeader::PACKET_TYPE_REQUEST,
+                extended_sequence_number: true,
+                sequence_number: 0x0102_0304_0506,
+            }
+            .to_bytes()[..],
+            &[
+                0x12,
+                0x34,
+                0x56,
+                0x78,
+                5,
+                0xa3,
+                0x9a,
+                0xbc,
+                0b0000_0001,
+                0,
+                1,
+                2,
+                3,
+                4,
+                5,
+                6
+            ]
+        );
+        // 24 bit sequence number & bits exceeding the field sizes masked
+        assert_eq!(
+            &DccpHeader {
+                source_port: 1,
+                destination_port: 2,
+                data_offset: 3,
+                ccval: 0xf1,
+                checksum_coverage: 0xf2,
+                checksum: 0,
+                packet_type: 0xf0 | DccpHeader::PACKET_TYPE_DATA_ACK,
+                extended_sequence_number: false,
+                sequence_number: 0xffff_ff12_3456,
+            }
+            .to_bytes()[..],
+            &[0, 1, 0, 2, 3, 0x12, 0, 0, 0b0000_1000, 0x12, 0x34, 0x56]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_debug(header in dccp_any()) {
+            assert_eq!(header, header.clone());
+            assert_eq!(
+                format!(
+                    "DccpHeader {{ source_port: {:?}, destination_port: {:?}, data_offset: {:?}, ccval: {:?}, checksum_coverage: {:?}, checksum: {:?}, packet_type: {:?}, extended_sequence_number: {:?}, sequence_number: {:?} }}",
+                    header.source_port,
+                    header.destination_port,
+                    header.data_offset,
+                    header.ccval,
+                    header.checksum_coverage,
+                    header.checksum,
+                    header.packet_type,
+                    header.extended_sequence_number,
+                    header.sequence_number
+                ),
+                format!("{:?}", header)
+            );
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing a DCCP generic header (additional fields & options
+/// excluded).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DccpHeaderSlice<'a> {
+    /// Slice containing the header data.
+    slice: &'a [u8],
+}
+
+impl<'a> DccpHeaderSlice<'a> {
+    /// Creates a DCCP generic header slice from a slice.
+    ///
+    /// The length of the header is determined by the "extended sequence
+    /// number" flag ("X"). If it is set the header has a length of 16
+    /// bytes, otherwise 12 bytes.
+    pub fn from_slice(slice: &'a [u8]) -> Result<DccpHeaderSlice<'a>, err::LenError> {
+        // check length
+        if slice.len() < DccpHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: DccpHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::DccpHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check the length again now that the sequence number size is known
+        let len = DccpHeaderSlice::header_len_from_flags(slice[8]);
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::DccpHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(DccpHeaderSlice {
+            // SAFETY:
+            // Safe as the slice has been checked in the previous if
+            // to have at least the the length of the variable len.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Creates a DCCP generic header slice from a slice (assumes slice
+    /// size & content was validated before).
+    ///
+    /// # Safety
+    ///
+    /// This method assumes that the slice was previously validated to contain
+    /// a valid DCCP generic header. This means the slice length must at least
+    /// be 12 and 16 if the "X" flag in the 9th byte is set.
+    ///
+    /// If these preconditions are not fulfilled the behavior of this function
+    /// and the methods of the return [`DccpHeaderSlice`] will be undefined.
+    pub unsafe fn from_slice_unchecked(slice: &'a [u8]) -> DccpHeaderSlice<'a> {
+        DccpHeaderSlice {
+            slice: from_raw_parts(
+                slice.as_ptr(),
+                DccpHeaderSlice::header_len_from_flags(*slice.get_unchecked(8)),
+            ),
+        }
+    }
+
+    /// Calculates the generic header length based on the 9th byte of
+    /// a DCCP header (containing the type & "X" flag).
+    #[inline]
+    pub(crate) fn header_len_from_flags(flags: u8) -> usize {
+        if 0 != flags & 1 {
+            DccpHeader::MAX_LEN
+        } else {
+            DccpHeader::MIN_LEN
+        }
+    }
+
+    /// Returns the slice containing the DCCP generic header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Reads the "source port" from the slice.
+    #[inline]
+    pub fn source_port(&self) -> u16 {
+        // SAFETY:
+        // Slice size checked to be at least 12 bytes in constructor.
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr()) }
+    }
+
+    /// Reads the "destination port" from the slice.
+    #[inline]
+    pub fn destination_port(&self) -> u16 {
+        // SAFETY:
+        // Slice size checked to be at least 12 bytes in constructor.
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+    }
+
+    /// Reads the "data offset" from the slice (offset to the application
+    /// data in 32 bit words).
+    #[inline]
+    pub fn data_offset(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 12 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(4) }
+    }
+
+    /// Reads the "CCVal" field (4 bits) from the slice.
+    #[inline]
+    pub fn ccval(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 12 bytes in constructor.
+        (unsafe { *self.slice.get_unchecked(5) }) >> 4
+    }
+
+    /// Reads the "checksum coverage" ("CsCov", 4 bits) from the slice.
+    #[inline]
+    pub fn checksum_coverage(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 12 bytes in constructor.
+        (unsafe { *self.slice.get_unchecked(5) }) & 0b1111
+    }
+
+    /// Reads the "checksum" from the slice.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        // SAFETY:
+        // Slice size checked to be at least 12 bytes in constructor.
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(6)) }
+    }
+
+    /// Reads the packet "type" (4 bits) from the slice.
+    #[inline]
+    pub fn packet_type(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 12 bytes in constructor.
+        (unsafe { *self.slice.get_unchecked(8) } >> 1) & 0b1111
+    }
+
+    /// Returns true if the "extended sequence number" flag ("X") is set.
+    #[inline]
+    pub fn extended_sequence_number(&self) -> bool {
+        // SAFETY:
+        // Slice size checked to be at least 12 bytes in constructor.
+        0 != unsafe { *self.slice.get_unchecked(8) } & 1
+    }
+
+    /// Reads the "sequence number" from the slice (48 bits if the "X"
+    /// flag is set, otherwise 24 bits).
+    #[inline]
+    pub fn sequence_number(&self) -> u64 {
+        // SAFETY:
+        // Slice size checked in the constructor to be at least 16 bytes
+        // if the "X" flag is set and 12 bytes otherwise.
+        unsafe {
+            if self.extended_sequence_number() {
+                u64::from_be_bytes([
+                    0,
+                    0,
+                    *self.slice.get_unchecked(10),
+                    *self.slice.get_unchecked(11),
+                    *self.slice.get_unchecked(12),
+                    *self.slice.get_unchecked(13),
+                    *self.slice.get_unchecked(14),
+                    *self.slice.get_unchecked(15),
+                ])
+            } else {
+                u64::from_be_bytes([
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                    *self.slice.get_unchecked(9),
+                    *self.slice.get_unchecked(10),
+                    *self.slice.get_unchecked(11),
+                ])
+            }
+        }
+    }
+
+    /// Decode all the fields and copy the results to a [`DccpHeader`] struct.
+    pub fn to_header(&self) -> DccpHeader {
+        DccpHeader {
+            source_port: self.source_port(),
+            destination_port: self.destination_port(),
+            data_offset: self.data_offset(),
+            ccval: self.ccval(),
+            checksum_coverage: self.checksum_coverage(),
+            checksum: self.checksum(),
+            packet_type: self.packet_type(),
+            extended_sequence_number: self.extended_sequence_number(),
+            sequence_number: self.sequence_number(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::format;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(header in dccp_any()) {
+            let bytes = header.to_bytes();
+            let slice = DccpHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.clone(), slice);
+            assert_eq!(
+                format!("{:?}", slice),
+                format!("DccpHeaderSlice {{ slice: {:?} }}", slice.slice())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(header in dccp_any()) {
+            let bytes = header.to_bytes();
+
+            // ok
+            {
+                let slice = DccpHeaderSlice::from_slice(&bytes).unwrap();
+                assert_eq!(slice.slice(), &bytes[..]);
+            }
+
+            // length error
+            for len in 0..bytes.len() {
+                assert_eq!(
+                    DccpHeaderSlice::from_slice(&bytes[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: if len < DccpHeader::MIN_LEN {
+                            DccpHeader::MIN_LEN
+                        } else {
+                            header.header_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::DccpHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_unchecked(header in dccp_any()) {
+            let bytes = header.to_bytes();
+            let slice = unsafe {
+                DccpHeaderSlice::from_slice_unchecked(&bytes)
+            };
+            assert_eq!(&bytes[..], slice.slice());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(header in dccp_any()) {
+            let bytes = header.to_bytes();
+            let slice = DccpHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.source_port(), header.source_port);
+            assert_eq!(slice.destination_port(), header.destination_port);
+            assert_eq!(slice.data_offset(), header.data_offset);
+            assert_eq!(slice.ccval(), header.ccval);
+            assert_eq!(slice.checksum_coverage(), header.checksum_coverage);
+            assert_eq!(slice.checksum(), header.checksum);
+            assert_eq!(slice.packet_type(), header.packet_type);
+            assert_eq!(slice.extended_sequence_number(), header.extended_sequence_number);
+            assert_eq!(slice.sequence_number(), header.sequence_number);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(header in dccp_any()) {
+            let bytes = header.to_bytes();
+            let slice = DccpHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(header, slice.to_header());
+        }
+    }
+}
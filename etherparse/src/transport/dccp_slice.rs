@@ -0,0 +1,419 @@
+use crate::{
+    err::{ValueTooBigError, ValueType},
+    *,
+};
+
+/// Slice containing the DCCP header (generic header, additional fields
+/// & options) & payload.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DccpSlice<'a> {
+    header_len: usize,
+    slice: &'a [u8],
+}
+
+impl<'a> DccpSlice<'a> {
+    /// Try creating a [`DccpSlice`] from a slice containing the
+    /// DCCP header and the DCCP payload.
+    ///
+    /// The length of the header (generic header, additional fields &
+    /// options) is determined via the "data offset" field.
+    pub fn from_slice(slice: &'a [u8]) -> Result<DccpSlice<'a>, err::dccp::HeaderSliceError> {
+        use err::dccp::{HeaderError::*, HeaderSliceError::*};
+
+        // check that the generic header is present
+        let header = DccpHeaderSlice::from_slice(slice).map_err(Len)?;
+
+        // "data offset" contains the length of the header in 4 byte steps
+        let header_len = usize::from(header.data_offset()) * 4;
+        if header_len < header.slice().len() {
+            Err(Content(DataOffsetTooSmall {
+                data_offset: header.data_offset(),
+                extended_sequence_number: header.extended_sequence_number(),
+            }))
+        } else if slice.len() < header_len {
+            Err(Len(err::LenError {
+                required_len: header_len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::DccpHeader,
+                layer_start_offset: 0,
+            }))
+        } else {
+            Ok(DccpSlice { header_len, slice })
+        }
+    }
+
+    /// Returns the slice containing the DCCP header and payload.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the DCCP generic header.
+    #[inline]
+    pub fn header(&self) -> DccpHeaderSlice<'a> {
+        // SAFETY: Safe as the slice was verified in the constructor
+        // to contain the generic header.
+        unsafe { DccpHeaderSlice::from_slice_unchecked(self.slice) }
+    }
+
+    /// Returns the slice containing the complete DCCP header (generic
+    /// header, additional fields & options).
+    #[inline]
+    pub fn header_slice(&self) -> &'a [u8] {
+        unsafe {
+            // SAFETY: Safe as the slice was verified
+            // to be at least header_len long.
+            core::slice::from_raw_parts(self.slice.as_ptr(), self.header_len)
+        }
+    }
+
+    /// Length of the complete DCCP header (generic header, additional
+    /// fields & options) in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.header_len
+    }
+
+    /// Returns the slice containing the data between the generic header
+    /// and the payload (type specific additional fields like the
+    /// acknowledgement number & the options).
+    #[inline]
+    pub fn additional_fields_and_options(&self) -> &'a [u8] {
+        let start = self.header().slice().len();
+        unsafe {
+            // SAFETY: Safe as the constructor verified that header_len
+            // is at least as big as the generic header & that the slice
+            // is at least header_len long.
+            core::slice::from_raw_parts(self.slice.as_ptr().add(start), self.header_len - start)
+        }
+    }
+
+    /// Returns the slice containing the DCCP payload (application data).
+    #[inline]
+    pub fn payload(&self) -> &'a [u8] {
+        unsafe {
+            // SAFETY: Safe as the slice was verified
+            // to be at least header_len long.
+            core::slice::from_raw_parts(
+                self.slice.as_ptr().add(self.header_len),
+                self.slice.len() - self.header_len,
+            )
+        }
+    }
+
+    /// Length of the DCCP payload in bytes.
+    #[inline]
+    pub fn payload_len(&self) -> usize {
+        self.slice.len() - self.header_len
+    }
+
+    /// Reads the "source port" from the DCCP header.
+    #[inline]
+    pub fn source_port(&self) -> u16 {
+        self.header().source_port()
+    }
+
+    /// Reads the "destination port" from the DCCP header.
+    #[inline]
+    pub fn destination_port(&self) -> u16 {
+        self.header().destination_port()
+    }
+
+    /// Reads the packet "type" (4 bits) from the DCCP header.
+    #[inline]
+    pub fn packet_type(&self) -> u8 {
+        self.header().packet_type()
+    }
+
+    /// Reads the "sequence number" from the DCCP header (48 bits if the
+    /// "X" flag is set, otherwise 24 bits).
+    #[inline]
+    pub fn sequence_number(&self) -> u64 {
+        self.header().sequence_number()
+    }
+
+    /// Decode all the fields of the DCCP generic header and copy the
+    /// results to a [`DccpHeader`] struct.
+    #[inline]
+    pub fn to_header(&self) -> DccpHeader {
+        self.header().to_header()
+    }
+
+    /// Returns the part of the payload covered by the checksum (based on
+    /// the "checksum coverage" field of the header).
+    ///
+    /// A checksum coverage of 0 covers the complete payload, otherwise
+    /// the first `(coverage - 1) * 4` bytes of the payload are covered.
+    /// If the coverage exceeds the payload the complete payload is
+    /// returned (note that RFC 4340 requires receivers to drop such
+    /// packets).
+    pub fn checksum_covered_payload(&self) -> &'a [u8] {
+        let payload = self.payload();
+        match self.header().checksum_coverage() {
+            0 => payload,
+            cov => {
+                let len = (usize::from(cov) - 1) * 4;
+                if len < payload.len() {
+                    &payload[..len]
+                } else {
+                    payload
+                }
+            }
+        }
+    }
+
+    /// Calculates the checksum for the current packet in ipv4 mode and
+    /// returns the result. This does NOT set the checksum.
+    ///
+    /// The checksum covers the IPv4 pseudo header, the DCCP header and
+    /// the part of the payload selected by the "checksum coverage"
+    /// field (see [`DccpSlice::checksum_covered_payload`]).
+    pub fn calc_checksum_ipv4(
+        &self,
+        source_ip: [u8; 4],
+        destination_ip: [u8; 4],
+    ) -> Result<u16, ValueTooBigError<usize>> {
+        // check that the total length fits into the field
+        if usize::from(u16::MAX) < self.slice.len() {
+            return Err(ValueTooBigError {
+                actual: self.slice.len(),
+                max_allowed: usize::from(u16::MAX),
+                value_type: ValueType::DccpLengthIpv4,
+            });
+        }
+
+        // calculate the checksum
+        Ok(self.calc_checksum_post_ip(
+            checksum::Sum16BitWords::new()
+                .add_4bytes(source_ip)
+                .add_4bytes(destination_ip)
+                .add_2bytes([0, ip_number::DCCP.0])
+                .add_2bytes((self.slice.len() as u16).to_be_bytes()),
+        ))
+    }
+
+    /// Calculates the checksum for the current packet in ipv6 mode and
+    /// returns the result. This does NOT set the checksum.
+    ///
+    /// The checksum covers the IPv6 pseudo header, the DCCP header and
+    /// the part of the payload selected by the "checksum coverage"
+    /// field (see [`DccpSlice::checksum_covered_payload`]).
+    pub fn calc_checksum_ipv6(
+        &self,
+        source: [u8; 16],
+        destination: [u8; 16],
+    ) -> Result<u16, ValueTooBigError<usize>> {
+        // check that the total length fits into the field
+        #[cfg(not(any(target_pointer_width = "16", target_pointer_width = "32")))]
+        if (u32::MAX as usize) < self.slice.len() {
+            return Err(ValueTooBigError {
+                actual: self.slice.len(),
+                max_allowed: (u32::MAX as usize),
+                value_type: ValueType::DccpLengthIpv6,
+            });
+        }
+
+        // calculate the checksum
+        Ok(self.calc_checksum_post_ip(
+            checksum::Sum16BitWords::new()
+                .add_16bytes(source)
+                .add_16bytes(destination)
+                .add_4bytes((self.slice.len() as u32).to_be_bytes())
+                .add_2bytes([0, ip_number::DCCP.0]),
+        ))
+    }
+
+    /// This method takes the sum of the pseudo ip header and calculates the rest of the checksum.
+    fn calc_checksum_post_ip(&self, ip_pseudo_header_sum: checksum::Sum16BitWords) -> u16 {
+        ip_pseudo_header_sum
+            .add_slice(&self.slice[..6]) //until checksum
+            .add_slice(&self.slice[8..self.header_len])
+            .add_slice(self.checksum_covered_payload())
+            .ones_complement()
+            .to_be()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            header in dccp_any(),
+            additional in proptest::collection::vec(any::<u8>(), 0..3),
+            payload in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // build a header with a matching data offset
+            let additional_len = additional.len() * 4;
+            let header = {
+                let mut header = header.clone();
+                header.data_offset = ((header.header_len() + additional_len) / 4) as u8;
+                header
+            };
+            let mut buffer = Vec::with_capacity(header.header_len() + additional_len + payload.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            for v in &additional {
+                buffer.extend_from_slice(&[*v; 4]);
+            }
+            buffer.extend_from_slice(&payload);
+            let header_len = header.header_len() + additional_len;
+
+            // ok case
+            {
+                let slice = DccpSlice::from_slice(&buffer).unwrap();
+                assert_eq!(slice.slice(), &buffer[..]);
+                assert_eq!(slice.header().slice(), &buffer[..header.header_len()]);
+                assert_eq!(slice.header_slice(), &buffer[..header_len]);
+                assert_eq!(slice.header_len(), header_len);
+                assert_eq!(
+                    slice.additional_fields_and_options(),
+                    &buffer[header.header_len()..header_len]
+                );
+                assert_eq!(slice.payload(), &payload[..]);
+                assert_eq!(slice.payload_len(), payload.len());
+                assert_eq!(slice.source_port(), header.source_port);
+                assert_eq!(slice.destination_port(), header.destination_port);
+                assert_eq!(slice.packet_type(), header.packet_type);
+                assert_eq!(slice.sequence_number(), header.sequence_number);
+                assert_eq!(slice.to_header(), header);
+            }
+
+            // length error
+            for len in 0..header_len {
+                assert_eq!(
+                    DccpSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::dccp::HeaderSliceError::Len(err::LenError{
+                        required_len: if len < DccpHeader::MIN_LEN {
+                            DccpHeader::MIN_LEN
+                        } else if len < header.header_len() {
+                            header.header_len()
+                        } else {
+                            header_len
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::DccpHeader,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+
+            // data offset too small
+            for data_offset in 0..(header.header_len() / 4) as u8 {
+                let mut modified = buffer.clone();
+                modified[4] = data_offset;
+                assert_eq!(
+                    DccpSlice::from_slice(&modified).unwrap_err(),
+                    err::dccp::HeaderSliceError::Content(
+                        err::dccp::HeaderError::DataOffsetTooSmall {
+                            data_offset,
+                            extended_sequence_number: header.extended_sequence_number,
+                        }
+                    )
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn calc_checksum(
+            header in dccp_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20),
+            ipv4_source in any::<[u8;4]>(),
+            ipv4_destination in any::<[u8;4]>(),
+            ipv6_source in any::<[u8;16]>(),
+            ipv6_destination in any::<[u8;16]>(),
+        ) {
+            let mut buffer = Vec::with_capacity(header.header_len() + payload.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&payload);
+            let len = buffer.len();
+
+            // covered payload
+            let covered_len = if 0 == header.checksum_coverage {
+                payload.len()
+            } else {
+                (usize::from(header.checksum_coverage - 1) * 4).min(payload.len())
+            };
+            let covered_end = header.header_len() + covered_len;
+            {
+                let slice = DccpSlice::from_slice(&buffer).unwrap();
+                assert_eq!(slice.checksum_covered_payload(), &payload[..covered_len]);
+            }
+
+            // ipv4 (the sum over the pseudo header & the covered data
+            // including the checksum has to result in zero)
+            {
+                let checksum = DccpSlice::from_slice(&buffer)
+                    .unwrap()
+                    .calc_checksum_ipv4(ipv4_source, ipv4_destination)
+                    .unwrap();
+                let mut modified = buffer.clone();
+                modified[6..8].copy_from_slice(&checksum.to_be_bytes());
+                assert_eq!(
+                    0,
+                    checksum::Sum16BitWords::new()
+                        .add_4bytes(ipv4_source)
+                        .add_4bytes(ipv4_destination)
+                        .add_2bytes([0, ip_number::DCCP.0])
+                        .add_2bytes((len as u16).to_be_bytes())
+                        .add_slice(&modified[..covered_end])
+                        .ones_complement()
+                );
+            }
+
+            // ipv6
+            {
+                let checksum = DccpSlice::from_slice(&buffer)
+                    .unwrap()
+                    .calc_checksum_ipv6(ipv6_source, ipv6_destination)
+                    .unwrap();
+                let mut modified = buffer.clone();
+                modified[6..8].copy_from_slice(&checksum.to_be_bytes());
+                assert_eq!(
+                    0,
+                    checksum::Sum16BitWords::new()
+                        .add_16bytes(ipv6_source)
+                        .add_16bytes(ipv6_destination)
+                        .add_4bytes((len as u32).to_be_bytes())
+                        .add_2bytes([0, ip_number::DCCP.0])
+                        .add_slice(&modified[..covered_end])
+                        .ones_complement()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn calc_checksum_ipv4_len_error() {
+        let mut buffer = Vec::with_capacity(usize::from(u16::MAX) + 1);
+        buffer.extend_from_slice(&DccpHeader::default().to_bytes());
+        buffer.resize(usize::from(u16::MAX) + 1, 0);
+        let slice = DccpSlice::from_slice(&buffer).unwrap();
+        assert_eq!(
+            slice.calc_checksum_ipv4([0; 4], [0; 4]),
+            Err(err::ValueTooBigError {
+                actual: usize::from(u16::MAX) + 1,
+                max_allowed: usize::from(u16::MAX),
+                value_type: err::ValueType::DccpLengthIpv4,
+            })
+        );
+    }
+
+    #[test]
+    fn clone_eq_dbg() {
+        let bytes = DccpHeader::default().to_bytes();
+        let slice = DccpSlice::from_slice(&bytes).unwrap();
+        assert_eq!(slice, slice.clone());
+        assert_eq!(
+            &format!("DccpSlice {{ header_len: 16, slice: {:?} }}", slice.slice()),
+            &format!("{:?}", slice)
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use crate::{err::dhcpv4::HeaderSliceError, *};
+
+/// Slice containing a DHCPv4 (or BOOTP) message (RFC 2131 & RFC 2132).
+///
+/// DHCP messages are usually transported via UDP using the ports
+/// [`Dhcpv4Message::SERVER_PORT`] (67) & [`Dhcpv4Message::CLIENT_PORT`] (68).
+/// As the protocol is only identified by the port numbers, the message
+/// is not automatically decoded by [`SlicedPacket`] & has to be decoded
+/// from the UDP payload:
+///
+/// ```
+/// use etherparse::{Dhcpv4Message, SlicedPacket, TransportSlice};
+///
+/// # let packet = {
+/// #     let mut message = [0u8; 244];
+/// #     message[0] = 1; // op (request)
+/// #     message[236..240].copy_from_slice(&Dhcpv4Message::MAGIC_COOKIE);
+/// #     message[240..].copy_from_slice(&[53, 1, 1, 255]); // DHCPDISCOVER
+/// #     let builder = etherparse::PacketBuilder::ipv4([0; 4], [255; 4], 64).udp(68, 67);
+/// #     let mut packet = Vec::new();
+/// #     builder.write(&mut packet, &message).unwrap();
+/// #     packet
+/// # };
+/// let sliced = SlicedPacket::from_ip(&packet).unwrap();
+/// if let Some(TransportSlice::Udp(udp)) = sliced.transport {
+///     if udp.destination_port() == Dhcpv4Message::SERVER_PORT {
+///         let message = Dhcpv4Message::from_slice(udp.payload()).unwrap();
+///         for (code, data) in message.options() {
+///             println!("option {}: {:?}", code, data);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dhcpv4Message<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> Dhcpv4Message<'a> {
+    /// UDP port used by DHCP servers (& BOOTP relays).
+    pub const SERVER_PORT: u16 = 67;
+
+    /// UDP port used by DHCP clients.
+    pub const CLIENT_PORT: u16 = 68;
+
+    /// Length of the fixed part of the message including the magic
+    /// cookie (options start after this).
+    pub const MIN_LEN: usize = 240;
+
+    /// Magic cookie in front of the options.
+    pub const MAGIC_COOKIE: [u8; 4] = [99, 130, 83, 99];
+
+    /// Value of the `op` field in messages sent by a client.
+    pub const OP_BOOTREQUEST: u8 = 1;
+
+    /// Value of the `op` field in messages sent by a server.
+    pub const OP_BOOTREPLY: u8 = 2;
+
+    /// Bit in the `flags` field requesting broadcast replies.
+    pub const FLAG_BROADCAST: u16 = 0x8000;
+
+    /// Option code of the pad option (single byte without length).
+    pub const OPTION_PAD: u8 = 0;
+
+    /// Option code of the end option (marks the end of the options).
+    pub const OPTION_END: u8 = 255;
+
+    /// Decodes a DHCPv4 message from a slice (usually the payload of
+    /// an UDP packet) & validates the magic cookie.
+    pub fn from_slice(slice: &'a [u8]) -> Result<Dhcpv4Message<'a>, HeaderSliceError> {
+        if slice.len() < Dhcpv4Message::MIN_LEN {
+            return Err(HeaderSliceError::Len(err::LenError {
+                required_len: Dhcpv4Message::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Dhcpv4Message,
+                layer_start_offset: 0,
+            }));
+        }
+
+        let magic_cookie = [slice[236], slice[237], slice[238], slice[239]];
+        if magic_cookie != Dhcpv4Message::MAGIC_COOKIE {
+            return Err(HeaderSliceError::Content(
+                err::dhcpv4::HeaderError::InvalidMagicCookie { magic_cookie },
+            ));
+        }
+
+        Ok(Dhcpv4Message { slice })
+    }
+
+    /// Returns the slice containing the complete message.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Message op code ([`Dhcpv4Message::OP_BOOTREQUEST`] or
+    /// [`Dhcpv4Message::OP_BOOTREPLY`]).
+    #[inline]
+    pub fn op(&self) -> u8 {
+        self.slice[0]
+    }
+
+    /// Hardware address type (e.g. 1 for Ethernet).
+    #[inline]
+    pub fn htype(&self) -> u8 {
+        self.slice[1]
+    }
+
+    /// Hardware address length (e.g. 6 for Ethernet).
+    #[inline]
+    pub fn hlen(&self) -> u8 {
+        self.slice[2]
+    }
+
+    /// Number of relay agents the message passed (set by relay agents).
+    #[inline]
+    pub fn hops(&self) -> u8 {
+        self.slice[3]
+    }
+
+    /// Transaction id chosen by the client.
+    #[inline]
+    pub fn xid(&self) -> u32 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of Dhcpv4Message::MIN_LEN (240).
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Seconds elapsed since the client began the address acquisition.
+    #[inline]
+    pub fn secs(&self) -> u16 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of Dhcpv4Message::MIN_LEN (240).
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(8)) }
+    }
+
+    /// Flags field (see [`Dhcpv4Message::FLAG_BROADCAST`]).
+    #[inline]
+    pub fn flags(&self) -> u16 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of Dhcpv4Message::MIN_LEN (240).
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(10)) }
+    }
+
+    /// Returns true if the broadcast flag is set.
+    #[inline]
+    pub fn is_broadcast(&self) -> bool {
+        0 != self.flags() & Dhcpv4Message::FLAG_BROADCAST
+    }
+
+    /// Client IP address (only set if the client already has an address).
+    #[inline]
+    pub fn ciaddr(&self) -> [u8; 4] {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of Dhcpv4Message::MIN_LEN (240).
+        unsafe { get_unchecked_4_byte_array(self.slice.as_ptr().add(12)) }
+    }
+
+    /// "Your" (client) IP address assigned by the server.
+    #[inline]
+    pub fn yiaddr(&self) -> [u8; 4] {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of Dhcpv4Message::MIN_LEN (240).
+        unsafe { get_unchecked_4_byte_array(self.slice.as_ptr().add(16)) }
+    }
+
+    /// IP address of the next server to use in bootstrap.
+    #[inline]
+    pub fn siaddr(&self) -> [u8; 4] {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of Dhcpv4Message::MIN_LEN (240).
+        unsafe { get_unchecked_4_byte_array(self.slice.as_ptr().add(20)) }
+    }
+
+    /// Relay agent IP address.
+    #[inline]
+    pub fn giaddr(&self) -> [u8; 4] {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of Dhcpv4Message::MIN_LEN (240).
+        unsafe { get_unchecked_4_byte_array(self.slice.as_ptr().add(24)) }
+    }
+
+    /// Client hardware address field (the first [`Dhcpv4Message::hlen`]
+    /// bytes contain the address).
+    #[inline]
+    pub fn chaddr(&self) -> [u8; 16] {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of Dhcpv4Message::MIN_LEN (240).
+        unsafe { get_unchecked_16_byte_array(self.slice.as_ptr().add(28)) }
+    }
+
+    /// Optional server host name (null terminated string, 64 bytes).
+    #[inline]
+    pub fn sname(&self) -> &'a [u8] {
+        &self.slice[44..108]
+    }
+
+    /// Boot file name (null terminated string, 128 bytes).
+    #[inline]
+    pub fn file(&self) -> &'a [u8] {
+        &self.slice[108..236]
+    }
+
+    /// Slice containing the options (after the magic cookie).
+    #[inline]
+    pub fn options_slice(&self) -> &'a [u8] {
+        &self.slice[Dhcpv4Message::MIN_LEN..]
+    }
+
+    /// Returns an iterator over the options returning the option code
+    /// & data (pad options are skipped & the iteration stops at the
+    /// end option).
+    #[inline]
+    pub fn options(&self) -> Dhcpv4OptionsIter<'a> {
+        Dhcpv4OptionsIter::from_slice(self.options_slice())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice_getters(
+            fixed in proptest::collection::vec(any::<u8>(), 236),
+            options in proptest::collection::vec(any::<u8>(), 0..20),
+        ) {
+            let mut data = Vec::with_capacity(Dhcpv4Message::MIN_LEN + options.len());
+            data.extend_from_slice(&fixed);
+            data.extend_from_slice(&Dhcpv4Message::MAGIC_COOKIE);
+            data.extend_from_slice(&options);
+
+            // ok
+            {
+                let message = Dhcpv4Message::from_slice(&data).unwrap();
+                assert_eq!(message.clone(), message);
+                assert_eq!(
+                    format!("{:?}", message),
+                    format!("Dhcpv4Message {{ slice: {:?} }}", &data[..])
+                );
+                assert_eq!(message.slice(), &data[..]);
+                assert_eq!(message.op(), fixed[0]);
+                assert_eq!(message.htype(), fixed[1]);
+                assert_eq!(message.hlen(), fixed[2]);
+                assert_eq!(message.hops(), fixed[3]);
+                assert_eq!(
+                    message.xid(),
+                    u32::from_be_bytes([fixed[4], fixed[5], fixed[6], fixed[7]])
+                );
+                assert_eq!(message.secs(), u16::from_be_bytes([fixed[8], fixed[9]]));
+                assert_eq!(message.flags(), u16::from_be_bytes([fixed[10], fixed[11]]));
+                assert_eq!(message.is_broadcast(), 0 != fixed[10] & 0x80);
+                assert_eq!(&message.ciaddr()[..], &fixed[12..16]);
+                assert_eq!(&message.yiaddr()[..], &fixed[16..20]);
+                assert_eq!(&message.siaddr()[..], &fixed[20..24]);
+                assert_eq!(&message.giaddr()[..], &fixed[24..28]);
+                assert_eq!(&message.chaddr()[..], &fixed[28..44]);
+                assert_eq!(message.sname(), &fixed[44..108]);
+                assert_eq!(message.file(), &fixed[108..236]);
+                assert_eq!(message.options_slice(), &options[..]);
+                assert_eq!(message.options(), Dhcpv4OptionsIter::from_slice(&options));
+            }
+
+            // length error
+            for len in 0..Dhcpv4Message::MIN_LEN {
+                assert_eq!(
+                    Dhcpv4Message::from_slice(&data[..len]).unwrap_err(),
+                    HeaderSliceError::Len(err::LenError {
+                        required_len: Dhcpv4Message::MIN_LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::Dhcpv4Message,
+                        layer_start_offset: 0,
+                    })
+                );
+            }
+
+            // magic cookie error
+            {
+                let mut data = data.clone();
+                data[239] = 0;
+                assert_eq!(
+                    Dhcpv4Message::from_slice(&data).unwrap_err(),
+                    HeaderSliceError::Content(err::dhcpv4::HeaderError::InvalidMagicCookie {
+                        magic_cookie: [99, 130, 83, 0]
+                    })
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn options() {
+        let mut data = [0u8; Dhcpv4Message::MIN_LEN + 10];
+        data[236..240].copy_from_slice(&Dhcpv4Message::MAGIC_COOKIE);
+        // message type DHCPDISCOVER, pad, requested address & end
+        data[240..].copy_from_slice(&[53, 1, 1, 0, 50, 4, 192, 168, 1, 100]);
+        let message = Dhcpv4Message::from_slice(&data).unwrap();
+        assert_eq!(
+            message.options().collect::<Vec<_>>(),
+            [(53, &[1u8][..]), (50, &[192u8, 168, 1, 100][..])]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::*;
+
+/// Iterator over the options of a [`crate::Dhcpv4Message`] returning
+/// the option code & the option data.
+///
+/// Pad options ([`Dhcpv4Message::OPTION_PAD`]) are skipped and the
+/// iteration stops at the end option ([`Dhcpv4Message::OPTION_END`]).
+/// If an option is truncated (length field points beyond the end of
+/// the slice) the iteration stops and [`Dhcpv4OptionsIter::rest`]
+/// returns the slice starting at the truncated option.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dhcpv4OptionsIter<'a> {
+    /// Not yet decoded options.
+    rest: &'a [u8],
+}
+
+impl<'a> Dhcpv4OptionsIter<'a> {
+    /// Creates an iterator over the options contained in the given
+    /// slice (the slice must start after the magic cookie).
+    #[inline]
+    pub fn from_slice(slice: &'a [u8]) -> Dhcpv4OptionsIter<'a> {
+        Dhcpv4OptionsIter { rest: slice }
+    }
+
+    /// Returns the not yet decoded part of the options.
+    ///
+    /// After the iteration finished the returned slice is empty if
+    /// the end option was reached or the options were completely
+    /// decoded. Otherwise it starts at a truncated option.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for Dhcpv4OptionsIter<'a> {
+    type Item = (u8, &'a [u8]);
+
+    fn next(&mut self) -> Option<(u8, &'a [u8])> {
+        loop {
+            match self.rest {
+                [Dhcpv4Message::OPTION_PAD, rest @ ..] => {
+                    self.rest = rest;
+                }
+                [Dhcpv4Message::OPTION_END, ..] => {
+                    self.rest = &[];
+                    return None;
+                }
+                [code, len, rest @ ..] if usize::from(*len) <= rest.len() => {
+                    let (data, rest) = rest.split_at(usize::from(*len));
+                    self.rest = rest;
+                    return Some((*code, data));
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = Dhcpv4OptionsIter::from_slice(&[1]);
+        assert_eq!(iter.clone(), iter);
+        assert_eq!(format!("{:?}", iter), "Dhcpv4OptionsIter { rest: [1] }");
+    }
+
+    #[test]
+    fn next_rest() {
+        // empty
+        {
+            let mut iter = Dhcpv4OptionsIter::from_slice(&[]);
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+        // pad options are skipped & iteration stops at the end option
+        {
+            let data = [0, 53, 1, 5, 0, 0, 12, 2, b'a', b'b', 0, 255, 1, 2, 3];
+            let mut iter = Dhcpv4OptionsIter::from_slice(&data);
+            assert_eq!(
+                iter.clone().collect::<Vec<_>>(),
+                [(53, &[5u8][..]), (12, &b"ab"[..])]
+            );
+            iter.by_ref().count();
+            assert!(iter.rest().is_empty());
+        }
+        // options without an end option & zero length options
+        {
+            let data = [80, 0, 53, 1, 2];
+            let mut iter = Dhcpv4OptionsIter::from_slice(&data);
+            assert_eq!(Some((80, &[][..])), iter.next());
+            assert_eq!(Some((53, &[2u8][..])), iter.next());
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+        // truncated options
+        for data in [&[53u8][..], &[53, 2, 1][..]] {
+            let mut iter = Dhcpv4OptionsIter::from_slice(data);
+            assert_eq!(None, iter.next());
+            assert_eq!(data, iter.rest());
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use crate::*;
+
+/// Geneve (Generic Network Virtualization Encapsulation) header according to
+/// [RFC 8926](https://datatracker.ietf.org/doc/html/rfc8926#section-3.4).
+///
+/// The header is transported in the payload of an UDP packet (by default
+/// on the destination port [`GeneveHeader::IANA_PORT`]) and is followed by
+/// variable length options & the encapsulated packet (identified via the
+/// `protocol_type` field, e.g. [`EtherType::TRANSPARENT_ETHERNET_BRIDGING`]
+/// for an Ethernet II frame).
+///
+/// The options are not part of this struct (see [`GeneveOptionsIterator`]),
+/// only their length is stored in `opt_len`. The reserved fields of the
+/// header are ignored when reading and are set to zero when writing the
+/// header.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GeneveHeader {
+    /// Version (2 bits, only the lower 2 bits are serialized).
+    ///
+    /// RFC 8926 only defines the version [`GeneveHeader::VERSION`] (0).
+    pub version: u8,
+    /// Length of the options in multiples of 4 bytes (6 bits, only the
+    /// lower 6 bits are serialized).
+    pub opt_len: u8,
+    /// OAM flag ("O", set if the packet contains a control message).
+    pub oam: bool,
+    /// Critical options present flag ("C", set if at least one option
+    /// has the critical bit set).
+    pub critical: bool,
+    /// Type of the encapsulated protocol.
+    pub protocol_type: EtherType,
+    /// Virtual Network Identifier (24 bits) identifying the overlay network.
+    pub vni: VxlanVni,
+}
+
+impl GeneveHeader {
+    /// Minimum length of a Geneve header in bytes/octets (no options).
+    pub const MIN_LEN: usize = 8;
+
+    /// Maximum length of a Geneve header in bytes/octets (including the
+    /// maximum length of options).
+    pub const MAX_LEN: usize = GeneveHeader::MIN_LEN + 4 * (GeneveHeader::MAX_OPT_LEN as usize);
+
+    /// Maximum value of the "Opt Len" field.
+    pub const MAX_OPT_LEN: u8 = 0b0011_1111;
+
+    /// UDP destination port assigned by IANA for Geneve.
+    pub const IANA_PORT: u16 = 6081;
+
+    /// Geneve version defined in RFC 8926.
+    pub const VERSION: u8 = 0;
+
+    /// Bit in the second byte containing the OAM flag ("O").
+    pub const OAM_FLAG: u8 = 0b1000_0000;
+
+    /// Bit in the second byte containing the critical options
+    /// present flag ("C").
+    pub const CRITICAL_FLAG: u8 = 0b0100_0000;
+
+    /// Read a Geneve header from a slice and return the header & unused
+    /// parts of the slice (starting with the options if present).
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(GeneveHeader, &[u8]), err::LenError> {
+        if slice.len() < GeneveHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: GeneveHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::GeneveHeader,
+                layer_start_offset: 0,
+            });
+        }
+        Ok((
+            GeneveHeader::from_bytes([
+                slice[0], slice[1], slice[2], slice[3], slice[4], slice[5], slice[6], slice[7],
+            ]),
+            &slice[GeneveHeader::MIN_LEN..],
+        ))
+    }
+
+    /// Read a Geneve header from a static sized byte array (options excluded).
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 8]) -> GeneveHeader {
+        GeneveHeader {
+            version: bytes[0] >> 6,
+            opt_len: bytes[0] & GeneveHeader::MAX_OPT_LEN,
+            oam: 0 != bytes[1] & GeneveHeader::OAM_FLAG,
+            critical: 0 != bytes[1] & GeneveHeader::CRITICAL_FLAG,
+            protocol_type: EtherType(u16::from_be_bytes([bytes[2], bytes[3]])),
+            // SAFETY: Safe as only 24 bits are read.
+            vni: unsafe {
+                VxlanVni::new_unchecked(u32::from_be_bytes([0, bytes[4], bytes[5], bytes[6]]))
+            },
+        }
+    }
+
+    /// Tries to read a Geneve header (options excluded) from the current position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<GeneveHeader, std::io::Error> {
+        let mut bytes = [0u8; 8];
+        reader.read_exact(&mut bytes)?;
+        Ok(GeneveHeader::from_bytes(bytes))
+    }
+
+    /// Write the Geneve header (options excluded) to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Length of the options in bytes (based on the `opt_len` field).
+    #[inline]
+    pub fn options_len(&self) -> usize {
+        4 * usize::from(self.opt_len & GeneveHeader::MAX_OPT_LEN)
+    }
+
+    /// Length of the header including the options in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        GeneveHeader::MIN_LEN + self.options_len()
+    }
+
+    /// Returns the serialized form of the header (options excluded) as a
+    /// statically sized byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 8] {
+        let mut flags = 0;
+        if self.oam {
+            flags |= GeneveHeader::OAM_FLAG;
+        }
+        if self.critical {
+            flags |= GeneveHeader::CRITICAL_FLAG;
+        }
+        let protocol_type_be = self.protocol_type.0.to_be_bytes();
+        let vni_be = self.vni.value().to_be_bytes();
+        [
+            ((self.version & 0b11) << 6) | (self.opt_len & GeneveHeader::MAX_OPT_LEN),
+            flags,
+            protocol_type_be[0],
+            protocol_type_be[1],
+            vni_be[1],
+            vni_be[2],
+            vni_be[3],
+            0,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn constants() {
+        assert_eq!(8, GeneveHeader::MIN_LEN);
+        assert_eq!(8 + 63 * 4, GeneveHeader::MAX_LEN);
+        assert_eq!(6081, GeneveHeader::IANA_PORT);
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in geneve_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(GeneveHeader::MIN_LEN + dummy_data.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer.extend(&dummy_data[..]);
+
+            // ok case
+            {
+                let (result, rest) = GeneveHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(result, input);
+                assert_eq!(rest, &dummy_data[..]);
+            }
+
+            // length error
+            for len in 0..GeneveHeader::MIN_LEN {
+                assert_eq!(
+                    GeneveHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: GeneveHeader::MIN_LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::GeneveHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(input in geneve_any()) {
+            assert_eq!(input, GeneveHeader::from_bytes(input.to_bytes()));
+
+            // reserved fields are ignored
+            let mut bytes = input.to_bytes();
+            bytes[1] |= 0b0011_1111;
+            bytes[7] = 1;
+            assert_eq!(input, GeneveHeader::from_bytes(bytes));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(input in geneve_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(GeneveHeader::MIN_LEN);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(input, GeneveHeader::read(&mut cursor).unwrap());
+                assert_eq!(GeneveHeader::MIN_LEN, cursor.position() as usize);
+            }
+            // io errors
+            for len in 0..GeneveHeader::MIN_LEN {
+                let bytes = input.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(GeneveHeader::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; GeneveHeader::MIN_LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len(input in geneve_any()) {
+            assert_eq!(usize::from(input.opt_len) * 4, input.options_len());
+            assert_eq!(8 + usize::from(input.opt_len) * 4, input.header_len());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in geneve_any()) {
+            let p_be = input.protocol_type.0.to_be_bytes();
+            let v_be = input.vni.value().to_be_bytes();
+            assert_eq!(
+                input.to_bytes(),
+                [
+                    (input.version << 6) | input.opt_len,
+                    if input.oam { 0b1000_0000 } else { 0 }
+                        | if input.critical { 0b0100_0000 } else { 0 },
+                    p_be[0], p_be[1],
+                    v_be[1], v_be[2], v_be[3], 0,
+                ]
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_debug(input in geneve_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                format!(
+                    "GeneveHeader {{ version: {:?}, opt_len: {:?}, oam: {:?}, critical: {:?}, protocol_type: {:?}, vni: {:?} }}",
+                    input.version, input.opt_len, input.oam, input.critical, input.protocol_type, input.vni
+                ),
+                format!("{:?}", input)
+            );
+        }
+    }
+
+    #[test]
+    fn default() {
+        let header: GeneveHeader = Default::default();
+        assert_eq!(0, header.version);
+        assert_eq!(0, header.opt_len);
+        assert!(!header.oam);
+        assert!(!header.critical);
+        assert_eq!(EtherType(0), header.protocol_type);
+        assert_eq!(VxlanVni::ZERO, header.vni);
+    }
+}
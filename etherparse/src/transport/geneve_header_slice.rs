@@ -0,0 +1,250 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing a Geneve header (including the options).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GeneveHeaderSlice<'a> {
+    /// Slice containing the header & options.
+    slice: &'a [u8],
+}
+
+impl<'a> GeneveHeaderSlice<'a> {
+    /// Creates a Geneve header slice from a slice.
+    ///
+    /// The length of the header is determined by the "Opt Len" field. The
+    /// options themselves are not validated (errors are returned when
+    /// iterating over them via [`GeneveHeaderSlice::options`]).
+    pub fn from_slice(slice: &'a [u8]) -> Result<GeneveHeaderSlice<'a>, err::LenError> {
+        // check length
+        if slice.len() < GeneveHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: GeneveHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::GeneveHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check the length again now that the options length is known
+        let len = GeneveHeaderSlice::header_len_from_first_byte(slice[0]);
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::GeneveHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(GeneveHeaderSlice {
+            // SAFETY:
+            // Safe as the slice has been checked in the previous if
+            // to have at least the the length of the variable len.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Calculates the header length based on the first byte of a Geneve header.
+    #[inline]
+    fn header_len_from_first_byte(first: u8) -> usize {
+        GeneveHeader::MIN_LEN + 4 * usize::from(first & GeneveHeader::MAX_OPT_LEN)
+    }
+
+    /// Returns the slice containing the Geneve header & options.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the version (2 bits).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        (unsafe { *self.slice.get_unchecked(0) }) >> 6
+    }
+
+    /// Returns the "Opt Len" field (length of the options in multiples of
+    /// 4 bytes).
+    #[inline]
+    pub fn opt_len(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        (unsafe { *self.slice.get_unchecked(0) }) & GeneveHeader::MAX_OPT_LEN
+    }
+
+    /// Returns true if the OAM flag ("O") is set.
+    #[inline]
+    pub fn oam(&self) -> bool {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        0 != unsafe { *self.slice.get_unchecked(1) } & GeneveHeader::OAM_FLAG
+    }
+
+    /// Returns true if the critical options present flag ("C") is set.
+    #[inline]
+    pub fn critical(&self) -> bool {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        0 != unsafe { *self.slice.get_unchecked(1) } & GeneveHeader::CRITICAL_FLAG
+    }
+
+    /// Returns the type of the encapsulated protocol.
+    #[inline]
+    pub fn protocol_type(&self) -> EtherType {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        EtherType(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) })
+    }
+
+    /// Returns the "Virtual Network Identifier".
+    #[inline]
+    pub fn vni(&self) -> VxlanVni {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of GeneveHeader::MIN_LEN (8) and
+        // only 24 bits are read.
+        unsafe {
+            VxlanVni::new_unchecked(u32::from_be_bytes([
+                0,
+                *self.slice.get_unchecked(4),
+                *self.slice.get_unchecked(5),
+                *self.slice.get_unchecked(6),
+            ]))
+        }
+    }
+
+    /// Returns the slice containing the options.
+    #[inline]
+    pub fn options_slice(&self) -> &'a [u8] {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe {
+            from_raw_parts(
+                self.slice.as_ptr().add(GeneveHeader::MIN_LEN),
+                self.slice.len() - GeneveHeader::MIN_LEN,
+            )
+        }
+    }
+
+    /// Returns an iterator over the options.
+    #[inline]
+    pub fn options(&self) -> GeneveOptionsIterator<'a> {
+        GeneveOptionsIterator::new(self.options_slice())
+    }
+
+    /// Length of the header including the options in bytes.
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Decode all the fields and copy the results to a [`GeneveHeader`] struct.
+    #[inline]
+    pub fn to_header(&self) -> GeneveHeader {
+        GeneveHeader {
+            version: self.version(),
+            opt_len: self.opt_len(),
+            oam: self.oam(),
+            critical: self.critical(),
+            protocol_type: self.protocol_type(),
+            vni: self.vni(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in geneve_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..300)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(GeneveHeader::MIN_LEN + dummy_data.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer.extend(&dummy_data[..]);
+
+            if buffer.len() >= input.header_len() {
+                // ok case
+                let slice = GeneveHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(slice.slice(), &buffer[..input.header_len()]);
+                assert_eq!(slice.header_len(), input.header_len());
+                assert_eq!(slice.options_slice(), &buffer[GeneveHeader::MIN_LEN..input.header_len()]);
+            }
+
+            // length error
+            for len in 0..input.header_len().min(buffer.len()) {
+                assert_eq!(
+                    GeneveHeaderSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: if len < GeneveHeader::MIN_LEN {
+                            GeneveHeader::MIN_LEN
+                        } else {
+                            input.header_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::GeneveHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in geneve_any()) {
+            let mut bytes = Vec::with_capacity(input.header_len());
+            bytes.extend_from_slice(&input.to_bytes());
+            bytes.resize(input.header_len(), 0);
+            let slice = GeneveHeaderSlice::from_slice(&bytes).unwrap();
+
+            assert_eq!(slice.version(), input.version);
+            assert_eq!(slice.opt_len(), input.opt_len);
+            assert_eq!(slice.oam(), input.oam);
+            assert_eq!(slice.critical(), input.critical);
+            assert_eq!(slice.protocol_type(), input.protocol_type);
+            assert_eq!(slice.vni(), input.vni);
+            assert_eq!(slice.to_header(), input);
+        }
+    }
+
+    #[test]
+    fn options() {
+        let header = GeneveHeader {
+            opt_len: 3,
+            ..Default::default()
+        };
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&header.to_bytes());
+        bytes.extend_from_slice(&[0x01, 0x02, 0x80, 1, 1, 2, 3, 4, 0x01, 0x03, 0x04, 0]);
+        let slice = GeneveHeaderSlice::from_slice(&bytes).unwrap();
+        let mut iter = slice.options();
+        assert_eq!(Some(Ok((0x0102, 0x80, &[1, 2, 3, 4][..]))), iter.next());
+        assert_eq!(Some(Ok((0x0103, 0x04, &[][..]))), iter.next());
+        assert_eq!(None, iter.next());
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in geneve_any()) {
+            let mut bytes = Vec::with_capacity(input.header_len());
+            bytes.extend_from_slice(&input.to_bytes());
+            bytes.resize(input.header_len(), 0);
+            let slice = GeneveHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                &format!("GeneveHeaderSlice {{ slice: {:?} }}", slice.slice()),
+                &format!("{:?}", slice)
+            );
+        }
+    }
+}
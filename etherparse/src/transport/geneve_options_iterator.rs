@@ -0,0 +1,149 @@
+use crate::*;
+
+/// Allows iterating over the options of a Geneve header.
+///
+/// Each option is returned as a tuple containing the option class, the
+/// type (including the critical bit) & the option data (without the
+/// 4 byte option header). Iteration ends at the end of the slice or after
+/// an error was returned.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GeneveOptionsIterator<'a> {
+    /// Not yet decoded part of the slice.
+    rest: &'a [u8],
+    /// Offset of `rest` relative to the start of the options.
+    offset: usize,
+}
+
+impl<'a> GeneveOptionsIterator<'a> {
+    /// Length of the header of an option in bytes (option class, type
+    /// & length).
+    pub const OPTION_HEADER_LEN: usize = 4;
+
+    /// Bit in the type field indicating that the option is critical.
+    pub const CRITICAL_TYPE_FLAG: u8 = 0b1000_0000;
+
+    /// Creates an iterator over the options in the given slice.
+    #[inline]
+    pub fn new(options: &'a [u8]) -> GeneveOptionsIterator<'a> {
+        GeneveOptionsIterator {
+            rest: options,
+            offset: 0,
+        }
+    }
+
+    /// Returns the not yet decoded part of the options.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for GeneveOptionsIterator<'a> {
+    type Item = Result<(u16, u8, &'a [u8]), err::LenError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let len = if self.rest.len() < GeneveOptionsIterator::OPTION_HEADER_LEN {
+            GeneveOptionsIterator::OPTION_HEADER_LEN
+        } else {
+            GeneveOptionsIterator::OPTION_HEADER_LEN + 4 * usize::from(self.rest[3] & 0b1_1111)
+        };
+        if self.rest.len() < len {
+            let err = err::LenError {
+                required_len: len,
+                len: self.rest.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::GeneveHeader,
+                layer_start_offset: self.offset,
+            };
+            // stop the iteration after an error
+            self.rest = &[];
+            return Some(Err(err));
+        }
+
+        let option_class = u16::from_be_bytes([self.rest[0], self.rest[1]]);
+        let option_type = self.rest[2];
+        let data = &self.rest[GeneveOptionsIterator::OPTION_HEADER_LEN..len];
+        self.rest = &self.rest[len..];
+        self.offset += len;
+        Some(Ok((option_class, option_type, data)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = GeneveOptionsIterator::new(&[1, 2, 3, 0]);
+        assert_eq!(iter.clone(), iter);
+        assert_eq!(
+            format!("{:?}", iter),
+            "GeneveOptionsIterator { rest: [1, 2, 3, 0], offset: 0 }"
+        );
+    }
+
+    #[test]
+    fn next() {
+        // no options
+        {
+            let mut iter = GeneveOptionsIterator::new(&[]);
+            assert!(iter.next().is_none());
+        }
+        // multiple options
+        {
+            let data = [0, 1, 0x81, 1, 1, 2, 3, 4, 0xff, 0xfe, 2, 0];
+            let mut iter = GeneveOptionsIterator::new(&data);
+            assert_eq!(Some(Ok((1, 0x81, &data[4..8]))), iter.next());
+            assert_eq!(&data[8..], iter.rest());
+            assert_eq!(Some(Ok((0xfffe, 2, &[][..]))), iter.next());
+            assert!(iter.next().is_none());
+            assert!(iter.rest().is_empty());
+        }
+        // reserved bits in the length byte are ignored
+        {
+            let data = [0, 1, 2, 0b1110_0001, 1, 2, 3, 4];
+            let mut iter = GeneveOptionsIterator::new(&data);
+            assert_eq!(Some(Ok((1, 2, &data[4..]))), iter.next());
+            assert!(iter.next().is_none());
+        }
+        // option header too short
+        {
+            let data = [0, 1, 2, 0, 1, 2];
+            let mut iter = GeneveOptionsIterator::new(&data);
+            assert!(iter.next().unwrap().is_ok());
+            assert_eq!(
+                iter.next().unwrap().unwrap_err(),
+                err::LenError {
+                    required_len: 4,
+                    len: 2,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::GeneveHeader,
+                    layer_start_offset: 4,
+                }
+            );
+            assert!(iter.next().is_none());
+        }
+        // option data too short
+        {
+            let data = [0, 1, 2, 2, 1, 2, 3, 4];
+            let mut iter = GeneveOptionsIterator::new(&data);
+            assert_eq!(
+                iter.next().unwrap().unwrap_err(),
+                err::LenError {
+                    required_len: 12,
+                    len: 8,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::GeneveHeader,
+                    layer_start_offset: 0,
+                }
+            );
+            assert!(iter.next().is_none());
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::*;
+
+/// Geneve header, options & payload with the slices of the encapsulated
+/// packet.
+///
+/// Only set in [`SlicedPacket::geneve`] if Geneve decoding was enabled via
+/// [`ParseOptions::geneve_port`] and the UDP destination port matched.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GeneveSlice<'a> {
+    /// Geneve header (including the options) following the UDP header.
+    pub header: GeneveHeaderSlice<'a>,
+    /// Payload following the options.
+    pub payload: &'a [u8],
+    /// Ethernet II header of the encapsulated frame (only decoded if the
+    /// protocol type is [`EtherType::TRANSPARENT_ETHERNET_BRIDGING`]).
+    pub link: Option<LinkSlice<'a>>,
+    /// Single or double vlan headers of the encapsulated frame if present.
+    pub vlan: Option<VlanSlice<'a>>,
+    /// IPv4 or IPv6 header, IP extension headers & payload of the
+    /// encapsulated packet if present (decoded if the protocol type is
+    /// [`EtherType::IPV4`], [`EtherType::IPV6`] or part of an
+    /// encapsulated Ethernet II frame).
+    pub net: Option<NetSlice<'a>>,
+    /// Transport header & payload of the encapsulated packet if present.
+    pub transport: Option<TransportSlice<'a>>,
+}
+
+impl<'a> GeneveSlice<'a> {
+    /// Returns an iterator over the options of the Geneve header.
+    #[inline]
+    pub fn options(&self) -> GeneveOptionsIterator<'a> {
+        self.header.options()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let bytes = GeneveHeader::default().to_bytes();
+        let value = GeneveSlice {
+            header: GeneveHeaderSlice::from_slice(&bytes).unwrap(),
+            payload: &[],
+            link: None,
+            vlan: None,
+            net: None,
+            transport: None,
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!("{:?}", value),
+            format!(
+                "GeneveSlice {{ header: {:?}, payload: [], link: None, vlan: None, net: None, transport: None }}",
+                value.header
+            )
+        );
+    }
+
+    #[test]
+    fn options() {
+        let bytes = [0x01, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0];
+        let value = GeneveSlice {
+            header: GeneveHeaderSlice::from_slice(&bytes).unwrap(),
+            payload: &[],
+            link: None,
+            vlan: None,
+            net: None,
+            transport: None,
+        };
+        let mut iter = value.options();
+        assert_eq!(Some(Ok((1, 2, &[][..]))), iter.next());
+        assert_eq!(None, iter.next());
+    }
+}
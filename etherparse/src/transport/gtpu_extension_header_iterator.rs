@@ -0,0 +1,116 @@
+use crate::*;
+
+/// Allows iterating over the extension headers following a GTP-U header.
+///
+/// Iteration ends after an extension header with the next extension
+/// header type [`GtpuExtensionHeaderSlice::NO_MORE_EXTENSION_HEADERS`]
+/// or after an error was returned.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GtpuExtensionHeaderIterator<'a> {
+    /// Type of the next extension header.
+    next_header_type: u8,
+    /// Not yet decoded part of the slice.
+    rest: &'a [u8],
+}
+
+impl<'a> GtpuExtensionHeaderIterator<'a> {
+    /// Creates an iterator over the extension headers in the given slice.
+    ///
+    /// `next_header_type` is the "next extension header type" field of
+    /// the GTP-U header (use
+    /// [`GtpuExtensionHeaderSlice::NO_MORE_EXTENSION_HEADERS`] if the
+    /// field is not present).
+    #[inline]
+    pub fn new(next_header_type: u8, slice: &'a [u8]) -> GtpuExtensionHeaderIterator<'a> {
+        GtpuExtensionHeaderIterator {
+            next_header_type,
+            rest: slice,
+        }
+    }
+
+    /// Returns the non processed part of the slice (after the last
+    /// extension header this is the payload).
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for GtpuExtensionHeaderIterator<'a> {
+    type Item = Result<GtpuExtensionHeaderSlice<'a>, err::LenError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if GtpuExtensionHeaderSlice::NO_MORE_EXTENSION_HEADERS == self.next_header_type {
+            return None;
+        }
+        match GtpuExtensionHeaderSlice::from_slice(self.next_header_type, self.rest) {
+            Ok(header) => {
+                self.next_header_type = header.next_header_type();
+                self.rest = &self.rest[header.slice().len()..];
+                Some(Ok(header))
+            }
+            Err(err) => {
+                // stop the iteration after an error
+                self.next_header_type = GtpuExtensionHeaderSlice::NO_MORE_EXTENSION_HEADERS;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = GtpuExtensionHeaderIterator::new(0x85, &[1, 2, 3, 0]);
+        assert_eq!(iter.clone(), iter);
+        assert_eq!(
+            format!("{:?}", iter),
+            "GtpuExtensionHeaderIterator { next_header_type: 133, rest: [1, 2, 3, 0] }"
+        );
+    }
+
+    #[test]
+    fn next() {
+        // no extension headers
+        {
+            let mut iter = GtpuExtensionHeaderIterator::new(0, &[1, 2]);
+            assert!(iter.next().is_none());
+            assert_eq!(&[1, 2], iter.rest());
+        }
+        // multiple extension headers
+        {
+            let data = [1, 2, 3, 0x40, 2, 4, 5, 6, 7, 8, 9, 0, 10, 11];
+            let mut iter = GtpuExtensionHeaderIterator::new(0x85, &data);
+            let first = iter.next().unwrap().unwrap();
+            assert_eq!(0x85, first.header_type());
+            assert_eq!(&data[..4], first.slice());
+            let second = iter.next().unwrap().unwrap();
+            assert_eq!(0x40, second.header_type());
+            assert_eq!(&data[4..12], second.slice());
+            assert!(iter.next().is_none());
+            assert_eq!(&[10, 11], iter.rest());
+        }
+        // error
+        {
+            let data = [1, 2, 3, 0x40, 2, 4];
+            let mut iter = GtpuExtensionHeaderIterator::new(0x85, &data);
+            assert!(iter.next().unwrap().is_ok());
+            assert_eq!(
+                iter.next().unwrap().unwrap_err(),
+                err::LenError {
+                    required_len: 8,
+                    len: 2,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::GtpuHeader,
+                    layer_start_offset: 0,
+                }
+            );
+            assert!(iter.next().is_none());
+            assert_eq!(&[2, 4], iter.rest());
+        }
+    }
+}
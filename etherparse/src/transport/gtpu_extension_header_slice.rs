@@ -0,0 +1,173 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing a single GTP-U extension header.
+///
+/// The type of an extension header is not part of the extension header
+/// itself, it is given by the "next extension header type" field of the
+/// preceding GTP-U header or extension header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GtpuExtensionHeaderSlice<'a> {
+    /// Type of the extension header.
+    header_type: u8,
+    /// Slice containing the extension header.
+    slice: &'a [u8],
+}
+
+impl<'a> GtpuExtensionHeaderSlice<'a> {
+    /// Minimum length of a GTP-U extension header in bytes/octets.
+    pub const MIN_LEN: usize = 4;
+
+    /// Value of the "next extension header type" field indicating that
+    /// no further extension header follows.
+    pub const NO_MORE_EXTENSION_HEADERS: u8 = 0;
+
+    /// Creates an extension header slice from a slice.
+    ///
+    /// The length of the extension header is determined by its first
+    /// byte (length in 4 octet units). A length of zero results in an
+    /// error with the length source [`LenSource::GtpuHeaderLen`].
+    pub fn from_slice(
+        header_type: u8,
+        slice: &'a [u8],
+    ) -> Result<GtpuExtensionHeaderSlice<'a>, err::LenError> {
+        let len = match slice.first() {
+            None => {
+                return Err(err::LenError {
+                    required_len: GtpuExtensionHeaderSlice::MIN_LEN,
+                    len: 0,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::GtpuHeader,
+                    layer_start_offset: 0,
+                });
+            }
+            Some(0) => {
+                return Err(err::LenError {
+                    required_len: GtpuExtensionHeaderSlice::MIN_LEN,
+                    len: 0,
+                    len_source: LenSource::GtpuHeaderLen,
+                    layer: err::Layer::GtpuHeader,
+                    layer_start_offset: 0,
+                });
+            }
+            Some(len_units) => usize::from(*len_units) * 4,
+        };
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::GtpuHeader,
+                layer_start_offset: 0,
+            });
+        }
+        Ok(GtpuExtensionHeaderSlice {
+            header_type,
+            // SAFETY:
+            // Safe as the slice length was checked to be at least len.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Returns the type of the extension header.
+    #[inline]
+    pub fn header_type(&self) -> u8 {
+        self.header_type
+    }
+
+    /// Returns the slice containing the extension header (including
+    /// the length & next extension header type fields).
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the content of the extension header (everything between
+    /// the length & the next extension header type fields).
+    #[inline]
+    pub fn content(&self) -> &'a [u8] {
+        // SAFETY:
+        // Safe as the constructor checks that the slice has
+        // a length of at least 4.
+        unsafe { from_raw_parts(self.slice.as_ptr().add(1), self.slice.len() - 2) }
+    }
+
+    /// Returns the type of the next extension header
+    /// ([`GtpuExtensionHeaderSlice::NO_MORE_EXTENSION_HEADERS`] if none follows).
+    #[inline]
+    pub fn next_header_type(&self) -> u8 {
+        // SAFETY:
+        // Safe as the constructor checks that the slice has
+        // a length of at least 4.
+        unsafe { *self.slice.get_unchecked(self.slice.len() - 1) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let data = [1, 2, 3, 0];
+        let slice = GtpuExtensionHeaderSlice::from_slice(0x85, &data).unwrap();
+        assert_eq!(slice.clone(), slice);
+        assert_eq!(
+            format!("{:?}", slice),
+            format!(
+                "GtpuExtensionHeaderSlice {{ header_type: 133, slice: {:?} }}",
+                &data
+            )
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        // ok
+        {
+            let data = [2, 1, 2, 3, 4, 5, 6, 0x40, 9];
+            let slice = GtpuExtensionHeaderSlice::from_slice(0x85, &data).unwrap();
+            assert_eq!(0x85, slice.header_type());
+            assert_eq!(&data[..8], slice.slice());
+            assert_eq!(&[1, 2, 3, 4, 5, 6], slice.content());
+            assert_eq!(0x40, slice.next_header_type());
+        }
+        // empty slice
+        assert_eq!(
+            GtpuExtensionHeaderSlice::from_slice(0x85, &[]).unwrap_err(),
+            err::LenError {
+                required_len: 4,
+                len: 0,
+                len_source: LenSource::Slice,
+                layer: err::Layer::GtpuHeader,
+                layer_start_offset: 0,
+            }
+        );
+        // zero length field
+        assert_eq!(
+            GtpuExtensionHeaderSlice::from_slice(0x85, &[0, 1, 2, 3]).unwrap_err(),
+            err::LenError {
+                required_len: 4,
+                len: 0,
+                len_source: LenSource::GtpuHeaderLen,
+                layer: err::Layer::GtpuHeader,
+                layer_start_offset: 0,
+            }
+        );
+        // slice too short
+        for len in 1..8 {
+            let data = [2, 1, 2, 3, 4, 5, 6, 0];
+            assert_eq!(
+                GtpuExtensionHeaderSlice::from_slice(0x85, &data[..len]).unwrap_err(),
+                err::LenError {
+                    required_len: 8,
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::GtpuHeader,
+                    layer_start_offset: 0,
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use crate::*;
+use arrayvec::ArrayVec;
+
+/// GTP-U (GPRS Tunnelling Protocol User Plane) header according to
+/// [3GPP TS 29.281](https://www.3gpp.org/DynaReport/29281.htm).
+///
+/// The header is transported in the payload of an UDP packet (by default
+/// on the port [`GtpuHeader::IANA_PORT`]). G-PDU messages
+/// ([`GtpuHeader::MESSAGE_TYPE_G_PDU`]) carry an IPv4 or IPv6 packet
+/// of the subscriber as payload.
+///
+/// The optional fields (sequence number, N-PDU number & next extension
+/// header type) are present in the serialized header if at least one of
+/// them is set to `Some`. Fields set to `None` are then serialized as zero
+/// and their flag is not set. Extension headers are not part of this
+/// struct (see [`GtpuExtensionHeaderIterator`]).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GtpuHeader {
+    /// Version (3 bits, only the lower 3 bits are serialized).
+    ///
+    /// GTP-U uses version 1 ([`GtpuHeader::VERSION`]).
+    pub version: u8,
+    /// Protocol type flag ("PT", set for GTP & unset for GTP').
+    pub protocol_type: bool,
+    /// Type of the GTP-U message (e.g. [`GtpuHeader::MESSAGE_TYPE_G_PDU`]).
+    pub message_type: u8,
+    /// Length of the data following the first 8 bytes of the header
+    /// (optional fields, extension headers & payload) in bytes.
+    pub length: u16,
+    /// Tunnel endpoint identifier.
+    pub teid: u32,
+    /// Sequence number (present if the "S" flag is set).
+    pub sequence_number: Option<u16>,
+    /// N-PDU number (present if the "PN" flag is set).
+    pub n_pdu_number: Option<u8>,
+    /// Type of the first extension header (present if the "E" flag is set).
+    pub next_extension_header_type: Option<u8>,
+}
+
+impl GtpuHeader {
+    /// Minimum length of a GTP-U header in bytes/octets (no optional fields).
+    pub const MIN_LEN: usize = 8;
+
+    /// Maximum length of a GTP-U header in bytes/octets (optional
+    /// fields present, extension headers excluded).
+    pub const MAX_LEN: usize = 12;
+
+    /// UDP port assigned by IANA for GTP-U.
+    pub const IANA_PORT: u16 = 2152;
+
+    /// GTP version used by GTP-U.
+    pub const VERSION: u8 = 1;
+
+    /// Bit in the first byte containing the protocol type ("PT").
+    pub const PROTOCOL_TYPE_FLAG: u8 = 0b0001_0000;
+
+    /// Bit in the first byte indicating that the next extension
+    /// header type field is meaningful ("E").
+    pub const EXTENSION_HEADER_FLAG: u8 = 0b0000_0100;
+
+    /// Bit in the first byte indicating that the sequence number
+    /// field is meaningful ("S").
+    pub const SEQUENCE_NUMBER_FLAG: u8 = 0b0000_0010;
+
+    /// Bit in the first byte indicating that the N-PDU number
+    /// field is meaningful ("PN").
+    pub const N_PDU_NUMBER_FLAG: u8 = 0b0000_0001;
+
+    /// Message type of an echo request.
+    pub const MESSAGE_TYPE_ECHO_REQUEST: u8 = 1;
+
+    /// Message type of an echo response.
+    pub const MESSAGE_TYPE_ECHO_RESPONSE: u8 = 2;
+
+    /// Message type of an error indication.
+    pub const MESSAGE_TYPE_ERROR_INDICATION: u8 = 26;
+
+    /// Message type of a supported extension headers notification.
+    pub const MESSAGE_TYPE_SUPPORTED_EXTENSION_HEADERS_NOTIFICATION: u8 = 31;
+
+    /// Message type of an end marker.
+    pub const MESSAGE_TYPE_END_MARKER: u8 = 254;
+
+    /// Message type of a G-PDU (encapsulated user data).
+    pub const MESSAGE_TYPE_G_PDU: u8 = 255;
+
+    /// Read a GTP-U header from a slice and return the header & unused
+    /// parts of the slice (starting with the extension headers if present).
+    pub fn from_slice(slice: &[u8]) -> Result<(GtpuHeader, &[u8]), err::LenError> {
+        let s = GtpuHeaderSlice::from_slice(slice)?;
+        Ok((s.to_header(), &slice[s.slice().len()..]))
+    }
+
+    /// Reads a GTP-U header from the current position of the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<GtpuHeader, std::io::Error> {
+        let mut buffer = [0u8; GtpuHeader::MAX_LEN];
+        reader.read_exact(&mut buffer[..GtpuHeader::MIN_LEN])?;
+        let len = GtpuHeaderSlice::header_len_from_flags(buffer[0]);
+        reader.read_exact(&mut buffer[GtpuHeader::MIN_LEN..len])?;
+        // SAFETY:
+        // Safe as the buffer contains at least the required
+        // number of bytes given by the flags.
+        Ok(unsafe { GtpuHeaderSlice::from_slice_unchecked(&buffer[..len]) }.to_header())
+    }
+
+    /// Writes the GTP-U header to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Returns true if the optional fields are present in the serialized header.
+    #[inline]
+    pub fn has_optional_fields(&self) -> bool {
+        self.sequence_number.is_some()
+            || self.n_pdu_number.is_some()
+            || self.next_extension_header_type.is_some()
+    }
+
+    /// Length of the serialized header in bytes (extension headers excluded).
+    #[inline]
+    pub fn header_len(&self) -> usize {
+        if self.has_optional_fields() {
+            GtpuHeader::MAX_LEN
+        } else {
+            GtpuHeader::MIN_LEN
+        }
+    }
+
+    /// Returns the serialized header.
+    pub fn to_bytes(&self) -> ArrayVec<u8, { GtpuHeader::MAX_LEN }> {
+        let mut flags = (self.version & 0b111) << 5;
+        if self.protocol_type {
+            flags |= GtpuHeader::PROTOCOL_TYPE_FLAG;
+        }
+        if self.next_extension_header_type.is_some() {
+            flags |= GtpuHeader::EXTENSION_HEADER_FLAG;
+        }
+        if self.sequence_number.is_some() {
+            flags |= GtpuHeader::SEQUENCE_NUMBER_FLAG;
+        }
+        if self.n_pdu_number.is_some() {
+            flags |= GtpuHeader::N_PDU_NUMBER_FLAG;
+        }
+        let length_be = self.length.to_be_bytes();
+        let teid_be = self.teid.to_be_bytes();
+
+        let mut result = ArrayVec::new();
+        result.extend([
+            flags,
+            self.message_type,
+            length_be[0],
+            length_be[1],
+            teid_be[0],
+            teid_be[1],
+            teid_be[2],
+            teid_be[3],
+        ]);
+        if self.has_optional_fields() {
+            let sequence_number_be = self.sequence_number.unwrap_or(0).to_be_bytes();
+            result.extend([
+                sequence_number_be[0],
+                sequence_number_be[1],
+                self.n_pdu_number.unwrap_or(0),
+                self.next_extension_header_type.unwrap_or(0),
+            ]);
+        }
+        result
+    }
+}
+
+impl Default for GtpuHeader {
+    fn default() -> Self {
+        GtpuHeader {
+            version: GtpuHeader::VERSION,
+            protocol_type: true,
+            message_type: GtpuHeader::MESSAGE_TYPE_G_PDU,
+            length: 0,
+            teid: 0,
+            sequence_number: None,
+            n_pdu_number: None,
+            next_extension_header_type: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn default() {
+        let header: GtpuHeader = Default::default();
+        assert_eq!(1, header.version);
+        assert!(header.protocol_type);
+        assert_eq!(GtpuHeader::MESSAGE_TYPE_G_PDU, header.message_type);
+        assert_eq!(0, header.length);
+        assert_eq!(0, header.teid);
+        assert_eq!(None, header.sequence_number);
+        assert_eq!(None, header.n_pdu_number);
+        assert_eq!(None, header.next_extension_header_type);
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            header in gtpu_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(header.header_len() + dummy_data.len());
+            buffer.extend_from_slice(&header.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+
+            // ok
+            {
+                let (actual, rest) = GtpuHeader::from_slice(&buffer).unwrap();
+                assert_eq!(actual, header);
+                assert_eq!(rest, &dummy_data[..]);
+            }
+
+            // length error
+            for len in 0..header.header_len() {
+                assert_eq!(
+                    GtpuHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: if len < GtpuHeader::MIN_LEN {
+                            GtpuHeader::MIN_LEN
+                        } else {
+                            header.header_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::GtpuHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(header in gtpu_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(header.header_len());
+                header.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &header.to_bytes()[..]);
+
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(header, GtpuHeader::read(&mut cursor).unwrap());
+                assert_eq!(header.header_len(), cursor.position() as usize);
+            }
+
+            // io errors
+            for len in 0..header.header_len() {
+                let bytes = header.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(GtpuHeader::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; GtpuHeader::MAX_LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(header.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn header_len() {
+        let mut header: GtpuHeader = Default::default();
+        assert!(!header.has_optional_fields());
+        assert_eq!(8, header.header_len());
+        header.sequence_number = Some(1);
+        assert!(header.has_optional_fields());
+        assert_eq!(12, header.header_len());
+        header.sequence_number = None;
+        header.n_pdu_number = Some(2);
+        assert!(header.has_optional_fields());
+        assert_eq!(12, header.header_len());
+        header.n_pdu_number = None;
+        header.next_extension_header_type = Some(3);
+        assert!(header.has_optional_fields());
+        assert_eq!(12, header.header_len());
+    }
+
+    #[test]
+    fn to_bytes() {
+        // no optional fields
+        assert_eq!(
+            &GtpuHeader {
+                version: 1,
+                protocol_type: true,
+                message_type: GtpuHeader::MESSAGE_TYPE_G_PDU,
+                length: 0x1234,
+                teid: 0x5678_9abc,
+                sequence_number: None,
+                n_pdu_number: None,
+                next_extension_header_type: None,
+            }
+            .to_bytes()[..],
+            &[0b0011_0000, 0xff, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc]
+        );
+        // optional fields & version bits masked
+        assert_eq!(
+            &GtpuHeader {
+                version: 0b1111_1010,
+                protocol_type: false,
+                message_type: GtpuHeader::MESSAGE_TYPE_ECHO_REQUEST,
+                length: 4,
+                teid: 0,
+                sequence_number: Some(0xdef0),
+                n_pdu_number: None,
+                next_extension_header_type: Some(0x85),
+            }
+            .to_bytes()[..],
+            &[0b0100_0110, 1, 0, 4, 0, 0, 0, 0, 0xde, 0xf0, 0, 0x85]
+        );
+        assert_eq!(
+            &GtpuHeader {
+                version: 1,
+                protocol_type: true,
+                message_type: GtpuHeader::MESSAGE_TYPE_G_PDU,
+                length: 4,
+                teid: 0,
+                sequence_number: None,
+                n_pdu_number: Some(0x12),
+                next_extension_header_type: None,
+            }
+            .to_bytes()[..],
+            &[0b0011_0001, 0xff, 0, 4, 0, 0, 0, 0, 0, 0, 0x12, 0]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_debug(header in gtpu_any()) {
+            assert_eq!(header, header.clone());
+            assert_eq!(
+                format!(
+                    "GtpuHeader {{ version: {:?}, protocol_type: {:?}, message_type: {:?}, length: {:?}, teid: {:?}, sequence_number: {:?}, n_pdu_number: {:?}, next_extension_header_type: {:?} }}",
+                    header.version,
+                    header.protocol_type,
+                    header.message_type,
+                    header.length,
+                    header.teid,
+                    header.sequence_number,
+                    header.n_pdu_number,
+                    header.next_extension_header_type
+                ),
+                format!("{:?}", header)
+            );
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing a GTP-U header (extension headers excluded).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GtpuHeaderSlice<'a> {
+    /// Slice containing the header data.
+    slice: &'a [u8],
+}
+
+impl<'a> GtpuHeaderSlice<'a> {
+    /// Creates a GTP-U header slice from a slice.
+    ///
+    /// The length of the header is determined by the "E", "S" & "PN"
+    /// flags (if one of them is set the optional fields are present).
+    pub fn from_slice(slice: &'a [u8]) -> Result<GtpuHeaderSlice<'a>, err::LenError> {
+        // check length
+        if slice.len() < GtpuHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: GtpuHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::GtpuHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check the length again now that the optional fields are known
+        let len = GtpuHeaderSlice::header_len_from_flags(slice[0]);
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::GtpuHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(GtpuHeaderSlice {
+            // SAFETY:
+            // Safe as the slice has been checked in the previous if
+            // to have at least the the length of the variable len.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Creates a GTP-U header slice from a slice (assumes slice
+    /// size & content was validated before).
+    ///
+    /// # Safety
+    ///
+    /// This method assumes that the slice was previously validated to contain
+    /// a valid GTP-U header. This means the slice length must at least be
+    /// at least 8 and the length indicated by the flags in the first byte.
+    ///
+    /// If these preconditions are not fulfilled the behavior of this function
+    /// and the methods of the return [`GtpuHeaderSlice`] will be undefined.
+    pub unsafe fn from_slice_unchecked(slice: &'a [u8]) -> GtpuHeaderSlice<'a> {
+        GtpuHeaderSlice {
+            slice: from_raw_parts(
+                slice.as_ptr(),
+                GtpuHeaderSlice::header_len_from_flags(*slice.get_unchecked(0)),
+            ),
+        }
+    }
+
+    /// Calculates the header length based on the first byte of a GTP-U header.
+    #[inline]
+    pub(crate) fn header_len_from_flags(flags: u8) -> usize {
+        if 0 != flags
+            & (GtpuHeader::EXTENSION_HEADER_FLAG
+                | GtpuHeader::SEQUENCE_NUMBER_FLAG
+                | GtpuHeader::N_PDU_NUMBER_FLAG)
+        {
+            GtpuHeader::MAX_LEN
+        } else {
+            GtpuHeader::MIN_LEN
+        }
+    }
+
+    /// Returns the slice containing the GTP-U header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the version (3 bits).
+    #[inline]
+    pub fn version(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        (unsafe { *self.slice.get_unchecked(0) }) >> 5
+    }
+
+    /// Returns the protocol type flag ("PT", set for GTP & unset for GTP').
+    #[inline]
+    pub fn protocol_type(&self) -> bool {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        0 != unsafe { *self.slice.get_unchecked(0) } & GtpuHeader::PROTOCOL_TYPE_FLAG
+    }
+
+    /// Returns true if the extension header flag ("E") is set.
+    #[inline]
+    pub fn extension_header_flag(&self) -> bool {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        0 != unsafe { *self.slice.get_unchecked(0) } & GtpuHeader::EXTENSION_HEADER_FLAG
+    }
+
+    /// Returns true if the sequence number flag ("S") is set.
+    #[inline]
+    pub fn sequence_number_flag(&self) -> bool {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        0 != unsafe { *self.slice.get_unchecked(0) } & GtpuHeader::SEQUENCE_NUMBER_FLAG
+    }
+
+    /// Returns true if the N-PDU number flag ("PN") is set.
+    #[inline]
+    pub fn n_pdu_number_flag(&self) -> bool {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        0 != unsafe { *self.slice.get_unchecked(0) } & GtpuHeader::N_PDU_NUMBER_FLAG
+    }
+
+    /// Returns the message type.
+    #[inline]
+    pub fn message_type(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(1) }
+    }
+
+    /// Returns the length of the data following the first 8 bytes of the
+    /// header (optional fields, extension headers & payload).
+    #[inline]
+    pub fn length(&self) -> u16 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+    }
+
+    /// Returns the tunnel endpoint identifier.
+    #[inline]
+    pub fn teid(&self) -> u32 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Returns the sequence number if the "S" flag is set.
+    #[inline]
+    pub fn sequence_number(&self) -> Option<u16> {
+        if self.sequence_number_flag() {
+            // SAFETY:
+            // Slice size checked in the constructor to contain
+            // the optional fields if the flag is set.
+            Some(unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(8)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the N-PDU number if the "PN" flag is set.
+    #[inline]
+    pub fn n_pdu_number(&self) -> Option<u8> {
+        if self.n_pdu_number_flag() {
+            // SAFETY:
+            // Slice size checked in the constructor to contain
+            // the optional fields if the flag is set.
+            Some(unsafe { *self.slice.get_unchecked(10) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the type of the first extension header if the "E" flag is set.
+    #[inline]
+    pub fn next_extension_header_type(&self) -> Option<u8> {
+        if self.extension_header_flag() {
+            // SAFETY:
+            // Slice size checked in the constructor to contain
+            // the optional fields if the flag is set.
+            Some(unsafe { *self.slice.get_unchecked(11) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the extension headers in the given
+    /// slice (the data directly following the GTP-U header).
+    #[inline]
+    pub fn extension_headers(&self, slice: &'a [u8]) -> GtpuExtensionHeaderIterator<'a> {
+        GtpuExtensionHeaderIterator::new(
+            self.next_extension_header_type()
+                .unwrap_or(GtpuExtensionHeaderSlice::NO_MORE_EXTENSION_HEADERS),
+            slice,
+        )
+    }
+
+    /// Decode all the fields and copy the results to a [`GtpuHeader`] struct.
+    pub fn to_header(&self) -> GtpuHeader {
+        GtpuHeader {
+            version: self.version(),
+            protocol_type: self.protocol_type(),
+            message_type: self.message_type(),
+            length: self.length(),
+            teid: self.teid(),
+            sequence_number: self.sequence_number(),
+            n_pdu_number: self.n_pdu_number(),
+            next_extension_header_type: self.next_extension_header_type(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::format;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(header in gtpu_any()) {
+            let bytes = header.to_bytes();
+            let slice = GtpuHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.clone(), slice);
+            assert_eq!(
+                format!("{:?}", slice),
+                format!("GtpuHeaderSlice {{ slice: {:?} }}", slice.slice())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(header in gtpu_any()) {
+            let bytes = header.to_bytes();
+
+            // ok
+            {
+                let slice = GtpuHeaderSlice::from_slice(&bytes).unwrap();
+                assert_eq!(slice.slice(), &bytes[..]);
+            }
+
+            // length error
+            for len in 0..bytes.len() {
+                assert_eq!(
+                    GtpuHeaderSlice::from_slice(&bytes[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: if len < GtpuHeader::MIN_LEN {
+                            GtpuHeader::MIN_LEN
+                        } else {
+                            header.header_len()
+                        },
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::GtpuHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice_unchecked(header in gtpu_any()) {
+            let bytes = header.to_bytes();
+            let slice = unsafe {
+                GtpuHeaderSlice::from_slice_unchecked(&bytes)
+            };
+            assert_eq!(&bytes[..], slice.slice());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(header in gtpu_any()) {
+            let bytes = header.to_bytes();
+            let slice = GtpuHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.version(), header.version);
+            assert_eq!(slice.protocol_type(), header.protocol_type);
+            assert_eq!(slice.extension_header_flag(), header.next_extension_header_type.is_some());
+            assert_eq!(slice.sequence_number_flag(), header.sequence_number.is_some());
+            assert_eq!(slice.n_pdu_number_flag(), header.n_pdu_number.is_some());
+            assert_eq!(slice.message_type(), header.message_type);
+            assert_eq!(slice.length(), header.length);
+            assert_eq!(slice.teid(), header.teid);
+            assert_eq!(slice.sequence_number(), header.sequence_number);
+            assert_eq!(slice.n_pdu_number(), header.n_pdu_number);
+            assert_eq!(slice.next_extension_header_type(), header.next_extension_header_type);
+        }
+    }
+
+    #[test]
+    fn extension_headers() {
+        // no extension headers
+        {
+            let bytes = GtpuHeader::default().to_bytes();
+            let slice = GtpuHeaderSlice::from_slice(&bytes).unwrap();
+            let mut iter = slice.extension_headers(&[1, 2, 3, 4]);
+            assert!(iter.next().is_none());
+            assert_eq!(iter.rest(), &[1, 2, 3, 4]);
+        }
+        // one extension header
+        {
+            let bytes = GtpuHeader {
+                next_extension_header_type: Some(0x85),
+                ..Default::default()
+            }
+            .to_bytes();
+            let slice = GtpuHeaderSlice::from_slice(&bytes).unwrap();
+            let data = [1, 2, 3, 0, 4];
+            let mut iter = slice.extension_headers(&data);
+            let ext = iter.next().unwrap().unwrap();
+            assert_eq!(0x85, ext.header_type());
+            assert_eq!(&data[..4], ext.slice());
+            assert!(iter.next().is_none());
+            assert_eq!(iter.rest(), &[4]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(header in gtpu_any()) {
+            let bytes = header.to_bytes();
+            let slice = GtpuHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(header, slice.to_header());
+        }
+    }
+}
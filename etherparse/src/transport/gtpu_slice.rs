@@ -0,0 +1,77 @@
+use crate::*;
+
+/// GTP-U header, extension headers & payload with the slices of the
+/// encapsulated IP packet.
+///
+/// Only set in [`SlicedPacket::gtpu`] if GTP-U decoding was enabled via
+/// [`ParseOptions::gtpu_port`] and the UDP destination port matched.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GtpuSlice<'a> {
+    /// GTP-U header following the UDP header.
+    pub header: GtpuHeaderSlice<'a>,
+    /// Slice containing all extension headers (empty if no extension
+    /// headers are present).
+    pub extensions: &'a [u8],
+    /// Payload following the extension headers (limited by the
+    /// length field of the GTP-U header).
+    pub payload: &'a [u8],
+    /// IPv4 or IPv6 header, IP extension headers & payload of the
+    /// encapsulated packet (only decoded for G-PDU messages).
+    pub net: Option<NetSlice<'a>>,
+    /// Transport header & payload of the encapsulated packet if present.
+    pub transport: Option<TransportSlice<'a>>,
+}
+
+impl<'a> GtpuSlice<'a> {
+    /// Returns an iterator over the extension headers.
+    #[inline]
+    pub fn extension_headers(&self) -> GtpuExtensionHeaderIterator<'a> {
+        self.header.extension_headers(self.extensions)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let bytes = GtpuHeader::default().to_bytes();
+        let value = GtpuSlice {
+            header: GtpuHeaderSlice::from_slice(&bytes).unwrap(),
+            extensions: &[],
+            payload: &[],
+            net: None,
+            transport: None,
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!("{:?}", value),
+            format!(
+                "GtpuSlice {{ header: {:?}, extensions: [], payload: [], net: None, transport: None }}",
+                value.header
+            )
+        );
+    }
+
+    #[test]
+    fn extension_headers() {
+        let bytes = GtpuHeader {
+            next_extension_header_type: Some(0x85),
+            ..Default::default()
+        }
+        .to_bytes();
+        let extensions = [1, 2, 3, 0];
+        let value = GtpuSlice {
+            header: GtpuHeaderSlice::from_slice(&bytes).unwrap(),
+            extensions: &extensions,
+            payload: &[],
+            net: None,
+            transport: None,
+        };
+        let mut iter = value.extension_headers();
+        assert_eq!(&extensions, iter.next().unwrap().unwrap().slice());
+        assert!(iter.next().is_none());
+    }
+}
@@ -7,7 +7,8 @@
 /// node SHOULD also implement an application-layer interface for
 /// originating Echo Requests and receiving Echo Replies, for diagnostic
 /// purposes.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct IcmpEchoHeader {
     /// An identifier to aid in matching Echo Replies to Echo Requests. May be zero.
     pub id: u16,
@@ -43,6 +44,13 @@ mod test {
     use alloc::format;
     use proptest::prelude::*;
 
+    #[test]
+    fn default() {
+        let actual: IcmpEchoHeader = Default::default();
+        assert_eq!(actual.id, 0);
+        assert_eq!(actual.seq, 0);
+    }
+
     proptest! {
         #[test]
         fn to_bytes(
@@ -24,7 +24,8 @@
 ///
 /// Codes 0, 1, 4, and 5 may be received from a gateway.  Codes 2 and
 /// 3 may be received from a host.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum DestUnreachableHeader {
     /// Network unreachable error.
     Network,
@@ -1,5 +1,6 @@
 /// Code value in an ICMPv4 Redirect message.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum RedirectCode {
     /// Redirect Datagram for the Network (or subnet)
     RedirectForNetwork = 0,
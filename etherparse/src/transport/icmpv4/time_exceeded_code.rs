@@ -1,7 +1,8 @@
 use super::*;
 
 /// Code values for ICMPv4 time exceeded message.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum TimeExceededCode {
     /// Time-to-live exceeded in transit.
     TtlExceededInTransit = 0,
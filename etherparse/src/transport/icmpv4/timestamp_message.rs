@@ -1,5 +1,6 @@
 /// A ICMPv4 timestamp or timestamp response message.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct TimestampMessage {
     pub id: u16,
     pub seq: u16,
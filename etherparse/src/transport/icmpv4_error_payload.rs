@@ -0,0 +1,176 @@
+use crate::*;
+
+/// Decoded payload of an ICMPv4 error message containing the IPv4 header
+/// and the start of the payload of the packet that caused the error.
+///
+/// ICMPv4 error messages (destination unreachable, source quench,
+/// redirect, time exceeded & parameter problem) contain the "internet
+/// header + 64 bits of original data datagram". The 64 bits usually
+/// contain the ports of a TCP, UDP or SCTP header which can be used to
+/// correlate the error to the original flow.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{Icmpv4ErrorPayload, Icmpv4Slice};
+///
+/// # let icmp_packet = {
+/// #     use etherparse::{Icmpv4Header, Icmpv4Type, icmpv4::DestUnreachableHeader, PacketBuilder};
+/// #     let builder = PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+/// #         .udp(1234, 53);
+/// #     let mut original = Vec::new();
+/// #     builder.write(&mut original, &[1, 2, 3, 4]).unwrap();
+/// #     let mut icmp = Icmpv4Header::new(
+/// #         Icmpv4Type::DestinationUnreachable(DestUnreachableHeader::Port)
+/// #     ).to_bytes().to_vec();
+/// #     icmp.extend_from_slice(&original[..28]);
+/// #     icmp
+/// # };
+/// let icmp = Icmpv4Slice::from_slice(&icmp_packet).unwrap();
+/// if let Some(Ok(error_payload)) = icmp.error_payload() {
+///     assert_eq!([192, 168, 1, 2], error_payload.header.destination());
+///     assert_eq!(Some(1234), error_payload.source_port());
+///     assert_eq!(Some(53), error_payload.destination_port());
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Icmpv4ErrorPayload<'a> {
+    /// IPv4 header of the packet that caused the error.
+    pub header: Ipv4HeaderSlice<'a>,
+    /// Data following the IPv4 header (usually the first 8 bytes of the
+    /// payload of the packet that caused the error).
+    pub payload: &'a [u8],
+}
+
+impl<'a> Icmpv4ErrorPayload<'a> {
+    /// Decodes the IPv4 header at the start of the payload of an ICMPv4
+    /// error message.
+    ///
+    /// The "total length" field of the embedded header is not checked as
+    /// the error message only contains the start of the original packet.
+    pub fn from_slice(
+        slice: &'a [u8],
+    ) -> Result<Icmpv4ErrorPayload<'a>, err::ipv4::HeaderSliceError> {
+        let header = Ipv4HeaderSlice::from_slice(slice)?;
+        let payload = &slice[header.slice().len()..];
+        Ok(Icmpv4ErrorPayload { header, payload })
+    }
+
+    /// Returns the source port of the TCP, UDP, UDP-Lite or SCTP header of the
+    /// packet that caused the error.
+    ///
+    /// `None` is returned if the protocol has no ports, the packet was
+    /// not the first fragment or the payload is too short.
+    #[inline]
+    pub fn source_port(&self) -> Option<u16> {
+        self.transport_port(0)
+    }
+
+    /// Returns the destination port of the TCP, UDP, UDP-Lite or SCTP header
+    /// of the packet that caused the error.
+    ///
+    /// `None` is returned if the protocol has no ports, the packet was
+    /// not the first fragment or the payload is too short.
+    #[inline]
+    pub fn destination_port(&self) -> Option<u16> {
+        self.transport_port(2)
+    }
+
+    fn transport_port(&self, offset: usize) -> Option<u16> {
+        if 0 == self.header.fragments_offset().value() {
+            get_transport_port(self.header.protocol(), self.payload, offset)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::{format, vec::Vec};
+
+    fn build(protocol: IpNumber, fragment_offset: u16, payload: &[u8]) -> Vec<u8> {
+        let mut header =
+            Ipv4Header::new(100, 20, protocol, [192, 168, 1, 1], [192, 168, 1, 2]).unwrap();
+        header.fragment_offset = fragment_offset.try_into().unwrap();
+        let mut result = Vec::new();
+        result.extend_from_slice(&header.to_bytes());
+        result.extend_from_slice(payload);
+        result
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let data = build(ip_number::UDP, 0, &[1, 2, 3, 4]);
+        let value = Icmpv4ErrorPayload::from_slice(&data).unwrap();
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!("{:?}", value),
+            format!(
+                "Icmpv4ErrorPayload {{ header: {:?}, payload: [1, 2, 3, 4] }}",
+                value.header
+            )
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        // ok
+        {
+            let data = build(ip_number::UDP, 0, &[1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = Icmpv4ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(value.header.slice(), &data[..20]);
+            assert_eq!(value.payload, &data[20..]);
+        }
+        // error
+        {
+            let data = build(ip_number::UDP, 0, &[]);
+            assert_eq!(
+                Icmpv4ErrorPayload::from_slice(&data[..19]).unwrap_err(),
+                Ipv4HeaderSlice::from_slice(&data[..19]).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn ports() {
+        let payload = [0x12, 0x34, 0x56, 0x78, 1, 2, 3, 4];
+        for protocol in [
+            ip_number::TCP,
+            ip_number::UDP,
+            ip_number::UDP_LITE,
+            ip_number::SCTP,
+        ] {
+            // complete ports
+            let data = build(protocol, 0, &payload);
+            let value = Icmpv4ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(Some(0x1234), value.source_port());
+            assert_eq!(Some(0x5678), value.destination_port());
+
+            // truncated
+            let data = build(protocol, 0, &payload[..3]);
+            let value = Icmpv4ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(Some(0x1234), value.source_port());
+            assert_eq!(None, value.destination_port());
+
+            let data = build(protocol, 0, &payload[..1]);
+            let value = Icmpv4ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(None, value.source_port());
+            assert_eq!(None, value.destination_port());
+
+            // not the first fragment
+            let data = build(protocol, 1, &payload);
+            let value = Icmpv4ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(None, value.source_port());
+            assert_eq!(None, value.destination_port());
+        }
+        // protocol without ports
+        {
+            let data = build(ip_number::ICMP, 0, &payload);
+            let value = Icmpv4ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(None, value.source_port());
+            assert_eq!(None, value.destination_port());
+        }
+    }
+}
@@ -6,7 +6,8 @@ use arrayvec::ArrayVec;
 /// What is part of the header depends on the ICMPv4 type
 /// and code. But usually the static sized elements are part
 /// of the header.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Icmpv4Header {
     /// Type & type specific values & code.
     pub icmp_type: Icmpv4Type,
@@ -266,6 +266,23 @@ impl<'a> Icmpv4Slice<'a> {
         unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
     }
 
+    /// Calculates the checksum over the complete ICMPv4 packet (the
+    /// checksum field itself is treated as zero).
+    pub fn calc_checksum(&self) -> u16 {
+        checksum::Sum16BitWords::new()
+            .add_slice(&self.slice[..2])
+            .add_slice(&self.slice[4..])
+            .ones_complement()
+            .to_be()
+    }
+
+    /// Returns true if the "checksum" field matches the checksum
+    /// calculated over the complete ICMPv4 packet.
+    #[inline]
+    pub fn is_checksum_valid(&self) -> bool {
+        self.checksum() == self.calc_checksum()
+    }
+
     /// Returns the bytes from position 4 till and including the 8th position
     /// in the ICMPv4 header.
     ///
@@ -331,6 +348,29 @@ impl<'a> Icmpv4Slice<'a> {
         }
     }
 
+    /// Decodes the IPv4 header & start of the original packet contained
+    /// in the payload of ICMPv4 error messages.
+    ///
+    /// `None` is returned if the message is not an error message
+    /// (destination unreachable, source quench, redirect, time exceeded
+    /// or parameter problem). Offsets in returned errors are relative to
+    /// the start of the ICMPv4 packet.
+    pub fn error_payload(
+        &self,
+    ) -> Option<Result<Icmpv4ErrorPayload<'a>, err::ipv4::HeaderSliceError>> {
+        match self.type_u8() {
+            TYPE_DEST_UNREACH
+            | TYPE_SOURCE_QUENCH
+            | TYPE_REDIRECT
+            | TYPE_TIME_EXCEEDED
+            | TYPE_PARAMETER_PROBLEM => Some(
+                Icmpv4ErrorPayload::from_slice(self.payload())
+                    .map_err(|err| err.add_slice_offset(Icmpv4Header::MIN_LEN)),
+            ),
+            _ => None,
+        }
+    }
+
     /// Returns the slice containing the ICMPv4 packet.
     #[inline]
     pub fn slice(&self) -> &'a [u8] {
@@ -792,6 +832,39 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn calc_checksum_is_checksum_valid(
+            id in any::<u16>(),
+            seq in any::<u16>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..64)
+        ) {
+            let header = Icmpv4Header::with_checksum(
+                Icmpv4Type::EchoRequest(IcmpEchoHeader { id, seq }),
+                &payload,
+            );
+            let mut bytes = Vec::with_capacity(header.header_len() + payload.len());
+            bytes.extend_from_slice(&header.to_bytes());
+            bytes.extend_from_slice(&payload);
+
+            // valid checksum
+            {
+                let slice = Icmpv4Slice::from_slice(&bytes).unwrap();
+                assert_eq!(header.checksum, slice.calc_checksum());
+                assert!(slice.is_checksum_valid());
+            }
+
+            // invalid checksum
+            {
+                let mut bytes = bytes.clone();
+                bytes[2] = !bytes[2];
+                let slice = Icmpv4Slice::from_slice(&bytes).unwrap();
+                assert_eq!(header.checksum, slice.calc_checksum());
+                assert!(false == slice.is_checksum_valid());
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn bytes5to8(bytes in any::<[u8;20]>()) {
@@ -892,6 +965,69 @@ mod test {
         }
     }
 
+    #[test]
+    fn error_payload() {
+        let original = {
+            let mut result = Vec::new();
+            PacketBuilder::ipv4([192, 168, 1, 1], [192, 168, 1, 2], 20)
+                .udp(1234, 53)
+                .write(&mut result, &[1, 2, 3, 4])
+                .unwrap();
+            result
+        };
+
+        // error messages
+        for type_u8 in [
+            TYPE_DEST_UNREACH,
+            TYPE_SOURCE_QUENCH,
+            TYPE_REDIRECT,
+            TYPE_TIME_EXCEEDED,
+            TYPE_PARAMETER_PROBLEM,
+        ] {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&[type_u8, 0, 0, 0, 0, 0, 0, 0]);
+            bytes.extend_from_slice(&original[..28]);
+
+            // ok
+            {
+                let slice = Icmpv4Slice::from_slice(&bytes).unwrap();
+                let actual = slice.error_payload().unwrap().unwrap();
+                assert_eq!(actual.header.slice(), &original[..20]);
+                assert_eq!(actual.payload, &original[20..28]);
+                assert_eq!(Some(1234), actual.source_port());
+                assert_eq!(Some(53), actual.destination_port());
+            }
+
+            // truncated ports
+            {
+                let slice = Icmpv4Slice::from_slice(&bytes[..8 + 21]).unwrap();
+                let actual = slice.error_payload().unwrap().unwrap();
+                assert_eq!(None, actual.source_port());
+                assert_eq!(None, actual.destination_port());
+            }
+
+            // truncated ipv4 header
+            {
+                let slice = Icmpv4Slice::from_slice(&bytes[..8 + 19]).unwrap();
+                assert_eq!(
+                    slice.error_payload().unwrap().unwrap_err(),
+                    Ipv4HeaderSlice::from_slice(&original[..19])
+                        .unwrap_err()
+                        .add_slice_offset(8)
+                );
+            }
+        }
+
+        // non error messages
+        for type_u8 in [TYPE_ECHO_REPLY, TYPE_ECHO_REQUEST, 123] {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&[type_u8, 0, 0, 0, 0, 0, 0, 0]);
+            bytes.extend_from_slice(&original[..28]);
+            let slice = Icmpv4Slice::from_slice(&bytes).unwrap();
+            assert!(slice.error_payload().is_none());
+        }
+    }
+
     proptest! {
         #[test]
         fn debug(bytes in any::<[u8;20]>()) {
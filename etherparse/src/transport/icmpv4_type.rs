@@ -1,7 +1,8 @@
 use crate::*;
 
 /// Starting contents of an ICMPv4 packet without the checksum.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Icmpv4Type {
     /// In case of an unknown ICMP type and code combination is received the
     /// header elements are stored raw in this enum value. The `Unknown` value can
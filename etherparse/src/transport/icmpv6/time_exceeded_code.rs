@@ -1,7 +1,8 @@
 use super::*;
 
 /// Code values for ICMPv6 time exceeded message.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum TimeExceededCode {
     /// "hop limit exceeded in transit"
     HopLimitExceeded = 0,
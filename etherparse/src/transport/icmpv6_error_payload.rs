@@ -0,0 +1,229 @@
+use crate::*;
+
+/// Decoded payload of an ICMPv6 error message containing the IPv6 header
+/// and the start of the payload of the packet that caused the error.
+///
+/// ICMPv6 error messages (destination unreachable, packet too big, time
+/// exceeded & parameter problem) contain "as much of invoking packet as
+/// possible without the ICMPv6 packet exceeding the minimum IPv6 MTU"
+/// (1280 bytes). The embedded packet can contain extension headers and is
+/// usually truncated, so the transport header might only be partially
+/// present.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{Icmpv6ErrorPayload, Icmpv6Slice};
+///
+/// # let icmp_packet = {
+/// #     use etherparse::{Icmpv6Header, Icmpv6Type, PacketBuilder};
+/// #     let builder = PacketBuilder::ipv6([0; 16], [1; 16], 20).udp(1234, 53);
+/// #     let mut original = Vec::new();
+/// #     builder.write(&mut original, &[1, 2, 3, 4]).unwrap();
+/// #     let mut icmp = Icmpv6Header::new(Icmpv6Type::PacketTooBig { mtu: 1280 })
+/// #         .to_bytes()
+/// #         .to_vec();
+/// #     icmp.extend_from_slice(&original);
+/// #     icmp
+/// # };
+/// let icmp = Icmpv6Slice::from_slice(&icmp_packet).unwrap();
+/// if let Some(Ok(error_payload)) = icmp.error_payload() {
+///     assert_eq!([1; 16], error_payload.header.destination());
+///     assert_eq!(Some(1234), error_payload.source_port());
+///     assert_eq!(Some(53), error_payload.destination_port());
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Icmpv6ErrorPayload<'a> {
+    /// IPv6 header of the packet that caused the error.
+    pub header: Ipv6HeaderSlice<'a>,
+    /// Data following the IPv6 header (extension headers & the start
+    /// of the transport layer).
+    pub payload: &'a [u8],
+}
+
+impl<'a> Icmpv6ErrorPayload<'a> {
+    /// Decodes the IPv6 header at the start of the payload of an ICMPv6
+    /// error message.
+    ///
+    /// The "payload length" field of the embedded header is not checked as
+    /// the error message only contains the start of the original packet.
+    pub fn from_slice(
+        slice: &'a [u8],
+    ) -> Result<Icmpv6ErrorPayload<'a>, err::ipv6::HeaderSliceError> {
+        let header = Ipv6HeaderSlice::from_slice(slice)?;
+        let payload = &slice[header.slice().len()..];
+        Ok(Icmpv6ErrorPayload { header, payload })
+    }
+
+    /// Skips the extension headers in the payload and returns the
+    /// [`IpNumber`] & the (potentially truncated) data of the transport
+    /// layer.
+    ///
+    /// `None` is returned if the extension headers are truncated or
+    /// malformed or if the packet was not the first fragment.
+    pub fn transport(&self) -> Option<(IpNumber, &'a [u8])> {
+        let mut iter = Ipv6ExtensionsIter::new(self.header.next_header(), self.payload);
+        for ext in iter.by_ref() {
+            match ext {
+                Ok(Ipv6ExtensionSlice::Fragment(frag)) => {
+                    if 0 != frag.fragment_offset().value() {
+                        return None;
+                    }
+                }
+                Ok(_) => {}
+                Err(_) => return None,
+            }
+        }
+        Some((iter.next_header(), iter.rest()))
+    }
+
+    /// Returns the source port of the TCP, UDP, UDP-Lite or SCTP header of the
+    /// packet that caused the error.
+    ///
+    /// `None` is returned if the protocol has no ports, the packet was
+    /// not the first fragment or the payload is too short.
+    #[inline]
+    pub fn source_port(&self) -> Option<u16> {
+        self.transport_port(0)
+    }
+
+    /// Returns the destination port of the TCP, UDP, UDP-Lite or SCTP header
+    /// of the packet that caused the error.
+    ///
+    /// `None` is returned if the protocol has no ports, the packet was
+    /// not the first fragment or the payload is too short.
+    #[inline]
+    pub fn destination_port(&self) -> Option<u16> {
+        self.transport_port(2)
+    }
+
+    fn transport_port(&self, offset: usize) -> Option<u16> {
+        let (ip_number, transport) = self.transport()?;
+        get_transport_port(ip_number, transport, offset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::{format, vec::Vec};
+
+    fn build(next_header: IpNumber, exts: &[u8], payload: &[u8]) -> Vec<u8> {
+        let header = Ipv6Header {
+            payload_length: 100,
+            next_header,
+            hop_limit: 20,
+            source: [0; 16],
+            destination: [1; 16],
+            ..Default::default()
+        };
+        let mut result = Vec::new();
+        result.extend_from_slice(&header.to_bytes());
+        result.extend_from_slice(exts);
+        result.extend_from_slice(payload);
+        result
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let data = build(ip_number::UDP, &[], &[1, 2, 3, 4]);
+        let value = Icmpv6ErrorPayload::from_slice(&data).unwrap();
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!("{:?}", value),
+            format!(
+                "Icmpv6ErrorPayload {{ header: {:?}, payload: [1, 2, 3, 4] }}",
+                value.header
+            )
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        // ok
+        {
+            let data = build(ip_number::UDP, &[], &[1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = Icmpv6ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(value.header.slice(), &data[..40]);
+            assert_eq!(value.payload, &data[40..]);
+        }
+        // error
+        {
+            let data = build(ip_number::UDP, &[], &[]);
+            assert_eq!(
+                Icmpv6ErrorPayload::from_slice(&data[..39]).unwrap_err(),
+                Ipv6HeaderSlice::from_slice(&data[..39]).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn transport_and_ports() {
+        let payload = [0x12, 0x34, 0x56, 0x78, 1, 2, 3, 4];
+
+        // without extension headers
+        for protocol in [
+            ip_number::TCP,
+            ip_number::UDP,
+            ip_number::UDP_LITE,
+            ip_number::SCTP,
+        ] {
+            let data = build(protocol, &[], &payload);
+            let value = Icmpv6ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(Some((protocol, &payload[..])), value.transport());
+            assert_eq!(Some(0x1234), value.source_port());
+            assert_eq!(Some(0x5678), value.destination_port());
+
+            // truncated
+            let data = build(protocol, &[], &payload[..3]);
+            let value = Icmpv6ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(Some(0x1234), value.source_port());
+            assert_eq!(None, value.destination_port());
+        }
+
+        // with extension headers
+        {
+            let hop_by_hop = Ipv6RawExtHeader::new_raw(ip_number::IPV6_FRAG, &[0; 6]).unwrap();
+            let frag = Ipv6FragmentHeader::new(ip_number::UDP, 0.try_into().unwrap(), true, 123);
+            let mut exts = Vec::new();
+            exts.extend_from_slice(&hop_by_hop.to_bytes());
+            exts.extend_from_slice(&frag.to_bytes());
+
+            let data = build(ip_number::IPV6_HOP_BY_HOP, &exts, &payload);
+            let value = Icmpv6ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(Some((ip_number::UDP, &payload[..])), value.transport());
+            assert_eq!(Some(0x1234), value.source_port());
+            assert_eq!(Some(0x5678), value.destination_port());
+
+            // truncated extension header
+            let data = build(ip_number::IPV6_HOP_BY_HOP, &exts[..12], &[]);
+            let value = Icmpv6ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(None, value.transport());
+            assert_eq!(None, value.source_port());
+            assert_eq!(None, value.destination_port());
+        }
+
+        // not the first fragment
+        {
+            let frag = Ipv6FragmentHeader::new(ip_number::UDP, 1.try_into().unwrap(), true, 123);
+            let data = build(ip_number::IPV6_FRAG, &frag.to_bytes(), &payload);
+            let value = Icmpv6ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(None, value.transport());
+            assert_eq!(None, value.source_port());
+            assert_eq!(None, value.destination_port());
+        }
+
+        // protocol without ports
+        {
+            let data = build(ip_number::IPV6_ICMP, &[], &payload);
+            let value = Icmpv6ErrorPayload::from_slice(&data).unwrap();
+            assert_eq!(
+                Some((ip_number::IPV6_ICMP, &payload[..])),
+                value.transport()
+            );
+            assert_eq!(None, value.source_port());
+            assert_eq!(None, value.destination_port());
+        }
+    }
+}
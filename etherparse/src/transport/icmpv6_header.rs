@@ -2,7 +2,8 @@ use crate::{err::ValueTooBigError, *};
 use arrayvec::ArrayVec;
 
 /// The statically sized data at the start of an ICMPv6 packet (at least the first 8 bytes of an ICMPv6 packet).
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Icmpv6Header {
     /// Type & type specific values & code.
     pub icmp_type: Icmpv6Type,
@@ -112,6 +113,51 @@ impl Icmpv6Header {
         Ok(())
     }
 
+    /// Calculates the checksum of the ICMPv6 packet based on the
+    /// addresses of the given IPv6 header and the payload.
+    ///
+    /// The checksum is calculated over the IPv6 pseudo header (source &
+    /// destination address, upper-layer packet length & next header 58),
+    /// the ICMPv6 header & the payload. The checksum field of `self` is
+    /// ignored (treated as zero) and the upper-layer packet length is the
+    /// length of the ICMPv6 header plus the payload (the `payload_length` of
+    /// the IPv6 header is not used as it also contains extension headers).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{Icmpv6Header, Icmpv6Type, IcmpEchoHeader, Ipv6Header};
+    ///
+    /// let ip = Ipv6Header {
+    ///     source: [0; 16],
+    ///     destination: [1; 16],
+    ///     ..Default::default()
+    /// };
+    /// let payload = [1, 2, 3, 4];
+    /// let mut icmp = Icmpv6Header::new(Icmpv6Type::EchoRequest(IcmpEchoHeader { id: 1, seq: 2 }));
+    /// icmp.checksum = icmp.calc_checksum(&ip, &payload).unwrap();
+    /// assert!(icmp.is_checksum_valid(&ip, &payload));
+    /// ```
+    #[inline]
+    pub fn calc_checksum(
+        &self,
+        ip_header: &Ipv6Header,
+        payload: &[u8],
+    ) -> Result<u16, ValueTooBigError<usize>> {
+        self.icmp_type
+            .calc_checksum(ip_header.source, ip_header.destination, payload)
+    }
+
+    /// Returns true if the checksum field matches the checksum calculated
+    /// based on the addresses of the given IPv6 header and the payload
+    /// (see [`Icmpv6Header::calc_checksum`]).
+    ///
+    /// Returns false if the payload is too big for a checksum to be calculated.
+    #[inline]
+    pub fn is_checksum_valid(&self, ip_header: &Ipv6Header, payload: &[u8]) -> bool {
+        Ok(self.checksum) == self.calc_checksum(ip_header, payload)
+    }
+
     /// Returns the header on the wire bytes.
     #[inline]
     pub fn to_bytes(&self) -> ArrayVec<u8, { Icmpv6Header::MAX_LEN }> {
@@ -446,6 +492,40 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn calc_checksum_and_is_checksum_valid(
+            ip_header in ipv6_any(),
+            icmp_type in icmpv6_type_any(),
+            checksum in any::<u16>(),
+            payload in proptest::collection::vec(any::<u8>(), 0..1024)
+        ) {
+            let header = Icmpv6Header{ icmp_type, checksum };
+            let expected = icmp_type.calc_checksum(ip_header.source, ip_header.destination, &payload).unwrap();
+
+            // checksum field is ignored
+            assert_eq!(header.calc_checksum(&ip_header, &payload), Ok(expected));
+
+            // valid checksum
+            let valid = Icmpv6Header{ icmp_type, checksum: expected };
+            assert!(valid.is_checksum_valid(&ip_header, &payload));
+
+            // the result must match the validation of the slice
+            let mut bytes = Vec::with_capacity(valid.header_len() + payload.len());
+            bytes.extend_from_slice(&valid.to_bytes());
+            bytes.extend_from_slice(&payload);
+            assert!(
+                Icmpv6Slice::from_slice(&bytes)
+                    .unwrap()
+                    .is_checksum_valid(ip_header.source, ip_header.destination)
+            );
+
+            // invalid checksum
+            let invalid = Icmpv6Header{ icmp_type, checksum: !expected };
+            assert!(!invalid.is_checksum_valid(&ip_header, &payload));
+        }
+    }
+
     proptest! {
         #[test]
         fn to_bytes(
@@ -188,6 +188,29 @@ impl<'a> Icmpv6Slice<'a> {
         // at least the length of Icmpv6Header::MIN_LEN(8).
         unsafe { core::slice::from_raw_parts(self.slice.as_ptr().add(8), self.slice.len() - 8) }
     }
+
+    /// Decodes the IPv6 header & start of the original packet contained
+    /// in the payload of ICMPv6 error messages.
+    ///
+    /// `None` is returned if the message is not an error message
+    /// (destination unreachable, packet too big, time exceeded or
+    /// parameter problem). Offsets in returned errors are relative to
+    /// the start of the ICMPv6 packet.
+    pub fn error_payload(
+        &self,
+    ) -> Option<Result<Icmpv6ErrorPayload<'a>, err::ipv6::HeaderSliceError>> {
+        use icmpv6::*;
+        match self.type_u8() {
+            TYPE_DST_UNREACH
+            | TYPE_PACKET_TOO_BIG
+            | TYPE_TIME_EXCEEDED
+            | TYPE_PARAMETER_PROBLEM => Some(
+                Icmpv6ErrorPayload::from_slice(self.payload())
+                    .map_err(|err| err.add_slice_offset(Icmpv6Header::MIN_LEN)),
+            ),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -603,6 +626,60 @@ mod test {
         }
     }
 
+    #[test]
+    fn error_payload() {
+        let original = {
+            let mut result = Vec::new();
+            PacketBuilder::ipv6([0; 16], [1; 16], 20)
+                .udp(1234, 53)
+                .write(&mut result, &[1, 2, 3, 4])
+                .unwrap();
+            result
+        };
+
+        // error messages
+        for type_u8 in [
+            TYPE_DST_UNREACH,
+            TYPE_PACKET_TOO_BIG,
+            TYPE_TIME_EXCEEDED,
+            TYPE_PARAMETER_PROBLEM,
+        ] {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&[type_u8, 0, 0, 0, 0, 0, 0, 0]);
+            bytes.extend_from_slice(&original);
+
+            // ok
+            {
+                let slice = Icmpv6Slice::from_slice(&bytes).unwrap();
+                let actual = slice.error_payload().unwrap().unwrap();
+                assert_eq!(actual.header.slice(), &original[..40]);
+                assert_eq!(actual.payload, &original[40..]);
+                assert_eq!(Some(1234), actual.source_port());
+                assert_eq!(Some(53), actual.destination_port());
+            }
+
+            // truncated ipv6 header
+            {
+                let slice = Icmpv6Slice::from_slice(&bytes[..8 + 39]).unwrap();
+                assert_eq!(
+                    slice.error_payload().unwrap().unwrap_err(),
+                    Ipv6HeaderSlice::from_slice(&original[..39])
+                        .unwrap_err()
+                        .add_slice_offset(8)
+                );
+            }
+        }
+
+        // non error messages
+        for type_u8 in [TYPE_ECHO_REQUEST, TYPE_ECHO_REPLY, 100] {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&[type_u8, 0, 0, 0, 0, 0, 0, 0]);
+            bytes.extend_from_slice(&original);
+            let slice = Icmpv6Slice::from_slice(&bytes).unwrap();
+            assert!(slice.error_payload().is_none());
+        }
+    }
+
     #[test]
     fn debug() {
         let data = [0u8; 8];
@@ -0,0 +1,32 @@
+/// IGMP type value indicating a "Membership Query" message (defined in [RFC 2236](https://tools.ietf.org/html/rfc2236) & [RFC 3376](https://tools.ietf.org/html/rfc3376)).
+pub const TYPE_MEMBERSHIP_QUERY: u8 = 0x11;
+
+/// IGMP type value indicating a "Version 1 Membership Report" message (defined in [RFC 1112](https://tools.ietf.org/html/rfc1112)).
+pub const TYPE_V1_MEMBERSHIP_REPORT: u8 = 0x12;
+
+/// IGMP type value indicating a "Version 2 Membership Report" message (defined in [RFC 2236](https://tools.ietf.org/html/rfc2236)).
+pub const TYPE_V2_MEMBERSHIP_REPORT: u8 = 0x16;
+
+/// IGMP type value indicating a "Leave Group" message (defined in [RFC 2236](https://tools.ietf.org/html/rfc2236)).
+pub const TYPE_LEAVE_GROUP: u8 = 0x17;
+
+/// IGMP type value indicating a "Version 3 Membership Report" message (defined in [RFC 3376](https://tools.ietf.org/html/rfc3376)).
+pub const TYPE_V3_MEMBERSHIP_REPORT: u8 = 0x22;
+
+/// IGMPv3 group record type "MODE_IS_INCLUDE" (defined in [RFC 3376](https://tools.ietf.org/html/rfc3376#section-4.2.12)).
+pub const RECORD_TYPE_MODE_IS_INCLUDE: u8 = 1;
+
+/// IGMPv3 group record type "MODE_IS_EXCLUDE" (defined in [RFC 3376](https://tools.ietf.org/html/rfc3376#section-4.2.12)).
+pub const RECORD_TYPE_MODE_IS_EXCLUDE: u8 = 2;
+
+/// IGMPv3 group record type "CHANGE_TO_INCLUDE_MODE" (defined in [RFC 3376](https://tools.ietf.org/html/rfc3376#section-4.2.12)).
+pub const RECORD_TYPE_CHANGE_TO_INCLUDE_MODE: u8 = 3;
+
+/// IGMPv3 group record type "CHANGE_TO_EXCLUDE_MODE" (defined in [RFC 3376](https://tools.ietf.org/html/rfc3376#section-4.2.12)).
+pub const RECORD_TYPE_CHANGE_TO_EXCLUDE_MODE: u8 = 4;
+
+/// IGMPv3 group record type "ALLOW_NEW_SOURCES" (defined in [RFC 3376](https://tools.ietf.org/html/rfc3376#section-4.2.12)).
+pub const RECORD_TYPE_ALLOW_NEW_SOURCES: u8 = 5;
+
+/// IGMPv3 group record type "BLOCK_OLD_SOURCES" (defined in [RFC 3376](https://tools.ietf.org/html/rfc3376#section-4.2.12)).
+pub const RECORD_TYPE_BLOCK_OLD_SOURCES: u8 = 6;
@@ -0,0 +1,30 @@
+use crate::*;
+
+/// Decoded IGMP message (see [`IgmpSlice::message`]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IgmpMessage<'a> {
+    /// Message with the 8 byte IGMPv1/IGMPv2 layout (membership query,
+    /// version 1 & 2 membership report or leave group).
+    ///
+    /// IGMPv3 membership queries are also returned as this variant
+    /// (only the first 8 bytes are decoded).
+    Igmpv2(Igmpv2Message),
+    /// IGMPv3 membership report containing group records.
+    Igmpv3MembershipReport(Igmpv3MembershipReport<'a>),
+    /// IGMP message with an unknown type (the contained value is the
+    /// IGMP type).
+    Unknown(u8),
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = IgmpMessage::Unknown(1);
+        assert_eq!(value.clone(), value);
+        assert_eq!(format!("{:?}", value), "Unknown(1)");
+    }
+}
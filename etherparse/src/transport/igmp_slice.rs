@@ -0,0 +1,309 @@
+use crate::{igmp::*, *};
+
+/// A slice containing an IGMP packet (IP number 2).
+///
+/// The slice contains the complete IGMP message (e.g. the complete
+/// IP payload in case it was decoded by [`SlicedPacket`]) as the
+/// checksum is calculated over all of it.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{igmp, IgmpMessage, IgmpSlice};
+///
+/// let data = [igmp::TYPE_V2_MEMBERSHIP_REPORT, 0, 0x09, 0x04, 224, 0, 0, 251];
+/// let slice = IgmpSlice::from_slice(&data).unwrap();
+/// assert!(slice.is_checksum_valid());
+///
+/// match slice.message() {
+///     IgmpMessage::Igmpv2(message) => {
+///         assert_eq!(message.group_address, [224, 0, 0, 251]);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IgmpSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> IgmpSlice<'a> {
+    /// Minimum length of an IGMP message in bytes/octets.
+    pub const MIN_LEN: usize = 8;
+
+    /// Creates a slice containing an IGMP packet.
+    ///
+    /// # Errors
+    ///
+    /// The function will return an `Err` `err::LenError` if the given
+    /// slice is smaller then 8 bytes or (in case of an IGMPv3
+    /// membership report) does not contain all group records.
+    pub fn from_slice(slice: &'a [u8]) -> Result<IgmpSlice<'a>, err::LenError> {
+        if slice.len() < IgmpSlice::MIN_LEN {
+            return Err(err::LenError {
+                required_len: IgmpSlice::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Igmp,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check that all group records are present
+        if TYPE_V3_MEMBERSHIP_REPORT == slice[0] {
+            Igmpv3MembershipReport::from_slice(slice)?;
+        }
+
+        Ok(IgmpSlice { slice })
+    }
+
+    /// Returns the slice containing the IGMP packet.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the IGMP type (see the `TYPE_*` constants in [`crate::igmp`]).
+    #[inline]
+    pub fn igmp_type(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(0) }
+    }
+
+    /// Returns the "checksum" field.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+    }
+
+    /// Calculates the checksum over the complete IGMP packet (the
+    /// checksum field itself is treated as zero).
+    pub fn calc_checksum(&self) -> u16 {
+        checksum::Sum16BitWords::new()
+            .add_slice(&self.slice[..2])
+            .add_slice(&self.slice[4..])
+            .ones_complement()
+            .to_be()
+    }
+
+    /// Returns true if the "checksum" field matches the checksum
+    /// calculated over the complete IGMP packet.
+    #[inline]
+    pub fn is_checksum_valid(&self) -> bool {
+        self.checksum() == self.calc_checksum()
+    }
+
+    /// Decodes the message based on the IGMP type.
+    pub fn message(&self) -> IgmpMessage<'a> {
+        match self.igmp_type() {
+            TYPE_MEMBERSHIP_QUERY
+            | TYPE_V1_MEMBERSHIP_REPORT
+            | TYPE_V2_MEMBERSHIP_REPORT
+            | TYPE_LEAVE_GROUP => IgmpMessage::Igmpv2(Igmpv2Message::from_bytes([
+                self.slice[0],
+                self.slice[1],
+                self.slice[2],
+                self.slice[3],
+                self.slice[4],
+                self.slice[5],
+                self.slice[6],
+                self.slice[7],
+            ])),
+            TYPE_V3_MEMBERSHIP_REPORT => {
+                IgmpMessage::Igmpv3MembershipReport(Igmpv3MembershipReport { slice: self.slice })
+            }
+            value => IgmpMessage::Unknown(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{igmp::*, test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn debug_clone_eq(message in igmpv2_any()) {
+            let bytes = Igmpv2Message {
+                igmp_type: TYPE_MEMBERSHIP_QUERY,
+                ..message
+            }.to_bytes();
+            let slice = IgmpSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.clone(), slice);
+            assert_eq!(
+                format!("{:?}", slice),
+                format!("IgmpSlice {{ slice: {:?} }}", slice.slice())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            message in igmpv2_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(Igmpv2Message::LEN + dummy_data.len());
+            buffer.extend_from_slice(&message.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+            // prevent the buffer from being interpreted as an IGMPv3 report
+            if TYPE_V3_MEMBERSHIP_REPORT == buffer[0] {
+                buffer[0] = TYPE_MEMBERSHIP_QUERY;
+            }
+
+            // ok
+            {
+                let slice = IgmpSlice::from_slice(&buffer).unwrap();
+                assert_eq!(slice.slice(), &buffer[..]);
+                assert_eq!(slice.igmp_type(), buffer[0]);
+                assert_eq!(slice.checksum(), message.checksum);
+            }
+
+            // length error
+            for len in 0..IgmpSlice::MIN_LEN {
+                assert_eq!(
+                    IgmpSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: IgmpSlice::MIN_LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::Igmp,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn from_slice_v3_report() {
+        let data = [
+            TYPE_V3_MEMBERSHIP_REPORT,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            1,
+            RECORD_TYPE_MODE_IS_INCLUDE,
+            0,
+            0,
+            1,
+            239,
+            0,
+            0,
+            1,
+            10,
+            0,
+            0,
+            1,
+        ];
+        assert!(IgmpSlice::from_slice(&data).is_ok());
+        assert_eq!(
+            IgmpSlice::from_slice(&data[..19]).unwrap_err(),
+            err::LenError {
+                required_len: 20,
+                len: 19,
+                len_source: LenSource::Slice,
+                layer: err::Layer::Igmp,
+                layer_start_offset: 0,
+            }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn checksum(
+            message in igmpv2_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(Igmpv2Message::LEN + dummy_data.len());
+            buffer.extend_from_slice(&message.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+            if TYPE_V3_MEMBERSHIP_REPORT == buffer[0] {
+                buffer[0] = TYPE_MEMBERSHIP_QUERY;
+            }
+
+            let calc = IgmpSlice::from_slice(&buffer).unwrap().calc_checksum();
+            assert_eq!(
+                calc,
+                checksum::Sum16BitWords::new()
+                    .add_slice(&buffer[..2])
+                    .add_slice(&buffer[4..])
+                    .ones_complement()
+                    .to_be()
+            );
+            assert_eq!(
+                IgmpSlice::from_slice(&buffer).unwrap().is_checksum_valid(),
+                calc == message.checksum
+            );
+
+            // without dummy data the result must match the Igmpv2Message
+            {
+                let message = Igmpv2Message {
+                    igmp_type: buffer[0],
+                    ..message.clone()
+                };
+                let bytes = message.to_bytes();
+                assert_eq!(
+                    IgmpSlice::from_slice(&bytes).unwrap().calc_checksum(),
+                    message.calc_checksum()
+                );
+            }
+
+            // valid checksum
+            let calc_be = calc.to_be_bytes();
+            buffer[2] = calc_be[0];
+            buffer[3] = calc_be[1];
+            assert!(IgmpSlice::from_slice(&buffer).unwrap().is_checksum_valid());
+        }
+    }
+
+    #[test]
+    fn message() {
+        // igmpv2 layout
+        for igmp_type in [
+            TYPE_MEMBERSHIP_QUERY,
+            TYPE_V1_MEMBERSHIP_REPORT,
+            TYPE_V2_MEMBERSHIP_REPORT,
+            TYPE_LEAVE_GROUP,
+        ] {
+            let data = [igmp_type, 1, 2, 3, 4, 5, 6, 7, 8];
+            assert_eq!(
+                IgmpSlice::from_slice(&data).unwrap().message(),
+                IgmpMessage::Igmpv2(Igmpv2Message {
+                    igmp_type,
+                    max_response_time: 1,
+                    checksum: 0x0203,
+                    group_address: [4, 5, 6, 7],
+                })
+            );
+        }
+
+        // igmpv3 membership report
+        {
+            let data = [TYPE_V3_MEMBERSHIP_REPORT, 0, 0, 0, 0, 0, 0, 0];
+            assert_eq!(
+                IgmpSlice::from_slice(&data).unwrap().message(),
+                IgmpMessage::Igmpv3MembershipReport(
+                    Igmpv3MembershipReport::from_slice(&data).unwrap()
+                )
+            );
+        }
+
+        // unknown
+        {
+            let data = [0x30, 0, 0, 0, 0, 0, 0, 0];
+            assert_eq!(
+                IgmpSlice::from_slice(&data).unwrap().message(),
+                IgmpMessage::Unknown(0x30)
+            );
+        }
+    }
+}
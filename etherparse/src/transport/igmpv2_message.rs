@@ -0,0 +1,243 @@
+use crate::*;
+
+/// IGMP message with the fixed 8 byte layout shared by IGMPv1 & IGMPv2
+/// ([RFC 2236](https://tools.ietf.org/html/rfc2236)) messages.
+///
+/// The layout is used for membership queries, version 1 & 2
+/// membership reports & leave group messages. For IGMPv3 membership
+/// queries ([RFC 3376](https://tools.ietf.org/html/rfc3376)) only the
+/// first 8 bytes are decoded.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Igmpv2Message {
+    /// Type of the IGMP message (see the `TYPE_*` constants in
+    /// [`crate::igmp`]).
+    pub igmp_type: u8,
+    /// Maximum allowed time before sending a responding report
+    /// in units of 1/10 second (only meaningful in membership queries).
+    pub max_response_time: u8,
+    /// Checksum over the whole IGMP message.
+    pub checksum: u16,
+    /// Address of the multicast group the message refers to
+    /// (zero in general queries).
+    pub group_address: [u8; 4],
+}
+
+impl Igmpv2Message {
+    /// Serialized length of an IGMPv2 message in bytes/octets.
+    pub const LEN: usize = 8;
+
+    /// Read an IGMPv2 message from a slice and return the message & unused
+    /// parts of the slice.
+    pub fn from_slice(slice: &[u8]) -> Result<(Igmpv2Message, &[u8]), err::LenError> {
+        if slice.len() < Igmpv2Message::LEN {
+            return Err(err::LenError {
+                required_len: Igmpv2Message::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Igmp,
+                layer_start_offset: 0,
+            });
+        }
+        Ok((
+            Igmpv2Message::from_bytes([
+                slice[0], slice[1], slice[2], slice[3], slice[4], slice[5], slice[6], slice[7],
+            ]),
+            &slice[Igmpv2Message::LEN..],
+        ))
+    }
+
+    /// Decodes the message from the given bytes.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 8]) -> Igmpv2Message {
+        Igmpv2Message {
+            igmp_type: bytes[0],
+            max_response_time: bytes[1],
+            checksum: u16::from_be_bytes([bytes[2], bytes[3]]),
+            group_address: [bytes[4], bytes[5], bytes[6], bytes[7]],
+        }
+    }
+
+    /// Reads an IGMPv2 message from the current position of the reader.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<Igmpv2Message, std::io::Error> {
+        let mut bytes = [0u8; Igmpv2Message::LEN];
+        reader.read_exact(&mut bytes)?;
+        Ok(Igmpv2Message::from_bytes(bytes))
+    }
+
+    /// Writes the message to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// Returns the serialized message.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 8] {
+        let checksum_be = self.checksum.to_be_bytes();
+        [
+            self.igmp_type,
+            self.max_response_time,
+            checksum_be[0],
+            checksum_be[1],
+            self.group_address[0],
+            self.group_address[1],
+            self.group_address[2],
+            self.group_address[3],
+        ]
+    }
+
+    /// Calculates the checksum over the message (the checksum field
+    /// itself is treated as zero).
+    pub fn calc_checksum(&self) -> u16 {
+        checksum::Sum16BitWords::new()
+            .add_2bytes([self.igmp_type, self.max_response_time])
+            .add_4bytes(self.group_address)
+            .ones_complement()
+            .to_be()
+    }
+
+    /// Returns true if the `checksum` field matches the checksum
+    /// calculated over the message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{igmp, Igmpv2Message};
+    ///
+    /// let mut message = Igmpv2Message {
+    ///     igmp_type: igmp::TYPE_V2_MEMBERSHIP_REPORT,
+    ///     max_response_time: 0,
+    ///     checksum: 0,
+    ///     group_address: [224, 0, 0, 251],
+    /// };
+    /// assert!(false == message.is_checksum_valid());
+    ///
+    /// message.checksum = message.calc_checksum();
+    /// assert!(message.is_checksum_valid());
+    /// ```
+    #[inline]
+    pub fn is_checksum_valid(&self) -> bool {
+        self.checksum == self.calc_checksum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            message in igmpv2_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer = Vec::with_capacity(Igmpv2Message::LEN + dummy_data.len());
+            buffer.extend_from_slice(&message.to_bytes());
+            buffer.extend_from_slice(&dummy_data);
+
+            // ok
+            {
+                let (actual, rest) = Igmpv2Message::from_slice(&buffer).unwrap();
+                assert_eq!(actual, message);
+                assert_eq!(rest, &dummy_data[..]);
+            }
+
+            // length error
+            for len in 0..Igmpv2Message::LEN {
+                assert_eq!(
+                    Igmpv2Message::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: Igmpv2Message::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::Igmp,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(message in igmpv2_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(Igmpv2Message::LEN);
+                message.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &message.to_bytes()[..]);
+
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(message, Igmpv2Message::read(&mut cursor).unwrap());
+            }
+
+            // io errors
+            for len in 0..Igmpv2Message::LEN {
+                let bytes = message.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(Igmpv2Message::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; Igmpv2Message::LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(message.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn to_bytes() {
+        assert_eq!(
+            Igmpv2Message {
+                igmp_type: igmp::TYPE_MEMBERSHIP_QUERY,
+                max_response_time: 100,
+                checksum: 0x1234,
+                group_address: [224, 0, 0, 1],
+            }
+            .to_bytes(),
+            [0x11, 100, 0x12, 0x34, 224, 0, 0, 1]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn calc_checksum(message in igmpv2_any()) {
+            let mut message = message.clone();
+            message.checksum = message.calc_checksum();
+            assert!(message.is_checksum_valid());
+
+            // the checksum over the complete message must result in 0
+            let sum = checksum::Sum16BitWords::new()
+                .add_slice(&message.to_bytes())
+                .ones_complement();
+            assert_eq!(0, sum);
+
+            // modified checksum
+            message.checksum = !message.checksum;
+            assert!(false == message.is_checksum_valid());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_debug(message in igmpv2_any()) {
+            assert_eq!(message, message.clone());
+            assert_eq!(
+                format!(
+                    "Igmpv2Message {{ igmp_type: {:?}, max_response_time: {:?}, checksum: {:?}, group_address: {:?} }}",
+                    message.igmp_type,
+                    message.max_response_time,
+                    message.checksum,
+                    message.group_address
+                ),
+                format!("{:?}", message)
+            );
+        }
+    }
+}
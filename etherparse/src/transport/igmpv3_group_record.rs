@@ -0,0 +1,205 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// Slice containing a group record of an IGMPv3 membership report
+/// ([RFC 3376](https://tools.ietf.org/html/rfc3376#section-4.2.4)).
+///
+/// A group record consists of a record type, the length of the
+/// auxiliary data, the number of sources, the multicast address, the
+/// source addresses & the auxiliary data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Igmpv3GroupRecord<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> Igmpv3GroupRecord<'a> {
+    /// Minimum length of a group record in bytes/octets (no sources
+    /// & no auxiliary data).
+    pub const MIN_LEN: usize = 8;
+
+    /// Creates a group record slice from a slice (the resulting slice
+    /// only contains the group record).
+    pub fn from_slice(slice: &'a [u8]) -> Result<Igmpv3GroupRecord<'a>, err::LenError> {
+        if slice.len() < Igmpv3GroupRecord::MIN_LEN {
+            return Err(err::LenError {
+                required_len: Igmpv3GroupRecord::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Igmp,
+                layer_start_offset: 0,
+            });
+        }
+
+        // the aux data length is given in units of 32 bit words
+        let len = Igmpv3GroupRecord::MIN_LEN
+            + 4 * usize::from(u16::from_be_bytes([slice[2], slice[3]]))
+            + 4 * usize::from(slice[1]);
+        if slice.len() < len {
+            return Err(err::LenError {
+                required_len: len,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Igmp,
+                layer_start_offset: 0,
+            });
+        }
+
+        Ok(Igmpv3GroupRecord {
+            // SAFETY:
+            // Safe as the slice has been checked in the previous if
+            // to have at least the the length of the variable len.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), len) },
+        })
+    }
+
+    /// Returns the slice containing the group record.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the "record type" (see the `RECORD_TYPE_*` constants in
+    /// [`crate::igmp`]).
+    #[inline]
+    pub fn record_type(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(0) }
+    }
+
+    /// Returns the "aux data len" field (length of the auxiliary data
+    /// in units of 32 bit words).
+    #[inline]
+    pub fn aux_data_len(&self) -> u8 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { *self.slice.get_unchecked(1) }
+    }
+
+    /// Returns the "number of sources" field.
+    #[inline]
+    pub fn num_sources(&self) -> u16 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+    }
+
+    /// Returns the "multicast address" field.
+    #[inline]
+    pub fn multicast_address(&self) -> [u8; 4] {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { get_unchecked_4_byte_array(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Returns the slice containing the source addresses (4 bytes per
+    /// source).
+    #[inline]
+    pub fn source_addresses_slice(&self) -> &'a [u8] {
+        &self.slice[Igmpv3GroupRecord::MIN_LEN
+            ..Igmpv3GroupRecord::MIN_LEN + 4 * usize::from(self.num_sources())]
+    }
+
+    /// Returns the source address at the given index (`None` if the
+    /// index is bigger or equal to `num_sources`).
+    pub fn source_address(&self, index: usize) -> Option<[u8; 4]> {
+        let s = self.source_addresses_slice();
+        if index < s.len() / 4 {
+            let start = index * 4;
+            Some([s[start], s[start + 1], s[start + 2], s[start + 3]])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the source addresses.
+    #[inline]
+    pub fn source_addresses(&self) -> Igmpv3SourceAddressIter<'a> {
+        Igmpv3SourceAddressIter::from_slice(self.source_addresses_slice())
+    }
+
+    /// Returns the auxiliary data.
+    #[inline]
+    pub fn aux_data(&self) -> &'a [u8] {
+        &self.slice[Igmpv3GroupRecord::MIN_LEN + 4 * usize::from(self.num_sources())..]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn debug_clone_eq() {
+        let data = [1, 0, 0, 0, 224, 0, 0, 1];
+        let record = Igmpv3GroupRecord::from_slice(&data).unwrap();
+        assert_eq!(record.clone(), record);
+        assert_eq!(
+            format!("{:?}", record),
+            format!("Igmpv3GroupRecord {{ slice: {:?} }}", &data)
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        // record with 2 sources, 1 word aux data & trailing data
+        let data = [
+            igmp::RECORD_TYPE_MODE_IS_EXCLUDE,
+            1,
+            0,
+            2,
+            224,
+            0,
+            0,
+            1,
+            192,
+            168,
+            0,
+            1,
+            192,
+            168,
+            0,
+            2,
+            1,
+            2,
+            3,
+            4,
+            // trailing data
+            9,
+        ];
+        let record = Igmpv3GroupRecord::from_slice(&data).unwrap();
+        assert_eq!(record.slice(), &data[..20]);
+        assert_eq!(record.record_type(), igmp::RECORD_TYPE_MODE_IS_EXCLUDE);
+        assert_eq!(record.aux_data_len(), 1);
+        assert_eq!(record.num_sources(), 2);
+        assert_eq!(record.multicast_address(), [224, 0, 0, 1]);
+        assert_eq!(record.source_addresses_slice(), &data[8..16]);
+        assert_eq!(record.source_address(0), Some([192, 168, 0, 1]));
+        assert_eq!(record.source_address(1), Some([192, 168, 0, 2]));
+        assert_eq!(record.source_address(2), None);
+        assert_eq!(
+            record.source_addresses().collect::<Vec<_>>(),
+            [[192, 168, 0, 1], [192, 168, 0, 2]]
+        );
+        assert_eq!(record.aux_data(), &[1, 2, 3, 4]);
+
+        // length errors
+        for len in 0..20 {
+            assert_eq!(
+                Igmpv3GroupRecord::from_slice(&data[..len]).unwrap_err(),
+                err::LenError {
+                    required_len: if len < Igmpv3GroupRecord::MIN_LEN {
+                        Igmpv3GroupRecord::MIN_LEN
+                    } else {
+                        20
+                    },
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Igmp,
+                    layer_start_offset: 0,
+                }
+            );
+        }
+    }
+}
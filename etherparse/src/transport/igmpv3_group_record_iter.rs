@@ -0,0 +1,97 @@
+use crate::*;
+
+/// Iterator over the group records of an [`Igmpv3MembershipReport`].
+///
+/// At most "number of group records" records are returned. If a
+/// record is malformed (e.g. too short) the iteration ends.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Igmpv3GroupRecordIter<'a> {
+    /// Number of records that are left to be returned.
+    remaining: u16,
+    /// Not yet decoded records.
+    rest: &'a [u8],
+}
+
+impl<'a> Igmpv3GroupRecordIter<'a> {
+    /// Creates an iterator returning at most `num_group_records`
+    /// records from the given slice (data after the 8 byte report header).
+    #[inline]
+    pub fn new(num_group_records: u16, records: &'a [u8]) -> Igmpv3GroupRecordIter<'a> {
+        Igmpv3GroupRecordIter {
+            remaining: num_group_records,
+            rest: records,
+        }
+    }
+
+    /// Returns the part of the records that was not yet decoded.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for Igmpv3GroupRecordIter<'a> {
+    type Item = Igmpv3GroupRecord<'a>;
+
+    fn next(&mut self) -> Option<Igmpv3GroupRecord<'a>> {
+        if 0 == self.remaining {
+            return None;
+        }
+        match Igmpv3GroupRecord::from_slice(self.rest) {
+            Ok(record) => {
+                self.remaining -= 1;
+                self.rest = &self.rest[record.slice().len()..];
+                Some(record)
+            }
+            Err(_) => {
+                self.remaining = 0;
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = Igmpv3GroupRecordIter::new(1, &[1]);
+        assert_eq!(iter.clone(), iter);
+        assert_eq!(
+            format!("{:?}", iter),
+            "Igmpv3GroupRecordIter { remaining: 1, rest: [1] }"
+        );
+    }
+
+    #[test]
+    fn next() {
+        let data = [
+            1, 0, 0, 1, 224, 0, 0, 1, 10, 0, 0, 1, // record with one source
+            2, 0, 0, 0, 224, 0, 0, 2, // record without sources
+            3, 0, 0, 0, 224, 0, 0, // too short record
+        ];
+
+        // limited by number of group records
+        {
+            let mut iter = Igmpv3GroupRecordIter::new(1, &data);
+            let record = iter.next().unwrap();
+            assert_eq!(record.slice(), &data[..12]);
+            assert_eq!(None, iter.next());
+            assert_eq!(iter.rest(), &data[12..]);
+        }
+
+        // stop at malformed record
+        {
+            let mut iter = Igmpv3GroupRecordIter::new(5, &data);
+            assert_eq!(
+                iter.by_ref().map(|v| v.record_type()).collect::<Vec<_>>(),
+                [1, 2]
+            );
+            assert_eq!(None, iter.next());
+            assert_eq!(iter.rest(), &data[20..]);
+        }
+    }
+}
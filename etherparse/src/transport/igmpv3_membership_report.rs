@@ -0,0 +1,191 @@
+use crate::*;
+
+/// Slice containing an IGMPv3 membership report
+/// ([RFC 3376](https://tools.ietf.org/html/rfc3376#section-4.2)).
+///
+/// The report consists of an 8 byte header (type, reserved, checksum,
+/// reserved & number of group records) followed by the group records.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Igmpv3MembershipReport<'a> {
+    pub(crate) slice: &'a [u8],
+}
+
+impl<'a> Igmpv3MembershipReport<'a> {
+    /// Length of the report header in bytes/octets (before the group records).
+    pub const HEADER_LEN: usize = 8;
+
+    /// Creates a membership report slice from a slice.
+    ///
+    /// All group records indicated by the "number of group records"
+    /// field are checked to be present in the slice. Bytes after the
+    /// last group record are kept in the slice (as they are covered
+    /// by the checksum).
+    pub fn from_slice(slice: &'a [u8]) -> Result<Igmpv3MembershipReport<'a>, err::LenError> {
+        if slice.len() < Igmpv3MembershipReport::HEADER_LEN {
+            return Err(err::LenError {
+                required_len: Igmpv3MembershipReport::HEADER_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::Igmp,
+                layer_start_offset: 0,
+            });
+        }
+
+        // check that all group records are present
+        let num_group_records = u16::from_be_bytes([slice[6], slice[7]]);
+        let mut offset = Igmpv3MembershipReport::HEADER_LEN;
+        for _ in 0..num_group_records {
+            let record =
+                Igmpv3GroupRecord::from_slice(&slice[offset..]).map_err(|err| err::LenError {
+                    required_len: offset + err.required_len,
+                    len: slice.len(),
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Igmp,
+                    layer_start_offset: 0,
+                })?;
+            offset += record.slice().len();
+        }
+
+        Ok(Igmpv3MembershipReport { slice })
+    }
+
+    /// Returns the slice containing the membership report.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Returns the "checksum" field.
+    #[inline]
+    pub fn checksum(&self) -> u16 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+    }
+
+    /// Returns the "number of group records" field.
+    #[inline]
+    pub fn num_group_records(&self) -> u16 {
+        // SAFETY:
+        // Slice size checked to be at least 8 bytes in constructor.
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(6)) }
+    }
+
+    /// Returns an iterator over the group records.
+    #[inline]
+    pub fn group_records(&self) -> Igmpv3GroupRecordIter<'a> {
+        Igmpv3GroupRecordIter::new(
+            self.num_group_records(),
+            &self.slice[Igmpv3MembershipReport::HEADER_LEN..],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn debug_clone_eq() {
+        let data = [igmp::TYPE_V3_MEMBERSHIP_REPORT, 0, 0, 0, 0, 0, 0, 0];
+        let report = Igmpv3MembershipReport::from_slice(&data).unwrap();
+        assert_eq!(report.clone(), report);
+        assert_eq!(
+            format!("{:?}", report),
+            format!("Igmpv3MembershipReport {{ slice: {:?} }}", &data)
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        let data = [
+            igmp::TYPE_V3_MEMBERSHIP_REPORT,
+            0,
+            0x12,
+            0x34,
+            0,
+            0,
+            0,
+            2,
+            // record with one source
+            igmp::RECORD_TYPE_CHANGE_TO_INCLUDE_MODE,
+            0,
+            0,
+            1,
+            239,
+            1,
+            2,
+            3,
+            10,
+            0,
+            0,
+            1,
+            // record with aux data
+            igmp::RECORD_TYPE_BLOCK_OLD_SOURCES,
+            1,
+            0,
+            0,
+            239,
+            1,
+            2,
+            4,
+            5,
+            6,
+            7,
+            8,
+        ];
+
+        // ok
+        {
+            let report = Igmpv3MembershipReport::from_slice(&data).unwrap();
+            assert_eq!(report.slice(), &data[..]);
+            assert_eq!(report.checksum(), 0x1234);
+            assert_eq!(report.num_group_records(), 2);
+
+            let records: Vec<_> = report.group_records().collect();
+            assert_eq!(2, records.len());
+            assert_eq!(
+                records[0].record_type(),
+                igmp::RECORD_TYPE_CHANGE_TO_INCLUDE_MODE
+            );
+            assert_eq!(records[0].multicast_address(), [239, 1, 2, 3]);
+            assert_eq!(
+                records[0].source_addresses().collect::<Vec<_>>(),
+                [[10, 0, 0, 1]]
+            );
+            assert!(records[0].aux_data().is_empty());
+            assert_eq!(
+                records[1].record_type(),
+                igmp::RECORD_TYPE_BLOCK_OLD_SOURCES
+            );
+            assert_eq!(records[1].multicast_address(), [239, 1, 2, 4]);
+            assert_eq!(records[1].source_addresses().next(), None);
+            assert_eq!(records[1].aux_data(), &[5, 6, 7, 8]);
+        }
+
+        // length errors
+        for len in 0..data.len() {
+            assert_eq!(
+                Igmpv3MembershipReport::from_slice(&data[..len]).unwrap_err(),
+                err::LenError {
+                    required_len: if len < 8 {
+                        8
+                    } else if len < 16 {
+                        16
+                    } else if len < 20 {
+                        20
+                    } else if len < 28 {
+                        28
+                    } else {
+                        32
+                    },
+                    len,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::Igmp,
+                    layer_start_offset: 0,
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,57 @@
+/// Iterator over the source addresses of an [`crate::Igmpv3GroupRecord`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Igmpv3SourceAddressIter<'a> {
+    /// Not yet returned source addresses.
+    rest: &'a [u8],
+}
+
+impl<'a> Igmpv3SourceAddressIter<'a> {
+    /// Creates an iterator over the source addresses contained in the
+    /// given slice (4 bytes per address, trailing bytes are ignored).
+    #[inline]
+    pub fn from_slice(slice: &'a [u8]) -> Igmpv3SourceAddressIter<'a> {
+        Igmpv3SourceAddressIter { rest: slice }
+    }
+}
+
+impl<'a> Iterator for Igmpv3SourceAddressIter<'a> {
+    type Item = [u8; 4];
+
+    fn next(&mut self) -> Option<[u8; 4]> {
+        match self.rest {
+            [a, b, c, d, rest @ ..] => {
+                self.rest = rest;
+                Some([*a, *b, *c, *d])
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = Igmpv3SourceAddressIter::from_slice(&[1]);
+        assert_eq!(iter.clone(), iter);
+        assert_eq!(
+            format!("{:?}", iter),
+            "Igmpv3SourceAddressIter { rest: [1] }"
+        );
+    }
+
+    #[test]
+    fn next() {
+        assert_eq!(
+            Igmpv3SourceAddressIter::from_slice(&[]).collect::<Vec<_>>(),
+            Vec::<[u8; 4]>::new()
+        );
+        assert_eq!(
+            Igmpv3SourceAddressIter::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9]).collect::<Vec<_>>(),
+            [[1, 2, 3, 4], [5, 6, 7, 8]]
+        );
+    }
+}
@@ -1,14 +1,45 @@
+pub mod dccp_header;
+pub mod dccp_header_slice;
+pub mod dccp_slice;
+pub mod dhcpv4_message;
+pub mod dhcpv4_options_iter;
+pub mod geneve_header;
+pub mod geneve_header_slice;
+pub mod geneve_options_iterator;
+pub mod geneve_slice;
+pub mod gtpu_extension_header_iterator;
+pub mod gtpu_extension_header_slice;
+pub mod gtpu_header;
+pub mod gtpu_header_slice;
+pub mod gtpu_slice;
 pub mod icmp_echo_header;
 /// Module containing ICMPv4 related types and constants.
 pub mod icmpv4;
+pub mod icmpv4_error_payload;
 pub mod icmpv4_header;
 pub mod icmpv4_slice;
 pub mod icmpv4_type;
 /// Module containing ICMPv6 related types and constants
 pub mod icmpv6;
+pub mod icmpv6_error_payload;
 pub mod icmpv6_header;
 pub mod icmpv6_slice;
 pub mod icmpv6_type;
+/// Module containing IGMP related types and constants.
+pub mod igmp;
+pub mod igmp_message;
+pub mod igmp_slice;
+pub mod igmpv2_message;
+pub mod igmpv3_group_record;
+pub mod igmpv3_group_record_iter;
+pub mod igmpv3_membership_report;
+pub mod igmpv3_source_address_iter;
+pub mod sctp_chunk_iter;
+pub mod sctp_header;
+pub mod sctp_header_slice;
+pub mod tcp_authentication_mac;
+pub mod tcp_fast_open_cookie;
+pub mod tcp_flag_anomaly;
 pub mod tcp_header;
 pub mod tcp_header_slice;
 pub mod tcp_option_element;
@@ -23,3 +54,7 @@ pub mod transport_slice;
 pub mod udp_header;
 pub mod udp_header_slice;
 pub mod udp_slice;
+pub mod vxlan_header;
+pub mod vxlan_header_slice;
+pub mod vxlan_slice;
+pub mod vxlan_vni;
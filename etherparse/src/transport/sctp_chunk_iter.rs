@@ -0,0 +1,146 @@
+/// Iterator over the chunks following an SCTP common header.
+///
+/// Every chunk is returned as a tuple of the chunk type, the chunk
+/// flags and the chunk value (data after the 4 byte chunk header
+/// without the padding).
+///
+/// If a chunk has an invalid length (smaller then the 4 byte chunk
+/// header or bigger then the remaining data) the iteration ends and
+/// the non decoded bytes can be accessed via [`SctpChunkIter::rest`].
+///
+/// # Example
+///
+/// ```
+/// use etherparse::SctpChunkIter;
+///
+/// let chunks = [
+///     // DATA chunk with 1 byte of user data (+ 3 bytes padding)
+///     0, 3, 0, 17, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0xab, 0, 0, 0,
+///     // COOKIE ACK chunk
+///     11, 0, 0, 4,
+/// ];
+/// let mut iter = SctpChunkIter::from_slice(&chunks);
+/// assert_eq!(
+///     Some((0, 3, &[0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0xab][..])),
+///     iter.next()
+/// );
+/// assert_eq!(Some((11, 0, &[][..])), iter.next());
+/// assert_eq!(None, iter.next());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SctpChunkIter<'a> {
+    /// Not yet decoded chunks.
+    rest: &'a [u8],
+}
+
+impl<'a> SctpChunkIter<'a> {
+    /// Length of the header present in each chunk (type, flags & length).
+    pub const CHUNK_HEADER_LEN: usize = 4;
+
+    /// Creates an iterator over the chunks contained in the given slice
+    /// (data after the SCTP common header).
+    #[inline]
+    pub fn from_slice(chunks: &'a [u8]) -> SctpChunkIter<'a> {
+        SctpChunkIter { rest: chunks }
+    }
+
+    /// Returns the part of the chunks that was not yet decoded.
+    ///
+    /// If this is not empty after the iterator returned `None` the
+    /// last chunk was malformed.
+    #[inline]
+    pub fn rest(&self) -> &'a [u8] {
+        self.rest
+    }
+}
+
+impl<'a> Iterator for SctpChunkIter<'a> {
+    type Item = (u8, u8, &'a [u8]);
+
+    fn next(&mut self) -> Option<(u8, u8, &'a [u8])> {
+        match self.rest {
+            [chunk_type, flags, len_0, len_1, ..] => {
+                // the length includes the chunk header but not the padding
+                let len = usize::from(u16::from_be_bytes([*len_0, *len_1]));
+                if len < Self::CHUNK_HEADER_LEN || len > self.rest.len() {
+                    return None;
+                }
+                let value = &self.rest[Self::CHUNK_HEADER_LEN..len];
+
+                // skip the padding (tolerate missing padding after the last chunk)
+                let padded_len = core::cmp::min((len + 3) & !3, self.rest.len());
+                self.rest = &self.rest[padded_len..];
+                Some((*chunk_type, *flags, value))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn debug_clone_eq() {
+        let iter = SctpChunkIter::from_slice(&[1]);
+        assert_eq!(iter.clone(), iter);
+        assert_eq!(format!("{:?}", iter), "SctpChunkIter { rest: [1] }");
+    }
+
+    #[test]
+    fn next() {
+        // empty
+        {
+            let mut iter = SctpChunkIter::from_slice(&[]);
+            assert_eq!(None, iter.next());
+            assert!(iter.rest().is_empty());
+        }
+
+        // multiple chunks with & without padding
+        {
+            let data = [
+                1, 2, 0, 4, // chunk without value
+                3, 4, 0, 6, 5, 6, 0, 0, // chunk with padding
+                7, 8, 0, 8, 9, 10, 11, 12, // chunk without padding
+                13, 14, 0, 5, 15, // last chunk with missing padding
+            ];
+            let iter = SctpChunkIter::from_slice(&data);
+            assert_eq!(
+                iter.collect::<Vec<_>>(),
+                [
+                    (1, 2, &[][..]),
+                    (3, 4, &[5, 6][..]),
+                    (7, 8, &[9, 10, 11, 12][..]),
+                    (13, 14, &[15][..]),
+                ]
+            );
+        }
+
+        // chunk header too short
+        {
+            let data = [1, 2, 0, 4, 1, 2, 0];
+            let mut iter = SctpChunkIter::from_slice(&data);
+            assert_eq!(Some((1, 2, &[][..])), iter.next());
+            assert_eq!(None, iter.next());
+            assert_eq!(&[1, 2, 0], iter.rest());
+        }
+
+        // length smaller then the chunk header
+        {
+            let data = [1, 2, 0, 3, 0, 0, 0, 0];
+            let mut iter = SctpChunkIter::from_slice(&data);
+            assert_eq!(None, iter.next());
+            assert_eq!(&data, iter.rest());
+        }
+
+        // length bigger then the data
+        {
+            let data = [1, 2, 0, 9, 0, 0, 0, 0];
+            let mut iter = SctpChunkIter::from_slice(&data);
+            assert_eq!(None, iter.next());
+            assert_eq!(&data, iter.rest());
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use crate::*;
+
+/// SCTP common header according to
+/// [RFC 9260](https://datatracker.ietf.org/doc/html/rfc9260#section-3.1).
+///
+/// The header is followed by one or more chunks that can be iterated
+/// via [`SctpChunkIter`].
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SctpHeader {
+    /// SCTP sender's port number.
+    pub source_port: u16,
+    /// SCTP port number to which the packet is destined.
+    pub destination_port: u16,
+    /// Tag used by the receiver to validate the sender of the packet.
+    pub verification_tag: u32,
+    /// CRC32c checksum of the SCTP packet (header & chunks).
+    ///
+    /// The value is read from the header as a big endian number. Use
+    /// [`SctpHeader::calc_checksum`] to calculate a value that can be
+    /// compared with this field.
+    pub checksum: u32,
+}
+
+impl SctpHeader {
+    /// Serialized size of an SCTP common header in bytes/octets.
+    pub const LEN: usize = 12;
+
+    /// Read an SctpHeader from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(SctpHeader, &[u8]), err::LenError> {
+        Ok((
+            SctpHeaderSlice::from_slice(slice)?.to_header(),
+            &slice[SctpHeader::LEN..],
+        ))
+    }
+
+    /// Read an SctpHeader from a static sized byte array.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 12]) -> SctpHeader {
+        SctpHeader {
+            source_port: u16::from_be_bytes([bytes[0], bytes[1]]),
+            destination_port: u16::from_be_bytes([bytes[2], bytes[3]]),
+            verification_tag: u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+            checksum: u32::from_be_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]),
+        }
+    }
+
+    /// Tries to read an SCTP common header from the current position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<SctpHeader, std::io::Error> {
+        let bytes = {
+            let mut bytes: [u8; 12] = [0; 12];
+            reader.read_exact(&mut bytes)?;
+            bytes
+        };
+        Ok(SctpHeader::from_bytes(bytes))
+    }
+
+    /// Write the SCTP common header without recalculating the checksum.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())?;
+        Ok(())
+    }
+
+    /// Calculates the CRC32c checksum of the header and the given chunks
+    /// (everything after the common header).
+    ///
+    /// The returned value uses the same representation as the
+    /// [`SctpHeader::checksum`] field (the checksum field itself is
+    /// treated as zero during the calculation).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::SctpHeader;
+    ///
+    /// let chunks = [1, 0, 0, 4];
+    /// let mut header = SctpHeader {
+    ///     source_port: 1234,
+    ///     destination_port: 80,
+    ///     verification_tag: 0,
+    ///     checksum: 0,
+    /// };
+    /// header.checksum = header.calc_checksum(&chunks);
+    /// assert!(header.is_checksum_valid(&chunks));
+    /// ```
+    pub fn calc_checksum(&self, chunks: &[u8]) -> u32 {
+        let mut bytes = self.to_bytes();
+        bytes[8..12].copy_from_slice(&[0; 4]);
+        let crc = checksum::crc32c_update(checksum::crc32c_update(!0, &bytes), chunks);
+        // The CRC is transmitted with its least significant byte first.
+        u32::from_be_bytes((!crc).to_le_bytes())
+    }
+
+    /// Returns true if the `checksum` field matches the checksum
+    /// calculated over the header and the given chunks.
+    #[inline]
+    pub fn is_checksum_valid(&self, chunks: &[u8]) -> bool {
+        self.checksum == self.calc_checksum(chunks)
+    }
+
+    /// Length of the serialized header in bytes.
+    ///
+    /// The function always returns the constant [`crate::SctpHeader::LEN`]
+    /// and exists to keep the methods consistent with other headers.
+    #[inline]
+    pub const fn header_len(&self) -> usize {
+        SctpHeader::LEN
+    }
+
+    /// Returns the serialized form of the header as a statically
+    /// sized byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 12] {
+        let source_port_be = self.source_port.to_be_bytes();
+        let destination_port_be = self.destination_port.to_be_bytes();
+        let verification_tag_be = self.verification_tag.to_be_bytes();
+        let checksum_be = self.checksum.to_be_bytes();
+        [
+            source_port_be[0],
+            source_port_be[1],
+            destination_port_be[0],
+            destination_port_be[1],
+            verification_tag_be[0],
+            verification_tag_be[1],
+            verification_tag_be[2],
+            verification_tag_be[3],
+            checksum_be[0],
+            checksum_be[1],
+            checksum_be[2],
+            checksum_be[3],
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in sctp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(SctpHeader::LEN + dummy_data.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer.extend(&dummy_data[..]);
+
+            // ok case
+            {
+                let (result, rest) = SctpHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(result, input);
+                assert_eq!(rest, &dummy_data[..]);
+            }
+
+            // length error
+            for len in 0..SctpHeader::LEN {
+                assert_eq!(
+                    SctpHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: SctpHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::SctpHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(input in sctp_any()) {
+            assert_eq!(input, SctpHeader::from_bytes(input.to_bytes()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(input in sctp_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(SctpHeader::LEN);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(input, SctpHeader::read(&mut cursor).unwrap());
+                assert_eq!(SctpHeader::LEN, cursor.position() as usize);
+            }
+            // io errors
+            for len in 0..SctpHeader::LEN {
+                let bytes = input.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(SctpHeader::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; SctpHeader::LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in sctp_any()) {
+            let s_be = input.source_port.to_be_bytes();
+            let d_be = input.destination_port.to_be_bytes();
+            let v_be = input.verification_tag.to_be_bytes();
+            let c_be = input.checksum.to_be_bytes();
+            assert_eq!(
+                input.to_bytes(),
+                [
+                    s_be[0], s_be[1], d_be[0], d_be[1],
+                    v_be[0], v_be[1], v_be[2], v_be[3],
+                    c_be[0], c_be[1], c_be[2], c_be[3],
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn calc_checksum() {
+        // SCTP INIT packet (checksum bytes are the CRC32c in
+        // least significant byte first order, see RFC 9260 appendix A)
+        let packet = [
+            // common header
+            0x80, 0x44, 0x00, 0x50, 0x00, 0x00, 0x00, 0x00, 0xc5, 0xd1, 0x5f, 0xb8,
+            // init chunk
+            0x01, 0x00, 0x00, 0x14, 0x3b, 0xb5, 0xc5, 0x62, 0x00, 0x01, 0xa0, 0x00, 0x00, 0x0a,
+            0xff, 0xff, 0x5b, 0xa2, 0xc4, 0x1f,
+        ];
+        let (header, chunks) = SctpHeader::from_slice(&packet).unwrap();
+        assert_eq!(header.checksum, header.calc_checksum(chunks));
+        assert!(header.is_checksum_valid(chunks));
+
+        // changing the data invalidates the checksum
+        let mut changed = header.clone();
+        changed.verification_tag = 1;
+        assert!(false == changed.is_checksum_valid(chunks));
+        assert!(false == header.is_checksum_valid(&chunks[1..]));
+    }
+
+    proptest! {
+        #[test]
+        fn calc_checksum_ignores_checksum_field(
+            input in sctp_any(),
+            chunks in proptest::collection::vec(any::<u8>(), 0..40)
+        ) {
+            let mut other = input.clone();
+            other.checksum = !input.checksum;
+            assert_eq!(input.calc_checksum(&chunks), other.calc_checksum(&chunks));
+        }
+    }
+
+    #[test]
+    fn header_len() {
+        assert_eq!(SctpHeader::LEN, SctpHeader::default().header_len());
+    }
+
+    #[test]
+    fn default() {
+        let actual: SctpHeader = Default::default();
+        assert_eq!(actual.source_port, 0);
+        assert_eq!(actual.destination_port, 0);
+        assert_eq!(actual.verification_tag, 0);
+        assert_eq!(actual.checksum, 0);
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq(input in sctp_any()) {
+            assert_eq!(input, input.clone());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn dbg(input in sctp_any()) {
+            assert_eq!(
+                &format!(
+                    "SctpHeader {{ source_port: {}, destination_port: {}, verification_tag: {}, checksum: {} }}",
+                    input.source_port,
+                    input.destination_port,
+                    input.verification_tag,
+                    input.checksum,
+                ),
+                &format!("{:?}", input)
+            );
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing an SCTP common header of a network package.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SctpHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> SctpHeaderSlice<'a> {
+    /// Creates a slice containing an SCTP common header.
+    #[inline]
+    pub fn from_slice(slice: &'a [u8]) -> Result<SctpHeaderSlice<'a>, err::LenError> {
+        //check length
+        if slice.len() < SctpHeader::LEN {
+            return Err(err::LenError {
+                required_len: SctpHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::SctpHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        //done
+        Ok(SctpHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // SctpHeader::LEN (12) before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), SctpHeader::LEN) },
+        })
+    }
+
+    /// Returns the slice containing the SCTP common header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Reads the "source port" from the slice.
+    #[inline]
+    pub fn source_port(&self) -> u16 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of SctpHeader::LEN (12).
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr()) }
+    }
+
+    /// Reads the "destination port" from the slice.
+    #[inline]
+    pub fn destination_port(&self) -> u16 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of SctpHeader::LEN (12).
+        unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(2)) }
+    }
+
+    /// Reads the "verification tag" from the slice.
+    #[inline]
+    pub fn verification_tag(&self) -> u32 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of SctpHeader::LEN (12).
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(4)) }
+    }
+
+    /// Reads the "checksum" from the slice (as a big endian number).
+    #[inline]
+    pub fn checksum(&self) -> u32 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of SctpHeader::LEN (12).
+        unsafe { get_unchecked_be_u32(self.slice.as_ptr().add(8)) }
+    }
+
+    /// Calculates the CRC32c checksum of the header and the given chunks
+    /// (see [`SctpHeader::calc_checksum`]).
+    #[inline]
+    pub fn calc_checksum(&self, chunks: &[u8]) -> u32 {
+        self.to_header().calc_checksum(chunks)
+    }
+
+    /// Returns true if the "checksum" field matches the checksum
+    /// calculated over the header and the given chunks.
+    #[inline]
+    pub fn is_checksum_valid(&self, chunks: &[u8]) -> bool {
+        self.checksum() == self.calc_checksum(chunks)
+    }
+
+    /// Decode all the fields and copy the results to a SctpHeader struct.
+    #[inline]
+    pub fn to_header(&self) -> SctpHeader {
+        SctpHeader {
+            source_port: self.source_port(),
+            destination_port: self.destination_port(),
+            verification_tag: self.verification_tag(),
+            checksum: self.checksum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in sctp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(SctpHeader::LEN + dummy_data.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer.extend(&dummy_data[..]);
+
+            // ok case
+            {
+                let slice = SctpHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(slice.slice(), &buffer[..SctpHeader::LEN]);
+            }
+
+            // length error
+            for len in 0..SctpHeader::LEN {
+                assert_eq!(
+                    SctpHeaderSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: SctpHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::SctpHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in sctp_any()) {
+            let bytes = input.to_bytes();
+            let slice = SctpHeaderSlice::from_slice(&bytes).unwrap();
+
+            assert_eq!(slice.source_port(), input.source_port);
+            assert_eq!(slice.destination_port(), input.destination_port);
+            assert_eq!(slice.verification_tag(), input.verification_tag);
+            assert_eq!(slice.checksum(), input.checksum);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn calc_checksum(
+            input in sctp_any(),
+            chunks in proptest::collection::vec(any::<u8>(), 0..40)
+        ) {
+            let bytes = input.to_bytes();
+            let slice = SctpHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice.calc_checksum(&chunks), input.calc_checksum(&chunks));
+            assert_eq!(slice.is_checksum_valid(&chunks), input.is_checksum_valid(&chunks));
+
+            let valid = SctpHeader {
+                checksum: input.calc_checksum(&chunks),
+                ..input.clone()
+            };
+            let bytes = valid.to_bytes();
+            assert!(SctpHeaderSlice::from_slice(&bytes).unwrap().is_checksum_valid(&chunks));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in sctp_any()) {
+            let bytes = input.to_bytes();
+            let slice = SctpHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in sctp_any()) {
+            let bytes = input.to_bytes();
+            let slice = SctpHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                &format!("SctpHeaderSlice {{ slice: {:?} }}", slice.slice()),
+                &format!("{:?}", slice)
+            );
+        }
+    }
+}
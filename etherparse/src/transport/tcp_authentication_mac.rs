@@ -0,0 +1,150 @@
+use crate::{tcp_option, TcpOptionWriteError};
+
+/// Message authentication code of a "TCP Authentication Option" (TCP-AO,
+/// kind 29, see [RFC 5925](https://datatracker.ietf.org/doc/html/rfc5925)).
+///
+/// The length of the MAC depends on the used algorithm and is limited by
+/// the space available in the TCP options (at most 36 bytes).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{TcpAuthenticationMac, TcpOptionWriteError};
+///
+/// let mac = TcpAuthenticationMac::try_from_slice(&[1; 12]).unwrap();
+/// assert_eq!(mac.as_slice(), &[1; 12]);
+///
+/// // the option (4 bytes + mac) has to fit into the 40 bytes of tcp options
+/// assert_eq!(
+///     TcpAuthenticationMac::try_from_slice(&[1; 37]),
+///     Err(TcpOptionWriteError::NotEnoughSpace(41))
+/// );
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TcpAuthenticationMac {
+    /// Number of bytes in the buffer.
+    len: u8,
+
+    /// Buffer containing the MAC (note that the `len` field defines
+    /// the actual length & the unused bytes are always zero).
+    buf: [u8; 36],
+}
+
+impl TcpAuthenticationMac {
+    /// Maximum length of a MAC in bytes (40 bytes of TCP options minus the
+    /// kind, length, key id & rnext key id fields).
+    pub const MAX_LEN: usize = 36;
+
+    /// Empty MAC.
+    pub const EMPTY: TcpAuthenticationMac = TcpAuthenticationMac {
+        len: 0,
+        buf: [0; 36],
+    };
+
+    /// Tries to convert an `u8` slice into a [`TcpAuthenticationMac`].
+    ///
+    /// Returns a [`TcpOptionWriteError::NotEnoughSpace`] error containing the
+    /// length of the complete option if the slice is longer than
+    /// [`TcpAuthenticationMac::MAX_LEN`].
+    pub fn try_from_slice(slice: &[u8]) -> Result<TcpAuthenticationMac, TcpOptionWriteError> {
+        if TcpAuthenticationMac::MAX_LEN < slice.len() {
+            Err(TcpOptionWriteError::NotEnoughSpace(
+                usize::from(tcp_option::LEN_AUTHENTICATION_MIN) + slice.len(),
+            ))
+        } else {
+            let mut buf = [0; 36];
+            buf[..slice.len()].copy_from_slice(slice);
+            Ok(TcpAuthenticationMac {
+                len: slice.len() as u8,
+                buf,
+            })
+        }
+    }
+
+    /// Returns the slice containing the MAC.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..usize::from(self.len)]
+    }
+
+    /// Length of the MAC in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        usize::from(self.len)
+    }
+
+    /// Returns true if the MAC is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        0 == self.len
+    }
+}
+
+impl Default for TcpAuthenticationMac {
+    #[inline]
+    fn default() -> Self {
+        TcpAuthenticationMac::EMPTY
+    }
+}
+
+impl core::fmt::Debug for TcpAuthenticationMac {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("TcpAuthenticationMac")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl TryFrom<&[u8]> for TcpAuthenticationMac {
+    type Error = TcpOptionWriteError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        TcpAuthenticationMac::try_from_slice(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn default() {
+        let actual: TcpAuthenticationMac = Default::default();
+        assert!(actual.is_empty());
+        assert_eq!(0, actual.len());
+        assert_eq!(actual.as_slice(), &[]);
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let mac = TcpAuthenticationMac::try_from_slice(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(mac, mac.clone());
+        assert_eq!("TcpAuthenticationMac([1, 2, 3, 4])", format!("{:?}", mac));
+    }
+
+    proptest! {
+        #[test]
+        fn try_from_slice(data in proptest::collection::vec(any::<u8>(), 0..40)) {
+            if data.len() <= TcpAuthenticationMac::MAX_LEN {
+                let actual = TcpAuthenticationMac::try_from_slice(&data).unwrap();
+                assert_eq!(actual.as_slice(), &data[..]);
+                assert_eq!(actual.len(), data.len());
+                assert_eq!(actual.is_empty(), data.is_empty());
+                assert_eq!(actual, TcpAuthenticationMac::try_from(&data[..]).unwrap());
+            } else {
+                assert_eq!(
+                    TcpAuthenticationMac::try_from_slice(&data),
+                    Err(TcpOptionWriteError::NotEnoughSpace(4 + data.len()))
+                );
+                assert_eq!(
+                    TcpAuthenticationMac::try_from(&data[..]),
+                    Err(TcpOptionWriteError::NotEnoughSpace(4 + data.len()))
+                );
+            }
+        }
+    }
+}
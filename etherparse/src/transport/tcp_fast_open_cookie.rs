@@ -0,0 +1,158 @@
+use crate::TcpOptionWriteError;
+
+/// Cookie of a "TCP Fast Open" option (kind 34, see
+/// [RFC 7413](https://datatracker.ietf.org/doc/html/rfc7413)).
+///
+/// The cookie is either empty (used by a client to request a cookie from
+/// the server) or has a length between 4 & 16 bytes.
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{TcpFastOpenCookie, TcpOptionWriteError};
+///
+/// let cookie = TcpFastOpenCookie::try_from_slice(&[1, 2, 3, 4]).unwrap();
+/// assert_eq!(cookie.as_slice(), &[1, 2, 3, 4]);
+///
+/// // empty cookies are used to request a cookie
+/// assert!(TcpFastOpenCookie::EMPTY.is_empty());
+///
+/// // cookies must be between 4 & 16 bytes long (or empty)
+/// assert_eq!(
+///     TcpFastOpenCookie::try_from_slice(&[1, 2, 3]),
+///     Err(TcpOptionWriteError::InvalidFastOpenCookieLen(3))
+/// );
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TcpFastOpenCookie {
+    /// Number of bytes in the buffer.
+    len: u8,
+
+    /// Buffer containing the cookie (note that the `len` field defines
+    /// the actual length & the unused bytes are always zero).
+    buf: [u8; 16],
+}
+
+impl TcpFastOpenCookie {
+    /// Minimum length of a non empty cookie in bytes.
+    pub const MIN_LEN: usize = 4;
+
+    /// Maximum length of a cookie in bytes.
+    pub const MAX_LEN: usize = 16;
+
+    /// Empty cookie (used to request a cookie).
+    pub const EMPTY: TcpFastOpenCookie = TcpFastOpenCookie {
+        len: 0,
+        buf: [0; 16],
+    };
+
+    /// Tries to convert an `u8` slice into a [`TcpFastOpenCookie`].
+    ///
+    /// Returns a [`TcpOptionWriteError::InvalidFastOpenCookieLen`] error if
+    /// the slice is neither empty nor has a length between
+    /// [`TcpFastOpenCookie::MIN_LEN`] & [`TcpFastOpenCookie::MAX_LEN`].
+    pub fn try_from_slice(slice: &[u8]) -> Result<TcpFastOpenCookie, TcpOptionWriteError> {
+        if !slice.is_empty()
+            && (slice.len() < TcpFastOpenCookie::MIN_LEN
+                || TcpFastOpenCookie::MAX_LEN < slice.len())
+        {
+            Err(TcpOptionWriteError::InvalidFastOpenCookieLen(slice.len()))
+        } else {
+            let mut buf = [0; 16];
+            buf[..slice.len()].copy_from_slice(slice);
+            Ok(TcpFastOpenCookie {
+                len: slice.len() as u8,
+                buf,
+            })
+        }
+    }
+
+    /// Returns the slice containing the cookie.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..usize::from(self.len)]
+    }
+
+    /// Length of the cookie in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        usize::from(self.len)
+    }
+
+    /// Returns true if the cookie is empty (cookie request).
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        0 == self.len
+    }
+}
+
+impl Default for TcpFastOpenCookie {
+    #[inline]
+    fn default() -> Self {
+        TcpFastOpenCookie::EMPTY
+    }
+}
+
+impl core::fmt::Debug for TcpFastOpenCookie {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("TcpFastOpenCookie")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+impl TryFrom<&[u8]> for TcpFastOpenCookie {
+    type Error = TcpOptionWriteError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        TcpFastOpenCookie::try_from_slice(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::format;
+    use proptest::prelude::*;
+
+    #[test]
+    fn default() {
+        let actual: TcpFastOpenCookie = Default::default();
+        assert!(actual.is_empty());
+        assert_eq!(0, actual.len());
+        assert_eq!(actual.as_slice(), &[]);
+    }
+
+    #[test]
+    fn debug_clone_eq() {
+        let cookie = TcpFastOpenCookie::try_from_slice(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(cookie, cookie.clone());
+        assert_eq!("TcpFastOpenCookie([1, 2, 3, 4])", format!("{:?}", cookie));
+    }
+
+    proptest! {
+        #[test]
+        fn try_from_slice(data in proptest::collection::vec(any::<u8>(), 0..20)) {
+            let valid = data.is_empty()
+                || (TcpFastOpenCookie::MIN_LEN..=TcpFastOpenCookie::MAX_LEN).contains(&data.len());
+            if valid {
+                let actual = TcpFastOpenCookie::try_from_slice(&data).unwrap();
+                assert_eq!(actual.as_slice(), &data[..]);
+                assert_eq!(actual.len(), data.len());
+                assert_eq!(actual.is_empty(), data.is_empty());
+                assert_eq!(actual, TcpFastOpenCookie::try_from(&data[..]).unwrap());
+            } else {
+                assert_eq!(
+                    TcpFastOpenCookie::try_from_slice(&data),
+                    Err(TcpOptionWriteError::InvalidFastOpenCookieLen(data.len()))
+                );
+                assert_eq!(
+                    TcpFastOpenCookie::try_from(&data[..]),
+                    Err(TcpOptionWriteError::InvalidFastOpenCookieLen(data.len()))
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+/// Illegal or suspicious combination of TCP flags (e.g. used by port
+/// scanners or to fingerprint operating systems).
+///
+/// The classification is only based on the FIN, SYN, RST, PSH, ACK & URG
+/// flags (ECE, CWR & NS are ignored).
+///
+/// # Example
+///
+/// ```
+/// use etherparse::{TcpFlagAnomaly, TcpHeader};
+///
+/// let mut header = TcpHeader::new(1234, 80, 0, 1024);
+/// header.fin = true;
+/// header.psh = true;
+/// header.urg = true;
+///
+/// assert_eq!(Some(TcpFlagAnomaly::Xmas), header.flag_anomaly());
+/// assert!(header.has_illegal_flag_combo());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TcpFlagAnomaly {
+    /// None of the FIN, SYN, RST, PSH, ACK & URG flags are set ("null scan").
+    Null,
+    /// FIN, PSH & URG are set without ACK ("christmas tree" packet).
+    Xmas,
+    /// SYN & FIN are set at the same time.
+    SynFin,
+    /// SYN & RST are set at the same time.
+    SynRst,
+    /// FIN & RST are set at the same time.
+    FinRst,
+    /// FIN is set without ACK ("FIN scan").
+    FinWithoutAck,
+}
+
+impl TcpFlagAnomaly {
+    const FIN: u8 = 0b0000_0001;
+    const SYN: u8 = 0b0000_0010;
+    const RST: u8 = 0b0000_0100;
+    const PSH: u8 = 0b0000_1000;
+    const ACK: u8 = 0b0001_0000;
+    const URG: u8 = 0b0010_0000;
+
+    /// Classifies the flags byte of a TCP header (the 14th byte containing
+    /// the CWR, ECE, URG, ACK, PSH, RST, SYN & FIN flags).
+    ///
+    /// Returns `None` if the flags are a legal combination. If multiple
+    /// anomalies apply the first one in the order of the enum variants
+    /// is returned.
+    pub const fn from_flags_byte(flags: u8) -> Option<TcpFlagAnomaly> {
+        use TcpFlagAnomaly::*;
+        const fn has(flags: u8, mask: u8) -> bool {
+            mask == flags & mask
+        }
+        if 0 == flags & (Self::FIN | Self::SYN | Self::RST | Self::PSH | Self::ACK | Self::URG) {
+            Some(Null)
+        } else if has(flags, Self::FIN | Self::PSH | Self::URG) && !has(flags, Self::ACK) {
+            Some(Xmas)
+        } else if has(flags, Self::SYN | Self::FIN) {
+            Some(SynFin)
+        } else if has(flags, Self::SYN | Self::RST) {
+            Some(SynRst)
+        } else if has(flags, Self::FIN | Self::RST) {
+            Some(FinRst)
+        } else if has(flags, Self::FIN) && !has(flags, Self::ACK) {
+            Some(FinWithoutAck)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TcpFlagAnomaly::*;
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let value = Xmas;
+        assert_eq!(value, value.clone());
+        assert_eq!("Xmas", format!("{:?}", value));
+    }
+
+    #[test]
+    fn from_flags_byte() {
+        const FIN: u8 = 0b0000_0001;
+        const SYN: u8 = 0b0000_0010;
+        const RST: u8 = 0b0000_0100;
+        const PSH: u8 = 0b0000_1000;
+        const ACK: u8 = 0b0001_0000;
+        const URG: u8 = 0b0010_0000;
+        const ECE: u8 = 0b0100_0000;
+        const CWR: u8 = 0b1000_0000;
+
+        let tests = [
+            // legal combinations
+            (SYN, None),
+            (SYN | ACK, None),
+            (ACK, None),
+            (PSH | ACK, None),
+            (FIN | ACK, None),
+            (RST, None),
+            (RST | ACK, None),
+            (URG | ACK | PSH, None),
+            (SYN | ECE | CWR, None),
+            // anomalies
+            (0, Some(Null)),
+            (ECE | CWR, Some(Null)),
+            (FIN | PSH | URG, Some(Xmas)),
+            (FIN | PSH | URG | SYN | RST, Some(Xmas)),
+            (FIN | PSH | URG | ACK, None),
+            (SYN | FIN, Some(SynFin)),
+            (SYN | FIN | ACK, Some(SynFin)),
+            (SYN | RST, Some(SynRst)),
+            (SYN | RST | ACK, Some(SynRst)),
+            (FIN | RST, Some(FinRst)),
+            (FIN | RST | ACK, Some(FinRst)),
+            (FIN, Some(FinWithoutAck)),
+            (FIN | PSH, Some(FinWithoutAck)),
+            (FIN | URG, Some(FinWithoutAck)),
+        ];
+        for (flags, expected) in tests {
+            assert_eq!(
+                expected,
+                TcpFlagAnomaly::from_flags_byte(flags),
+                "{:#010b}",
+                flags
+            );
+        }
+    }
+}
@@ -20,6 +20,8 @@ pub const TCP_MAXIMUM_DATA_OFFSET: u8 = 0xf;
 ///
 /// Field descriptions copied from RFC 793 page 15++
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TcpHeader {
     /// The source port number.
     pub source_port: u16,
@@ -206,6 +208,63 @@ impl TcpHeader {
         self.options.elements_iter()
     }
 
+    /// Returns the illegal flag combination present in the header
+    /// (see [`TcpFlagAnomaly`]) or `None` if the flags are legal.
+    pub const fn flag_anomaly(&self) -> Option<TcpFlagAnomaly> {
+        TcpFlagAnomaly::from_flags_byte(
+            (self.fin as u8)
+                | ((self.syn as u8) << 1)
+                | ((self.rst as u8) << 2)
+                | ((self.psh as u8) << 3)
+                | ((self.ack as u8) << 4)
+                | ((self.urg as u8) << 5),
+        )
+    }
+
+    /// Returns true if the header contains an illegal flag combination
+    /// (see [`TcpFlagAnomaly`] for the detected combinations).
+    #[inline]
+    pub const fn has_illegal_flag_combo(&self) -> bool {
+        self.flag_anomaly().is_some()
+    }
+
+    /// Returns true if none of the FIN, SYN, RST, PSH, ACK & URG flags
+    /// are set ("null scan").
+    #[inline]
+    pub const fn is_null_scan(&self) -> bool {
+        !(self.fin || self.syn || self.rst || self.psh || self.ack || self.urg)
+    }
+
+    /// Returns true if FIN, PSH & URG are set without ACK ("christmas tree" packet).
+    #[inline]
+    pub const fn is_xmas_scan(&self) -> bool {
+        self.fin && self.psh && self.urg && !self.ack
+    }
+
+    /// Returns true if SYN & FIN are both set.
+    #[inline]
+    pub const fn is_syn_fin(&self) -> bool {
+        self.syn && self.fin
+    }
+
+    /// Returns true if SYN & RST are both set.
+    #[inline]
+    pub const fn is_syn_rst(&self) -> bool {
+        self.syn && self.rst
+    }
+
+    /// Returns true if FIN & RST are both set.
+    #[inline]
+    pub const fn is_fin_rst(&self) -> bool {
+        self.fin && self.rst
+    }
+
+    /// Returns true if FIN is set without ACK ("FIN scan").
+    #[inline]
+    pub const fn is_fin_without_ack(&self) -> bool {
+        self.fin && !self.ack
+    }
+
     /// Renamed to `TcpHeader::from_slice`
     #[deprecated(since = "0.10.1", note = "Use TcpHeader::from_slice instead.")]
     #[inline]
@@ -220,6 +279,17 @@ impl TcpHeader {
         Ok((h.to_header(), &slice[h.slice().len()..]))
     }
 
+    /// Reads a tcp header from a slice and returns the header & the number
+    /// of bytes consumed from the slice (the header length including the
+    /// options).
+    #[inline]
+    pub fn from_slice_with_len(
+        slice: &[u8],
+    ) -> Result<(TcpHeader, usize), err::tcp::HeaderSliceError> {
+        let (header, rest) = TcpHeader::from_slice(slice)?;
+        Ok((header, slice.len() - rest.len()))
+    }
+
     /// Read a tcp header from the current position
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -361,7 +431,24 @@ impl TcpHeader {
         Ok(())
     }
 
-    /// Returns the serialized header.
+    /// Returns the serialized header including the options (does NOT
+    /// calculate the checksum).
+    ///
+    /// The result is identical to the data written by [`TcpHeader::write`]
+    /// and can be decoded again via [`TcpHeader::from_slice`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{TcpHeader, TcpOptionElement};
+    ///
+    /// let mut header = TcpHeader::new(1234, 80, 1, 4096);
+    /// header.set_options(&[TcpOptionElement::MaximumSegmentSize(1400)]).unwrap();
+    ///
+    /// let bytes = header.to_bytes();
+    /// assert_eq!(24, bytes.len());
+    /// assert_eq!(header, TcpHeader::from_slice(&bytes).unwrap().0);
+    /// ```
     pub fn to_bytes(&self) -> ArrayVec<u8, { TcpHeader::MAX_LEN }> {
         //check that the data offset is within range
         let src_be = self.source_port.to_be_bytes();
@@ -443,6 +530,40 @@ impl TcpHeader {
         result
     }
 
+    /// Serialize the header including the options to the given slice
+    /// (does NOT calculate the checksum). Returns the unused part of the
+    /// slice.
+    ///
+    /// Does not allocate & can be used without the `std` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::TcpHeader;
+    ///
+    /// let header = TcpHeader::new(1234, 80, 1, 4096);
+    ///
+    /// let mut buffer = [0u8; 28];
+    /// let rest = header.write_to_slice(&mut buffer).unwrap();
+    /// assert_eq!(8, rest.len());
+    /// ```
+    pub fn write_to_slice<'a>(
+        &self,
+        slice: &'a mut [u8],
+    ) -> Result<&'a mut [u8], err::SliceWriteSpaceError> {
+        let header_len = self.header_len();
+        if slice.len() < header_len {
+            return Err(err::SliceWriteSpaceError {
+                required_len: header_len,
+                len: slice.len(),
+                layer: err::Layer::TcpHeader,
+                layer_start_offset: 0,
+            });
+        }
+        slice[..header_len].copy_from_slice(&self.to_bytes());
+        Ok(&mut slice[header_len..])
+    }
+
     /// Calculates the upd header checksum based on a ipv4 header and returns the result. This does NOT set the checksum.
     pub fn calc_checksum_ipv4(
         &self,
@@ -603,6 +724,67 @@ impl Default for TcpHeader {
     }
 }
 
+impl TryFrom<&[u8]> for TcpHeader {
+    type Error = err::tcp::HeaderSliceError;
+
+    /// Decodes the header from the start of the slice via
+    /// [`TcpHeader::from_slice`] (any data after the header is ignored).
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        TcpHeader::from_slice(value).map(|(header, _)| header)
+    }
+}
+
+/// Writes a compact single line summary of the header (the set flags
+/// are listed in brackets).
+///
+/// ```
+/// use etherparse::TcpHeader;
+///
+/// let mut header = TcpHeader::new(1234, 80, 1, 1024);
+/// header.syn = true;
+/// header.ack = true;
+/// header.acknowledgment_number = 2;
+/// assert_eq!(
+///     "TCP 1234 -> 80 seq=1 ack=2 win=1024 flags=[SYN,ACK]",
+///     format!("{}", header)
+/// );
+/// ```
+impl core::fmt::Display for TcpHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "TCP {} -> {} seq={} ack={} win={} flags=[",
+            self.source_port,
+            self.destination_port,
+            self.sequence_number,
+            self.acknowledgment_number,
+            self.window_size
+        )?;
+        let flags = [
+            (self.fin, "FIN"),
+            (self.syn, "SYN"),
+            (self.rst, "RST"),
+            (self.psh, "PSH"),
+            (self.ack, "ACK"),
+            (self.urg, "URG"),
+            (self.ece, "ECE"),
+            (self.cwr, "CWR"),
+            (self.ns, "NS"),
+        ];
+        let mut first = true;
+        for (_, name) in flags.iter().filter(|(set, _)| *set) {
+            if first {
+                first = false;
+            } else {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", name)?;
+        }
+        write!(f, "]")
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -724,6 +906,81 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn flag_anomaly(
+            base in tcp_any(),
+            flags in any::<u8>()
+        ) {
+            let header = TcpHeader {
+                fin: 0 != flags & 0b0000_0001,
+                syn: 0 != flags & 0b0000_0010,
+                rst: 0 != flags & 0b0000_0100,
+                psh: 0 != flags & 0b0000_1000,
+                ack: 0 != flags & 0b0001_0000,
+                urg: 0 != flags & 0b0010_0000,
+                ece: 0 != flags & 0b0100_0000,
+                cwr: 0 != flags & 0b1000_0000,
+                ..base
+            };
+            let expected = TcpFlagAnomaly::from_flags_byte(flags);
+            assert_eq!(expected, header.flag_anomaly());
+            assert_eq!(expected.is_some(), header.has_illegal_flag_combo());
+
+            // individual predicates
+            assert_eq!(
+                !(header.fin || header.syn || header.rst || header.psh || header.ack || header.urg),
+                header.is_null_scan()
+            );
+            assert_eq!(
+                header.fin && header.psh && header.urg && !header.ack,
+                header.is_xmas_scan()
+            );
+            assert_eq!(header.syn && header.fin, header.is_syn_fin());
+            assert_eq!(header.syn && header.rst, header.is_syn_rst());
+            assert_eq!(header.fin && header.rst, header.is_fin_rst());
+            assert_eq!(header.fin && !header.ack, header.is_fin_without_ack());
+            assert_eq!(
+                header.has_illegal_flag_combo(),
+                header.is_null_scan()
+                    || header.is_xmas_scan()
+                    || header.is_syn_fin()
+                    || header.is_syn_rst()
+                    || header.is_fin_rst()
+                    || header.is_fin_without_ack()
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        let mut header = TcpHeader::new(1, 2, 3, 4);
+        header.acknowledgment_number = 5;
+        assert_eq!(
+            "TCP 1 -> 2 seq=3 ack=5 win=4 flags=[]",
+            format!("{}", header)
+        );
+
+        header.fin = true;
+        assert_eq!(
+            "TCP 1 -> 2 seq=3 ack=5 win=4 flags=[FIN]",
+            format!("{}", header)
+        );
+
+        header.syn = true;
+        header.rst = true;
+        header.psh = true;
+        header.ack = true;
+        header.urg = true;
+        header.ece = true;
+        header.cwr = true;
+        header.ns = true;
+        assert_eq!(
+            "TCP 1 -> 2 seq=3 ack=5 win=4 flags=[FIN,SYN,RST,PSH,ACK,URG,ECE,CWR,NS]",
+            format!("{}", header)
+        );
+    }
+
     #[test]
     fn eq() {
         let options = [
@@ -1213,6 +1470,74 @@ mod test {
                 );
             }
 
+            // tcp fast open cookie
+            {
+                let mut header = header.clone();
+                header.set_options(
+                    &[
+                        Noop,
+                        FastOpenCookie(TcpFastOpenCookie::EMPTY),
+                        FastOpenCookie(TcpFastOpenCookie::try_from_slice(&arg0_u32.to_be_bytes()).unwrap()),
+                    ]
+                ).unwrap();
+                let arg0_be = arg0_u32.to_be_bytes();
+                assert_eq!(
+                    header.options.as_slice(),
+                    &[
+                        KIND_NOOP, KIND_FAST_OPEN_COOKIE, 2, KIND_FAST_OPEN_COOKIE,
+                        6, arg0_be[0], arg0_be[1], arg0_be[2],
+                        arg0_be[3], KIND_END, 0, 0
+                    ]
+                );
+                assert_eq!(
+                    header.options_iterator().collect::<Vec<_>>(),
+                    [
+                        Ok(Noop),
+                        Ok(FastOpenCookie(TcpFastOpenCookie::EMPTY)),
+                        Ok(FastOpenCookie(TcpFastOpenCookie::try_from_slice(&arg0_be).unwrap())),
+                    ]
+                );
+            }
+
+            // md5 signature & tcp authentication option
+            {
+                let mut header = header.clone();
+                let arg0_be = arg0_u32.to_be_bytes();
+                header.set_options(
+                    &[
+                        Md5Signature([1; 16]),
+                        AuthenticationOption {
+                            key_id: 2,
+                            rnext_key_id: 3,
+                            mac: TcpAuthenticationMac::try_from_slice(&arg0_be).unwrap(),
+                        },
+                    ]
+                ).unwrap();
+                assert_eq!(
+                    header.options.as_slice(),
+                    &[
+                        KIND_MD5_SIGNATURE, 18, 1, 1,
+                        1, 1, 1, 1,
+                        1, 1, 1, 1,
+                        1, 1, 1, 1,
+                        1, 1, KIND_AUTHENTICATION, 8,
+                        2, 3, arg0_be[0], arg0_be[1],
+                        arg0_be[2], arg0_be[3], KIND_END, 0
+                    ]
+                );
+                assert_eq!(
+                    header.options_iterator().collect::<Vec<_>>(),
+                    [
+                        Ok(Md5Signature([1; 16])),
+                        Ok(AuthenticationOption {
+                            key_id: 2,
+                            rnext_key_id: 3,
+                            mac: TcpAuthenticationMac::try_from_slice(&arg0_be).unwrap(),
+                        }),
+                    ]
+                );
+            }
+
             // check for padding
             {
                 let mut header = header.clone();
@@ -1421,6 +1746,12 @@ mod test {
                 let (actual_header, actual_rest) = TcpHeader::from_slice(&bytes[..]).unwrap();
                 assert_eq!(actual_header, header);
                 assert_eq!(actual_rest, &bytes[header.header_len() as usize..]);
+
+                // with len
+                assert_eq!(
+                    TcpHeader::from_slice_with_len(&bytes[..]).unwrap(),
+                    (header.clone(), header.header_len() as usize)
+                );
             }
 
             // data offset error
@@ -1460,6 +1791,30 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn try_from(header in tcp_any()) {
+            // ok
+            {
+                let mut bytes = Vec::with_capacity(header.header_len() as usize + 1);
+                bytes.extend_from_slice(&header.to_bytes());
+                bytes.push(1);
+                assert_eq!(TcpHeader::try_from(&bytes[..]), Ok(header.clone()));
+            }
+
+            // error
+            {
+                let bytes = header.to_bytes();
+                let len = header.header_len() as usize - 1;
+                assert_eq!(
+                    TcpHeader::try_from(&bytes[..len]),
+                    TcpHeader::from_slice(&bytes[..len]).map(|(h, _)| h)
+                );
+                assert!(TcpHeader::try_from(&bytes[..len]).is_err());
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn read(header in tcp_any()) {
@@ -1534,6 +1889,42 @@ mod test {
             let bytes = header.to_bytes();
             let actual = TcpHeader::from_slice(&bytes).unwrap().0;
             assert_eq!(actual, header);
+
+            // identical to the data written by write
+            let mut written = Vec::with_capacity(header.header_len());
+            header.write(&mut written).unwrap();
+            assert_eq!(&written[..], &bytes[..]);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn write_to_slice(header in tcp_any()) {
+            // ok
+            {
+                let mut buffer = [0u8; TcpHeader::MAX_LEN + 1];
+                let rest_len = header.write_to_slice(&mut buffer).unwrap().len();
+                assert_eq!(buffer.len() - header.header_len(), rest_len);
+                assert_eq!(&buffer[..header.header_len()], &header.to_bytes()[..]);
+                assert_eq!(
+                    header,
+                    TcpHeader::from_slice(&buffer).unwrap().0
+                );
+            }
+
+            // len too small
+            for len in 0..header.header_len() {
+                let mut buffer = [0u8; TcpHeader::MAX_LEN];
+                assert_eq!(
+                    err::SliceWriteSpaceError {
+                        required_len: header.header_len(),
+                        len,
+                        layer: err::Layer::TcpHeader,
+                        layer_start_offset: 0,
+                    },
+                    header.write_to_slice(&mut buffer[..len]).unwrap_err()
+                );
+            }
         }
     }
 
@@ -1885,4 +2276,53 @@ mod test {
             );
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_tokens, Token};
+        let mut header = TcpHeader::new(1, 2, 3, 4);
+        header.syn = true;
+        header.set_options_raw(&[5, 6, 7, 8]).unwrap();
+        let mut tokens = alloc::vec![
+            Token::Struct {
+                name: "TcpHeader",
+                len: 17,
+            },
+            Token::Str("source_port"),
+            Token::U16(1),
+            Token::Str("destination_port"),
+            Token::U16(2),
+            Token::Str("sequence_number"),
+            Token::U32(3),
+            Token::Str("acknowledgment_number"),
+            Token::U32(0),
+        ];
+        for (name, value) in [
+            ("ns", false),
+            ("fin", false),
+            ("syn", true),
+            ("rst", false),
+            ("psh", false),
+            ("ack", false),
+            ("urg", false),
+            ("ece", false),
+            ("cwr", false),
+        ] {
+            tokens.push(Token::Str(name));
+            tokens.push(Token::Bool(value));
+        }
+        tokens.extend([
+            Token::Str("window_size"),
+            Token::U16(4),
+            Token::Str("checksum"),
+            Token::U16(0),
+            Token::Str("urgent_pointer"),
+            Token::U16(0),
+            Token::Str("options"),
+            Token::Bytes(&[5, 6, 7, 8]),
+            Token::StructEnd,
+        ]);
+        assert_tokens(&header, &tokens);
+    }
 }
@@ -233,6 +233,16 @@ impl<'a> TcpHeaderSlice<'a> {
         unsafe { 0 != (*self.slice.get_unchecked(13) & 0b1000_0000) }
     }
 
+    /// Returns the illegal flag combination present in the header
+    /// (see [`TcpFlagAnomaly`]) or `None` if the flags are legal.
+    #[inline]
+    pub fn flag_anomaly(&self) -> Option<TcpFlagAnomaly> {
+        // SAFETY:
+        // Constructor checks that the slice has at least the length
+        // of 20.
+        TcpFlagAnomaly::from_flags_byte(unsafe { *self.slice.get_unchecked(13) })
+    }
+
     /// The number of data octets beginning with the one indicated in the
     /// acknowledgment field which the sender of this segment is willing to
     /// accept.
@@ -519,6 +529,7 @@ mod test {
             assert_eq!(header.urg, slice.urg());
             assert_eq!(header.ece, slice.ece());
             assert_eq!(header.cwr, slice.cwr());
+            assert_eq!(header.flag_anomaly(), slice.flag_anomaly());
             assert_eq!(header.window_size, slice.window_size());
             assert_eq!(header.checksum, slice.checksum());
             assert_eq!(header.urgent_pointer, slice.urgent_pointer());
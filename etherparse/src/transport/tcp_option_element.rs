@@ -1,6 +1,9 @@
+use crate::{TcpAuthenticationMac, TcpFastOpenCookie};
+
 /// Different kinds of options that can be present in the options part of a tcp header.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum TcpOptionElement {
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TcpOptionElement<'a> {
     /// "No-Operation" option.
     ///
     /// Description from RFC 793:
@@ -26,6 +29,36 @@ pub enum TcpOptionElement {
     SelectiveAcknowledgement((u32, u32), [Option<(u32, u32)>; 3]),
     ///Timestamp & echo (first number is the sender timestamp, the second the echo timestamp)
     Timestamp(u32, u32),
+    /// "MD5 Signature" option (RFC 2385) containing the 16 byte MD5 digest.
+    Md5Signature([u8; 16]),
+    /// "TCP Authentication Option" (TCP-AO, RFC 5925).
+    AuthenticationOption {
+        /// Identifier of the master key tuple used to generate the MAC.
+        key_id: u8,
+        /// Identifier of the master key tuple the sender is ready to
+        /// use for received segments.
+        rnext_key_id: u8,
+        /// Message authentication code.
+        mac: TcpAuthenticationMac,
+    },
+    /// "TCP Fast Open" option (RFC 7413) containing the cookie.
+    ///
+    /// An empty cookie is used by a client to request a cookie from
+    /// the server.
+    FastOpenCookie(TcpFastOpenCookie),
+    /// Option with a kind not known to etherparse.
+    ///
+    /// Only options using the "kind, length, data" format can be
+    /// represented (all kinds except "End of Option List" (0) and
+    /// "No-Operation" (1) are expected to use this format).
+    /// [`crate::TcpOptions::try_from_elements`] rejects unknown options
+    /// with the kinds 0 & 1.
+    Unknown {
+        /// Kind of the option.
+        kind: u8,
+        /// Data of the option (without the kind & length bytes).
+        data: &'a [u8],
+    },
 }
 
 #[cfg(test)]
@@ -43,6 +76,17 @@ mod test {
             SelectiveAcknowledgementPermitted,
             SelectiveAcknowledgement((1, 2), [Some((3, 4)), Some((5, 6)), None]),
             Timestamp(123, 456),
+            Md5Signature([1; 16]),
+            AuthenticationOption {
+                key_id: 1,
+                rnext_key_id: 2,
+                mac: TcpAuthenticationMac::EMPTY,
+            },
+            FastOpenCookie(TcpFastOpenCookie::EMPTY),
+            Unknown {
+                kind: 253,
+                data: &[1, 2],
+            },
         ];
         for value in values {
             assert_eq!(value.clone(), value);
@@ -70,5 +114,37 @@ mod test {
             )
         );
         assert_eq!("Timestamp(123, 456)", format!("{:?}", Timestamp(123, 456)));
+        assert_eq!(
+            "Md5Signature([1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1])",
+            format!("{:?}", Md5Signature([1; 16]))
+        );
+        assert_eq!(
+            "AuthenticationOption { key_id: 1, rnext_key_id: 2, mac: TcpAuthenticationMac([3, 4]) }",
+            format!(
+                "{:?}",
+                AuthenticationOption {
+                    key_id: 1,
+                    rnext_key_id: 2,
+                    mac: TcpAuthenticationMac::try_from_slice(&[3, 4]).unwrap(),
+                }
+            )
+        );
+        assert_eq!(
+            "FastOpenCookie(TcpFastOpenCookie([1, 2, 3, 4]))",
+            format!(
+                "{:?}",
+                FastOpenCookie(TcpFastOpenCookie::try_from_slice(&[1, 2, 3, 4]).unwrap())
+            )
+        );
+        assert_eq!(
+            "Unknown { kind: 253, data: [1, 2] }",
+            format!(
+                "{:?}",
+                Unknown {
+                    kind: 253,
+                    data: &[1, 2],
+                }
+            )
+        );
     }
 }
@@ -57,6 +57,12 @@ pub mod tcp_option {
     pub const KIND_SELECTIVE_ACK: u8 = 5;
     /// `u8` identifying a "timestamp and echo of previous timestamp" tcp option.
     pub const KIND_TIMESTAMP: u8 = 8;
+    /// `u8` identifying a "MD5 signature" tcp option (RFC 2385).
+    pub const KIND_MD5_SIGNATURE: u8 = 19;
+    /// `u8` identifying a "TCP authentication option" (TCP-AO, RFC 5925).
+    pub const KIND_AUTHENTICATION: u8 = 29;
+    /// `u8` identifying a "TCP Fast Open cookie" tcp option (RFC 7413).
+    pub const KIND_FAST_OPEN_COOKIE: u8 = 34;
     /// Length in octets/bytes of the "end" tcp option (includes kind value).
     pub const LEN_END: u8 = 1;
     /// Length in octets/bytes of the "no operation" tcp option (includes kind value).
@@ -69,4 +75,15 @@ pub mod tcp_option {
     pub const LEN_SELECTIVE_ACK_PERMITTED: u8 = 2;
     /// Length in octets/bytes of the "timestamp and echo of previous timestamp" tcp option (includes kind value).
     pub const LEN_TIMESTAMP: u8 = 10;
+    /// Length in octets/bytes of the "MD5 signature" tcp option (includes kind & length value).
+    pub const LEN_MD5_SIGNATURE: u8 = 18;
+    /// Minimum length in octets/bytes of the "TCP authentication option"
+    /// (includes kind, length, key id & rnext key id value).
+    pub const LEN_AUTHENTICATION_MIN: u8 = 4;
+    /// Minimum length in octets/bytes of the "TCP Fast Open cookie" tcp option
+    /// (includes kind & length value, empty cookie used to request a cookie).
+    pub const LEN_FAST_OPEN_COOKIE_MIN: u8 = 2;
+    /// Maximum length in octets/bytes of the "TCP Fast Open cookie" tcp option
+    /// (includes kind & length value).
+    pub const LEN_FAST_OPEN_COOKIE_MAX: u8 = 18;
 }
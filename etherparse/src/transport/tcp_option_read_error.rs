@@ -13,10 +13,44 @@ pub enum TcpOptionReadError {
 
     /// Returned if an unknown tcp header option is encountered.
     ///
-    /// The first element is the identifier and the slice contains the rest of data left in the options.
+    /// Note that [`crate::TcpOptionsIterator`] no longer returns this
+    /// error. Unknown options are instead returned as
+    /// [`crate::TcpOptionElement::Unknown`] and skipped based on their
+    /// length field.
+    #[deprecated(
+        note = "Unknown options are returned as `TcpOptionElement::Unknown`, this error is no longer produced."
+    )]
     UnknownId(u8),
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for TcpOptionReadError {
+    #[allow(deprecated)]
+    fn format(&self, f: defmt::Formatter) {
+        use TcpOptionReadError::*;
+        match self {
+            UnexpectedEndOfSlice {
+                option_id,
+                expected_len,
+                actual_len,
+            } => defmt::write!(
+                f,
+                "UnexpectedEndOfSlice {{ option_id: {}, expected_len: {}, actual_len: {} }}",
+                option_id,
+                expected_len,
+                actual_len
+            ),
+            UnexpectedSize { option_id, size } => defmt::write!(
+                f,
+                "UnexpectedSize {{ option_id: {}, size: {} }}",
+                option_id,
+                size
+            ),
+            UnknownId(id) => defmt::write!(f, "UnknownId({})", id),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl std::error::Error for TcpOptionReadError {
@@ -26,6 +60,7 @@ impl std::error::Error for TcpOptionReadError {
 }
 
 impl core::fmt::Display for TcpOptionReadError {
+    #[allow(deprecated)]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         use TcpOptionReadError::*;
         match self {
@@ -51,6 +86,7 @@ impl core::fmt::Display for TcpOptionReadError {
 }
 
 #[cfg(test)]
+#[allow(deprecated)]
 mod test {
     use crate::*;
     use alloc::format;
@@ -1,5 +1,6 @@
 /// Errors that can occour when setting the options of a tcp header.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum TcpOptionWriteError {
     /// There is not enough memory to store all options in the options section of the header (maximum 40 bytes).
     ///
@@ -7,6 +8,15 @@ pub enum TcpOptionWriteError {
     /// the total tcp header size in multiple of 4 bytes. This leads to a maximum size for the options
     /// part of the header of 4*(15 - 5) (minus 5 for the size of the tcp header itself).
     NotEnoughSpace(usize),
+
+    /// Length of a TCP Fast Open cookie is not valid (the cookie must either
+    /// be empty or have a length between 4 and 16 bytes).
+    InvalidFastOpenCookieLen(usize),
+
+    /// Kind of a [`crate::TcpOptionElement::Unknown`] is "End of Option
+    /// List" (0) or "No-Operation" (1). These kinds have no length field
+    /// and can not be represented as an unknown option.
+    InvalidUnknownKind(u8),
 }
 
 #[cfg(feature = "std")]
@@ -24,6 +34,12 @@ impl core::fmt::Display for TcpOptionWriteError {
             NotEnoughSpace(size) => {
                 write!(f, "TcpOptionWriteError: Not enough memory to store all options in the options section of a tcp header (maximum 40 bytes can be stored, the options would have needed {} bytes).", size)
             }
+            InvalidFastOpenCookieLen(len) => {
+                write!(f, "TcpOptionWriteError: Invalid TCP Fast Open cookie length of {} bytes (the cookie must be empty or have a length between 4 and 16 bytes).", len)
+            }
+            InvalidUnknownKind(kind) => {
+                write!(f, "TcpOptionWriteError: Unknown TCP option has the kind {} which is reserved for the single byte options 'End of Option List' (0) & 'No-Operation' (1).", kind)
+            }
         }
     }
 }
@@ -38,6 +54,14 @@ mod test {
     fn debug() {
         use TcpOptionWriteError::*;
         assert_eq!("NotEnoughSpace(0)", format!("{:?}", NotEnoughSpace(0)));
+        assert_eq!(
+            "InvalidFastOpenCookieLen(3)",
+            format!("{:?}", InvalidFastOpenCookieLen(3))
+        );
+        assert_eq!(
+            "InvalidUnknownKind(1)",
+            format!("{:?}", InvalidUnknownKind(1))
+        );
     }
 
     #[test]
@@ -50,23 +74,33 @@ mod test {
     #[cfg(feature = "std")]
     proptest! {
         #[test]
-        fn source(arg_usize in any::<usize>()) {
+        fn source(arg_usize in any::<usize>(), arg_u8 in any::<u8>()) {
             use std::error::Error;
             use crate::TcpOptionWriteError::*;
 
             assert!(NotEnoughSpace(arg_usize).source().is_none());
+            assert!(InvalidFastOpenCookieLen(arg_usize).source().is_none());
+            assert!(InvalidUnknownKind(arg_u8).source().is_none());
         }
     }
 
     proptest! {
         #[test]
-        fn fmt(arg_usize in any::<usize>()) {
+        fn fmt(arg_usize in any::<usize>(), arg_u8 in any::<u8>()) {
             use crate::TcpOptionWriteError::*;
 
             assert_eq!(
                 &format!("TcpOptionWriteError: Not enough memory to store all options in the options section of a tcp header (maximum 40 bytes can be stored, the options would have needed {} bytes).", arg_usize),
                 &format!("{}", NotEnoughSpace(arg_usize))
             );
+            assert_eq!(
+                &format!("TcpOptionWriteError: Invalid TCP Fast Open cookie length of {} bytes (the cookie must be empty or have a length between 4 and 16 bytes).", arg_usize),
+                &format!("{}", InvalidFastOpenCookieLen(arg_usize))
+            );
+            assert_eq!(
+                &format!("TcpOptionWriteError: Unknown TCP option has the kind {} which is reserved for the single byte options 'End of Option List' (0) & 'No-Operation' (1).", arg_u8),
+                &format!("{}", InvalidUnknownKind(arg_u8))
+            );
         }
     }
 }
@@ -247,11 +247,30 @@ impl TcpOptions {
     ///     ]
     /// );
     /// ```
+    ///
+    /// # Errors
+    ///
+    /// * [`TcpOptionWriteError::NotEnoughSpace`] if the elements need more
+    ///   than 40 bytes.
+    /// * [`TcpOptionWriteError::InvalidUnknownKind`] if an
+    ///   [`TcpOptionElement::Unknown`] has the kind "End of Option List" (0)
+    ///   or "No-Operation" (1).
     pub fn try_from_elements(
-        elements: &[TcpOptionElement],
+        elements: &[TcpOptionElement<'_>],
     ) -> Result<TcpOptions, TcpOptionWriteError> {
-        // calculate the required size of the options
         use crate::TcpOptionElement::*;
+
+        // end & noop have no length field and can not be written as
+        // unknown options
+        for element in elements {
+            if let Unknown { kind, .. } = element {
+                if tcp_option::KIND_END == *kind || tcp_option::KIND_NOOP == *kind {
+                    return Err(TcpOptionWriteError::InvalidUnknownKind(*kind));
+                }
+            }
+        }
+
+        // calculate the required size of the options
         let required_len = elements.iter().fold(0, |acc, ref x| {
             acc + match x {
                 Noop => 1,
@@ -263,6 +282,10 @@ impl TcpOptions {
                     Some(_) => acc2 + 8,
                 }),
                 Timestamp(_, _) => 10,
+                Md5Signature(_) => 18,
+                AuthenticationOption { mac, .. } => 4 + mac.len(),
+                FastOpenCookie(cookie) => 2 + cookie.len(),
+                Unknown { data, .. } => 2 + data.len(),
             }
         });
 
@@ -358,6 +381,50 @@ impl TcpOptions {
 
                         len += 10;
                     }
+                    Md5Signature(digest) => {
+                        let t = &mut buf[len..len + 18];
+
+                        t[0] = KIND_MD5_SIGNATURE;
+                        t[1] = 18;
+                        t[2..].copy_from_slice(digest);
+
+                        len += 18;
+                    }
+                    AuthenticationOption {
+                        key_id,
+                        rnext_key_id,
+                        mac,
+                    } => {
+                        let mac = mac.as_slice();
+                        let t = &mut buf[len..len + 4 + mac.len()];
+
+                        t[0] = KIND_AUTHENTICATION;
+                        t[1] = (4 + mac.len()) as u8;
+                        t[2] = *key_id;
+                        t[3] = *rnext_key_id;
+                        t[4..].copy_from_slice(mac);
+
+                        len += 4 + mac.len();
+                    }
+                    FastOpenCookie(cookie) => {
+                        let cookie = cookie.as_slice();
+                        let t = &mut buf[len..len + 2 + cookie.len()];
+
+                        t[0] = KIND_FAST_OPEN_COOKIE;
+                        t[1] = (2 + cookie.len()) as u8;
+                        t[2..].copy_from_slice(cookie);
+
+                        len += 2 + cookie.len();
+                    }
+                    Unknown { kind, data } => {
+                        let t = &mut buf[len..len + 2 + data.len()];
+
+                        t[0] = *kind;
+                        t[1] = (2 + data.len()) as u8;
+                        t[2..].copy_from_slice(data);
+
+                        len += 2 + data.len();
+                    }
                 }
             }
             // set the new data offset
@@ -489,21 +556,55 @@ impl<'a> TryFrom<&'a [u8]> for TcpOptions {
     }
 }
 
-impl<'a> TryFrom<&'a [TcpOptionElement]> for TcpOptions {
+impl<'a, 'b> TryFrom<&'a [TcpOptionElement<'b>]> for TcpOptions {
     type Error = TcpOptionWriteError;
 
     #[inline]
-    fn try_from(value: &'a [TcpOptionElement]) -> Result<Self, Self::Error> {
+    fn try_from(value: &'a [TcpOptionElement<'b>]) -> Result<Self, Self::Error> {
         TcpOptions::try_from_elements(value)
     }
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for TcpOptions {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.as_slice())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for TcpOptions {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (buf, len) = deserializer.deserialize_bytes(crate::OptionsBufVisitor)?;
+        TcpOptions::try_from_slice(&buf[..len]).map_err(serde::de::Error::custom)
+    }
+}
+
 impl core::fmt::Debug for TcpOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.elements_iter().fmt(f)
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for TcpOptions {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "[");
+        for (index, element) in self.elements_iter().enumerate() {
+            if index > 0 {
+                defmt::write!(f, ", ");
+            }
+            match element {
+                Ok(e) => defmt::write!(f, "{}", e),
+                Err(e) => defmt::write!(f, "Err({})", e),
+            }
+        }
+        defmt::write!(f, "]");
+    }
+}
+
 impl core::hash::Hash for TcpOptions {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.as_slice().hash(state);
@@ -633,6 +734,33 @@ mod test {
                 KIND_NOOP, KIND_NOOP, KIND_NOOP, KIND_NOOP
             ]))
         );
+
+        // unknown options are written with their kind & length
+        {
+            use crate::TcpOptionElement::Unknown;
+            let actual = TcpOptions::try_from_elements(
+                &[
+                    Unknown {
+                        kind: 253,
+                        data: &[1, 2, 3],
+                    },
+                    Noop,
+                ][..],
+            );
+            assert_eq!(
+                actual,
+                Ok(TcpOptions::from([253, 5, 1, 2, 3, KIND_NOOP, 0, 0]))
+            );
+        }
+
+        // end & noop can not be written as unknown options
+        for kind in [crate::tcp_option::KIND_END, KIND_NOOP] {
+            use crate::TcpOptionElement::Unknown;
+            assert_eq!(
+                TcpOptions::try_from_elements(&[Unknown { kind, data: &[] }][..]),
+                Err(TcpOptionWriteError::InvalidUnknownKind(kind))
+            );
+        }
     }
 
     proptest! {
@@ -889,4 +1017,32 @@ mod test {
             ]
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+        let options = TcpOptions::try_from_slice(&[1, 2, 3, 4]).unwrap();
+        assert_tokens(&options, &[Token::Bytes(&[1, 2, 3, 4])]);
+
+        // sequence of bytes
+        assert_de_tokens(
+            &options,
+            &[
+                Token::Seq { len: Some(4) },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+                Token::U8(4),
+                Token::SeqEnd,
+            ],
+        );
+
+        // too many bytes
+        assert_de_tokens_error::<TcpOptions>(
+            &[Token::Bytes(&[0; 41])],
+            "invalid length 41, expected a byte array with a length of at most 40 bytes",
+        );
+    }
 }
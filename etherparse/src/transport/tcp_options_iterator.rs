@@ -19,7 +19,7 @@ impl<'a> TcpOptionsIterator<'a> {
 }
 
 impl<'a> Iterator for TcpOptionsIterator<'a> {
-    type Item = Result<TcpOptionElement, TcpOptionReadError>;
+    type Item = Result<TcpOptionElement<'a>, TcpOptionReadError>;
 
     fn next(&mut self) -> Option<Self::Item> {
         use crate::TcpOptionElement::*;
@@ -161,9 +161,134 @@ impl<'a> Iterator for TcpOptionsIterator<'a> {
                         },
                     }
                 }
+                KIND_MD5_SIGNATURE => match expect_specific_size(LEN_MD5_SIGNATURE, self.options) {
+                    Err(value) => Some(Err(value)),
+                    _ => {
+                        let mut digest = [0u8; 16];
+                        digest.copy_from_slice(&self.options[2..18]);
+                        self.options = &self.options[18..];
+                        Some(Ok(Md5Signature(digest)))
+                    }
+                },
+                KIND_AUTHENTICATION => {
+                    //check that the length field can be read
+                    if self.options.len() < 2 {
+                        Some(Err(UnexpectedEndOfSlice {
+                            option_id: self.options[0],
+                            expected_len: 2,
+                            actual_len: self.options.len(),
+                        }))
+                    } else {
+                        // the mac length is variable (depends on the algorithm)
+                        let len = self.options[1];
+                        if len < LEN_AUTHENTICATION_MIN {
+                            Some(Err(UnexpectedSize {
+                                option_id: self.options[0],
+                                size: len,
+                            }))
+                        } else if self.options.len() < usize::from(len) {
+                            Some(Err(UnexpectedEndOfSlice {
+                                option_id: self.options[0],
+                                expected_len: len,
+                                actual_len: self.options.len(),
+                            }))
+                        } else {
+                            // the iterator can be created from arbitrary slices,
+                            // so macs that can not be part of a tcp header
+                            // (longer then 36 bytes) are reported as size errors
+                            let mac = TcpAuthenticationMac::try_from_slice(
+                                &self.options[4..usize::from(len)],
+                            );
+                            match mac {
+                                Ok(mac) => {
+                                    let result = AuthenticationOption {
+                                        key_id: self.options[2],
+                                        rnext_key_id: self.options[3],
+                                        mac,
+                                    };
+                                    self.options = &self.options[usize::from(len)..];
+                                    Some(Ok(result))
+                                }
+                                Err(_) => Some(Err(UnexpectedSize {
+                                    option_id: self.options[0],
+                                    size: len,
+                                })),
+                            }
+                        }
+                    }
+                }
+                KIND_FAST_OPEN_COOKIE => {
+                    //check that the length field can be read
+                    if self.options.len() < 2 {
+                        Some(Err(UnexpectedEndOfSlice {
+                            option_id: self.options[0],
+                            expected_len: 2,
+                            actual_len: self.options.len(),
+                        }))
+                    } else {
+                        // the cookie is either empty or 4 to 16 bytes long
+                        let len = self.options[1];
+                        let cookie_len = usize::from(len).wrapping_sub(2);
+                        if len != LEN_FAST_OPEN_COOKIE_MIN
+                            && !(TcpFastOpenCookie::MIN_LEN..=TcpFastOpenCookie::MAX_LEN)
+                                .contains(&cookie_len)
+                        {
+                            Some(Err(UnexpectedSize {
+                                option_id: self.options[0],
+                                size: len,
+                            }))
+                        } else if self.options.len() < usize::from(len) {
+                            Some(Err(UnexpectedEndOfSlice {
+                                option_id: self.options[0],
+                                expected_len: len,
+                                actual_len: self.options.len(),
+                            }))
+                        } else {
+                            // the conversion can not fail as the cookie
+                            // length was checked above
+                            let cookie = TcpFastOpenCookie::try_from_slice(
+                                &self.options[2..usize::from(len)],
+                            )
+                            .unwrap();
+                            self.options = &self.options[usize::from(len)..];
+                            Some(Ok(FastOpenCookie(cookie)))
+                        }
+                    }
+                }
 
-                //unknown id
-                _ => Some(Err(UnknownId(self.options[0]))),
+                //unknown id (skipped based on the length field)
+                kind => {
+                    //check that the length field can be read
+                    if self.options.len() < 2 {
+                        Some(Err(UnexpectedEndOfSlice {
+                            option_id: kind,
+                            expected_len: 2,
+                            actual_len: self.options.len(),
+                        }))
+                    } else {
+                        // the length includes the kind & length bytes
+                        let len = self.options[1];
+                        if len < 2 {
+                            Some(Err(UnexpectedSize {
+                                option_id: kind,
+                                size: len,
+                            }))
+                        } else if self.options.len() < usize::from(len) {
+                            Some(Err(UnexpectedEndOfSlice {
+                                option_id: kind,
+                                expected_len: len,
+                                actual_len: self.options.len(),
+                            }))
+                        } else {
+                            let (option, rest) = self.options.split_at(usize::from(len));
+                            self.options = rest;
+                            Some(Ok(Unknown {
+                                kind,
+                                data: &option[2..],
+                            }))
+                        }
+                    }
+                }
             };
 
             //in case the result was an error or the end move the slice to an end position
@@ -318,22 +443,69 @@ mod test {
                     Timestamp(30,31)
                 ]
             );
+
+            // tcp fast open cookies (cookie request, min & max size)
+            #[rustfmt::skip]
+            expect_elements(&[
+                    KIND_FAST_OPEN_COOKIE, 2,
+                    KIND_FAST_OPEN_COOKIE, 6,
+                    1, 2, 3, 4,
+                    KIND_FAST_OPEN_COOKIE, 18,
+                    1, 2, 3, 4, 5, 6, 7, 8,
+                    9, 10, 11, 12, 13, 14, 15, 16,
+                ],
+                &[
+                    FastOpenCookie(TcpFastOpenCookie::EMPTY),
+                    FastOpenCookie(TcpFastOpenCookie::try_from_slice(&[1, 2, 3, 4]).unwrap()),
+                    FastOpenCookie(TcpFastOpenCookie::try_from_slice(
+                        &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]
+                    ).unwrap()),
+                ]
+            );
+
+            // md5 signature & tcp authentication option (empty & 12 byte mac)
+            #[rustfmt::skip]
+            expect_elements(&[
+                    KIND_MD5_SIGNATURE, 18,
+                    1, 2, 3, 4, 5, 6, 7, 8,
+                    9, 10, 11, 12, 13, 14, 15, 16,
+                    KIND_AUTHENTICATION, 4, 1, 2,
+                    KIND_AUTHENTICATION, 16, 3, 4,
+                    1, 2, 3, 4, 5, 6, 7, 8,
+                    9, 10, 11, 12,
+                ],
+                &[
+                    Md5Signature([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]),
+                    AuthenticationOption {
+                        key_id: 1,
+                        rnext_key_id: 2,
+                        mac: TcpAuthenticationMac::EMPTY,
+                    },
+                    AuthenticationOption {
+                        key_id: 3,
+                        rnext_key_id: 4,
+                        mac: TcpAuthenticationMac::try_from_slice(
+                            &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]
+                        ).unwrap(),
+                    },
+                ]
+            );
         }
 
-        // unknown id
+        // unknown id (skipped via the length field)
         {
-            let data = [255, 2, 0, 0, 0,
-                0, 0, 0, 0, 0, //10
-                0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, //20
-                0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, //30
-                0, 0, 0, 0];
+            let data = [
+                255, 2,
+                253, 5, 1, 2, 3,
+                KIND_NOOP,
+                254, 3, 4,
+                KIND_END,
+            ];
             let mut it = TcpOptionsIterator::from_slice(&data);
-            assert_eq!(Some(Err(TcpOptionReadError::UnknownId(255))), it.next());
-            
-            //expect the iterator slice to be moved to the end
-            assert_eq!(0, it.rest().len());
+            assert_eq!(Some(Ok(Unknown{ kind: 255, data: &[] })), it.next());
+            assert_eq!(Some(Ok(Unknown{ kind: 253, data: &[1, 2, 3] })), it.next());
+            assert_eq!(Some(Ok(Noop)), it.next());
+            assert_eq!(Some(Ok(Unknown{ kind: 254, data: &[4] })), it.next());
             assert_eq!(None, it.next());
             assert_eq!(0, it.rest().len());
         }
@@ -352,7 +524,7 @@ mod test {
                                         KIND_MAXIMUM_SEGMENT_SIZE => 4,
                                         KIND_WINDOW_SCALE => 3,
                                         KIND_SELECTIVE_ACK_PERMITTED => 2,
-                                        KIND_SELECTIVE_ACK => if i < 2 {
+                                        KIND_SELECTIVE_ACK | KIND_AUTHENTICATION | KIND_FAST_OPEN_COOKIE | 253 => if i < 2 {
                                             // the inial check only checks if there
                                             // is enough data to read the length field
                                             2
@@ -360,6 +532,7 @@ mod test {
                                             slice[1]
                                         },
                                         KIND_TIMESTAMP => 10,
+                                        KIND_MD5_SIGNATURE => 18,
                                         _ => panic!("not part of the tests"),
                                     },
                                     actual_len: i
@@ -399,6 +572,22 @@ mod test {
                                     0, 0, 0, 0]);
             expect_unexpected_eos(&[KIND_TIMESTAMP, 10, 0, 0, 0,
                                     0, 0, 0, 0, 0]);
+            expect_unexpected_eos(&[KIND_MD5_SIGNATURE, 18, 0, 0, 0,
+                                    0, 0, 0, 0, 0,
+                                    0, 0, 0, 0, 0,
+                                    0, 0, 0]);
+            expect_unexpected_eos(&[KIND_AUTHENTICATION, 4, 0, 0]);
+            expect_unexpected_eos(&[KIND_AUTHENTICATION, 16, 0, 0, 0,
+                                    0, 0, 0, 0, 0,
+                                    0, 0, 0, 0, 0,
+                                    0]);
+            expect_unexpected_eos(&[KIND_FAST_OPEN_COOKIE, 6, 0, 0, 0,
+                                    0]);
+            expect_unexpected_eos(&[KIND_FAST_OPEN_COOKIE, 18, 0, 0, 0,
+                                    0, 0, 0, 0, 0,
+                                    0, 0, 0, 0, 0,
+                                    0, 0, 0]);
+            expect_unexpected_eos(&[253, 5, 0, 0, 0]);
         }
 
         // unexpected option size error 
@@ -449,6 +638,38 @@ mod test {
         
             expect_unexpected_size(KIND_TIMESTAMP, 9);
             expect_unexpected_size(KIND_TIMESTAMP, 11);
+
+            for size in [0, 1, 3, 4, 5, 19, 20] {
+                expect_unexpected_size(KIND_FAST_OPEN_COOKIE, size);
+            }
+
+            expect_unexpected_size(KIND_MD5_SIGNATURE, 17);
+            expect_unexpected_size(KIND_MD5_SIGNATURE, 19);
+
+            for size in [0, 1, 2, 3] {
+                expect_unexpected_size(KIND_AUTHENTICATION, size);
+            }
+
+            for size in [0, 1] {
+                expect_unexpected_size(253, size);
+            }
+
+            // authentication mac too long to be part of a tcp header
+            {
+                let mut data = [0u8; 41];
+                data[0] = KIND_AUTHENTICATION;
+                data[1] = 41;
+                let mut it = TcpOptionsIterator::from_slice(&data);
+                assert_eq!(
+                    Some(Err(TcpOptionReadError::UnexpectedSize {
+                        option_id: KIND_AUTHENTICATION,
+                        size: 41
+                    })),
+                    it.next()
+                );
+                assert_eq!(0, it.rest().len());
+                assert_eq!(None, it.next());
+            }
         }
     }
 }
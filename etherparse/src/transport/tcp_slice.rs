@@ -81,6 +81,97 @@ impl<'a> TcpSlice<'a> {
         }
     }
 
+    /// Try creating a [`TcpSlice`] from a slice containing the TCP header
+    /// and the TCP payload while tolerating invalid "data offset" values.
+    ///
+    /// Instead of returning an error the header length is clamped to the
+    /// valid range:
+    ///
+    /// * A "data offset" smaller than 5 is treated as 5 (no options).
+    /// * A "data offset" pointing behind the end of the slice is reduced
+    ///   to the number of complete 32 bit words in the slice.
+    ///
+    /// In both cases [`TcpSlice::header_len`] returns the clamped length
+    /// while [`TcpSlice::data_offset`] still returns the value present in
+    /// the header. The error [`TcpSlice::from_slice`] would have returned
+    /// can be retrieved via [`TcpSlice::data_offset_error`]. An error is
+    /// only returned if the slice is too short to contain the TCP header
+    /// without options (20 bytes).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{err::tcp::{HeaderError, HeaderSliceError}, TcpHeader, TcpSlice};
+    ///
+    /// let mut data = TcpHeader::new(1, 2, 3, 4).to_bytes();
+    /// // set data offset to 2
+    /// data[12] = 2 << 4;
+    ///
+    /// let tcp = TcpSlice::from_slice_lax(&data).unwrap();
+    /// assert_eq!(tcp.data_offset(), 2);
+    /// assert_eq!(tcp.header_len(), 20);
+    /// assert_eq!(
+    ///     tcp.data_offset_error(),
+    ///     Some(HeaderSliceError::Content(HeaderError::DataOffsetTooSmall { data_offset: 2 }))
+    /// );
+    /// ```
+    pub fn from_slice_lax(slice: &'a [u8]) -> Result<TcpSlice<'a>, err::LenError> {
+        // check length
+        if slice.len() < TcpHeader::MIN_LEN {
+            return Err(err::LenError {
+                required_len: TcpHeader::MIN_LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::TcpHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        // SAFETY:
+        // Safe as it is checked at the start of the function that the
+        // length of the slice is at least TcpHeader::MIN_LEN (20).
+        let header_len = usize::from((unsafe { *slice.get_unchecked(12) } & 0xf0) >> 2);
+
+        Ok(TcpSlice {
+            header_len: if header_len < TcpHeader::MIN_LEN {
+                TcpHeader::MIN_LEN
+            } else if slice.len() < header_len {
+                slice.len() & !0b11
+            } else {
+                header_len
+            },
+            slice,
+        })
+    }
+
+    /// Returns the error [`TcpSlice::from_slice`] would have returned
+    /// because of an invalid "data offset" (only possible if the slice was
+    /// created via [`TcpSlice::from_slice_lax`]).
+    ///
+    /// * [`err::tcp::HeaderError::DataOffsetTooSmall`] if the data offset
+    ///   is smaller than 5.
+    /// * [`err::LenError`] if the data offset points behind the end of the
+    ///   slice.
+    pub fn data_offset_error(&self) -> Option<err::tcp::HeaderSliceError> {
+        use err::tcp::{HeaderError::*, HeaderSliceError::*};
+
+        let data_offset = self.data_offset();
+        let header_len = usize::from(data_offset) * 4;
+        if header_len < TcpHeader::MIN_LEN {
+            Some(Content(DataOffsetTooSmall { data_offset }))
+        } else if self.slice.len() < header_len {
+            Some(Len(err::LenError {
+                required_len: header_len,
+                len: self.slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::TcpHeader,
+                layer_start_offset: 0,
+            }))
+        } else {
+            None
+        }
+    }
+
     /// Returns the slice containing the TCP header and payload.
     #[inline]
     pub fn slice(&self) -> &'a [u8] {
@@ -111,6 +202,16 @@ impl<'a> TcpSlice<'a> {
         }
     }
 
+    /// Length of the TCP payload in bytes.
+    ///
+    /// As TCP has no length field the payload length is determined by the
+    /// length of the sliced data (e.g. the payload length of the IP header)
+    /// minus the TCP header length (including TCP options).
+    #[inline]
+    pub fn payload_len(&self) -> usize {
+        self.slice.len() - self.header_len
+    }
+
     /// Length of the TCP header (including TCP options).
     #[inline]
     pub const fn header_len(&self) -> usize {
@@ -261,6 +362,16 @@ impl<'a> TcpSlice<'a> {
         unsafe { 0 != (*self.slice.get_unchecked(13) & 0b1000_0000) }
     }
 
+    /// Returns the illegal flag combination present in the header
+    /// (see [`TcpFlagAnomaly`]) or `None` if the flags are legal.
+    #[inline]
+    pub fn flag_anomaly(&self) -> Option<TcpFlagAnomaly> {
+        // SAFETY:
+        // Constructor checks that the slice has at least the length
+        // of 20.
+        TcpFlagAnomaly::from_flags_byte(unsafe { *self.slice.get_unchecked(13) })
+    }
+
     /// The number of data octets beginning with the one indicated in the
     /// acknowledgment field which the sender of this segment is willing to
     /// accept.
@@ -476,6 +587,7 @@ mod test {
                 let slice = TcpSlice::from_slice(&data).unwrap();
                 assert_eq!(&slice.to_header(), &tcp);
                 assert_eq!(slice.payload(), &payload);
+                assert_eq!(slice.payload_len(), payload.len());
             }
 
             // too little data to even decode the header
@@ -511,6 +623,78 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn from_slice_lax(
+            tcp in tcp_any()
+        ) {
+            use err::tcp::{HeaderError::*, HeaderSliceError::*};
+
+            let payload: [u8;4] = [1,2,3,4];
+            let data = {
+                let mut data = Vec::with_capacity(
+                    tcp.header_len() as usize +
+                    payload.len()
+                );
+                data.extend_from_slice(&tcp.to_bytes());
+                data.extend_from_slice(&payload);
+                data
+            };
+
+            // normal decode
+            {
+                let slice = TcpSlice::from_slice_lax(&data).unwrap();
+                assert_eq!(slice, TcpSlice::from_slice(&data).unwrap());
+                assert_eq!(slice.data_offset_error(), None);
+            }
+
+            // too little data to decode the header without options
+            for len in 0..TcpHeader::MIN_LEN {
+                assert_eq!(
+                    TcpSlice::from_slice_lax(&data[..len]).unwrap_err(),
+                    err::LenError {
+                        required_len: TcpHeader::MIN_LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::TcpHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+
+            // data offset pointing behind the end of the slice
+            for len in TcpHeader::MIN_LEN..tcp.header_len() {
+                let slice = TcpSlice::from_slice_lax(&data[..len]).unwrap();
+                assert_eq!(slice.data_offset(), tcp.data_offset());
+                assert_eq!(slice.header_len(), len / 4 * 4);
+                assert_eq!(slice.header_slice(), &data[..len / 4 * 4]);
+                assert_eq!(slice.payload(), &data[len / 4 * 4..len]);
+                assert_eq!(
+                    slice.data_offset_error(),
+                    Some(TcpSlice::from_slice(&data[..len]).unwrap_err())
+                );
+            }
+
+            // data offset smaller then minimum header size
+            {
+                let mut broken_data = data.clone();
+                for data_offset in 0..TcpHeader::MIN_DATA_OFFSET {
+                    // inject a bad data offset
+                    broken_data[12] = data_offset << 4 | ( broken_data[12] & 0b0000_1111);
+                    let slice = TcpSlice::from_slice_lax(&broken_data).unwrap();
+                    assert_eq!(slice.data_offset(), data_offset);
+                    assert_eq!(slice.header_len(), TcpHeader::MIN_LEN);
+                    assert_eq!(slice.options(), &[]);
+                    assert_eq!(slice.payload(), &broken_data[TcpHeader::MIN_LEN..]);
+                    assert_eq!(
+                        slice.data_offset_error(),
+                        Some(Content(DataOffsetTooSmall { data_offset }))
+                    );
+                }
+            }
+        }
+    }
+
     proptest! {
         #[test]
         fn getters(
@@ -545,6 +729,7 @@ mod test {
             assert_eq!(slice.urg(), tcp.urg);
             assert_eq!(slice.ece(), tcp.ece);
             assert_eq!(slice.cwr(), tcp.cwr);
+            assert_eq!(slice.flag_anomaly(), tcp.flag_anomaly());
             assert_eq!(slice.window_size(), tcp.window_size);
             assert_eq!(slice.checksum(), tcp.checksum);
             assert_eq!(slice.urgent_pointer(), tcp.urgent_pointer);
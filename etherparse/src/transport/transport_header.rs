@@ -4,7 +4,8 @@ use crate::{
 };
 
 /// The possible headers on the transport layer
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum TransportHeader {
     Udp(UdpHeader),
     Tcp(TcpHeader),
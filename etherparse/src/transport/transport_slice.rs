@@ -10,6 +10,78 @@ pub enum TransportSlice<'a> {
     Udp(UdpSlice<'a>),
     /// A slice containing a TCP header.
     Tcp(TcpSlice<'a>),
+    /// A slice containing an IGMP packet.
+    Igmp(IgmpSlice<'a>),
+    /// A slice containing an IPsec ESP header & the encrypted payload.
+    Esp(EspSlice<'a>),
+    /// A slice containing a DCCP header & payload.
+    Dccp(DccpSlice<'a>),
+}
+
+impl<'a> TransportSlice<'a> {
+    /// Decodes the transport layer identified by the given IP number
+    /// (e.g. the protocol of an IP header that was already decoded
+    /// elsewhere).
+    ///
+    /// The same protocols as in [`SlicedPacket`] are supported (ICMPv4,
+    /// ICMPv6, UDP, TCP, IGMP, ESP & DCCP). `Ok(None)` is returned if the
+    /// IP number identifies a protocol that is not supported. The length
+    /// of the transport layer is determined by the slice (e.g. the UDP
+    /// length field is checked against the slice length).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use etherparse::{ip_number, TransportSlice, UdpHeader};
+    ///
+    /// let udp = UdpHeader {
+    ///     source_port: 1234,
+    ///     destination_port: 53,
+    ///     length: UdpHeader::LEN_U16 + 4,
+    ///     checksum: 0,
+    /// };
+    /// let mut data = Vec::new();
+    /// data.extend_from_slice(&udp.to_bytes());
+    /// data.extend_from_slice(&[1, 2, 3, 4]);
+    ///
+    /// match TransportSlice::from_slice(ip_number::UDP, &data).unwrap() {
+    ///     Some(TransportSlice::Udp(udp)) => {
+    ///         assert_eq!(53, udp.destination_port());
+    ///         assert_eq!(&[1, 2, 3, 4], udp.payload());
+    ///     }
+    ///     _ => panic!("expected udp"),
+    /// }
+    ///
+    /// // unsupported protocols are not decoded
+    /// assert_eq!(None, TransportSlice::from_slice(ip_number::SCTP, &data).unwrap());
+    /// ```
+    pub fn from_slice(
+        ip_number: IpNumber,
+        slice: &'a [u8],
+    ) -> Result<Option<TransportSlice<'a>>, err::packet::SliceError> {
+        SlicedPacketCursor::new(slice, &Default::default())
+            .slice_transport(ip_number)
+            .map(|result| result.transport)
+    }
+
+    /// Returns the slice containing the transport header (including
+    /// options).
+    ///
+    /// For ICMP this is the part decoded by the `header()` methods, for ESP
+    /// the SPI & sequence number. IGMP messages are returned completely as
+    /// they have no separate payload.
+    pub fn header_slice(&self) -> &'a [u8] {
+        use TransportSlice::*;
+        match self {
+            Icmpv4(s) => &s.slice()[..s.header_len()],
+            Icmpv6(s) => &s.slice()[..s.header_len()],
+            Udp(s) => s.header_slice(),
+            Tcp(s) => s.header_slice(),
+            Igmp(s) => s.slice(),
+            Esp(s) => s.header().slice(),
+            Dccp(s) => s.header_slice(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -50,5 +122,217 @@ mod test {
             // debug
             assert_eq!(format!("{:?}", slice), format!("Tcp({:?})", t));
         }
+        // igmp
+        {
+            let raw = [0x11, 0, 0, 0, 0, 0, 0, 0];
+            let i = IgmpSlice::from_slice(&raw).unwrap();
+            let slice = TransportSlice::Igmp(i.clone());
+
+            // clone & eq
+            assert_eq!(slice.clone(), slice);
+
+            // debug
+            assert_eq!(format!("{:?}", slice), format!("Igmp({:?})", i));
+        }
+        // esp
+        {
+            let raw = EspHeader::default().to_bytes();
+            let e = EspSlice::from_slice(&raw).unwrap();
+            let slice = TransportSlice::Esp(e.clone());
+
+            // clone & eq
+            assert_eq!(slice.clone(), slice);
+
+            // debug
+            assert_eq!(format!("{:?}", slice), format!("Esp({:?})", e));
+        }
+        // dccp
+        {
+            let raw = DccpHeader::default().to_bytes();
+            let d = DccpSlice::from_slice(&raw).unwrap();
+            let slice = TransportSlice::Dccp(d.clone());
+
+            // clone & eq
+            assert_eq!(slice.clone(), slice);
+
+            // debug
+            assert_eq!(format!("{:?}", slice), format!("Dccp({:?})", d));
+        }
+    }
+
+    #[test]
+    fn from_slice() {
+        let payload = [1, 2, 3, 4];
+
+        // icmpv4 & icmpv6
+        {
+            let mut raw =
+                Icmpv4Header::new(Icmpv4Type::EchoRequest(IcmpEchoHeader { id: 1, seq: 2 }))
+                    .to_bytes()
+                    .to_vec();
+            raw.extend_from_slice(&payload);
+            assert_eq!(
+                TransportSlice::from_slice(ip_number::ICMP, &raw).unwrap(),
+                Some(TransportSlice::Icmpv4(
+                    Icmpv4Slice::from_slice(&raw).unwrap()
+                ))
+            );
+
+            let mut raw =
+                Icmpv6Header::new(Icmpv6Type::EchoRequest(IcmpEchoHeader { id: 1, seq: 2 }))
+                    .to_bytes()
+                    .to_vec();
+            raw.extend_from_slice(&payload);
+            assert_eq!(
+                TransportSlice::from_slice(ip_number::IPV6_ICMP, &raw).unwrap(),
+                Some(TransportSlice::Icmpv6(
+                    Icmpv6Slice::from_slice(&raw).unwrap()
+                ))
+            );
+        }
+        // udp
+        {
+            let mut raw = UdpHeader {
+                length: (UdpHeader::LEN + payload.len()) as u16,
+                ..Default::default()
+            }
+            .to_bytes()
+            .to_vec();
+            raw.extend_from_slice(&payload);
+            assert_eq!(
+                TransportSlice::from_slice(ip_number::UDP, &raw).unwrap(),
+                Some(TransportSlice::Udp(UdpSlice::from_slice(&raw).unwrap()))
+            );
+        }
+        // tcp
+        {
+            let header = TcpHeader::new(1, 2, 3, 4);
+            let mut raw = header.to_bytes().to_vec();
+            raw.extend_from_slice(&payload);
+            assert_eq!(
+                TransportSlice::from_slice(ip_number::TCP, &raw).unwrap(),
+                Some(TransportSlice::Tcp(TcpSlice::from_slice(&raw).unwrap()))
+            );
+
+            // content error
+            raw[12] = 2 << 4;
+            assert_eq!(
+                TransportSlice::from_slice(ip_number::TCP, &raw).unwrap_err(),
+                err::packet::SliceError::Tcp(err::tcp::HeaderError::DataOffsetTooSmall {
+                    data_offset: 2
+                })
+            );
+
+            // length error
+            assert_eq!(
+                TransportSlice::from_slice(ip_number::TCP, &raw[..TcpHeader::MIN_LEN - 1])
+                    .unwrap_err(),
+                err::packet::SliceError::Len(err::LenError {
+                    required_len: TcpHeader::MIN_LEN,
+                    len: TcpHeader::MIN_LEN - 1,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::TcpHeader,
+                    layer_start_offset: 0,
+                })
+            );
+        }
+        // igmp
+        {
+            let raw = [0x11, 0, 0, 0, 0, 0, 0, 0];
+            assert_eq!(
+                TransportSlice::from_slice(ip_number::IGMP, &raw).unwrap(),
+                Some(TransportSlice::Igmp(IgmpSlice::from_slice(&raw).unwrap()))
+            );
+        }
+        // esp
+        {
+            let mut raw = EspHeader::default().to_bytes().to_vec();
+            raw.extend_from_slice(&payload);
+            assert_eq!(
+                TransportSlice::from_slice(ip_number::ENCAP_SEC, &raw).unwrap(),
+                Some(TransportSlice::Esp(EspSlice::from_slice(&raw).unwrap()))
+            );
+        }
+        // dccp
+        {
+            let mut raw = DccpHeader::default().to_bytes().to_vec();
+            raw.extend_from_slice(&payload);
+            assert_eq!(
+                TransportSlice::from_slice(ip_number::DCCP, &raw).unwrap(),
+                Some(TransportSlice::Dccp(DccpSlice::from_slice(&raw).unwrap()))
+            );
+        }
+        // unsupported protocol
+        assert_eq!(
+            TransportSlice::from_slice(ip_number::SCTP, &payload).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn header_slice() {
+        let payload = [1, 2, 3, 4];
+
+        // icmpv4 & icmpv6
+        {
+            let mut raw =
+                Icmpv4Header::new(Icmpv4Type::EchoRequest(IcmpEchoHeader { id: 1, seq: 2 }))
+                    .to_bytes()
+                    .to_vec();
+            raw.extend_from_slice(&payload);
+            let slice = TransportSlice::Icmpv4(Icmpv4Slice::from_slice(&raw).unwrap());
+            assert_eq!(slice.header_slice(), &raw[..8]);
+
+            let mut raw =
+                Icmpv6Header::new(Icmpv6Type::EchoRequest(IcmpEchoHeader { id: 1, seq: 2 }))
+                    .to_bytes()
+                    .to_vec();
+            raw.extend_from_slice(&payload);
+            let slice = TransportSlice::Icmpv6(Icmpv6Slice::from_slice(&raw).unwrap());
+            assert_eq!(slice.header_slice(), &raw[..8]);
+        }
+        // udp
+        {
+            let mut raw = UdpHeader {
+                length: (UdpHeader::LEN + payload.len()) as u16,
+                ..Default::default()
+            }
+            .to_bytes()
+            .to_vec();
+            raw.extend_from_slice(&payload);
+            let slice = TransportSlice::Udp(UdpSlice::from_slice(&raw).unwrap());
+            assert_eq!(slice.header_slice(), &raw[..UdpHeader::LEN]);
+        }
+        // tcp with options
+        {
+            let mut header: TcpHeader = Default::default();
+            header
+                .set_options(&[TcpOptionElement::MaximumSegmentSize(1400)])
+                .unwrap();
+            let mut raw = header.to_bytes().to_vec();
+            raw.extend_from_slice(&payload);
+            let slice = TransportSlice::Tcp(TcpSlice::from_slice(&raw).unwrap());
+            assert_eq!(slice.header_slice(), &raw[..header.header_len()]);
+        }
+        // igmp
+        {
+            let raw = [0x11, 0, 0, 0, 0, 0, 0, 0];
+            let slice = TransportSlice::Igmp(IgmpSlice::from_slice(&raw).unwrap());
+            assert_eq!(slice.header_slice(), &raw);
+        }
+        // esp
+        {
+            let mut raw = EspHeader::default().to_bytes().to_vec();
+            raw.extend_from_slice(&payload);
+            let slice = TransportSlice::Esp(EspSlice::from_slice(&raw).unwrap());
+            assert_eq!(slice.header_slice(), &raw[..EspHeader::LEN]);
+        }
+        // dccp
+        {
+            let mut raw = DccpHeader::default().to_bytes().to_vec();
+            raw.extend_from_slice(&payload);
+            let slice = TransportSlice::Dccp(DccpSlice::from_slice(&raw).unwrap());
+            assert_eq!(slice.header_slice(), &raw[..raw.len() - payload.len()]);
+        }
     }
 }
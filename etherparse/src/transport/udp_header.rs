@@ -1,7 +1,9 @@
 use crate::{err::ValueTooBigError, *};
 
 /// Udp header according to rfc768.
-#[derive(Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UdpHeader {
     /// Source port of the packet (optional).
     pub source_port: u16,
@@ -151,6 +153,11 @@ impl UdpHeader {
     }
 
     /// Calculates the checksum of the current udp header given an ipv6 header and the payload.
+    ///
+    /// The checksum is calculated over the IPv6 pseudo header (source &
+    /// destination address, upper-layer packet length & next header),
+    /// the udp header & the payload. A calculated checksum of 0 is
+    /// returned as 0xffff (as 0 is reserved for "no checksum").
     pub fn calc_checksum_ipv6(
         &self,
         ip_header: &Ipv6Header,
@@ -179,6 +186,17 @@ impl UdpHeader {
         Ok(self.calc_checksum_ipv6_internal(source, destination, payload))
     }
 
+    /// Returns true if the checksum field matches the checksum calculated
+    /// given an ipv6 header and the payload.
+    ///
+    /// As the checksum is mandatory for UDP over IPv6 a checksum field
+    /// with the value 0 is treated as invalid.
+    pub fn is_checksum_valid_ipv6(&self, ip_header: &Ipv6Header, payload: &[u8]) -> bool {
+        self.calc_checksum_ipv6(ip_header, payload)
+            .map(|checksum| checksum == self.checksum)
+            .unwrap_or(false)
+    }
+
     fn calc_checksum_ipv6_internal(
         &self,
         source: [u8; 16],
@@ -299,6 +317,35 @@ impl UdpHeader {
     }
 }
 
+impl TryFrom<&[u8]> for UdpHeader {
+    type Error = err::LenError;
+
+    /// Decodes the header from the start of the slice via
+    /// [`UdpHeader::from_slice`] (any data after the header is ignored).
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        UdpHeader::from_slice(value).map(|(header, _)| header)
+    }
+}
+
+/// Writes a compact single line summary of the header.
+///
+/// ```
+/// use etherparse::UdpHeader;
+///
+/// let header = UdpHeader::without_ipv4_checksum(53, 1234, 4).unwrap();
+/// assert_eq!("UDP 53 -> 1234 len=12", format!("{}", header));
+/// ```
+impl core::fmt::Display for UdpHeader {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "UDP {} -> {} len={}",
+            self.source_port, self.destination_port, self.length
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -820,6 +867,38 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn is_checksum_valid_ipv6(
+            source_port in any::<u16>(),
+            destination_port in any::<u16>(),
+            ipv6 in ipv6_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20),
+        ) {
+            let valid = UdpHeader::with_ipv6_checksum(
+                source_port,
+                destination_port,
+                &ipv6,
+                &payload
+            ).unwrap();
+            assert!(valid.is_checksum_valid_ipv6(&ipv6, &payload));
+
+            // wrong checksum
+            {
+                let mut header = valid.clone();
+                header.checksum = header.checksum.wrapping_add(1);
+                assert!(false == header.is_checksum_valid_ipv6(&ipv6, &payload));
+            }
+
+            // zero checksum (not allowed for ipv6)
+            {
+                let mut header = valid.clone();
+                header.checksum = 0;
+                assert!(false == header.is_checksum_valid_ipv6(&ipv6, &payload));
+            }
+        }
+    }
+
     proptest! {
         #[test]
         #[cfg(not(any(target_pointer_width = "16", target_pointer_width = "32")))]
@@ -969,6 +1048,33 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn try_from(
+            input in udp_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(8 + dummy_data.len());
+            input.write(&mut buffer).unwrap();
+            buffer.extend(&dummy_data[..]);
+
+            // ok
+            assert_eq!(UdpHeader::try_from(&buffer[..]), Ok(input));
+
+            // error
+            assert_eq!(
+                UdpHeader::try_from(&buffer[..UdpHeader::LEN - 1]),
+                Err(err::LenError{
+                    required_len: UdpHeader::LEN,
+                    len: UdpHeader::LEN - 1,
+                    len_source: LenSource::Slice,
+                    layer: err::Layer::UdpHeader,
+                    layer_start_offset: 0,
+                })
+            );
+        }
+    }
+
     proptest! {
         #[test]
         fn from_bytes(input in udp_any()) {
@@ -1081,6 +1187,25 @@ mod test {
         }
     }
 
+    proptest! {
+        #[test]
+        fn hash(input in udp_any()) {
+            use std::collections::hash_map::DefaultHasher;
+            use core::hash::{Hash, Hasher};
+            let a = {
+                let mut hasher = DefaultHasher::new();
+                input.hash(&mut hasher);
+                hasher.finish()
+            };
+            let b = {
+                let mut hasher = DefaultHasher::new();
+                input.clone().hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(a, b);
+        }
+    }
+
     proptest! {
         #[test]
         fn dbg(input in udp_any()) {
@@ -1096,4 +1221,48 @@ mod test {
             );
         }
     }
+
+    proptest! {
+        #[test]
+        fn display(input in udp_any()) {
+            assert_eq!(
+                format!(
+                    "UDP {} -> {} len={}",
+                    input.source_port,
+                    input.destination_port,
+                    input.length,
+                ),
+                format!("{}", input)
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        use serde_test::{assert_tokens, Token};
+        assert_tokens(
+            &UdpHeader {
+                source_port: 1,
+                destination_port: 2,
+                length: 3,
+                checksum: 4,
+            },
+            &[
+                Token::Struct {
+                    name: "UdpHeader",
+                    len: 4,
+                },
+                Token::Str("source_port"),
+                Token::U16(1),
+                Token::Str("destination_port"),
+                Token::U16(2),
+                Token::Str("length"),
+                Token::U16(3),
+                Token::Str("checksum"),
+                Token::U16(4),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -152,6 +152,15 @@ impl<'a> UdpSlice<'a> {
         unsafe { get_unchecked_be_u16(self.slice.as_ptr().add(6)) }
     }
 
+    /// Returns true if the "checksum" field matches the checksum calculated
+    /// given an ipv6 header and the payload of the slice (see
+    /// [`UdpHeader::is_checksum_valid_ipv6`]).
+    #[inline]
+    pub fn is_checksum_valid_ipv6(&self, ip_header: &Ipv6Header) -> bool {
+        self.to_header()
+            .is_checksum_valid_ipv6(ip_header, self.payload())
+    }
+
     /// Length of the UDP header (equal to [`crate::UdpHeader::LEN`]).
     #[inline]
     pub const fn header_len(&self) -> usize {
@@ -456,4 +465,41 @@ mod test {
             }
         }
     }
+
+    proptest! {
+        #[test]
+        fn is_checksum_valid_ipv6(
+            source_port in any::<u16>(),
+            destination_port in any::<u16>(),
+            ipv6 in ipv6_any(),
+            payload in proptest::collection::vec(any::<u8>(), 0..20),
+        ) {
+            let udp = UdpHeader::with_ipv6_checksum(
+                source_port,
+                destination_port,
+                &ipv6,
+                &payload
+            ).unwrap();
+
+            // valid
+            {
+                let mut data = Vec::with_capacity(udp.header_len() + payload.len());
+                data.extend_from_slice(&udp.to_bytes());
+                data.extend_from_slice(&payload);
+                let slice = UdpSlice::from_slice(&data).unwrap();
+                assert!(slice.is_checksum_valid_ipv6(&ipv6));
+            }
+
+            // invalid
+            {
+                let mut bad = udp.clone();
+                bad.checksum = bad.checksum.wrapping_add(1);
+                let mut data = Vec::with_capacity(bad.header_len() + payload.len());
+                data.extend_from_slice(&bad.to_bytes());
+                data.extend_from_slice(&payload);
+                let slice = UdpSlice::from_slice(&data).unwrap();
+                assert!(false == slice.is_checksum_valid_ipv6(&ipv6));
+            }
+        }
+    }
 }
@@ -0,0 +1,244 @@
+use crate::*;
+
+/// VXLAN header according to
+/// [RFC 7348](https://datatracker.ietf.org/doc/html/rfc7348#section-5).
+///
+/// The header is transported in the payload of an UDP packet (by default
+/// on the destination port [`VxlanHeader::IANA_PORT`]) and is followed
+/// by an Ethernet II frame.
+///
+/// The reserved fields of the header are ignored when reading and
+/// are set to zero when writing the header.
+#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VxlanHeader {
+    /// Flags of the VXLAN header (only [`VxlanHeader::VNI_PRESENT_FLAG`]
+    /// is defined by RFC 7348 and must be set for a valid VNI).
+    pub flags: u8,
+    /// VXLAN Network Identifier identifying the overlay network.
+    pub vni: VxlanVni,
+}
+
+impl VxlanHeader {
+    /// Serialized size of a VXLAN header in bytes/octets.
+    pub const LEN: usize = 8;
+
+    /// UDP destination port assigned by IANA for VXLAN.
+    pub const IANA_PORT: u16 = 4789;
+
+    /// Flag indicating that the VNI field contains a valid value ("I" flag).
+    pub const VNI_PRESENT_FLAG: u8 = 0b0000_1000;
+
+    /// Creates a new VXLAN header with the given VNI and the "I" flag set.
+    #[inline]
+    pub fn new(vni: VxlanVni) -> VxlanHeader {
+        VxlanHeader {
+            flags: VxlanHeader::VNI_PRESENT_FLAG,
+            vni,
+        }
+    }
+
+    /// Read a VxlanHeader from a slice and return the header & unused parts of the slice.
+    #[inline]
+    pub fn from_slice(slice: &[u8]) -> Result<(VxlanHeader, &[u8]), err::LenError> {
+        Ok((
+            VxlanHeaderSlice::from_slice(slice)?.to_header(),
+            &slice[VxlanHeader::LEN..],
+        ))
+    }
+
+    /// Read a VxlanHeader from a static sized byte array.
+    #[inline]
+    pub fn from_bytes(bytes: [u8; 8]) -> VxlanHeader {
+        VxlanHeader {
+            flags: bytes[0],
+            // SAFETY: Safe as only 24 bits are read.
+            vni: unsafe {
+                VxlanVni::new_unchecked(u32::from_be_bytes([0, bytes[4], bytes[5], bytes[6]]))
+            },
+        }
+    }
+
+    /// Tries to read a VXLAN header from the current position.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read<T: std::io::Read + Sized>(reader: &mut T) -> Result<VxlanHeader, std::io::Error> {
+        let bytes = {
+            let mut bytes: [u8; 8] = [0; 8];
+            reader.read_exact(&mut bytes)?;
+            bytes
+        };
+        Ok(VxlanHeader::from_bytes(bytes))
+    }
+
+    /// Write the VXLAN header to the given writer.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write<T: std::io::Write + Sized>(&self, writer: &mut T) -> Result<(), std::io::Error> {
+        writer.write_all(&self.to_bytes())?;
+        Ok(())
+    }
+
+    /// Returns true if the "I" flag is set (indicating a valid VNI).
+    #[inline]
+    pub fn is_vni_present(&self) -> bool {
+        0 != self.flags & VxlanHeader::VNI_PRESENT_FLAG
+    }
+
+    /// Length of the serialized header in bytes.
+    ///
+    /// The function always returns the constant [`crate::VxlanHeader::LEN`]
+    /// and exists to keep the methods consistent with other headers.
+    #[inline]
+    pub const fn header_len(&self) -> usize {
+        VxlanHeader::LEN
+    }
+
+    /// Returns the serialized form of the header as a statically
+    /// sized byte array.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 8] {
+        let vni_be = self.vni.value().to_be_bytes();
+        [self.flags, 0, 0, 0, vni_be[1], vni_be[2], vni_be[3], 0]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn new() {
+        let vni = VxlanVni::try_new(123).unwrap();
+        let header = VxlanHeader::new(vni);
+        assert_eq!(header.flags, VxlanHeader::VNI_PRESENT_FLAG);
+        assert_eq!(header.vni, vni);
+        assert!(header.is_vni_present());
+    }
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in vxlan_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            // serialize
+            let mut buffer: Vec<u8> = Vec::with_capacity(VxlanHeader::LEN + dummy_data.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer.extend(&dummy_data[..]);
+
+            // ok case
+            {
+                let (result, rest) = VxlanHeader::from_slice(&buffer[..]).unwrap();
+                assert_eq!(result, input);
+                assert_eq!(rest, &dummy_data[..]);
+            }
+
+            // length error
+            for len in 0..VxlanHeader::LEN {
+                assert_eq!(
+                    VxlanHeader::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: VxlanHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::VxlanHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes(input in vxlan_any()) {
+            assert_eq!(input, VxlanHeader::from_bytes(input.to_bytes()));
+
+            // reserved fields are ignored
+            let mut bytes = input.to_bytes();
+            bytes[1] = 1;
+            bytes[2] = 2;
+            bytes[3] = 3;
+            bytes[7] = 4;
+            assert_eq!(input, VxlanHeader::from_bytes(bytes));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn read_write(input in vxlan_any()) {
+            // ok
+            {
+                let mut buffer = Vec::with_capacity(VxlanHeader::LEN);
+                input.write(&mut buffer).unwrap();
+                assert_eq!(&buffer[..], &input.to_bytes());
+                let mut cursor = Cursor::new(&buffer);
+                assert_eq!(input, VxlanHeader::read(&mut cursor).unwrap());
+                assert_eq!(VxlanHeader::LEN, cursor.position() as usize);
+            }
+            // io errors
+            for len in 0..VxlanHeader::LEN {
+                let bytes = input.to_bytes();
+                let mut cursor = Cursor::new(&bytes[..len]);
+                assert!(VxlanHeader::read(&mut cursor).is_err());
+
+                let mut buffer = [0u8; VxlanHeader::LEN];
+                let mut cursor = Cursor::new(&mut buffer[..len]);
+                assert!(input.write(&mut cursor).is_err());
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn is_vni_present(input in vxlan_any()) {
+            assert_eq!(
+                input.is_vni_present(),
+                0 != input.flags & 0b0000_1000
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn header_len(input in vxlan_any()) {
+            assert_eq!(VxlanHeader::LEN, input.header_len());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_bytes(input in vxlan_any()) {
+            let v_be = input.vni.value().to_be_bytes();
+            assert_eq!(
+                input.to_bytes(),
+                [
+                    input.flags, 0, 0, 0,
+                    v_be[1], v_be[2], v_be[3], 0,
+                ]
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_debug(input in vxlan_any()) {
+            assert_eq!(input, input.clone());
+            assert_eq!(
+                format!("VxlanHeader {{ flags: {:?}, vni: {:?} }}", input.flags, input.vni),
+                format!("{:?}", input)
+            );
+        }
+    }
+
+    #[test]
+    fn default() {
+        let header: VxlanHeader = Default::default();
+        assert_eq!(0, header.flags);
+        assert_eq!(VxlanVni::ZERO, header.vni);
+    }
+}
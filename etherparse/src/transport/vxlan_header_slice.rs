@@ -0,0 +1,153 @@
+use crate::*;
+use core::slice::from_raw_parts;
+
+/// A slice containing a VXLAN header of a network package.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VxlanHeaderSlice<'a> {
+    slice: &'a [u8],
+}
+
+impl<'a> VxlanHeaderSlice<'a> {
+    /// Creates a slice containing a VXLAN header.
+    #[inline]
+    pub fn from_slice(slice: &'a [u8]) -> Result<VxlanHeaderSlice<'a>, err::LenError> {
+        //check length
+        if slice.len() < VxlanHeader::LEN {
+            return Err(err::LenError {
+                required_len: VxlanHeader::LEN,
+                len: slice.len(),
+                len_source: LenSource::Slice,
+                layer: err::Layer::VxlanHeader,
+                layer_start_offset: 0,
+            });
+        }
+
+        //done
+        Ok(VxlanHeaderSlice {
+            // SAFETY:
+            // Safe as slice length is checked to be at least
+            // VxlanHeader::LEN (8) before this.
+            slice: unsafe { from_raw_parts(slice.as_ptr(), VxlanHeader::LEN) },
+        })
+    }
+
+    /// Returns the slice containing the VXLAN header.
+    #[inline]
+    pub fn slice(&self) -> &'a [u8] {
+        self.slice
+    }
+
+    /// Reads the "flags" byte from the slice.
+    #[inline]
+    pub fn flags(&self) -> u8 {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of VxlanHeader::LEN (8).
+        unsafe { *self.slice.get_unchecked(0) }
+    }
+
+    /// Returns true if the "I" flag is set (indicating a valid VNI).
+    #[inline]
+    pub fn is_vni_present(&self) -> bool {
+        0 != self.flags() & VxlanHeader::VNI_PRESENT_FLAG
+    }
+
+    /// Reads the "VXLAN Network Identifier" from the slice.
+    #[inline]
+    pub fn vni(&self) -> VxlanVni {
+        // SAFETY:
+        // Safe as the contructor checks that the slice has
+        // at least the length of VxlanHeader::LEN (8) and
+        // only 24 bits are read.
+        unsafe {
+            VxlanVni::new_unchecked(u32::from_be_bytes([
+                0,
+                *self.slice.get_unchecked(4),
+                *self.slice.get_unchecked(5),
+                *self.slice.get_unchecked(6),
+            ]))
+        }
+    }
+
+    /// Decode all the fields and copy the results to a VxlanHeader struct.
+    #[inline]
+    pub fn to_header(&self) -> VxlanHeader {
+        VxlanHeader {
+            flags: self.flags(),
+            vni: self.vni(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{test_gens::*, *};
+    use alloc::{format, vec::Vec};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn from_slice(
+            input in vxlan_any(),
+            dummy_data in proptest::collection::vec(any::<u8>(), 0..20)
+        ) {
+            let mut buffer: Vec<u8> = Vec::with_capacity(VxlanHeader::LEN + dummy_data.len());
+            buffer.extend_from_slice(&input.to_bytes());
+            buffer.extend(&dummy_data[..]);
+
+            // ok case
+            {
+                let slice = VxlanHeaderSlice::from_slice(&buffer[..]).unwrap();
+                assert_eq!(slice.slice(), &buffer[..VxlanHeader::LEN]);
+            }
+
+            // length error
+            for len in 0..VxlanHeader::LEN {
+                assert_eq!(
+                    VxlanHeaderSlice::from_slice(&buffer[..len]).unwrap_err(),
+                    err::LenError{
+                        required_len: VxlanHeader::LEN,
+                        len,
+                        len_source: LenSource::Slice,
+                        layer: err::Layer::VxlanHeader,
+                        layer_start_offset: 0,
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn getters(input in vxlan_any()) {
+            let bytes = input.to_bytes();
+            let slice = VxlanHeaderSlice::from_slice(&bytes).unwrap();
+
+            assert_eq!(slice.flags(), input.flags);
+            assert_eq!(slice.is_vni_present(), input.is_vni_present());
+            assert_eq!(slice.vni(), input.vni);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn to_header(input in vxlan_any()) {
+            let bytes = input.to_bytes();
+            let slice = VxlanHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(input, slice.to_header());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn clone_eq_dbg(input in vxlan_any()) {
+            let bytes = input.to_bytes();
+            let slice = VxlanHeaderSlice::from_slice(&bytes).unwrap();
+            assert_eq!(slice, slice.clone());
+            assert_eq!(
+                &format!("VxlanHeaderSlice {{ slice: {:?} }}", slice.slice()),
+                &format!("{:?}", slice)
+            );
+        }
+    }
+}
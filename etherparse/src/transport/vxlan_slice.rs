@@ -0,0 +1,46 @@
+use crate::*;
+
+/// VXLAN header and the slices of the encapsulated Ethernet II frame.
+///
+/// Only set in [`SlicedPacket::vxlan`] if VXLAN decoding was enabled via
+/// [`ParseOptions::vxlan_port`] and the UDP destination port matched.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VxlanSlice<'a> {
+    /// VXLAN header following the UDP header.
+    pub header: VxlanHeaderSlice<'a>,
+    /// Ethernet II header of the encapsulated frame.
+    pub link: Option<LinkSlice<'a>>,
+    /// Single or double vlan headers of the encapsulated frame if present.
+    pub vlan: Option<VlanSlice<'a>>,
+    /// IPv4 or IPv6 header, IP extension headers & payload of the
+    /// encapsulated frame if present.
+    pub net: Option<NetSlice<'a>>,
+    /// TCP or UDP header & payload of the encapsulated frame if present.
+    pub transport: Option<TransportSlice<'a>>,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use alloc::format;
+
+    #[test]
+    fn debug_clone_eq() {
+        let bytes = VxlanHeader::default().to_bytes();
+        let value = VxlanSlice {
+            header: VxlanHeaderSlice::from_slice(&bytes).unwrap(),
+            link: None,
+            vlan: None,
+            net: None,
+            transport: None,
+        };
+        assert_eq!(value, value.clone());
+        assert_eq!(
+            format!("{:?}", value),
+            format!(
+                "VxlanSlice {{ header: {:?}, link: None, vlan: None, net: None, transport: None }}",
+                value.header
+            )
+        );
+    }
+}
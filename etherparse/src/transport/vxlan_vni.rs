@@ -0,0 +1,299 @@
+use crate::err::ValueTooBigError;
+
+/// The VXLAN "VNI" (VXLAN Network Identifier) is a 24 bit unsigned
+/// integer present in the [`crate::VxlanHeader`].
+///
+/// # Example Usage:
+///
+/// ```
+/// use etherparse::VxlanVni;
+///
+/// // try into
+/// {
+///     let vni: VxlanVni = 123.try_into().unwrap();
+///     assert_eq!(vni.value(), 123);
+///
+///     // the VNI can always be converted back to an u32
+///     let value: u32 = vni.into();
+///     assert_eq!(123, value);
+/// }
+///
+/// // via try_new
+/// {
+///     let vni = VxlanVni::try_new(123).unwrap();
+///     assert_eq!(vni.value(), 123);
+///
+///     // note that only 24 bit numbers are allowed (meaning
+///     // 0b11111111_11111111_11111111 is the maximum allowed value)
+///     use etherparse::err::{ValueTooBigError, ValueType};
+///     assert_eq!(
+///         VxlanVni::try_new(VxlanVni::MAX_U32 + 1),
+///         Err(ValueTooBigError{
+///             actual: VxlanVni::MAX_U32 + 1,
+///             max_allowed: VxlanVni::MAX_U32,
+///             value_type: ValueType::VxlanVni,
+///         })
+///     );
+/// }
+///
+/// // via new_unchecked
+/// {
+///     // in case you are sure the number does not exceed the max
+///     // you can use the unsafe new_unchecked function
+///     let vni = unsafe {
+///         // please make sure that the value is not greater than VxlanVni::MAX_U32
+///         // before calling this method
+///         VxlanVni::new_unchecked(123)
+///     };
+///     assert_eq!(vni.value(), 123);
+/// }
+/// ```
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VxlanVni(u32);
+
+impl VxlanVni {
+    /// VxlanVni with value 0.
+    pub const ZERO: VxlanVni = VxlanVni(0);
+
+    /// Maximum value of a VXLAN Network Identifier.
+    pub const MAX_U32: u32 = 0b1111_1111_1111_1111_1111_1111;
+
+    /// Tries to create an [`VxlanVni`] and checks that the passed value
+    /// is smaller or equal than [`VxlanVni::MAX_U32`] (24 bit unsigned integer).
+    ///
+    /// In case the passed value is bigger then what can be represented in a 24 bit
+    /// integer an error is returned. Otherwise an `Ok` containing the [`VxlanVni`].
+    ///
+    /// ```
+    /// use etherparse::VxlanVni;
+    ///
+    /// let vni = VxlanVni::try_new(123).unwrap();
+    /// assert_eq!(vni.value(), 123);
+    ///
+    /// // if a number that can not be represented in a 24 bit integer
+    /// // gets passed in an error is returned
+    /// use etherparse::err::{ValueTooBigError, ValueType};
+    /// assert_eq!(
+    ///     VxlanVni::try_new(VxlanVni::MAX_U32 + 1),
+    ///     Err(ValueTooBigError{
+    ///         actual: VxlanVni::MAX_U32 + 1,
+    ///         max_allowed: VxlanVni::MAX_U32,
+    ///         value_type: ValueType::VxlanVni,
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    pub const fn try_new(value: u32) -> Result<VxlanVni, ValueTooBigError<u32>> {
+        use crate::err::ValueType;
+        if value <= VxlanVni::MAX_U32 {
+            Ok(VxlanVni(value))
+        } else {
+            Err(ValueTooBigError {
+                actual: value,
+                max_allowed: VxlanVni::MAX_U32,
+                value_type: ValueType::VxlanVni,
+            })
+        }
+    }
+
+    /// Creates an [`VxlanVni`] without checking that the value
+    /// is smaller or equal than [`VxlanVni::MAX_U32`] (24 bit unsigned integer).
+    /// The caller must guarantee that `value <= VxlanVni::MAX_U32`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be smaller or equal than [`VxlanVni::MAX_U32`]
+    /// otherwise the behavior of functions or data structures relying
+    /// on this pre-requirement is undefined.
+    #[inline]
+    pub const unsafe fn new_unchecked(value: u32) -> VxlanVni {
+        debug_assert!(value <= VxlanVni::MAX_U32);
+        VxlanVni(value)
+    }
+
+    /// Returns the underlying unsigned 24 bit value as an `u32` value.
+    #[inline]
+    pub const fn value(self) -> u32 {
+        self.0
+    }
+}
+
+impl core::fmt::Display for VxlanVni {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<VxlanVni> for u32 {
+    #[inline]
+    fn from(value: VxlanVni) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<u32> for VxlanVni {
+    type Error = ValueTooBigError<u32>;
+
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        use crate::err::ValueType;
+        if value <= VxlanVni::MAX_U32 {
+            Ok(VxlanVni(value))
+        } else {
+            Err(Self::Error {
+                actual: value,
+                max_allowed: VxlanVni::MAX_U32,
+                value_type: ValueType::VxlanVni,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::hash::{Hash, Hasher};
+    use proptest::prelude::*;
+    use std::format;
+
+    #[test]
+    fn derived_traits() {
+        // copy & clone
+        {
+            let a = VxlanVni(123);
+            let b = a;
+            assert_eq!(a, b);
+            assert_eq!(a.clone(), a);
+        }
+
+        // default
+        {
+            let actual: VxlanVni = Default::default();
+            assert_eq!(actual.value(), 0);
+        }
+
+        // debug
+        {
+            let a = VxlanVni(123);
+            assert_eq!(format!("{:?}", a), format!("VxlanVni(123)"));
+        }
+
+        // ord & partial ord
+        {
+            use core::cmp::Ordering;
+            let a = VxlanVni(123);
+            let b = a;
+            assert_eq!(a.cmp(&b), Ordering::Equal);
+            assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+        }
+
+        // hash
+        {
+            use std::collections::hash_map::DefaultHasher;
+            let a = {
+                let mut hasher = DefaultHasher::new();
+                VxlanVni(123).hash(&mut hasher);
+                hasher.finish()
+            };
+            let b = {
+                let mut hasher = DefaultHasher::new();
+                VxlanVni(123).hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(a, b);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_new(
+            valid_value in 0..=0b11111111_11111111_11111111u32,
+            invalid_value in 0b1_00000000_00000000_00000000u32..=u32::MAX
+        ) {
+            use crate::err::{ValueType, ValueTooBigError};
+            assert_eq!(
+                valid_value,
+                VxlanVni::try_new(valid_value).unwrap().value()
+            );
+            assert_eq!(
+                VxlanVni::try_new(invalid_value).unwrap_err(),
+                ValueTooBigError{
+                    actual: invalid_value,
+                    max_allowed: 0b11111111_11111111_11111111,
+                    value_type:  ValueType::VxlanVni
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn try_from(
+            valid_value in 0..=0b11111111_11111111_11111111u32,
+            invalid_value in 0b1_00000000_00000000_00000000u32..=u32::MAX
+        ) {
+            use crate::err::{ValueType, ValueTooBigError};
+            // try_into
+            {
+                let actual: VxlanVni = valid_value.try_into().unwrap();
+                assert_eq!(actual.value(), valid_value);
+
+                let err: Result<VxlanVni, ValueTooBigError<u32>> = invalid_value.try_into();
+                assert_eq!(
+                    err.unwrap_err(),
+                    ValueTooBigError{
+                        actual: invalid_value,
+                        max_allowed: 0b11111111_11111111_11111111,
+                        value_type:  ValueType::VxlanVni
+                    }
+                );
+            }
+            // try_from
+            {
+                assert_eq!(
+                    VxlanVni::try_from(valid_value).unwrap().value(),
+                    valid_value
+                );
+
+                assert_eq!(
+                    VxlanVni::try_from(invalid_value).unwrap_err(),
+                    ValueTooBigError{
+                        actual: invalid_value,
+                        max_allowed: 0b11111111_11111111_11111111,
+                        value_type:  ValueType::VxlanVni
+                    }
+                );
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn new_unchecked(valid_value in 0..=0b11111111_11111111_11111111u32) {
+            assert_eq!(
+                valid_value,
+                unsafe {
+                    VxlanVni::new_unchecked(valid_value).value()
+                }
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn fmt(valid_value in 0..=0b11111111_11111111_11111111u32) {
+            assert_eq!(format!("{}", VxlanVni(valid_value)), format!("{}", valid_value));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn from(valid_value in 0..=0b11111111_11111111_11111111u32,) {
+            let vni = VxlanVni::try_new(valid_value).unwrap();
+            let actual: u32 = vni.into();
+            assert_eq!(actual, valid_value);
+        }
+    }
+}